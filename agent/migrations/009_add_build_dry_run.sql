@@ -0,0 +1,51 @@
+-- Add dry-run builds: 'Verified' status + dry_run flag on builds
+-- SQLite doesn't support ALTER CONSTRAINT, so we need to recreate the table
+
+-- Step 1: Create new table with updated constraint
+CREATE TABLE builds_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    project_id INTEGER NOT NULL,
+    build_number INTEGER NOT NULL,
+    commit_hash TEXT NOT NULL,
+    commit_message TEXT,
+    author TEXT,
+
+    -- Build status
+    status TEXT NOT NULL CHECK(status IN ('Queued', 'Building', 'Success', 'Failed', 'Verified')) DEFAULT 'Queued',
+
+    -- Paths
+    log_path TEXT NOT NULL,
+    output_path TEXT,
+    deploy_log_path TEXT,
+
+    -- Deployment info
+    deployed_slot TEXT CHECK(deployed_slot IN ('Blue', 'Green')),
+
+    -- Dry-run (빌드/검증만 수행, 배포 생략)
+    dry_run INTEGER NOT NULL DEFAULT 0,
+
+    -- Timestamps
+    started_at TEXT NOT NULL DEFAULT (datetime('now')),
+    finished_at TEXT,
+
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+-- Step 2: Copy data from old table
+INSERT INTO builds_new (id, project_id, build_number, commit_hash, commit_message, author, status,
+                        log_path, output_path, deploy_log_path, deployed_slot, started_at, finished_at)
+SELECT id, project_id, build_number, commit_hash, commit_message, author, status,
+       log_path, output_path, deploy_log_path, deployed_slot, started_at, finished_at
+FROM builds;
+
+-- Step 3: Drop old table
+DROP TABLE builds;
+
+-- Step 4: Rename new table
+ALTER TABLE builds_new RENAME TO builds;
+
+-- Step 5: Recreate indexes
+CREATE INDEX IF NOT EXISTS idx_builds_project_id ON builds(project_id);
+CREATE INDEX IF NOT EXISTS idx_builds_status ON builds(status);
+CREATE INDEX IF NOT EXISTS idx_builds_started_at ON builds(started_at DESC);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_builds_project_build_number ON builds(project_id, build_number);
@@ -0,0 +1,4 @@
+-- Add lifecycle hook commands to projects table
+-- hooks: JSON string {"pre_build", "post_build", "post_deploy_success", "post_deploy_failure"}
+
+ALTER TABLE projects ADD COLUMN hooks TEXT;
@@ -0,0 +1,19 @@
+-- Container resource usage rollups (5-minute buckets)
+-- metrics collector가 주기적으로 샘플링한 CPU/메모리를 버킷 단위로 누적 평균/최대값으로 저장
+CREATE TABLE IF NOT EXISTS container_metrics (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    project_id INTEGER NOT NULL,
+    slot TEXT NOT NULL CHECK(slot IN ('Blue', 'Green')),
+    bucket_start INTEGER NOT NULL,          -- unix timestamp (초), 버킷 시작 시각
+    samples INTEGER NOT NULL DEFAULT 0,
+    cpu_avg REAL NOT NULL DEFAULT 0,        -- % (코어 1개 = 100)
+    cpu_max REAL NOT NULL DEFAULT 0,
+    memory_avg INTEGER NOT NULL DEFAULT 0,  -- bytes
+    memory_max INTEGER NOT NULL DEFAULT 0,
+    memory_limit INTEGER NOT NULL DEFAULT 0,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    UNIQUE (project_id, slot, bucket_start)
+);
+
+CREATE INDEX IF NOT EXISTS idx_container_metrics_project_bucket ON container_metrics(project_id, bucket_start);
+CREATE INDEX IF NOT EXISTS idx_container_metrics_bucket ON container_metrics(bucket_start);
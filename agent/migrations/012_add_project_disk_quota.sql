@@ -0,0 +1,2 @@
+-- Per-project disk quota (MB). NULL이면 전역 설정(settings.default_disk_quota_mb)을 따르고, 둘 다 없으면 무제한
+ALTER TABLE projects ADD COLUMN disk_quota_mb INTEGER;
@@ -0,0 +1,5 @@
+-- Test sharding: 빌드 성공 후 테스트 명령을 N개 컨테이너에 나눠 병렬 실행
+-- projects.test_config: JSON string {"command", "shards"} (ProjectTestConfig)
+-- builds.test_summary: JSON string, shard별 결과 (TestSummary)
+ALTER TABLE projects ADD COLUMN test_config TEXT;
+ALTER TABLE builds ADD COLUMN test_summary TEXT;
@@ -0,0 +1,17 @@
+-- Test results per build (JUnit 리포트에서 수집, flaky 테스트 판정용)
+-- attempt: 1 = 최초 실행, 2 = 실패 테스트 재실행 (ProjectTestConfig.retry_failed_command)
+CREATE TABLE IF NOT EXISTS test_results (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    build_id INTEGER NOT NULL,
+    project_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    status TEXT NOT NULL CHECK(status IN ('passed', 'failed', 'skipped')),
+    duration_ms INTEGER NOT NULL DEFAULT 0,
+    attempt INTEGER NOT NULL DEFAULT 1,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    FOREIGN KEY (build_id) REFERENCES builds(id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_test_results_build_id ON test_results(build_id);
+CREATE INDEX IF NOT EXISTS idx_test_results_project_name ON test_results(project_id, name);
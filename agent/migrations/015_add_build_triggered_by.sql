@@ -0,0 +1,4 @@
+-- 빌드를 누가/무엇이 시작했는지 기록 (BuildTrigger 형식)
+-- webhook, webhook:simulated, manual:{email}, api-token:{name}
+-- 기존 빌드는 NULL
+ALTER TABLE builds ADD COLUMN triggered_by TEXT;
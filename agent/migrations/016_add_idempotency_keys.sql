@@ -0,0 +1,14 @@
+-- 빌드 트리거 중복 방지용 Idempotency key
+-- scope: "build:{project_id}" (Idempotency-Key 헤더) 또는 "webhook" (X-GitHub-Delivery)
+-- response가 NULL이면 처리 중 (같은 key의 동시 요청은 409)
+CREATE TABLE IF NOT EXISTS idempotency_keys (
+    scope TEXT NOT NULL,
+    key TEXT NOT NULL,
+    status_code INTEGER,
+    response TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    expires_at TEXT NOT NULL,
+    PRIMARY KEY (scope, key)
+);
+
+CREATE INDEX IF NOT EXISTS idx_idempotency_keys_expires_at ON idempotency_keys(expires_at);
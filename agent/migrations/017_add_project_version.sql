@@ -0,0 +1,2 @@
+-- 프로젝트 설정 낙관적 동시성 제어: 설정 변경(PUT /api/projects/{id})마다 1씩 증가
+ALTER TABLE projects ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
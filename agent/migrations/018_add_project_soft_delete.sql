@@ -0,0 +1,4 @@
+-- 프로젝트 soft delete: 삭제 요청 시 deleted_at만 기록하고 유예 기간 후 project purge worker가 실제 삭제
+ALTER TABLE projects ADD COLUMN deleted_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_projects_deleted_at ON projects(deleted_at);
@@ -0,0 +1,2 @@
+-- 프로젝트 보관: 컨테이너/포트/webhook은 해제하고 설정, 빌드, 로그는 유지
+ALTER TABLE projects ADD COLUMN archived_at TEXT;
@@ -0,0 +1,2 @@
+-- 소스 가져오기 방식: git (컨테이너 안에서 git clone) 또는 tarball (서버가 GitHub API로 받아 마운트)
+ALTER TABLE projects ADD COLUMN source_fetch TEXT NOT NULL DEFAULT 'git';
@@ -0,0 +1,2 @@
+-- 빌드 컨테이너 네트워크: bridge (기본, socket proxy 접근 가능), isolated (격리 네트워크), none (네트워크 없음)
+ALTER TABLE projects ADD COLUMN build_network TEXT NOT NULL DEFAULT 'bridge';
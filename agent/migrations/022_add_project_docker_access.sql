@@ -0,0 +1,2 @@
+-- 빌드 컨테이너의 Docker(socket proxy) 접근 허용 여부. 기본은 차단 (필요한 프로젝트만 명시적으로 허용)
+ALTER TABLE projects ADD COLUMN docker_access INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,4 @@
+-- 독립 컨테이너 헬스체크: 설정(JSON, ContainerHealthCheck)과 health monitor가 기록한 마지막 결과
+ALTER TABLE containers ADD COLUMN health_check TEXT;
+ALTER TABLE containers ADD COLUMN health_status TEXT;
+ALTER TABLE containers ADD COLUMN health_checked_at TEXT;
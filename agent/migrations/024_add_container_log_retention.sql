@@ -0,0 +1,3 @@
+-- 독립 컨테이너 로그 보관 한도 (NULL이면 기본값: 10MB, 7일)
+ALTER TABLE containers ADD COLUMN log_max_size_mb INTEGER;
+ALTER TABLE containers ADD COLUMN log_retention_days INTEGER;
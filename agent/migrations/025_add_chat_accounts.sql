@@ -0,0 +1,12 @@
+-- ChatOps: Slack/Discord 사용자 → easyCICD 사용자 연결 (slash command 실행자 확인용)
+CREATE TABLE IF NOT EXISTS chat_accounts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    provider TEXT NOT NULL,            -- slack, discord
+    external_user_id TEXT NOT NULL,    -- Slack user_id / Discord user id
+    user_id INTEGER NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE (provider, external_user_id),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_chat_accounts_user_id ON chat_accounts(user_id);
@@ -0,0 +1,2 @@
+-- 빌드 동시 실행 그룹 (예: "heavy-java"). 그룹별 최대 동시 빌드 수는 settings의 build_concurrency_groups
+ALTER TABLE projects ADD COLUMN concurrency_group TEXT;
@@ -0,0 +1,3 @@
+-- 빌드 컨테이너 리소스 사용량 (docker stats 샘플링). 이전 빌드는 NULL
+ALTER TABLE builds ADD COLUMN peak_memory_bytes INTEGER;
+ALTER TABLE builds ADD COLUMN cpu_time_ms INTEGER;
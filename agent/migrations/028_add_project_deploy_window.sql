@@ -0,0 +1,2 @@
+-- 배포 허용 시간대 (JSON, see DeployWindow). 창 밖에서 성공한 빌드는 Held로 대기했다가 다음 창이 열리면 배포
+ALTER TABLE projects ADD COLUMN deploy_window TEXT;
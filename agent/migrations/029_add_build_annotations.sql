@@ -0,0 +1,3 @@
+-- 빌드/배포 메모와 라벨 (예: "hotfix for incident #12"). labels는 JSON 배열 문자열
+ALTER TABLE builds ADD COLUMN note TEXT;
+ALTER TABLE builds ADD COLUMN labels TEXT;
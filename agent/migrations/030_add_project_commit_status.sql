@@ -0,0 +1,2 @@
+-- GitHub commit status 보고 설정 (JSON, see ProjectCommitStatus). NULL이면 보고하지 않음
+ALTER TABLE projects ADD COLUMN commit_status TEXT;
@@ -0,0 +1,2 @@
+-- 배포 시점에 고정한 런타임 이미지 (예: node@sha256:...). 롤백/재배포는 태그 대신 이 digest로 실행
+ALTER TABLE builds ADD COLUMN runtime_image_digest TEXT;
@@ -0,0 +1,3 @@
+-- 베이스 이미지 업데이트 확인: upstream digest가 바뀌면 자동 재빌드할지, 마지막 확인 결과 (JSON, see ImageUpdateStatus)
+ALTER TABLE projects ADD COLUMN auto_rebuild_on_image_update INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE projects ADD COLUMN image_update_status TEXT;
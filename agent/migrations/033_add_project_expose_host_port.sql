@@ -0,0 +1,2 @@
+-- 런타임 컨테이너 호스트 포트 노출 여부 (0이면 포트를 바인딩하지 않고 easycicd 네트워크의 프록시로만 접근)
+ALTER TABLE projects ADD COLUMN expose_host_port INTEGER NOT NULL DEFAULT 1;
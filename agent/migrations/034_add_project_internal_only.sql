@@ -0,0 +1,2 @@
+-- 내부 전용 프로젝트: 프록시 라우팅/서브도메인 없이 easycicd 네트워크의 다른 컨테이너에서만 접근
+ALTER TABLE projects ADD COLUMN internal_only INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,2 @@
+-- 서비스 의존 설정 (JSON, see ProjectDependencies). 배포 시 SERVICE_<NAME>_HOST/PORT 환경 변수로 주입
+ALTER TABLE projects ADD COLUMN dependencies TEXT;
@@ -0,0 +1,2 @@
+-- 원격 로그 전송 기록 (JSON: 위치, 원본/압축본 SHA-256). 원격 사본 변조 여부 확인용
+ALTER TABLE builds ADD COLUMN log_shipment TEXT;
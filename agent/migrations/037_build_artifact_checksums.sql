@@ -0,0 +1,2 @@
+-- 빌드 산출물 파일별 SHA-256과 agent 키 서명 (JSON). 롤백 전에 산출물 변조/손상 여부 확인
+ALTER TABLE builds ADD COLUMN artifact_checksums TEXT;
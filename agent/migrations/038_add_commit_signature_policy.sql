@@ -0,0 +1,3 @@
+-- 서명된 커밋만 배포 (GitHub API의 commit verification 기준). 검증되지 않은 커밋의 빌드는 Verified로 끝나고 이유를 기록
+ALTER TABLE projects ADD COLUMN require_signed_commits INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE builds ADD COLUMN deploy_blocked_reason TEXT;
@@ -0,0 +1,2 @@
+-- 빌드가 Queued 상태로 기다린 시간 (ms). 실행을 시작할 때 기록
+ALTER TABLE builds ADD COLUMN queue_wait_ms INTEGER;
@@ -0,0 +1,2 @@
+-- 웜 스탠바이: 슬롯 전환 후 이전 활성 컨테이너를 지우지 않고 비활성 슬롯에서 계속 실행 (수동 전환/긴급 롤백 즉시 처리)
+ALTER TABLE projects ADD COLUMN warm_standby INTEGER NOT NULL DEFAULT 0;
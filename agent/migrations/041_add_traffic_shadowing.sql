@@ -0,0 +1,4 @@
+-- 트래픽 섀도잉: 전환 전에 운영 요청 일부(%)를 새 비활성 슬롯으로 복제해 상태 코드/지연 시간 비교. 0이면 사용 안 함
+ALTER TABLE projects ADD COLUMN shadow_traffic_percent INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE projects ADD COLUMN shadow_duration_secs INTEGER NOT NULL DEFAULT 60;
+ALTER TABLE builds ADD COLUMN shadow_report TEXT;
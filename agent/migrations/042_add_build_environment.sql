@@ -0,0 +1,2 @@
+-- 빌드 환경 스냅샷: 실제로 실행한 이미지 digest, 전체 명령(환경 변수 주입 후), 마운트, 작업 디렉토리 (JSON)
+ALTER TABLE builds ADD COLUMN build_environment TEXT;
@@ -0,0 +1,2 @@
+-- 정확한 재빌드: 환경 스냅샷과 커밋을 재사용한 원본 빌드 ID
+ALTER TABLE builds ADD COLUMN rebuild_of INTEGER;
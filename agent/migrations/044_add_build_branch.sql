@@ -0,0 +1,2 @@
+-- 빌드한 브랜치 (webhook ref 또는 트리거 시점의 프로젝트 브랜치)
+ALTER TABLE builds ADD COLUMN branch TEXT;
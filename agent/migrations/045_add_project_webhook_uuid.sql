@@ -0,0 +1,2 @@
+-- 숫자가 아닌 webhook ID (Bitbucket hook UUID). GitHub/GitLab webhook ID는 github_webhook_id에 저장
+ALTER TABLE projects ADD COLUMN webhook_uuid TEXT;
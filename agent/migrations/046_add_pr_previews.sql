@@ -0,0 +1,20 @@
+-- PR 프리뷰 환경: GitHub pull_request 이벤트마다 PR 브랜치를 빌드해 project-{id}-pr-{number} 컨테이너로 실행
+ALTER TABLE projects ADD COLUMN pr_previews INTEGER NOT NULL DEFAULT 0;
+-- 프리뷰 빌드면 PR 번호 (배포/롤백 대상이 아님)
+ALTER TABLE builds ADD COLUMN preview_pr INTEGER;
+
+CREATE TABLE IF NOT EXISTS preview_environments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    project_id INTEGER NOT NULL,
+    pr_number INTEGER NOT NULL,
+    branch TEXT NOT NULL,
+    head_sha TEXT NOT NULL,
+    title TEXT,
+    status TEXT NOT NULL DEFAULT 'building',   -- building, running, failed
+    build_id INTEGER,                         -- 마지막 프리뷰 빌드
+    container_id TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE (project_id, pr_number),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
@@ -0,0 +1,5 @@
+-- 배포 전략: blue_green(즉시 전환) 또는 canary(새 슬롯으로 트래픽 일부만 보내며 단계적으로 늘림)
+ALTER TABLE projects ADD COLUMN deployment_strategy TEXT NOT NULL DEFAULT 'blue_green';
+-- 진행 중인 카나리 빌드 (비활성 슬롯에서 실행 중)와 현재 트래픽 비율(%)
+ALTER TABLE projects ADD COLUMN canary_build_id INTEGER;
+ALTER TABLE projects ADD COLUMN canary_weight INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,2 @@
+-- 런타임 컨테이너를 기본 네트워크 외에 추가로 연결할 사용자 Docker 네트워크 (JSON 배열). NULL이면 없음
+ALTER TABLE projects ADD COLUMN extra_networks TEXT;
@@ -0,0 +1,22 @@
+-- 빌드 파이프라인: 순서대로 실행할 단계 목록 (JSON 배열 [{name, command}]). NULL이면 build_command 하나로 실행
+ALTER TABLE projects ADD COLUMN pipeline TEXT;
+
+-- 빌드별 파이프라인 단계 실행 기록
+-- log_start_line/log_end_line: 빌드 로그 안의 단계 구간 (0부터, 양끝 포함). skipped 단계는 NULL
+CREATE TABLE IF NOT EXISTS build_stages (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    build_id INTEGER NOT NULL,
+    project_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    status TEXT NOT NULL CHECK(status IN ('completed', 'failed', 'skipped')),
+    started_at TEXT,
+    duration_ms INTEGER,
+    log_start_line INTEGER,
+    log_end_line INTEGER,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    FOREIGN KEY (build_id) REFERENCES builds(id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_build_stages_build_id ON build_stages(build_id, position);
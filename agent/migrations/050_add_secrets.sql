@@ -0,0 +1,9 @@
+-- 암호화된 비밀 값 (AES-256-GCM, base64(nonce || ciphertext)). 프로젝트 환경변수에서 `${secret:NAME}`으로 참조
+CREATE TABLE IF NOT EXISTS secrets (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE,
+    encrypted_value TEXT NOT NULL,
+    description TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
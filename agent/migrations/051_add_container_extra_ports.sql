@@ -0,0 +1,2 @@
+-- 독립 컨테이너 추가 포트 매핑 (JSON 배열: [{"host_port": 15001, "container_port": 9093, "protocol": "udp"}]). NULL이면 기본 매핑만
+ALTER TABLE containers ADD COLUMN extra_ports TEXT;
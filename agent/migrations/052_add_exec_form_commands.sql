@@ -0,0 +1,5 @@
+-- exec 형식 실행 설정 (JSON 문자열 배열). 있으면 command를 `/bin/sh -c`로 감싸지 않고 ENTRYPOINT/CMD로 그대로 전달
+ALTER TABLE containers ADD COLUMN entrypoint TEXT;
+ALTER TABLE containers ADD COLUMN args TEXT;
+ALTER TABLE projects ADD COLUMN runtime_entrypoint TEXT;
+ALTER TABLE projects ADD COLUMN runtime_args TEXT;
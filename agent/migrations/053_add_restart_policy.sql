@@ -0,0 +1,5 @@
+-- Docker 재시작 정책 (no / on-failure / unless-stopped / always). max_retries는 on-failure에서만 사용 (NULL이면 무제한)
+ALTER TABLE containers ADD COLUMN restart_policy TEXT NOT NULL DEFAULT 'unless-stopped';
+ALTER TABLE containers ADD COLUMN restart_max_retries INTEGER;
+ALTER TABLE projects ADD COLUMN runtime_restart_policy TEXT NOT NULL DEFAULT 'unless-stopped';
+ALTER TABLE projects ADD COLUMN runtime_restart_max_retries INTEGER;
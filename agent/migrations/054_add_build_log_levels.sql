@@ -0,0 +1,2 @@
+-- 빌드 로그 줄 심각도별 개수 (JSON, LogLevelCounts). 분류 전/이전 빌드는 NULL
+ALTER TABLE builds ADD COLUMN log_levels TEXT;
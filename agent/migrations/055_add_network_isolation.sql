@@ -0,0 +1,5 @@
+-- 프로젝트 전용 Docker 네트워크 (easycicd-project-{id}). network_peers: 이 네트워크에 함께 붙는 프로젝트 ID (JSON 배열)
+ALTER TABLE projects ADD COLUMN network_isolation INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE projects ADD COLUMN network_peers TEXT;
+-- 독립 컨테이너 그룹 전용 네트워크 (easycicd-group-{name}). NULL이면 기본 네트워크
+ALTER TABLE containers ADD COLUMN network_group TEXT;
@@ -0,0 +1,4 @@
+-- Dockerfile 빌드: 저장소 Dockerfile 경로 (working_directory 기준). NULL이면 산출물을 /app에 마운트하는 기존 방식
+ALTER TABLE projects ADD COLUMN dockerfile_path TEXT;
+-- Dockerfile 빌드로 만든 런타임 이미지 태그 (project-{id}:build-{n}). 배포/롤백 때 이 이미지를 그대로 실행
+ALTER TABLE builds ADD COLUMN image_tag TEXT;
@@ -0,0 +1,2 @@
+-- 실패한 빌드의 짧은 원인 요약 (로그 휴리스틱). 성공/이전 빌드는 NULL
+ALTER TABLE builds ADD COLUMN failure_summary TEXT;
@@ -0,0 +1,2 @@
+-- 실패한 빌드에 대해 LLM이 제안한 원인/해결 진단 (FAILURE_DIAGNOSIS_URL). 진단하지 않은 빌드는 NULL
+ALTER TABLE builds ADD COLUMN diagnosis TEXT;
@@ -0,0 +1,3 @@
+-- 빌드 보존 정책: 최근 성공 빌드 N개 / 최대 보존 일수. 둘 다 NULL이면 보존 (정리하지 않음)
+ALTER TABLE projects ADD COLUMN build_retention_count INTEGER;
+ALTER TABLE projects ADD COLUMN build_retention_days INTEGER;
@@ -0,0 +1,4 @@
+-- 프로젝트 노트 (Markdown: 배포 런북, 온콜 정보). 실패 알림에 링크로 포함
+ALTER TABLE projects ADD COLUMN notes TEXT;
+ALTER TABLE projects ADD COLUMN notes_updated_at DATETIME;
+ALTER TABLE projects ADD COLUMN notes_updated_by TEXT;
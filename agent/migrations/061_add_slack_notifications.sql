@@ -0,0 +1,31 @@
+-- Slack incoming webhook configurations
+CREATE TABLE IF NOT EXISTS slack_webhooks (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+
+    -- Webhook 정보
+    label TEXT NOT NULL,
+    webhook_url TEXT NOT NULL,
+
+    -- 알림 필터링 설정
+    enabled INTEGER NOT NULL DEFAULT 1,
+    notify_on_build_start INTEGER NOT NULL DEFAULT 0,
+    notify_on_build_success INTEGER NOT NULL DEFAULT 1,
+    notify_on_build_failure INTEGER NOT NULL DEFAULT 1,
+    notify_on_deploy_start INTEGER NOT NULL DEFAULT 0,
+    notify_on_deploy_success INTEGER NOT NULL DEFAULT 1,
+    notify_on_deploy_failure INTEGER NOT NULL DEFAULT 1,
+
+    -- 멘션 설정 (선택사항, Slack 사용자 ID 또는 user group ID JSON 배열)
+    mention_user_ids TEXT,
+    mention_group_ids TEXT,
+    mention_on_failure_only INTEGER NOT NULL DEFAULT 1,
+
+    -- Timestamps
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_slack_webhooks_label ON slack_webhooks(label);
+
+-- 프로젝트별 Slack webhook 설정 (선택사항, Discord webhook과 독립적으로 함께 사용 가능)
+ALTER TABLE projects ADD COLUMN slack_webhook_id INTEGER REFERENCES slack_webhooks(id) ON DELETE SET NULL;
@@ -0,0 +1,2 @@
+-- 이메일 알림 수신자 (JSON 배열). SMTP 설정(settings.smtp)이 있으면 빌드 실패/배포 결과를 메일로 전송
+ALTER TABLE projects ADD COLUMN notification_emails TEXT;
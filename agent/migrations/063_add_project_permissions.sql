@@ -0,0 +1,14 @@
+-- 프로젝트별 사용자 권한 (deploy, view_logs, manage_settings)
+-- 권한이 하나도 없는 프로젝트는 로그인한 모든 사용자가 전체 권한, 하나라도 있으면 부여된 사용자만 접근
+CREATE TABLE IF NOT EXISTS project_permissions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    project_id INTEGER NOT NULL,
+    user_email TEXT NOT NULL,          -- 소문자로 저장
+    permission TEXT NOT NULL,          -- deploy, view_logs, manage_settings
+    created_by TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE (project_id, user_email, permission),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_permissions_user_email ON project_permissions(user_email);
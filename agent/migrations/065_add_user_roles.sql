@@ -0,0 +1,3 @@
+-- 사용자 역할: admin / developer / viewer
+-- 기존 사용자는 모두 전체 권한이었으므로 admin으로 시작 (새 사용자는 INSERT 시 지정)
+ALTER TABLE users ADD COLUMN role TEXT NOT NULL DEFAULT 'admin';
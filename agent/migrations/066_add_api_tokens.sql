@@ -0,0 +1,16 @@
+-- 개인 API 토큰 (Authorization: Bearer ecd_...). 토큰 원문은 저장하지 않고 SHA-256 해시만 보관
+CREATE TABLE IF NOT EXISTS api_tokens (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,
+    token_prefix TEXT NOT NULL,        -- 목록에서 토큰 구분용 (예: ecd_1a2b3c4d)
+    scopes TEXT NOT NULL,              -- JSON 배열: read, trigger-builds, admin
+    expires_at TEXT,                   -- NULL이면 만료 없음
+    last_used_at TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    UNIQUE (user_id, name)
+);
+
+CREATE INDEX IF NOT EXISTS idx_api_tokens_user_id ON api_tokens(user_id);
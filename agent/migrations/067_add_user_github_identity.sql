@@ -0,0 +1,6 @@
+-- GitHub 로그인 연결 정보. github_token은 저장소 목록 조회에 사용 (PAT 미지정 시)
+ALTER TABLE users ADD COLUMN github_id INTEGER;
+ALTER TABLE users ADD COLUMN github_login TEXT;
+ALTER TABLE users ADD COLUMN github_token TEXT;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_users_github_id ON users(github_id);
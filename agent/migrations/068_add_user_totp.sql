@@ -0,0 +1,14 @@
+-- TOTP 2단계 인증. secret은 SecretCipher로 암호화 (AAD: totp:{user_id})
+CREATE TABLE IF NOT EXISTS user_totp (
+    user_id INTEGER PRIMARY KEY,
+    secret TEXT NOT NULL,
+    -- NULL이면 등록 중 (첫 코드 확인 전)
+    enabled_at TEXT,
+    -- 마지막으로 사용한 간격 번호 (같은 코드 재사용 방지)
+    last_used_step INTEGER,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+-- 세션별 마지막 2단계 인증 시각 (민감한 작업은 최근 인증 필요)
+ALTER TABLE sessions ADD COLUMN mfa_verified_at TEXT;
@@ -0,0 +1,9 @@
+-- ChatOps 계정 연결 코드: 채팅 `link` 명령으로 발급, 로그인 사용자가 API로 사용해 채팅 계정 소유를 확인
+CREATE TABLE IF NOT EXISTS chat_link_codes (
+    code_hash TEXT PRIMARY KEY,        -- SHA-256 (코드 원문은 저장하지 않음)
+    provider TEXT NOT NULL,            -- slack, discord
+    external_user_id TEXT NOT NULL,
+    expires_at TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE (provider, external_user_id)
+);
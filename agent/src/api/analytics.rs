@@ -0,0 +1,175 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::application::ports::repositories::{BuildRepository, ProjectRepository};
+use crate::db::models::Build;
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::infrastructure::timezone;
+use crate::state::AppContext;
+
+const DEFAULT_ANALYTICS_BUILDS: i64 = 50;
+const MAX_ANALYTICS_BUILDS: i64 = 500;
+
+#[derive(Deserialize)]
+pub struct AnalyticsQuery {
+    /// 최근 빌드 수 (기본 50, 최대 500)
+    limit: Option<i64>,
+}
+
+/// 빌드 하나의 소요 시간/리소스 사용량
+#[derive(Debug, Serialize)]
+struct BuildUsagePoint {
+    build_id: i64,
+    build_number: i64,
+    status: String,
+    #[serde(serialize_with = "timezone::serialize")]
+    started_at: String,
+    duration_secs: Option<i64>,
+    peak_memory_bytes: Option<i64>,
+    cpu_time_ms: Option<i64>,
+}
+
+impl From<&Build> for BuildUsagePoint {
+    fn from(build: &Build) -> Self {
+        let duration_secs = match (
+            timezone::parse_stored(&build.started_at),
+            build.finished_at.as_deref().and_then(timezone::parse_stored),
+        ) {
+            (Some(start), Some(end)) => Some((end - start).num_seconds().max(0)),
+            _ => None,
+        };
+        Self {
+            build_id: build.id,
+            build_number: build.build_number,
+            status: build.status.to_string(),
+            started_at: build.started_at.clone(),
+            duration_secs,
+            peak_memory_bytes: build.peak_memory_bytes,
+            cpu_time_ms: build.cpu_time_ms,
+        }
+    }
+}
+
+/// 한 지표의 평균/최대와 추세
+#[derive(Debug, Default, PartialEq, Serialize)]
+struct MetricSummary {
+    samples: usize,
+    avg: Option<f64>,
+    max: Option<i64>,
+    /// 앞쪽 절반 대비 뒤쪽(최근) 절반 평균의 변화율 (%). 샘플이 4개 미만이면 None
+    trend_percent: Option<f64>,
+}
+
+/// 오래된 순 값으로 요약
+fn summarize(values: &[i64]) -> MetricSummary {
+    if values.is_empty() {
+        return MetricSummary::default();
+    }
+    let mean = |v: &[i64]| v.iter().sum::<i64>() as f64 / v.len() as f64;
+
+    let trend_percent = if values.len() >= 4 {
+        let (older, recent) = values.split_at(values.len() / 2);
+        let older_avg = mean(older);
+        (older_avg > 0.0).then(|| (mean(recent) - older_avg) / older_avg * 100.0)
+    } else {
+        None
+    };
+
+    MetricSummary {
+        samples: values.len(),
+        avg: Some(mean(values)),
+        max: values.iter().copied().max(),
+        trend_percent,
+    }
+}
+
+/// GET /api/projects/{id}/analytics
+/// 최근 빌드의 소요 시간, 최대 메모리, CPU 시간 추이 (빌드 이미지/리소스 제한 조정용)
+pub async fn project_build_analytics(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Query(query): Query<AnalyticsQuery>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/analytics", id);
+    let limit = query.limit.unwrap_or(DEFAULT_ANALYTICS_BUILDS).clamp(1, MAX_ANALYTICS_BUILDS);
+
+    ctx.logger.api_entry(&trace_id, "GET", &path, &format!("project_id={}, limit={}", id, limit));
+
+    match ctx.project_repo.get(id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 404);
+            return (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({"error": "Project not found"})),
+            );
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    }
+
+    let builds = match ctx.build_repo.list_by_project(id, limit).await {
+        Ok(builds) => builds,
+        Err(e) => {
+            warn!("[{}] Failed to list builds: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    };
+
+    // list_by_project는 최신순 → 추이는 오래된 순으로
+    let points: Vec<BuildUsagePoint> = builds.iter().rev().map(BuildUsagePoint::from).collect();
+    let collect = |f: fn(&BuildUsagePoint) -> Option<i64>| points.iter().filter_map(f).collect::<Vec<_>>();
+    let summary = serde_json::json!({
+        "duration_secs": summarize(&collect(|p| p.duration_secs)),
+        "peak_memory_bytes": summarize(&collect(|p| p.peak_memory_bytes)),
+        "cpu_time_ms": summarize(&collect(|p| p.cpu_time_ms)),
+    });
+
+    ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "project_id": id,
+            "summary": summary,
+            "builds": points,
+        })),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize() {
+        assert_eq!(summarize(&[]), MetricSummary::default());
+
+        let summary = summarize(&[100, 100, 150, 150]);
+        assert_eq!(summary.samples, 4);
+        assert_eq!(summary.avg, Some(125.0));
+        assert_eq!(summary.max, Some(150));
+        assert_eq!(summary.trend_percent, Some(50.0));
+
+        // 샘플이 적으면 추세 없음
+        assert_eq!(summarize(&[10, 20, 30]).trend_percent, None);
+    }
+}
@@ -0,0 +1,179 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Extension, Json,
+};
+use serde::Deserialize;
+use tracing::warn;
+
+use crate::db::models::User;
+use crate::infrastructure::database::{generate_api_token, ApiTokenScope, MAX_API_TOKENS_PER_USER};
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::state::AppContext;
+
+/// 최대 만료 기간 (일)
+const MAX_EXPIRES_IN_DAYS: u32 = 365;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateApiTokenRequest {
+    pub name: String,
+    pub scopes: Vec<ApiTokenScope>,
+    /// 없으면 만료 없음
+    #[serde(default)]
+    pub expires_in_days: Option<u32>,
+}
+
+fn unauthorized() -> (StatusCode, Json<serde_json::Value>) {
+    (StatusCode::UNAUTHORIZED, Json(serde_json::json!({"error": "Authentication required"})))
+}
+
+/// GET /api/auth/tokens - 내 API 토큰 목록 (토큰 원문은 포함하지 않음)
+pub async fn list_tokens(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    user: Option<Extension<User>>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/auth/tokens", "");
+
+    let Some(Extension(user)) = user else {
+        ctx.logger.api_exit(&trace_id, "GET", "/api/auth/tokens", timer.elapsed_ms(), 401);
+        return unauthorized();
+    };
+
+    match ctx.api_token_repo.list_by_user(user.id).await {
+        Ok(tokens) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/auth/tokens", timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({"tokens": tokens})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to list API tokens: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/api/auth/tokens", timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// POST /api/auth/tokens - API 토큰 발급 (토큰 원문은 이 응답에서만 반환)
+pub async fn create_token(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    user: Option<Extension<User>>,
+    Json(req): Json<CreateApiTokenRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/auth/tokens", &format!("name={}, scopes={:?}", req.name, req.scopes));
+
+    let Some(Extension(user)) = user else {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/auth/tokens", timer.elapsed_ms(), 401);
+        return unauthorized();
+    };
+
+    let name = req.name.trim();
+    let error = if name.is_empty() || name.len() > 64 {
+        Some("name must be 1-64 characters".to_string())
+    } else if req.scopes.is_empty() {
+        Some("scopes must not be empty (read, trigger-builds, admin)".to_string())
+    } else if req.expires_in_days.is_some_and(|d| d == 0 || d > MAX_EXPIRES_IN_DAYS) {
+        Some(format!("expires_in_days must be 1-{}", MAX_EXPIRES_IN_DAYS))
+    } else {
+        None
+    };
+    if let Some(error) = error {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/auth/tokens", timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": error})));
+    }
+
+    match ctx.api_token_repo.list_by_user(user.id).await {
+        Ok(tokens) if tokens.len() >= MAX_API_TOKENS_PER_USER => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/auth/tokens", timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": format!("Too many API tokens (max {})", MAX_API_TOKENS_PER_USER)})),
+            );
+        }
+        Ok(_) => {}
+        Err(e) => {
+            warn!("[{}] Failed to list API tokens: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", "/api/auth/tokens", timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    }
+
+    let expires_at = req.expires_in_days.map(|days| {
+        (chrono::Utc::now() + chrono::Duration::days(days as i64)).format("%Y-%m-%d %H:%M:%S").to_string()
+    });
+    let token = generate_api_token();
+
+    match ctx.api_token_repo.create(user.id, name, &token, &req.scopes, expires_at.as_deref()).await {
+        Ok(Some(api_token)) => {
+            tracing::info!(
+                target: "audit",
+                event = "auth.token_created",
+                trace_id = %trace_id,
+                token_id = api_token.id,
+                name = %api_token.name,
+                scopes = %api_token.scopes,
+                user = %user.email,
+            );
+            ctx.logger.api_exit(&trace_id, "POST", "/api/auth/tokens", timer.elapsed_ms(), 201);
+            (StatusCode::CREATED, Json(serde_json::json!({"token": token, "api_token": api_token})))
+        }
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/auth/tokens", timer.elapsed_ms(), 409);
+            (StatusCode::CONFLICT, Json(serde_json::json!({"error": format!("API token '{}' already exists", name)})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to create API token: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", "/api/auth/tokens", timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// DELETE /api/auth/tokens/{id} - API 토큰 폐기
+pub async fn delete_token(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    user: Option<Extension<User>>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/auth/tokens/{}", id);
+
+    ctx.logger.api_entry(&trace_id, "DELETE", &path, "");
+
+    let Some(Extension(user)) = user else {
+        ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 401);
+        return unauthorized();
+    };
+
+    match ctx.api_token_repo.delete(user.id, id).await {
+        Ok(true) => {
+            tracing::info!(
+                target: "audit",
+                event = "auth.token_deleted",
+                trace_id = %trace_id,
+                token_id = id,
+                user = %user.email,
+            );
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({"success": true})))
+        }
+        Ok(false) => {
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 404);
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "API token not found"})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to delete API token: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
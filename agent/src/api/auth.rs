@@ -3,20 +3,22 @@ use axum::{
     http::{HeaderMap, StatusCode},
     response::{IntoResponse, Redirect, Response},
     routing::{get, post},
-    Json, Router,
+    Extension, Json, Router,
 };
 use oauth2::{
     reqwest::async_http_client, AuthorizationCode, CsrfToken, PkceCodeChallenge,
     PkceCodeVerifier, Scope, TokenResponse,
 };
 use serde::{Deserialize, Serialize};
+use std::time::Instant;
 use tower_cookies::{Cookie, Cookies};
 use tracing::{info, warn, error};
 
-use crate::db::models::CreateUser;
+use crate::db::models::{CreateGitHubUser, CreateUser, User, UserRole};
 use crate::infrastructure::logging::{TraceContext, Timer};
-use crate::state::AppContext;
+use crate::state::{AppContext, ClientIp, LoginKey};
 use crate::application::ports::repositories::{SessionRepository, UserRepository};
+use super::middleware::login_guard::record_login_failure;
 use super::settings::is_email_allowed;
 
 // Session cookie name
@@ -31,6 +33,8 @@ pub fn auth_routes() -> Router<AppContext> {
     Router::new()
         .route("/google", get(google_login))
         .route("/google/callback", get(google_callback))
+        .route("/github", get(github_login))
+        .route("/github/callback", get(github_callback))
         .route("/logout", post(logout))
         .route("/me", get(get_current_user))
 }
@@ -68,23 +72,7 @@ async fn google_login(
         .set_pkce_challenge(pkce_challenge)
         .url();
 
-    // Store PKCE verifier in HTTP-only cookie (expires in 10 minutes)
-    let mut pkce_cookie = Cookie::new(PKCE_COOKIE, pkce_verifier.secret().clone());
-    pkce_cookie.set_path("/");
-    pkce_cookie.set_http_only(true);
-    pkce_cookie.set_secure(true);
-    pkce_cookie.set_same_site(tower_cookies::cookie::SameSite::Lax);
-    pkce_cookie.set_max_age(tower_cookies::cookie::time::Duration::minutes(10));
-    cookies.add(pkce_cookie);
-
-    // Store CSRF state in HTTP-only cookie
-    let mut csrf_cookie = Cookie::new(CSRF_COOKIE, csrf_token.secret().clone());
-    csrf_cookie.set_path("/");
-    csrf_cookie.set_http_only(true);
-    csrf_cookie.set_secure(true);
-    csrf_cookie.set_same_site(tower_cookies::cookie::SameSite::Lax);
-    csrf_cookie.set_max_age(tower_cookies::cookie::time::Duration::minutes(10));
-    cookies.add(csrf_cookie);
+    set_oauth_cookies(&cookies, &pkce_verifier, &csrf_token);
 
     info!("[{}] Redirecting to Google OAuth", trace_id);
     ctx.logger.api_exit(&trace_id, "GET", "/auth/google", 0.0, 302);
@@ -92,6 +80,71 @@ async fn google_login(
     Redirect::temporary(auth_url.as_str()).into_response()
 }
 
+/// PKCE verifier/CSRF state를 HTTP-only 쿠키에 저장 (10분)
+fn set_oauth_cookies(cookies: &Cookies, pkce_verifier: &PkceCodeVerifier, csrf_token: &CsrfToken) {
+    for (name, value) in [(PKCE_COOKIE, pkce_verifier.secret()), (CSRF_COOKIE, csrf_token.secret())] {
+        let mut cookie = Cookie::new(name, value.clone());
+        cookie.set_path("/");
+        cookie.set_http_only(true);
+        cookie.set_secure(true);
+        cookie.set_same_site(tower_cookies::cookie::SameSite::Lax);
+        cookie.set_max_age(tower_cookies::cookie::time::Duration::minutes(10));
+        cookies.add(cookie);
+    }
+}
+
+/// 콜백의 state를 CSRF 쿠키와 비교하고 PKCE verifier 반환. 실패하면 에러 코드
+fn verify_oauth_state(cookies: &Cookies, state: &str) -> Result<PkceCodeVerifier, &'static str> {
+    let stored_csrf = cookies.get(CSRF_COOKIE).ok_or("missing_csrf")?;
+    if stored_csrf.value() != state {
+        return Err("invalid_csrf");
+    }
+    cookies
+        .get(PKCE_COOKIE)
+        .map(|c| PkceCodeVerifier::new(c.value().to_string()))
+        .ok_or("missing_pkce")
+}
+
+/// 세션 생성 후 임시 OAuth 쿠키 삭제, 세션 쿠키 설정 (7일, HTTP-only, Secure)
+async fn start_session(ctx: &AppContext, cookies: &Cookies, user_id: i64) -> anyhow::Result<()> {
+    let session_id = uuid::Uuid::new_v4().to_string();
+    let expires_at = chrono::Utc::now()
+        .checked_add_signed(chrono::Duration::days(7))
+        .unwrap()
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string();
+
+    let create_session = crate::db::models::CreateSession {
+        id: session_id.clone(),
+        user_id,
+        expires_at,
+    };
+    ctx.session_repo.create(create_session).await?;
+
+    // Clear temporary cookies
+    for name in [PKCE_COOKIE, CSRF_COOKIE] {
+        let mut removal = Cookie::new(name, "");
+        removal.set_path("/");
+        removal.set_max_age(tower_cookies::cookie::time::Duration::seconds(0));
+        cookies.add(removal);
+    }
+
+    let mut session_cookie = Cookie::new(SESSION_COOKIE, session_id);
+    session_cookie.set_path("/");
+    session_cookie.set_http_only(true);
+    session_cookie.set_secure(true);
+    session_cookie.set_same_site(tower_cookies::cookie::SameSite::Lax);
+    session_cookie.set_max_age(tower_cookies::cookie::time::Duration::days(7));
+    cookies.add(session_cookie);
+    Ok(())
+}
+
+/// 현재 세션 쿠키의 사용자 (없거나 만료되면 None)
+async fn session_user(ctx: &AppContext, cookies: &Cookies) -> Option<User> {
+    let session_id = cookies.get(SESSION_COOKIE)?.value().to_string();
+    ctx.session_repo.get_with_user(&session_id).await.ok().flatten().map(|(_, user)| user)
+}
+
 #[derive(Deserialize)]
 pub struct CallbackQuery {
     code: String,
@@ -111,12 +164,21 @@ async fn google_callback(
     State(ctx): State<AppContext>,
     cookies: Cookies,
     headers: HeaderMap,
+    client_ip: Option<Extension<ClientIp>>,
     Query(query): Query<CallbackQuery>,
 ) -> Response {
     let trace_id = TraceContext::extract_or_generate(&headers);
     let timer = Timer::start();
     ctx.logger.api_entry(&trace_id, "GET", "/auth/google/callback", "");
 
+    // 위조/재전송된 콜백과 허용되지 않은 계정은 IP별 실패로 기록 (limit_login_attempts가 잠금 적용)
+    let client_ip = client_ip.map(|Extension(ClientIp(ip))| ip);
+    let record_ip_failure = |reason: &str| {
+        if let Some(ip) = client_ip {
+            record_login_failure(&ctx, LoginKey::Ip(ip), reason);
+        }
+    };
+
     let oauth_config = match &ctx.oauth_config {
         Some(cfg) => cfg,
         None => {
@@ -128,29 +190,14 @@ async fn google_callback(
         }
     };
 
-    // Verify CSRF state
-    let stored_csrf = match cookies.get(CSRF_COOKIE) {
-        Some(c) => c.value().to_string(),
-        None => {
-            warn!("[{}] Missing CSRF state cookie", trace_id);
+    // Verify CSRF state, get PKCE verifier
+    let pkce_verifier = match verify_oauth_state(&cookies, &query.state) {
+        Ok(verifier) => verifier,
+        Err(reason) => {
+            warn!("[{}] OAuth state verification failed: {}", trace_id, reason);
+            record_ip_failure(reason);
             ctx.logger.api_exit(&trace_id, "GET", "/auth/google/callback", timer.elapsed_ms(), 400);
-            return Redirect::temporary("/login?error=missing_csrf").into_response();
-        }
-    };
-
-    if stored_csrf != query.state {
-        warn!("[{}] Invalid CSRF state", trace_id);
-        ctx.logger.api_exit(&trace_id, "GET", "/auth/google/callback", timer.elapsed_ms(), 400);
-        return Redirect::temporary("/login?error=invalid_csrf").into_response();
-    }
-
-    // Get PKCE verifier
-    let pkce_verifier = match cookies.get(PKCE_COOKIE) {
-        Some(c) => PkceCodeVerifier::new(c.value().to_string()),
-        None => {
-            warn!("[{}] Missing PKCE verifier cookie", trace_id);
-            ctx.logger.api_exit(&trace_id, "GET", "/auth/google/callback", timer.elapsed_ms(), 400);
-            return Redirect::temporary("/login?error=missing_pkce").into_response();
+            return Redirect::temporary(&format!("/login?error={}", reason)).into_response();
         }
     };
 
@@ -166,6 +213,7 @@ async fn google_callback(
         Ok(t) => t,
         Err(e) => {
             error!("[{}] Token exchange failed: {:?}", trace_id, e);
+            record_ip_failure("token_exchange_failed");
             ctx.logger.api_exit(&trace_id, "GET", "/auth/google/callback", timer.elapsed_ms(), 500);
             return Redirect::temporary("/login?error=token_exchange_failed").into_response();
         }
@@ -181,6 +229,15 @@ async fn google_callback(
         }
     };
 
+    // 실패가 반복된 계정은 잠금 시간 동안 로그인 거부
+    let email_key = LoginKey::email(&user_info.email);
+    if let Some(remaining) = ctx.login_guard.locked_for(&email_key, Instant::now()) {
+        warn!("[{}] Login for {} is locked ({}s remaining)", trace_id, user_info.email, remaining.as_secs());
+        record_ip_failure("locked");
+        ctx.logger.api_exit(&trace_id, "GET", "/auth/google/callback", timer.elapsed_ms(), 429);
+        return Redirect::temporary("/login?error=too_many_attempts").into_response();
+    }
+
     // Check email whitelist
     if !is_email_allowed(&ctx, &user_info.email).await {
         warn!("[{}] Email not in whitelist: {}", trace_id, user_info.email);
@@ -190,6 +247,8 @@ async fn google_callback(
             email = %user_info.email,
             reason = "not_in_whitelist",
         );
+        record_ip_failure("not_in_whitelist");
+        record_login_failure(&ctx, email_key, "not_in_whitelist");
         ctx.logger.api_exit(&trace_id, "GET", "/auth/google/callback", timer.elapsed_ms(), 403);
         return Redirect::temporary("/login?error=not_allowed").into_response();
     }
@@ -212,44 +271,14 @@ async fn google_callback(
     };
 
     // Create session
-    let session_id = uuid::Uuid::new_v4().to_string();
-    let expires_at = chrono::Utc::now()
-        .checked_add_signed(chrono::Duration::days(7))
-        .unwrap()
-        .format("%Y-%m-%d %H:%M:%S")
-        .to_string();
-
-    let create_session = crate::db::models::CreateSession {
-        id: session_id.clone(),
-        user_id: user.id,
-        expires_at,
-    };
-
-    if let Err(e) = ctx.session_repo.create(create_session).await {
+    if let Err(e) = start_session(&ctx, &cookies, user.id).await {
         error!("[{}] Failed to create session: {}", trace_id, e);
         ctx.logger.api_exit(&trace_id, "GET", "/auth/google/callback", timer.elapsed_ms(), 500);
         return Redirect::temporary("/login?error=session_error").into_response();
     }
 
-    // Clear temporary cookies
-    let mut remove_pkce = Cookie::new(PKCE_COOKIE, "");
-    remove_pkce.set_path("/");
-    remove_pkce.set_max_age(tower_cookies::cookie::time::Duration::seconds(0));
-    cookies.add(remove_pkce);
-
-    let mut remove_csrf = Cookie::new(CSRF_COOKIE, "");
-    remove_csrf.set_path("/");
-    remove_csrf.set_max_age(tower_cookies::cookie::time::Duration::seconds(0));
-    cookies.add(remove_csrf);
-
-    // Set session cookie (7 days, HTTP-only, Secure)
-    let mut session_cookie = Cookie::new(SESSION_COOKIE, session_id);
-    session_cookie.set_path("/");
-    session_cookie.set_http_only(true);
-    session_cookie.set_secure(true);
-    session_cookie.set_same_site(tower_cookies::cookie::SameSite::Lax);
-    session_cookie.set_max_age(tower_cookies::cookie::time::Duration::days(7));
-    cookies.add(session_cookie);
+    // IP 실패 기록은 유지 (허용된 계정 하나로 다른 계정 시도 횟수를 초기화할 수 없도록)
+    ctx.login_guard.record_success(&email_key);
 
     info!("[{}] User {} logged in successfully", trace_id, user_info.email);
     tracing::info!(
@@ -284,6 +313,250 @@ async fn fetch_google_user_info(access_token: &str) -> Result<GoogleUserInfo, St
         .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
+/// GET /auth/github - Start GitHub OAuth flow
+///
+/// repo 범위를 요청해 로그인한 사용자의 토큰으로 저장소 목록을 조회할 수 있게 함
+async fn github_login(
+    State(ctx): State<AppContext>,
+    cookies: Cookies,
+    headers: HeaderMap,
+) -> Response {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    ctx.logger.api_entry(&trace_id, "GET", "/auth/github", "");
+
+    let Some(oauth_config) = &ctx.github_oauth_config else {
+        error!("[{}] GitHub OAuth not configured", trace_id);
+        return (StatusCode::INTERNAL_SERVER_ERROR, "GitHub OAuth not configured").into_response();
+    };
+
+    let (pkce_challenge, pkce_verifier) = PkceCodeChallenge::new_random_sha256();
+    let (auth_url, csrf_token) = oauth_config
+        .client
+        .authorize_url(CsrfToken::new_random)
+        .add_scope(Scope::new("read:user".to_string()))
+        .add_scope(Scope::new("user:email".to_string()))
+        .add_scope(Scope::new("repo".to_string()))
+        .set_pkce_challenge(pkce_challenge)
+        .url();
+
+    set_oauth_cookies(&cookies, &pkce_verifier, &csrf_token);
+
+    info!("[{}] Redirecting to GitHub OAuth", trace_id);
+    ctx.logger.api_exit(&trace_id, "GET", "/auth/github", 0.0, 302);
+
+    Redirect::temporary(auth_url.as_str()).into_response()
+}
+
+#[derive(Deserialize)]
+struct GitHubUserInfo {
+    id: i64,
+    login: String,
+    name: Option<String>,
+    avatar_url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GitHubEmail {
+    email: String,
+    primary: bool,
+    verified: bool,
+}
+
+/// GET /auth/github/callback - GitHub OAuth callback handler
+///
+/// 연결 대상: GitHub ID로 연결된 사용자 → 로그인 중인 사용자 → 같은 이메일 사용자 → 새 사용자
+async fn github_callback(
+    State(ctx): State<AppContext>,
+    cookies: Cookies,
+    headers: HeaderMap,
+    client_ip: Option<Extension<ClientIp>>,
+    Query(query): Query<CallbackQuery>,
+) -> Response {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    ctx.logger.api_entry(&trace_id, "GET", "/auth/github/callback", "");
+
+    let client_ip = client_ip.map(|Extension(ClientIp(ip))| ip);
+    let record_ip_failure = |reason: &str| {
+        if let Some(ip) = client_ip {
+            record_login_failure(&ctx, LoginKey::Ip(ip), reason);
+        }
+    };
+    let fail = |status: u16, error: &str| {
+        ctx.logger.api_exit(&trace_id, "GET", "/auth/github/callback", timer.elapsed_ms(), status);
+        Redirect::temporary(&format!("/login?error={}", error)).into_response()
+    };
+
+    let Some(oauth_config) = &ctx.github_oauth_config else {
+        error!("[{}] GitHub OAuth not configured", trace_id);
+        return (StatusCode::INTERNAL_SERVER_ERROR, "GitHub OAuth not configured").into_response();
+    };
+
+    let pkce_verifier = match verify_oauth_state(&cookies, &query.state) {
+        Ok(verifier) => verifier,
+        Err(reason) => {
+            warn!("[{}] OAuth state verification failed: {}", trace_id, reason);
+            record_ip_failure(reason);
+            return fail(400, reason);
+        }
+    };
+
+    let token = match oauth_config
+        .client
+        .exchange_code(AuthorizationCode::new(query.code))
+        .set_pkce_verifier(pkce_verifier)
+        .request_async(async_http_client)
+        .await
+    {
+        Ok(t) => t,
+        Err(e) => {
+            error!("[{}] GitHub token exchange failed: {:?}", trace_id, e);
+            record_ip_failure("token_exchange_failed");
+            return fail(500, "token_exchange_failed");
+        }
+    };
+
+    let github_user = match fetch_github_user(token.access_token().secret()).await {
+        Ok(info) => info,
+        Err(e) => {
+            error!("[{}] Failed to fetch GitHub user: {}", trace_id, e);
+            return fail(500, "user_info_failed");
+        }
+    };
+
+    let email_key = LoginKey::email(&github_user.email);
+    if let Some(remaining) = ctx.login_guard.locked_for(&email_key, Instant::now()) {
+        warn!("[{}] Login for {} is locked ({}s remaining)", trace_id, github_user.email, remaining.as_secs());
+        record_ip_failure("locked");
+        return fail(429, "too_many_attempts");
+    }
+
+    let current_user = session_user(&ctx, &cookies).await;
+    let linked_user = match ctx.user_repo.get_by_github_id(github_user.github_id).await {
+        Ok(u) => u,
+        Err(e) => {
+            error!("[{}] Failed to look up GitHub user: {}", trace_id, e);
+            return fail(500, "database_error");
+        }
+    };
+
+    // 다른 사용자에 이미 연결된 GitHub 계정은 로그인 중인 사용자에 옮기지 않음
+    if let (Some(current), Some(linked)) = (&current_user, &linked_user) {
+        if current.id != linked.id {
+            warn!("[{}] GitHub account {} is already linked to {}", trace_id, github_user.github_login, linked.email);
+            return fail(409, "github_already_linked");
+        }
+    }
+
+    let target = match linked_user.or(current_user) {
+        Some(user) => Some(user),
+        None => match ctx.user_repo.get_by_email(&github_user.email).await {
+            Ok(u) => u,
+            Err(e) => {
+                error!("[{}] Failed to look up user by email: {}", trace_id, e);
+                return fail(500, "database_error");
+            }
+        },
+    };
+
+    // 허용 목록은 연결될 계정의 이메일 기준
+    let email = target.as_ref().map_or(github_user.email.as_str(), |u| u.email.as_str()).to_string();
+    if !is_email_allowed(&ctx, &email).await {
+        warn!("[{}] Email not in whitelist: {}", trace_id, email);
+        tracing::warn!(
+            target: "audit",
+            event = "user.login_denied",
+            email = %email,
+            github_login = %github_user.github_login,
+            reason = "not_in_whitelist",
+        );
+        record_ip_failure("not_in_whitelist");
+        record_login_failure(&ctx, email_key, "not_in_whitelist");
+        return fail(403, "not_allowed");
+    }
+
+    let newly_linked = target.as_ref().is_none_or(|u| u.github_id != Some(github_user.github_id));
+    let result = match &target {
+        Some(user) => ctx.user_repo.link_github(user.id, &github_user).await,
+        None => ctx.user_repo.create_from_github(&github_user).await,
+    };
+    let user = match result {
+        Ok(u) => u,
+        Err(e) => {
+            error!("[{}] Failed to save GitHub user: {}", trace_id, e);
+            return fail(500, "database_error");
+        }
+    };
+
+    if let Err(e) = start_session(&ctx, &cookies, user.id).await {
+        error!("[{}] Failed to create session: {}", trace_id, e);
+        return fail(500, "session_error");
+    }
+
+    ctx.login_guard.record_success(&email_key);
+
+    if newly_linked {
+        tracing::info!(
+            target: "audit",
+            event = "user.github_linked",
+            email = %user.email,
+            user_id = user.id,
+            github_login = %github_user.github_login,
+        );
+    }
+    info!("[{}] User {} logged in with GitHub ({})", trace_id, user.email, github_user.github_login);
+    tracing::info!(
+        target: "audit",
+        event = "user.login",
+        email = %user.email,
+        user_id = user.id,
+        provider = "github",
+        result = "success",
+    );
+    ctx.logger.api_exit(&trace_id, "GET", "/auth/github/callback", timer.elapsed_ms(), 302);
+
+    Redirect::temporary("/").into_response()
+}
+
+/// GitHub 사용자 정보 + 인증된 기본 이메일
+async fn fetch_github_user(access_token: &str) -> Result<CreateGitHubUser, String> {
+    let client = reqwest::Client::new();
+    let get = |url: &'static str| {
+        client
+            .get(url)
+            .bearer_auth(access_token)
+            .header("User-Agent", "EasyCI CD")
+            .header("Accept", "application/vnd.github+json")
+            .send()
+    };
+
+    let response = get("https://api.github.com/user").await.map_err(|e| format!("Request failed: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("GitHub API error: {}", response.status()));
+    }
+    let info: GitHubUserInfo = response.json().await.map_err(|e| format!("Failed to parse response: {}", e))?;
+
+    let response = get("https://api.github.com/user/emails").await.map_err(|e| format!("Request failed: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("GitHub API error: {}", response.status()));
+    }
+    let emails: Vec<GitHubEmail> = response.json().await.map_err(|e| format!("Failed to parse response: {}", e))?;
+    let email = emails
+        .into_iter()
+        .find(|e| e.primary && e.verified)
+        .map(|e| e.email)
+        .ok_or("No verified primary email on GitHub account")?;
+
+    Ok(CreateGitHubUser {
+        github_id: info.id,
+        name: info.name.filter(|n| !n.trim().is_empty()).unwrap_or_else(|| info.login.clone()),
+        github_login: info.login,
+        github_token: access_token.to_string(),
+        email,
+        picture: info.avatar_url,
+    })
+}
+
 /// POST /auth/logout - Logout
 async fn logout(
     State(ctx): State<AppContext>,
@@ -330,6 +603,14 @@ async fn logout(
 struct CurrentUserResponse {
     authenticated: bool,
     user: Option<UserInfo>,
+    providers: AuthProviders,
+}
+
+/// 설정된 로그인 방식 (로그인 화면 버튼 표시용)
+#[derive(Serialize)]
+struct AuthProviders {
+    google: bool,
+    github: bool,
 }
 
 #[derive(Serialize)]
@@ -338,6 +619,8 @@ struct UserInfo {
     email: String,
     name: String,
     picture: Option<String>,
+    role: UserRole,
+    github_login: Option<String>,
 }
 
 /// GET /auth/me - Get current user
@@ -350,6 +633,11 @@ async fn get_current_user(
     let timer = Timer::start();
     ctx.logger.api_entry(&trace_id, "GET", "/auth/me", "");
 
+    let providers = || AuthProviders {
+        google: ctx.oauth_config.is_some(),
+        github: ctx.github_oauth_config.is_some(),
+    };
+
     let session_cookie = match cookies.get(SESSION_COOKIE) {
         Some(c) => c,
         None => {
@@ -359,6 +647,7 @@ async fn get_current_user(
                 Json(CurrentUserResponse {
                     authenticated: false,
                     user: None,
+                    providers: providers(),
                 }),
             );
         }
@@ -373,11 +662,14 @@ async fn get_current_user(
                 StatusCode::OK,
                 Json(CurrentUserResponse {
                     authenticated: true,
+                    providers: providers(),
                     user: Some(UserInfo {
                         id: user.id,
                         email: user.email,
                         name: user.name,
                         picture: user.picture,
+                        role: user.role,
+                        github_login: user.github_login,
                     }),
                 }),
             )
@@ -389,6 +681,7 @@ async fn get_current_user(
                 Json(CurrentUserResponse {
                     authenticated: false,
                     user: None,
+                    providers: providers(),
                 }),
             )
         }
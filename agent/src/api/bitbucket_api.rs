@@ -0,0 +1,121 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use tracing::warn;
+
+use crate::application::ports::repositories::SettingsRepository;
+use crate::application::services::BITBUCKET_TOKEN_SETTING;
+use crate::bitbucket::BitbucketClient;
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::state::AppContext;
+
+#[derive(Debug, Deserialize)]
+pub struct SetBitbucketTokenRequest {
+    /// repository/workspace access token (repository, webhook 권한)
+    pub token: String,
+}
+
+/// Set Bitbucket Cloud access token (global, validated with GET /repositories)
+pub async fn set_bitbucket_token(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(payload): Json<SetBitbucketTokenRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/settings/bitbucket-token", "set_token");
+
+    if let Err(e) = BitbucketClient::new(payload.token.clone()).check_token().await {
+        warn!("[{}] Invalid Bitbucket token: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/bitbucket-token", timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": format!("Invalid Bitbucket token: {}", e)})),
+        );
+    }
+
+    if let Err(e) = ctx.settings_repo.set(BITBUCKET_TOKEN_SETTING, &payload.token).await {
+        warn!("[{}] Failed to save Bitbucket token: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/bitbucket-token", timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Failed to save Bitbucket token: {}", e)})),
+        );
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.bitbucket_token_set",
+        trace_id = %trace_id,
+    );
+
+    ctx.logger.api_exit(&trace_id, "POST", "/api/settings/bitbucket-token", timer.elapsed_ms(), 200);
+    (StatusCode::OK, Json(serde_json::json!({"success": true})))
+}
+
+/// Get Bitbucket token status
+pub async fn get_bitbucket_token_status(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/settings/bitbucket-token", "");
+
+    let token = match ctx.settings_repo.get(BITBUCKET_TOKEN_SETTING).await {
+        Ok(Some(token)) => token,
+        _ => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/bitbucket-token", timer.elapsed_ms(), 200);
+            return (StatusCode::OK, Json(serde_json::json!({"configured": false})));
+        }
+    };
+
+    let body = match BitbucketClient::new(token).check_token().await {
+        Ok(()) => serde_json::json!({"configured": true}),
+        Err(e) => {
+            warn!("[{}] Bitbucket token validation failed: {}", trace_id, e);
+            serde_json::json!({
+                "configured": false,
+                "error": "Bitbucket token is invalid or expired"
+            })
+        }
+    };
+
+    ctx.logger.api_exit(&trace_id, "GET", "/api/settings/bitbucket-token", timer.elapsed_ms(), 200);
+    (StatusCode::OK, Json(body))
+}
+
+/// Delete Bitbucket token
+pub async fn delete_bitbucket_token(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "DELETE", "/api/settings/bitbucket-token", "");
+
+    if let Err(e) = ctx.settings_repo.delete(BITBUCKET_TOKEN_SETTING).await {
+        warn!("[{}] Failed to delete Bitbucket token: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "DELETE", "/api/settings/bitbucket-token", timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Failed to delete Bitbucket token: {}", e)})),
+        );
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.bitbucket_token_deleted",
+        trace_id = %trace_id,
+    );
+
+    ctx.logger.api_exit(&trace_id, "DELETE", "/api/settings/bitbucket-token", timer.elapsed_ms(), 200);
+    (StatusCode::OK, Json(serde_json::json!({"success": true})))
+}
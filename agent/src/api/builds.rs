@@ -1,30 +1,90 @@
 use axum::{
-    extract::{Path, Query, State},
+    extract::{ws::{Message, WebSocket, WebSocketUpgrade}, Path, Query, State},
     http::{HeaderMap, StatusCode},
-    response::IntoResponse,
-    routing::get,
-    Json, Router,
+    response::{IntoResponse, Response},
+    routing::{get, post, put},
+    Extension, Json, Router,
 };
-use serde::Deserialize;
-use tracing::warn;
+use futures_util::{SinkExt, StreamExt};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{hash_map::Entry, HashMap};
+use std::io::SeekFrom;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio::time::{interval, Duration};
+use tracing::{info, warn};
 
-use crate::state::AppContext;
+use crate::state::{AppContext, DeploymentOperation};
 use crate::infrastructure::logging::{TraceContext, Timer};
-use crate::application::ports::repositories::BuildRepository;
+use crate::application::ports::repositories::{BuildRepository, ProjectRepository};
+use crate::application::services::build_estimate::{self, HISTORY_LOOKBACK};
+use crate::application::services::{check_build_allowed, estimate_build, next_deploy_window, BuildEstimate};
+use crate::build::release_held_build;
+use crate::application::services::log_levels::LogClassifier;
+use crate::db::models::{normalize_build_labels, Build, BuildStatus, CreateBuild, LogLevel, User, MAX_BUILD_NOTE_LEN};
+use super::middleware::ApiTokenAuth;
+use super::projects::{build_rejection_response, deployment_conflict, manual_trigger};
 
 pub fn builds_routes() -> Router<AppContext> {
     Router::new()
         .route("/", get(list_builds))
+        .route("/held", get(list_held_builds))
         .route("/{id}", get(get_build))
         .route("/{id}/logs", get(get_build_logs))
         .route("/{id}/build-logs", get(get_build_logs_only))
         .route("/{id}/deploy-logs", get(get_deploy_logs))
+        .route("/{id}/deploy-logs/stream", get(deploy_logs_stream))
+        .route("/{id}/tests", get(get_build_tests))
+        .route("/{id}/stages", get(get_build_stages))
+        .route("/{id}/environment", get(get_build_environment))
+        .route("/{id}/log-shipment", get(get_build_log_shipment))
+        .route("/{id}/rebuild-exact", post(rebuild_exact))
+        .route("/{id}/release", post(release_build))
+        .route("/{id}/annotation", put(update_build_annotation))
+}
+
+/// 빌드 + 실행 중이면 예상 소요 시간/진행률
+#[derive(Serialize)]
+struct BuildResponse {
+    #[serde(flatten)]
+    build: Build,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    estimate: Option<BuildEstimate>,
+}
+
+/// 실행 중 빌드에 예상치 추가 (같은 프로젝트 이력은 한 번만 조회)
+async fn with_estimates(ctx: &AppContext, trace_id: &str, builds: Vec<Build>) -> Vec<BuildResponse> {
+    let now = Utc::now();
+    let mut histories: HashMap<i64, Vec<Build>> = HashMap::new();
+    let mut responses = Vec::with_capacity(builds.len());
+
+    for build in builds {
+        let mut estimate = None;
+        if build.status == BuildStatus::Building {
+            if let Entry::Vacant(entry) = histories.entry(build.project_id) {
+                match ctx.build_repo.list_by_project(build.project_id, HISTORY_LOOKBACK).await {
+                    Ok(history) => {
+                        entry.insert(history);
+                    }
+                    Err(e) => warn!("[{}] Failed to load build history for project {}: {}", trace_id, build.project_id, e),
+                }
+            }
+            estimate = histories
+                .get(&build.project_id)
+                .and_then(|history| build_estimate::estimate(&build, history, now));
+        }
+        responses.push(BuildResponse { build, estimate });
+    }
+
+    responses
 }
 
 #[derive(Deserialize)]
 struct ListBuildsQuery {
     project_id: Option<i64>,
     limit: Option<i64>,
+    /// 메모/라벨/커밋 메시지 검색어
+    q: Option<String>,
 }
 
 async fn list_builds(
@@ -38,7 +98,10 @@ async fn list_builds(
 
     ctx.logger.api_entry(&trace_id, "GET", "/api/builds", &format!("limit={}", limit));
 
-    let builds = if let Some(project_id) = params.project_id {
+    let query = params.q.as_deref().map(str::trim).filter(|q| !q.is_empty());
+    let builds = if let Some(query) = query {
+        ctx.build_repo.search(params.project_id, query, limit).await
+    } else if let Some(project_id) = params.project_id {
         ctx.build_repo.list_by_project(project_id, limit).await
     } else {
         ctx.build_repo.list_recent(limit).await
@@ -46,6 +109,7 @@ async fn list_builds(
 
     match builds {
         Ok(builds) => {
+            let builds = with_estimates(&ctx, &trace_id, builds).await;
             ctx.logger.api_exit(&trace_id, "GET", "/api/builds", timer.elapsed_ms(), 200);
             (StatusCode::OK, Json(builds))
         }
@@ -69,8 +133,15 @@ async fn get_build(
 
     match ctx.build_repo.get(id).await {
         Ok(Some(build)) => {
+            let estimate = match estimate_build(ctx.build_repo.as_ref(), &build).await {
+                Ok(estimate) => estimate,
+                Err(e) => {
+                    warn!("[{}] Failed to estimate build {}: {}", trace_id, id, e);
+                    None
+                }
+            };
             ctx.logger.api_exit(&trace_id, "GET", &format!("/api/builds/{}", id), timer.elapsed_ms(), 200);
-            (StatusCode::OK, Json(Some(build)))
+            (StatusCode::OK, Json(Some(BuildResponse { build, estimate })))
         }
         Ok(None) => {
             ctx.logger.api_exit(&trace_id, "GET", &format!("/api/builds/{}", id), timer.elapsed_ms(), 404);
@@ -84,10 +155,38 @@ async fn get_build(
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct BuildLogQuery {
+    /// 이 심각도 이상인 줄만 JSON으로 반환 (error / warn / info)
+    level: Option<LogLevel>,
+}
+
+/// `?level=` 응답: 현재 패턴으로 분류해 기준 이상인 줄과 번호 (Log 이벤트와 같은 0부터)
+fn leveled_log(ctx: &AppContext, build: &Build, content: &str, min_level: LogLevel) -> serde_json::Value {
+    let lines = leveled_lines(&ctx.log_levels.classifier(), content, min_level);
+    serde_json::json!({
+        "level": min_level,
+        "counts": build.parsed_log_levels(),
+        "lines": lines,
+    })
+}
+
+fn leveled_lines(classifier: &LogClassifier, content: &str, min_level: LogLevel) -> Vec<serde_json::Value> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(line_number, line)| {
+            let level = classifier.classify(line);
+            (level >= min_level).then(|| serde_json::json!({"line_number": line_number, "level": level, "line": line}))
+        })
+        .collect()
+}
+
 async fn get_build_logs(
     State(ctx): State<AppContext>,
     headers: HeaderMap,
     Path(id): Path<i64>,
+    Query(query): Query<BuildLogQuery>,
 ) -> impl IntoResponse {
     let trace_id = TraceContext::extract_or_generate(&headers);
     let timer = Timer::start();
@@ -100,22 +199,25 @@ async fn get_build_logs(
             match tokio::fs::read_to_string(&build.log_path).await {
                 Ok(content) => {
                     ctx.logger.api_exit(&trace_id, "GET", &format!("/api/builds/{}/logs", id), timer.elapsed_ms(), 200);
-                    (StatusCode::OK, content)
+                    match query.level {
+                        Some(level) => (StatusCode::OK, Json(leveled_log(&ctx, &build, &content, level))).into_response(),
+                        None => (StatusCode::OK, content).into_response(),
+                    }
                 }
                 Err(_) => {
                     ctx.logger.api_exit(&trace_id, "GET", &format!("/api/builds/{}/logs", id), timer.elapsed_ms(), 404);
-                    (StatusCode::NOT_FOUND, String::from("Log file not found"))
+                    (StatusCode::NOT_FOUND, String::from("Log file not found")).into_response()
                 }
             }
         }
         Ok(None) => {
             ctx.logger.api_exit(&trace_id, "GET", &format!("/api/builds/{}/logs", id), timer.elapsed_ms(), 404);
-            (StatusCode::NOT_FOUND, String::from("Build not found"))
+            (StatusCode::NOT_FOUND, String::from("Build not found")).into_response()
         }
         Err(e) => {
             warn!("[{}] Failed to get build logs: {}", trace_id, e);
             ctx.logger.api_exit(&trace_id, "GET", &format!("/api/builds/{}/logs", id), timer.elapsed_ms(), 500);
-            (StatusCode::INTERNAL_SERVER_ERROR, format!("Error: {}", e))
+            (StatusCode::INTERNAL_SERVER_ERROR, format!("Error: {}", e)).into_response()
         }
     }
 }
@@ -124,6 +226,7 @@ async fn get_build_logs_only(
     State(ctx): State<AppContext>,
     headers: HeaderMap,
     Path(id): Path<i64>,
+    Query(query): Query<BuildLogQuery>,
 ) -> impl IntoResponse {
     let trace_id = TraceContext::extract_or_generate(&headers);
     let timer = Timer::start();
@@ -136,22 +239,25 @@ async fn get_build_logs_only(
             match tokio::fs::read_to_string(&build.log_path).await {
                 Ok(content) => {
                     ctx.logger.api_exit(&trace_id, "GET", &format!("/api/builds/{}/build-logs", id), timer.elapsed_ms(), 200);
-                    (StatusCode::OK, content)
+                    match query.level {
+                        Some(level) => (StatusCode::OK, Json(leveled_log(&ctx, &build, &content, level))).into_response(),
+                        None => (StatusCode::OK, content).into_response(),
+                    }
                 }
                 Err(_) => {
                     ctx.logger.api_exit(&trace_id, "GET", &format!("/api/builds/{}/build-logs", id), timer.elapsed_ms(), 404);
-                    (StatusCode::NOT_FOUND, String::from("Build log file not found"))
+                    (StatusCode::NOT_FOUND, String::from("Build log file not found")).into_response()
                 }
             }
         }
         Ok(None) => {
             ctx.logger.api_exit(&trace_id, "GET", &format!("/api/builds/{}/build-logs", id), timer.elapsed_ms(), 404);
-            (StatusCode::NOT_FOUND, String::from("Build not found"))
+            (StatusCode::NOT_FOUND, String::from("Build not found")).into_response()
         }
         Err(e) => {
             warn!("[{}] Failed to get build logs: {}", trace_id, e);
             ctx.logger.api_exit(&trace_id, "GET", &format!("/api/builds/{}/build-logs", id), timer.elapsed_ms(), 500);
-            (StatusCode::INTERNAL_SERVER_ERROR, format!("Error: {}", e))
+            (StatusCode::INTERNAL_SERVER_ERROR, format!("Error: {}", e)).into_response()
         }
     }
 }
@@ -196,3 +302,642 @@ async fn get_deploy_logs(
         }
     }
 }
+
+/// 배포 로그 파일을 확인하는 주기
+const DEPLOY_LOG_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// 배포 로그 실시간 스트리밍 (WebSocket)
+///
+/// 지금까지 기록된 내용을 먼저 보내고, 빌드가 처리 중인 동안 새로 추가되는 줄을 보낸다.
+/// 빌드 처리가 끝나면 남은 내용을 보내고 연결을 닫는다.
+async fn deploy_logs_stream(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+
+    ctx.logger.api_entry(&trace_id, "GET", &format!("/api/builds/{}/deploy-logs/stream", id), &format!("build_id={}", id));
+
+    ws.on_upgrade(move |socket| tail_deploy_log(socket, ctx, trace_id, id))
+}
+
+async fn tail_deploy_log(socket: WebSocket, ctx: AppContext, trace_id: String, build_id: i64) {
+    let (mut ws_sender, mut ws_receiver) = socket.split();
+
+    let build = match ctx.build_repo.get(build_id).await {
+        Ok(Some(b)) => b,
+        Ok(None) => {
+            let _ = ws_sender.send(Message::Text(
+                serde_json::json!({"error": "Build not found"}).to_string().into()
+            )).await;
+            return;
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get build: {}", trace_id, e);
+            let _ = ws_sender.send(Message::Text(
+                serde_json::json!({"error": "Database error"}).to_string().into()
+            )).await;
+            return;
+        }
+    };
+
+    info!("[{}] Streaming deploy log of build {}", trace_id, build_id);
+
+    let mut deploy_log_path = build.deploy_log_path;
+    let mut offset: u64 = 0;
+    let mut pending = Vec::new();
+    let mut ticker = interval(DEPLOY_LOG_POLL_INTERVAL);
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {
+                // 파일을 읽기 전에 진행 여부를 확인해야 종료 직전에 기록된 줄을 놓치지 않음
+                let in_progress = ctx.build_queue.processing_build(build.project_id).await == Some(build_id);
+
+                if deploy_log_path.is_none() {
+                    deploy_log_path = ctx.build_repo.get(build_id).await.ok().flatten().and_then(|b| b.deploy_log_path);
+                }
+
+                if let Some(path) = &deploy_log_path {
+                    match read_from(path, offset).await {
+                        Ok(chunk) => {
+                            offset += chunk.len() as u64;
+                            pending.extend_from_slice(&chunk);
+                        }
+                        // 배포 단계 전이면 아직 파일이 없음
+                        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                        Err(e) => {
+                            warn!("[{}] Failed to read deploy log {}: {}", trace_id, path, e);
+                            break;
+                        }
+                    }
+                }
+
+                // 완성된 줄만 보내고, 끝나면 나머지도 전송
+                let send_len = if in_progress {
+                    pending.iter().rposition(|b| *b == b'\n').map(|i| i + 1).unwrap_or(0)
+                } else {
+                    pending.len()
+                };
+                if send_len > 0 {
+                    let text = String::from_utf8_lossy(&pending[..send_len]).to_string();
+                    pending.drain(..send_len);
+                    if ws_sender.send(Message::Text(text.into())).await.is_err() {
+                        break;
+                    }
+                }
+
+                if !in_progress {
+                    info!("[{}] Build {} is no longer in progress, closing deploy log stream", trace_id, build_id);
+                    let _ = ws_sender.send(Message::Close(None)).await;
+                    break;
+                }
+            }
+            // WebSocket close 감지
+            msg = ws_receiver.next() => {
+                if matches!(msg, None | Some(Err(_)) | Some(Ok(Message::Close(_)))) {
+                    info!("[{}] WebSocket closed by client", trace_id);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+async fn read_from(path: &str, offset: u64) -> std::io::Result<Vec<u8>> {
+    let mut file = tokio::fs::File::open(path).await?;
+    file.seek(SeekFrom::Start(offset)).await?;
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf).await?;
+    Ok(buf)
+}
+
+/// 빌드의 테스트 결과 (shard 요약 + 테스트 케이스별 결과)
+async fn get_build_tests(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", &format!("/api/builds/{}/tests", id), "");
+
+    let build = match ctx.build_repo.get(id).await {
+        Ok(Some(build)) => build,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/builds/{}/tests", id), timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Build not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get build: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/builds/{}/tests", id), timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    match ctx.build_repo.list_test_results(id).await {
+        Ok(results) => {
+            let summary = build
+                .test_summary
+                .as_deref()
+                .and_then(|s| serde_json::from_str::<serde_json::Value>(s).ok());
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/builds/{}/tests", id), timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "build_id": id,
+                    "summary": summary,
+                    "results": results,
+                })),
+            )
+        }
+        Err(e) => {
+            warn!("[{}] Failed to list test results: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/builds/{}/tests", id), timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Failed to list test results"})))
+        }
+    }
+}
+
+/// GET /api/builds/{id}/stages
+/// 파이프라인 단계별 상태, 소요 시간, 빌드 로그 안의 구간 (파이프라인이 없는 빌드는 빈 목록)
+async fn get_build_stages(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/builds/{}/stages", id);
+
+    ctx.logger.api_entry(&trace_id, "GET", &path, "");
+
+    match ctx.build_repo.get(id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Build not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get build: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    }
+
+    match ctx.build_repo.list_build_stages(id).await {
+        Ok(stages) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({"build_id": id, "stages": stages})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to list build stages: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Failed to list build stages"})))
+        }
+    }
+}
+
+/// GET /api/builds/{id}/environment
+/// 빌드가 실제로 실행한 환경 스냅샷과, 그 뒤로 바뀐 프로젝트 빌드 설정 항목
+/// GET /api/builds/{id}/log-shipment - 원격 저장소로 보낸 로그 위치와 checksum
+async fn get_build_log_shipment(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/builds/{}/log-shipment", id);
+
+    ctx.logger.api_entry(&trace_id, "GET", &path, "");
+
+    match ctx.build_repo.get(id).await {
+        Ok(Some(build)) => match build.parsed_log_shipment() {
+            Some(shipment) => {
+                ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 200);
+                (StatusCode::OK, Json(serde_json::json!(shipment)))
+            }
+            None => {
+                ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 404);
+                (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Logs of this build have not been shipped"})))
+            }
+        },
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 404);
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Build not found"})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get build: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+async fn get_build_environment(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/builds/{}/environment", id);
+
+    ctx.logger.api_entry(&trace_id, "GET", &path, "");
+
+    let build = match ctx.build_repo.get(id).await {
+        Ok(Some(build)) => build,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Build not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get build: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    let Some(environment) = build.parsed_build_environment() else {
+        ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 404);
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": "No environment snapshot recorded for this build"})),
+        );
+    };
+
+    // 현재 프로젝트 설정과 다른 항목 (프로젝트가 삭제됐으면 비교 생략)
+    let changed: Option<Vec<&str>> = match ctx.project_repo.get(build.project_id).await {
+        Ok(Some(project)) => {
+            let working_directory = match &project.working_directory {
+                Some(wd) => format!("/workspace/{}", wd),
+                None => "/workspace".to_string(),
+            };
+            let fields = [
+                ("build_image", project.build_image != environment.image),
+                ("cache_type", project.cache_type != environment.cache_type),
+                ("working_directory", working_directory != environment.working_directory),
+                ("build_network", project.build_network != environment.network),
+                ("docker_access", project.docker_access != environment.docker_access),
+                ("source_fetch", project.source_fetch != environment.source_fetch),
+                ("build_command", !environment.command.contains(&project.build_command)),
+            ];
+            Some(fields.into_iter().filter(|(_, changed)| *changed).map(|(name, _)| name).collect())
+        }
+        Ok(None) => None,
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            None
+        }
+    };
+
+    ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "build_id": build.id,
+            "build_number": build.build_number,
+            "environment": environment,
+            "changed_since_build": changed,
+        })),
+    )
+}
+
+#[derive(Deserialize)]
+struct RebuildExactRequest {
+    /// 기본 true: 재현 확인용이라 배포/슬롯 전환은 생략. false면 성공 시 배포
+    #[serde(default = "default_rebuild_dry_run")]
+    dry_run: bool,
+}
+
+impl Default for RebuildExactRequest {
+    fn default() -> Self {
+        Self { dry_run: true }
+    }
+}
+
+fn default_rebuild_dry_run() -> bool {
+    true
+}
+
+/// POST /api/builds/{id}/rebuild-exact
+/// 현재 프로젝트 설정 대신 원본 빌드의 환경 스냅샷과 커밋으로 다시 빌드 (재현성 확인, 설정 drift 디버깅)
+async fn rebuild_exact(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    user: Option<Extension<User>>,
+    api_token: Option<Extension<ApiTokenAuth>>,
+    body: Option<Json<RebuildExactRequest>>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/builds/{}/rebuild-exact", id);
+    let req = body.map(|Json(r)| r).unwrap_or_default();
+
+    ctx.logger.api_entry(&trace_id, "POST", &path, &format!("build_id={}, dry_run={}", id, req.dry_run));
+
+    let source = match ctx.build_repo.get(id).await {
+        Ok(Some(build)) => build,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Build not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get build: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    if source.parsed_build_environment().is_none() {
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({"error": "No environment snapshot recorded for this build"})),
+        );
+    }
+    // 커밋을 몰랐던 빌드(HEAD)는 같은 소스를 보장할 수 없음
+    if !source.commit_hash.chars().all(|c| c.is_ascii_hexdigit()) || source.commit_hash.len() < 7 {
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({"error": "Original commit SHA is unknown for this build"})),
+        );
+    }
+
+    let project = match ctx.project_repo.get(source.project_id).await {
+        Ok(Some(p)) if p.deleted_at.is_none() => p,
+        Ok(_) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    if let Err(rejection) = check_build_allowed(&ctx.disk_quota_service, &trace_id, &project).await {
+        let (status, body) = build_rejection_response(rejection);
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), status.as_u16());
+        return (status, body);
+    }
+
+    let create_build = CreateBuild {
+        project_id: project.id,
+        commit_hash: source.commit_hash.clone(),
+        commit_message: source.commit_message.clone(),
+        author: source.author.clone(),
+        dry_run: req.dry_run,
+        triggered_by: Some(manual_trigger(user, api_token).to_string()),
+        rebuild_of: Some(source.id),
+        branch: source.branch.clone(),
+        preview_pr: source.preview_pr,
+    };
+
+    let build = match ctx.build_repo.create(create_build).await {
+        Ok(b) => b,
+        Err(e) => {
+            warn!("[{}] Failed to create build: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Failed to create build"})));
+        }
+    };
+
+    ctx.build_queue.enqueue_new(&ctx.event_bus, project.id, build.id).await;
+
+    tracing::info!(
+        target: "audit",
+        event = "build.rebuild_exact",
+        trace_id = %trace_id,
+        project_id = project.id,
+        build_id = build.id,
+        rebuild_of = source.id,
+        triggered_by = build.triggered_by.as_deref().unwrap_or_default(),
+    );
+
+    info!(
+        "[{}] Build #{} queued to reproduce build #{} at {}",
+        trace_id, build.build_number, source.build_number, source.commit_hash
+    );
+    ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 201);
+    (
+        StatusCode::CREATED,
+        Json(serde_json::json!({
+            "build_id": build.id,
+            "build_number": build.build_number,
+            "rebuild_of": source.id,
+            "commit_hash": build.commit_hash,
+            "dry_run": build.dry_run,
+            "triggered_by": build.triggered_by,
+            "message": "Exact rebuild triggered successfully"
+        })),
+    )
+}
+
+/// GET /api/builds/held
+/// 배포 창 밖이라 배포 대기 중인 빌드와 프로젝트별 다음 배포 창 시각
+async fn list_held_builds(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/builds/held", "");
+
+    let builds = match ctx.build_repo.list_by_status(BuildStatus::Held).await {
+        Ok(builds) => builds,
+        Err(e) => {
+            warn!("[{}] Failed to list held builds: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/api/builds/held", timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    let mut held = Vec::with_capacity(builds.len());
+    for build in builds {
+        let project = ctx.project_repo.get(build.project_id).await.ok().flatten();
+        held.push(serde_json::json!({
+            "project_name": project.as_ref().map(|p| p.name.clone()),
+            "deploy_window": project.as_ref().and_then(|p| p.parsed_deploy_window()),
+            "next_window_at": project.as_ref().and_then(next_deploy_window),
+            "build": build,
+        }));
+    }
+
+    ctx.logger.api_exit(&trace_id, "GET", "/api/builds/held", timer.elapsed_ms(), 200);
+    (StatusCode::OK, Json(serde_json::json!({ "builds": held })))
+}
+
+/// POST /api/builds/{id}/release
+/// Held 빌드를 배포 창과 관계없이 지금 배포 (수동 override)
+async fn release_build(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/builds/{}/release", id);
+
+    ctx.logger.api_entry(&trace_id, "POST", &path, &format!("build_id={}", id));
+
+    let build = match ctx.build_repo.get(id).await {
+        Ok(Some(build)) => build,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Build not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get build: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    let _deployment_guard = match ctx.deployment_locks.try_acquire(build.project_id, DeploymentOperation::Deploy, &trace_id) {
+        Ok(guard) => guard,
+        Err(holder) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+            return (StatusCode::CONFLICT, deployment_conflict(holder));
+        }
+    };
+
+    // 잠금을 얻는 동안 배포 창이 열려 워커가 먼저 배포했을 수 있으므로 잠금 후 상태 확인
+    let build = match ctx.build_repo.get(id).await {
+        Ok(Some(build)) if build.status == BuildStatus::Held => build,
+        Ok(Some(build)) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+            return (
+                StatusCode::CONFLICT,
+                Json(serde_json::json!({"error": format!("Build is not held (status: {})", build.status)})),
+            );
+        }
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Build not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get build: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    tracing::info!(
+        target: "audit",
+        event = "build.released",
+        trace_id = %trace_id,
+        project_id = build.project_id,
+        build_id = build.id,
+    );
+
+    match release_held_build(&ctx, &trace_id, &build).await {
+        Ok(()) => {
+            info!("[{}] Held build #{} released", trace_id, build.build_number);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "message": "Build deployed",
+                    "build_id": build.id,
+                    "build_number": build.build_number,
+                })),
+            )
+        }
+        Err(e) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": format!("Deployment failed: {}", e)})),
+            )
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct BuildAnnotationRequest {
+    /// 메모. null이면 삭제
+    note: Option<String>,
+    /// 라벨 목록 (전체 교체)
+    #[serde(default)]
+    labels: Vec<String>,
+}
+
+/// PUT /api/builds/{id}/annotation
+/// 빌드/배포에 메모와 라벨 지정 (예: "hotfix for incident #12", ["hotfix"])
+async fn update_build_annotation(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    user: Option<Extension<User>>,
+    Json(req): Json<BuildAnnotationRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/builds/{}/annotation", id);
+
+    ctx.logger.api_entry(&trace_id, "PUT", &path, &format!("build_id={}", id));
+
+    let note = req.note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+    if note.as_ref().is_some_and(|n| n.chars().count() > MAX_BUILD_NOTE_LEN) {
+        ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": format!("note too long (max {} characters)", MAX_BUILD_NOTE_LEN)})),
+        );
+    }
+    let labels = match normalize_build_labels(&req.labels) {
+        Ok(labels) => labels,
+        Err(msg) => {
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 400);
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+        }
+    };
+
+    let build = match ctx.build_repo.get(id).await {
+        Ok(Some(build)) => build,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Build not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get build: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    let labels_json = (!labels.is_empty()).then(|| serde_json::to_string(&labels).unwrap_or_default());
+    if let Err(e) = ctx.build_repo.update_annotation(id, note.clone(), labels_json).await {
+        warn!("[{}] Failed to update build annotation: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 500);
+        return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "build.annotated",
+        trace_id = %trace_id,
+        project_id = build.project_id,
+        build_id = build.id,
+        user = user.as_ref().map(|Extension(u)| u.email.as_str()).unwrap_or_default(),
+    );
+
+    ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "build_id": id,
+            "note": note,
+            "labels": labels,
+        })),
+    )
+}
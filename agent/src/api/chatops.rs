@@ -0,0 +1,841 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::post,
+    Extension, Json, Router,
+};
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+use std::path::PathBuf;
+use tracing::{info, warn};
+
+use crate::application::ports::repositories::{BuildRepository, ProjectRepository, SettingsRepository, UserRepository};
+use crate::db::models::{BuildStatus, BuildTrigger, User};
+use crate::infrastructure::database::{generate_link_code, ChatProvider, LinkCodeRedemption, ProjectPermission, LINK_CODE_TTL_MINUTES};
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::state::{AppContext, DeploymentOperation};
+use super::middleware::has_project_permission;
+use super::projects::{create_manual_build, TriggerBuildRequest};
+use super::settings::is_email_allowed;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Slack 앱 Signing Secret
+pub const SLACK_SIGNING_SECRET_SETTING: &str = "chatops_slack_signing_secret";
+/// Discord 앱 Public Key (hex, Ed25519)
+pub const DISCORD_PUBLIC_KEY_SETTING: &str = "chatops_discord_public_key";
+
+/// 서명 timestamp 허용 오차 (재전송 공격 방지)
+const MAX_TIMESTAMP_SKEW_SECS: i64 = 300;
+
+/// Discord interaction 응답 flag: 명령을 보낸 사용자에게만 표시
+const DISCORD_EPHEMERAL_FLAG: u64 = 64;
+
+const USAGE: &str = "Usage: `status [project]`, `build <project>`, `rollback <project> [build_number]`, `link`, `help`";
+
+/// 채팅 서비스에서 들어오는 slash command (인증 없음, 서명으로 검증)
+pub fn chatops_routes() -> Router<AppContext> {
+    Router::new()
+        .route("/slack", post(slack_command))
+        .route("/discord", post(discord_interaction))
+}
+
+/// slash command 텍스트로 실행할 작업
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ChatCommand {
+    Help,
+    /// 채팅 계정 연결 코드 발급
+    Link,
+    Status(Option<String>),
+    Build(String),
+    Rollback { project: String, build_number: Option<i64> },
+}
+
+impl ChatCommand {
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut words = text.split_whitespace();
+        let command = words.next().map(str::to_lowercase);
+        let project = words.next().map(str::to_string);
+        let extra = words.next();
+
+        match (command.as_deref(), project, extra) {
+            (None | Some("help"), _, _) => Ok(ChatCommand::Help),
+            (Some("link"), None, None) => Ok(ChatCommand::Link),
+            (Some("status"), project, None) => Ok(ChatCommand::Status(project)),
+            (Some("build" | "deploy"), Some(project), None) => Ok(ChatCommand::Build(project)),
+            (Some("rollback"), Some(project), build) => {
+                let build_number = match build.map(|b| b.trim_start_matches('#').parse::<i64>()) {
+                    None => None,
+                    Some(Ok(n)) => Some(n),
+                    Some(Err(_)) => return Err(format!("Invalid build number. {}", USAGE)),
+                };
+                Ok(ChatCommand::Rollback { project, build_number })
+            }
+            _ => Err(format!("Unknown command `{}`. {}", text.trim(), USAGE)),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            ChatCommand::Help => "help",
+            ChatCommand::Link => "link",
+            ChatCommand::Status(_) => "status",
+            ChatCommand::Build(_) => "build",
+            ChatCommand::Rollback { .. } => "rollback",
+        }
+    }
+}
+
+/// 채팅 응답 (public이면 채널 전체에 표시)
+struct ChatReply {
+    text: String,
+    public: bool,
+}
+
+impl ChatReply {
+    fn private(text: impl Into<String>) -> Self {
+        Self { text: text.into(), public: false }
+    }
+
+    fn public(text: impl Into<String>) -> Self {
+        Self { text: text.into(), public: true }
+    }
+}
+
+fn check_timestamp(timestamp: &str, now: i64) -> Result<(), String> {
+    let ts: i64 = timestamp.parse().map_err(|_| "Invalid timestamp header".to_string())?;
+    if (now - ts).abs() > MAX_TIMESTAMP_SKEW_SECS {
+        return Err("Request timestamp is too old".to_string());
+    }
+    Ok(())
+}
+
+/// Slack 서명: `v0=` + hex(HMAC-SHA256(secret, "v0:{timestamp}:{body}"))
+fn slack_mac(secret: &str, timestamp: &str, body: &str) -> Result<HmacSha256, String> {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes())
+        .map_err(|e| format!("Invalid key: {}", e))?;
+    mac.update(format!("v0:{}:{}", timestamp, body).as_bytes());
+    Ok(mac)
+}
+
+fn verify_slack(secret: &str, headers: &HeaderMap, body: &str, now: i64) -> Result<(), String> {
+    let timestamp = header_str(headers, "x-slack-request-timestamp").ok_or("Missing timestamp header")?;
+    let signature = header_str(headers, "x-slack-signature").ok_or("Missing signature header")?;
+    check_timestamp(timestamp, now)?;
+
+    let signature = signature
+        .strip_prefix("v0=")
+        .and_then(|hex_sig| hex::decode(hex_sig).ok())
+        .ok_or("Invalid signature format")?;
+    // 상수 시간 비교
+    slack_mac(secret, timestamp, body)?
+        .verify_slice(&signature)
+        .map_err(|_| "Signature mismatch".to_string())
+}
+
+/// Discord 서명: Ed25519(public key, timestamp + body)
+fn verify_discord(public_key_hex: &str, headers: &HeaderMap, body: &str, now: i64) -> Result<(), String> {
+    let timestamp = header_str(headers, "x-signature-timestamp").ok_or("Missing timestamp header")?;
+    let signature = header_str(headers, "x-signature-ed25519").ok_or("Missing signature header")?;
+    check_timestamp(timestamp, now)?;
+
+    let public_key = hex::decode(public_key_hex.trim()).map_err(|_| "Invalid Discord public key".to_string())?;
+    let signature = hex::decode(signature).map_err(|_| "Invalid signature format".to_string())?;
+    let message = format!("{}{}", timestamp, body);
+
+    ring::signature::UnparsedPublicKey::new(&ring::signature::ED25519, public_key)
+        .verify(message.as_bytes(), &signature)
+        .map_err(|_| "Signature mismatch".to_string())
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackCommand {
+    user_id: String,
+    #[serde(default)]
+    text: String,
+}
+
+/// POST /chatops/slack
+/// Slack slash command (application/x-www-form-urlencoded, Signing Secret 검증)
+async fn slack_command(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    body: String,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/chatops/slack", "");
+
+    let secret = match ctx.settings_repo.get(SLACK_SIGNING_SECRET_SETTING).await {
+        Ok(Some(secret)) => secret,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/chatops/slack", timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Slack integration is not configured"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to load Slack signing secret: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", "/chatops/slack", timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    if let Err(e) = verify_slack(&secret, &headers, &body, Utc::now().timestamp()) {
+        warn!("[{}] Slack command rejected: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "POST", "/chatops/slack", timer.elapsed_ms(), 401);
+        return (StatusCode::UNAUTHORIZED, Json(serde_json::json!({"error": e})));
+    }
+
+    let command: SlackCommand = match serde_urlencoded::from_str(&body) {
+        Ok(command) => command,
+        Err(e) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/chatops/slack", timer.elapsed_ms(), 400);
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": format!("Invalid payload: {}", e)})));
+        }
+    };
+
+    let reply = run_command(&ctx, &trace_id, ChatProvider::Slack, &command.user_id, &command.text).await;
+
+    ctx.logger.api_exit(&trace_id, "POST", "/chatops/slack", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "response_type": if reply.public { "in_channel" } else { "ephemeral" },
+            "text": reply.text,
+        })),
+    )
+}
+
+#[derive(Debug, Deserialize)]
+struct DiscordInteraction {
+    #[serde(rename = "type")]
+    kind: u8,
+    data: Option<DiscordCommandData>,
+    /// 서버 채널에서 실행하면 member.user, DM이면 user
+    member: Option<DiscordMember>,
+    user: Option<DiscordUser>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DiscordCommandData {
+    #[serde(default)]
+    options: Vec<DiscordOption>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DiscordOption {
+    value: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DiscordMember {
+    user: DiscordUser,
+}
+
+#[derive(Debug, Deserialize)]
+struct DiscordUser {
+    id: String,
+}
+
+/// Discord interaction type
+const DISCORD_PING: u8 = 1;
+const DISCORD_APPLICATION_COMMAND: u8 = 2;
+
+/// POST /chatops/discord
+/// Discord Interactions Endpoint (Ed25519 서명 검증). 명령 옵션 값을 이어 붙여 명령 텍스트로 사용
+/// (예: `/easycicd command:build my-app`)
+async fn discord_interaction(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    body: String,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/chatops/discord", "");
+
+    let public_key = match ctx.settings_repo.get(DISCORD_PUBLIC_KEY_SETTING).await {
+        Ok(Some(key)) => key,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/chatops/discord", timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Discord integration is not configured"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to load Discord public key: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", "/chatops/discord", timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    // Discord는 서명이 틀린 요청에 401을 돌려주는지 확인하므로 반드시 401
+    if let Err(e) = verify_discord(&public_key, &headers, &body, Utc::now().timestamp()) {
+        warn!("[{}] Discord interaction rejected: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "POST", "/chatops/discord", timer.elapsed_ms(), 401);
+        return (StatusCode::UNAUTHORIZED, Json(serde_json::json!({"error": e})));
+    }
+
+    let interaction: DiscordInteraction = match serde_json::from_str(&body) {
+        Ok(interaction) => interaction,
+        Err(e) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/chatops/discord", timer.elapsed_ms(), 400);
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": format!("Invalid payload: {}", e)})));
+        }
+    };
+
+    if interaction.kind == DISCORD_PING {
+        ctx.logger.api_exit(&trace_id, "POST", "/chatops/discord", timer.elapsed_ms(), 200);
+        return (StatusCode::OK, Json(serde_json::json!({"type": 1})));
+    }
+
+    let user_id = interaction.member.map(|m| m.user).or(interaction.user).map(|u| u.id);
+    let reply = match (interaction.kind, interaction.data, user_id) {
+        (DISCORD_APPLICATION_COMMAND, Some(data), Some(user_id)) => {
+            let text = data.options.iter()
+                .filter_map(|o| o.value.as_ref())
+                .map(|v| v.as_str().map(str::to_string).unwrap_or_else(|| v.to_string()))
+                .collect::<Vec<_>>()
+                .join(" ");
+            run_command(&ctx, &trace_id, ChatProvider::Discord, &user_id, &text).await
+        }
+        _ => ChatReply::private("Unsupported interaction"),
+    };
+
+    ctx.logger.api_exit(&trace_id, "POST", "/chatops/discord", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "type": 4,
+            "data": {
+                "content": reply.text,
+                "flags": if reply.public { 0 } else { DISCORD_EPHEMERAL_FLAG },
+            },
+        })),
+    )
+}
+
+/// 채팅 사용자 확인 후 명령 실행
+async fn run_command(
+    ctx: &AppContext,
+    trace_id: &str,
+    provider: ChatProvider,
+    external_user_id: &str,
+    text: &str,
+) -> ChatReply {
+    let command = match ChatCommand::parse(text) {
+        Ok(command) => command,
+        Err(message) => return ChatReply::private(message),
+    };
+
+    let user = match linked_user(ctx, provider, external_user_id).await {
+        Ok(Some(user)) => user,
+        Ok(None) if command == ChatCommand::Link => return issue_link_code(ctx, trace_id, provider, external_user_id).await,
+        Ok(None) => {
+            return ChatReply::private(format!(
+                "Your {} account ({}) is not linked to an easyCICD user. Run `link` to get a link code first.",
+                provider, external_user_id
+            ));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to resolve chat user: {}", trace_id, e);
+            return ChatReply::private("Internal error while resolving your account");
+        }
+    };
+
+    // 로그인 화이트리스트에서 빠진 사용자는 연결이 남아 있어도 거부
+    if !is_email_allowed(ctx, &user.email).await {
+        return ChatReply::private(format!("{} is not allowed to use easyCICD", user.email));
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "chatops.command",
+        trace_id = %trace_id,
+        provider = %provider,
+        user = %user.email,
+        command = command.name(),
+        text = %text.trim(),
+    );
+
+    let result = match &command {
+        ChatCommand::Help => Ok(ChatReply::private(USAGE)),
+        ChatCommand::Link => Ok(ChatReply::private(format!("Your {} account is already linked to {}", provider, user.email))),
+        ChatCommand::Status(project) => status(ctx, &user, project.as_deref()).await,
+        ChatCommand::Build(project) => build(ctx, trace_id, provider, &user, project).await,
+        ChatCommand::Rollback { project, build_number } => rollback(ctx, trace_id, &user, project, *build_number).await,
+    };
+
+    result.unwrap_or_else(|e| {
+        warn!("[{}] Chat command '{}' failed: {}", trace_id, command.name(), e);
+        ChatReply::private(format!("Command failed: {}", e))
+    })
+}
+
+/// 연결 코드 발급. 로그인 사용자가 API로 코드를 사용해야 연결되므로 채팅 계정 소유가 확인됨
+async fn issue_link_code(ctx: &AppContext, trace_id: &str, provider: ChatProvider, external_user_id: &str) -> ChatReply {
+    let code = generate_link_code();
+    if let Err(e) = ctx.chat_account_repo.issue_link_code(provider, external_user_id, &code).await {
+        warn!("[{}] Failed to issue chat link code: {}", trace_id, e);
+        return ChatReply::private("Internal error while issuing a link code");
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "chatops.link_code_issued",
+        trace_id = %trace_id,
+        provider = %provider,
+        external_user_id = %external_user_id,
+    );
+    ChatReply::private(format!(
+        "Your link code is `{}` (valid for {} minutes). While logged in to easyCICD, send it with POST /api/chatops/links {{\"code\": \"{}\"}}.",
+        code, LINK_CODE_TTL_MINUTES, code
+    ))
+}
+
+async fn linked_user(ctx: &AppContext, provider: ChatProvider, external_user_id: &str) -> anyhow::Result<Option<User>> {
+    match ctx.chat_account_repo.find_user_id(provider, external_user_id).await? {
+        Some(user_id) => ctx.user_repo.get(user_id).await,
+        None => Ok(None),
+    }
+}
+
+/// 조회 권한이 있는 프로젝트만 표시 (권한 없는 프로젝트는 없는 것처럼)
+async fn status(ctx: &AppContext, user: &User, project_name: Option<&str>) -> anyhow::Result<ChatReply> {
+    let Some(name) = project_name else {
+        let mut lines = Vec::new();
+        for project in ctx.project_repo.list().await? {
+            if !has_project_permission(ctx, Some(user), project.id, None).await? {
+                continue;
+            }
+            let last = ctx.build_repo.get_latest_by_project(project.id).await?
+                .map(|b| format!("#{} {}", b.build_number, b.status))
+                .unwrap_or_else(|| "no builds".to_string());
+            lines.push(format!("• {} — {} (last build: {})", project.name, project.health_state(), last));
+        }
+        if lines.is_empty() {
+            return Ok(ChatReply::private("No projects"));
+        }
+        return Ok(ChatReply::private(lines.join("\n")));
+    };
+
+    let project = match ctx.project_repo.get_by_name(name).await? {
+        Some(project) if has_project_permission(ctx, Some(user), project.id, None).await? => project,
+        _ => return Ok(ChatReply::private(format!("Project `{}` not found", name))),
+    };
+
+    let mut lines = vec![format!(
+        "*{}* — {} (active slot: {}, port {})",
+        project.name,
+        project.health_state(),
+        project.active_slot,
+        project.get_active_port(),
+    )];
+    if let Some(current) = ctx.deployment_service.current_build(&project).await? {
+        lines.push(format!("Serving build #{} ({})", current.build_number, short_hash(&current.commit_hash)));
+    }
+    if let Some(latest) = ctx.build_repo.get_latest_by_project(project.id).await? {
+        lines.push(format!(
+            "Last build #{} {} ({}, by {})",
+            latest.build_number,
+            latest.status,
+            short_hash(&latest.commit_hash),
+            latest.triggered_by.as_deref().unwrap_or("unknown"),
+        ));
+    }
+    Ok(ChatReply::private(lines.join("\n")))
+}
+
+async fn build(
+    ctx: &AppContext,
+    trace_id: &str,
+    provider: ChatProvider,
+    user: &User,
+    project_name: &str,
+) -> anyhow::Result<ChatReply> {
+    let Some(project) = ctx.project_repo.get_by_name(project_name).await? else {
+        return Ok(ChatReply::private(format!("Project `{}` not found", project_name)));
+    };
+    if !has_project_permission(ctx, Some(user), project.id, Some(ProjectPermission::Deploy)).await? {
+        return Ok(ChatReply::private(format!("{} does not have 'deploy' permission for `{}`", user.email, project.name)));
+    }
+
+    let trigger = BuildTrigger::Chat { provider: provider.to_string(), email: user.email.clone() };
+    let (status, Json(response)) = create_manual_build(ctx, trace_id, project.id, trigger, TriggerBuildRequest::default()).await;
+    if !status.is_success() {
+        let error = response["error"].as_str().unwrap_or("Failed to trigger build");
+        return Ok(ChatReply::private(format!("Could not build `{}`: {}", project.name, error)));
+    }
+
+    let build_number = match response["build_id"].as_i64() {
+        Some(build_id) => ctx.build_repo.get(build_id).await?.map(|b| b.build_number),
+        None => None,
+    };
+    Ok(ChatReply::public(format!(
+        "Build {}queued for `{}` (requested by {})",
+        build_number.map(|n| format!("#{} ", n)).unwrap_or_default(),
+        project.name,
+        user.email,
+    )))
+}
+
+async fn rollback(
+    ctx: &AppContext,
+    trace_id: &str,
+    user: &User,
+    project_name: &str,
+    build_number: Option<i64>,
+) -> anyhow::Result<ChatReply> {
+    let Some(project) = ctx.project_repo.get_by_name(project_name).await? else {
+        return Ok(ChatReply::private(format!("Project `{}` not found", project_name)));
+    };
+    if !has_project_permission(ctx, Some(user), project.id, Some(ProjectPermission::Deploy)).await? {
+        return Ok(ChatReply::private(format!("{} does not have 'deploy' permission for `{}`", user.email, project.name)));
+    }
+    if project.archived_at.is_some() {
+        return Ok(ChatReply::private(format!("Project `{}` is archived", project.name)));
+    }
+
+    let current = ctx.deployment_service.current_build(&project).await?;
+
+    // 번호를 지정하지 않으면 현재 서비스 중인 빌드 바로 이전에 배포된 성공 빌드
+    let target = match build_number {
+        Some(n) => ctx.build_repo.list_by_project(project.id, 100).await?
+            .into_iter()
+            .find(|b| b.build_number == n)
+            .filter(|b| {
+                b.status == BuildStatus::Success
+                    && b.get_deployed_slot().is_some()
+                    && b.output_path.as_ref().is_some_and(|p| PathBuf::from(p).exists())
+            }),
+        None => ctx.deployment_service.previous_build(&project).await?,
+    };
+    let Some(target) = target else {
+        return Ok(ChatReply::private(match build_number {
+            Some(n) => format!("Build #{} of `{}` is not a previously deployed build with artifacts", n, project.name),
+            None => format!("No previous successful build to roll back to for `{}`", project.name),
+        }));
+    };
+    if current.as_ref().is_some_and(|c| c.id == target.id) {
+        return Ok(ChatReply::private(format!("Build #{} is already serving `{}`", target.build_number, project.name)));
+    }
+
+    let guard = match ctx.deployment_locks.try_acquire(project.id, DeploymentOperation::Rollback, trace_id) {
+        Ok(guard) => guard,
+        Err(holder) => {
+            return Ok(ChatReply::private(match holder {
+                Some(h) => format!("Another {} is in progress for `{}` (trace_id: {})", h.operation, project.name, h.trace_id),
+                None => format!("Another deployment is in progress for `{}`", project.name),
+            }));
+        }
+    };
+    let project = ctx.project_repo.get(project.id).await?.unwrap_or(project);
+
+    // 슬롯 전환은 헬스체크까지 오래 걸릴 수 있어 응답 제한 시간(Slack/Discord 3초) 안에 먼저 답하고 백그라운드 실행
+    let ctx = ctx.clone();
+    let trace_id = trace_id.to_string();
+    let reply = format!(
+        "Rolling back `{}` to build #{} (requested by {})",
+        project.name, target.build_number, user.email,
+    );
+    tokio::spawn(async move {
+        let _guard = guard;
+        match ctx.deployment_service.rollback(&trace_id, &project, &target).await {
+            Ok(()) => info!("[{}] Chat rollback of {} to build #{} completed", trace_id, project.name, target.build_number),
+            Err(e) => warn!("[{}] Chat rollback of {} to build #{} failed: {}", trace_id, project.name, target.build_number, e),
+        }
+    });
+
+    Ok(ChatReply::public(reply))
+}
+
+fn short_hash(hash: &str) -> &str {
+    &hash[..hash.len().min(7)]
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateChatLinkRequest {
+    /// 채팅에서 `link` 명령으로 받은 코드
+    pub code: String,
+}
+
+/// GET /api/chatops/links - 로그인 사용자의 채팅 계정 연결 목록
+pub async fn list_links(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    user: Option<Extension<User>>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/chatops/links", "");
+
+    let Some(Extension(user)) = user else {
+        ctx.logger.api_exit(&trace_id, "GET", "/api/chatops/links", timer.elapsed_ms(), 401);
+        return (StatusCode::UNAUTHORIZED, Json(serde_json::json!({"error": "Authentication required"})));
+    };
+
+    match ctx.chat_account_repo.list_by_user(user.id).await {
+        Ok(links) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/chatops/links", timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({"links": links})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to list chat links: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/api/chatops/links", timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// POST /api/chatops/links - 연결 코드를 사용해 채팅 계정을 로그인 사용자에 연결
+pub async fn create_link(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    user: Option<Extension<User>>,
+    Json(req): Json<CreateChatLinkRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/chatops/links", "");
+
+    let Some(Extension(user)) = user else {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/chatops/links", timer.elapsed_ms(), 401);
+        return (StatusCode::UNAUTHORIZED, Json(serde_json::json!({"error": "Authentication required"})));
+    };
+
+    match ctx.chat_account_repo.redeem_link_code(&req.code, user.id).await {
+        Ok(LinkCodeRedemption::Linked(link)) => {
+            tracing::info!(
+                target: "audit",
+                event = "chatops.account_linked",
+                trace_id = %trace_id,
+                provider = %link.provider,
+                external_user_id = %link.external_user_id,
+                user = %user.email,
+            );
+            ctx.logger.api_exit(&trace_id, "POST", "/api/chatops/links", timer.elapsed_ms(), 201);
+            (StatusCode::CREATED, Json(serde_json::json!(link)))
+        }
+        Ok(LinkCodeRedemption::InvalidCode) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/chatops/links", timer.elapsed_ms(), 400);
+            (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "Invalid or expired link code"})))
+        }
+        Ok(LinkCodeRedemption::AlreadyLinked) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/chatops/links", timer.elapsed_ms(), 409);
+            (StatusCode::CONFLICT, Json(serde_json::json!({"error": "This chat account is already linked"})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to link chat account: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", "/api/chatops/links", timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// DELETE /api/chatops/links/{id} - 본인 연결 해제
+pub async fn delete_link(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    user: Option<Extension<User>>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/chatops/links/{}", id);
+
+    ctx.logger.api_entry(&trace_id, "DELETE", &path, "");
+
+    let Some(Extension(user)) = user else {
+        ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 401);
+        return (StatusCode::UNAUTHORIZED, Json(serde_json::json!({"error": "Authentication required"})));
+    };
+
+    match ctx.chat_account_repo.unlink(id, user.id).await {
+        Ok(true) => {
+            tracing::info!(
+                target: "audit",
+                event = "chatops.account_unlinked",
+                trace_id = %trace_id,
+                link_id = id,
+                user = %user.email,
+            );
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({"success": true})))
+        }
+        Ok(false) => {
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 404);
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Link not found"})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to unlink chat account: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetChatOpsSettingsRequest {
+    /// 생략하면 유지, 빈 문자열이면 해제
+    pub slack_signing_secret: Option<String>,
+    pub discord_public_key: Option<String>,
+}
+
+/// GET /api/settings/chatops - 연동 설정 여부 (secret 값은 반환하지 않음)
+pub async fn get_settings(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/settings/chatops", "");
+
+    let slack = ctx.settings_repo.get(SLACK_SIGNING_SECRET_SETTING).await;
+    let discord = ctx.settings_repo.get(DISCORD_PUBLIC_KEY_SETTING).await;
+    match (slack, discord) {
+        (Ok(slack), Ok(discord)) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/chatops", timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "slack_configured": slack.is_some(),
+                    "discord_configured": discord.is_some(),
+                    "slack_endpoint": "/chatops/slack",
+                    "discord_endpoint": "/chatops/discord",
+                })),
+            )
+        }
+        (Err(e), _) | (_, Err(e)) => {
+            warn!("[{}] Failed to load chatops settings: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/chatops", timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// POST /api/settings/chatops - Slack Signing Secret / Discord Public Key 설정
+pub async fn set_settings(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(req): Json<SetChatOpsSettingsRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/settings/chatops", "");
+
+    if let Some(key) = req.discord_public_key.as_deref().map(str::trim).filter(|k| !k.is_empty()) {
+        if hex::decode(key).map_or(true, |k| k.len() != 32) {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/chatops", timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": "discord_public_key must be a 64-character hex Ed25519 key"})),
+            );
+        }
+    }
+
+    for (key, value) in [
+        (SLACK_SIGNING_SECRET_SETTING, &req.slack_signing_secret),
+        (DISCORD_PUBLIC_KEY_SETTING, &req.discord_public_key),
+    ] {
+        let Some(value) = value.as_deref().map(str::trim) else { continue };
+        let result = if value.is_empty() {
+            ctx.settings_repo.delete(key).await
+        } else {
+            ctx.settings_repo.set(key, value).await
+        };
+        if let Err(e) = result {
+            warn!("[{}] Failed to save {}: {}", trace_id, key, e);
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/chatops", timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.chatops_updated",
+        trace_id = %trace_id,
+        slack = req.slack_signing_secret.is_some(),
+        discord = req.discord_public_key.is_some(),
+    );
+
+    ctx.logger.api_exit(&trace_id, "POST", "/api/settings/chatops", timer.elapsed_ms(), 200);
+    (StatusCode::OK, Json(serde_json::json!({"success": true})))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(ChatCommand::parse(""), Ok(ChatCommand::Help));
+        assert_eq!(ChatCommand::parse("status"), Ok(ChatCommand::Status(None)));
+        assert_eq!(ChatCommand::parse("link"), Ok(ChatCommand::Link));
+        assert_eq!(ChatCommand::parse("Status my-app"), Ok(ChatCommand::Status(Some("my-app".to_string()))));
+        assert_eq!(ChatCommand::parse("build my-app"), Ok(ChatCommand::Build("my-app".to_string())));
+        assert_eq!(
+            ChatCommand::parse("rollback my-app #12"),
+            Ok(ChatCommand::Rollback { project: "my-app".to_string(), build_number: Some(12) })
+        );
+        assert_eq!(
+            ChatCommand::parse("rollback my-app"),
+            Ok(ChatCommand::Rollback { project: "my-app".to_string(), build_number: None })
+        );
+        assert!(ChatCommand::parse("build").is_err());
+        assert!(ChatCommand::parse("link U999").is_err());
+        assert!(ChatCommand::parse("rollback my-app latest").is_err());
+        assert!(ChatCommand::parse("delete my-app").is_err());
+    }
+
+    fn slack_signature(secret: &str, timestamp: &str, body: &str) -> String {
+        let mac = slack_mac(secret, timestamp, body).unwrap();
+        format!("v0={}", hex::encode(mac.finalize().into_bytes()))
+    }
+
+    #[test]
+    fn test_verify_slack() {
+        let body = "user_id=U123&text=status";
+        let now = 1_700_000_000;
+        let mut headers = HeaderMap::new();
+        headers.insert("x-slack-request-timestamp", HeaderValue::from_static("1700000000"));
+        let signature = slack_signature("secret", "1700000000", body);
+        headers.insert("x-slack-signature", HeaderValue::from_str(&signature).unwrap());
+
+        assert!(verify_slack("secret", &headers, body, now).is_ok());
+        assert!(verify_slack("other", &headers, body, now).is_err());
+        assert!(verify_slack("secret", &headers, "user_id=U999&text=status", now).is_err());
+        assert!(verify_slack("secret", &headers, body, now + MAX_TIMESTAMP_SKEW_SECS + 1).is_err());
+
+        // 접두사 없음, hex 아님, 길이 다름
+        for bad in [&signature[3..], "v0=zz", &signature[..signature.len() - 2]] {
+            headers.insert("x-slack-signature", HeaderValue::from_str(bad).unwrap());
+            assert!(verify_slack("secret", &headers, body, now).is_err());
+        }
+    }
+
+    #[test]
+    fn test_verify_discord() {
+        let key_pair = Ed25519KeyPair::from_seed_unchecked(&[7u8; 32]).unwrap();
+        let public_key = hex::encode(key_pair.public_key().as_ref());
+        let body = r#"{"type":1}"#;
+        let signature = key_pair.sign(format!("1700000000{}", body).as_bytes());
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-signature-timestamp", HeaderValue::from_static("1700000000"));
+        headers.insert("x-signature-ed25519", HeaderValue::from_str(&hex::encode(signature.as_ref())).unwrap());
+
+        assert!(verify_discord(&public_key, &headers, body, 1_700_000_000).is_ok());
+        assert!(verify_discord(&public_key, &headers, r#"{"type":2}"#, 1_700_000_000).is_err());
+    }
+}
@@ -0,0 +1,301 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::infrastructure::notifications::email_client::{send_email, test_email, SmtpSettings};
+use crate::infrastructure::notifications::{DiscordClient, SlackClient};
+use crate::state::AppContext;
+
+/// 레지스트리/DNS 확인 대기 시간
+const CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 와일드카드 DNS 확인에 쓰는 서브도메인 (실제 프로젝트 이름과 겹치지 않도록)
+const DNS_PROBE_LABEL: &str = "easycicd-dns-check";
+
+/// 연결 테스트 결과
+#[derive(Debug, Serialize)]
+pub struct CheckResult {
+    pub target: &'static str,
+    pub ok: bool,
+    pub latency_ms: u64,
+    pub message: String,
+    #[serde(skip_serializing_if = "serde_json::Value::is_null")]
+    pub details: serde_json::Value,
+}
+
+/// 확인을 실행하고 소요 시간과 함께 결과로 변환 (Err는 ok=false, 메시지는 원인 체인 전체)
+async fn run_check<F>(target: &'static str, check: F) -> CheckResult
+where
+    F: Future<Output = anyhow::Result<(String, serde_json::Value)>>,
+{
+    let started = Instant::now();
+    let result = check.await;
+    let latency_ms = started.elapsed().as_millis() as u64;
+    match result {
+        Ok((message, details)) => CheckResult { target, ok: true, latency_ms, message, details },
+        Err(e) => CheckResult { target, ok: false, latency_ms, message: format!("{:#}", e), details: serde_json::Value::Null },
+    }
+}
+
+fn respond(
+    ctx: &AppContext,
+    trace_id: &str,
+    path: &str,
+    timer: &Timer,
+    result: Result<CheckResult, String>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let (status, body) = match result {
+        Ok(result) => {
+            tracing::info!(
+                target: "audit",
+                event = "settings.connectivity_tested",
+                trace_id = %trace_id,
+                check = result.target,
+                ok = result.ok,
+            );
+            (StatusCode::OK, serde_json::json!(result))
+        }
+        Err(msg) => (StatusCode::BAD_REQUEST, serde_json::json!({"error": msg})),
+    };
+    ctx.logger.api_exit(trace_id, "POST", path, timer.elapsed_ms(), status.as_u16());
+    (status, Json(body))
+}
+
+/// 웹훅 테스트 대상: 저장된 웹훅(webhook_id) 또는 저장 전 URL(webhook_url)
+#[derive(Debug, Deserialize)]
+pub struct WebhookTestRequest {
+    pub webhook_id: Option<i64>,
+    pub webhook_url: Option<String>,
+}
+
+fn validate_webhook_url(url: &str) -> Result<(), String> {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => Ok(()),
+        _ => Err("webhook_url must be an http(s) URL".to_string()),
+    }
+}
+
+/// POST /api/settings/test/discord-webhook - 테스트 메시지 전송
+pub async fn test_discord_webhook(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(req): Json<WebhookTestRequest>,
+) -> impl IntoResponse {
+    const PATH: &str = "/api/settings/test/discord-webhook";
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    ctx.logger.api_entry(&trace_id, "POST", PATH, &format!("webhook_id={:?}", req.webhook_id));
+
+    let target = match (req.webhook_id, req.webhook_url) {
+        (Some(id), _) => match ctx.discord_webhook_repo.get(id).await {
+            Ok(Some(config)) => Ok((config.label, config.webhook_url)),
+            Ok(None) => Err("Discord webhook not found".to_string()),
+            Err(e) => Err(format!("Failed to load webhook: {}", e)),
+        },
+        (None, Some(url)) => validate_webhook_url(&url).map(|_| ("(unsaved)".to_string(), url)),
+        (None, None) => Err("webhook_id or webhook_url is required".to_string()),
+    };
+
+    let result = match target {
+        Ok((label, url)) => Ok(run_check("discord_webhook", async {
+            let client = DiscordClient::new();
+            client.send_message(&url, client.test_message(&label)).await?;
+            Ok((format!("Test message delivered to '{}'", label), serde_json::Value::Null))
+        })
+        .await),
+        Err(msg) => Err(msg),
+    };
+    respond(&ctx, &trace_id, PATH, &timer, result)
+}
+
+/// POST /api/settings/test/slack-webhook - 테스트 메시지 전송
+pub async fn test_slack_webhook(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(req): Json<WebhookTestRequest>,
+) -> impl IntoResponse {
+    const PATH: &str = "/api/settings/test/slack-webhook";
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    ctx.logger.api_entry(&trace_id, "POST", PATH, &format!("webhook_id={:?}", req.webhook_id));
+
+    let target = match (req.webhook_id, req.webhook_url) {
+        (Some(id), _) => match ctx.slack_webhook_repo.get(id).await {
+            Ok(Some(config)) => Ok((config.label, config.webhook_url)),
+            Ok(None) => Err("Slack webhook not found".to_string()),
+            Err(e) => Err(format!("Failed to load webhook: {}", e)),
+        },
+        (None, Some(url)) => validate_webhook_url(&url).map(|_| ("(unsaved)".to_string(), url)),
+        (None, None) => Err("webhook_id or webhook_url is required".to_string()),
+    };
+
+    let result = match target {
+        Ok((label, url)) => Ok(run_check("slack_webhook", async {
+            let client = SlackClient::new();
+            client.send_message(&url, client.test_message(&label)).await?;
+            Ok((format!("Test message delivered to '{}'", label), serde_json::Value::Null))
+        })
+        .await),
+        Err(msg) => Err(msg),
+    };
+    respond(&ctx, &trace_id, PATH, &timer, result)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SmtpTestRequest {
+    /// 테스트 메일 수신자. 없으면 연결/인증만 확인
+    pub to: Option<String>,
+}
+
+/// POST /api/settings/test/smtp - 저장된 SMTP 설정으로 연결/인증 확인 (to가 있으면 테스트 메일 전송)
+pub async fn test_smtp(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(req): Json<SmtpTestRequest>,
+) -> impl IntoResponse {
+    const PATH: &str = "/api/settings/test/smtp";
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    ctx.logger.api_entry(&trace_id, "POST", PATH, &format!("to={:?}", req.to));
+
+    let settings = match SmtpSettings::load(ctx.settings_repo.as_ref()).await {
+        Ok(Some(settings)) => Ok(settings),
+        Ok(None) => Err("SMTP is not configured (POST /api/settings/smtp)".to_string()),
+        Err(e) => Err(format!("Failed to load SMTP settings: {}", e)),
+    };
+
+    let result = match settings {
+        Ok(settings) => Ok(run_check("smtp", async {
+            settings.test_connection().await?;
+            let details = serde_json::json!({"host": settings.host, "port": settings.port, "security": settings.security});
+            match req.to.as_deref().map(str::trim).filter(|to| !to.is_empty()) {
+                Some(to) => {
+                    send_email(&settings, &[to.to_string()], test_email(&settings.host)).await?;
+                    Ok((format!("Connected and sent a test email to {}", to), details))
+                }
+                None => Ok((format!("Connected to {}:{}", settings.host, settings.port), details)),
+            }
+        })
+        .await),
+        Err(msg) => Err(msg),
+    };
+    respond(&ctx, &trace_id, PATH, &timer, result)
+}
+
+/// `/v2/` 응답이 200(익명 허용) 또는 401(인증 필요)이면 Docker Registry API로 판단
+fn is_registry_response(status: reqwest::StatusCode) -> bool {
+    status.is_success() || status == reqwest::StatusCode::UNAUTHORIZED
+}
+
+/// POST /api/settings/test/registry - 레지스트리 미러(REGISTRY_MIRROR) Registry API 응답 확인
+///
+/// 레지스트리 인증 정보는 아직 저장하지 않으므로 도달 가능성과 API 응답만 확인한다
+pub async fn test_registry(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    const PATH: &str = "/api/settings/test/registry";
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    ctx.logger.api_entry(&trace_id, "POST", PATH, "");
+
+    let result = match ctx.docker.registry_mirror().map(str::to_string) {
+        Some(mirror) => Ok(run_check("registry", async move {
+            let client = reqwest::Client::builder().timeout(CHECK_TIMEOUT).build()?;
+            let candidates = if mirror.contains("://") {
+                vec![mirror.clone()]
+            } else {
+                vec![format!("https://{}", mirror), format!("http://{}", mirror)]
+            };
+
+            let mut last_error = None;
+            for base in candidates {
+                let url = format!("{}/v2/", base.trim_end_matches('/'));
+                match client.get(&url).send().await {
+                    Ok(response) if is_registry_response(response.status()) => {
+                        let status = response.status();
+                        let auth_required = status == reqwest::StatusCode::UNAUTHORIZED;
+                        return Ok((
+                            format!("Registry API reachable at {} ({})", url, status),
+                            serde_json::json!({"url": url, "status": status.as_u16(), "auth_required": auth_required}),
+                        ));
+                    }
+                    Ok(response) => last_error = Some(anyhow::anyhow!("{} returned {}, not a Docker registry", url, response.status())),
+                    Err(e) => last_error = Some(anyhow::Error::new(e).context(format!("Failed to reach {}", url))),
+                }
+            }
+            Err(last_error.unwrap_or_else(|| anyhow::anyhow!("Registry unreachable")))
+        })
+        .await),
+        None => Err("No registry configured (set REGISTRY_MIRROR)".to_string()),
+    };
+    respond(&ctx, &trace_id, PATH, &timer, result)
+}
+
+async fn resolve(host: &str) -> anyhow::Result<Vec<String>> {
+    let addrs = tokio::time::timeout(CHECK_TIMEOUT, tokio::net::lookup_host((host, 0)))
+        .await
+        .map_err(|_| anyhow::anyhow!("DNS lookup for {} timed out", host))?
+        .map_err(|e| anyhow::anyhow!("DNS lookup for {} failed: {}", host, e))?;
+    let mut ips: Vec<String> = addrs.map(|a| a.ip().to_string()).collect();
+    ips.sort();
+    ips.dedup();
+    Ok(ips)
+}
+
+/// POST /api/settings/test/dns - base domain과 와일드카드(`*.{domain}`) DNS 레코드 확인
+///
+/// DNS 공급자 API 연동은 없으므로 프록시 라우팅에 필요한 레코드가 조회되는지만 확인한다
+pub async fn test_dns(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    const PATH: &str = "/api/settings/test/dns";
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    ctx.logger.api_entry(&trace_id, "POST", PATH, "");
+
+    let result = match ctx.base_domain() {
+        Some(domain) => Ok(run_check("dns", async move {
+            let probe = format!("{}.{}", DNS_PROBE_LABEL, domain);
+            let base = resolve(&domain).await?;
+            let wildcard = resolve(&probe).await.map_err(|e| e.context("Wildcard record *.{domain} is missing"))?;
+            let details = serde_json::json!({"domain": domain, "addresses": base, "wildcard_addresses": wildcard});
+            if base != wildcard {
+                anyhow::bail!("{} and *.{} resolve to different addresses ({:?} vs {:?})", domain, domain, base, wildcard);
+            }
+            Ok((format!("{} and *.{} resolve to {}", domain, domain, base.join(", ")), details))
+        })
+        .await),
+        None => Err("Base domain is not configured (POST /api/settings/domain)".to_string()),
+    };
+    respond(&ctx, &trace_id, PATH, &timer, result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_run_check() {
+        let ok = run_check("dns", async { Ok(("resolved".to_string(), serde_json::json!({"a": 1}))) }).await;
+        assert!(ok.ok);
+        assert_eq!(ok.details["a"], 1);
+
+        let failed = run_check("smtp", async { Err(anyhow::anyhow!("535 auth failed").context("SMTP connection failed")) }).await;
+        assert!(!failed.ok);
+        assert_eq!(failed.message, "SMTP connection failed: 535 auth failed");
+        assert!(serde_json::to_value(&failed).unwrap().get("details").is_none());
+
+        assert!(is_registry_response(reqwest::StatusCode::UNAUTHORIZED));
+        assert!(!is_registry_response(reqwest::StatusCode::NOT_FOUND));
+        assert!(validate_webhook_url("ftp://example.com").is_err());
+    }
+}
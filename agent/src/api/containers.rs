@@ -1,24 +1,35 @@
 use axum::{
-    extract::{Path, State},
-    http::{HeaderMap, StatusCode},
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
     response::IntoResponse,
-    routing::{get, post, delete},
+    routing::{get, post, put},
     Json, Router,
 };
 use serde::{Deserialize, Serialize};
 use tracing::error;
 
 use crate::state::AppContext;
-use crate::infrastructure::logging::{TraceContext, Timer};
-use crate::db::models::{CreateContainer, ProtocolType};
+use crate::infrastructure::logging::{ContainerLogStore, TraceContext, Timer};
+use crate::infrastructure::logging::container_log_store::{
+    filter_lines, LogRetention, DEFAULT_LOG_MAX_SIZE_MB, DEFAULT_LOG_RETENTION_DAYS,
+};
+use crate::application::ports::repositories::ContainerRepository;
+use crate::application::services::image_profiles::missing_required_env;
+use crate::docker::{group_network_name, validate_network_group};
+use crate::db::models::{validate_exec_args, validate_port_mappings, ContainerHealthCheck, CreateContainer, PortMapping, PortMappingRequest, ProtocolType, RestartConfig, RestartPolicy};
 
 pub fn containers_routes() -> Router<AppContext> {
     Router::new()
         .route("/", get(list_containers).post(create_container))
+        .route("/batch", post(batch_containers))
         .route("/{id}", get(get_container).delete(delete_container))
         .route("/{id}/start", post(start_container))
         .route("/{id}/stop", post(stop_container))
-        .route("/{id}/logs", get(get_logs))
+        .route("/{id}/health-check", put(update_health_check))
+        .route("/{id}/logs", get(get_logs).delete(purge_logs))
+        .route("/{id}/log-retention", put(update_log_retention))
+        .route("/{id}/restart-policy", put(update_restart_policy))
+        .route("/{id}/logs/download", get(download_logs))
         .route("/{id}/terminal", get(super::terminal::container_terminal))
 }
 
@@ -32,6 +43,42 @@ pub struct CreateContainerRequest {
     pub persist_data: Option<bool>,
     #[serde(default)]
     pub protocol_type: ProtocolType,
+    /// 헬스체크 설정 (tcp/http/command)
+    pub health_check: Option<ContainerHealthCheck>,
+    /// 추가 포트 매핑 (Kafka, SIP 등 여러 포트를 쓰는 서비스)
+    #[serde(default)]
+    pub extra_ports: Vec<PortMappingRequest>,
+    /// 이미지 ENTRYPOINT 대체 (exec 형식, command와 함께 쓸 수 없음)
+    pub entrypoint: Option<Vec<String>>,
+    /// CMD 인자 (exec 형식, 셸을 거치지 않음)
+    pub args: Option<Vec<String>>,
+    /// no / on-failure / unless-stopped(기본) / always
+    #[serde(default)]
+    pub restart_policy: RestartPolicy,
+    /// on-failure 최대 재시도 횟수 (null이면 무제한)
+    pub restart_max_retries: Option<i64>,
+    /// 같은 그룹끼리만 통신하는 전용 네트워크 (easycicd-group-{group}). 없으면 기본 네트워크
+    pub network_group: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateLogRetentionRequest {
+    /// 보관 용량 MB (1-1024, null이면 기본값)
+    pub max_size_mb: Option<i64>,
+    /// 보관 기간 일 (1-365, null이면 기본값)
+    pub retention_days: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateRestartPolicyRequest {
+    pub restart_policy: RestartPolicy,
+    pub restart_max_retries: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateHealthCheckRequest {
+    /// null이면 헬스체크 해제
+    pub health_check: Option<ContainerHealthCheck>,
 }
 
 #[derive(Debug, Serialize)]
@@ -47,12 +94,32 @@ pub struct ContainerResponse {
     pub persist_data: bool,
     pub protocol_type: String,
     pub status: String,
+    pub health_check: Option<serde_json::Value>,
+    /// starting / healthy / unhealthy (헬스체크가 없거나 중지 상태면 null)
+    pub health: Option<String>,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize_option")]
+    pub health_checked_at: Option<String>,
+    /// 적용 중인 로그 보관 한도 (설정이 없으면 기본값)
+    pub log_max_size_mb: i64,
+    pub log_retention_days: i64,
+    pub extra_ports: Vec<PortMapping>,
+    pub entrypoint: Option<Vec<String>>,
+    pub args: Option<Vec<String>>,
+    pub restart_policy: String,
+    pub restart_max_retries: Option<i64>,
+    pub network_group: Option<String>,
+    /// 그룹 전용 네트워크 이름 (null이면 기본 네트워크)
+    pub network: Option<String>,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
     pub created_at: String,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
     pub updated_at: String,
 }
 
 impl From<crate::db::models::Container> for ContainerResponse {
     fn from(c: crate::db::models::Container) -> Self {
+        let extra_ports = c.parsed_extra_ports();
+        let exec = c.exec_form();
         Self {
             id: c.id,
             name: c.name,
@@ -65,6 +132,18 @@ impl From<crate::db::models::Container> for ContainerResponse {
             persist_data: c.persist_data != 0,
             protocol_type: c.protocol_type.to_string(),
             status: c.status.to_string(),
+            health_check: c.health_check.and_then(|s| serde_json::from_str(&s).ok()),
+            health: c.health_status,
+            health_checked_at: c.health_checked_at,
+            log_max_size_mb: c.log_max_size_mb.unwrap_or(DEFAULT_LOG_MAX_SIZE_MB),
+            log_retention_days: c.log_retention_days.unwrap_or(DEFAULT_LOG_RETENTION_DAYS),
+            extra_ports,
+            entrypoint: exec.entrypoint,
+            args: exec.args,
+            restart_policy: c.restart_policy.to_string(),
+            restart_max_retries: c.restart_max_retries,
+            network: c.network_group.as_deref().map(group_network_name),
+            network_group: c.network_group,
             created_at: c.created_at,
             updated_at: c.updated_at,
         }
@@ -123,6 +202,66 @@ async fn create_container(
         return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "컨테이너 이름은 하이픈(-)으로 시작하거나 끝날 수 없습니다"}))).into_response();
     }
 
+    if let Err(e) = validate_port_mappings(req.container_port, &req.extra_ports) {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/containers", timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": format!("Invalid extra_ports: {}", e)}))).into_response();
+    }
+
+    let exec_check = req
+        .entrypoint
+        .as_deref()
+        .map_or(Ok(()), |e| validate_exec_args("entrypoint", e, false))
+        .and_then(|_| req.args.as_deref().map_or(Ok(()), |a| validate_exec_args("args", a, true)))
+        .and_then(|_| {
+            if req.command.is_some() && (req.entrypoint.is_some() || req.args.is_some()) {
+                Err("command cannot be combined with entrypoint/args".to_string())
+            } else {
+                Ok(())
+            }
+        });
+    if let Err(e) = exec_check {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/containers", timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e}))).into_response();
+    }
+
+    if let Some(Err(e)) = req.network_group.as_deref().map(validate_network_group) {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/containers", timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e}))).into_response();
+    }
+
+    let restart = RestartConfig { policy: req.restart_policy, max_retries: req.restart_max_retries };
+    if let Err(e) = restart.validate() {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/containers", timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e}))).into_response();
+    }
+
+    if let Some(Err(e)) = req.health_check.as_ref().map(|h| h.validate()) {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/containers", timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": format!("Invalid health_check: {}", e)}))).into_response();
+    }
+
+    // 이미지 프로필: 필수 환경변수 확인, 헬스체크를 지정하지 않았으면 프로필 기본값
+    let profile = ctx.image_profiles.find(&req.image);
+    let mut health_check = req.health_check;
+    if let Some(profile) = &profile {
+        let missing = missing_required_env(profile, req.env_vars.as_ref());
+        if !missing.is_empty() {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/containers", timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": format!("Image profile '{}' requires environment variables: {}", profile.name, missing.join(", ")),
+                    "profile": profile.name,
+                    "missing_env": missing,
+                })),
+            )
+                .into_response();
+        }
+        if health_check.is_none() {
+            health_check = profile.health_check.clone();
+        }
+    }
+
     let create_req = CreateContainer {
         name: name.to_string(),
         image: req.image,
@@ -131,6 +270,12 @@ async fn create_container(
         command: req.command,
         persist_data: req.persist_data.unwrap_or(false),
         protocol_type: req.protocol_type,
+        health_check: health_check.and_then(|h| serde_json::to_string(&h).ok()),
+        extra_ports: req.extra_ports,
+        entrypoint: req.entrypoint,
+        args: req.args,
+        restart,
+        network_group: req.network_group,
     };
 
     match ctx.container_service.create_container(&trace_id, create_req).await {
@@ -147,6 +292,109 @@ async fn create_container(
     }
 }
 
+/// 일괄 작업 최대 대상 수
+const MAX_BATCH_SIZE: usize = 100;
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BatchAction {
+    Start,
+    Stop,
+    Restart,
+    Delete,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BatchContainerRequest {
+    pub action: BatchAction,
+    pub ids: Vec<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchItemResult {
+    pub id: i64,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// POST /api/containers/batch
+/// 여러 standalone 컨테이너에 같은 작업을 순차 적용하고 항목별 결과를 반환한다.
+/// 일부가 실패해도 나머지는 계속 진행한다.
+async fn batch_containers(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(req): Json<BatchContainerRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    ctx.logger.api_entry(&trace_id, "POST", "/api/containers/batch", &format!("action={:?}, ids={:?}", req.action, req.ids));
+
+    if req.ids.is_empty() || req.ids.len() > MAX_BATCH_SIZE {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/containers/batch", timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": format!("ids must contain 1-{} items", MAX_BATCH_SIZE)})),
+        ).into_response();
+    }
+
+    // 중복 ID 제거 (순서 유지)
+    let mut seen = std::collections::HashSet::new();
+    let ids: Vec<i64> = req.ids.iter().copied().filter(|id| seen.insert(*id)).collect();
+
+    let mut results = Vec::with_capacity(ids.len());
+    for id in ids {
+        let result = match req.action {
+            BatchAction::Start => ctx.container_service.start_container(&trace_id, id).await
+                .map(|c| Some(c.status.to_string())),
+            BatchAction::Stop => ctx.container_service.stop_container(&trace_id, id).await
+                .map(|c| Some(c.status.to_string())),
+            BatchAction::Restart => {
+                match ctx.container_service.stop_container(&trace_id, id).await {
+                    Ok(_) => ctx.container_service.start_container(&trace_id, id).await
+                        .map(|c| Some(c.status.to_string())),
+                    Err(e) => Err(e),
+                }
+            }
+            BatchAction::Delete => ctx.container_service.delete_container(&trace_id, id).await
+                .map(|_| Some("deleted".to_string())),
+        };
+
+        match result {
+            Ok(status) => results.push(BatchItemResult { id, success: true, status, error: None }),
+            Err(e) => {
+                error!("[{}] Batch {:?} failed for container {}: {}", trace_id, req.action, id, e);
+                results.push(BatchItemResult { id, success: false, status: None, error: Some(e.to_string()) });
+            }
+        }
+    }
+
+    let succeeded = results.iter().filter(|r| r.success).count();
+    let failed = results.len() - succeeded;
+
+    tracing::info!(
+        target: "audit",
+        event = "containers.batch",
+        trace_id = %trace_id,
+        action = ?req.action,
+        succeeded,
+        failed,
+    );
+
+    ctx.logger.api_exit(&trace_id, "POST", "/api/containers/batch", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "action": req.action,
+            "succeeded": succeeded,
+            "failed": failed,
+            "results": results,
+        })),
+    ).into_response()
+}
+
 /// GET /api/containers/:id
 async fn get_container(
     State(ctx): State<AppContext>,
@@ -246,20 +494,183 @@ async fn stop_container(
     }
 }
 
-/// GET /api/containers/:id/logs
+/// PUT /api/containers/:id/health-check
+/// 헬스체크 설정/해제. 다음 모니터 주기부터 적용되며 이전 결과는 초기화됨
+async fn update_health_check(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateHealthCheckRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    ctx.logger.api_entry(&trace_id, "PUT", "/api/containers/:id/health-check", &id.to_string());
+
+    if let Some(Err(e)) = req.health_check.as_ref().map(|h| h.validate()) {
+        ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/health-check", timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": format!("Invalid health_check: {}", e)}))).into_response();
+    }
+
+    match ctx.container_repo.get(id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/health-check", timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Container not found"}))).into_response();
+        }
+        Err(e) => {
+            error!("[{}] Failed to get container: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/health-check", timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response();
+        }
+    }
+
+    let config = req.health_check.as_ref().and_then(|h| serde_json::to_string(h).ok());
+    let enabled = config.is_some();
+    let result = match ctx.container_repo.update_health_check(id, config).await {
+        Ok(()) => ctx.container_repo.get(id).await,
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(Some(container)) => {
+            tracing::info!(
+                target: "audit",
+                event = "container.health_check_updated",
+                trace_id = %trace_id,
+                container_id = id,
+                enabled = enabled,
+            );
+            ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/health-check", timer.elapsed_ms(), 200);
+            let response: ContainerResponse = container.into();
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/health-check", timer.elapsed_ms(), 404);
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Container not found"}))).into_response()
+        }
+        Err(e) => {
+            error!("[{}] Failed to update health check: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/health-check", timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response()
+        }
+    }
+}
+
+/// PUT /api/containers/:id/restart-policy
+/// 재시작 정책 변경. 실행 중이면 Docker에 바로 적용, 아니면 다음 시작부터 적용
+async fn update_restart_policy(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateRestartPolicyRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    ctx.logger.api_entry(&trace_id, "PUT", "/api/containers/:id/restart-policy", &id.to_string());
+
+    let restart = RestartConfig { policy: req.restart_policy, max_retries: req.restart_max_retries };
+    if let Err(e) = restart.validate() {
+        ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/restart-policy", timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e}))).into_response();
+    }
+
+    let container = match ctx.container_repo.get(id).await {
+        Ok(Some(container)) => container,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/restart-policy", timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Container not found"}))).into_response();
+        }
+        Err(e) => {
+            error!("[{}] Failed to get container: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/restart-policy", timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response();
+        }
+    };
+
+    if let Some(container_id) = container.container_id.as_deref() {
+        if ctx.docker.is_container_running(container_id).await {
+            if let Err(e) = ctx.docker.update_restart_policy(container_id, restart).await {
+                error!("[{}] Failed to apply restart policy: {}", trace_id, e);
+                ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/restart-policy", timer.elapsed_ms(), 500);
+                return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response();
+            }
+        }
+    }
+
+    let result = match ctx.container_repo.update_restart_policy(id, restart).await {
+        Ok(()) => ctx.container_repo.get(id).await,
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(Some(container)) => {
+            tracing::info!(
+                target: "audit",
+                event = "container.restart_policy_updated",
+                trace_id = %trace_id,
+                container_id = id,
+                restart_policy = %restart.policy,
+                max_retries = ?restart.max_retries,
+            );
+            ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/restart-policy", timer.elapsed_ms(), 200);
+            let response: ContainerResponse = container.into();
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/restart-policy", timer.elapsed_ms(), 404);
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Container not found"}))).into_response()
+        }
+        Err(e) => {
+            error!("[{}] Failed to update restart policy: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/restart-policy", timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response()
+        }
+    }
+}
+
+/// 로그 조회 기본/최대 줄 수
+const DEFAULT_LOG_TAIL: usize = 200;
+const MAX_LOG_TAIL: usize = 10_000;
+
+#[derive(Debug, Deserialize)]
+pub struct LogQuery {
+    /// 마지막 N줄 (검색 시 일치한 줄 중 마지막 N줄)
+    pub tail: Option<usize>,
+    /// 대소문자 구분 없는 부분 일치 검색어
+    pub search: Option<String>,
+}
+
+/// 보관된 로그(링 파일). 아직 기록된 것이 없으면 Docker에서 직접 조회
+async fn load_logs(ctx: &AppContext, trace_id: &str, id: i64, tail: Option<usize>) -> anyhow::Result<Vec<String>> {
+    let stored = ContainerLogStore::default().read_lines(id).await?;
+    if !stored.is_empty() {
+        return Ok(stored);
+    }
+    ctx.container_service.get_logs(trace_id, id, tail).await
+}
+
+/// GET /api/containers/:id/logs?tail=200&search=error
 async fn get_logs(
     State(ctx): State<AppContext>,
     headers: HeaderMap,
     Path(id): Path<i64>,
+    Query(query): Query<LogQuery>,
 ) -> impl IntoResponse {
     let trace_id = TraceContext::extract_or_generate(&headers);
     let timer = Timer::start();
     ctx.logger.api_entry(&trace_id, "GET", "/api/containers/:id/logs", &id.to_string());
 
-    match ctx.container_service.get_logs(&trace_id, id, Some(200)).await {
+    let tail = query.tail.unwrap_or(DEFAULT_LOG_TAIL).min(MAX_LOG_TAIL);
+    // 검색할 때는 Docker 폴백도 전체 범위에서 찾음
+    let docker_tail = if query.search.is_some() { MAX_LOG_TAIL } else { tail };
+
+    match load_logs(&ctx, &trace_id, id, Some(docker_tail)).await {
         Ok(logs) => {
+            let mut logs = filter_lines(logs, query.search.as_deref());
+            let matched = logs.len();
+            logs.drain(..logs.len().saturating_sub(tail));
             ctx.logger.api_exit(&trace_id, "GET", "/api/containers/:id/logs", timer.elapsed_ms(), 200);
-            (StatusCode::OK, Json(serde_json::json!({"logs": logs}))).into_response()
+            (StatusCode::OK, Json(serde_json::json!({"logs": logs, "matched": matched}))).into_response()
         }
         Err(e) => {
             error!("[{}] Failed to get container logs: {}", trace_id, e);
@@ -268,3 +679,165 @@ async fn get_logs(
         }
     }
 }
+
+/// GET /api/containers/:id/logs/download?search=error
+/// 보관된 로그 전체를 텍스트 파일로 다운로드
+async fn download_logs(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Query(query): Query<LogQuery>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    ctx.logger.api_entry(&trace_id, "GET", "/api/containers/:id/logs/download", &id.to_string());
+
+    let container = match ctx.container_repo.get(id).await {
+        Ok(Some(container)) => container,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/containers/:id/logs/download", timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Container not found"}))).into_response();
+        }
+        Err(e) => {
+            error!("[{}] Failed to get container: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/api/containers/:id/logs/download", timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response();
+        }
+    };
+
+    match load_logs(&ctx, &trace_id, id, Some(MAX_LOG_TAIL)).await {
+        Ok(logs) => {
+            let body: String = filter_lines(logs, query.search.as_deref())
+                .into_iter()
+                .map(|line| line + "\n")
+                .collect();
+            ctx.logger.api_exit(&trace_id, "GET", "/api/containers/:id/logs/download", timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                [
+                    (header::CONTENT_TYPE, "text/plain; charset=utf-8".to_string()),
+                    (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}.log\"", container.name)),
+                ],
+                body,
+            ).into_response()
+        }
+        Err(e) => {
+            error!("[{}] Failed to get container logs: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/api/containers/:id/logs/download", timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response()
+        }
+    }
+}
+
+/// DELETE /api/containers/:id/logs
+/// 보관된 로그 전체 삭제 (Docker 자체 로그는 그대로, 스트리밍 중이면 이후 로그부터 다시 보관)
+async fn purge_logs(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    ctx.logger.api_entry(&trace_id, "DELETE", "/api/containers/:id/logs", &id.to_string());
+
+    match ctx.container_repo.get(id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "DELETE", "/api/containers/:id/logs", timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Container not found"}))).into_response();
+        }
+        Err(e) => {
+            error!("[{}] Failed to get container: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "DELETE", "/api/containers/:id/logs", timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response();
+        }
+    }
+
+    match ContainerLogStore::default().purge(id).await {
+        Ok(freed) => {
+            tracing::info!(
+                target: "audit",
+                event = "container.logs_purged",
+                trace_id = %trace_id,
+                container_id = id,
+                freed_bytes = freed,
+            );
+            ctx.logger.api_exit(&trace_id, "DELETE", "/api/containers/:id/logs", timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({"success": true, "freed_bytes": freed}))).into_response()
+        }
+        Err(e) => {
+            error!("[{}] Failed to purge container logs: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "DELETE", "/api/containers/:id/logs", timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response()
+        }
+    }
+}
+
+/// PUT /api/containers/:id/log-retention
+/// 로그 보관 한도 변경. 기간은 바로 적용, 용량은 다음 로그 스트림 연결(컨테이너 재시작)부터 적용
+async fn update_log_retention(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateLogRetentionRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    ctx.logger.api_entry(&trace_id, "PUT", "/api/containers/:id/log-retention", &id.to_string());
+
+    if req.max_size_mb.is_some_and(|mb| !(1..=1024).contains(&mb)) {
+        ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/log-retention", timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "max_size_mb must be between 1 and 1024"}))).into_response();
+    }
+    if req.retention_days.is_some_and(|days| !(1..=365).contains(&days)) {
+        ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/log-retention", timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "retention_days must be between 1 and 365"}))).into_response();
+    }
+
+    match ctx.container_repo.get(id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/log-retention", timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Container not found"}))).into_response();
+        }
+        Err(e) => {
+            error!("[{}] Failed to get container: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/log-retention", timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response();
+        }
+    }
+
+    let result = match ctx.container_repo.update_log_retention(id, req.max_size_mb, req.retention_days).await {
+        Ok(()) => ctx.container_repo.get(id).await,
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(Some(container)) => {
+            let retention = LogRetention::new(req.max_size_mb, req.retention_days);
+            if let Err(e) = ContainerLogStore::default().enforce_age(id, retention.max_age).await {
+                error!("[{}] Failed to apply log retention: {}", trace_id, e);
+            }
+            tracing::info!(
+                target: "audit",
+                event = "container.log_retention_updated",
+                trace_id = %trace_id,
+                container_id = id,
+                max_size_mb = ?req.max_size_mb,
+                retention_days = ?req.retention_days,
+            );
+            ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/log-retention", timer.elapsed_ms(), 200);
+            let response: ContainerResponse = container.into();
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/log-retention", timer.elapsed_ms(), 404);
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Container not found"}))).into_response()
+        }
+        Err(e) => {
+            error!("[{}] Failed to update log retention: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", "/api/containers/:id/log-retention", timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()}))).into_response()
+        }
+    }
+}
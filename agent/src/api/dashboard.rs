@@ -0,0 +1,153 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{Duration, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use tracing::warn;
+
+use crate::db::models::BuildStatus;
+use crate::state::AppContext;
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::infrastructure::timezone;
+use crate::application::ports::repositories::{ProjectRepository, BuildRepository};
+
+/// 최근 실패로 집계하는 기간
+const RECENT_FAILURE_HOURS: i64 = 24;
+/// 최근 실패 집계 시 조회하는 빌드 수
+const RECENT_BUILD_SCAN: i64 = 200;
+const RECENT_FAILURE_LIMIT: usize = 5;
+const TOP_DISK_USAGE_LIMIT: usize = 3;
+
+#[derive(Serialize)]
+struct RecentFailure {
+    build_id: i64,
+    build_number: i64,
+    project_id: i64,
+    project_name: Option<String>,
+    commit_hash: String,
+    #[serde(serialize_with = "timezone::serialize_option")]
+    finished_at: Option<String>,
+}
+
+#[derive(Serialize)]
+struct DiskUsageHighlight {
+    project_id: i64,
+    project_name: String,
+    /// 쿼터 대상 합계 (bytes)
+    total: u64,
+    quota_mb: Option<i64>,
+    exceeded: bool,
+}
+
+/// GET /api/dashboard
+/// 대시보드 첫 화면에 필요한 집계 (프로젝트 상태별 수, 빌드 큐, 최근 실패, 디스크 사용량 상위)
+pub async fn get_dashboard(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/dashboard", "");
+
+    let projects = match ctx.project_repo.list().await {
+        Ok(projects) => projects,
+        Err(e) => {
+            warn!("[{}] Failed to list projects: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/api/dashboard", timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    };
+
+    let mut by_health: BTreeMap<&'static str, usize> = BTreeMap::new();
+    for project in &projects {
+        *by_health.entry(project.health_state()).or_default() += 1;
+    }
+
+    let running_builds = ctx.build_queue.processing_count().await;
+    let queue_depth = ctx.build_queue.queued_count().await;
+
+    // 최근 실패 (dry-run 포함, 보관된 프로젝트의 이력도 포함)
+    let names: HashMap<i64, &str> = projects.iter().map(|p| (p.id, p.name.as_str())).collect();
+    let since = Utc::now() - Duration::hours(RECENT_FAILURE_HOURS);
+    let failed: Vec<_> = match ctx.build_repo.list_recent(RECENT_BUILD_SCAN).await {
+        Ok(builds) => builds
+            .into_iter()
+            .filter(|b| b.status == BuildStatus::Failed)
+            .filter(|b| timezone::parse_stored(&b.started_at).is_some_and(|t| t >= since))
+            .collect(),
+        Err(e) => {
+            warn!("[{}] Failed to list recent builds: {}", trace_id, e);
+            Vec::new()
+        }
+    };
+    let recent_failure_count = failed.len();
+    let recent_failures: Vec<RecentFailure> = failed
+        .into_iter()
+        .take(RECENT_FAILURE_LIMIT)
+        .map(|b| RecentFailure {
+            build_id: b.id,
+            build_number: b.build_number,
+            project_id: b.project_id,
+            project_name: names.get(&b.project_id).map(|n| n.to_string()),
+            commit_hash: b.commit_hash,
+            finished_at: b.finished_at,
+        })
+        .collect();
+
+    // 디스크 사용량 상위 프로젝트와 쿼터 초과 프로젝트
+    let mut disk_usage = Vec::new();
+    for project in &projects {
+        let usage = match ctx.disk_quota_service.usage(project).await {
+            Ok(usage) => usage,
+            Err(e) => {
+                warn!("[{}] Failed to compute disk usage for {}: {}", trace_id, project.name, e);
+                continue;
+            }
+        };
+        let quota_mb = ctx.disk_quota_service.effective_quota_mb(project).await.unwrap_or(None);
+        disk_usage.push(DiskUsageHighlight {
+            project_id: project.id,
+            project_name: project.name.clone(),
+            total: usage.total,
+            quota_mb,
+            exceeded: quota_mb.is_some_and(|q| usage.total > (q as u64) * 1024 * 1024),
+        });
+    }
+    disk_usage.sort_by_key(|d| std::cmp::Reverse(d.total));
+    let disk_total: u64 = disk_usage.iter().map(|d| d.total).sum();
+    let over_quota: Vec<&DiskUsageHighlight> = disk_usage.iter().filter(|d| d.exceeded).collect();
+    let top: Vec<&DiskUsageHighlight> = disk_usage.iter().take(TOP_DISK_USAGE_LIMIT).collect();
+
+    ctx.logger.api_exit(&trace_id, "GET", "/api/dashboard", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "projects": {
+                "total": projects.len(),
+                "by_health": by_health,
+            },
+            "builds": {
+                "running": running_builds,
+                "queued": queue_depth,
+            },
+            "recent_failures": {
+                "hours": RECENT_FAILURE_HOURS,
+                "count": recent_failure_count,
+                "builds": recent_failures,
+            },
+            "disk_usage": {
+                "total": disk_total,
+                "over_quota": over_quota,
+                "top": top,
+            },
+        })),
+    )
+}
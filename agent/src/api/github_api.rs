@@ -2,15 +2,22 @@ use axum::{
     extract::{Path, Query, State},
     http::{HeaderMap, StatusCode},
     response::IntoResponse,
-    Json,
+    Extension, Json,
 };
 use serde::Deserialize;
 use tracing::warn;
 
-use crate::db::models::{CreateGitHubPat, GitHubPatSummary};
+use crate::db::models::{CreateGitHubPat, GitHubPatSummary, User};
+use std::sync::Arc;
+
+use crate::application::ports::git_provider::{GitProvider, GitProviderKind};
+use crate::application::services::git_provider_for;
 use crate::github::{GitHubClient, ProjectDetector};
 use crate::state::AppContext;
 use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::workers::github_team_sync::{
+    sync_github_teams, TeamSyncConfig, TeamSyncState, GITHUB_TEAM_SYNC_SETTING, GITHUB_TEAM_SYNC_STATE_SETTING,
+};
 use crate::application::ports::repositories::{SettingsRepository, GitHubPatRepository, ProjectRepository};
 
 // ============================================================================
@@ -251,7 +258,11 @@ pub async fn delete_pat(
 // Helper: Resolve PAT token from pat_id or legacy settings
 // ============================================================================
 
-async fn resolve_pat(ctx: &AppContext, pat_id: Option<i64>) -> Result<String, (StatusCode, Json<serde_json::Value>)> {
+async fn resolve_pat(
+    ctx: &AppContext,
+    pat_id: Option<i64>,
+    user: Option<&User>,
+) -> Result<String, (StatusCode, Json<serde_json::Value>)> {
     if let Some(id) = pat_id {
         match ctx.github_pat_repo.get(id).await {
             Ok(Some(pat)) => return Ok(pat.token),
@@ -265,6 +276,10 @@ async fn resolve_pat(ctx: &AppContext, pat_id: Option<i64>) -> Result<String, (S
             )),
         }
     }
+    // GitHub로 로그인한 사용자는 자신의 OAuth 토큰 사용
+    if let Some(token) = user.and_then(|u| u.github_token.clone()) {
+        return Ok(token);
+    }
     // Fallback: legacy global PAT from settings
     match ctx.settings_repo.get("github_pat").await {
         Ok(Some(pat)) => Ok(pat),
@@ -275,6 +290,29 @@ async fn resolve_pat(ctx: &AppContext, pat_id: Option<i64>) -> Result<String, (S
     }
 }
 
+/// provider API 클라이언트 결정 (GitHub은 pat_id/로그인 사용자 토큰/레거시 PAT, GitLab/Bitbucket은 전역 token 설정)
+async fn resolve_provider(
+    ctx: &AppContext,
+    provider: GitProviderKind,
+    pat_id: Option<i64>,
+    user: Option<&User>,
+) -> Result<Arc<dyn GitProvider>, (StatusCode, Json<serde_json::Value>)> {
+    if provider == GitProviderKind::GitHub {
+        return Ok(Arc::new(GitHubClient::new(resolve_pat(ctx, pat_id, user).await?)));
+    }
+    match git_provider_for(ctx.github_pat_repo.as_ref(), ctx.settings_repo.as_ref(), provider, None).await {
+        Ok(Some(client)) => Ok(Arc::from(client)),
+        Ok(None) => Err((
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": format!("No {} token configured. Please set a {} token first.", provider, provider)})),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Failed to get {} token: {}", provider, e)})),
+        )),
+    }
+}
+
 // ============================================================================
 // Legacy PAT Endpoints (kept for backward compatibility)
 // ============================================================================
@@ -454,12 +492,16 @@ pub async fn delete_github_pat(
 #[derive(Debug, Deserialize)]
 pub struct PatIdQuery {
     pub pat_id: Option<i64>,
+    /// github(기본), gitlab, bitbucket
+    #[serde(default)]
+    pub provider: GitProviderKind,
 }
 
-/// List user's GitHub repositories
+/// List repositories accessible with the GitHub PAT or GitLab/Bitbucket token (`?provider=gitlab|bitbucket`)
 pub async fn list_repositories(
     State(ctx): State<AppContext>,
     headers: HeaderMap,
+    user: Option<Extension<User>>,
     Query(params): Query<PatIdQuery>,
 ) -> impl IntoResponse {
     let trace_id = TraceContext::extract_or_generate(&headers);
@@ -467,16 +509,14 @@ pub async fn list_repositories(
 
     ctx.logger.api_entry(&trace_id, "GET", "/api/github/repositories", "");
 
-    let pat = match resolve_pat(&ctx, params.pat_id).await {
-        Ok(pat) => pat,
+    let client = match resolve_provider(&ctx, params.provider, params.pat_id, user.as_ref().map(|Extension(u)| u)).await {
+        Ok(client) => client,
         Err((status, json)) => {
             ctx.logger.api_exit(&trace_id, "GET", "/api/github/repositories", timer.elapsed_ms(), status.as_u16());
             return (status, json);
         }
     };
 
-    let client = GitHubClient::new(pat);
-
     match client.list_repositories().await {
         Ok(repos) => {
             ctx.logger.api_exit(&trace_id, "GET", "/api/github/repositories", timer.elapsed_ms(), 200);
@@ -514,12 +554,15 @@ pub struct BranchesQuery {
     pub owner: String,
     pub repo: String,
     pub pat_id: Option<i64>,
+    #[serde(default)]
+    pub provider: GitProviderKind,
 }
 
 /// List repository branches
 pub async fn list_branches(
     State(ctx): State<AppContext>,
     headers: HeaderMap,
+    user: Option<Extension<User>>,
     Query(params): Query<BranchesQuery>,
 ) -> impl IntoResponse {
     let trace_id = TraceContext::extract_or_generate(&headers);
@@ -527,16 +570,14 @@ pub async fn list_branches(
 
     ctx.logger.api_entry(&trace_id, "GET", "/api/github/branches", &format!("{}/{}", params.owner, params.repo));
 
-    let pat = match resolve_pat(&ctx, params.pat_id).await {
-        Ok(pat) => pat,
+    let client = match resolve_provider(&ctx, params.provider, params.pat_id, user.as_ref().map(|Extension(u)| u)).await {
+        Ok(client) => client,
         Err((status, json)) => {
             ctx.logger.api_exit(&trace_id, "GET", "/api/github/branches", timer.elapsed_ms(), status.as_u16());
             return (status, json);
         }
     };
 
-    let client = GitHubClient::new(pat);
-
     match client.list_branches(&params.owner, &params.repo).await {
         Ok(branches) => {
             ctx.logger.api_exit(&trace_id, "GET", "/api/github/branches", timer.elapsed_ms(), 200);
@@ -562,12 +603,15 @@ pub struct FoldersQuery {
     pub repo: String,
     pub sha: String,
     pub pat_id: Option<i64>,
+    #[serde(default)]
+    pub provider: GitProviderKind,
 }
 
 /// List repository folders
 pub async fn list_folders(
     State(ctx): State<AppContext>,
     headers: HeaderMap,
+    user: Option<Extension<User>>,
     Query(params): Query<FoldersQuery>,
 ) -> impl IntoResponse {
     let trace_id = TraceContext::extract_or_generate(&headers);
@@ -575,16 +619,14 @@ pub async fn list_folders(
 
     ctx.logger.api_entry(&trace_id, "GET", "/api/github/folders", &format!("{}/{}/{}", params.owner, params.repo, params.sha));
 
-    let pat = match resolve_pat(&ctx, params.pat_id).await {
-        Ok(pat) => pat,
+    let client = match resolve_provider(&ctx, params.provider, params.pat_id, user.as_ref().map(|Extension(u)| u)).await {
+        Ok(client) => client,
         Err((status, json)) => {
             ctx.logger.api_exit(&trace_id, "GET", "/api/github/folders", timer.elapsed_ms(), status.as_u16());
             return (status, json);
         }
     };
 
-    let client = GitHubClient::new(pat);
-
     match client.get_tree(&params.owner, &params.repo, &params.sha).await {
         Ok(tree) => {
             // Filter only directories
@@ -620,12 +662,15 @@ pub struct DetectProjectQuery {
     pub path_filter: Option<String>,
     pub workflow_path: Option<String>,
     pub pat_id: Option<i64>,
+    #[serde(default)]
+    pub provider: GitProviderKind,
 }
 
 /// Detect project type and generate configuration
 pub async fn detect_project(
     State(ctx): State<AppContext>,
     headers: HeaderMap,
+    user: Option<Extension<User>>,
     Query(params): Query<DetectProjectQuery>,
 ) -> impl IntoResponse {
     let trace_id = TraceContext::extract_or_generate(&headers);
@@ -633,15 +678,13 @@ pub async fn detect_project(
 
     ctx.logger.api_entry(&trace_id, "GET", "/api/github/detect", &format!("{}/{}/{}", params.owner, params.repo, params.branch));
 
-    let pat = match resolve_pat(&ctx, params.pat_id).await {
-        Ok(pat) => pat,
+    let client = match resolve_provider(&ctx, params.provider, params.pat_id, user.as_ref().map(|Extension(u)| u)).await {
+        Ok(client) => client,
         Err((status, json)) => {
             ctx.logger.api_exit(&trace_id, "GET", "/api/github/detect", timer.elapsed_ms(), status.as_u16());
             return (status, json);
         }
     };
-
-    let client = GitHubClient::new(pat);
     let detector = ProjectDetector::new(client);
 
     match detector
@@ -670,3 +713,132 @@ pub async fn detect_project(
         }
     }
 }
+
+// ============================================================================
+// Team Sync Endpoints
+// ============================================================================
+
+async fn team_sync_status(ctx: &AppContext) -> anyhow::Result<serde_json::Value> {
+    let config = TeamSyncConfig::load(ctx.settings_repo.as_ref()).await?;
+    let state = TeamSyncState::load(ctx.settings_repo.as_ref()).await;
+    Ok(serde_json::json!({"enabled": config.is_some(), "config": config, "state": state}))
+}
+
+/// GET /api/settings/github-team-sync - 팀 동기화 설정과 마지막 결과
+pub async fn get_team_sync(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/settings/github-team-sync", "");
+
+    match team_sync_status(&ctx).await {
+        Ok(status) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/github-team-sync", timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(status))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to load team sync settings: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/github-team-sync", timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()})))
+        }
+    }
+}
+
+/// POST /api/settings/github-team-sync - 설정 저장 후 바로 동기화 (null이면 해제, 허용 목록은 그대로 둠)
+pub async fn set_team_sync(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(config): Json<Option<TeamSyncConfig>>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/settings/github-team-sync", &format!("org={:?}", config.as_ref().map(|c| &c.org)));
+
+    if let Some(config) = &config {
+        if let Err(msg) = config.validate() {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/github-team-sync", timer.elapsed_ms(), 400);
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+        }
+        if let Err((status, body)) = resolve_pat(&ctx, config.github_pat_id, None).await {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/github-team-sync", timer.elapsed_ms(), status.as_u16());
+            return (status, body);
+        }
+    }
+
+    let saved = match &config {
+        Some(config) => match serde_json::to_string(config) {
+            Ok(json) => ctx.settings_repo.set(GITHUB_TEAM_SYNC_SETTING, &json).await,
+            Err(e) => Err(e.into()),
+        },
+        None => async {
+            ctx.settings_repo.delete(GITHUB_TEAM_SYNC_SETTING).await?;
+            ctx.settings_repo.delete(GITHUB_TEAM_SYNC_STATE_SETTING).await
+        }
+        .await,
+    };
+    if let Err(e) = saved {
+        warn!("[{}] Failed to save team sync settings: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/github-team-sync", timer.elapsed_ms(), 500);
+        return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()})));
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.github_team_sync_changed",
+        trace_id = %trace_id,
+        org = ?config.as_ref().map(|c| &c.org),
+        teams = ?config.as_ref().map(|c| c.teams.iter().map(|t| t.slug.as_str()).collect::<Vec<_>>()),
+    );
+
+    // 동기화 실패는 state.last_error로 확인
+    if let Err(e) = sync_github_teams(&ctx).await {
+        warn!("[{}] GitHub team sync failed: {}", trace_id, e);
+    }
+    run_team_sync_response(&ctx, &trace_id, "/api/settings/github-team-sync", &timer).await
+}
+
+/// POST /api/settings/github-team-sync/run - 지금 동기화
+pub async fn run_team_sync(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/settings/github-team-sync/run", "");
+
+    match sync_github_teams(&ctx).await {
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/github-team-sync/run", timer.elapsed_ms(), 400);
+            (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "GitHub team sync is not configured"})))
+        }
+        Ok(Some(_)) => run_team_sync_response(&ctx, &trace_id, "/api/settings/github-team-sync/run", &timer).await,
+        Err(e) => {
+            warn!("[{}] GitHub team sync failed: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/github-team-sync/run", timer.elapsed_ms(), 502);
+            (StatusCode::BAD_GATEWAY, Json(serde_json::json!({"error": format!("{:#}", e)})))
+        }
+    }
+}
+
+async fn run_team_sync_response(
+    ctx: &AppContext,
+    trace_id: &str,
+    path: &str,
+    timer: &Timer,
+) -> (StatusCode, Json<serde_json::Value>) {
+    match team_sync_status(ctx).await {
+        Ok(status) => {
+            ctx.logger.api_exit(trace_id, "POST", path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(status))
+        }
+        Err(e) => {
+            ctx.logger.api_exit(trace_id, "POST", path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": e.to_string()})))
+        }
+    }
+}
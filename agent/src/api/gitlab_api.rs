@@ -0,0 +1,159 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use tracing::warn;
+
+use crate::application::ports::repositories::SettingsRepository;
+use crate::application::services::{gitlab_base_url, GITLAB_TOKEN_SETTING, GITLAB_URL_SETTING};
+use crate::gitlab::GitLabClient;
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::state::AppContext;
+
+#[derive(Debug, Deserialize)]
+pub struct SetGitLabTokenRequest {
+    pub token: String,
+    /// self-managed GitLab 주소 (생략 시 https://gitlab.com)
+    pub url: Option<String>,
+}
+
+/// Set GitLab access token (global, validated with GET /user)
+pub async fn set_gitlab_token(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(payload): Json<SetGitLabTokenRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/settings/gitlab-token", "set_token");
+
+    let base_url = match payload.url.as_deref().map(str::trim).filter(|u| !u.is_empty()) {
+        Some(url) if !url.starts_with("https://") && !url.starts_with("http://") => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/gitlab-token", timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": "GitLab URL must start with http:// or https://"})),
+            );
+        }
+        Some(url) => url.trim_end_matches('/').to_string(),
+        None => crate::gitlab::DEFAULT_GITLAB_URL.to_string(),
+    };
+
+    let user = match GitLabClient::new(&base_url, payload.token.clone()).get_user().await {
+        Ok(user) => user,
+        Err(e) => {
+            warn!("[{}] Invalid GitLab token: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/gitlab-token", timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": format!("Invalid GitLab token: {}", e)})),
+            );
+        }
+    };
+
+    let saved = async {
+        ctx.settings_repo.set(GITLAB_URL_SETTING, &base_url).await?;
+        ctx.settings_repo.set(GITLAB_TOKEN_SETTING, &payload.token).await
+    }
+    .await;
+    if let Err(e) = saved {
+        warn!("[{}] Failed to save GitLab token: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/gitlab-token", timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Failed to save GitLab token: {}", e)})),
+        );
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.gitlab_token_set",
+        trace_id = %trace_id,
+        gitlab_url = %base_url,
+        gitlab_username = %user.username,
+    );
+
+    ctx.logger.api_exit(&trace_id, "POST", "/api/settings/gitlab-token", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": true,
+            "gitlab_url": base_url,
+            "gitlab_username": user.username
+        })),
+    )
+}
+
+/// Get GitLab token status
+pub async fn get_gitlab_token_status(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/settings/gitlab-token", "");
+
+    let token = match ctx.settings_repo.get(GITLAB_TOKEN_SETTING).await {
+        Ok(Some(token)) => token,
+        _ => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/gitlab-token", timer.elapsed_ms(), 200);
+            return (StatusCode::OK, Json(serde_json::json!({"configured": false})));
+        }
+    };
+    let base_url = gitlab_base_url(ctx.settings_repo.as_ref())
+        .await
+        .unwrap_or_else(|_| crate::gitlab::DEFAULT_GITLAB_URL.to_string());
+
+    let body = match GitLabClient::new(&base_url, token).get_user().await {
+        Ok(user) => serde_json::json!({
+            "configured": true,
+            "gitlab_url": base_url,
+            "gitlab_username": user.username
+        }),
+        Err(e) => {
+            warn!("[{}] GitLab token validation failed: {}", trace_id, e);
+            serde_json::json!({
+                "configured": false,
+                "gitlab_url": base_url,
+                "error": "GitLab token is invalid or expired"
+            })
+        }
+    };
+
+    ctx.logger.api_exit(&trace_id, "GET", "/api/settings/gitlab-token", timer.elapsed_ms(), 200);
+    (StatusCode::OK, Json(body))
+}
+
+/// Delete GitLab token
+pub async fn delete_gitlab_token(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "DELETE", "/api/settings/gitlab-token", "");
+
+    if let Err(e) = ctx.settings_repo.delete(GITLAB_TOKEN_SETTING).await {
+        warn!("[{}] Failed to delete GitLab token: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "DELETE", "/api/settings/gitlab-token", timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Failed to delete GitLab token: {}", e)})),
+        );
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.gitlab_token_deleted",
+        trace_id = %trace_id,
+    );
+
+    ctx.logger.api_exit(&trace_id, "DELETE", "/api/settings/gitlab-token", timer.elapsed_ms(), 200);
+    (StatusCode::OK, Json(serde_json::json!({"success": true})))
+}
@@ -0,0 +1,206 @@
+use axum::{
+    extract::State,
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use chrono::Utc;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use tracing::{error, warn};
+
+use crate::application::ports::repositories::{BuildRepository, ProjectRepository};
+use crate::db::models::BuildStatus;
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::proxy::stats::RouteStats;
+use crate::state::AppContext;
+use crate::workers::queue_wait_monitor::{queue_wait_secs, queue_wait_threshold_secs};
+
+/// 큐 대기 시간 통계에 사용하는 최근 빌드 수
+const RECENT_BUILD_SCAN: i64 = 100;
+
+/// 빌드 큐 gauge 값
+#[derive(Debug, Default)]
+struct QueueMetrics {
+    /// 프로젝트 이름 → 대기 중인 빌드 수
+    queued_by_project: BTreeMap<String, usize>,
+    running: usize,
+    running_by_group: BTreeMap<String, usize>,
+    oldest_wait_secs: i64,
+    /// 최근 시작한 빌드들의 큐 대기 시간 (초)
+    recent_wait_avg_secs: f64,
+    recent_wait_max_secs: f64,
+    alert_threshold_secs: Option<i64>,
+    /// 리버스 프록시 라우트 통계 (최근 5분)
+    proxy_routes: Vec<RouteStats>,
+}
+
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Prometheus text exposition format
+fn render(metrics: &QueueMetrics) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(out, "# HELP easycicd_build_queue_depth Builds waiting in the queue");
+    let _ = writeln!(out, "# TYPE easycicd_build_queue_depth gauge");
+    let _ = writeln!(out, "easycicd_build_queue_depth {}", metrics.queued_by_project.values().sum::<usize>());
+    for (project, count) in &metrics.queued_by_project {
+        let _ = writeln!(out, "easycicd_build_queue_depth{{project=\"{}\"}} {}", escape_label(project), count);
+    }
+
+    let _ = writeln!(out, "# HELP easycicd_builds_running Builds currently running");
+    let _ = writeln!(out, "# TYPE easycicd_builds_running gauge");
+    let _ = writeln!(out, "easycicd_builds_running {}", metrics.running);
+    for (group, count) in &metrics.running_by_group {
+        let _ = writeln!(out, "easycicd_builds_running{{group=\"{}\"}} {}", escape_label(group), count);
+    }
+
+    let _ = writeln!(out, "# HELP easycicd_build_queue_oldest_wait_seconds Wait time of the oldest queued build");
+    let _ = writeln!(out, "# TYPE easycicd_build_queue_oldest_wait_seconds gauge");
+    let _ = writeln!(out, "easycicd_build_queue_oldest_wait_seconds {}", metrics.oldest_wait_secs);
+
+    let _ = writeln!(out, "# HELP easycicd_build_queue_wait_seconds Queue wait time of recently started builds");
+    let _ = writeln!(out, "# TYPE easycicd_build_queue_wait_seconds gauge");
+    let _ = writeln!(out, "easycicd_build_queue_wait_seconds{{stat=\"avg\"}} {:.3}", metrics.recent_wait_avg_secs);
+    let _ = writeln!(out, "easycicd_build_queue_wait_seconds{{stat=\"max\"}} {:.3}", metrics.recent_wait_max_secs);
+
+    if let Some(threshold) = metrics.alert_threshold_secs {
+        let _ = writeln!(out, "# HELP easycicd_build_queue_wait_alert_threshold_seconds Queue wait alert threshold");
+        let _ = writeln!(out, "# TYPE easycicd_build_queue_wait_alert_threshold_seconds gauge");
+        let _ = writeln!(out, "easycicd_build_queue_wait_alert_threshold_seconds {}", threshold);
+    }
+
+    if !metrics.proxy_routes.is_empty() {
+        let _ = writeln!(out, "# HELP easycicd_proxy_requests Proxied requests in the last 5 minutes");
+        let _ = writeln!(out, "# TYPE easycicd_proxy_requests gauge");
+        for route in &metrics.proxy_routes {
+            let _ = writeln!(out, "easycicd_proxy_requests{{{}}} {}", route_labels(route), route.requests);
+        }
+        let _ = writeln!(out, "# HELP easycicd_proxy_latency_ms Proxied request latency over the last 5 minutes");
+        let _ = writeln!(out, "# TYPE easycicd_proxy_latency_ms gauge");
+        for route in &metrics.proxy_routes {
+            let labels = route_labels(route);
+            let _ = writeln!(out, "easycicd_proxy_latency_ms{{{},quantile=\"0.5\"}} {}", labels, route.p50_ms);
+            let _ = writeln!(out, "easycicd_proxy_latency_ms{{{},quantile=\"0.95\"}} {}", labels, route.p95_ms);
+        }
+        let _ = writeln!(out, "# HELP easycicd_proxy_error_rate Share of 5xx responses over the last 5 minutes");
+        let _ = writeln!(out, "# TYPE easycicd_proxy_error_rate gauge");
+        for route in &metrics.proxy_routes {
+            let _ = writeln!(out, "easycicd_proxy_error_rate{{{}}} {:.4}", route_labels(route), route.error_rate);
+        }
+    }
+
+    out
+}
+
+fn route_labels(route: &RouteStats) -> String {
+    format!(
+        "kind=\"{}\",target=\"{}\",host=\"{}\"",
+        route.kind,
+        escape_label(&route.target),
+        escape_label(&route.host)
+    )
+}
+
+/// GET /api/metrics
+/// 빌드 큐 gauge와 프록시 라우트별 지연 시간/5xx 비율 (Prometheus text format). 빌드 동시 실행 수를 조정할 때 참고
+pub async fn get_metrics(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/metrics", "");
+
+    let queued = match ctx.build_repo.list_by_status(BuildStatus::Queued).await {
+        Ok(builds) => builds,
+        Err(e) => {
+            error!("[{}] Failed to list queued builds: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/api/metrics", timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"}))).into_response();
+        }
+    };
+    let names: HashMap<i64, String> = match ctx.project_repo.list().await {
+        Ok(projects) => projects.into_iter().map(|p| (p.id, p.name)).collect(),
+        Err(e) => {
+            warn!("[{}] Failed to list projects: {}", trace_id, e);
+            HashMap::new()
+        }
+    };
+
+    let now = Utc::now();
+    let mut metrics = QueueMetrics {
+        running: ctx.build_queue.processing_count().await,
+        running_by_group: ctx.build_queue.processing_by_group().await.into_iter().collect(),
+        oldest_wait_secs: queued.iter().filter_map(|b| queue_wait_secs(b, now)).max().unwrap_or(0),
+        alert_threshold_secs: queue_wait_threshold_secs(ctx.settings_repo.as_ref()).await.unwrap_or(None),
+        proxy_routes: ctx.proxy_stats.snapshot(),
+        ..Default::default()
+    };
+    for build in &queued {
+        let name = names.get(&build.project_id).cloned().unwrap_or_else(|| build.project_id.to_string());
+        *metrics.queued_by_project.entry(name).or_default() += 1;
+    }
+
+    match ctx.build_repo.list_recent(RECENT_BUILD_SCAN).await {
+        Ok(builds) => {
+            let waits: Vec<f64> = builds.iter().filter_map(|b| b.queue_wait_ms).map(|ms| ms as f64 / 1000.0).collect();
+            if !waits.is_empty() {
+                metrics.recent_wait_avg_secs = waits.iter().sum::<f64>() / waits.len() as f64;
+                metrics.recent_wait_max_secs = waits.iter().cloned().fold(0.0, f64::max);
+            }
+        }
+        Err(e) => warn!("[{}] Failed to list recent builds: {}", trace_id, e),
+    }
+
+    ctx.logger.api_exit(&trace_id, "GET", "/api/metrics", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")],
+        render(&metrics),
+    ).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let metrics = QueueMetrics {
+            queued_by_project: BTreeMap::from([("web".to_string(), 2), ("api \"v2\"".to_string(), 1)]),
+            running: 1,
+            running_by_group: BTreeMap::from([("heavy-java".to_string(), 1)]),
+            oldest_wait_secs: 420,
+            recent_wait_avg_secs: 12.5,
+            recent_wait_max_secs: 60.0,
+            alert_threshold_secs: Some(300),
+            proxy_routes: vec![RouteStats {
+                kind: "project",
+                project_id: Some(1),
+                target: "web".to_string(),
+                host: "web-app.example.com".to_string(),
+                requests: 40,
+                requests_per_sec: 0.133,
+                p50_ms: 12,
+                p95_ms: 80,
+                errors_5xx: 2,
+                error_rate: 0.05,
+            }],
+        };
+        let text = render(&metrics);
+
+        assert!(text.contains("easycicd_build_queue_depth 3\n"));
+        assert!(text.contains("easycicd_build_queue_depth{project=\"web\"} 2\n"));
+        assert!(text.contains("easycicd_build_queue_depth{project=\"api \\\"v2\\\"\"} 1\n"));
+        assert!(text.contains("easycicd_builds_running{group=\"heavy-java\"} 1\n"));
+        assert!(text.contains("easycicd_build_queue_oldest_wait_seconds 420\n"));
+        assert!(text.contains("easycicd_build_queue_wait_seconds{stat=\"avg\"} 12.500\n"));
+        assert!(text.contains("easycicd_build_queue_wait_alert_threshold_seconds 300\n"));
+        assert!(text.contains("easycicd_proxy_latency_ms{kind=\"project\",target=\"web\",host=\"web-app.example.com\",quantile=\"0.95\"} 80\n"));
+        assert!(text.contains("easycicd_proxy_error_rate{kind=\"project\",target=\"web\",host=\"web-app.example.com\"} 0.0500\n"));
+    }
+}
@@ -1,25 +1,196 @@
 use axum::{
     extract::{Request, State},
-    http::StatusCode,
+    http::{header, Method, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
     Json,
 };
+use std::time::Instant;
 use tower_cookies::Cookies;
+use tracing::warn;
 
-use crate::state::AppContext;
-use crate::application::ports::repositories::SessionRepository;
+use crate::state::{AppContext, LoginKey};
+use crate::application::ports::repositories::{SessionRepository, UserRepository};
+use crate::db::models::UserRole;
+use crate::infrastructure::database::ApiTokenScope;
+use super::login_guard::{record_login_failure, request_client_ip};
 
 const SESSION_COOKIE: &str = "easycicd_session";
 
-/// Auth middleware - validates session for all /api/* routes
+/// 민감한 작업에 필요한 2단계 인증의 유효 시간
+const MFA_MAX_AGE: chrono::Duration = chrono::Duration::minutes(15);
+
+/// Bearer 토큰으로 인증된 요청 (핸들러에서 Extension<ApiTokenAuth>로 확인)
+#[derive(Debug, Clone)]
+pub struct ApiTokenAuth {
+    pub name: String,
+}
+
+fn path_segments(path: &str) -> Vec<&str> {
+    let path = path.strip_prefix("/api").unwrap_or(path);
+    path.trim_matches('/').split('/').collect()
+}
+
+fn is_read(method: &Method) -> bool {
+    method == Method::GET || method == Method::HEAD
+}
+
+/// 관리자만 사용할 수 있는 경로 (설정, PAT, 로그인 허용 목록, 사용자 관리, 시스템 작업)
+fn is_admin_only(method: &Method, segments: &[&str]) -> bool {
+    match segments {
+        ["users", ..] | ["allowed-emails", ..] | ["github", "pats", ..] | ["settings", "webhook-secret", ..] => true,
+        ["settings" | "discord-webhooks" | "slack-webhooks" | "secrets" | "system" | "ports", ..] | ["proxy", "reload"] => !is_read(method),
+        _ => false,
+    }
+}
+
+/// 최근 2단계 인증이 필요한 경로 (TOTP를 등록한 사용자의 세션 요청만 해당)
+///
+/// 설정 변경, 프로젝트 삭제, 비밀 값/PAT/웹훅 서명 비밀 접근, 사용자/허용 목록 변경, API 토큰 발급
+fn is_sensitive(method: &Method, segments: &[&str]) -> bool {
+    match segments {
+        ["secrets", ..] | ["github", "pats", ..] | ["settings", "webhook-secret", ..] => true,
+        ["settings", ..] | ["users", ..] | ["allowed-emails", ..] => !is_read(method),
+        ["projects", _] => method == Method::DELETE,
+        ["auth", "tokens"] => method == Method::POST,
+        _ => false,
+    }
+}
+
+/// 세션의 마지막 2단계 인증이 MFA_MAX_AGE 이내인지
+fn mfa_is_recent(verified_at: Option<&str>, now: chrono::NaiveDateTime) -> bool {
+    verified_at
+        .and_then(|t| chrono::NaiveDateTime::parse_from_str(t, "%Y-%m-%d %H:%M:%S").ok())
+        .is_some_and(|t| now - t < MFA_MAX_AGE)
+}
+
+/// 역할별 API 접근 범위 (프로젝트 단위 권한은 require_project_permission에서 확인)
+///
+/// - admin: 전체
+/// - developer: 설정/PAT/로그인 허용 목록/사용자 관리/시스템 작업 제외
+/// - viewer: 조회(GET)만, 터미널 제외
+///
+/// 본인 API 토큰/TOTP 관리(`/auth/tokens`, `/auth/totp`)는 모든 역할에 허용
+fn role_allows(role: UserRole, method: &Method, path: &str) -> bool {
+    let segments = path_segments(path);
+    if let ["auth", "tokens" | "totp", ..] = segments.as_slice() {
+        return true;
+    }
+    let admin_only = is_admin_only(method, &segments);
+
+    match role {
+        UserRole::Admin => true,
+        UserRole::Developer => !admin_only,
+        // 터미널은 terminal 핸들러가 viewer를 읽기 전용으로만 연결
+        UserRole::Viewer => is_read(method) && !admin_only,
+    }
+}
+
+/// API 토큰 범위가 요청을 허용하는지 (역할 확인은 별도)
+///
+/// - read: 조회(GET)만
+/// - trigger-builds: 조회 + 빌드 실행 (`POST /projects/{id}/builds`, `POST /builds/{id}/rebuild-exact`)
+/// - admin: 전체. 관리자 전용 경로는 조회도 admin 범위 필요
+fn scope_allows(scopes: &[ApiTokenScope], method: &Method, path: &str) -> bool {
+    let segments = path_segments(path);
+    if scopes.contains(&ApiTokenScope::Admin) {
+        return true;
+    }
+    if is_admin_only(method, &segments) {
+        return false;
+    }
+    let trigger = method == Method::POST
+        && matches!(segments.as_slice(), ["projects", _, "builds"] | ["builds", _, "rebuild-exact"]);
+    scopes.iter().any(|scope| match scope {
+        ApiTokenScope::Read => is_read(method),
+        ApiTokenScope::TriggerBuilds => is_read(method) || trigger,
+        ApiTokenScope::Admin => true,
+    })
+}
+
+fn error_response(status: StatusCode, error: String, code: &str) -> Response {
+    (status, Json(serde_json::json!({"error": error, "code": code}))).into_response()
+}
+
+/// Authorization: Bearer 헤더 값
+fn bearer_token(request: &Request) -> Option<String> {
+    request
+        .headers()
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(|t| t.trim().to_string())
+}
+
+/// API 토큰 인증 (만료/범위/소유자 역할 확인)
+///
+/// 잘못된 토큰은 IP별 로그인 실패로 기록하고, 잠긴 IP는 토큰을 확인하지 않고 429
+async fn authenticate_token(ctx: &AppContext, token: &str, mut request: Request, next: Next) -> Response {
+    let ip = request_client_ip(ctx, &request);
+    if let Some(remaining) = ip.and_then(|ip| ctx.login_guard.locked_for(&LoginKey::Ip(ip), Instant::now())) {
+        let mut response = error_response(
+            StatusCode::TOO_MANY_REQUESTS,
+            "Too many failed authentication attempts".to_string(),
+            "TOO_MANY_ATTEMPTS",
+        );
+        if let Ok(value) = remaining.as_secs().max(1).to_string().parse() {
+            response.headers_mut().insert(header::RETRY_AFTER, value);
+        }
+        return response;
+    }
+
+    let api_token = match ctx.api_token_repo.find_valid(token).await {
+        Ok(Some(api_token)) => api_token,
+        Ok(None) => {
+            if let Some(ip) = ip {
+                record_login_failure(ctx, LoginKey::Ip(ip), "invalid_api_token");
+            }
+            return error_response(StatusCode::UNAUTHORIZED, "Invalid or expired API token".to_string(), "INVALID_TOKEN");
+        }
+        Err(e) => {
+            warn!("API token lookup failed: {}", e);
+            return error_response(StatusCode::INTERNAL_SERVER_ERROR, "Token check failed".to_string(), "INTERNAL_ERROR");
+        }
+    };
+    let user = match ctx.user_repo.get(api_token.user_id).await {
+        Ok(Some(user)) => user,
+        _ => return error_response(StatusCode::UNAUTHORIZED, "Invalid or expired API token".to_string(), "INVALID_TOKEN"),
+    };
+
+    let (method, path) = (request.method().clone(), request.uri().path().to_string());
+    if !scope_allows(&api_token.parsed_scopes(), &method, &path) {
+        return error_response(
+            StatusCode::FORBIDDEN,
+            format!("API token '{}' scope does not allow {} {}", api_token.name, method, path),
+            "FORBIDDEN",
+        );
+    }
+    if !role_allows(user.role, &method, &path) {
+        return error_response(StatusCode::FORBIDDEN, format!("Role '{}' cannot {} {}", user.role, method, path), "FORBIDDEN");
+    }
+
+    if let Err(e) = ctx.api_token_repo.touch(api_token.id).await {
+        warn!("Failed to update API token last_used_at: {}", e);
+    }
+    request.extensions_mut().insert(ApiTokenAuth { name: api_token.name });
+    request.extensions_mut().insert(user);
+    next.run(request).await
+}
+
+/// Auth middleware - validates session (or API token) for all /api/* routes
 /// Use with axum::middleware::from_fn_with_state
 pub async fn require_auth(
     State(ctx): State<AppContext>,
     cookies: Cookies,
-    request: Request,
+    mut request: Request,
     next: Next,
 ) -> Response {
+    // 스크립트/CLI: Authorization: Bearer ecd_... (세션 쿠키보다 우선)
+    if let Some(token) = bearer_token(&request) {
+        return authenticate_token(&ctx, &token, request, next).await;
+    }
+
     // Get session cookie
     let session_cookie = match cookies.get(SESSION_COOKIE) {
         Some(c) => c,
@@ -38,8 +209,46 @@ pub async fn require_auth(
 
     // Validate session
     match ctx.session_repo.get(session_id).await {
-        Ok(Some(_session)) => {
-            // Session valid, proceed
+        Ok(Some(session)) => {
+            // Session valid, proceed (핸들러에서 Extension<User>로 로그인 사용자 확인)
+            let user = match ctx.user_repo.get(session.user_id).await {
+                Ok(Some(user)) => user,
+                // 세션은 남았지만 사용자가 삭제됨
+                Ok(None) => {
+                    return error_response(StatusCode::UNAUTHORIZED, "Session expired or invalid".to_string(), "SESSION_EXPIRED");
+                }
+                Err(e) => {
+                    warn!("Session user lookup failed: {}", e);
+                    return error_response(StatusCode::INTERNAL_SERVER_ERROR, "Authentication check failed".to_string(), "INTERNAL_ERROR");
+                }
+            };
+            if !role_allows(user.role, request.method(), request.uri().path()) {
+                return error_response(
+                    StatusCode::FORBIDDEN,
+                    format!("Role '{}' cannot {} {}", user.role, request.method(), request.uri().path()),
+                    "FORBIDDEN",
+                );
+            }
+            let segments = path_segments(request.uri().path());
+            if is_sensitive(request.method(), &segments)
+                && !mfa_is_recent(session.mfa_verified_at.as_deref(), chrono::Utc::now().naive_utc())
+            {
+                match ctx.totp_repo.is_enabled(user.id).await {
+                    Ok(false) => {}
+                    Ok(true) => {
+                        return error_response(
+                            StatusCode::FORBIDDEN,
+                            "Recent two-factor authentication required (POST /api/auth/totp/verify)".to_string(),
+                            "MFA_REQUIRED",
+                        );
+                    }
+                    Err(e) => {
+                        warn!("TOTP status lookup failed: {}", e);
+                        return error_response(StatusCode::INTERNAL_SERVER_ERROR, "MFA check failed".to_string(), "INTERNAL_ERROR");
+                    }
+                }
+            }
+            request.extensions_mut().insert(user);
             next.run(request).await
         }
         _ => {
@@ -54,3 +263,66 @@ pub async fn require_auth(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_allows() {
+        assert!(role_allows(UserRole::Admin, &Method::POST, "/settings/domain"));
+        assert!(role_allows(UserRole::Admin, &Method::PUT, "/users/2/role"));
+
+        assert!(role_allows(UserRole::Developer, &Method::POST, "/projects"));
+        assert!(role_allows(UserRole::Developer, &Method::POST, "/projects/3/builds"));
+        assert!(role_allows(UserRole::Developer, &Method::GET, "/settings/domain"));
+        assert!(!role_allows(UserRole::Developer, &Method::POST, "/settings/domain"));
+        assert!(!role_allows(UserRole::Developer, &Method::GET, "/github/pats"));
+        assert!(!role_allows(UserRole::Developer, &Method::GET, "/api/users"));
+        assert!(!role_allows(UserRole::Developer, &Method::DELETE, "/allowed-emails"));
+
+        assert!(role_allows(UserRole::Viewer, &Method::GET, "/projects/3/builds"));
+        assert!(!role_allows(UserRole::Viewer, &Method::POST, "/projects/3/builds"));
+        assert!(role_allows(UserRole::Viewer, &Method::GET, "/projects/3/slots/blue/terminal"));
+        assert!(!role_allows(UserRole::Viewer, &Method::GET, "/settings/webhook-secret"));
+        assert!(role_allows(UserRole::Viewer, &Method::POST, "/api/auth/tokens"));
+    }
+
+    #[test]
+    fn test_mfa_sensitivity() {
+        let sensitive = |method: Method, path: &str| is_sensitive(&method, &path_segments(path));
+        assert!(sensitive(Method::POST, "/api/settings/domain"));
+        assert!(!sensitive(Method::GET, "/api/settings/domain"));
+        assert!(sensitive(Method::GET, "/settings/webhook-secret"));
+        assert!(sensitive(Method::GET, "/secrets"));
+        assert!(sensitive(Method::DELETE, "/projects/3"));
+        assert!(!sensitive(Method::PUT, "/projects/3"));
+        assert!(!sensitive(Method::POST, "/projects/3/builds"));
+        assert!(sensitive(Method::PUT, "/users/2/role"));
+        assert!(sensitive(Method::POST, "/auth/tokens"));
+        assert!(!sensitive(Method::POST, "/auth/totp/verify"));
+
+        let now = chrono::NaiveDateTime::parse_from_str("2026-01-01 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        assert!(mfa_is_recent(Some("2026-01-01 11:50:00"), now));
+        assert!(!mfa_is_recent(Some("2026-01-01 11:40:00"), now));
+        assert!(!mfa_is_recent(None, now));
+    }
+
+    #[test]
+    fn test_scope_allows() {
+        use ApiTokenScope::*;
+
+        assert!(scope_allows(&[Read], &Method::GET, "/api/projects/3/builds"));
+        assert!(!scope_allows(&[Read], &Method::POST, "/api/projects/3/builds"));
+        assert!(!scope_allows(&[Read], &Method::GET, "/api/github/pats"));
+
+        assert!(scope_allows(&[TriggerBuilds], &Method::POST, "/projects/3/builds"));
+        assert!(scope_allows(&[TriggerBuilds], &Method::POST, "/builds/7/rebuild-exact"));
+        assert!(!scope_allows(&[TriggerBuilds], &Method::POST, "/projects/3/rollback"));
+        assert!(!scope_allows(&[TriggerBuilds], &Method::POST, "/auth/tokens"));
+
+        assert!(scope_allows(&[Admin], &Method::POST, "/settings/domain"));
+        assert!(scope_allows(&[Read, Admin], &Method::GET, "/github/pats"));
+        assert!(!scope_allows(&[], &Method::GET, "/projects"));
+    }
+}
@@ -0,0 +1,82 @@
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    middleware::Next,
+    response::{IntoResponse, Redirect, Response},
+};
+use std::net::SocketAddr;
+use std::time::Instant;
+use tracing::warn;
+
+use crate::state::{AppContext, ClientIp, LoginKey};
+
+/// 로그인 시도로 취급하는 /auth 하위 경로 (브라우저 리다이렉트 흐름)
+fn is_login_path(path: &str) -> bool {
+    let path = path.strip_prefix("/auth").unwrap_or(path);
+    matches!(path.trim_matches('/').split('/').next(), Some("google" | "github"))
+}
+
+/// 요청의 클라이언트 IP (ConnectInfo + TRUSTED_PROXIES에서 온 프록시 헤더)
+pub fn request_client_ip(ctx: &AppContext, request: &Request) -> Option<std::net::IpAddr> {
+    let peer = request.extensions().get::<ConnectInfo<SocketAddr>>().map(|ConnectInfo(addr)| *addr);
+    ctx.login_guard.client_ip(request.headers(), peer)
+}
+
+/// Login guard middleware - /auth/* 로그인 경로의 IP별 요청 한도와 잠금 확인
+///
+/// 핸들러는 Extension<ClientIp>로 실패/성공을 기록. 한도를 넘거나 잠긴 IP는
+/// `/login?error=too_many_attempts`로 리다이렉트
+pub async fn limit_login_attempts(
+    State(ctx): State<AppContext>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let Some(ip) = request_client_ip(&ctx, &request) else {
+        return next.run(request).await;
+    };
+    request.extensions_mut().insert(ClientIp(ip));
+
+    if !is_login_path(request.uri().path()) {
+        return next.run(request).await;
+    }
+
+    let now = Instant::now();
+    if let Some(remaining) = ctx.login_guard.locked_for(&LoginKey::Ip(ip), now) {
+        warn!("Login attempt from locked IP {} ({}s remaining)", ip, remaining.as_secs());
+        return Redirect::temporary("/login?error=too_many_attempts").into_response();
+    }
+    if !ctx.login_guard.allow_request(ip, now) {
+        warn!("Login rate limit exceeded for IP {}", ip);
+        return Redirect::temporary("/login?error=too_many_attempts").into_response();
+    }
+
+    next.run(request).await
+}
+
+/// 로그인 실패 기록. 이번 실패로 잠기면 감사 로그 `auth.lockout`
+pub fn record_login_failure(ctx: &AppContext, key: LoginKey, reason: &str) {
+    if let Some((failures, lockout)) = ctx.login_guard.record_failure(key.clone(), Instant::now()) {
+        warn!("Locking {} for {}s after {} failed login attempts", key, lockout.as_secs(), failures);
+        tracing::warn!(
+            target: "audit",
+            event = "auth.lockout",
+            key = %key,
+            failures,
+            lock_secs = lockout.as_secs(),
+            reason = %reason,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_login_path() {
+        assert!(is_login_path("/google"));
+        assert!(is_login_path("/auth/google/callback"));
+        assert!(is_login_path("/auth/github/callback"));
+        assert!(!is_login_path("/me"));
+        assert!(!is_login_path("/auth/logout"));
+    }
+}
@@ -1,5 +1,9 @@
 pub mod trace_id;
 pub mod auth;
+pub mod project_permission;
+pub mod login_guard;
 
 pub use trace_id::TraceIdLayer;
-pub use auth::require_auth;
+pub use auth::{require_auth, ApiTokenAuth};
+pub use project_permission::{require_project_permission, has_project_permission};
+pub use login_guard::limit_login_attempts;
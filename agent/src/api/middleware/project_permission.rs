@@ -0,0 +1,211 @@
+use axum::{
+    extract::{Query, Request, State},
+    http::{Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use tracing::warn;
+
+use crate::application::ports::repositories::BuildRepository;
+use crate::db::models::{User, UserRole};
+use crate::infrastructure::database::{ProjectAccess, ProjectPermission};
+use crate::state::AppContext;
+use crate::api::terminal::TerminalQuery;
+
+/// 권한 확인 대상 (빌드는 소속 프로젝트 권한으로 확인)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PermissionTarget {
+    Project(i64),
+    Build(i64),
+}
+
+/// `/projects/{id}/...` 하위 경로에 필요한 권한. None이면 기본 정보 조회
+///
+/// 셸을 여는 터미널은 Deploy, 읽기 전용 터미널(`?mode=readonly`)은 로그 조회와 같은 ViewLogs
+fn project_requirement(method: &Method, rest: &[&str], read_only_terminal: bool) -> Option<ProjectPermission> {
+    let read = method == Method::GET;
+    match rest {
+        ["runtime-logs"] => Some(ProjectPermission::ViewLogs),
+        ["slots", _, "terminal"] if read_only_terminal => Some(ProjectPermission::ViewLogs),
+        ["slots", _, "terminal"] => Some(ProjectPermission::Deploy),
+        ["builds"] | ["warm-cache"] | ["simulate-webhook"] | ["rollback"] | ["rollback", _] | ["slots", "switch"]
+        | ["canary", "weight"] | ["containers", _] | ["image-updates", "check"] | ["previews", _]
+            if !read =>
+        {
+            Some(ProjectPermission::Deploy)
+        }
+        // 나머지 변경 요청은 설정 변경으로 취급 (새 라우트도 기본적으로 보호됨)
+        _ if read => None,
+        _ => Some(ProjectPermission::ManageSettings),
+    }
+}
+
+/// `/builds/{id}/...` 하위 경로에 필요한 권한
+fn build_requirement(method: &Method, rest: &[&str]) -> Option<ProjectPermission> {
+    match rest {
+        ["logs" | "build-logs" | "deploy-logs" | "tests" | "stages" | "environment" | "log-shipment"] | ["deploy-logs", "stream"] => {
+            Some(ProjectPermission::ViewLogs)
+        }
+        ["rebuild-exact" | "release"] => Some(ProjectPermission::Deploy),
+        _ if method == Method::GET => None,
+        _ => Some(ProjectPermission::ManageSettings),
+    }
+}
+
+/// 요청 경로의 대상 프로젝트/빌드와 필요한 권한. 프로젝트 단위 경로가 아니면 None
+fn required_permission(
+    method: &Method,
+    path: &str,
+    read_only_terminal: bool,
+) -> Option<(PermissionTarget, Option<ProjectPermission>)> {
+    let path = path.strip_prefix("/api").unwrap_or(path);
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match segments.as_slice() {
+        ["projects", id, rest @ ..] => {
+            let id = id.parse().ok()?;
+            Some((PermissionTarget::Project(id), project_requirement(method, rest, read_only_terminal)))
+        }
+        ["builds", id, rest @ ..] => {
+            let id = id.parse().ok()?;
+            Some((PermissionTarget::Build(id), build_requirement(method, rest)))
+        }
+        _ => None,
+    }
+}
+
+/// 역할에 따른 프로젝트 접근 수준
+///
+/// - admin: 항상 전체 권한
+/// - developer: 권한이 설정되지 않은 프로젝트는 조회/로그만 (빌드/배포는 멤버로 배정된 프로젝트만)
+/// - viewer: 부여된 권한과 관계없이 조회/로그만
+fn role_access(role: UserRole, access: ProjectAccess) -> ProjectAccess {
+    let read_only = |access: ProjectAccess| match access {
+        ProjectAccess::Full => ProjectAccess::Limited(vec![ProjectPermission::ViewLogs]),
+        ProjectAccess::Limited(granted) => {
+            ProjectAccess::Limited(granted.into_iter().filter(|p| *p == ProjectPermission::ViewLogs).collect())
+        }
+        ProjectAccess::Denied => ProjectAccess::Denied,
+    };
+    match role {
+        UserRole::Admin => ProjectAccess::Full,
+        UserRole::Developer => match access {
+            ProjectAccess::Full => read_only(access),
+            access => access,
+        },
+        UserRole::Viewer => read_only(access),
+    }
+}
+
+/// 사용자가 프로젝트에 `required` 권한을 가졌는지 (None이면 기본 정보 조회)
+pub async fn has_project_permission(
+    ctx: &AppContext,
+    user: Option<&User>,
+    project_id: i64,
+    required: Option<ProjectPermission>,
+) -> anyhow::Result<bool> {
+    if user.is_some_and(|u| u.role == UserRole::Admin) {
+        return Ok(true);
+    }
+    let access = ctx.project_permission_repo.access(project_id, user.map(|u| u.email.as_str())).await?;
+    let access = match user {
+        Some(user) => role_access(user.role, access),
+        None => access,
+    };
+    Ok(access.allows(required))
+}
+
+fn internal_error(e: anyhow::Error) -> Response {
+    warn!("Project permission check failed: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(serde_json::json!({"error": "Permission check failed"})),
+    ).into_response()
+}
+
+/// Project permission middleware - /projects/{id}/*, /builds/{id}/* 요청에 프로젝트별 권한 적용
+/// require_auth 안쪽에 둬야 함 (Extension<User> 사용)
+pub async fn require_project_permission(
+    State(ctx): State<AppContext>,
+    request: Request,
+    next: Next,
+) -> Response {
+    // viewer 역할은 terminal 핸들러가 읽기 전용으로만 연결
+    let read_only_terminal = Query::<TerminalQuery>::try_from_uri(request.uri()).is_ok_and(|Query(q)| q.read_only())
+        || request.extensions().get::<User>().is_some_and(|u| u.role == UserRole::Viewer);
+    let Some((target, required)) = required_permission(request.method(), request.uri().path(), read_only_terminal) else {
+        return next.run(request).await;
+    };
+
+    let project_id = match target {
+        PermissionTarget::Project(id) => id,
+        PermissionTarget::Build(id) => match ctx.build_repo.get(id).await {
+            Ok(Some(build)) => build.project_id,
+            // 없는 빌드는 핸들러에서 404
+            Ok(None) => return next.run(request).await,
+            Err(e) => return internal_error(e),
+        },
+    };
+
+    let user = request.extensions().get::<User>();
+    match has_project_permission(&ctx, user, project_id, required).await {
+        Ok(true) => next.run(request).await,
+        Ok(false) => (
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!({
+                "error": match required {
+                    Some(permission) => format!("'{}' permission required for project {}", permission, project_id),
+                    None => format!("No access to project {}", project_id),
+                },
+                "code": "FORBIDDEN"
+            })),
+        ).into_response(),
+        Err(e) => internal_error(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_permission() {
+        use ProjectPermission::*;
+        use PermissionTarget::*;
+
+        assert_eq!(required_permission(&Method::GET, "/projects/3", false), Some((Project(3), None)));
+        assert_eq!(required_permission(&Method::PUT, "/api/projects/3", false), Some((Project(3), Some(ManageSettings))));
+        assert_eq!(required_permission(&Method::POST, "/projects/3/builds", false), Some((Project(3), Some(Deploy))));
+        assert_eq!(required_permission(&Method::POST, "/projects/3/rollback/9", false), Some((Project(3), Some(Deploy))));
+        assert_eq!(required_permission(&Method::GET, "/projects/3/runtime-logs", false), Some((Project(3), Some(ViewLogs))));
+        assert_eq!(required_permission(&Method::PUT, "/projects/3/notes", false), Some((Project(3), Some(ManageSettings))));
+        assert_eq!(required_permission(&Method::GET, "/projects/3/slots/blue/terminal", false), Some((Project(3), Some(Deploy))));
+        assert_eq!(required_permission(&Method::GET, "/projects/3/slots/blue/terminal", true), Some((Project(3), Some(ViewLogs))));
+        assert_eq!(required_permission(&Method::GET, "/builds/7/deploy-logs/stream", false), Some((Build(7), Some(ViewLogs))));
+        assert_eq!(required_permission(&Method::GET, "/builds/7/log-shipment", false), Some((Build(7), Some(ViewLogs))));
+        assert_eq!(required_permission(&Method::POST, "/builds/7/release", false), Some((Build(7), Some(Deploy))));
+        assert_eq!(required_permission(&Method::GET, "/builds/7", false), Some((Build(7), None)));
+
+        // 프로젝트 단위가 아닌 경로
+        assert_eq!(required_permission(&Method::POST, "/projects/batch", false), None);
+        assert_eq!(required_permission(&Method::GET, "/projects", false), None);
+        assert_eq!(required_permission(&Method::GET, "/containers/3/logs", false), None);
+    }
+
+    #[test]
+    fn test_role_access() {
+        use ProjectPermission::*;
+
+        let developer = role_access(UserRole::Developer, ProjectAccess::Full);
+        assert!(developer.allows(Some(ViewLogs)));
+        assert!(!developer.allows(Some(Deploy)));
+        assert!(role_access(UserRole::Developer, ProjectAccess::Limited(vec![Deploy])).allows(Some(Deploy)));
+
+        let viewer = role_access(UserRole::Viewer, ProjectAccess::Limited(vec![Deploy, ViewLogs]));
+        assert!(viewer.allows(Some(ViewLogs)));
+        assert!(!viewer.allows(Some(Deploy)));
+        assert!(!role_access(UserRole::Viewer, ProjectAccess::Denied).allows(None));
+
+        assert!(role_access(UserRole::Admin, ProjectAccess::Denied).allows(Some(ManageSettings)));
+    }
+}
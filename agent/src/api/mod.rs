@@ -5,18 +5,39 @@ mod containers;
 mod ws;
 mod settings;
 mod github_api;
+mod gitlab_api;
+mod bitbucket_api;
 mod auth;
 mod discord_webhooks;
+mod slack_webhooks;
+mod project_validation;
+mod plugins;
+mod system;
+mod dashboard;
+mod analytics;
+mod metrics;
+mod ports;
+mod proxy;
+mod previews;
+mod secrets;
 pub mod terminal;
+mod chatops;
+mod connectivity;
+mod project_permissions;
+mod outbound_webhooks;
+mod users;
+mod api_tokens;
+mod totp;
 pub mod middleware;
 
-pub use webhook::github_webhook;
+pub use webhook::{github_webhook, gitlab_webhook, bitbucket_webhook, generate_webhook_secret};
 pub use projects::projects_routes;
 pub use builds::builds_routes;
 pub use containers::containers_routes;
 pub use ws::ws_handler;
 pub use middleware::TraceIdLayer;
 pub use auth::auth_routes;
+pub use chatops::chatops_routes;
 
 use axum::{routing::{get, post, put, delete}, Router};
 use crate::state::AppContext;
@@ -27,30 +48,87 @@ pub fn admin_routes() -> Router<AppContext> {
         .route("/allowed-emails", get(settings::get_allowed_emails))
         .route("/allowed-emails", post(settings::add_allowed_email))
         .route("/allowed-emails", delete(settings::remove_allowed_email))
+        .route("/users", get(users::list_users))
+        .route("/users/{id}/role", put(users::set_role))
+        .route("/users/{id}/projects/{project_id}", put(users::set_project_membership))
+        .route("/users/{id}/totp", delete(users::reset_totp))
 }
 
 pub fn api_routes() -> Router<AppContext> {
     Router::new()
+        .route("/auth/tokens", get(api_tokens::list_tokens).post(api_tokens::create_token))
+        .route("/auth/tokens/{id}", delete(api_tokens::delete_token))
+        .route("/auth/totp", get(totp::get_status).delete(totp::disable))
+        .route("/auth/totp/enroll", post(totp::enroll))
+        .route("/auth/totp/activate", post(totp::activate))
+        .route("/auth/totp/verify", post(totp::verify))
+        .route("/dashboard", get(dashboard::get_dashboard))
+        .route("/metrics", get(metrics::get_metrics))
+        .route("/projects/validate", post(project_validation::validate_project))
         .nest("/projects", projects_routes())
         .nest("/builds", builds_routes())
         .nest("/containers", containers_routes())
         .nest("/discord-webhooks", discord_webhooks::discord_webhooks_routes())
+        .nest("/slack-webhooks", slack_webhooks::slack_webhooks_routes())
+        .nest("/secrets", secrets::secrets_routes())
         .route("/projects/{id}/discord-webhook", post(discord_webhooks::set_project_discord_webhook))
+        .route("/projects/{id}/slack-webhook", post(slack_webhooks::set_project_slack_webhook))
+        .route("/projects/{id}/permissions", get(project_permissions::list_permissions).post(project_permissions::grant_permission))
+        .route("/projects/{id}/permissions/{permission_id}", delete(project_permissions::revoke_permission))
+        .route("/projects/{id}/webhooks", get(outbound_webhooks::list_webhooks).post(outbound_webhooks::create_webhook))
+        .route("/projects/{id}/webhooks/{webhook_id}", put(outbound_webhooks::update_webhook).delete(outbound_webhooks::delete_webhook))
+        .route("/projects/{id}/webhooks/{webhook_id}/deliveries", get(outbound_webhooks::list_deliveries))
+        .route("/projects/{id}/previews", get(previews::list_previews))
+        .route("/projects/{id}/previews/{pr}", delete(previews::delete_preview))
         .route("/settings/webhook-secret", get(settings::get_webhook_secret))
+        .route("/settings/webhook-secret/rotate", post(settings::rotate_webhook_secret))
         .route("/settings/domain", post(settings::set_domain))
         .route("/settings/domain", get(settings::get_domain))
         .route("/settings/tcp-domain", post(settings::set_tcp_domain))
         .route("/settings/tcp-domain", get(settings::get_tcp_domain))
         .route("/settings/webhook-url", post(settings::set_webhook_url))
         .route("/settings/webhook-url", get(settings::get_webhook_url))
+        .route("/settings/terminal-access", get(settings::get_terminal_access).post(settings::set_terminal_access))
+        .route("/settings/chatops", get(chatops::get_settings).post(chatops::set_settings))
+        .route("/chatops/links", get(chatops::list_links).post(chatops::create_link))
+        .route("/chatops/links/{id}", delete(chatops::delete_link))
         .route("/settings/server-ip", get(settings::get_server_ip))
+        .route("/settings/disk-quota", get(settings::get_disk_quota).post(settings::set_disk_quota))
+        .route("/settings/queue-wait-alert", get(settings::get_queue_wait_alert).post(settings::set_queue_wait_alert))
+        .route("/settings/smtp", get(settings::get_smtp).post(settings::set_smtp))
+        .route("/settings/test/discord-webhook", post(connectivity::test_discord_webhook))
+        .route("/settings/test/slack-webhook", post(connectivity::test_slack_webhook))
+        .route("/settings/test/smtp", post(connectivity::test_smtp))
+        .route("/settings/test/registry", post(connectivity::test_registry))
+        .route("/settings/test/dns", post(connectivity::test_dns))
+        .route("/settings/stale-build-timeout", get(settings::get_stale_build_timeout).post(settings::set_stale_build_timeout))
+        .route("/settings/image-profiles", get(settings::get_image_profiles).put(settings::set_image_profiles))
+        .route("/settings/log-level-patterns", get(settings::get_log_level_patterns).put(settings::set_log_level_patterns))
+        .route("/settings/cache-limits", get(settings::get_cache_limits).post(settings::set_cache_limits))
+        .route("/settings/concurrency-groups", get(settings::get_concurrency_groups).post(settings::set_concurrency_groups))
+        .route("/settings/cleanup-schedules", get(settings::get_cleanup_schedules))
+        .route("/settings/timezone", get(settings::get_timezone).post(settings::set_timezone))
+        .route("/settings/tls", get(settings::get_tls).post(settings::set_tls))
+        .route("/settings/tls/renew", post(settings::renew_tls))
+        .route("/settings/cleanup-schedules/{worker}", post(settings::set_cleanup_schedule))
         .route("/settings/github-pat", post(github_api::set_github_pat))
         .route("/settings/github-pat", delete(github_api::delete_github_pat))
         .route("/settings/github-pat-status", get(github_api::get_github_pat_status))
+        .route("/settings/github-team-sync", get(github_api::get_team_sync).post(github_api::set_team_sync))
+        .route("/settings/github-team-sync/run", post(github_api::run_team_sync))
+        .route("/settings/gitlab-token", get(gitlab_api::get_gitlab_token_status).post(gitlab_api::set_gitlab_token).delete(gitlab_api::delete_gitlab_token))
+        .route("/settings/bitbucket-token", get(bitbucket_api::get_bitbucket_token_status).post(bitbucket_api::set_bitbucket_token).delete(bitbucket_api::delete_bitbucket_token))
         .route("/github/pats", get(github_api::list_pats).post(github_api::create_pat))
         .route("/github/pats/{id}", get(github_api::get_pat).put(github_api::update_pat).delete(github_api::delete_pat))
         .route("/github/repositories", get(github_api::list_repositories))
         .route("/github/branches", get(github_api::list_branches))
         .route("/github/folders", get(github_api::list_folders))
         .route("/github/detect-project", get(github_api::detect_project))
+        .route("/plugins", get(plugins::list_plugins))
+        .route("/system/cleanup", post(system::trigger_cleanup))
+        .route("/ports/conflicts", get(ports::list_port_conflicts))
+        .route("/ports/conflicts/{port}/resolve", post(ports::resolve_port))
+        .route("/proxy/routes", get(proxy::list_routes))
+        .route("/proxy/reload", post(proxy::reload_routes))
+        .route("/proxy/stats", get(proxy::proxy_stats))
 }
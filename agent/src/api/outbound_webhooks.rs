@@ -0,0 +1,299 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use tracing::warn;
+
+use crate::application::ports::repositories::ProjectRepository;
+use crate::infrastructure::database::OUTBOUND_WEBHOOK_EVENTS;
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::state::AppContext;
+use super::webhook::generate_webhook_secret;
+
+/// 프로젝트당 최대 webhook 수
+const MAX_WEBHOOKS_PER_PROJECT: usize = 10;
+
+/// 전송 기록 조회 기본/최대 개수
+const DEFAULT_DELIVERY_LIMIT: i64 = 50;
+const MAX_DELIVERY_LIMIT: i64 = 100;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateOutboundWebhookRequest {
+    pub url: String,
+    /// 생략하면 모든 이벤트
+    pub events: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateOutboundWebhookRequest {
+    pub url: Option<String>,
+    pub events: Option<Vec<String>>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeliveriesQuery {
+    pub limit: Option<i64>,
+}
+
+fn validate_url(url: &str) -> Result<(), String> {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.host_str().is_some() => Ok(()),
+        _ => Err(format!("Invalid webhook url: {}", url)),
+    }
+}
+
+/// 중복 제거 후 정렬. 알 수 없는 이벤트나 빈 목록이면 에러
+fn normalize_events(events: &[String]) -> Result<Vec<String>, String> {
+    if let Some(unknown) = events.iter().find(|e| !OUTBOUND_WEBHOOK_EVENTS.contains(&e.as_str())) {
+        return Err(format!("Unknown event '{}' (expected: {})", unknown, OUTBOUND_WEBHOOK_EVENTS.join(", ")));
+    }
+    let mut events = events.to_vec();
+    events.sort();
+    events.dedup();
+    if events.is_empty() {
+        return Err("events must not be empty".to_string());
+    }
+    Ok(events)
+}
+
+/// GET /api/projects/{id}/webhooks - 프로젝트 outbound webhook 목록 (secret 제외)
+pub async fn list_webhooks(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(project_id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/webhooks", project_id);
+
+    ctx.logger.api_entry(&trace_id, "GET", &path, "");
+
+    match ctx.outbound_webhook_repo.list_by_project(project_id).await {
+        Ok(webhooks) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({"webhooks": webhooks})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to list outbound webhooks: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// POST /api/projects/{id}/webhooks - webhook 등록 (서명 secret은 이 응답에서만 반환)
+pub async fn create_webhook(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(project_id): Path<i64>,
+    Json(req): Json<CreateOutboundWebhookRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/webhooks", project_id);
+
+    ctx.logger.api_entry(&trace_id, "POST", &path, &format!("url={}", req.url));
+
+    let url = req.url.trim();
+    let events = req.events.unwrap_or_else(|| OUTBOUND_WEBHOOK_EVENTS.iter().map(|e| e.to_string()).collect());
+    let events = match validate_url(url).and_then(|_| normalize_events(&events)) {
+        Ok(events) => events,
+        Err(msg) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 400);
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+        }
+    };
+
+    let existing = async {
+        let project = ctx.project_repo.get(project_id).await?;
+        let count = ctx.outbound_webhook_repo.list_by_project(project_id).await?.len();
+        anyhow::Ok(project.map(|_| count))
+    }
+    .await;
+    match existing {
+        Ok(Some(count)) if count >= MAX_WEBHOOKS_PER_PROJECT => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": format!("A project can have at most {} webhooks", MAX_WEBHOOKS_PER_PROJECT)})),
+            );
+        }
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to load project webhooks: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    }
+
+    let secret = generate_webhook_secret();
+    match ctx.outbound_webhook_repo.create(project_id, url, &secret, &events).await {
+        Ok(webhook) => {
+            tracing::info!(
+                target: "audit",
+                event = "projects.outbound_webhook_created",
+                trace_id = %trace_id,
+                project_id,
+                webhook_id = webhook.id,
+                url = %webhook.url,
+            );
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 201);
+            (StatusCode::CREATED, Json(serde_json::json!({"webhook": webhook, "secret": secret})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to create outbound webhook: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// PUT /api/projects/{id}/webhooks/{webhook_id} - url/events/enabled 변경 (생략한 값은 유지)
+pub async fn update_webhook(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path((project_id, webhook_id)): Path<(i64, i64)>,
+    Json(req): Json<UpdateOutboundWebhookRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/webhooks/{}", project_id, webhook_id);
+
+    ctx.logger.api_entry(&trace_id, "PUT", &path, "");
+
+    let url = req.url.as_deref().map(str::trim);
+    let validated = url
+        .map(validate_url)
+        .transpose()
+        .and_then(|_| req.events.as_deref().map(normalize_events).transpose());
+    let events = match validated {
+        Ok(events) => events,
+        Err(msg) => {
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 400);
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+        }
+    };
+
+    match ctx.outbound_webhook_repo.update(project_id, webhook_id, url, events.as_deref(), req.enabled).await {
+        Ok(Some(webhook)) => {
+            tracing::info!(
+                target: "audit",
+                event = "projects.outbound_webhook_updated",
+                trace_id = %trace_id,
+                project_id,
+                webhook_id,
+                url = %webhook.url,
+                enabled = webhook.enabled,
+            );
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!(webhook)))
+        }
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 404);
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Webhook not found"})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to update outbound webhook: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// DELETE /api/projects/{id}/webhooks/{webhook_id} - webhook과 전송 기록 삭제
+pub async fn delete_webhook(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path((project_id, webhook_id)): Path<(i64, i64)>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/webhooks/{}", project_id, webhook_id);
+
+    ctx.logger.api_entry(&trace_id, "DELETE", &path, "");
+
+    match ctx.outbound_webhook_repo.delete(project_id, webhook_id).await {
+        Ok(true) => {
+            tracing::info!(
+                target: "audit",
+                event = "projects.outbound_webhook_deleted",
+                trace_id = %trace_id,
+                project_id,
+                webhook_id,
+            );
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({"success": true})))
+        }
+        Ok(false) => {
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 404);
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Webhook not found"})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to delete outbound webhook: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// GET /api/projects/{id}/webhooks/{webhook_id}/deliveries - 최근 전송 기록 (최신순)
+pub async fn list_deliveries(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path((project_id, webhook_id)): Path<(i64, i64)>,
+    Query(query): Query<DeliveriesQuery>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/webhooks/{}/deliveries", project_id, webhook_id);
+
+    ctx.logger.api_entry(&trace_id, "GET", &path, "");
+
+    let limit = query.limit.unwrap_or(DEFAULT_DELIVERY_LIMIT).clamp(1, MAX_DELIVERY_LIMIT);
+    let result = async {
+        if ctx.outbound_webhook_repo.get(project_id, webhook_id).await?.is_none() {
+            return anyhow::Ok(None);
+        }
+        Ok(Some(ctx.outbound_webhook_repo.list_deliveries(webhook_id, limit).await?))
+    }
+    .await;
+
+    match result {
+        Ok(Some(deliveries)) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({"deliveries": deliveries})))
+        }
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 404);
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Webhook not found"})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to list webhook deliveries: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_events() {
+        let events = vec!["error".to_string(), "build_status".to_string(), "error".to_string()];
+        assert_eq!(normalize_events(&events).unwrap(), vec!["build_status", "error"]);
+        assert!(normalize_events(&["log".to_string()]).is_err());
+        assert!(normalize_events(&[]).is_err());
+        assert!(validate_url("https://hooks.example.com/ci").is_ok());
+        assert!(validate_url("ftp://hooks.example.com").is_err());
+    }
+}
@@ -0,0 +1,33 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use std::path::Path;
+
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::infrastructure::plugins::{discover_plugins, PLUGIN_DIR};
+use crate::state::AppContext;
+
+/// GET /api/plugins - 검색된 플러그인 목록
+pub async fn list_plugins(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/plugins", "");
+
+    let plugins = discover_plugins(Path::new(PLUGIN_DIR)).await;
+
+    ctx.logger.api_exit(&trace_id, "GET", "/api/plugins", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "plugin_dir": PLUGIN_DIR,
+            "plugins": plugins,
+        })),
+    )
+}
@@ -0,0 +1,122 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use tracing::warn;
+
+use crate::state::AppContext;
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::workers::port_scanner::{detect_port_conflicts, resolve_port_conflict};
+
+/// GET /api/ports/conflicts
+/// port_allocations 기록과 실제 사용(프로젝트/컨테이너, 호스트 스캔)이 어긋난 포트 목록
+pub async fn list_port_conflicts(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/ports/conflicts", "");
+
+    match detect_port_conflicts(&ctx.port_allocation_repo).await {
+        Ok(conflicts) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/ports/conflicts", timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "conflicts": conflicts.iter().map(|c| {
+                        let mut value = serde_json::to_value(c).unwrap_or_default();
+                        value["resolvable"] = serde_json::json!(c.kind.is_resolvable());
+                        value
+                    }).collect::<Vec<_>>(),
+                })),
+            )
+        }
+        Err(e) => {
+            warn!("[{}] Failed to detect port conflicts: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/api/ports/conflicts", timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({ "error": e.to_string() })),
+            )
+        }
+    }
+}
+
+/// POST /api/ports/conflicts/{port}/resolve
+/// 주인 없는 할당은 해제, 기록 안 된 프로젝트/컨테이너 포트는 등록, 알 수 없는 호스트 포트는 외부 사용으로 기록.
+/// 여러 주인이 겹친 포트는 자동 해결하지 않음 (409, 포트 재배정 필요)
+pub async fn resolve_port(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(port): Path<i32>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/ports/conflicts/{}/resolve", port);
+
+    ctx.logger.api_entry(&trace_id, "POST", &path, &format!("port={}", port));
+
+    let conflicts = match detect_port_conflicts(&ctx.port_allocation_repo).await {
+        Ok(conflicts) => conflicts,
+        Err(e) => {
+            warn!("[{}] Failed to detect port conflicts: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({ "error": e.to_string() })),
+            );
+        }
+    };
+
+    let Some(conflict) = conflicts.into_iter().find(|c| c.port == port) else {
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "error": format!("No conflict on port {}", port) })),
+        );
+    };
+
+    if !conflict.kind.is_resolvable() {
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({
+                "error": "Port is claimed by more than one owner; reassign the project's ports instead",
+                "conflict": conflict,
+            })),
+        );
+    }
+
+    match resolve_port_conflict(&ctx.port_allocation_repo, &conflict).await {
+        Ok(_) => {
+            tracing::info!(
+                target: "audit",
+                event = "port.conflict_resolved",
+                trace_id = %trace_id,
+                port = port,
+                kind = ?conflict.kind,
+            );
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "success": true,
+                    "port": port,
+                    "kind": conflict.kind,
+                })),
+            )
+        }
+        Err(e) => {
+            warn!("[{}] Failed to resolve port {} conflict: {}", trace_id, port, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({ "error": e.to_string() })),
+            )
+        }
+    }
+}
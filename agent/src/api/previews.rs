@@ -0,0 +1,133 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use tracing::{info, warn};
+
+use crate::application::ports::repositories::ProjectRepository;
+use crate::state::AppContext;
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::proxy::routes::preview_host;
+
+/// PR 프리뷰 컨테이너와 기록 제거. 프리뷰가 없었으면 false
+pub(crate) async fn teardown_preview(ctx: &AppContext, trace_id: &str, project_id: i64, pr_number: i64) -> anyhow::Result<bool> {
+    ctx.deployment_service.remove_preview(trace_id, project_id, pr_number).await;
+    let removed = ctx.preview_repo.delete(project_id, pr_number).await?;
+
+    if removed {
+        tracing::info!(
+            target: "audit",
+            event = "preview.removed",
+            trace_id = %trace_id,
+            project_id = project_id,
+            pr_number = pr_number,
+        );
+    }
+    Ok(removed)
+}
+
+/// 프로젝트의 모든 PR 프리뷰 제거 (프로젝트 삭제/보관 시). 실패는 경고만 남긴다
+pub(crate) async fn teardown_previews(ctx: &AppContext, trace_id: &str, project_id: i64) {
+    let previews = match ctx.preview_repo.list_by_project(project_id).await {
+        Ok(previews) => previews,
+        Err(e) => {
+            warn!("[{}] Failed to list previews of project {}: {}", trace_id, project_id, e);
+            return;
+        }
+    };
+    for preview in previews {
+        if let Err(e) = teardown_preview(ctx, trace_id, project_id, preview.pr_number).await {
+            warn!("[{}] Failed to remove preview PR #{} of project {}: {}", trace_id, preview.pr_number, project_id, e);
+        }
+    }
+}
+
+/// GET /api/projects/{id}/previews
+/// 열린 PR의 프리뷰 환경 목록 (base_domain이 설정되어 있으면 접속 URL 포함)
+pub async fn list_previews(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/previews", id);
+
+    ctx.logger.api_entry(&trace_id, "GET", &path, &format!("project_id={}", id));
+
+    let project = match ctx.project_repo.get(id).await {
+        Ok(Some(p)) => p,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    match ctx.preview_repo.list_by_project(id).await {
+        Ok(previews) => {
+            let base_domain = ctx.base_domain();
+            let previews: Vec<serde_json::Value> = previews
+                .iter()
+                .map(|preview| {
+                    let mut value = serde_json::to_value(preview).unwrap_or_default();
+                    value["url"] = serde_json::json!(base_domain
+                        .as_deref()
+                        .map(|d| format!("https://{}", preview_host(d, &project.name, preview.pr_number))));
+                    value
+                })
+                .collect();
+
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "enabled": project.pr_previews,
+                    "previews": previews,
+                })),
+            )
+        }
+        Err(e) => {
+            warn!("[{}] Failed to list previews: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// DELETE /api/projects/{id}/previews/{pr}
+/// PR이 열려 있어도 프리뷰 컨테이너를 내림 (다음 push에서 다시 생성)
+pub async fn delete_preview(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path((id, pr_number)): Path<(i64, i64)>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/previews/{}", id, pr_number);
+
+    ctx.logger.api_entry(&trace_id, "DELETE", &path, &format!("project_id={}, pr={}", id, pr_number));
+
+    match teardown_preview(&ctx, &trace_id, id, pr_number).await {
+        Ok(true) => {
+            info!("[{}] Preview PR #{} of project {} removed", trace_id, pr_number, id);
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({"message": "Preview removed"})))
+        }
+        Ok(false) => {
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 404);
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Preview not found"})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to remove preview: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
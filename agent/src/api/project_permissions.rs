@@ -0,0 +1,188 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Extension, Json,
+};
+use serde::Deserialize;
+use tracing::warn;
+
+use crate::application::ports::repositories::ProjectRepository;
+use crate::db::models::User;
+use crate::infrastructure::database::ProjectPermission;
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::state::AppContext;
+use super::settings::normalize_emails;
+
+#[derive(Debug, Deserialize)]
+pub struct GrantPermissionRequest {
+    pub email: String,
+    pub permission: ProjectPermission,
+}
+
+/// GET /api/projects/{id}/permissions - 프로젝트 권한 목록 (비어 있으면 모든 사용자 전체 권한)
+pub async fn list_permissions(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(project_id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/permissions", project_id);
+
+    ctx.logger.api_entry(&trace_id, "GET", &path, "");
+
+    match ctx.project_permission_repo.list_by_project(project_id).await {
+        Ok(grants) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({"restricted": !grants.is_empty(), "permissions": grants})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to list project permissions: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// POST /api/projects/{id}/permissions - 사용자에게 권한 부여
+///
+/// 첫 권한을 부여하는 순간 프로젝트가 제한되므로, 요청자가 잠기지 않도록 요청자에게 manage_settings도 부여
+pub async fn grant_permission(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    user: Option<Extension<User>>,
+    Path(project_id): Path<i64>,
+    Json(req): Json<GrantPermissionRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/permissions", project_id);
+
+    ctx.logger.api_entry(&trace_id, "POST", &path, &format!("email={}, permission={}", req.email, req.permission));
+
+    let Some(Extension(user)) = user else {
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 401);
+        return (StatusCode::UNAUTHORIZED, Json(serde_json::json!({"error": "Authentication required"})));
+    };
+
+    let email = match normalize_emails(std::slice::from_ref(&req.email)) {
+        Ok(emails) if emails.len() == 1 => emails[0].clone(),
+        _ => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 400);
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": format!("Invalid email: {}", req.email)})));
+        }
+    };
+
+    match ctx.project_repo.get(project_id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    }
+
+    let result = async {
+        let first_grant = ctx.project_permission_repo.list_by_project(project_id).await?.is_empty();
+        let grant = ctx.project_permission_repo.grant(project_id, &email, req.permission, Some(&user.email)).await?;
+        let requester_grant = if first_grant {
+            ctx.project_permission_repo
+                .grant(project_id, &user.email, ProjectPermission::ManageSettings, Some(&user.email))
+                .await?
+        } else {
+            None
+        };
+        anyhow::Ok((grant, requester_grant))
+    }
+    .await;
+
+    match result {
+        Ok((Some(grant), requester_grant)) => {
+            tracing::info!(
+                target: "audit",
+                event = "projects.permission_granted",
+                trace_id = %trace_id,
+                project_id,
+                email = %email,
+                permission = %req.permission,
+                user = %user.email,
+            );
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 201);
+            (StatusCode::CREATED, Json(serde_json::json!({"permission": grant, "requester_permission": requester_grant})))
+        }
+        Ok((None, _)) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+            (StatusCode::CONFLICT, Json(serde_json::json!({"error": "Permission already granted"})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to grant project permission: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// DELETE /api/projects/{id}/permissions/{permission_id} - 권한 회수
+///
+/// 다른 권한이 남아 있으면 마지막 manage_settings는 회수할 수 없음 (모두 회수하면 제한 해제)
+pub async fn revoke_permission(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    user: Option<Extension<User>>,
+    Path((project_id, permission_id)): Path<(i64, i64)>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/permissions/{}", project_id, permission_id);
+
+    ctx.logger.api_entry(&trace_id, "DELETE", &path, "");
+
+    let grants = match ctx.project_permission_repo.list_by_project(project_id).await {
+        Ok(grants) => grants,
+        Err(e) => {
+            warn!("[{}] Failed to list project permissions: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+    let Some(target) = grants.iter().find(|g| g.id == permission_id) else {
+        ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 404);
+        return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Permission not found"})));
+    };
+
+    let manage = ProjectPermission::ManageSettings.to_string();
+    let managers = grants.iter().filter(|g| g.permission == manage).count();
+    if target.permission == manage && managers == 1 && grants.len() > 1 {
+        ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({"error": "Cannot revoke the last manage_settings permission while other permissions remain"})),
+        );
+    }
+
+    match ctx.project_permission_repo.revoke(project_id, permission_id).await {
+        Ok(_) => {
+            tracing::info!(
+                target: "audit",
+                event = "projects.permission_revoked",
+                trace_id = %trace_id,
+                project_id,
+                email = %target.user_email,
+                permission = %target.permission,
+                user = user.as_ref().map(|Extension(u)| u.email.as_str()).unwrap_or_default(),
+            );
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({"success": true})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to revoke project permission: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
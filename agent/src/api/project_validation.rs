@@ -0,0 +1,264 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpListener;
+use tokio::process::Command;
+use tracing::warn;
+
+use crate::application::ports::repositories::ProjectRepository;
+use crate::application::ports::git_provider::parse_repo_url;
+use crate::application::services::git_provider_for;
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::state::AppContext;
+use super::projects::validate_docker_image;
+
+/// 프로젝트 설정 검증 요청 (dry-run).
+/// 모든 필드는 선택 — 주어진 항목만 검사한다.
+/// project_id가 있으면 저장된 설정을 기본값으로 사용하고, 요청 필드로 덮어쓴다.
+#[derive(Debug, Default, Deserialize)]
+pub struct ValidateProjectRequest {
+    pub project_id: Option<i64>,
+    pub repo: Option<String>,
+    pub branch: Option<String>,
+    pub build_image: Option<String>,
+    pub build_command: Option<String>,
+    pub runtime_image: Option<String>,
+    pub runtime_command: Option<String>,
+    pub runtime_port: Option<i32>,
+    pub github_pat_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckLevel {
+    Ok,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ValidationCheck {
+    pub check: &'static str,
+    pub level: CheckLevel,
+    pub message: String,
+}
+
+#[derive(Default)]
+struct Checks(Vec<ValidationCheck>);
+
+impl Checks {
+    fn ok(&mut self, check: &'static str, message: impl Into<String>) {
+        self.push(check, CheckLevel::Ok, message);
+    }
+
+    fn warn(&mut self, check: &'static str, message: impl Into<String>) {
+        self.push(check, CheckLevel::Warning, message);
+    }
+
+    fn error(&mut self, check: &'static str, message: impl Into<String>) {
+        self.push(check, CheckLevel::Error, message);
+    }
+
+    fn push(&mut self, check: &'static str, level: CheckLevel, message: impl Into<String>) {
+        self.0.push(ValidationCheck { check, level, message: message.into() });
+    }
+}
+
+/// POST /api/projects/validate - 프로젝트 설정 dry-run 검증.
+/// 아무것도 생성하지 않고 이미지/명령어/포트/저장소 상태를 점검해 결과를 반환한다.
+pub async fn validate_project(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(mut req): Json<ValidateProjectRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/projects/validate", &format!("project_id={:?}", req.project_id));
+
+    // 기존 프로젝트 설정을 기본값으로 채움
+    if let Some(project_id) = req.project_id {
+        match ctx.project_repo.get(project_id).await {
+            Ok(Some(project)) => {
+                req.repo.get_or_insert(project.repo);
+                req.branch.get_or_insert(project.branch);
+                req.build_image.get_or_insert(project.build_image);
+                req.build_command.get_or_insert(project.build_command);
+                req.runtime_image.get_or_insert(project.runtime_image);
+                req.runtime_command.get_or_insert(project.runtime_command);
+                req.runtime_port.get_or_insert(project.runtime_port);
+                if req.github_pat_id.is_none() {
+                    req.github_pat_id = project.github_pat_id;
+                }
+            }
+            Ok(None) => {
+                ctx.logger.api_exit(&trace_id, "POST", "/api/projects/validate", timer.elapsed_ms(), 404);
+                return (
+                    StatusCode::NOT_FOUND,
+                    Json(serde_json::json!({"error": "Project not found"})),
+                );
+            }
+            Err(e) => {
+                warn!("[{}] Failed to get project: {}", trace_id, e);
+                ctx.logger.api_exit(&trace_id, "POST", "/api/projects/validate", timer.elapsed_ms(), 500);
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(serde_json::json!({"error": "Database error"})),
+                );
+            }
+        }
+    }
+
+    let mut checks = Checks::default();
+
+    if let Some(image) = &req.build_image {
+        check_image(&ctx, &mut checks, "build_image", image).await;
+    }
+    if let Some(image) = &req.runtime_image {
+        check_image(&ctx, &mut checks, "runtime_image", image).await;
+    }
+    if let Some(cmd) = &req.build_command {
+        check_command(&mut checks, "build_command", cmd, false).await;
+    }
+    if let Some(cmd) = &req.runtime_command {
+        check_command(&mut checks, "runtime_command", cmd, true).await;
+    }
+    if let Some(port) = req.runtime_port {
+        if (1..=65535).contains(&port) {
+            checks.ok("runtime_port", format!("Container port {} is valid", port));
+        } else {
+            checks.error("runtime_port", format!("Container port {} is out of range (1-65535)", port));
+        }
+    }
+    // 신규 프로젝트일 때만 할당 예정 호스트 포트 점검 (기존 프로젝트는 자기 컨테이너가 점유 중)
+    if req.project_id.is_none() {
+        check_host_ports(&ctx, &mut checks).await;
+    }
+    if let Some(repo) = &req.repo {
+        check_repository(&ctx, &mut checks, repo, req.branch.as_deref(), req.github_pat_id).await;
+    }
+
+    let valid = !checks.0.iter().any(|c| c.level == CheckLevel::Error);
+    let warnings = checks.0.iter().filter(|c| c.level == CheckLevel::Warning).count();
+
+    ctx.logger.api_exit(&trace_id, "POST", "/api/projects/validate", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "valid": valid,
+            "warnings": warnings,
+            "checks": checks.0,
+        })),
+    )
+}
+
+async fn check_image(ctx: &AppContext, checks: &mut Checks, field: &'static str, image: &str) {
+    if !validate_docker_image(image) {
+        checks.error(field, format!("Invalid image name: {}", image));
+        return;
+    }
+
+    match ctx.docker.image_exists(image).await {
+        Ok(true) => checks.ok(field, format!("Image {} is available", image)),
+        Ok(false) => checks.error(field, format!("Image {} was not found locally or in the registry", image)),
+        Err(e) => checks.warn(field, format!("Could not verify image {}: {}", image, e)),
+    }
+}
+
+/// `sh -n`으로 문법만 검사 (실행하지 않음)
+async fn check_command(checks: &mut Checks, field: &'static str, cmd: &str, allow_empty: bool) {
+    if cmd.trim().is_empty() {
+        if allow_empty {
+            checks.warn(field, "Command is empty; the image default command will be used");
+        } else {
+            checks.error(field, "Command is empty");
+        }
+        return;
+    }
+    if cmd.len() > 8192 {
+        checks.error(field, "Command is too long (max 8192 bytes)");
+        return;
+    }
+
+    match Command::new("sh").args(["-n", "-c", cmd]).output().await {
+        Ok(output) if output.status.success() => checks.ok(field, "Command parses"),
+        Ok(output) => checks.error(
+            field,
+            format!("Shell syntax error: {}", String::from_utf8_lossy(&output.stderr).trim()),
+        ),
+        Err(e) => checks.warn(field, format!("Could not run shell syntax check: {}", e)),
+    }
+}
+
+/// 새 프로젝트에 할당될 Blue/Green 포트가 비어있는지 확인
+async fn check_host_ports(ctx: &AppContext, checks: &mut Checks) {
+    let projects = match ctx.project_repo.list_with_deleted().await {
+        Ok(p) => p,
+        Err(e) => {
+            checks.warn("host_ports", format!("Could not load projects: {}", e));
+            return;
+        }
+    };
+
+    // SqliteProjectRepository::create와 같은 규칙 (MAX(green_port) + 1)
+    let base_port = projects.iter().map(|p| p.green_port).max().map(|p| p + 1).unwrap_or(10002);
+
+    for port in [base_port, base_port + 1] {
+        match TcpListener::bind(("0.0.0.0", port as u16)).await {
+            Ok(_) => checks.ok("host_ports", format!("Port {} is free", port)),
+            Err(_) => checks.warn("host_ports", format!("Port {} is already in use on the host", port)),
+        }
+    }
+}
+
+async fn check_repository(
+    ctx: &AppContext,
+    checks: &mut Checks,
+    repo_url: &str,
+    branch: Option<&str>,
+    github_pat_id: Option<i64>,
+) {
+    let repo_ref = match parse_repo_url(repo_url) {
+        Some(parts) => parts,
+        None => {
+            checks.error("repo", format!("Invalid repo URL format: {}", repo_url));
+            return;
+        }
+    };
+    let (owner, repo) = (repo_ref.owner.as_str(), repo_ref.name.as_str());
+
+    // 토큰 결정: GitHub은 지정된 PAT → 레거시 전역 PAT, GitLab은 gitlab_token 설정
+    let client = match git_provider_for(ctx.github_pat_repo.as_ref(), ctx.settings_repo.as_ref(), repo_ref.provider, github_pat_id).await {
+        Ok(Some(client)) => client,
+        Ok(None) => {
+            checks.warn("repo", format!("No {} token available; repository reachability was not checked", repo_ref.provider));
+            return;
+        }
+        Err(e) => {
+            checks.error("github_pat_id", e.to_string());
+            return;
+        }
+    };
+
+    let branch = branch.unwrap_or("main");
+    match client.get_branch(owner, repo, branch).await {
+        Ok(b) => {
+            checks.ok("repo", format!("{}/{} is reachable", owner, repo));
+            checks.ok("branch", format!("Branch {} found at {}", b.name, b.commit.sha));
+        }
+        Err(e) => {
+            // 브랜치 문제인지 저장소 문제인지 구분
+            match client.list_branches(owner, repo).await {
+                Ok(_) => {
+                    checks.ok("repo", format!("{}/{} is reachable", owner, repo));
+                    checks.error("branch", format!("Branch {} not found", branch));
+                }
+                Err(_) => checks.error("repo", format!("{}/{} is not reachable: {}", owner, repo, e)),
+            }
+        }
+    }
+}
@@ -1,30 +1,64 @@
 use axum::{
     extract::{Path, Query, State},
     http::{HeaderMap, StatusCode},
-    response::IntoResponse,
-    routing::{delete, get, post, put},
-    Json, Router,
+    response::{IntoResponse, Response},
+    routing::{get, post, put},
+    Extension, Json, Router,
 };
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
-use tokio::{fs, process::Command};
+use tokio::fs;
 use tracing::{info, warn};
 
-use crate::db::models::{CreateBuild, CreateProject, Project, Slot, UpdateProject};
+use crate::db::models::{BuildNetwork, BuildStatus, BuildTrigger, CreateBuild, CreateProject, DeployWindow, DeploymentStrategy, Project, ProjectCommitStatus, ProjectDependencies, PipelineStage, ProjectHooks, ProjectTestConfig, RestartConfig, RestartPolicy, Slot, SourceFetch, UpdateProject, User, UserRole, normalize_build_labels, validate_dockerfile_path, validate_exec_args, validate_pipeline, MAX_BUILD_NOTE_LEN, MAX_NOTIFICATION_EMAILS, MAX_PROJECT_NOTES_LEN, MAX_TEST_SHARDS};
+use crate::docker::{project_network_name, validate_extra_networks};
 use crate::events::Event;
 use crate::application::events::EventBus;
-use crate::github::client::GitHubClient;
-use crate::state::AppContext;
+use crate::application::services::{check_build_allowed, find_flaky_tests, resolve_github_token, validate_dependencies, validate_deploy_window, BuildRejection};
+use crate::application::services::build_service::{warm_cache_command, warm_cache_log_path};
+use crate::application::ports::git_provider::{parse_repo_url, GitProvider, RepoRef};
+use crate::application::services::git_provider_for;
+use crate::application::services::port_preflight::{host_port_available, HostPortConflict};
+use super::middleware::{has_project_permission, ApiTokenAuth};
+use super::settings::normalize_emails;
+use super::webhook::provider_webhook_url;
+use crate::state::{AppContext, DeploymentHolder, DeploymentOperation};
+use crate::infrastructure::database::{IdempotencyReservation, PortOwner, ProjectPermission, METRICS_BUCKET_SECS, MAX_IDEMPOTENCY_KEY_LEN};
+use crate::infrastructure::timezone;
+use crate::workers::{image_update_check, project_purge};
+use crate::workers::github_team_sync::TeamSyncConfig;
 use crate::infrastructure::logging::{TraceContext, Timer};
-use crate::application::ports::repositories::{ProjectRepository, BuildRepository, SettingsRepository, GitHubPatRepository};
+use crate::application::ports::repositories::{ProjectRepository, BuildRepository, ContainerRepository, SettingsRepository, GitHubPatRepository, ProjectVersionConflict};
 
 pub fn projects_routes() -> Router<AppContext> {
     Router::new()
         .route("/", get(list_projects).post(create_project))
+        .route("/batch", post(batch_project_containers))
+        .route("/deleted", get(list_deleted_projects))
         .route("/{id}", get(get_project).put(update_project).delete(delete_project))
         .route("/{id}/builds", post(trigger_build))
+        .route("/{id}/restore", post(restore_project))
+        .route("/{id}/archive", post(archive_project))
+        .route("/{id}/unarchive", post(unarchive_project))
+        .route("/{id}/ports", put(reassign_project_ports))
+        .route("/{id}/warm-cache", post(warm_cache))
+        .route("/{id}/simulate-webhook", post(super::webhook::simulate_webhook))
+        .route("/{id}/rollback", post(rollback_previous))
+        .route("/{id}/deployments", get(list_deployments))
         .route("/{id}/rollback/{build_id}", post(rollback_build))
+        .route("/{id}/slots/switch", post(switch_slot))
+        .route("/{id}/canary", get(canary_status))
+        .route("/{id}/canary/weight", post(set_canary_weight))
         .route("/{id}/runtime-logs", get(runtime_logs))
+        .route("/{id}/slots/{slot}/terminal", get(super::terminal::project_slot_terminal))
+        .route("/{id}/metrics", get(project_metrics))
+        .route("/{id}/analytics", get(super::analytics::project_build_analytics))
+        .route("/{id}/disk-usage", get(project_disk_usage))
+        .route("/{id}/flaky-tests", get(project_flaky_tests))
+        .route("/{id}/image-updates", get(project_image_updates))
+        .route("/{id}/network", get(project_network).put(update_project_network))
+        .route("/{id}/notes", get(project_notes).put(update_project_notes))
+        .route("/{id}/image-updates/check", post(check_project_image_updates))
         .route("/{id}/containers/start", post(start_containers))
         .route("/{id}/containers/stop", post(stop_containers))
         .route("/{id}/containers/restart", post(restart_containers))
@@ -33,7 +67,7 @@ pub fn projects_routes() -> Router<AppContext> {
 /// Docker 이미지 이름 유효성 검사.
 /// 허용 문자: alphanumeric, '/', ':', '.', '-', '_'
 /// 길이: 1~256자
-fn validate_docker_image(image: &str) -> bool {
+pub(super) fn validate_docker_image(image: &str) -> bool {
     if image.is_empty() || image.len() > 256 {
         return false;
     }
@@ -42,44 +76,214 @@ fn validate_docker_image(image: &str) -> bool {
     })
 }
 
+/// 같은 프로젝트에서 다른 배포 작업이 진행 중일 때의 409 응답 본문
+pub(super) fn deployment_conflict(holder: Option<DeploymentHolder>) -> Json<serde_json::Value> {
+    let message = match &holder {
+        Some(h) => format!("Another {} is in progress for this project (trace_id: {})", h.operation, h.trace_id),
+        None => "Another deployment is in progress for this project".to_string(),
+    };
+    Json(serde_json::json!({
+        "error": message,
+        "operation": holder.as_ref().map(|h| h.operation),
+        "trace_id": holder.as_ref().map(|h| h.trace_id.clone()),
+        "started_at": holder.map(|h| timezone::to_display(&h.started_at)),
+    }))
+}
+
+/// GitHub commit status context 유효성 검사 (1~100자, 앞뒤 공백 없음)
+fn validate_commit_status_context(context: &str) -> bool {
+    !context.is_empty()
+        && context.chars().count() <= 100
+        && context.trim() == context
+        && !context.chars().any(char::is_control)
+}
+
 /// Project response with last build status
 #[derive(Serialize)]
 struct ProjectWithStatus {
     #[serde(flatten)]
     project: Project,
     last_build_status: Option<String>,
+    health: &'static str,
+    /// 마지막 확인에서 빌드/런타임 이미지 업데이트가 발견됨
+    image_update_available: bool,
+    /// easycicd 네트워크에서 서비스 중인 컨테이너에 접근하는 이름
+    network_aliases: Vec<String>,
+}
+
+/// `GET /api/projects` 검색/필터/정렬 파라미터 (모두 선택)
+#[derive(Deserialize, Default)]
+struct ProjectListQuery {
+    /// 이름 부분 일치 (대소문자 무시)
+    q: Option<String>,
+    branch: Option<String>,
+    /// 저장소 URL 부분 일치 (예: "owner/repo")
+    repo: Option<String>,
+    /// 마지막 빌드 상태 (Queued, Building, Success, Failed, Verified, none)
+    status: Option<String>,
+    /// Project::health_state 값
+    health: Option<String>,
+    archived: Option<bool>,
+    /// GitHub 팀 동기화 설정의 팀 slug (그 팀에 매핑된 프로젝트만)
+    team: Option<String>,
+    /// name, created_at, updated_at, last_build (기본 created_at)
+    sort: Option<String>,
+    /// asc, desc (기본: name은 asc, 나머지는 desc)
+    order: Option<String>,
 }
 
+const PROJECT_SORT_KEYS: [&str; 4] = ["name", "created_at", "updated_at", "last_build"];
+const PROJECT_HEALTH_STATES: [&str; 5] = ["healthy", "unhealthy", "deploying", "not_deployed", "archived"];
+
 async fn list_projects(
     State(ctx): State<AppContext>,
     headers: HeaderMap,
-) -> impl IntoResponse {
+    user: Option<Extension<User>>,
+    Query(query): Query<ProjectListQuery>,
+) -> Response {
     let trace_id = TraceContext::extract_or_generate(&headers);
     let timer = Timer::start();
 
     ctx.logger.api_entry(&trace_id, "GET", "/api/projects", "");
 
+    let sort = query.sort.as_deref().unwrap_or("created_at");
+    if !PROJECT_SORT_KEYS.contains(&sort) {
+        ctx.logger.api_exit(&trace_id, "GET", "/api/projects", timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": format!("Invalid sort '{}' (expected one of: {})", sort, PROJECT_SORT_KEYS.join(", "))
+            })),
+        ).into_response();
+    }
+    let descending = match query.order.as_deref() {
+        None => sort != "name",
+        Some("asc") => false,
+        Some("desc") => true,
+        Some(other) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/projects", timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": format!("Invalid order '{}' (expected asc or desc)", other)})),
+            ).into_response();
+        }
+    };
+    if let Some(health) = query.health.as_deref() {
+        if !PROJECT_HEALTH_STATES.contains(&health) {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/projects", timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": format!("Invalid health '{}' (expected one of: {})", health, PROJECT_HEALTH_STATES.join(", "))
+                })),
+            ).into_response();
+        }
+    }
+
+    // 팀 → 프로젝트 매핑은 GitHub 팀 동기화 설정에만 있음
+    let team_projects = match query.team.as_deref().map(str::trim).filter(|t| !t.is_empty()) {
+        None => None,
+        Some(slug) => match TeamSyncConfig::load(ctx.settings_repo.as_ref()).await {
+            Ok(config) => match config.and_then(|c| c.teams.into_iter().find(|t| t.slug.eq_ignore_ascii_case(slug))) {
+                Some(team) => Some(team.project_permissions.iter().map(|p| p.project_id).collect::<std::collections::HashSet<_>>()),
+                None => {
+                    ctx.logger.api_exit(&trace_id, "GET", "/api/projects", timer.elapsed_ms(), 400);
+                    return (
+                        StatusCode::BAD_REQUEST,
+                        Json(serde_json::json!({
+                            "error": format!("Unknown team '{}' (teams come from the GitHub team sync configuration)", slug)
+                        })),
+                    ).into_response();
+                }
+            },
+            Err(e) => {
+                warn!("[{}] Failed to load team sync config: {}", trace_id, e);
+                ctx.logger.api_exit(&trace_id, "GET", "/api/projects", timer.elapsed_ms(), 500);
+                return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"}))).into_response();
+            }
+        },
+    };
+
+    let name_query = query.q.as_deref().map(str::trim).filter(|q| !q.is_empty()).map(str::to_lowercase);
+    let repo_query = query.repo.as_deref().map(str::trim).filter(|r| !r.is_empty()).map(str::to_lowercase);
+
+    // 권한이 설정됐지만 본인 권한이 없는 프로젝트는 목록에서 제외 (admin은 전체)
+    let email = user.as_ref().map(|Extension(u)| u.email.as_str());
+    let is_admin = user.as_ref().is_some_and(|Extension(u)| u.role == UserRole::Admin);
+    let hidden = if is_admin { Ok(Vec::new()) } else { ctx.project_permission_repo.hidden_project_ids(email).await };
+    let hidden = match hidden {
+        Ok(ids) => ids,
+        Err(e) => {
+            warn!("[{}] Failed to load project permissions: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/api/projects", timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"}))).into_response();
+        }
+    };
+
     match ctx.project_repo.list().await {
         Ok(projects) => {
             // Fetch last build status for each project
             let mut projects_with_status = Vec::new();
+            let mut last_build_ids = std::collections::HashMap::new();
             for project in projects {
-                let last_build_status = match ctx.build_repo.get_latest_by_project(project.id).await {
-                    Ok(Some(build)) => Some(build.status.to_string()),
-                    _ => None,
-                };
+                if hidden.contains(&project.id)
+                    || team_projects.as_ref().is_some_and(|ids| !ids.contains(&project.id))
+                    || name_query.as_ref().is_some_and(|q| !project.name.to_lowercase().contains(q.as_str()))
+                    || repo_query.as_ref().is_some_and(|r| !project.repo.to_lowercase().contains(r.as_str()))
+                    || query.branch.as_ref().is_some_and(|b| &project.branch != b)
+                    || query.archived.is_some_and(|a| a != project.archived_at.is_some())
+                    || query.health.as_deref().is_some_and(|h| h != project.health_state())
+                {
+                    continue;
+                }
+
+                let last_build = ctx.build_repo.get_latest_by_project(project.id).await.ok().flatten();
+                let last_build_status = last_build.as_ref().map(|build| build.status.to_string());
+
+                if let Some(status) = query.status.as_deref() {
+                    let matches = match &last_build_status {
+                        Some(s) => s.eq_ignore_ascii_case(status),
+                        None => status.eq_ignore_ascii_case("none"),
+                    };
+                    if !matches {
+                        continue;
+                    }
+                }
+
+                if let Some(build) = &last_build {
+                    last_build_ids.insert(project.id, build.id);
+                }
+                let health = project.health_state();
+                let image_update_available = project.parsed_image_update_status().is_some_and(|s| s.update_available());
+                let network_aliases = project.network_aliases();
                 projects_with_status.push(ProjectWithStatus {
                     project,
                     last_build_status,
+                    health,
+                    image_update_available,
+                    network_aliases,
                 });
             }
+
+            // 목록 조회는 created_at DESC로 정렬되어 있음
+            match sort {
+                "name" => projects_with_status.sort_by_key(|p| p.project.name.to_lowercase()),
+                "updated_at" => projects_with_status.sort_by(|a, b| a.project.updated_at.cmp(&b.project.updated_at)),
+                // 빌드 id는 생성 순서대로 증가, 빌드가 없는 프로젝트가 가장 오래된 것으로 취급
+                "last_build" => projects_with_status.sort_by_key(|p| last_build_ids.get(&p.project.id).copied()),
+                _ => projects_with_status.sort_by(|a, b| a.project.created_at.cmp(&b.project.created_at)),
+            }
+            if descending {
+                projects_with_status.reverse();
+            }
+
             ctx.logger.api_exit(&trace_id, "GET", "/api/projects", timer.elapsed_ms(), 200);
-            (StatusCode::OK, Json(projects_with_status))
+            (StatusCode::OK, Json(projects_with_status)).into_response()
         }
         Err(e) => {
             warn!("[{}] Failed to list projects: {}", trace_id, e);
             ctx.logger.api_exit(&trace_id, "GET", "/api/projects", timer.elapsed_ms(), 500);
-            (StatusCode::INTERNAL_SERVER_ERROR, Json(Vec::<ProjectWithStatus>::new()))
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(Vec::<ProjectWithStatus>::new())).into_response()
         }
     }
 }
@@ -127,13 +331,19 @@ struct CreateProjectRequest {
     runtime_port: i32,
     build_env_vars: Option<String>,
     runtime_env_vars: Option<String>,
+    /// webhook 등록, clone 인증, 상태 보고에 쓸 PAT (없으면 레거시 전역 PAT)
+    #[serde(alias = "pat_id")]
     github_pat_id: Option<i64>,
     discord_webhook_id: Option<i64>,
+    hooks: Option<ProjectHooks>,
+    /// Dockerfile 경로 (working_directory 기준). 있으면 소스로 런타임 이미지를 빌드해 실행
+    dockerfile_path: Option<String>,
 }
 
 async fn create_project(
     State(ctx): State<AppContext>,
     headers: HeaderMap,
+    user: Option<Extension<User>>,
     Json(req): Json<CreateProjectRequest>,
 ) -> impl IntoResponse {
     let trace_id = TraceContext::extract_or_generate(&headers);
@@ -154,6 +364,27 @@ async fn create_project(
         ctx.logger.api_exit(&trace_id, "POST", "/api/projects", timer.elapsed_ms(), 400);
         return (StatusCode::BAD_REQUEST, Json(None));
     }
+    if req.dockerfile_path.as_deref().is_some_and(|p| validate_dockerfile_path(p).is_err()) {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/projects", timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(None));
+    }
+
+    // 지정한 PAT가 없으면 다른 계정으로 조용히 대체되지 않도록 생성 전에 거부
+    if let Some(pat_id) = req.github_pat_id {
+        match ctx.github_pat_repo.get(pat_id).await {
+            Ok(Some(_)) => {}
+            Ok(None) => {
+                warn!("[{}] GitHub PAT {} not found", trace_id, pat_id);
+                ctx.logger.api_exit(&trace_id, "POST", "/api/projects", timer.elapsed_ms(), 400);
+                return (StatusCode::BAD_REQUEST, Json(None));
+            }
+            Err(e) => {
+                warn!("[{}] Failed to get GitHub PAT: {}", trace_id, e);
+                ctx.logger.api_exit(&trace_id, "POST", "/api/projects", timer.elapsed_ms(), 500);
+                return (StatusCode::INTERNAL_SERVER_ERROR, Json(None));
+            }
+        }
+    }
 
     let repo_url = req.repo.clone();
     let github_pat_id = req.github_pat_id;
@@ -174,6 +405,8 @@ async fn create_project(
         runtime_env_vars: req.runtime_env_vars,
         github_pat_id,
         discord_webhook_id: req.discord_webhook_id,
+        hooks: req.hooks.map(|h| serde_json::to_string(&h).unwrap_or_default()),
+        dockerfile_path: req.dockerfile_path,
     };
 
     let project = match ctx.project_repo.create(create_project).await {
@@ -185,6 +418,15 @@ async fn create_project(
         }
     };
 
+    // developer가 만든 프로젝트는 만든 사람을 멤버로 등록 (멤버가 아니면 빌드/배포 불가)
+    if let Some(Extension(user)) = user.as_ref().filter(|Extension(u)| u.role == UserRole::Developer) {
+        for permission in [ProjectPermission::Deploy, ProjectPermission::ViewLogs, ProjectPermission::ManageSettings] {
+            if let Err(e) = ctx.project_permission_repo.grant(project.id, &user.email, permission, Some(&user.email)).await {
+                warn!("[{}] Failed to grant {} to project creator: {}", trace_id, permission, e);
+            }
+        }
+    }
+
     // Register GitHub webhook
     if let Err(e) = register_github_webhook(&ctx, &trace_id, project.id, &repo_url).await {
         warn!("[{}] Failed to register GitHub webhook: {}", trace_id, e);
@@ -198,125 +440,108 @@ async fn create_project(
     (StatusCode::CREATED, Json(Some(final_project)))
 }
 
-/// Helper function to register GitHub webhook for a project
+/// API로 직접 실행한 빌드의 trigger (API 토큰이면 `api-token:{토큰 이름}:{이메일}`)
+pub(super) fn manual_trigger(user: Option<Extension<User>>, api_token: Option<Extension<ApiTokenAuth>>) -> BuildTrigger {
+    match (api_token, user) {
+        (Some(Extension(token)), Some(Extension(user))) => BuildTrigger::ApiToken(format!("{}:{}", token.name, user.email)),
+        (_, user) => BuildTrigger::Manual(user.map(|Extension(u)| u.email)),
+    }
+}
+
+/// 빌드 거부 응답 (보관 409, 디스크 쿼터 초과 507)
+pub(super) fn build_rejection_response(rejection: BuildRejection) -> (StatusCode, Json<serde_json::Value>) {
+    match rejection {
+        BuildRejection::Archived => (StatusCode::CONFLICT, Json(serde_json::json!({"error": rejection.message()}))),
+        BuildRejection::QuotaExceeded(status) => (
+            StatusCode::INSUFFICIENT_STORAGE,
+            Json(serde_json::json!({
+                "error": status.error_message(),
+                "disk_usage": status,
+            })),
+        ),
+    }
+}
+
+/// 프로젝트에 지정된 PAT (없으면 레거시 전역 PAT)
+pub(super) async fn project_github_token(ctx: &AppContext, project_id: i64) -> Result<String, String> {
+    let github_pat_id = ctx.project_repo.get(project_id).await
+        .map_err(|e| format!("Failed to get project: {}", e))?
+        .and_then(|p| p.github_pat_id);
+
+    resolve_github_token(ctx.github_pat_repo.as_ref(), ctx.settings_repo.as_ref(), github_pat_id).await
+        .map_err(|e| format!("Failed to get GitHub token: {}", e))?
+        .ok_or_else(|| "GitHub token not configured".to_string())
+}
+
+/// 프로젝트 저장소의 provider API 클라이언트와 owner/repo
+async fn project_git_provider(
+    ctx: &AppContext,
+    project_id: i64,
+    repo_url: &str,
+) -> Result<(Box<dyn GitProvider>, RepoRef), String> {
+    let repo_ref = parse_repo_url(repo_url)
+        .ok_or_else(|| format!("Invalid repo URL format: {}", repo_url))?;
+    let github_pat_id = ctx.project_repo.get(project_id).await
+        .map_err(|e| format!("Failed to get project: {}", e))?
+        .and_then(|p| p.github_pat_id);
+
+    let provider = git_provider_for(ctx.github_pat_repo.as_ref(), ctx.settings_repo.as_ref(), repo_ref.provider, github_pat_id).await
+        .map_err(|e| format!("Failed to get {} token: {}", repo_ref.provider, e))?
+        .ok_or_else(|| format!("{} token not configured", repo_ref.provider))?;
+    Ok((provider, repo_ref))
+}
+
+/// Helper function to register GitHub/GitLab/Bitbucket webhook for a project
 async fn register_github_webhook(
     ctx: &AppContext,
     trace_id: &str,
     project_id: i64,
     repo_url: &str,
 ) -> Result<(), String> {
-    // Resolve PAT: try project-specific PAT first, then fallback to legacy settings
-    let github_token = match ctx.github_pat_repo.get_token_for_project(project_id).await {
-        Ok(Some(token)) => token,
-        _ => {
-            // Fallback to legacy global PAT
-            ctx.settings_repo.get("github_pat").await
-                .map_err(|e| format!("Failed to get GitHub token: {}", e))?
-                .ok_or("GitHub token not configured")?
-        }
-    };
+    let (provider, repo_ref) = project_git_provider(ctx, project_id, repo_url).await?;
 
     let webhook_url = ctx.settings_repo.get("webhook_url").await
         .map_err(|e| format!("Failed to get webhook URL: {}", e))?
         .ok_or("Webhook URL not configured")?;
+    let webhook_url = provider_webhook_url(&webhook_url, repo_ref.provider);
 
     let webhook_secret = ctx.settings_repo.get("webhook_secret").await
         .map_err(|e| format!("Failed to get webhook secret: {}", e))?
         .ok_or("Webhook secret not configured")?;
 
-    // Parse owner/repo from repo URL (e.g., "owner/repo" or "https://github.com/owner/repo")
-    let (owner, repo) = parse_repo_owner_name(repo_url)
-        .ok_or_else(|| format!("Invalid repo URL format: {}", repo_url))?;
-
-    info!("[{}] Registering GitHub webhook for {}/{}", trace_id, owner, repo);
+    info!("[{}] Registering {} webhook for {}", trace_id, repo_ref.provider, repo_ref.full_name());
 
-    // Create GitHub client and register webhook
-    let github_client = GitHubClient::new(github_token);
-    let webhook = github_client.create_webhook(&owner, &repo, &webhook_url, &webhook_secret)
+    let webhook_id = provider.create_webhook(&repo_ref.owner, &repo_ref.name, &webhook_url, &webhook_secret)
         .await
-        .map_err(|e| format!("GitHub API error: {}", e))?;
+        .map_err(|e| format!("{} API error: {}", repo_ref.provider, e))?;
 
-    info!("[{}] GitHub webhook registered successfully: id={}", trace_id, webhook.id);
+    info!("[{}] {} webhook registered successfully: id={}", trace_id, repo_ref.provider, webhook_id);
 
     // Update project with webhook ID
-    ctx.project_repo.update_webhook_id(project_id, Some(webhook.id as i64))
+    ctx.project_repo.update_webhook_id(project_id, Some(&webhook_id))
         .await
         .map_err(|e| format!("Failed to update project with webhook ID: {}", e))?;
 
     Ok(())
 }
 
-/// Parse owner and repo name from various repo URL formats
-fn parse_repo_owner_name(repo_url: &str) -> Option<(String, String)> {
-    // Handle formats like:
-    // - "owner/repo"
-    // - "https://github.com/owner/repo"
-    // - "https://github.com/owner/repo.git"
-    // - "git@github.com:owner/repo.git"
-
-    let cleaned = repo_url
-        .trim()
-        .trim_end_matches(".git")
-        .trim_end_matches('/');
-
-    // Try to extract from URL format
-    if cleaned.contains("github.com") {
-        // HTTPS format: https://github.com/owner/repo
-        if let Some(path) = cleaned.strip_prefix("https://github.com/") {
-            let parts: Vec<&str> = path.split('/').collect();
-            if parts.len() >= 2 {
-                return Some((parts[0].to_string(), parts[1].to_string()));
-            }
-        }
-        // SSH format: git@github.com:owner/repo
-        if let Some(path) = cleaned.strip_prefix("git@github.com:") {
-            let parts: Vec<&str> = path.split('/').collect();
-            if parts.len() >= 2 {
-                return Some((parts[0].to_string(), parts[1].to_string()));
-            }
-        }
-    }
-
-    // Simple format: owner/repo
-    let parts: Vec<&str> = cleaned.split('/').collect();
-    if parts.len() == 2 && !parts[0].is_empty() && !parts[1].is_empty() {
-        return Some((parts[0].to_string(), parts[1].to_string()));
-    }
-
-    None
-}
-
-/// Helper function to delete GitHub webhook for a project
+/// Helper function to delete GitHub/GitLab/Bitbucket webhook for a project
 async fn delete_github_webhook(
     ctx: &AppContext,
     trace_id: &str,
     project_id: i64,
     repo_url: &str,
-    webhook_id: i64,
+    webhook_id: &str,
 ) -> Result<(), String> {
-    // Resolve PAT: try project-specific PAT first, then fallback to legacy settings
-    let github_token = match ctx.github_pat_repo.get_token_for_project(project_id).await {
-        Ok(Some(token)) => token,
-        _ => {
-            ctx.settings_repo.get("github_pat").await
-                .map_err(|e| format!("Failed to get GitHub token: {}", e))?
-                .ok_or("GitHub token not configured")?
-        }
-    };
-
-    // Parse owner/repo from repo URL
-    let (owner, repo) = parse_repo_owner_name(repo_url)
-        .ok_or_else(|| format!("Invalid repo URL format: {}", repo_url))?;
+    let (provider, repo_ref) = project_git_provider(ctx, project_id, repo_url).await?;
 
-    info!("[{}] Deleting GitHub webhook {} for {}/{}", trace_id, webhook_id, owner, repo);
+    info!("[{}] Deleting {} webhook {} for {}", trace_id, repo_ref.provider, webhook_id, repo_ref.full_name());
 
-    // Create GitHub client and delete webhook
-    let github_client = GitHubClient::new(github_token);
-    github_client.delete_webhook(&owner, &repo, webhook_id as u64)
+    provider.delete_webhook(&repo_ref.owner, &repo_ref.name, webhook_id)
         .await
-        .map_err(|e| format!("GitHub API error: {}", e))?;
+        .map_err(|e| format!("{} API error: {}", repo_ref.provider, e))?;
 
-    info!("[{}] GitHub webhook {} deleted successfully", trace_id, webhook_id);
+    info!("[{}] {} webhook {} deleted successfully", trace_id, repo_ref.provider, webhook_id);
 
     Ok(())
 }
@@ -341,6 +566,90 @@ struct UpdateProjectRequest {
     github_pat_id: Option<Option<i64>>,
     #[serde(default)]
     discord_webhook_id: Option<Option<i64>>,
+    #[serde(default)]
+    slack_webhook_id: Option<Option<i64>>,
+    hooks: Option<ProjectHooks>,
+    /// null이면 전역 기본값으로 되돌림
+    #[serde(default)]
+    disk_quota_mb: Option<Option<i64>>,
+    /// null이면 테스트 단계 비활성화
+    #[serde(default)]
+    test_config: Option<Option<ProjectTestConfig>>,
+    /// "git" 또는 "tarball"
+    source_fetch: Option<SourceFetch>,
+    /// "bridge", "isolated" 또는 "none"
+    build_network: Option<BuildNetwork>,
+    /// 빌드 컨테이너에서 docker 명령 허용 (bridge 네트워크에서만 동작)
+    docker_access: Option<bool>,
+    /// 빌드 동시 실행 그룹 (예: "heavy-java"). null이면 그룹 해제
+    #[serde(default)]
+    concurrency_group: Option<Option<String>>,
+    /// 배포 허용 시간대. 창 밖에서 성공한 빌드는 다음 창까지 대기. null이면 항상 배포
+    #[serde(default)]
+    deploy_window: Option<Option<DeployWindow>>,
+    /// GitHub commit status context 설정. null이면 보고 중단
+    #[serde(default)]
+    commit_status: Option<Option<ProjectCommitStatus>>,
+    /// 빌드/런타임 이미지의 upstream digest가 바뀌면 자동 재빌드
+    auto_rebuild_on_image_update: Option<bool>,
+    /// false면 런타임 컨테이너 포트를 호스트에 바인딩하지 않음 (다음 배포부터 적용)
+    expose_host_port: Option<bool>,
+    /// true면 프록시 라우팅 없이 easycicd 네트워크 alias로만 접근
+    internal_only: Option<bool>,
+    /// 의존하는 독립 컨테이너/프로젝트 (SERVICE_<NAME>_HOST/PORT 주입, 다음 배포부터 적용). null이면 해제
+    #[serde(default)]
+    dependencies: Option<Option<ProjectDependencies>>,
+    /// true면 GitHub에서 서명이 검증된 커밋만 배포 (검증되지 않은 커밋은 빌드 후 배포 차단)
+    require_signed_commits: Option<bool>,
+    /// true면 전환 후 이전 빌드 컨테이너를 비활성 슬롯에 계속 실행 (`POST /slots/switch`, 롤백 즉시 처리)
+    warm_standby: Option<bool>,
+    /// 전환 전 새 슬롯으로 복제할 운영 요청 비율 (0~100, 0이면 사용 안 함)
+    shadow_traffic_percent: Option<i32>,
+    /// 트래픽 섀도잉 시간 (초, 5~600)
+    shadow_duration_secs: Option<i32>,
+    /// true면 GitHub pull request마다 `pr-{number}.{name}.{base_domain}` 프리뷰 환경 실행
+    pr_previews: Option<bool>,
+    /// "blue_green" 또는 "canary" (다음 배포부터 적용)
+    deployment_strategy: Option<DeploymentStrategy>,
+    /// 런타임 컨테이너를 추가로 연결할 기존 Docker 네트워크 (다음 배포부터 적용). null이면 해제
+    #[serde(default)]
+    extra_networks: Option<Option<Vec<String>>>,
+    /// 순서대로 실행할 빌드 단계. null이면 build_command 하나로 실행
+    #[serde(default)]
+    pipeline: Option<Option<Vec<PipelineStage>>>,
+    /// 런타임 이미지 ENTRYPOINT 대체 (exec 형식). 설정하면 runtime_command 대신 사용. null이면 해제
+    #[serde(default)]
+    runtime_entrypoint: Option<Option<Vec<String>>>,
+    /// 런타임 CMD 인자 (exec 형식, 셸을 거치지 않음). null이면 해제
+    #[serde(default)]
+    runtime_args: Option<Option<Vec<String>>>,
+    /// 런타임 컨테이너 재시작 정책: no / on-failure / unless-stopped / always (다음 배포부터 적용)
+    runtime_restart_policy: Option<RestartPolicy>,
+    /// on-failure 최대 재시도 횟수. null이면 무제한
+    #[serde(default)]
+    runtime_restart_max_retries: Option<Option<i64>>,
+    /// Dockerfile 경로 (working_directory 기준). 설정하면 소스로 런타임 이미지를 빌드해 실행. null이면 해제
+    #[serde(default)]
+    dockerfile_path: Option<Option<String>>,
+    /// 보존할 최근 성공 빌드 수. 넘는 빌드의 산출물/로그/기록을 정리. null이면 해제
+    #[serde(default)]
+    build_retention_count: Option<Option<i64>>,
+    /// 빌드 최대 보존 일수. null이면 해제
+    #[serde(default)]
+    build_retention_days: Option<Option<i64>>,
+    /// 이메일 알림 수신자 (빌드 실패/배포 결과, SMTP 설정 필요). null이면 해제
+    #[serde(default)]
+    notification_emails: Option<Option<Vec<String>>>,
+    /// 편집을 시작할 때 받은 프로젝트 version (`If-Match` 헤더로도 전달 가능)
+    version: Option<i64>,
+}
+
+/// `If-Match: "3"` 또는 `If-Match: 3`
+fn if_match_version(headers: &HeaderMap) -> Option<i64> {
+    headers
+        .get(axum::http::header::IF_MATCH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().trim_start_matches("W/").trim_matches('"').parse().ok())
 }
 
 async fn update_project(
@@ -374,9 +683,91 @@ async fn update_project(
         }
     }
 
+    if let Some(Some(quota)) = req.disk_quota_mb {
+        if quota <= 0 {
+            ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "disk_quota_mb must be positive"})));
+        }
+    }
+
+    if req.build_retention_count.flatten().is_some_and(|n| n < 1) || req.build_retention_days.flatten().is_some_and(|d| d < 1) {
+        ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "build_retention_count and build_retention_days must be positive"})),
+        );
+    }
+
+    let notification_emails = match req.notification_emails.clone().map(|e| e.map(|e| normalize_emails(&e))) {
+        Some(Some(Err(invalid))) => {
+            ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": format!("Invalid email: {}", invalid)})));
+        }
+        Some(Some(Ok(emails))) if emails.len() > MAX_NOTIFICATION_EMAILS => {
+            ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": format!("At most {} notification_emails allowed", MAX_NOTIFICATION_EMAILS)})),
+            );
+        }
+        Some(Some(Ok(emails))) => Some(Some(emails).filter(|e| !e.is_empty())),
+        Some(None) => Some(None),
+        None => None,
+    };
+
+    if let Some(Some(ref test_config)) = req.test_config {
+        if test_config.command.trim().is_empty() || test_config.command.len() > 8192 {
+            ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "Invalid test_config.command"})));
+        }
+        if test_config.shards == 0 || test_config.shards > MAX_TEST_SHARDS {
+            ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": format!("test_config.shards must be between 1 and {}", MAX_TEST_SHARDS)})),
+            );
+        }
+    }
+
+    if let Some(Some(ref group)) = req.concurrency_group {
+        if !crate::build::validate_concurrency_group(group) {
+            ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": "concurrency_group must be 1-64 characters of a-z, 0-9, '-' or '_'"})),
+            );
+        }
+    }
+
+    if let Some(Some(ref window)) = req.deploy_window {
+        if let Err(msg) = validate_deploy_window(window) {
+            ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+        }
+    }
+
+    if let Some(Some(ref status)) = req.commit_status {
+        if !validate_commit_status_context(&status.context) {
+            ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": "commit_status.context must be 1-100 characters without leading/trailing spaces"})),
+            );
+        }
+    }
+
+    if req.shadow_traffic_percent.is_some_and(|p| !(0..=100).contains(&p)) {
+        ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "shadow_traffic_percent must be between 0 and 100"})));
+    }
+    if req.shadow_duration_secs.is_some_and(|s| !(5..=600).contains(&s)) {
+        ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "shadow_duration_secs must be between 5 and 600"})));
+    }
+
     // Check if project exists
-    match ctx.project_repo.get(id).await {
-        Ok(Some(_)) => {}
+    let current = match ctx.project_repo.get(id).await {
+        Ok(Some(project)) => project,
         Ok(None) => {
             ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 404);
             return (
@@ -392,6 +783,88 @@ async fn update_project(
                 Json(serde_json::json!({"error": "Database error"})),
             );
         }
+    };
+
+    if let Some(Some(ref deps)) = req.dependencies {
+        let project_name = req.name.as_deref().unwrap_or(&current.name);
+        if let Err(msg) = validate_dependencies(project_name, deps) {
+            ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+        }
+        let mut unknown = Vec::new();
+        for name in &deps.containers {
+            if !matches!(ctx.container_repo.get_by_name(name).await, Ok(Some(_))) {
+                unknown.push(format!("container '{}'", name));
+            }
+        }
+        for name in &deps.projects {
+            if !matches!(ctx.project_repo.get_by_name(name).await, Ok(Some(_))) {
+                unknown.push(format!("project '{}'", name));
+            }
+        }
+        if !unknown.is_empty() {
+            ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": format!("Unknown dependencies: {}", unknown.join(", "))})),
+            );
+        }
+    }
+
+    if let Some(Some(ref networks)) = req.extra_networks {
+        if let Err(msg) = validate_extra_networks(networks, ctx.docker.network()) {
+            ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+        }
+        let mut unknown = Vec::new();
+        for name in networks {
+            if !ctx.docker.network_exists(name).await {
+                unknown.push(name.as_str());
+            }
+        }
+        if !unknown.is_empty() {
+            ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": format!("Unknown Docker networks: {}", unknown.join(", "))})),
+            );
+        }
+    }
+
+    if let Some(Some(ref stages)) = req.pipeline {
+        if let Err(msg) = validate_pipeline(stages) {
+            ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+        }
+    }
+
+    let exec_check = req
+        .runtime_entrypoint
+        .as_ref()
+        .and_then(|e| e.as_deref())
+        .map_or(Ok(()), |e| validate_exec_args("runtime_entrypoint", e, false))
+        .and_then(|_| {
+            req.runtime_args
+                .as_ref()
+                .and_then(|a| a.as_deref())
+                .map_or(Ok(()), |a| validate_exec_args("runtime_args", a, true))
+        });
+    if let Err(msg) = exec_check {
+        ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+    }
+
+    if let Some(Err(msg)) = req.dockerfile_path.as_ref().and_then(|p| p.as_deref()).map(validate_dockerfile_path) {
+        ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+    }
+
+    if let Some(Some(max_retries)) = req.runtime_restart_max_retries {
+        let restart = RestartConfig { policy: RestartPolicy::OnFailure, max_retries: Some(max_retries) };
+        if let Err(msg) = restart.validate() {
+            ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 400);
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+        }
     }
 
     let update = UpdateProject {
@@ -411,6 +884,37 @@ async fn update_project(
         runtime_env_vars: req.runtime_env_vars,
         github_pat_id: req.github_pat_id,
         discord_webhook_id: req.discord_webhook_id,
+        slack_webhook_id: req.slack_webhook_id,
+        hooks: req.hooks.map(|h| serde_json::to_string(&h).unwrap_or_default()),
+        disk_quota_mb: req.disk_quota_mb,
+        test_config: req.test_config.map(|c| c.map(|c| serde_json::to_string(&c).unwrap_or_default())),
+        source_fetch: req.source_fetch,
+        build_network: req.build_network,
+        docker_access: req.docker_access,
+        concurrency_group: req.concurrency_group,
+        deploy_window: req.deploy_window.map(|w| w.map(|w| serde_json::to_string(&w).unwrap_or_default())),
+        commit_status: req.commit_status.map(|c| c.map(|c| serde_json::to_string(&c).unwrap_or_default())),
+        auto_rebuild_on_image_update: req.auto_rebuild_on_image_update,
+        expose_host_port: req.expose_host_port,
+        internal_only: req.internal_only,
+        dependencies: req.dependencies.map(|d| d.map(|d| serde_json::to_string(&d).unwrap_or_default())),
+        require_signed_commits: req.require_signed_commits,
+        warm_standby: req.warm_standby,
+        shadow_traffic_percent: req.shadow_traffic_percent,
+        shadow_duration_secs: req.shadow_duration_secs,
+        pr_previews: req.pr_previews,
+        deployment_strategy: req.deployment_strategy,
+        extra_networks: req.extra_networks.map(|n| n.map(|n| serde_json::to_string(&n).unwrap_or_default())),
+        pipeline: req.pipeline.map(|p| p.map(|p| serde_json::to_string(&p).unwrap_or_default())),
+        runtime_entrypoint: req.runtime_entrypoint.map(|e| e.map(|e| serde_json::to_string(&e).unwrap_or_default())),
+        runtime_args: req.runtime_args.map(|a| a.map(|a| serde_json::to_string(&a).unwrap_or_default())),
+        runtime_restart_policy: req.runtime_restart_policy,
+        runtime_restart_max_retries: req.runtime_restart_max_retries,
+        dockerfile_path: req.dockerfile_path,
+        build_retention_count: req.build_retention_count,
+        build_retention_days: req.build_retention_days,
+        notification_emails: notification_emails.map(|e| e.map(|e| serde_json::to_string(&e).unwrap_or_default())),
+        expected_version: req.version.or_else(|| if_match_version(&headers)),
     };
 
     match ctx.project_repo.update(id, update).await {
@@ -419,6 +923,18 @@ async fn update_project(
             ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 200);
             (StatusCode::OK, Json(serde_json::json!(project)))
         }
+        Err(e) if e.downcast_ref::<ProjectVersionConflict>().is_some() => {
+            warn!("[{}] Project update conflict: {}", trace_id, e);
+            let current = ctx.project_repo.get(id).await.ok().flatten();
+            ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 409);
+            (
+                StatusCode::CONFLICT,
+                Json(serde_json::json!({
+                    "error": "Project was modified by someone else. Reload and apply your changes again.",
+                    "current": current,
+                })),
+            )
+        }
         Err(e) => {
             warn!("[{}] Failed to update project: {}", trace_id, e);
             ctx.logger.api_exit(&trace_id, "PUT", &format!("/api/projects/{}", id), timer.elapsed_ms(), 500);
@@ -430,21 +946,128 @@ async fn update_project(
     }
 }
 
+#[derive(Deserialize, Default)]
+pub(super) struct TriggerBuildRequest {
+    /// true면 clone + build + 산출물 검증만 하고 배포/슬롯 전환은 생략 (결과: Verified)
+    #[serde(default)]
+    pub(super) dry_run: bool,
+    /// 빌드 메모 (예: "hotfix for incident #12"). 알림과 배포 이력에 표시
+    #[serde(default)]
+    pub(super) note: Option<String>,
+    #[serde(default)]
+    pub(super) labels: Vec<String>,
+}
+
+const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// 빌드 트리거. `Idempotency-Key` 헤더가 있으면 같은 key의 재요청에는
+/// 새 빌드를 만들지 않고 처음 응답을 그대로 반환한다 (IDEMPOTENCY_TTL_HOURS 동안).
 async fn trigger_build(
     State(ctx): State<AppContext>,
     headers: HeaderMap,
     Path(id): Path<i64>,
+    user: Option<Extension<User>>,
+    api_token: Option<Extension<ApiTokenAuth>>,
+    body: Option<Json<TriggerBuildRequest>>,
 ) -> impl IntoResponse {
     let trace_id = TraceContext::extract_or_generate(&headers);
+    let req = body.map(|Json(r)| r).unwrap_or_default();
+
+    let trigger = manual_trigger(user, api_token);
+
+    let key = match headers.get(IDEMPOTENCY_KEY_HEADER).map(|v| v.to_str()) {
+        None => return create_manual_build(&ctx, &trace_id, id, trigger, req).await,
+        Some(Ok(key)) if !key.trim().is_empty() && key.len() <= MAX_IDEMPOTENCY_KEY_LEN => key.trim().to_string(),
+        Some(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": format!("Idempotency-Key must be 1-{} visible ASCII characters", MAX_IDEMPOTENCY_KEY_LEN)
+                })),
+            );
+        }
+    };
+    let scope = format!("build:{}", id);
+
+    match ctx.idempotency_repo.reserve(&scope, &key).await {
+        Ok(IdempotencyReservation::Reserved) => {}
+        Ok(IdempotencyReservation::InProgress) => {
+            info!("[{}] Build request with idempotency key '{}' is still in progress", trace_id, key);
+            return (
+                StatusCode::CONFLICT,
+                Json(serde_json::json!({
+                    "error": "A request with this Idempotency-Key is still being processed"
+                })),
+            );
+        }
+        Ok(IdempotencyReservation::Completed(status, mut response)) => {
+            info!("[{}] Replaying build response for idempotency key '{}'", trace_id, key);
+            if let Some(obj) = response.as_object_mut() {
+                obj.insert("idempotent_replay".to_string(), serde_json::Value::Bool(true));
+            }
+            return (
+                StatusCode::from_u16(status).unwrap_or(StatusCode::OK),
+                Json(response),
+            );
+        }
+        Err(e) => {
+            warn!("[{}] Failed to reserve idempotency key: {}", trace_id, e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    }
+
+    let (status, Json(response)) = create_manual_build(&ctx, &trace_id, id, trigger, req).await;
+
+    // 성공한 응답만 재사용, 실패하면 같은 key로 재시도할 수 있게 해제
+    let stored = if status.is_success() {
+        ctx.idempotency_repo.complete(&scope, &key, status.as_u16(), &response).await
+    } else {
+        ctx.idempotency_repo.release(&scope, &key).await
+    };
+    if let Err(e) = stored {
+        warn!("[{}] Failed to update idempotency key '{}': {}", trace_id, key, e);
+    }
+
+    (status, Json(response))
+}
+
+/// 수동 빌드 생성 + 큐 등록 (API, ChatOps 공용)
+pub(super) async fn create_manual_build(
+    ctx: &AppContext,
+    trace_id: &str,
+    id: i64,
+    trigger: BuildTrigger,
+    req: TriggerBuildRequest,
+) -> (StatusCode, Json<serde_json::Value>) {
     let timer = Timer::start();
 
-    ctx.logger.api_entry(&trace_id, "POST", &format!("/api/projects/{}/builds", id), &format!("project_id={}", id));
+    ctx.logger.api_entry(trace_id, "POST", &format!("/api/projects/{}/builds", id), &format!("project_id={}, dry_run={}", id, req.dry_run));
+
+    let note = req.note.as_deref().map(str::trim).filter(|n| !n.is_empty()).map(str::to_string);
+    if note.as_ref().is_some_and(|n| n.chars().count() > MAX_BUILD_NOTE_LEN) {
+        ctx.logger.api_exit(trace_id, "POST", &format!("/api/projects/{}/builds", id), timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": format!("note too long (max {} characters)", MAX_BUILD_NOTE_LEN)})),
+        );
+    }
+    let labels = match normalize_build_labels(&req.labels) {
+        Ok(labels) => labels,
+        Err(msg) => {
+            ctx.logger.api_exit(trace_id, "POST", &format!("/api/projects/{}/builds", id), timer.elapsed_ms(), 400);
+            return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+        }
+    };
 
     // Get project
     let project = match ctx.project_repo.get(id).await {
-        Ok(Some(p)) => p,
-        Ok(None) => {
-            ctx.logger.api_exit(&trace_id, "POST", &format!("/api/projects/{}/builds", id), timer.elapsed_ms(), 404);
+        // 삭제 유예 중인 프로젝트는 빌드하지 않음
+        Ok(Some(p)) if p.deleted_at.is_none() => p,
+        Ok(_) => {
+            ctx.logger.api_exit(trace_id, "POST", &format!("/api/projects/{}/builds", id), timer.elapsed_ms(), 404);
             return (
                 StatusCode::NOT_FOUND,
                 Json(serde_json::json!({"error": "Project not found"})),
@@ -452,7 +1075,7 @@ async fn trigger_build(
         }
         Err(e) => {
             warn!("[{}] Failed to get project: {}", trace_id, e);
-            ctx.logger.api_exit(&trace_id, "POST", &format!("/api/projects/{}/builds", id), timer.elapsed_ms(), 500);
+            ctx.logger.api_exit(trace_id, "POST", &format!("/api/projects/{}/builds", id), timer.elapsed_ms(), 500);
             return (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(serde_json::json!({"error": "Database error"})),
@@ -460,84 +1083,33 @@ async fn trigger_build(
         }
     };
 
-    // Get current commit hash from workspace
-    let workspace_path = PathBuf::from("/data/workspace")
-        .join(&project.name);
-
-    let commit_hash = if workspace_path.exists() {
-        // Get current commit hash
-        match Command::new("git")
-            .args(&["-C", workspace_path.to_str().unwrap(), "rev-parse", "HEAD"])
-            .output()
-            .await
-        {
-            Ok(output) if output.status.success() => {
-                String::from_utf8_lossy(&output.stdout).trim().to_string()
-            }
-            _ => "HEAD".to_string(),
-        }
-    } else {
-        "HEAD".to_string()
-    };
-
-    // Get commit message and author
-    let (commit_message, author) = if workspace_path.exists() && commit_hash != "HEAD" {
-        let message = Command::new("git")
-            .args(&[
-                "-C",
-                workspace_path.to_str().unwrap(),
-                "log",
-                "-1",
-                "--format=%s",
-            ])
-            .output()
-            .await
-            .ok()
-            .and_then(|o| {
-                if o.status.success() {
-                    Some(String::from_utf8_lossy(&o.stdout).trim().to_string())
-                } else {
-                    None
-                }
-            });
-
-        let author = Command::new("git")
-            .args(&[
-                "-C",
-                workspace_path.to_str().unwrap(),
-                "log",
-                "-1",
-                "--format=%an <%ae>",
-            ])
-            .output()
-            .await
-            .ok()
-            .and_then(|o| {
-                if o.status.success() {
-                    Some(String::from_utf8_lossy(&o.stdout).trim().to_string())
-                } else {
-                    None
-                }
-            });
+    // 보관된 프로젝트, 디스크 쿼터 초과 시 빌드 거부
+    if let Err(rejection) = check_build_allowed(&ctx.disk_quota_service, trace_id, &project).await {
+        let (status, body) = build_rejection_response(rejection);
+        ctx.logger.api_exit(trace_id, "POST", &format!("/api/projects/{}/builds", id), timer.elapsed_ms(), status.as_u16());
+        return (status, body);
+    }
 
-        (message, author)
-    } else {
-        (None, None)
-    };
+    let commit = ctx.project_service.head_commit(trace_id, &project).await;
 
     // Create build
     let create_build = CreateBuild {
         project_id: project.id,
-        commit_hash: commit_hash.clone(),
-        commit_message,
-        author,
+        commit_hash: commit.hash,
+        commit_message: commit.message,
+        author: commit.author,
+        dry_run: req.dry_run,
+        triggered_by: Some(trigger.to_string()),
+        rebuild_of: None,
+        branch: Some(project.branch.clone()),
+        preview_pr: None,
     };
 
     let build = match ctx.build_repo.create(create_build).await {
         Ok(b) => b,
         Err(e) => {
             warn!("[{}] Failed to create build: {}", trace_id, e);
-            ctx.logger.api_exit(&trace_id, "POST", &format!("/api/projects/{}/builds", id), timer.elapsed_ms(), 500);
+            ctx.logger.api_exit(trace_id, "POST", &format!("/api/projects/{}/builds", id), timer.elapsed_ms(), 500);
             return (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(serde_json::json!({"error": "Failed to create build"})),
@@ -545,30 +1117,65 @@ async fn trigger_build(
         }
     };
 
+    // 큐에 넣기 전에 메모 저장 (시작/성공 알림에 포함)
+    if note.is_some() || !labels.is_empty() {
+        let labels_json = (!labels.is_empty()).then(|| serde_json::to_string(&labels).unwrap_or_default());
+        if let Err(e) = ctx.build_repo.update_annotation(build.id, note.clone(), labels_json).await {
+            warn!("[{}] Failed to save build annotation: {}", trace_id, e);
+        }
+    }
+
     // Enqueue build
-    ctx.build_queue.enqueue(project.id, build.id).await;
+    ctx.build_queue.enqueue_new(&ctx.event_bus, project.id, build.id).await;
+
+    tracing::info!(
+        target: "audit",
+        event = "build.triggered",
+        trace_id = %trace_id,
+        project_id = project.id,
+        build_id = build.id,
+        triggered_by = build.triggered_by.as_deref().unwrap_or_default(),
+    );
 
-    ctx.logger.api_exit(&trace_id, "POST", &format!("/api/projects/{}/builds", id), timer.elapsed_ms(), 201);
+    ctx.logger.api_exit(trace_id, "POST", &format!("/api/projects/{}/builds", id), timer.elapsed_ms(), 201);
 
     (
         StatusCode::CREATED,
         Json(serde_json::json!({
             "build_id": build.id,
-            "commit_hash": commit_hash,
+            "commit_hash": build.commit_hash,
+            "branch": build.branch,
+            "dry_run": build.dry_run,
+            "triggered_by": build.triggered_by,
+            "note": note,
+            "labels": labels,
             "message": "Build triggered successfully"
         })),
     )
 }
 
+#[derive(Deserialize, Default)]
+struct DeleteProjectQuery {
+    /// true면 유예 기간 없이 즉시 삭제
+    #[serde(default)]
+    purge: bool,
+}
+
+/// 프로젝트 삭제
+///
+/// 기본은 soft delete: 컨테이너를 멈추고 GitHub webhook을 해제한 뒤 deleted_at만 기록한다.
+/// 설정/빌드/로그는 유예 기간 동안 남아 `POST /api/projects/{id}/restore`로 복원할 수 있고,
+/// 이후 project purge worker가 실제로 삭제한다. `?purge=true`면 즉시 삭제.
 async fn delete_project(
     State(ctx): State<AppContext>,
     headers: HeaderMap,
     Path(id): Path<i64>,
+    Query(query): Query<DeleteProjectQuery>,
 ) -> impl IntoResponse {
     let trace_id = TraceContext::extract_or_generate(&headers);
     let timer = Timer::start();
 
-    ctx.logger.api_entry(&trace_id, "DELETE", &format!("/api/projects/{}", id), &format!("project_id={}", id));
+    ctx.logger.api_entry(&trace_id, "DELETE", &format!("/api/projects/{}", id), &format!("project_id={}, purge={}", id, query.purge));
 
     // Get project first
     let project = match ctx.project_repo.get(id).await {
@@ -590,11 +1197,24 @@ async fn delete_project(
         }
     };
 
+    if project.deleted_at.is_some() && !query.purge {
+        ctx.logger.api_exit(&trace_id, "DELETE", &format!("/api/projects/{}", id), timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({"error": "Project is already deleted (use ?purge=true to delete it now)"})),
+        );
+    }
+
     // Delete GitHub webhook if exists
-    if let Some(webhook_id) = project.github_webhook_id {
-        if let Err(e) = delete_github_webhook(&ctx, &trace_id, project.id, &project.repo, webhook_id).await {
-            warn!("[{}] Failed to delete GitHub webhook: {}", trace_id, e);
+    if let Some(webhook_id) = project.webhook_id() {
+        match delete_github_webhook(&ctx, &trace_id, project.id, &project.repo, &webhook_id).await {
+            Ok(()) => {
+                if let Err(e) = ctx.project_repo.update_webhook_id(project.id, None).await {
+                    warn!("[{}] Failed to clear webhook ID: {}", trace_id, e);
+                }
+            }
             // Continue with project deletion even if webhook deletion fails
+            Err(e) => warn!("[{}] Failed to delete GitHub webhook: {}", trace_id, e),
         }
     }
 
@@ -626,51 +1246,1510 @@ async fn delete_project(
         }
     }
 
+    super::previews::teardown_previews(&ctx, &trace_id, project.id).await;
+
+    if !query.purge {
+        if let Err(e) = ctx.project_repo.mark_deleted(id).await {
+            warn!("[{}] Failed to mark project as deleted: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "DELETE", &format!("/api/projects/{}", id), timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Failed to delete project"})),
+            );
+        }
+
+        let purge_after = ctx.project_repo.get(id).await.ok().flatten()
+            .and_then(|p| p.deleted_at)
+            .and_then(|deleted_at| project_purge::purge_after(&deleted_at, project_purge::grace_days()))
+            .map(|t| t.with_timezone(&timezone::display_timezone()).to_rfc3339());
+
+        tracing::info!(
+            target: "audit",
+            event = "project.deleted",
+            trace_id = %trace_id,
+            project_id = project.id,
+            project_name = %project.name,
+            purge_after = ?purge_after,
+        );
+
+        info!("[{}] Project {} marked as deleted", trace_id, project.name);
+        ctx.logger.api_exit(&trace_id, "DELETE", &format!("/api/projects/{}", id), timer.elapsed_ms(), 200);
+        return (
+            StatusCode::OK,
+            Json(serde_json::json!({
+                "message": "Project deleted. It can be restored until purge_after",
+                "purge_after": purge_after,
+            })),
+        );
+    }
+
     // Remove directories
     let workspace_path = PathBuf::from("/data/workspace").join(&project.name);
     let output_base = PathBuf::from("/data/output");
     let cache_path = PathBuf::from("/data/cache").join(&project.cache_type);
     let logs_path = PathBuf::from("/data/easycicd/logs").join(&project.name);
 
-    for path in [workspace_path, cache_path, logs_path] {
-        if path.exists() {
-            info!("[{}] Removing directory: {:?}", trace_id, path);
-            if let Err(e) = fs::remove_dir_all(&path).await {
-                warn!("[{}] Failed to remove directory {:?}: {}", trace_id, path, e);
+    for path in [workspace_path, cache_path, logs_path] {
+        if path.exists() {
+            info!("[{}] Removing directory: {:?}", trace_id, path);
+            if let Err(e) = fs::remove_dir_all(&path).await {
+                warn!("[{}] Failed to remove directory {:?}: {}", trace_id, path, e);
+            }
+        }
+    }
+
+    // Remove only build output directories for this specific project
+    // First, get all builds for this project to know which directories to delete
+    if let Ok(builds) = ctx.build_repo.list_by_project(id, 10000).await {
+        for build in builds {
+            let build_output_path = output_base.join(format!("build{}", build.id));
+            if build_output_path.exists() {
+                info!("[{}] Removing build output: {:?}", trace_id, build_output_path);
+                if let Err(e) = fs::remove_dir_all(&build_output_path).await {
+                    warn!("[{}] Failed to remove build output {:?}: {}", trace_id, build_output_path, e);
+                }
+            }
+        }
+    }
+
+    // Delete from database (this will cascade delete builds due to ON DELETE CASCADE)
+    if let Err(e) = ctx.project_repo.delete(id).await {
+        warn!("[{}] Failed to delete project from database: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "DELETE", &format!("/api/projects/{}", id), timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": "Failed to delete project"})),
+        );
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "project.purged",
+        trace_id = %trace_id,
+        project_id = project.id,
+        project_name = %project.name,
+    );
+
+    info!("[{}] Project {} deleted successfully", trace_id, project.name);
+    ctx.logger.api_exit(&trace_id, "DELETE", &format!("/api/projects/{}", id), timer.elapsed_ms(), 200);
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({"message": "Project deleted successfully"})),
+    )
+}
+
+/// 유예 기간 중인(soft delete된) 프로젝트 목록
+async fn list_deleted_projects(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/projects/deleted", "");
+
+    match ctx.project_repo.list_deleted().await {
+        Ok(projects) => {
+            let grace = project_purge::grace_days();
+            let projects: Vec<serde_json::Value> = projects
+                .into_iter()
+                .map(|project| {
+                    let purge_after = project
+                        .deleted_at
+                        .as_deref()
+                        .and_then(|deleted_at| project_purge::purge_after(deleted_at, grace))
+                        .map(|t| t.with_timezone(&timezone::display_timezone()).to_rfc3339());
+                    serde_json::json!({
+                        "id": project.id,
+                        "name": project.name,
+                        "repo": project.repo,
+                        "deleted_at": project.deleted_at.as_deref().map(timezone::to_display),
+                        "purge_after": purge_after,
+                    })
+                })
+                .collect();
+            ctx.logger.api_exit(&trace_id, "GET", "/api/projects/deleted", timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({ "projects": projects })))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to list deleted projects: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/api/projects/deleted", timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Failed to list deleted projects"})),
+            )
+        }
+    }
+}
+
+/// Soft delete된 프로젝트 복원 (GitHub webhook 재등록, 남아있는 컨테이너 재시작)
+async fn restore_project(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/restore", id);
+
+    ctx.logger.api_entry(&trace_id, "POST", &path, &format!("project_id={}", id));
+
+    let project = match ctx.project_repo.get(id).await {
+        Ok(Some(p)) => p,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+            return (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({"error": "Project not found"})),
+            );
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    };
+
+    match ctx.project_repo.restore(id).await {
+        Ok(true) => {}
+        Ok(false) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+            return (
+                StatusCode::CONFLICT,
+                Json(serde_json::json!({"error": "Project is not deleted"})),
+            );
+        }
+        Err(e) => {
+            warn!("[{}] Failed to restore project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Failed to restore project"})),
+            );
+        }
+    }
+
+    let mut warnings = Vec::new();
+
+    if project.webhook_id().is_none() {
+        if let Err(e) = register_github_webhook(&ctx, &trace_id, project.id, &project.repo).await {
+            warn!("[{}] Failed to re-register GitHub webhook: {}", trace_id, e);
+            warnings.push(format!("GitHub webhook: {}", e));
+        }
+    }
+
+    // 삭제 시 멈춘 컨테이너 재시작 (없으면 다음 빌드에서 배포)
+    if project.blue_container_id.is_some() || project.green_container_id.is_some() {
+        match ctx.project_service.start_containers(&trace_id, project.id).await {
+            Ok(results) => warnings.extend(
+                results
+                    .into_iter()
+                    .filter_map(|r| r.error.map(|e| format!("{} container: {}", r.slot, e))),
+            ),
+            Err(e) => warnings.push(format!("Containers: {}", e)),
+        }
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "project.restored",
+        trace_id = %trace_id,
+        project_id = project.id,
+        project_name = %project.name,
+    );
+
+    let restored = ctx.project_repo.get(id).await.ok().flatten().unwrap_or(project);
+    info!("[{}] Project {} restored", trace_id, restored.name);
+    ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "project": restored,
+            "warnings": warnings,
+        })),
+    )
+}
+
+/// 배포 전 포트 확인 실패(HostPortConflict)면 409 응답 body
+fn host_port_conflict_body(e: &anyhow::Error) -> Option<serde_json::Value> {
+    let conflict = e.downcast_ref::<HostPortConflict>()?;
+    Some(serde_json::json!({
+        "error": conflict.to_string(),
+        "port": conflict.port,
+        "owner": conflict.owner,
+        "suggested_port": conflict.suggested_port,
+    }))
+}
+
+/// 프로젝트 보관
+///
+/// Blue/Green 컨테이너를 멈추고 제거한 뒤 GitHub webhook을 해제한다.
+/// 설정/빌드/로그는 그대로 남고, 포트는 port scanner에서 사용 중으로 보지 않는다.
+/// `POST /api/projects/{id}/unarchive`로 되돌린 뒤 빌드나 롤백으로 다시 배포한다.
+async fn archive_project(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/archive", id);
+
+    ctx.logger.api_entry(&trace_id, "POST", &path, &format!("project_id={}", id));
+
+    let project = match ctx.project_repo.get(id).await {
+        Ok(Some(p)) if p.deleted_at.is_none() => p,
+        Ok(_) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+            return (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({"error": "Project not found"})),
+            );
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    };
+
+    if project.archived_at.is_some() {
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({"error": "Project is already archived"})),
+        );
+    }
+
+    // 진행 중인 빌드가 배포하면서 컨테이너를 다시 띄우지 않도록 대기
+    if ctx.build_queue.is_processing(project.id).await {
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({"error": "A build is in progress for this project. Try again after it finishes"})),
+        );
+    }
+
+    let mut warnings = Vec::new();
+
+    if let Some(webhook_id) = project.webhook_id() {
+        match delete_github_webhook(&ctx, &trace_id, project.id, &project.repo, &webhook_id).await {
+            Ok(()) => {
+                if let Err(e) = ctx.project_repo.update_webhook_id(project.id, None).await {
+                    warn!("[{}] Failed to clear webhook ID: {}", trace_id, e);
+                }
+            }
+            Err(e) => {
+                warn!("[{}] Failed to delete GitHub webhook: {}", trace_id, e);
+                warnings.push(format!("GitHub webhook: {}", e));
+            }
+        }
+    }
+
+    // 컨테이너를 제거해야 호스트 포트가 해제됨 (ID가 DB에 없을 수도 있어 이름으로도 시도)
+    for slot in [Slot::Blue, Slot::Green] {
+        let (container_id, container_name) = match slot {
+            Slot::Blue => (&project.blue_container_id, format!("project-{}-blue", project.id)),
+            Slot::Green => (&project.green_container_id, format!("project-{}-green", project.id)),
+        };
+
+        for target in container_id.iter().chain(std::iter::once(&container_name)) {
+            if let Err(e) = ctx.docker.stop_container(target).await {
+                info!("[{}] Container {} not stopped: {}", trace_id, target, e);
+            }
+            if let Err(e) = ctx.docker.remove_container(target).await {
+                info!("[{}] Container {} not removed: {}", trace_id, target, e);
+            }
+        }
+
+        let cleared = match slot {
+            Slot::Blue => ctx.project_repo.update_blue_container(project.id, None).await,
+            Slot::Green => ctx.project_repo.update_green_container(project.id, None).await,
+        };
+        if let Err(e) = cleared {
+            warn!("[{}] Failed to clear {:?} container ID: {}", trace_id, slot, e);
+        }
+    }
+
+    super::previews::teardown_previews(&ctx, &trace_id, project.id).await;
+
+    if let Err(e) = ctx.project_repo.set_archived(project.id, true).await {
+        warn!("[{}] Failed to archive project: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": "Failed to archive project"})),
+        );
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "project.archived",
+        trace_id = %trace_id,
+        project_id = project.id,
+        project_name = %project.name,
+    );
+
+    let archived = ctx.project_repo.get(id).await.ok().flatten().unwrap_or(project);
+    info!("[{}] Project {} archived", trace_id, archived.name);
+    ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "project": archived,
+            "warnings": warnings,
+        })),
+    )
+}
+
+/// 프로젝트 보관 해제
+///
+/// 보관 중 다른 프로세스가 Blue/Green 포트를 차지했으면 새 포트를 배정한다.
+/// 컨테이너는 띄우지 않으므로 빌드를 트리거하거나 이전 빌드로 롤백해서 배포한다.
+async fn unarchive_project(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/unarchive", id);
+
+    ctx.logger.api_entry(&trace_id, "POST", &path, &format!("project_id={}", id));
+
+    let project = match ctx.project_repo.get(id).await {
+        Ok(Some(p)) if p.deleted_at.is_none() => p,
+        Ok(_) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+            return (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({"error": "Project not found"})),
+            );
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    };
+
+    if project.archived_at.is_none() {
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({"error": "Project is not archived"})),
+        );
+    }
+
+    let mut ports_reassigned = false;
+    if !host_port_available(project.blue_port) || !host_port_available(project.green_port) {
+        match ctx.project_repo.reassign_ports(project.id).await {
+            Ok((blue_port, green_port)) => {
+                info!(
+                    "[{}] Ports {}/{} are in use, reassigned project {} to {}/{}",
+                    trace_id, project.blue_port, project.green_port, project.name, blue_port, green_port
+                );
+                ports_reassigned = true;
+            }
+            Err(e) => {
+                warn!("[{}] Failed to reassign ports: {}", trace_id, e);
+                ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(serde_json::json!({"error": "Failed to reassign ports"})),
+                );
+            }
+        }
+    }
+
+    if let Err(e) = ctx.project_repo.set_archived(project.id, false).await {
+        warn!("[{}] Failed to unarchive project: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": "Failed to unarchive project"})),
+        );
+    }
+
+    let mut warnings = Vec::new();
+
+    if project.webhook_id().is_none() {
+        if let Err(e) = register_github_webhook(&ctx, &trace_id, project.id, &project.repo).await {
+            warn!("[{}] Failed to re-register GitHub webhook: {}", trace_id, e);
+            warnings.push(format!("GitHub webhook: {}", e));
+        }
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "project.unarchived",
+        trace_id = %trace_id,
+        project_id = project.id,
+        project_name = %project.name,
+        ports_reassigned = ports_reassigned,
+    );
+
+    let unarchived = ctx.project_repo.get(id).await.ok().flatten().unwrap_or(project);
+    info!("[{}] Project {} unarchived", trace_id, unarchived.name);
+    ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "message": "Project unarchived. Trigger a build or roll back to a previous build to deploy it",
+            "project": unarchived,
+            "ports_reassigned": ports_reassigned,
+            "warnings": warnings,
+        })),
+    )
+}
+
+#[derive(Deserialize)]
+struct ReassignPortsRequest {
+    blue_port: Option<i32>,
+    green_port: Option<i32>,
+}
+
+/// 프로젝트 Blue/Green 호스트 포트 변경
+///
+/// 새 포트가 다른 프로젝트/컨테이너에 배정됐거나 `port_allocations`에 외부 사용으로 기록됐거나
+/// 호스트에서 이미 쓰이면 409. 생략한 슬롯은 현재 포트 유지.
+/// 서비스 중인 빌드가 있으면 비활성 슬롯에 새 포트로 다시 띄운 뒤 전환한다 (무중단).
+/// 리버스 프록시는 요청마다 활성 슬롯 컨테이너 이름으로 라우팅하므로 전환과 함께 반영된다.
+async fn reassign_project_ports(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(req): Json<ReassignPortsRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/ports", id);
+
+    ctx.logger.api_entry(&trace_id, "PUT", &path, &format!("blue_port={:?}, green_port={:?}", req.blue_port, req.green_port));
+
+    let project = match ctx.project_repo.get(id).await {
+        Ok(Some(p)) if p.deleted_at.is_none() => p,
+        Ok(_) => {
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 404);
+            return (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({"error": "Project not found"})),
+            );
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    };
+
+    if project.archived_at.is_some() {
+        ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({"error": "Project is archived. Unarchive it before changing ports"})),
+        );
+    }
+
+    if ctx.build_queue.is_processing(project.id).await {
+        ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({"error": "A build is in progress. Try again after it finishes"})),
+        );
+    }
+
+    // 포트 변경 후 재배포까지 다른 배포/롤백과 겹치지 않도록 잠금
+    let _deployment_guard = match ctx.deployment_locks.try_acquire(project.id, DeploymentOperation::Redeploy, &trace_id) {
+        Ok(guard) => guard,
+        Err(holder) => {
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 409);
+            return (StatusCode::CONFLICT, deployment_conflict(holder));
+        }
+    };
+
+    let blue_port = req.blue_port.unwrap_or(project.blue_port);
+    let green_port = req.green_port.unwrap_or(project.green_port);
+
+    if !(1024..=65535).contains(&blue_port) || !(1024..=65535).contains(&green_port) || blue_port == green_port {
+        ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "Ports must be two different values between 1024 and 65535"})),
+        );
+    }
+
+    if blue_port == project.blue_port && green_port == project.green_port {
+        ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 200);
+        return (
+            StatusCode::OK,
+            Json(serde_json::json!({
+                "project": project,
+                "changed": false,
+            })),
+        );
+    }
+
+    let (owners, allocations) = match tokio::try_join!(
+        ctx.port_allocation_repo.owners(),
+        ctx.port_allocation_repo.list(),
+    ) {
+        Ok(result) => result,
+        Err(e) => {
+            warn!("[{}] Failed to load port allocations: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    };
+
+    let is_own = |owner_type: Option<&str>, owner_id: Option<i64>| {
+        owner_type == Some("project") && owner_id == Some(project.id)
+    };
+    let mut conflicts = Vec::new();
+    for port in [blue_port, green_port] {
+        // 현재 이 프로젝트가 쓰는 포트 (슬롯 간 교체 포함)는 검사하지 않음
+        if port == project.blue_port || port == project.green_port {
+            continue;
+        }
+        let reason = if let Some(owner) = owners.iter().find(|o| o.port == port && !is_own(Some(o.owner_type.as_str()), Some(o.owner_id))) {
+            Some(format!("assigned to {} '{}'", owner.owner_type, owner.name))
+        } else if let Some(allocation) = allocations.iter().find(|a| a.port == port && !is_own(a.owner_type.as_deref(), a.owner_id)) {
+            Some(format!("recorded as {} in port_allocations", allocation.status))
+        } else if !host_port_available(port) {
+            Some("in use on the host".to_string())
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            conflicts.push(serde_json::json!({"port": port, "reason": reason}));
+        }
+    }
+
+    if !conflicts.is_empty() {
+        ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({
+                "error": "Requested ports are not available",
+                "conflicts": conflicts,
+            })),
+        );
+    }
+
+    if let Err(e) = ctx.project_repo.set_ports(project.id, blue_port, green_port).await {
+        warn!("[{}] Failed to update ports: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": "Failed to update ports"})),
+        );
+    }
+
+    // port_allocations 갱신 (실패해도 port scanner GC가 다음 주기에 맞춤)
+    for old_port in [project.blue_port, project.green_port] {
+        if old_port != blue_port && old_port != green_port {
+            if let Err(e) = ctx.port_allocation_repo.release_if_older(old_port, 0).await {
+                warn!("[{}] Failed to release port {}: {}", trace_id, old_port, e);
+            }
+        }
+    }
+    for port in [blue_port, green_port] {
+        let owner = PortOwner {
+            port,
+            port_type: "application".to_string(),
+            owner_type: "project".to_string(),
+            owner_id: project.id,
+            name: project.name.clone(),
+        };
+        if let Err(e) = ctx.port_allocation_repo.register(&owner).await {
+            warn!("[{}] Failed to register port {}: {}", trace_id, port, e);
+        }
+    }
+
+    let updated = match ctx.project_repo.get(id).await {
+        Ok(Some(p)) => p,
+        _ => Project { blue_port, green_port, ..project.clone() },
+    };
+
+    let mut warnings = Vec::new();
+    let mut redeployed_slot = None;
+    let has_container = match updated.active_slot {
+        Slot::Blue => updated.blue_container_id.is_some(),
+        Slot::Green => updated.green_container_id.is_some(),
+    };
+    if has_container {
+        match ctx.deployment_service.redeploy_current(&trace_id, &updated).await {
+            Ok(slot) => redeployed_slot = slot,
+            Err(e) => {
+                warn!("[{}] Failed to redeploy on new ports: {}", trace_id, e);
+                warnings.push(format!("Redeploy failed, the running container still uses the old port: {}", e));
+            }
+        }
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "project.ports_reassigned",
+        trace_id = %trace_id,
+        project_id = project.id,
+        project_name = %project.name,
+        old_blue_port = project.blue_port,
+        old_green_port = project.green_port,
+        blue_port = blue_port,
+        green_port = green_port,
+    );
+
+    let project = ctx.project_repo.get(id).await.ok().flatten().unwrap_or(updated);
+    ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "project": project,
+            "changed": true,
+            "redeployed_slot": redeployed_slot,
+            "warnings": warnings,
+        })),
+    )
+}
+
+#[derive(Deserialize)]
+struct MetricsQuery {
+    range: Option<String>,
+}
+
+/// "30m", "24h", "7d" 형식의 조회 범위를 초 단위로 변환 (최대 30일)
+fn parse_metrics_range(range: &str) -> Option<i64> {
+    let range = range.trim();
+    let unit = range.chars().last()?;
+    let value: i64 = range[..range.len() - unit.len_utf8()].parse().ok().filter(|v| *v > 0)?;
+    let secs = match unit {
+        'm' => value.checked_mul(60)?,
+        'h' => value.checked_mul(3600)?,
+        'd' => value.checked_mul(86400)?,
+        _ => return None,
+    };
+    (secs <= 30 * 86400).then_some(secs)
+}
+
+/// POST /api/projects/{id}/warm-cache
+/// 의존성 해석 단계만 백그라운드로 실행해 빌드 캐시를 미리 채움 (빌드 기록/배포 없음)
+async fn warm_cache(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", &format!("/api/projects/{}/warm-cache", id), &format!("project_id={}", id));
+
+    let project = match ctx.project_repo.get(id).await {
+        Ok(Some(p)) => p,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "POST", &format!("/api/projects/{}/warm-cache", id), timer.elapsed_ms(), 404);
+            return (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({"error": "Project not found"})),
+            );
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &format!("/api/projects/{}/warm-cache", id), timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    };
+
+    let Some(command) = warm_cache_command(&project.cache_type, &project.build_command) else {
+        ctx.logger.api_exit(&trace_id, "POST", &format!("/api/projects/{}/warm-cache", id), timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": format!("Cache warming is not supported for cache type '{}'", project.cache_type)
+            })),
+        );
+    };
+
+    tracing::info!(
+        target: "audit",
+        event = "project.cache_warm_started",
+        trace_id = %trace_id,
+        project_id = id,
+        cache_type = %project.cache_type,
+    );
+
+    // 의존성 다운로드는 수 분 걸릴 수 있으므로 백그라운드에서 실행
+    tokio::spawn({
+        let ctx = ctx.clone();
+        let trace_id = trace_id.clone();
+        async move {
+            match ctx.build_service.warm_cache(&trace_id, &project).await {
+                Ok(result) if result.success => {
+                    info!("[{}] Cache warming for project {} completed", trace_id, project.name);
+                }
+                Ok(result) => {
+                    warn!(
+                        "[{}] Cache warming for project {} failed with exit code {}",
+                        trace_id, project.name, result.exit_code
+                    );
+                }
+                Err(e) => warn!("[{}] Cache warming for project {} failed: {}", trace_id, project.name, e),
+            }
+        }
+    });
+
+    ctx.logger.api_exit(&trace_id, "POST", &format!("/api/projects/{}/warm-cache", id), timer.elapsed_ms(), 202);
+    (
+        StatusCode::ACCEPTED,
+        Json(serde_json::json!({
+            "status": "started",
+            "command": command,
+            "log_path": warm_cache_log_path(id).display().to_string(),
+        })),
+    )
+}
+
+#[derive(Debug, Deserialize)]
+struct FlakyTestsQuery {
+    /// 최근 몇 개 빌드를 볼지 (기본 20, 최대 200)
+    builds: Option<i64>,
+    /// 빌드 간 pass/fail 전환이 몇 번 이상이면 flaky로 볼지 (기본 2)
+    min_flips: Option<u32>,
+}
+
+/// GET /api/projects/{id}/flaky-tests?builds=20&min_flips=2
+/// 최근 빌드에서 pass/fail이 번갈아 나오거나 재실행으로 통과한 테스트 목록
+async fn project_flaky_tests(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Query(query): Query<FlakyTestsQuery>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let builds = query.builds.unwrap_or(20).clamp(2, 200);
+    let min_flips = query.min_flips.unwrap_or(2).max(1);
+
+    ctx.logger.api_entry(&trace_id, "GET", &format!("/api/projects/{}/flaky-tests", id), &format!("project_id={}, builds={}, min_flips={}", id, builds, min_flips));
+
+    match ctx.project_repo.get(id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/projects/{}/flaky-tests", id), timer.elapsed_ms(), 404);
+            return (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({"error": "Project not found"})),
+            );
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/projects/{}/flaky-tests", id), timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    }
+
+    match ctx.build_repo.list_test_history(id, builds).await {
+        Ok(history) => {
+            let analyzed_builds = history
+                .iter()
+                .map(|(build_id, _)| *build_id)
+                .collect::<std::collections::HashSet<_>>()
+                .len();
+            let flaky = find_flaky_tests(&history, min_flips);
+
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/projects/{}/flaky-tests", id), timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "project_id": id,
+                    "analyzed_builds": analyzed_builds,
+                    "min_flips": min_flips,
+                    "flaky_tests": flaky,
+                })),
+            )
+        }
+        Err(e) => {
+            warn!("[{}] Failed to load test history: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/projects/{}/flaky-tests", id), timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Failed to load test history"})),
+            )
+        }
+    }
+}
+
+/// GET /api/projects/{id}/network
+/// easycicd 네트워크 안에서 이 프로젝트에 접근하는 방법 (내부 전용 프로젝트의 서비스 디스커버리용)
+async fn project_network(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/network", id);
+
+    ctx.logger.api_entry(&trace_id, "GET", &path, &format!("project_id={}", id));
+
+    let project = match ctx.project_repo.get(id).await {
+        Ok(Some(project)) => project,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    // 이 프로젝트를 peer로 둔 격리 프로젝트 (그 전용 네트워크에도 붙음)
+    let attached_to: Vec<i64> = match ctx.project_repo.list().await {
+        Ok(projects) => projects
+            .iter()
+            .filter(|p| p.id != project.id && p.network_isolation && p.parsed_network_peers().contains(&project.id))
+            .map(|p| p.id)
+            .collect(),
+        Err(e) => {
+            warn!("[{}] Failed to list projects: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    let aliases = project.network_aliases();
+    let (container_name, port) = crate::proxy::routes::project_target(&project);
+    let internal_urls: Vec<String> = aliases.iter().map(|a| format!("http://{}:{}", a, port)).collect();
+
+    ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "project_id": project.id,
+            "internal_only": project.internal_only,
+            "network": ctx.docker.project_network(project.id, project.network_isolation),
+            "isolated": project.network_isolation,
+            "peers": project.parsed_network_peers(),
+            "attached_to": attached_to,
+            "extra_networks": project.parsed_extra_networks(),
+            "aliases": aliases,
+            "port": port,
+            "internal_urls": internal_urls,
+            // 슬롯 전환 후에는 바뀌는 이름 (디버깅용)
+            "active_container": container_name,
+            "host_port": project.expose_host_port.then(|| project.get_active_port()),
+        })),
+    )
+}
+
+/// GET /api/projects/{id}/notes
+async fn project_notes(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/notes", id);
+
+    ctx.logger.api_entry(&trace_id, "GET", &path, &format!("project_id={}", id));
+
+    match ctx.project_repo.get(id).await {
+        Ok(Some(project)) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(notes_response(&project)))
+        }
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 404);
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+fn notes_response(project: &Project) -> serde_json::Value {
+    serde_json::json!({
+        "project_id": project.id,
+        "notes": project.notes,
+        "updated_at": project.notes_updated_at.as_deref().map(timezone::to_display),
+        "updated_by": project.notes_updated_by,
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateProjectNotesRequest {
+    /// Markdown. null 또는 빈 문자열이면 삭제
+    notes: Option<String>,
+}
+
+/// PUT /api/projects/{id}/notes
+/// 프로젝트 노트(배포 런북, 온콜 정보) 교체. 설정 version과 무관하게 저장
+async fn update_project_notes(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    user: Option<Extension<User>>,
+    Json(req): Json<UpdateProjectNotesRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/notes", id);
+
+    ctx.logger.api_entry(&trace_id, "PUT", &path, &format!("project_id={}", id));
+
+    let notes = req.notes.filter(|n| !n.trim().is_empty());
+    if notes.as_ref().is_some_and(|n| n.chars().count() > MAX_PROJECT_NOTES_LEN) {
+        ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": format!("notes too long (max {} characters)", MAX_PROJECT_NOTES_LEN)})),
+        );
+    }
+
+    match ctx.project_repo.get(id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    }
+
+    let email = user.as_ref().map(|Extension(u)| u.email.as_str());
+    let result = async {
+        ctx.project_repo.update_notes(id, notes.as_deref(), email).await?;
+        ctx.project_repo.get(id).await
+    }
+    .await;
+    let project = match result {
+        Ok(Some(project)) => project,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to update project notes: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    tracing::info!(
+        target: "audit",
+        event = "project.notes_updated",
+        trace_id = %trace_id,
+        project_id = id,
+        cleared = notes.is_none(),
+        user = email.unwrap_or_default(),
+    );
+
+    ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 200);
+    (StatusCode::OK, Json(notes_response(&project)))
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateProjectNetworkRequest {
+    isolated: Option<bool>,
+    peers: Option<Vec<i64>>,
+}
+
+/// PUT /api/projects/{id}/network
+/// 전용 네트워크 격리와 peer 프로젝트 설정
+///
+/// 격리 전환은 다음 배포부터 적용. peer 변경은 실행 중인 peer 컨테이너에 바로 연결/해제한다
+async fn update_project_network(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateProjectNetworkRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/network", id);
+
+    ctx.logger.api_entry(&trace_id, "PUT", &path, &format!("project_id={}", id));
+
+    let (project, projects) = match ctx.project_repo.list().await {
+        Ok(projects) => match projects.iter().find(|p| p.id == id).cloned() {
+            Some(project) => (project, projects),
+            None => {
+                ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 404);
+                return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})));
+            }
+        },
+        Err(e) => {
+            warn!("[{}] Failed to list projects: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    let isolated = req.isolated.unwrap_or(project.network_isolation);
+    let old_peers = project.parsed_network_peers();
+    let mut peers = req.peers.unwrap_or_else(|| old_peers.clone());
+    peers.sort_unstable();
+    peers.dedup();
+
+    if peers.contains(&id) {
+        ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "A project cannot be its own peer"})));
+    }
+    let unknown: Vec<i64> = peers.iter().copied().filter(|p| !projects.iter().any(|x| x.id == *p)).collect();
+    if !unknown.is_empty() {
+        ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "Unknown peer projects", "project_ids": unknown})),
+        );
+    }
+
+    let peers_json = (!peers.is_empty()).then(|| serde_json::to_string(&peers).unwrap_or_default());
+    if let Err(e) = ctx.project_repo.update_network(id, isolated, peers_json).await {
+        warn!("[{}] Failed to update project network: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 500);
+        return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+    }
+
+    // 실행 중인 peer 컨테이너를 전용 네트워크에 연결/해제 (실패해도 다음 배포 때 다시 붙음)
+    let network = project_network_name(id);
+    if isolated {
+        if let Err(e) = ctx.docker.ensure_dedicated_network(&network).await {
+            warn!("[{}] Failed to create network {}: {}", trace_id, network, e);
+        }
+    }
+    for peer in &projects {
+        let connect = isolated && peers.contains(&peer.id);
+        let disconnect = !connect && old_peers.contains(&peer.id);
+        for container_id in [&peer.blue_container_id, &peer.green_container_id].into_iter().flatten() {
+            let result = if connect {
+                ctx.docker.connect_container_network(&network, container_id).await
+            } else if disconnect {
+                ctx.docker.disconnect_container_network(&network, container_id).await
+            } else {
+                continue;
+            };
+            if let Err(e) = result {
+                warn!("[{}] Failed to update network of peer project {}: {}", trace_id, peer.name, e);
+            }
+        }
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "project.network_updated",
+        trace_id = %trace_id,
+        project_id = id,
+        isolated,
+        peers = ?peers,
+    );
+
+    ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "project_id": id,
+            "isolated": isolated,
+            "network": ctx.docker.project_network(id, isolated),
+            "peers": peers,
+            // 격리 전환은 다음 배포부터 적용
+            "redeploy_required": isolated != project.network_isolation,
+        })),
+    )
+}
+
+fn image_update_response(project: &Project) -> serde_json::Value {
+    let status = project.parsed_image_update_status();
+    serde_json::json!({
+        "project_id": project.id,
+        "auto_rebuild": project.auto_rebuild_on_image_update,
+        "update_available": status.as_ref().is_some_and(|s| s.update_available()),
+        "checked_at": status.as_ref().map(|s| timezone::to_display(&s.checked_at)),
+        "images": status.map(|s| s.images).unwrap_or_default(),
+    })
+}
+
+/// GET /api/projects/{id}/image-updates
+/// 마지막 베이스 이미지 업데이트 확인 결과 (image_updates worker가 갱신)
+async fn project_image_updates(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/image-updates", id);
+
+    ctx.logger.api_entry(&trace_id, "GET", &path, &format!("project_id={}", id));
+
+    match ctx.project_repo.get(id).await {
+        Ok(Some(project)) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(image_update_response(&project)))
+        }
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 404);
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// POST /api/projects/{id}/image-updates/check
+/// 스케줄을 기다리지 않고 바로 확인 (결과만 갱신, 자동 재빌드는 하지 않음)
+async fn check_project_image_updates(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/image-updates/check", id);
+
+    ctx.logger.api_entry(&trace_id, "POST", &path, &format!("project_id={}", id));
+
+    let project = match ctx.project_repo.get(id).await {
+        Ok(Some(project)) => project,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    let mut registry = image_update_check::RegistryDigests::new();
+    if let Err(e) = image_update_check::check_project_images(&ctx, &trace_id, &project, &mut registry).await {
+        warn!("[{}] Failed to check image updates: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 502);
+        return (
+            StatusCode::BAD_GATEWAY,
+            Json(serde_json::json!({"error": format!("Failed to check image updates: {}", e)})),
+        );
+    }
+
+    match ctx.project_repo.get(id).await {
+        Ok(Some(project)) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(image_update_response(&project)))
+        }
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// GET /api/projects/{id}/metrics?range=24h
+/// Blue/Green 컨테이너 CPU/메모리 시계열. 24시간 이하 범위는 5분, 그 이상은 1시간 간격
+async fn project_metrics(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Query(query): Query<MetricsQuery>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let range = query.range.unwrap_or_else(|| "24h".to_string());
+
+    ctx.logger.api_entry(&trace_id, "GET", &format!("/api/projects/{}/metrics", id), &format!("project_id={}, range={}", id, range));
+
+    let range_secs = match parse_metrics_range(&range) {
+        Some(secs) => secs,
+        None => {
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/projects/{}/metrics", id), timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": "Invalid range (examples: 30m, 24h, 7d; max 30d)"})),
+            );
+        }
+    };
+
+    match ctx.project_repo.get(id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/projects/{}/metrics", id), timer.elapsed_ms(), 404);
+            return (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({"error": "Project not found"})),
+            );
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/projects/{}/metrics", id), timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    }
+
+    let step_secs = if range_secs <= 86400 { METRICS_BUCKET_SECS } else { 3600 };
+    let since = chrono::Utc::now().timestamp() - range_secs;
+
+    match ctx.metrics_repo.list(id, since, step_secs).await {
+        Ok(points) => {
+            let points: Vec<serde_json::Value> = points
+                .into_iter()
+                .map(|p| {
+                    let timestamp = timezone::unix_to_display(p.bucket_start).unwrap_or_default();
+                    serde_json::json!({
+                        "timestamp": timestamp,
+                        "slot": p.slot,
+                        "samples": p.samples,
+                        "cpu_avg": p.cpu_avg,
+                        "cpu_max": p.cpu_max,
+                        "memory_avg": p.memory_avg,
+                        "memory_max": p.memory_max,
+                        "memory_limit": p.memory_limit,
+                    })
+                })
+                .collect();
+
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/projects/{}/metrics", id), timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "project_id": id,
+                    "range": range,
+                    "step_seconds": step_secs,
+                    "points": points,
+                    // 리버스 프록시 최근 5분 지연 시간/5xx 비율 (요청이 없으면 null)
+                    "proxy": ctx.proxy_stats.project_stats(id),
+                })),
+            )
+        }
+        Err(e) => {
+            warn!("[{}] Failed to load metrics: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/projects/{}/metrics", id), timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            )
+        }
+    }
+}
+
+/// GET /api/projects/{id}/disk-usage
+/// 프로젝트 디스크 사용량 내역과 적용 중인 쿼터
+async fn project_disk_usage(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", &format!("/api/projects/{}/disk-usage", id), &format!("project_id={}", id));
+
+    let project = match ctx.project_repo.get(id).await {
+        Ok(Some(p)) => p,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/projects/{}/disk-usage", id), timer.elapsed_ms(), 404);
+            return (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({"error": "Project not found"})),
+            );
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/projects/{}/disk-usage", id), timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    };
+
+    let quota_mb = match ctx.disk_quota_service.effective_quota_mb(&project).await {
+        Ok(q) => q,
+        Err(e) => {
+            warn!("[{}] Failed to load disk quota: {}", trace_id, e);
+            None
+        }
+    };
+
+    match ctx.disk_quota_service.usage(&project).await {
+        Ok(usage) => {
+            let exceeded = quota_mb.is_some_and(|q| usage.total > (q as u64) * 1024 * 1024);
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/projects/{}/disk-usage", id), timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "project_id": id,
+                    "usage": usage,
+                    "quota_mb": quota_mb,
+                    "project_quota_mb": project.disk_quota_mb,
+                    "exceeded": exceeded,
+                })),
+            )
+        }
+        Err(e) => {
+            warn!("[{}] Failed to compute disk usage: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &format!("/api/projects/{}/disk-usage", id), timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Failed to compute disk usage"})),
+            )
+        }
+    }
+}
+
+/// 프로젝트 일괄 작업 최대 대상 수
+const MAX_BATCH_SIZE: usize = 100;
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+enum ProjectBatchAction {
+    Start,
+    Stop,
+    Restart,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchProjectRequest {
+    action: ProjectBatchAction,
+    ids: Vec<i64>,
+}
+
+/// POST /api/projects/batch
+/// 여러 프로젝트의 Blue/Green 컨테이너를 일괄 시작/중지/재시작.
+/// 프로젝트별 결과(슬롯별 상세 포함)를 반환하고, 실패한 항목이 있어도 나머지는 계속 진행한다.
+async fn batch_project_containers(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    user: Option<Extension<User>>,
+    Json(req): Json<BatchProjectRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/projects/batch", &format!("action={:?}, ids={:?}", req.action, req.ids));
+
+    if req.ids.is_empty() || req.ids.len() > MAX_BATCH_SIZE {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/projects/batch", timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": format!("ids must contain 1-{} items", MAX_BATCH_SIZE)})),
+        );
+    }
+
+    // 중복 ID 제거 (순서 유지)
+    let mut seen = std::collections::HashSet::new();
+    let ids: Vec<i64> = req.ids.iter().copied().filter(|id| seen.insert(*id)).collect();
+
+    let mut results = Vec::with_capacity(ids.len());
+    let mut succeeded = 0;
+    for id in ids {
+        // 경로에 프로젝트 ID가 없으므로 권한 layer 대신 여기서 항목별로 확인
+        match has_project_permission(&ctx, user.as_ref().map(|Extension(u)| u), id, Some(ProjectPermission::Deploy)).await {
+            Ok(true) => {}
+            Ok(false) => {
+                results.push(serde_json::json!({
+                    "id": id,
+                    "success": false,
+                    "error": "'deploy' permission required",
+                }));
+                continue;
+            }
+            Err(e) => {
+                results.push(serde_json::json!({
+                    "id": id,
+                    "success": false,
+                    "error": e.to_string(),
+                }));
+                continue;
             }
         }
-    }
 
-    // Remove only build output directories for this specific project
-    // First, get all builds for this project to know which directories to delete
-    if let Ok(builds) = ctx.build_repo.list_by_project(id, 10000).await {
-        for build in builds {
-            let build_output_path = output_base.join(format!("build{}", build.id));
-            if build_output_path.exists() {
-                info!("[{}] Removing build output: {:?}", trace_id, build_output_path);
-                if let Err(e) = fs::remove_dir_all(&build_output_path).await {
-                    warn!("[{}] Failed to remove build output {:?}: {}", trace_id, build_output_path, e);
-                }
+        let result = match req.action {
+            ProjectBatchAction::Start => ctx.project_service.start_containers(&trace_id, id).await,
+            ProjectBatchAction::Stop => ctx.project_service.stop_containers(&trace_id, id).await,
+            ProjectBatchAction::Restart => ctx.project_service.restart_containers(&trace_id, id).await,
+        };
+
+        match result {
+            Ok(slots) => {
+                succeeded += 1;
+                results.push(serde_json::json!({
+                    "id": id,
+                    "success": true,
+                    "results": slots,
+                }));
+            }
+            Err(e) => {
+                warn!("[{}] Batch {:?} failed for project {}: {}", trace_id, req.action, id, e);
+                results.push(serde_json::json!({
+                    "id": id,
+                    "success": false,
+                    "error": e.to_string(),
+                }));
             }
         }
     }
 
-    // Delete from database (this will cascade delete builds due to ON DELETE CASCADE)
-    if let Err(e) = ctx.project_repo.delete(id).await {
-        warn!("[{}] Failed to delete project from database: {}", trace_id, e);
-        ctx.logger.api_exit(&trace_id, "DELETE", &format!("/api/projects/{}", id), timer.elapsed_ms(), 500);
-        return (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({"error": "Failed to delete project"})),
-        );
-    }
+    let failed = results.len() - succeeded;
 
-    info!("[{}] Project {} deleted successfully", trace_id, project.name);
-    ctx.logger.api_exit(&trace_id, "DELETE", &format!("/api/projects/{}", id), timer.elapsed_ms(), 200);
+    tracing::info!(
+        target: "audit",
+        event = "projects.batch",
+        trace_id = %trace_id,
+        action = ?req.action,
+        succeeded,
+        failed,
+    );
 
+    ctx.logger.api_exit(&trace_id, "POST", "/api/projects/batch", timer.elapsed_ms(), 200);
     (
         StatusCode::OK,
-        Json(serde_json::json!({"message": "Project deleted successfully"})),
+        Json(serde_json::json!({
+            "action": req.action,
+            "succeeded": succeeded,
+            "failed": failed,
+            "results": results,
+        })),
     )
 }
 
@@ -926,6 +3005,14 @@ async fn rollback_build(
         }
     };
 
+    if project.archived_at.is_some() {
+        ctx.logger.api_exit(&trace_id, "POST", &format!("/api/projects/{}/rollback/{}", project_id, build_id), timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({"error": "Project is archived. Unarchive it before deploying"})),
+        );
+    }
+
     // Get target build
     let target_build = match ctx.build_repo.get(build_id).await {
         Ok(Some(b)) => b,
@@ -955,6 +3042,16 @@ async fn rollback_build(
         );
     }
 
+    let _deployment_guard = match ctx.deployment_locks.try_acquire(project_id, DeploymentOperation::Rollback, &trace_id) {
+        Ok(guard) => guard,
+        Err(holder) => {
+            ctx.logger.api_exit(&trace_id, "POST", &format!("/api/projects/{}/rollback/{}", project_id, build_id), timer.elapsed_ms(), 409);
+            return (StatusCode::CONFLICT, deployment_conflict(holder));
+        }
+    };
+    // 프로젝트를 읽은 뒤 끝난 배포가 active_slot을 바꿨을 수 있으므로 잠금 후 다시 조회
+    let project = ctx.project_repo.get(project_id).await.ok().flatten().unwrap_or(project);
+
     // Execute rollback via DeploymentService
     match ctx.deployment_service.rollback(&trace_id, &project, &target_build).await {
         Ok(_) => {
@@ -969,6 +3066,11 @@ async fn rollback_build(
             )
         }
         Err(e) => {
+            if let Some(body) = host_port_conflict_body(&e) {
+                warn!("[{}] Rollback blocked by port conflict: {}", trace_id, e);
+                ctx.logger.api_exit(&trace_id, "POST", &format!("/api/projects/{}/rollback/{}", project_id, build_id), timer.elapsed_ms(), 409);
+                return (StatusCode::CONFLICT, Json(body));
+            }
             warn!("[{}] Rollback failed: {}", trace_id, e);
             ctx.logger.api_exit(&trace_id, "POST", &format!("/api/projects/{}/rollback/{}", project_id, build_id), timer.elapsed_ms(), 500);
             (
@@ -979,8 +3081,412 @@ async fn rollback_build(
     }
 }
 
+#[derive(Deserialize)]
+struct DeploymentHistoryQuery {
+    /// 최대 항목 수 (기본 20, 최대 100)
+    limit: Option<i64>,
+}
+
+/// GET /api/projects/{id}/deployments
+/// 배포된 빌드 이력 (최신순, 메모/라벨 포함). `active`는 현재 활성 슬롯에서 서비스 중인 빌드
+async fn list_deployments(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Query(query): Query<DeploymentHistoryQuery>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/deployments", id);
+    let limit = query.limit.unwrap_or(20).clamp(1, 100) as usize;
+
+    ctx.logger.api_entry(&trace_id, "GET", &path, &format!("project_id={}, limit={}", id, limit));
+
+    let project = match ctx.project_repo.get(id).await {
+        Ok(Some(p)) => p,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    // dry-run/실패 빌드가 섞여 있으므로 넉넉히 읽어서 배포된 것만 추림
+    let builds = match ctx.build_repo.list_by_project(id, 500).await {
+        Ok(builds) => builds,
+        Err(e) => {
+            warn!("[{}] Failed to list builds: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    // 슬롯별로 가장 최근에 배포된 빌드가 그 슬롯에서 실행 중 → 활성 슬롯의 것이 서비스 중
+    let active_id = builds
+        .iter()
+        .find(|b| b.status == BuildStatus::Success && b.get_deployed_slot() == Some(project.active_slot))
+        .map(|b| b.id);
+    let deployments: Vec<serde_json::Value> = builds
+        .iter()
+        .filter(|b| b.status == BuildStatus::Success && b.deployed_slot.is_some())
+        .take(limit)
+        .map(|b| {
+            serde_json::json!({
+                "build_id": b.id,
+                "build_number": b.build_number,
+                "commit_hash": b.commit_hash,
+                "commit_message": b.commit_message,
+                "deployed_slot": b.deployed_slot,
+                "runtime_image_digest": b.runtime_image_digest,
+                "triggered_by": b.triggered_by,
+                "deployed_at": b.finished_at.as_deref().map(timezone::to_display),
+                "note": b.note,
+                "labels": b.parsed_labels(),
+                "shadow_report": b.parsed_shadow_report(),
+                "active": Some(b.id) == active_id,
+            })
+        })
+        .collect();
+
+    ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "project_id": id,
+            "deployments": deployments,
+        })),
+    )
+}
+
+/// POST /api/projects/{id}/rollback
+/// 현재 서비스 중인 빌드 바로 이전에 배포된 성공 빌드(산출물이 남아 있는 것)로 롤백
+async fn rollback_previous(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/rollback", id);
+
+    ctx.logger.api_entry(&trace_id, "POST", &path, &format!("project_id={}", id));
+
+    let project = match ctx.project_repo.get(id).await {
+        Ok(Some(p)) if p.deleted_at.is_none() => p,
+        Ok(_) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+            return (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({"error": "Project not found"})),
+            );
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    };
+
+    if project.archived_at.is_some() {
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({"error": "Project is archived. Unarchive it before deploying"})),
+        );
+    }
+
+    let _deployment_guard = match ctx.deployment_locks.try_acquire(id, DeploymentOperation::Rollback, &trace_id) {
+        Ok(guard) => guard,
+        Err(holder) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+            return (StatusCode::CONFLICT, deployment_conflict(holder));
+        }
+    };
+    let project = ctx.project_repo.get(id).await.ok().flatten().unwrap_or(project);
+
+    let (current, target) = match tokio::try_join!(
+        ctx.deployment_service.current_build(&project),
+        ctx.deployment_service.previous_build(&project),
+    ) {
+        Ok(result) => result,
+        Err(e) => {
+            warn!("[{}] Failed to find rollback target: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    };
+
+    let Some(target) = target else {
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": "No previous successful deployment with available artifacts to roll back to"})),
+        );
+    };
+
+    info!(
+        "[{}] Rolling back project {} from build #{} to previous build #{}",
+        trace_id,
+        project.name,
+        current.as_ref().map(|b| b.build_number.to_string()).unwrap_or_else(|| "-".to_string()),
+        target.build_number
+    );
+
+    match ctx.deployment_service.rollback(&trace_id, &project, &target).await {
+        Ok(_) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "message": "Rollback completed successfully",
+                    "build_id": target.id,
+                    "build_number": target.build_number,
+                    "previous_build_id": current.as_ref().map(|b| b.id),
+                    "previous_build_number": current.as_ref().map(|b| b.build_number),
+                })),
+            )
+        }
+        Err(e) => {
+            if let Some(body) = host_port_conflict_body(&e) {
+                warn!("[{}] Rollback blocked by port conflict: {}", trace_id, e);
+                ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+                return (StatusCode::CONFLICT, Json(body));
+            }
+            warn!("[{}] Rollback failed: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": format!("Rollback failed: {}", e)})),
+            )
+        }
+    }
+}
+
+/// POST /api/projects/{id}/slots/switch
+/// 비활성 슬롯에서 실행 중인 웜 스탠바이로 즉시 전환 (컨테이너를 새로 띄우지 않음)
+async fn switch_slot(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/slots/switch", id);
+
+    ctx.logger.api_entry(&trace_id, "POST", &path, &format!("project_id={}", id));
+
+    let project = match ctx.project_repo.get(id).await {
+        Ok(Some(p)) if p.deleted_at.is_none() => p,
+        Ok(_) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    if project.archived_at.is_some() {
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({"error": "Project is archived. Unarchive it before deploying"})),
+        );
+    }
+
+    let _deployment_guard = match ctx.deployment_locks.try_acquire(id, DeploymentOperation::SwitchSlot, &trace_id) {
+        Ok(guard) => guard,
+        Err(holder) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+            return (StatusCode::CONFLICT, deployment_conflict(holder));
+        }
+    };
+    let project = ctx.project_repo.get(id).await.ok().flatten().unwrap_or(project);
+
+    if ctx.deployment_service.standby(&trace_id, &project).await.is_none() {
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({
+                "error": format!("No warm standby is running in the {} slot", project.get_inactive_slot()),
+                "warm_standby": project.warm_standby,
+            })),
+        );
+    }
+
+    match ctx.deployment_service.switch_slot(&trace_id, &project).await {
+        Ok(build) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "message": "Switched to warm standby",
+                    "active_slot": project.get_inactive_slot(),
+                    "build_id": build.id,
+                    "build_number": build.build_number,
+                })),
+            )
+        }
+        Err(e) => {
+            warn!("[{}] Slot switch failed: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": format!("Slot switch failed: {}", e)})),
+            )
+        }
+    }
+}
+
+/// GET /api/projects/{id}/canary
+/// 진행 중인 카나리 (빌드, 트래픽 비율, 현재 비율에서의 카나리/운영 슬롯 오류율)
+async fn canary_status(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/canary", id);
+
+    ctx.logger.api_entry(&trace_id, "GET", &path, &format!("project_id={}", id));
+
+    let project = match ctx.project_repo.get(id).await {
+        Ok(Some(p)) if p.deleted_at.is_none() => p,
+        Ok(_) => {
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    let metrics = ctx.deployment_service.canary_session(&project).map(|session| {
+        let metrics = session.metrics();
+        serde_json::json!({
+            "weight": metrics.weight,
+            "canary_requests": metrics.canary_requests,
+            "canary_errors": metrics.canary_errors,
+            "canary_error_percent": metrics.canary_error_percent(),
+            "stable_requests": metrics.stable_requests,
+            "stable_errors": metrics.stable_errors,
+            "stable_error_percent": metrics.stable_error_percent(),
+            "secs_at_weight": metrics.secs_at_weight,
+        })
+    });
+
+    ctx.logger.api_exit(&trace_id, "GET", &path, timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "deployment_strategy": project.deployment_strategy,
+            "build_id": project.canary_build_id,
+            "slot": project.canary_build_id.map(|_| project.get_inactive_slot()),
+            "weight": project.canary_weight,
+            "metrics": metrics,
+        })),
+    )
+}
+
+#[derive(Deserialize)]
+struct CanaryWeightRequest {
+    /// 카나리로 보낼 트래픽 비율 (%). 100이면 승격, 0이면 중단
+    weight: u8,
+}
+
+/// POST /api/projects/{id}/canary/weight
+/// 진행 중인 카나리의 트래픽 비율 변경 (100이면 새 슬롯으로 전환, 0이면 카나리 중단)
+async fn set_canary_weight(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(req): Json<CanaryWeightRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/canary/weight", id);
+
+    ctx.logger.api_entry(&trace_id, "POST", &path, &format!("project_id={}, weight={}", id, req.weight));
+
+    if req.weight > 100 {
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": "weight must be between 0 and 100"})));
+    }
+
+    let _deployment_guard = match ctx.deployment_locks.try_acquire(id, DeploymentOperation::Canary, &trace_id) {
+        Ok(guard) => guard,
+        Err(holder) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+            return (StatusCode::CONFLICT, deployment_conflict(holder));
+        }
+    };
+
+    let project = match ctx.project_repo.get(id).await {
+        Ok(Some(p)) if p.deleted_at.is_none() => p,
+        Ok(_) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    let Some(build_id) = project.canary_build_id else {
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 409);
+        return (StatusCode::CONFLICT, Json(serde_json::json!({"error": "No canary deployment in progress"})));
+    };
+
+    let (result, message) = match req.weight {
+        100 => (crate::build::promote_canary_build(&ctx, &trace_id, &project).await.map(|_| ()), "Canary promoted"),
+        0 => (
+            crate::build::abort_canary_build(&ctx, &trace_id, &project, "Canary weight set to 0").await.map(|_| ()),
+            "Canary aborted",
+        ),
+        weight => (ctx.deployment_service.set_canary_weight(&trace_id, &project, weight).await, "Canary weight updated"),
+    };
+
+    match result {
+        Ok(()) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "message": message,
+                    "build_id": build_id,
+                    "weight": req.weight,
+                })),
+            )
+        }
+        Err(e) => {
+            warn!("[{}] Failed to update canary: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": format!("Failed to update canary: {}", e)})),
+            )
+        }
+    }
+}
+
 use axum::extract::ws::{WebSocket, WebSocketUpgrade};
-use axum::response::Response;
 use futures_util::{SinkExt, StreamExt};
 
 #[derive(Deserialize)]
@@ -1030,10 +3536,16 @@ async fn runtime_logs_stream(
         }
     };
 
-    // Get active container ID
-    let container_id = match project.active_slot {
-        Slot::Blue => project.blue_container_id,
-        Slot::Green => project.green_container_id,
+    // Get active container ID (저장된 ID가 stale이면 이름으로 다시 찾음)
+    let container_id = match ctx.docker.resolve_container(ctx.project_repo.as_ref(), project.id, project.active_slot).await {
+        Ok(container_id) => container_id,
+        Err(e) => {
+            warn!("[{}] Failed to resolve active container: {}", trace_id, e);
+            match project.active_slot {
+                Slot::Blue => project.blue_container_id,
+                Slot::Green => project.green_container_id,
+            }
+        }
     };
 
     let container_id = match container_id {
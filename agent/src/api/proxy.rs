@@ -0,0 +1,136 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use tracing::{info, warn};
+
+use crate::application::ports::repositories::{ContainerRepository, ProjectRepository, SettingsRepository};
+use crate::state::AppContext;
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::proxy::routes::{route_table, ProxyRoute};
+use crate::proxy::stats::STATS_WINDOW;
+
+async fn current_routes(ctx: &AppContext) -> anyhow::Result<Vec<ProxyRoute>> {
+    let projects = ctx.project_repo.list().await?;
+    let previews = ctx.preview_repo.list().await?;
+    let containers = ctx.container_repo.list().await?;
+    Ok(route_table(ctx.base_domain().as_deref(), &projects, &previews, &containers))
+}
+
+/// GET /api/proxy/routes
+/// 리버스 프록시가 지금 사용하는 호스트/경로 → 프로젝트(활성 슬롯)/컨테이너 → 대상 주소 표
+pub async fn list_routes(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/proxy/routes", "");
+
+    match current_routes(&ctx).await {
+        Ok(routes) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/proxy/routes", timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "base_domain": ctx.base_domain(),
+                    "routes": routes,
+                })),
+            )
+        }
+        Err(e) => {
+            warn!("[{}] Failed to build proxy routes: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/api/proxy/routes", timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            )
+        }
+    }
+}
+
+/// POST /api/proxy/reload
+/// settings의 base_domain을 다시 읽어 프록시에 반영하고 갱신된 라우팅 표를 반환
+/// (프로젝트/컨테이너 라우팅은 요청마다 DB에서 조회하므로 따로 캐시하지 않음)
+pub async fn reload_routes(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/proxy/reload", "");
+
+    let base_domain = match ctx.settings_repo.get("base_domain").await {
+        Ok(domain) => domain.filter(|d| !d.trim().is_empty()),
+        Err(e) => {
+            warn!("[{}] Failed to load base domain: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", "/api/proxy/reload", timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    };
+
+    let previous = ctx.base_domain();
+    ctx.set_base_domain(base_domain.clone());
+    if previous != base_domain {
+        info!("[{}] Proxy base domain reloaded: {:?} -> {:?}", trace_id, previous, base_domain);
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "proxy.reloaded",
+        trace_id = %trace_id,
+        base_domain = ?base_domain,
+    );
+
+    match current_routes(&ctx).await {
+        Ok(routes) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/proxy/reload", timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "base_domain": base_domain,
+                    "previous_base_domain": previous,
+                    "routes": routes,
+                })),
+            )
+        }
+        Err(e) => {
+            warn!("[{}] Failed to build proxy routes: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", "/api/proxy/reload", timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            )
+        }
+    }
+}
+
+/// GET /api/proxy/stats
+/// 최근 5분 동안 리버스 프록시 라우트(프로젝트/컨테이너 + Host)별 요청 수, p50/p95 지연 시간, 5xx 비율
+pub async fn proxy_stats(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/proxy/stats", "");
+
+    let routes = ctx.proxy_stats.snapshot();
+
+    ctx.logger.api_exit(&trace_id, "GET", "/api/proxy/stats", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "window_secs": STATS_WINDOW.as_secs(),
+            "routes": routes,
+        })),
+    )
+}
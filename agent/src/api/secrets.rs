@@ -0,0 +1,238 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json, Router,
+    routing::{get, put},
+};
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
+use tracing::warn;
+
+use crate::application::ports::repositories::ProjectRepository;
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::infrastructure::secrets::{env_secret_refs, validate_secret_name, MAX_SECRET_VALUE_LEN};
+use crate::state::AppContext;
+
+pub fn secrets_routes() -> Router<AppContext> {
+    Router::new()
+        .route("/", get(list_secrets).post(create_secret))
+        .route("/{name}", put(update_secret).delete(delete_secret))
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateSecretRequest {
+    name: String,
+    value: String,
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateSecretRequest {
+    value: Option<String>,
+    description: Option<String>,
+}
+
+fn validate_secret_value(value: &str) -> Result<(), String> {
+    if value.is_empty() || value.len() > MAX_SECRET_VALUE_LEN {
+        return Err(format!("Secret value must be 1-{} bytes", MAX_SECRET_VALUE_LEN));
+    }
+    Ok(())
+}
+
+/// 비밀 이름별 참조하는 프로젝트 이름 (build/runtime 환경변수의 `${secret:NAME}`)
+async fn secret_usage(ctx: &AppContext) -> anyhow::Result<BTreeMap<String, BTreeSet<String>>> {
+    let mut usage: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for project in ctx.project_repo.list().await? {
+        let refs = env_secret_refs(project.build_env_vars.as_deref())
+            .into_iter()
+            .chain(env_secret_refs(project.runtime_env_vars.as_deref()));
+        for name in refs {
+            usage.entry(name).or_default().insert(project.name.clone());
+        }
+    }
+    Ok(usage)
+}
+
+/// List secrets (names and descriptions only, never values)
+async fn list_secrets(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/secrets", "");
+
+    let result = async {
+        let secrets = ctx.secret_repo.list().await?;
+        let mut usage = secret_usage(&ctx).await?;
+        let items: Vec<serde_json::Value> = secrets
+            .into_iter()
+            .map(|secret| {
+                let used_by = usage.remove(&secret.name).unwrap_or_default();
+                let mut item = serde_json::json!(secret);
+                item["used_by"] = serde_json::json!(used_by);
+                item
+            })
+            .collect();
+        // 참조하지만 등록되지 않은 비밀 (빌드/배포 실패 원인)
+        anyhow::Ok(serde_json::json!({"secrets": items, "missing": usage}))
+    }
+    .await;
+
+    match result {
+        Ok(body) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/secrets", timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(body))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to list secrets: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/api/secrets", timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": format!("Failed to list secrets: {}", e)})),
+            )
+        }
+    }
+}
+
+/// Create a secret (the value is encrypted at rest)
+async fn create_secret(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(payload): Json<CreateSecretRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/secrets", &format!("name={}", payload.name));
+
+    if let Err(msg) = validate_secret_name(&payload.name).and_then(|_| validate_secret_value(&payload.value)) {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/secrets", timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+    }
+
+    match ctx.secret_repo.create(&payload.name, &payload.value, payload.description.as_deref()).await {
+        Ok(Some(secret)) => {
+            tracing::info!(target: "audit", event = "secret.created", trace_id = %trace_id, name = %secret.name);
+            ctx.logger.api_exit(&trace_id, "POST", "/api/secrets", timer.elapsed_ms(), 201);
+            (StatusCode::CREATED, Json(serde_json::json!(secret)))
+        }
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/secrets", timer.elapsed_ms(), 409);
+            (
+                StatusCode::CONFLICT,
+                Json(serde_json::json!({"error": format!("Secret '{}' already exists", payload.name)})),
+            )
+        }
+        Err(e) => {
+            warn!("[{}] Failed to create secret: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", "/api/secrets", timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": format!("Failed to create secret: {}", e)})),
+            )
+        }
+    }
+}
+
+/// Update a secret's value and/or description
+async fn update_secret(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(name): Path<String>,
+    Json(payload): Json<UpdateSecretRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/secrets/{}", name);
+
+    ctx.logger.api_entry(&trace_id, "PUT", &path, "");
+
+    if let Some(Err(msg)) = payload.value.as_deref().map(validate_secret_value) {
+        ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+    }
+
+    match ctx.secret_repo.update(&name, payload.value.as_deref(), payload.description.as_deref()).await {
+        Ok(Some(secret)) => {
+            tracing::info!(
+                target: "audit",
+                event = "secret.updated",
+                trace_id = %trace_id,
+                name = %secret.name,
+                value_changed = payload.value.is_some(),
+            );
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!(secret)))
+        }
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 404);
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Secret not found"})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to update secret {}: {}", trace_id, name, e);
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": format!("Failed to update secret: {}", e)})),
+            )
+        }
+    }
+}
+
+/// Delete a secret (refused while a project still references it)
+async fn delete_secret(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(name): Path<String>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/secrets/{}", name);
+
+    ctx.logger.api_entry(&trace_id, "DELETE", &path, "");
+
+    let used_by = match secret_usage(&ctx).await {
+        Ok(mut usage) => usage.remove(&name).unwrap_or_default(),
+        Err(e) => {
+            warn!("[{}] Failed to check secret usage: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": format!("Failed to check secret usage: {}", e)})),
+            );
+        }
+    };
+    if !used_by.is_empty() {
+        ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({
+                "error": format!("Secret '{}' is still referenced by projects", name),
+                "used_by": used_by,
+            })),
+        );
+    }
+
+    match ctx.secret_repo.delete(&name).await {
+        Ok(true) => {
+            tracing::info!(target: "audit", event = "secret.deleted", trace_id = %trace_id, name = %name);
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 204);
+            (StatusCode::NO_CONTENT, Json(serde_json::json!({})))
+        }
+        Ok(false) => {
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 404);
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Secret not found"})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to delete secret {}: {}", trace_id, name, e);
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": format!("Failed to delete secret: {}", e)})),
+            )
+        }
+    }
+}
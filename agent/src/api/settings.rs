@@ -1,14 +1,40 @@
 use axum::{
-    extract::State,
+    extract::{Path, Query, State},
     http::{HeaderMap, StatusCode},
     response::IntoResponse,
     Json,
 };
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
+use tracing::warn;
 
 use crate::state::AppContext;
+use crate::application::ports::git_provider::{parse_repo_url, GitProviderKind};
+use crate::application::services::{gitlab_base_url, BITBUCKET_TOKEN_SETTING, GITLAB_TOKEN_SETTING};
+use crate::bitbucket::BitbucketClient;
+use crate::github::GitHubClient;
+use crate::gitlab::GitLabClient;
+use super::projects::project_github_token;
+use super::terminal::{TERMINAL_ALLOWED_EMAILS_SETTING, TERMINAL_VIEWER_EMAILS_SETTING};
+use super::webhook::{
+    generate_webhook_secret, provider_webhook_url, WEBHOOK_SECRET_PREVIOUS_EXPIRES_SETTING, WEBHOOK_SECRET_PREVIOUS_SETTING,
+    WEBHOOK_SECRET_SETTING,
+};
+use crate::infrastructure::acme;
 use crate::infrastructure::logging::{TraceContext, Timer};
-use crate::application::ports::repositories::SettingsRepository;
+use crate::proxy::tls::{ACME_EMAIL_SETTING, RENEW_BEFORE_DAYS, TLS_ENABLED_SETTING};
+use crate::infrastructure::timezone::{self, DISPLAY_TIMEZONE_SETTING};
+use crate::application::ports::repositories::{ProjectRepository, SettingsRepository};
+use crate::application::services::DEFAULT_DISK_QUOTA_SETTING;
+use crate::workers::cache_eviction::{cache_usage, CacheLimits, CACHE_LIMITS_SETTING};
+use crate::build::{validate_concurrency_group, ConcurrencyGroupLimits, CONCURRENCY_GROUPS_SETTING};
+use crate::workers::cleanup_schedule::{CleanupSchedule, CleanupWorker};
+use crate::workers::queue_wait_monitor::{queue_wait_threshold_secs, QUEUE_WAIT_ALERT_SETTING};
+use crate::application::services::image_profiles::{default_image_profiles, validate_image_profiles, IMAGE_PROFILES_SETTING};
+use crate::application::services::log_levels::{default_log_level_patterns, validate_log_level_patterns, LOG_LEVEL_PATTERNS_SETTING};
+use crate::db::models::{ImageProfile, LogLevelPatterns};
+use crate::workers::stale_build_watchdog::{stale_build_max_age_secs, STALE_BUILD_SETTING};
+use crate::infrastructure::notifications::email_client::{SmtpSettings, SMTP_SETTING};
 
 #[derive(Serialize)]
 pub struct WebhookSecretResponse {
@@ -55,6 +81,176 @@ pub async fn get_webhook_secret(
     }
 }
 
+/// 이전 secret을 함께 허용하는 기본 유예 기간 (분)
+const DEFAULT_WEBHOOK_SECRET_GRACE_MINUTES: i64 = 60;
+const MAX_WEBHOOK_SECRET_GRACE_MINUTES: i64 = 7 * 24 * 60;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct RotateWebhookSecretRequest {
+    /// 이전 secret으로 서명된 요청도 허용할 기간 (분, 기본 60)
+    pub grace_minutes: Option<i64>,
+}
+
+/// POST /api/settings/webhook-secret/rotate
+/// 새 webhook secret을 만들고 등록된 GitHub webhook 설정을 모두 갱신한다.
+/// GitHub 쪽 갱신이 끝나기 전 전송분이 버려지지 않도록 유예 기간 동안 이전 secret도 허용
+pub async fn rotate_webhook_secret(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    body: Option<Json<RotateWebhookSecretRequest>>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let req = body.map(|Json(r)| r).unwrap_or_default();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/settings/webhook-secret/rotate", &format!("grace_minutes={:?}", req.grace_minutes));
+
+    let grace_minutes = req.grace_minutes.unwrap_or(DEFAULT_WEBHOOK_SECRET_GRACE_MINUTES);
+    if !(0..=MAX_WEBHOOK_SECRET_GRACE_MINUTES).contains(&grace_minutes) {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/webhook-secret/rotate", timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": format!("grace_minutes must be between 0 and {}", MAX_WEBHOOK_SECRET_GRACE_MINUTES)
+            })),
+        );
+    }
+
+    let projects = match ctx.project_repo.list().await {
+        Ok(projects) => projects,
+        Err(e) => {
+            warn!("[{}] Failed to list projects: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/webhook-secret/rotate", timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    };
+    let hooked: Vec<_> = projects.into_iter().filter(|p| p.webhook_id().is_some()).collect();
+
+    let webhook_url = match ctx.settings_repo.get("webhook_url").await {
+        Ok(url) => url,
+        Err(e) => {
+            warn!("[{}] Failed to get webhook URL: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/webhook-secret/rotate", timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    };
+    let webhook_url = match webhook_url {
+        Some(url) => url,
+        None if hooked.is_empty() => String::new(),
+        None => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/webhook-secret/rotate", timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": "Webhook URL not configured"})),
+            );
+        }
+    };
+
+    let old_secret = ctx.settings_repo.get(WEBHOOK_SECRET_SETTING).await.ok().flatten();
+    let new_secret = generate_webhook_secret();
+    let grace_until = Utc::now() + chrono::Duration::minutes(grace_minutes);
+
+    // 이전 secret을 먼저 저장해야 교체 중 들어온 요청도 검증됨
+    let saved = async {
+        if let Some(old) = &old_secret {
+            ctx.settings_repo.set(WEBHOOK_SECRET_PREVIOUS_SETTING, old).await?;
+            ctx.settings_repo
+                .set(WEBHOOK_SECRET_PREVIOUS_EXPIRES_SETTING, &grace_until.format(timezone::DB_FORMAT).to_string())
+                .await?;
+        }
+        ctx.settings_repo.set(WEBHOOK_SECRET_SETTING, &new_secret).await
+    }
+    .await;
+    if let Err(e) = saved {
+        warn!("[{}] Failed to save webhook secret: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/webhook-secret/rotate", timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Failed to save webhook secret: {}", e)})),
+        );
+    }
+
+    let mut results = Vec::new();
+    let mut failed = 0;
+    for project in &hooked {
+        let Some(webhook_id) = project.webhook_id() else { continue };
+        let outcome = async {
+            let repo_ref = parse_repo_url(&project.repo)
+                .ok_or_else(|| format!("Invalid repo URL format: {}", project.repo))?;
+            let hook_url = provider_webhook_url(&webhook_url, repo_ref.provider);
+            match repo_ref.provider {
+                GitProviderKind::GitHub => {
+                    let token = project_github_token(&ctx, project.id).await?;
+                    let hook_id = webhook_id.parse().map_err(|_| format!("Invalid webhook ID: {}", webhook_id))?;
+                    GitHubClient::new(token)
+                        .update_webhook_config(&repo_ref.owner, &repo_ref.name, hook_id, &hook_url, &new_secret)
+                        .await
+                        .map_err(|e| format!("GitHub API error: {}", e))
+                }
+                GitProviderKind::GitLab => {
+                    let token = ctx.settings_repo.get(GITLAB_TOKEN_SETTING).await
+                        .map_err(|e| format!("Failed to get GitLab token: {}", e))?
+                        .ok_or("GitLab token not configured")?;
+                    let base_url = gitlab_base_url(ctx.settings_repo.as_ref()).await
+                        .map_err(|e| format!("Failed to get GitLab URL: {}", e))?;
+                    let hook_id = webhook_id.parse().map_err(|_| format!("Invalid webhook ID: {}", webhook_id))?;
+                    GitLabClient::new(&base_url, token)
+                        .update_webhook(&repo_ref.owner, &repo_ref.name, hook_id, &hook_url, &new_secret)
+                        .await
+                        .map_err(|e| format!("GitLab API error: {}", e))
+                }
+                GitProviderKind::Bitbucket => {
+                    let token = ctx.settings_repo.get(BITBUCKET_TOKEN_SETTING).await
+                        .map_err(|e| format!("Failed to get Bitbucket token: {}", e))?
+                        .ok_or("Bitbucket token not configured")?;
+                    BitbucketClient::new(token)
+                        .update_webhook(&repo_ref.owner, &repo_ref.name, &webhook_id, &hook_url, &new_secret)
+                        .await
+                        .map_err(|e| format!("Bitbucket API error: {}", e))
+                }
+            }
+        }
+        .await;
+
+        if let Err(e) = &outcome {
+            warn!("[{}] Failed to update webhook secret for {}: {}", trace_id, project.name, e);
+            failed += 1;
+        }
+        results.push(serde_json::json!({
+            "project_id": project.id,
+            "project_name": project.name,
+            "updated": outcome.is_ok(),
+            "error": outcome.err(),
+        }));
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.webhook_secret_rotated",
+        trace_id = %trace_id,
+        grace_minutes = grace_minutes,
+        webhooks_updated = results.len() - failed,
+        webhooks_failed = failed,
+    );
+
+    ctx.logger.api_exit(&trace_id, "POST", "/api/settings/webhook-secret/rotate", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": failed == 0,
+            "webhook_secret": new_secret,
+            "previous_valid_until": old_secret.map(|_| grace_until.with_timezone(&timezone::display_timezone()).to_rfc3339()),
+            "webhooks": results,
+        })),
+    )
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SetDomainRequest {
     pub domain: String,
@@ -100,6 +296,13 @@ pub async fn set_domain(
         );
     }
 
+    // 리버스 프록시 서브도메인 라우팅에 바로 반영
+    ctx.set_base_domain(Some(domain.to_string()));
+    // HTTPS가 켜져 있으면 새 도메인 인증서 발급
+    if ctx.tls.enabled() {
+        ctx.tls.request_renewal();
+    }
+
     tracing::info!(
         target: "audit",
         event = "settings.domain_changed",
@@ -375,6 +578,138 @@ pub struct AllowedEmailsResponse {
     pub enabled: bool,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SetTerminalAccessRequest {
+    pub emails: Vec<String>,
+    /// 읽기 전용 터미널만 허용할 사용자 (생략하면 유지)
+    pub viewer_emails: Option<Vec<String>>,
+}
+
+/// 소문자/공백 정리, 중복 제거 후 형식이 잘못된 이메일이 있으면 Err(그 이메일)
+pub fn normalize_emails(emails: &[String]) -> Result<Vec<String>, String> {
+    let mut emails: Vec<String> = emails.iter()
+        .map(|e| e.trim().to_lowercase())
+        .filter(|e| !e.is_empty())
+        .collect();
+    emails.sort();
+    emails.dedup();
+
+    match emails.iter().find(|e| !e.contains('@') || e.len() < 5) {
+        Some(invalid) => Err(invalid.clone()),
+        None => Ok(emails),
+    }
+}
+
+async fn load_email_setting(ctx: &AppContext, key: &str) -> anyhow::Result<Vec<String>> {
+    Ok(ctx.settings_repo.get(key).await?
+        .and_then(|v| serde_json::from_str(&v).ok())
+        .unwrap_or_default())
+}
+
+/// GET /api/settings/terminal-access - 컨테이너/프로젝트 터미널을 열 수 있는 사용자 (비어 있으면 로그인한 모든 사용자)
+pub async fn get_terminal_access(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/settings/terminal-access", "");
+
+    let lists = match load_email_setting(&ctx, TERMINAL_ALLOWED_EMAILS_SETTING).await {
+        Ok(emails) => load_email_setting(&ctx, TERMINAL_VIEWER_EMAILS_SETTING).await.map(|viewers| (emails, viewers)),
+        Err(e) => Err(e),
+    };
+
+    match lists {
+        Ok((emails, viewer_emails)) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/terminal-access", timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "restricted": !emails.is_empty(),
+                    "emails": emails,
+                    "viewer_emails": viewer_emails,
+                })),
+            )
+        }
+        Err(e) => {
+            warn!("[{}] Failed to load terminal access: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/terminal-access", timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            )
+        }
+    }
+}
+
+/// POST /api/settings/terminal-access - 터미널 허용 사용자 목록 교체 (빈 배열이면 제한 해제).
+/// `viewer_emails`는 허용 목록에 없어도 읽기 전용 터미널은 열 수 있는 사용자
+pub async fn set_terminal_access(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(payload): Json<SetTerminalAccessRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/settings/terminal-access", &format!("emails={}", payload.emails.len()));
+
+    let normalized = normalize_emails(&payload.emails).and_then(|emails| {
+        match payload.viewer_emails.as_deref().map(normalize_emails).transpose() {
+            Ok(viewers) => Ok((emails, viewers)),
+            Err(invalid) => Err(invalid),
+        }
+    });
+    let (emails, viewer_emails) = match normalized {
+        Ok(lists) => lists,
+        Err(invalid) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/terminal-access", timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": format!("Invalid email format: {}", invalid)})),
+            );
+        }
+    };
+
+    let mut saved = ctx.settings_repo
+        .set(TERMINAL_ALLOWED_EMAILS_SETTING, &serde_json::to_string(&emails).unwrap_or_else(|_| "[]".to_string()))
+        .await;
+    if let (Ok(()), Some(viewers)) = (&saved, &viewer_emails) {
+        saved = ctx.settings_repo
+            .set(TERMINAL_VIEWER_EMAILS_SETTING, &serde_json::to_string(viewers).unwrap_or_else(|_| "[]".to_string()))
+            .await;
+    }
+    if let Err(e) = saved {
+        warn!("[{}] Failed to save terminal access: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/terminal-access", timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": "Database error"})),
+        );
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.terminal_access_updated",
+        trace_id = %trace_id,
+        emails = ?emails,
+        viewer_emails = ?viewer_emails,
+    );
+
+    ctx.logger.api_exit(&trace_id, "POST", "/api/settings/terminal-access", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": true,
+            "restricted": !emails.is_empty(),
+            "emails": emails,
+            "viewer_emails": viewer_emails,
+        })),
+    )
+}
+
 /// Helper function to get allowed emails from settings
 async fn get_allowed_emails_list(ctx: &AppContext) -> Vec<String> {
     match ctx.settings_repo.get("allowed_emails").await {
@@ -628,3 +963,1087 @@ fn get_fallback_ip() -> String {
         Err(_) => "localhost".to_string(),
     }
 }
+
+// Default disk quota settings
+#[derive(Debug, Deserialize)]
+pub struct SetDiskQuotaRequest {
+    /// MB. null이면 기본 쿼터 해제 (무제한)
+    pub default_disk_quota_mb: Option<i64>,
+}
+
+/// Set default per-project disk quota (projects without their own disk_quota_mb)
+pub async fn set_disk_quota(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(payload): Json<SetDiskQuotaRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/settings/disk-quota", &format!("default_disk_quota_mb={:?}", payload.default_disk_quota_mb));
+
+    let result = match payload.default_disk_quota_mb {
+        Some(quota) if quota <= 0 => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/disk-quota", timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": "default_disk_quota_mb must be positive"
+                })),
+            );
+        }
+        Some(quota) => ctx.settings_repo.set(DEFAULT_DISK_QUOTA_SETTING, &quota.to_string()).await,
+        None => ctx.settings_repo.delete(DEFAULT_DISK_QUOTA_SETTING).await,
+    };
+
+    if let Err(e) = result {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/disk-quota", timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({
+                "error": format!("Failed to save disk quota: {}", e)
+            })),
+        );
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.disk_quota_changed",
+        trace_id = %trace_id,
+        default_disk_quota_mb = ?payload.default_disk_quota_mb,
+    );
+
+    ctx.logger.api_exit(&trace_id, "POST", "/api/settings/disk-quota", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": true,
+            "default_disk_quota_mb": payload.default_disk_quota_mb
+        })),
+    )
+}
+
+/// Get default per-project disk quota
+pub async fn get_disk_quota(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/settings/disk-quota", "");
+
+    match ctx.settings_repo.get(DEFAULT_DISK_QUOTA_SETTING).await {
+        Ok(value) => {
+            let quota = value.and_then(|v| v.parse::<i64>().ok());
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/disk-quota", timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "default_disk_quota_mb": quota
+                })),
+            )
+        }
+        Err(e) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/disk-quota", timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to load disk quota: {}", e)
+                })),
+            )
+        }
+    }
+}
+
+// Queue wait alert settings
+#[derive(Debug, Deserialize)]
+pub struct SetQueueWaitAlertRequest {
+    /// 초. null이면 알림 해제
+    pub threshold_secs: Option<i64>,
+}
+
+/// Set queue wait alert threshold (builds queued longer than this emit queue_wait_exceeded)
+pub async fn set_queue_wait_alert(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(payload): Json<SetQueueWaitAlertRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/settings/queue-wait-alert", &format!("threshold_secs={:?}", payload.threshold_secs));
+
+    let result = match payload.threshold_secs {
+        Some(secs) if secs <= 0 => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/queue-wait-alert", timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": "threshold_secs must be positive"
+                })),
+            );
+        }
+        Some(secs) => ctx.settings_repo.set(QUEUE_WAIT_ALERT_SETTING, &secs.to_string()).await,
+        None => ctx.settings_repo.delete(QUEUE_WAIT_ALERT_SETTING).await,
+    };
+
+    if let Err(e) = result {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/queue-wait-alert", timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({
+                "error": format!("Failed to save queue wait alert: {}", e)
+            })),
+        );
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.queue_wait_alert_changed",
+        trace_id = %trace_id,
+        threshold_secs = ?payload.threshold_secs,
+    );
+
+    ctx.logger.api_exit(&trace_id, "POST", "/api/settings/queue-wait-alert", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": true,
+            "threshold_secs": payload.threshold_secs
+        })),
+    )
+}
+
+/// Get queue wait alert threshold
+pub async fn get_queue_wait_alert(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/settings/queue-wait-alert", "");
+
+    match queue_wait_threshold_secs(ctx.settings_repo.as_ref()).await {
+        Ok(threshold_secs) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/queue-wait-alert", timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "threshold_secs": threshold_secs
+                })),
+            )
+        }
+        Err(e) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/queue-wait-alert", timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to load queue wait alert: {}", e)
+                })),
+            )
+        }
+    }
+}
+
+/// 비밀번호를 제외한 SMTP 설정 응답
+fn smtp_response(settings: Option<&SmtpSettings>) -> serde_json::Value {
+    match settings {
+        Some(s) => serde_json::json!({
+            "configured": true,
+            "host": s.host,
+            "port": s.port,
+            "security": s.security,
+            "username": s.username,
+            "password_configured": s.password.is_some(),
+            "from": s.from,
+            "max_emails_per_hour": s.max_emails_per_hour,
+        }),
+        None => serde_json::json!({"configured": false}),
+    }
+}
+
+/// Set SMTP settings for email notifications (null disables). 비밀번호를 생략하면 기존 값 유지
+pub async fn set_smtp(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(payload): Json<Option<SmtpSettings>>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/settings/smtp", &format!("host={:?}", payload.as_ref().map(|s| &s.host)));
+
+    let result = match payload {
+        Some(mut settings) => {
+            if settings.password.is_none() {
+                if let Ok(Some(current)) = SmtpSettings::load(ctx.settings_repo.as_ref()).await {
+                    if current.username == settings.username {
+                        settings.password = current.password;
+                    }
+                }
+            }
+            settings.password = settings.password.filter(|p| !p.is_empty());
+            if let Err(msg) = settings.validate() {
+                ctx.logger.api_exit(&trace_id, "POST", "/api/settings/smtp", timer.elapsed_ms(), 400);
+                return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+            }
+            match serde_json::to_string(&settings) {
+                Ok(json) => ctx.settings_repo.set(SMTP_SETTING, &json).await.map(|_| Some(settings)),
+                Err(e) => Err(e.into()),
+            }
+        }
+        None => ctx.settings_repo.delete(SMTP_SETTING).await.map(|_| None),
+    };
+
+    let settings = match result {
+        Ok(settings) => settings,
+        Err(e) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/smtp", timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to save SMTP settings: {}", e)
+                })),
+            );
+        }
+    };
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.smtp_changed",
+        trace_id = %trace_id,
+        host = ?settings.as_ref().map(|s| &s.host),
+    );
+
+    ctx.logger.api_exit(&trace_id, "POST", "/api/settings/smtp", timer.elapsed_ms(), 200);
+    (StatusCode::OK, Json(smtp_response(settings.as_ref())))
+}
+
+/// Get SMTP settings (password omitted)
+pub async fn get_smtp(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/settings/smtp", "");
+
+    match SmtpSettings::load(ctx.settings_repo.as_ref()).await {
+        Ok(settings) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/smtp", timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(smtp_response(settings.as_ref())))
+        }
+        Err(e) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/smtp", timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to load SMTP settings: {}", e)
+                })),
+            )
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetStaleBuildTimeoutRequest {
+    /// 초. 0이면 감시 안 함, null이면 기본값 (2시간)
+    pub max_age_secs: Option<i64>,
+}
+
+/// Set stale build max age (Queued/Building builds older than this are failed by the watchdog)
+pub async fn set_stale_build_timeout(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(payload): Json<SetStaleBuildTimeoutRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/settings/stale-build-timeout", &format!("max_age_secs={:?}", payload.max_age_secs));
+
+    let result = match payload.max_age_secs {
+        Some(secs) if secs < 0 || (secs > 0 && secs < 600) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/stale-build-timeout", timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": "max_age_secs must be 0 (disabled) or at least 600"
+                })),
+            );
+        }
+        Some(secs) => ctx.settings_repo.set(STALE_BUILD_SETTING, &secs.to_string()).await,
+        None => ctx.settings_repo.delete(STALE_BUILD_SETTING).await,
+    };
+
+    if let Err(e) = result {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/stale-build-timeout", timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({
+                "error": format!("Failed to save stale build timeout: {}", e)
+            })),
+        );
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.stale_build_timeout_changed",
+        trace_id = %trace_id,
+        max_age_secs = ?payload.max_age_secs,
+    );
+
+    ctx.logger.api_exit(&trace_id, "POST", "/api/settings/stale-build-timeout", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": true,
+            "max_age_secs": payload.max_age_secs
+        })),
+    )
+}
+
+/// Get stale build max age (null = watchdog disabled)
+pub async fn get_stale_build_timeout(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/settings/stale-build-timeout", "");
+
+    match stale_build_max_age_secs(ctx.settings_repo.as_ref()).await {
+        Ok(max_age_secs) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/stale-build-timeout", timer.elapsed_ms(), 200);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "max_age_secs": max_age_secs
+                })),
+            )
+        }
+        Err(e) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/stale-build-timeout", timer.elapsed_ms(), 500);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to load stale build timeout: {}", e)
+                })),
+            )
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetImageProfilesRequest {
+    /// null이면 기본 카탈로그로 되돌림
+    pub profiles: Option<Vec<ImageProfile>>,
+}
+
+/// Replace the standalone container image profile catalog
+pub async fn set_image_profiles(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(payload): Json<SetImageProfilesRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "PUT", "/api/settings/image-profiles", &format!("profiles={:?}", payload.profiles.as_ref().map(|p| p.len())));
+
+    if let Some(Err(msg)) = payload.profiles.as_deref().map(validate_image_profiles) {
+        ctx.logger.api_exit(&trace_id, "PUT", "/api/settings/image-profiles", timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+    }
+
+    let result = match &payload.profiles {
+        Some(profiles) => match serde_json::to_string(profiles) {
+            Ok(json) => ctx.settings_repo.set(IMAGE_PROFILES_SETTING, &json).await,
+            Err(e) => Err(e.into()),
+        },
+        None => ctx.settings_repo.delete(IMAGE_PROFILES_SETTING).await,
+    };
+    if let Err(e) = result {
+        ctx.logger.api_exit(&trace_id, "PUT", "/api/settings/image-profiles", timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({
+                "error": format!("Failed to save image profiles: {}", e)
+            })),
+        );
+    }
+
+    let profiles = payload.profiles.unwrap_or_else(default_image_profiles);
+    let names: Vec<&str> = profiles.iter().map(|p| p.name.as_str()).collect();
+    tracing::info!(
+        target: "audit",
+        event = "settings.image_profiles_changed",
+        trace_id = %trace_id,
+        profiles = ?names,
+    );
+    ctx.image_profiles.set(profiles.clone());
+
+    ctx.logger.api_exit(&trace_id, "PUT", "/api/settings/image-profiles", timer.elapsed_ms(), 200);
+    (StatusCode::OK, Json(serde_json::json!({"profiles": profiles})))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImageProfileQuery {
+    pub image: Option<String>,
+}
+
+/// Get the image profile catalog (optionally the profile matching `?image=`)
+pub async fn get_image_profiles(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Query(query): Query<ImageProfileQuery>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/settings/image-profiles", "");
+
+    let body = match query.image {
+        Some(image) => serde_json::json!({"profile": ctx.image_profiles.find(&image), "image": image}),
+        None => serde_json::json!({"profiles": ctx.image_profiles.list()}),
+    };
+
+    ctx.logger.api_exit(&trace_id, "GET", "/api/settings/image-profiles", timer.elapsed_ms(), 200);
+    (StatusCode::OK, Json(body))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetLogLevelPatternsRequest {
+    /// null이면 기본 패턴으로 되돌림
+    pub patterns: Option<LogLevelPatterns>,
+}
+
+/// Replace the build log severity patterns (applies to builds persisted afterwards)
+pub async fn set_log_level_patterns(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(payload): Json<SetLogLevelPatternsRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "PUT", "/api/settings/log-level-patterns", "");
+
+    if let Some(Err(msg)) = payload.patterns.as_ref().map(validate_log_level_patterns) {
+        ctx.logger.api_exit(&trace_id, "PUT", "/api/settings/log-level-patterns", timer.elapsed_ms(), 400);
+        return (StatusCode::BAD_REQUEST, Json(serde_json::json!({"error": msg})));
+    }
+
+    let result = match &payload.patterns {
+        Some(patterns) => match serde_json::to_string(patterns) {
+            Ok(json) => ctx.settings_repo.set(LOG_LEVEL_PATTERNS_SETTING, &json).await,
+            Err(e) => Err(e.into()),
+        },
+        None => ctx.settings_repo.delete(LOG_LEVEL_PATTERNS_SETTING).await,
+    };
+    let patterns = payload.patterns.unwrap_or_else(default_log_level_patterns);
+    if let Err(e) = result.and_then(|_| ctx.log_levels.set(patterns.clone())) {
+        ctx.logger.api_exit(&trace_id, "PUT", "/api/settings/log-level-patterns", timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({
+                "error": format!("Failed to save log level patterns: {}", e)
+            })),
+        );
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.log_level_patterns_changed",
+        trace_id = %trace_id,
+        error_patterns = patterns.error.len(),
+        warn_patterns = patterns.warn.len(),
+    );
+
+    ctx.logger.api_exit(&trace_id, "PUT", "/api/settings/log-level-patterns", timer.elapsed_ms(), 200);
+    (StatusCode::OK, Json(serde_json::json!({"patterns": patterns})))
+}
+
+/// Get the build log severity patterns
+pub async fn get_log_level_patterns(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/settings/log-level-patterns", "");
+    let patterns = ctx.log_levels.patterns();
+    ctx.logger.api_exit(&trace_id, "GET", "/api/settings/log-level-patterns", timer.elapsed_ms(), 200);
+    (StatusCode::OK, Json(serde_json::json!({"patterns": patterns})))
+}
+
+/// Set cache size limits (LRU eviction by the cache eviction worker)
+pub async fn set_cache_limits(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(payload): Json<CacheLimits>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/settings/cache-limits", &format!("{:?}", payload));
+
+    if payload.default_mb == Some(0) || payload.per_type.values().any(|mb| *mb == 0) {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/cache-limits", timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": "Cache limits must be positive"
+            })),
+        );
+    }
+
+    let result = if payload.default_mb.is_none() && payload.per_type.is_empty() {
+        ctx.settings_repo.delete(CACHE_LIMITS_SETTING).await
+    } else {
+        match serde_json::to_string(&payload) {
+            Ok(json) => ctx.settings_repo.set(CACHE_LIMITS_SETTING, &json).await,
+            Err(e) => Err(e.into()),
+        }
+    };
+
+    if let Err(e) = result {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/cache-limits", timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({
+                "error": format!("Failed to save cache limits: {}", e)
+            })),
+        );
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.cache_limits_changed",
+        trace_id = %trace_id,
+        default_mb = ?payload.default_mb,
+        per_type = ?payload.per_type,
+    );
+
+    ctx.logger.api_exit(&trace_id, "POST", "/api/settings/cache-limits", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": true,
+            "limits": payload
+        })),
+    )
+}
+
+/// Get cache size limits and current usage per cache type
+pub async fn get_cache_limits(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/settings/cache-limits", "");
+
+    let limits = CacheLimits::load(&ctx).await;
+    let usage = cache_usage().await;
+
+    let caches: Vec<serde_json::Value> = {
+        let mut types: Vec<&String> = usage.keys().collect();
+        types.sort();
+        types
+            .into_iter()
+            .map(|cache_type| {
+                serde_json::json!({
+                    "cache_type": cache_type,
+                    "size_bytes": usage[cache_type],
+                    "limit_mb": limits.limit_for(cache_type),
+                })
+            })
+            .collect()
+    };
+
+    ctx.logger.api_exit(&trace_id, "GET", "/api/settings/cache-limits", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "limits": limits,
+            "caches": caches
+        })),
+    )
+}
+
+/// Set max parallel builds overall and per concurrency group (projects.concurrency_group)
+pub async fn set_concurrency_groups(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(payload): Json<ConcurrencyGroupLimits>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/settings/concurrency-groups", &format!("{:?}", payload));
+
+    if let Some(group) = payload.groups.keys().find(|g| !validate_concurrency_group(g)) {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/concurrency-groups", timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": format!("Invalid group name: {}", group)
+            })),
+        );
+    }
+    if payload.groups.values().any(|max| *max == 0) || payload.max_concurrent == Some(0) {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/concurrency-groups", timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": "Max parallel builds must be positive"
+            })),
+        );
+    }
+
+    let result = if payload.groups.is_empty() && payload.max_concurrent.is_none() {
+        ctx.settings_repo.delete(CONCURRENCY_GROUPS_SETTING).await
+    } else {
+        match serde_json::to_string(&payload) {
+            Ok(json) => ctx.settings_repo.set(CONCURRENCY_GROUPS_SETTING, &json).await,
+            Err(e) => Err(e.into()),
+        }
+    };
+
+    if let Err(e) = result {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/concurrency-groups", timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({
+                "error": format!("Failed to save concurrency groups: {}", e)
+            })),
+        );
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.concurrency_groups_changed",
+        trace_id = %trace_id,
+        max_concurrent = ?payload.max_concurrent,
+        groups = ?payload.groups,
+    );
+
+    ctx.logger.api_exit(&trace_id, "POST", "/api/settings/concurrency-groups", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": true,
+            "limits": payload
+        })),
+    )
+}
+
+/// Get concurrency group limits, member projects and running builds per group
+pub async fn get_concurrency_groups(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/settings/concurrency-groups", "");
+
+    let limits = ConcurrencyGroupLimits::load(&ctx).await;
+    let running = ctx.build_queue.processing_by_group().await;
+    let projects = match ctx.project_repo.list().await {
+        Ok(projects) => projects,
+        Err(e) => {
+            warn!("[{}] Failed to list projects: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/concurrency-groups", timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": "Database error"
+                })),
+            );
+        }
+    };
+
+    // 한도만 설정된 그룹과 프로젝트에만 지정된 그룹 모두 표시
+    let mut members: std::collections::BTreeMap<String, Vec<String>> =
+        limits.groups.keys().map(|g| (g.clone(), Vec::new())).collect();
+    for project in projects {
+        if let Some(group) = project.concurrency_group {
+            members.entry(group).or_default().push(project.name);
+        }
+    }
+
+    let groups: Vec<serde_json::Value> = members
+        .into_iter()
+        .map(|(group, projects)| {
+            serde_json::json!({
+                "running": running.get(&group).copied().unwrap_or(0),
+                "max_parallel": limits.groups.get(&group),
+                "group": group,
+                "projects": projects,
+            })
+        })
+        .collect();
+
+    ctx.logger.api_exit(&trace_id, "GET", "/api/settings/concurrency-groups", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "max_concurrent": limits.max_concurrent(),
+            "running": ctx.build_queue.processing_count().await,
+            "limits": limits,
+            "groups": groups
+        })),
+    )
+}
+
+/// Get cleanup worker schedules (with next run time)
+pub async fn get_cleanup_schedules(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/settings/cleanup-schedules", "");
+
+    let mut schedules = serde_json::Map::new();
+    for worker in CleanupWorker::ALL {
+        let schedule = CleanupSchedule::load(&ctx, worker).await;
+        // interval은 마지막 실행 시각 기준이라 cron만 다음 실행 시각을 알 수 있음
+        let next_run = matches!(schedule, CleanupSchedule::Cron { .. })
+            .then(|| schedule.next_after(chrono::Utc::now()).with_timezone(&timezone::display_timezone()).to_rfc3339());
+        schedules.insert(
+            worker.to_string(),
+            serde_json::json!({
+                "schedule": schedule,
+                "is_default": schedule == worker.default_schedule(),
+                "next_run": next_run,
+            }),
+        );
+    }
+
+    ctx.logger.api_exit(&trace_id, "GET", "/api/settings/cleanup-schedules", timer.elapsed_ms(), 200);
+    (StatusCode::OK, Json(serde_json::Value::Object(schedules)))
+}
+
+/// Set a cleanup worker schedule (`{"interval_secs": N}` or `{"cron": "m h dom mon dow"}`, null resets to default)
+pub async fn set_cleanup_schedule(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(worker): Path<String>,
+    Json(schedule): Json<Option<CleanupSchedule>>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/settings/cleanup-schedules/{}", worker);
+
+    ctx.logger.api_entry(&trace_id, "POST", &path, &format!("{:?}", schedule));
+
+    let Some(worker) = CleanupWorker::parse(&worker) else {
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "Unknown cleanup worker (containers, sessions, image_updates, github_teams)"
+            })),
+        );
+    };
+
+    let result = match &schedule {
+        Some(schedule) => {
+            if let Err(e) = schedule.validate() {
+                ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 400);
+                return (StatusCode::BAD_REQUEST, Json(serde_json::json!({ "error": e })));
+            }
+            match serde_json::to_string(schedule) {
+                Ok(json) => ctx.settings_repo.set(worker.setting_key(), &json).await,
+                Err(e) => Err(e.into()),
+            }
+        }
+        None => ctx.settings_repo.delete(worker.setting_key()).await,
+    };
+
+    if let Err(e) = result {
+        ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({
+                "error": format!("Failed to save cleanup schedule: {}", e)
+            })),
+        );
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.cleanup_schedule_changed",
+        trace_id = %trace_id,
+        worker = %worker,
+        schedule = ?schedule,
+    );
+
+    let effective = schedule.unwrap_or_else(|| worker.default_schedule());
+    ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": true,
+            "worker": worker.to_string(),
+            "schedule": effective,
+            "next_run": matches!(effective, CleanupSchedule::Cron { .. })
+                .then(|| effective.next_after(chrono::Utc::now()).with_timezone(&timezone::display_timezone()).to_rfc3339()),
+        })),
+    )
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetTimezoneRequest {
+    /// IANA 타임존 이름. null이면 UTC로 초기화
+    pub timezone: Option<String>,
+}
+
+/// Set display timezone (timestamps are stored in UTC, API responses use this offset)
+pub async fn set_timezone(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(payload): Json<SetTimezoneRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/settings/timezone", &format!("timezone={:?}", payload.timezone));
+
+    let tz = match payload.timezone.as_deref().map(timezone::parse_timezone) {
+        Some(Ok(tz)) => tz,
+        Some(Err(e)) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/timezone", timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": e
+                })),
+            );
+        }
+        None => chrono_tz::Tz::UTC,
+    };
+
+    let result = if payload.timezone.is_some() {
+        ctx.settings_repo.set(DISPLAY_TIMEZONE_SETTING, tz.name()).await
+    } else {
+        ctx.settings_repo.delete(DISPLAY_TIMEZONE_SETTING).await
+    };
+
+    if let Err(e) = result {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/timezone", timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({
+                "error": format!("Failed to save timezone: {}", e)
+            })),
+        );
+    }
+
+    timezone::set_display_timezone(tz);
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.timezone_changed",
+        trace_id = %trace_id,
+        timezone = %tz,
+    );
+
+    ctx.logger.api_exit(&trace_id, "POST", "/api/settings/timezone", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": true,
+            "timezone": tz.name(),
+            "now": timezone::now_display()
+        })),
+    )
+}
+
+/// Get display timezone
+pub async fn get_timezone(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/settings/timezone", "");
+
+    let tz = timezone::display_timezone();
+
+    ctx.logger.api_exit(&trace_id, "GET", "/api/settings/timezone", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "timezone": tz.name(),
+            "now": timezone::now_display()
+        })),
+    )
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetTlsRequest {
+    pub enabled: bool,
+    /// ACME 계정 연락처. 생략하면 저장된 값 유지 (켤 때는 필수)
+    #[serde(default)]
+    pub email: Option<String>,
+}
+
+/// Enable/disable HTTPS on the reverse proxy (certificates are obtained via ACME HTTP-01)
+pub async fn set_tls(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(payload): Json<SetTlsRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/settings/tls", &format!("enabled={}", payload.enabled));
+
+    let email = match payload.email.as_deref().map(str::trim) {
+        Some(email) => Some(email.to_string()),
+        None => ctx.settings_repo.get(ACME_EMAIL_SETTING).await.ok().flatten(),
+    };
+    if let Some(email) = email.as_deref().filter(|e| !e.contains('@')) {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/tls", timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": format!("Invalid ACME email: {}", email)
+            })),
+        );
+    }
+    if payload.enabled {
+        let missing = if email.is_none() {
+            Some("email is required to enable TLS")
+        } else if ctx.base_domain().is_none() {
+            Some("Set the base domain before enabling TLS")
+        } else {
+            None
+        };
+        if let Some(error) = missing {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/settings/tls", timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": error
+                })),
+            );
+        }
+    }
+
+    let mut result = ctx.settings_repo.set(TLS_ENABLED_SETTING, if payload.enabled { "true" } else { "false" }).await;
+    if let (Ok(()), Some(email)) = (&result, email.as_deref()) {
+        result = ctx.settings_repo.set(ACME_EMAIL_SETTING, email).await;
+    }
+    if let Err(e) = result {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/tls", timer.elapsed_ms(), 500);
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({
+                "error": format!("Failed to save TLS settings: {}", e)
+            })),
+        );
+    }
+
+    ctx.tls.set_enabled(payload.enabled);
+    if payload.enabled {
+        ctx.tls.request_renewal();
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "settings.tls_changed",
+        trace_id = %trace_id,
+        enabled = payload.enabled,
+    );
+
+    ctx.logger.api_exit(&trace_id, "POST", "/api/settings/tls", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": true,
+            "enabled": payload.enabled,
+            "email": email
+        })),
+    )
+}
+
+/// Get HTTPS status and certificate expiry
+pub async fn get_tls(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/settings/tls", "");
+
+    let email = match ctx.settings_repo.get(ACME_EMAIL_SETTING).await {
+        Ok(email) => email,
+        Err(e) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/settings/tls", timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to load TLS settings: {}", e)
+                })),
+            );
+        }
+    };
+
+    ctx.logger.api_exit(&trace_id, "GET", "/api/settings/tls", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "enabled": ctx.tls.enabled(),
+            "email": email,
+            "directory_url": acme::directory_url(),
+            "renew_before_days": RENEW_BEFORE_DAYS,
+            "certificates": ctx.tls.certificates(Utc::now()),
+            "errors": ctx.tls.errors()
+        })),
+    )
+}
+
+/// Check certificates now instead of waiting for the hourly renewal run
+pub async fn renew_tls(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/settings/tls/renew", "");
+
+    if !ctx.tls.enabled() {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/settings/tls/renew", timer.elapsed_ms(), 409);
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({
+                "error": "TLS is disabled"
+            })),
+        );
+    }
+
+    ctx.tls.request_renewal();
+
+    ctx.logger.api_exit(&trace_id, "POST", "/api/settings/tls/renew", timer.elapsed_ms(), 202);
+    (
+        StatusCode::ACCEPTED,
+        Json(serde_json::json!({
+            "success": true
+        })),
+    )
+}
@@ -0,0 +1,75 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+
+use crate::state::AppContext;
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::workers::system_cleanup::{run_cleanup, CleanupScope};
+
+#[derive(Debug, Deserialize)]
+pub struct CleanupRequest {
+    pub scopes: Vec<CleanupScope>,
+    /// logs 범위에서 이보다 오래된 로그 파일도 삭제 (일)
+    pub older_than_days: Option<u64>,
+}
+
+/// POST /api/system/cleanup
+/// 선택한 범위(logs, artifacts, images, sessions, containers)를 즉시 정리
+pub async fn trigger_cleanup(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Json(req): Json<CleanupRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/system/cleanup", &format!("scopes={:?}, older_than_days={:?}", req.scopes, req.older_than_days));
+
+    if req.scopes.is_empty() {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/system/cleanup", timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": "scopes must not be empty (logs, artifacts, images, sessions, containers)"
+            })),
+        );
+    }
+    if req.older_than_days == Some(0) {
+        ctx.logger.api_exit(&trace_id, "POST", "/api/system/cleanup", timer.elapsed_ms(), 400);
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": "older_than_days must be positive"
+            })),
+        );
+    }
+
+    // 같은 범위가 여러 번 들어와도 한 번만 실행
+    let mut scopes = req.scopes.clone();
+    let mut seen = std::collections::HashSet::new();
+    scopes.retain(|s| seen.insert(*s));
+
+    let results = run_cleanup(&ctx, &scopes, req.older_than_days).await;
+
+    tracing::info!(
+        target: "audit",
+        event = "system.cleanup",
+        trace_id = %trace_id,
+        scopes = ?scopes,
+        older_than_days = ?req.older_than_days,
+    );
+
+    let success = results.iter().all(|r| r.error.is_none());
+    ctx.logger.api_exit(&trace_id, "POST", "/api/system/cleanup", timer.elapsed_ms(), 200);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": success,
+            "results": results,
+        })),
+    )
+}
@@ -1,17 +1,62 @@
 use axum::{
-    extract::{ws::{Message, WebSocket, WebSocketUpgrade}, Path, State},
+    extract::{ws::{Message, WebSocket, WebSocketUpgrade}, Path, Query, State},
     response::Response,
+    Extension,
 };
 use bollard::container::LogOutput;
 use bollard::exec::StartExecResults;
+use futures_util::stream::{SplitSink, SplitStream};
 use futures_util::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
 use tokio::io::AsyncWriteExt;
 use tracing::{info, warn};
 
-use crate::application::ports::repositories::ContainerRepository;
+use crate::application::ports::repositories::{ContainerRepository, ProjectRepository, SettingsRepository};
+use crate::db::models::{Slot, User, UserRole};
 use crate::state::AppContext;
 
+/// 터미널을 열 수 있는 사용자 이메일 목록 (JSON 배열). 비어 있으면 로그인한 모든 사용자 허용
+pub const TERMINAL_ALLOWED_EMAILS_SETTING: &str = "terminal_allowed_emails";
+/// 읽기 전용 터미널(로그 관찰)만 열 수 있는 viewer 사용자 이메일 목록 (JSON 배열)
+pub const TERMINAL_VIEWER_EMAILS_SETTING: &str = "terminal_viewer_emails";
+
+/// 읽기 전용 모드에서 처음 보여줄 로그 줄 수
+const READ_ONLY_TAIL: &str = "200";
+
+#[derive(Debug, Default, Deserialize)]
+pub struct TerminalQuery {
+    /// "readonly"면 셸 없이 stdout/stderr만 관찰
+    pub mode: Option<String>,
+}
+
+impl TerminalQuery {
+    pub(crate) fn read_only(&self) -> bool {
+        matches!(self.mode.as_deref(), Some("readonly" | "read-only" | "read_only"))
+    }
+}
+
+/// 사용자의 터미널 권한
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TerminalAccess {
+    /// 셸 실행 가능 (stdin 포함)
+    Full,
+    /// 로그 관찰만 가능
+    ReadOnly,
+    Denied,
+}
+
+/// 허용 목록이 비어 있으면 모두 Full, 아니면 목록에 있으면 Full, viewer 목록에 있으면 ReadOnly.
+/// viewer 역할은 목록과 관계없이 최대 ReadOnly
+fn access_level(role: UserRole, email: &str, allowed: &[String], viewers: &[String]) -> TerminalAccess {
+    if allowed.is_empty() || allowed.iter().any(|e| e.eq_ignore_ascii_case(email)) {
+        if role == UserRole::Viewer { TerminalAccess::ReadOnly } else { TerminalAccess::Full }
+    } else if viewers.iter().any(|e| e.eq_ignore_ascii_case(email)) {
+        TerminalAccess::ReadOnly
+    } else {
+        TerminalAccess::Denied
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(tag = "type")]
 pub enum TerminalInput {
@@ -29,7 +74,7 @@ pub enum TerminalOutput {
     #[serde(rename = "error")]
     Error { message: String },
     #[serde(rename = "connected")]
-    Connected,
+    Connected { read_only: bool },
 }
 
 /// WebSocket handler for container terminal
@@ -37,59 +82,239 @@ pub enum TerminalOutput {
 pub async fn container_terminal(
     State(ctx): State<AppContext>,
     Path(container_db_id): Path<i64>,
+    Query(query): Query<TerminalQuery>,
+    user: Option<Extension<User>>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    let user = user.map(|Extension(u)| u);
+    let target = TerminalTarget::Container(container_db_id);
+    ws.on_upgrade(move |socket| handle_terminal_session(socket, ctx, user, target, query.read_only()))
+}
+
+/// WebSocket handler for project slot container terminal
+/// Route: /api/projects/{project_id}/slots/{slot}/terminal (slot: blue, green, active)
+pub async fn project_slot_terminal(
+    State(ctx): State<AppContext>,
+    Path((project_id, slot)): Path<(i64, String)>,
+    Query(query): Query<TerminalQuery>,
+    user: Option<Extension<User>>,
     ws: WebSocketUpgrade,
 ) -> Response {
-    ws.on_upgrade(move |socket| handle_terminal_session(socket, ctx, container_db_id))
+    let user = user.map(|Extension(u)| u);
+    let target = TerminalTarget::ProjectSlot { project_id, slot };
+    ws.on_upgrade(move |socket| handle_terminal_session(socket, ctx, user, target, query.read_only()))
+}
+
+/// 터미널 대상 (독립 컨테이너 또는 프로젝트 Blue/Green 컨테이너)
+#[derive(Debug)]
+enum TerminalTarget {
+    Container(i64),
+    ProjectSlot { project_id: i64, slot: String },
+}
+
+impl std::fmt::Display for TerminalTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TerminalTarget::Container(id) => write!(f, "container {}", id),
+            TerminalTarget::ProjectSlot { project_id, slot } => write!(f, "project {} slot {}", project_id, slot),
+        }
+    }
+}
+
+/// 경로의 slot 값 해석 ("active"는 현재 서비스 중인 슬롯)
+fn resolve_slot(slot: &str, active: Slot) -> Option<Slot> {
+    match slot.to_ascii_lowercase().as_str() {
+        "blue" => Some(Slot::Blue),
+        "green" => Some(Slot::Green),
+        "active" => Some(active),
+        _ => None,
+    }
+}
+
+async fn email_list(ctx: &AppContext, key: &str) -> Result<Vec<String>, String> {
+    match ctx.settings_repo.get(key).await {
+        Ok(Some(json)) => Ok(serde_json::from_str(&json).unwrap_or_default()),
+        Ok(None) => Ok(Vec::new()),
+        Err(e) => Err(format!("DB error: {}", e)),
+    }
+}
+
+/// 터미널 권한 확인: 로그인 사용자여야 하고, 허용/viewer 목록에 따라 셸 또는 읽기 전용
+async fn authorize(ctx: &AppContext, user: Option<&User>) -> Result<TerminalAccess, String> {
+    let Some(user) = user else {
+        return Err("Authentication required".to_string());
+    };
+
+    let allowed = email_list(ctx, TERMINAL_ALLOWED_EMAILS_SETTING).await?;
+    let viewers = email_list(ctx, TERMINAL_VIEWER_EMAILS_SETTING).await?;
+    match access_level(user.role, &user.email, &allowed, &viewers) {
+        TerminalAccess::Denied => Err(format!("{} is not allowed to open terminals", user.email)),
+        access => Ok(access),
+    }
+}
+
+/// 대상의 Docker 컨테이너 이름
+async fn resolve_target(ctx: &AppContext, target: &TerminalTarget) -> Result<String, String> {
+    match target {
+        TerminalTarget::Container(id) => match ctx.container_repo.get(*id).await {
+            Ok(Some(c)) => Ok(format!("container-{}", c.name)),
+            Ok(None) => Err("Container not found".to_string()),
+            Err(e) => Err(format!("DB error: {}", e)),
+        },
+        TerminalTarget::ProjectSlot { project_id, slot } => {
+            let project = match ctx.project_repo.get(*project_id).await {
+                Ok(Some(p)) if p.deleted_at.is_none() => p,
+                Ok(_) => return Err("Project not found".to_string()),
+                Err(e) => return Err(format!("DB error: {}", e)),
+            };
+            let Some(slot) = resolve_slot(slot, project.active_slot) else {
+                return Err(format!("Invalid slot: {} (use blue, green or active)", slot));
+            };
+            let container_id = match slot {
+                Slot::Blue => project.blue_container_id,
+                Slot::Green => project.green_container_id,
+            };
+            // 컨테이너 이름은 project-{id}-{slot}, 저장된 ID로 실제 존재 여부 확인
+            match container_id {
+                Some(id) if ctx.docker.is_container_running(&id).await => Ok(id),
+                _ => Err(format!("{} slot of project {} has no running container", slot, project.name)),
+            }
+        }
+    }
+}
+
+async fn send_error(ws_sender: &mut SplitSink<WebSocket, Message>, message: String) {
+    let msg = serde_json::to_string(&TerminalOutput::Error { message }).unwrap();
+    let _ = ws_sender.send(Message::Text(msg.into())).await;
 }
 
 async fn handle_terminal_session(
     socket: WebSocket,
     ctx: AppContext,
-    container_db_id: i64,
+    user: Option<User>,
+    target: TerminalTarget,
+    read_only: bool,
 ) {
-    info!("Terminal WebSocket connected for container DB ID: {}", container_db_id);
-
-    let (mut ws_sender, mut ws_receiver) = socket.split();
-
-    // 1. Get container info from DB
-    let container = match ctx.container_repo.get(container_db_id).await {
-        Ok(Some(c)) => c,
-        Ok(None) => {
-            let msg = serde_json::to_string(&TerminalOutput::Error {
-                message: "Container not found".to_string(),
-            }).unwrap();
-            let _ = ws_sender.send(Message::Text(msg.into())).await;
+    info!("Terminal WebSocket connected for {}", target);
+
+    let (mut ws_sender, ws_receiver) = socket.split();
+
+    // 1. Check permission and resolve the Docker container
+    let access = match authorize(&ctx, user.as_ref()).await {
+        Ok(access) => access,
+        Err(message) => {
+            warn!("Terminal access denied for {}: {}", target, message);
+            send_error(&mut ws_sender, message).await;
+            return;
+        }
+    };
+    // viewer는 요청과 상관없이 읽기 전용으로 연결 (Connected 메시지의 read_only로 알림)
+    let read_only = read_only || access == TerminalAccess::ReadOnly;
+
+    let docker_container_name = match resolve_target(&ctx, &target).await {
+        Ok(name) => name,
+        Err(message) => {
+            send_error(&mut ws_sender, message).await;
             return;
         }
+    };
+
+    tracing::info!(
+        target: "audit",
+        event = "terminal.opened",
+        user = user.as_ref().map(|u| u.email.as_str()).unwrap_or_default(),
+        terminal_target = %target,
+        docker_container = %docker_container_name,
+        read_only = read_only,
+    );
+
+    if read_only {
+        run_read_only_session(ws_sender, ws_receiver, &ctx, &docker_container_name).await;
+    } else {
+        run_exec_session(ws_sender, ws_receiver, &ctx, &docker_container_name).await;
+    }
+
+    info!("Terminal session ended for {}", target);
+}
+
+/// 읽기 전용: 컨테이너 stdout/stderr를 따라가며 전달, 입력은 받지 않음
+async fn run_read_only_session(
+    mut ws_sender: SplitSink<WebSocket, Message>,
+    mut ws_receiver: SplitStream<WebSocket>,
+    ctx: &AppContext,
+    docker_container_name: &str,
+) {
+    let mut log_stream = match ctx.docker.stream_container_logs(docker_container_name, Some(READ_ONLY_TAIL)).await {
+        Ok(stream) => Box::pin(stream),
         Err(e) => {
-            let msg = serde_json::to_string(&TerminalOutput::Error {
-                message: format!("DB error: {}", e),
-            }).unwrap();
-            let _ = ws_sender.send(Message::Text(msg.into())).await;
+            send_error(&mut ws_sender, format!("Failed to attach logs: {}", e)).await;
             return;
         }
     };
 
-    // Get Docker container name
-    let docker_container_name = format!("container-{}", container.name);
+    let connected_msg = serde_json::to_string(&TerminalOutput::Connected { read_only: true }).unwrap();
+    if ws_sender.send(Message::Text(connected_msg.into())).await.is_err() {
+        return;
+    }
+
+    loop {
+        tokio::select! {
+            chunk = log_stream.next() => match chunk {
+                Some(Ok(bytes)) => {
+                    if bytes.is_empty() {
+                        continue;
+                    }
+                    let data = String::from_utf8_lossy(&bytes).to_string();
+                    let msg = serde_json::to_string(&TerminalOutput::Output { data }).unwrap();
+                    if ws_sender.send(Message::Text(msg.into())).await.is_err() {
+                        break;
+                    }
+                }
+                Some(Err(e)) => {
+                    warn!("Read-only terminal log error: {}", e);
+                    break;
+                }
+                None => {
+                    send_error(&mut ws_sender, "Container stopped".to_string()).await;
+                    break;
+                }
+            },
+            msg = ws_receiver.next() => match msg {
+                Some(Ok(Message::Text(text))) => {
+                    // 입력은 무시 (resize는 셸이 없으므로 의미 없음)
+                    if let Ok(TerminalInput::Input { .. }) = serde_json::from_str::<TerminalInput>(&text) {
+                        send_error(&mut ws_sender, "Read-only terminal: input is disabled".to_string()).await;
+                    }
+                }
+                Some(Ok(Message::Close(_))) | None | Some(Err(_)) => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
+/// 셸 exec 세션: WebSocket 입력을 stdin으로, 출력은 WebSocket으로
+async fn run_exec_session(
+    mut ws_sender: SplitSink<WebSocket, Message>,
+    mut ws_receiver: SplitStream<WebSocket>,
+    ctx: &AppContext,
+    docker_container_name: &str,
+) {
 
     // 2. Create exec session (stty -echo로 서버 에코 비활성화, 클라이언트가 로컬 에코 담당)
     let (exec_id, exec_output) = match ctx.docker.create_exec_session(
-        &docker_container_name,
+        docker_container_name,
         vec!["/bin/sh".to_string(), "-c".to_string(), "stty -echo; exec /bin/sh".to_string()],
     ).await {
         Ok(result) => result,
         Err(e) => {
-            let msg = serde_json::to_string(&TerminalOutput::Error {
-                message: format!("Failed to create exec: {}", e),
-            }).unwrap();
-            let _ = ws_sender.send(Message::Text(msg.into())).await;
+            send_error(&mut ws_sender, format!("Failed to create exec: {}", e)).await;
             return;
         }
     };
 
     // Send connected message
-    let connected_msg = serde_json::to_string(&TerminalOutput::Connected).unwrap();
+    let connected_msg = serde_json::to_string(&TerminalOutput::Connected { read_only: false }).unwrap();
     if ws_sender.send(Message::Text(connected_msg.into())).await.is_err() {
         return;
     }
@@ -160,12 +385,37 @@ async fn handle_terminal_session(
             }
         }
         StartExecResults::Detached => {
-            let msg = serde_json::to_string(&TerminalOutput::Error {
-                message: "Exec detached unexpectedly".to_string(),
-            }).unwrap();
-            let _ = ws_sender.send(Message::Text(msg.into())).await;
+            send_error(&mut ws_sender, "Exec detached unexpectedly".to_string()).await;
         }
     }
+}
 
-    info!("Terminal session ended for container {}", container_db_id);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_slot() {
+        assert_eq!(resolve_slot("blue", Slot::Green), Some(Slot::Blue));
+        assert_eq!(resolve_slot("Green", Slot::Blue), Some(Slot::Green));
+        assert_eq!(resolve_slot("active", Slot::Green), Some(Slot::Green));
+        assert_eq!(resolve_slot("purple", Slot::Blue), None);
+    }
+
+    #[test]
+    fn test_access_level() {
+        let allowed = vec!["admin@example.com".to_string()];
+        let viewers = vec!["viewer@example.com".to_string()];
+
+        let dev = UserRole::Developer;
+        assert_eq!(access_level(dev, "anyone@example.com", &[], &viewers), TerminalAccess::Full);
+        assert_eq!(access_level(dev, "Admin@Example.com", &allowed, &viewers), TerminalAccess::Full);
+        assert_eq!(access_level(dev, "viewer@example.com", &allowed, &viewers), TerminalAccess::ReadOnly);
+        assert_eq!(access_level(dev, "other@example.com", &allowed, &viewers), TerminalAccess::Denied);
+
+        // viewer 역할은 읽기 전용만
+        assert_eq!(access_level(UserRole::Viewer, "anyone@example.com", &[], &viewers), TerminalAccess::ReadOnly);
+        assert_eq!(access_level(UserRole::Viewer, "Admin@Example.com", &allowed, &viewers), TerminalAccess::ReadOnly);
+        assert_eq!(access_level(UserRole::Viewer, "other@example.com", &allowed, &viewers), TerminalAccess::Denied);
+    }
 }
@@ -0,0 +1,284 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Extension, Json,
+};
+use serde::Deserialize;
+use std::time::Instant;
+use tower_cookies::Cookies;
+use tracing::warn;
+
+use crate::application::ports::repositories::SessionRepository;
+use crate::db::models::User;
+use crate::infrastructure::database::UserTotp;
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::infrastructure::totp::{generate_totp_secret, otpauth_uri, verify_totp};
+use crate::state::{AppContext, LoginKey};
+use super::middleware::login_guard::record_login_failure;
+use super::middleware::ApiTokenAuth;
+
+const SESSION_COOKIE: &str = "easycicd_session";
+
+type ApiError = (StatusCode, Json<serde_json::Value>);
+
+#[derive(Debug, Deserialize)]
+pub struct TotpCodeRequest {
+    pub code: String,
+}
+
+fn error(status: StatusCode, message: &str) -> ApiError {
+    (status, Json(serde_json::json!({"error": message})))
+}
+
+fn database_error(trace_id: &str, e: anyhow::Error) -> ApiError {
+    warn!("[{}] TOTP database error: {}", trace_id, e);
+    error(StatusCode::INTERNAL_SERVER_ERROR, "Database error")
+}
+
+/// 로그인 사용자와 세션 ID. TOTP 관리는 브라우저 세션에서만 (API 토큰 불가)
+fn session_user(
+    cookies: &Cookies,
+    user: Option<Extension<User>>,
+    api_token: Option<Extension<ApiTokenAuth>>,
+) -> Result<(User, String), ApiError> {
+    if api_token.is_some() {
+        return Err(error(StatusCode::FORBIDDEN, "Two-factor authentication requires a browser session"));
+    }
+    match (user, cookies.get(SESSION_COOKIE)) {
+        (Some(Extension(user)), Some(cookie)) => Ok((user, cookie.value().to_string())),
+        _ => Err(error(StatusCode::UNAUTHORIZED, "Authentication required")),
+    }
+}
+
+/// 코드 확인 (잠금 확인, 실패 기록, 같은 코드 재사용 거부)
+///
+/// 실패는 이메일별 로그인 실패로 기록해 추측 공격을 잠금
+async fn check_code(ctx: &AppContext, trace_id: &str, user: &User, totp: &UserTotp, code: &str) -> Result<(), ApiError> {
+    let key = LoginKey::email(&user.email);
+    if ctx.login_guard.locked_for(&key, Instant::now()).is_some() {
+        return Err(error(StatusCode::TOO_MANY_REQUESTS, "Too many failed attempts. Try again later."));
+    }
+    let Some(step) = verify_totp(&totp.secret, code, chrono::Utc::now().timestamp()) else {
+        record_login_failure(ctx, key, "invalid_totp");
+        return Err(error(StatusCode::BAD_REQUEST, "Invalid code"));
+    };
+    match ctx.totp_repo.use_step(user.id, step).await {
+        Ok(true) => {
+            ctx.login_guard.record_success(&key);
+            Ok(())
+        }
+        Ok(false) => Err(error(StatusCode::BAD_REQUEST, "Code already used. Wait for the next code.")),
+        Err(e) => Err(database_error(trace_id, e)),
+    }
+}
+
+/// GET /api/auth/totp - 내 2단계 인증 상태
+pub async fn get_status(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    user: Option<Extension<User>>,
+    api_token: Option<Extension<ApiTokenAuth>>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/api/auth/totp", "");
+
+    let result = async {
+        let (user, session_id) = session_user(&cookies, user, api_token)?;
+        let totp = ctx.totp_repo.get(user.id).await.map_err(|e| database_error(&trace_id, e))?;
+        let session = ctx.session_repo.get(&session_id).await.map_err(|e| database_error(&trace_id, e))?;
+        Ok::<_, ApiError>(serde_json::json!({
+            "enabled": totp.as_ref().is_some_and(|t| t.enabled),
+            "pending": totp.as_ref().is_some_and(|t| !t.enabled),
+            "mfa_verified_at": session.and_then(|s| s.mfa_verified_at),
+        }))
+    }
+    .await;
+
+    match result {
+        Ok(body) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/auth/totp", timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(body))
+        }
+        Err((status, body)) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/api/auth/totp", timer.elapsed_ms(), status.as_u16());
+            (status, body)
+        }
+    }
+}
+
+/// POST /api/auth/totp/enroll - 등록 시작 (비밀과 otpauth URI 반환, activate로 확인해야 활성화)
+pub async fn enroll(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    user: Option<Extension<User>>,
+    api_token: Option<Extension<ApiTokenAuth>>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/auth/totp/enroll", "");
+
+    let result = async {
+        let (user, _) = session_user(&cookies, user, api_token)?;
+        let secret = generate_totp_secret();
+        match ctx.totp_repo.set_pending(user.id, &secret).await {
+            Ok(true) => Ok(serde_json::json!({
+                "secret": secret,
+                "otpauth_uri": otpauth_uri(&user.email, &secret),
+            })),
+            Ok(false) => Err(error(StatusCode::CONFLICT, "Two-factor authentication is already enabled")),
+            Err(e) => Err(database_error(&trace_id, e)),
+        }
+    }
+    .await;
+
+    match result {
+        Ok(body) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/auth/totp/enroll", timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(body))
+        }
+        Err((status, body)) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/auth/totp/enroll", timer.elapsed_ms(), status.as_u16());
+            (status, body)
+        }
+    }
+}
+
+/// POST /api/auth/totp/activate - 인증 앱의 첫 코드로 등록 완료 (현재 세션은 인증된 것으로 기록)
+pub async fn activate(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    user: Option<Extension<User>>,
+    api_token: Option<Extension<ApiTokenAuth>>,
+    Json(req): Json<TotpCodeRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/auth/totp/activate", "");
+
+    let result = async {
+        let (user, session_id) = session_user(&cookies, user, api_token)?;
+        let totp = match ctx.totp_repo.get(user.id).await.map_err(|e| database_error(&trace_id, e))? {
+            Some(totp) if totp.enabled => {
+                return Err(error(StatusCode::CONFLICT, "Two-factor authentication is already enabled"));
+            }
+            Some(totp) => totp,
+            None => return Err(error(StatusCode::BAD_REQUEST, "Start enrollment first (POST /api/auth/totp/enroll)")),
+        };
+        check_code(&ctx, &trace_id, &user, &totp, &req.code).await?;
+        ctx.totp_repo.enable(user.id).await.map_err(|e| database_error(&trace_id, e))?;
+        ctx.session_repo.mark_mfa_verified(&session_id).await.map_err(|e| database_error(&trace_id, e))?;
+
+        tracing::info!(
+            target: "audit",
+            event = "user.totp_enabled",
+            trace_id = %trace_id,
+            user_id = user.id,
+            email = %user.email,
+        );
+        Ok(serde_json::json!({"enabled": true}))
+    }
+    .await;
+
+    match result {
+        Ok(body) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/auth/totp/activate", timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(body))
+        }
+        Err((status, body)) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/auth/totp/activate", timer.elapsed_ms(), status.as_u16());
+            (status, body)
+        }
+    }
+}
+
+/// POST /api/auth/totp/verify - 2단계 인증. 현재 세션에서 민감한 작업을 일정 시간 허용
+pub async fn verify(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    user: Option<Extension<User>>,
+    api_token: Option<Extension<ApiTokenAuth>>,
+    Json(req): Json<TotpCodeRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/api/auth/totp/verify", "");
+
+    let result = async {
+        let (user, session_id) = session_user(&cookies, user, api_token)?;
+        let totp = match ctx.totp_repo.get(user.id).await.map_err(|e| database_error(&trace_id, e))? {
+            Some(totp) if totp.enabled => totp,
+            _ => return Err(error(StatusCode::BAD_REQUEST, "Two-factor authentication is not enabled")),
+        };
+        check_code(&ctx, &trace_id, &user, &totp, &req.code).await?;
+        ctx.session_repo.mark_mfa_verified(&session_id).await.map_err(|e| database_error(&trace_id, e))?;
+        Ok(serde_json::json!({"verified": true}))
+    }
+    .await;
+
+    match result {
+        Ok(body) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/auth/totp/verify", timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(body))
+        }
+        Err((status, body)) => {
+            ctx.logger.api_exit(&trace_id, "POST", "/api/auth/totp/verify", timer.elapsed_ms(), status.as_u16());
+            (status, body)
+        }
+    }
+}
+
+/// DELETE /api/auth/totp - 2단계 인증 해제 (현재 코드 필요)
+pub async fn disable(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    user: Option<Extension<User>>,
+    api_token: Option<Extension<ApiTokenAuth>>,
+    Json(req): Json<TotpCodeRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "DELETE", "/api/auth/totp", "");
+
+    let result = async {
+        let (user, _) = session_user(&cookies, user, api_token)?;
+        let totp = match ctx.totp_repo.get(user.id).await.map_err(|e| database_error(&trace_id, e))? {
+            Some(totp) if totp.enabled => totp,
+            _ => return Err(error(StatusCode::BAD_REQUEST, "Two-factor authentication is not enabled")),
+        };
+        check_code(&ctx, &trace_id, &user, &totp, &req.code).await?;
+        ctx.totp_repo.delete(user.id).await.map_err(|e| database_error(&trace_id, e))?;
+
+        tracing::info!(
+            target: "audit",
+            event = "user.totp_disabled",
+            trace_id = %trace_id,
+            user_id = user.id,
+            email = %user.email,
+        );
+        Ok(serde_json::json!({"enabled": false}))
+    }
+    .await;
+
+    match result {
+        Ok(body) => {
+            ctx.logger.api_exit(&trace_id, "DELETE", "/api/auth/totp", timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(body))
+        }
+        Err((status, body)) => {
+            ctx.logger.api_exit(&trace_id, "DELETE", "/api/auth/totp", timer.elapsed_ms(), status.as_u16());
+            (status, body)
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Extension, Json,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tracing::warn;
+
+use crate::application::ports::repositories::{ProjectRepository, UserRepository};
+use crate::db::models::{User, UserRole};
+use crate::infrastructure::database::ProjectPermission;
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::state::AppContext;
+
+#[derive(Debug, Deserialize)]
+pub struct SetRoleRequest {
+    pub role: UserRole,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetMembershipRequest {
+    /// 빈 목록이면 멤버십 해제
+    pub permissions: Vec<ProjectPermission>,
+}
+
+/// 프로젝트 멤버십 (사용자에게 부여된 프로젝트별 권한)
+#[derive(Debug, Serialize)]
+pub struct ProjectMembership {
+    pub project_id: i64,
+    pub permissions: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UserWithMemberships {
+    #[serde(flatten)]
+    pub user: User,
+    pub projects: Vec<ProjectMembership>,
+}
+
+async fn memberships(ctx: &AppContext, email: &str) -> anyhow::Result<Vec<ProjectMembership>> {
+    let mut by_project: BTreeMap<i64, Vec<String>> = BTreeMap::new();
+    for grant in ctx.project_permission_repo.list_by_email(email).await? {
+        by_project.entry(grant.project_id).or_default().push(grant.permission);
+    }
+    Ok(by_project
+        .into_iter()
+        .map(|(project_id, permissions)| ProjectMembership { project_id, permissions })
+        .collect())
+}
+
+/// GET /admin/users - 사용자 목록 (역할, 프로젝트 멤버십 포함)
+pub async fn list_users(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "GET", "/admin/users", "");
+
+    let result = async {
+        let mut users = Vec::new();
+        for user in ctx.user_repo.list().await? {
+            let projects = memberships(&ctx, &user.email).await?;
+            users.push(UserWithMemberships { user, projects });
+        }
+        anyhow::Ok(users)
+    }
+    .await;
+
+    match result {
+        Ok(users) => {
+            ctx.logger.api_exit(&trace_id, "GET", "/admin/users", timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({"users": users})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to list users: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "GET", "/admin/users", timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// PUT /admin/users/{id}/role - 역할 변경 (마지막 admin은 변경 불가)
+pub async fn set_role(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    admin: Option<Extension<User>>,
+    Path(user_id): Path<i64>,
+    Json(req): Json<SetRoleRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/admin/users/{}/role", user_id);
+
+    ctx.logger.api_entry(&trace_id, "PUT", &path, &format!("role={}", req.role));
+
+    let users = match ctx.user_repo.list().await {
+        Ok(users) => users,
+        Err(e) => {
+            warn!("[{}] Failed to list users: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+    let Some(target) = users.iter().find(|u| u.id == user_id) else {
+        ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 404);
+        return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "User not found"})));
+    };
+
+    let admins = users.iter().filter(|u| u.role == UserRole::Admin).count();
+    if target.role == UserRole::Admin && req.role != UserRole::Admin && admins == 1 {
+        ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 409);
+        return (StatusCode::CONFLICT, Json(serde_json::json!({"error": "Cannot change the role of the last admin"})));
+    }
+
+    match ctx.user_repo.set_role(user_id, req.role).await {
+        Ok(_) => {
+            tracing::info!(
+                target: "audit",
+                event = "user.role_changed",
+                trace_id = %trace_id,
+                email = %target.email,
+                from = %target.role,
+                to = %req.role,
+                user = admin.as_ref().map(|Extension(u)| u.email.as_str()).unwrap_or_default(),
+            );
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({"id": user_id, "email": target.email, "role": req.role})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to set user role: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// PUT /admin/users/{id}/projects/{project_id} - 프로젝트 멤버십 설정
+///
+/// 사용자의 프로젝트 권한을 `permissions`와 같게 맞춤 (없는 권한은 부여, 빠진 권한은 회수)
+pub async fn set_project_membership(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    admin: Option<Extension<User>>,
+    Path((user_id, project_id)): Path<(i64, i64)>,
+    Json(req): Json<SetMembershipRequest>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/admin/users/{}/projects/{}", user_id, project_id);
+
+    ctx.logger.api_entry(&trace_id, "PUT", &path, &format!("permissions={:?}", req.permissions));
+
+    let target = match ctx.user_repo.get(user_id).await {
+        Ok(Some(user)) => user,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "User not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get user: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+    match ctx.project_repo.get(project_id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Project not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    }
+
+    let granted_by = admin.as_ref().map(|Extension(u)| u.email.clone()).unwrap_or_default();
+    let result = async {
+        let repo = &ctx.project_permission_repo;
+        let wanted: Vec<String> = req.permissions.iter().map(|p| p.to_string()).collect();
+        for grant in repo.list_by_email(&target.email).await? {
+            if grant.project_id == project_id && !wanted.contains(&grant.permission) {
+                repo.revoke(project_id, grant.id).await?;
+            }
+        }
+        for permission in &req.permissions {
+            repo.grant(project_id, &target.email, *permission, Some(&granted_by)).await?;
+        }
+        memberships(&ctx, &target.email).await
+    }
+    .await;
+
+    match result {
+        Ok(projects) => {
+            tracing::info!(
+                target: "audit",
+                event = "user.project_membership_changed",
+                trace_id = %trace_id,
+                email = %target.email,
+                project_id,
+                permissions = ?req.permissions,
+                user = %granted_by,
+            );
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({"id": user_id, "email": target.email, "projects": projects})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to set project membership: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "PUT", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
+
+/// DELETE /admin/users/{id}/totp - 2단계 인증 초기화 (인증 앱을 잃어버린 사용자)
+pub async fn reset_totp(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    admin: Option<Extension<User>>,
+    Path(user_id): Path<i64>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/admin/users/{}/totp", user_id);
+
+    ctx.logger.api_entry(&trace_id, "DELETE", &path, "");
+
+    let target = match ctx.user_repo.get(user_id).await {
+        Ok(Some(user)) => user,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 404);
+            return (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "User not found"})));
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get user: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 500);
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})));
+        }
+    };
+
+    match ctx.totp_repo.delete(user_id).await {
+        Ok(true) => {
+            tracing::info!(
+                target: "audit",
+                event = "user.totp_reset",
+                trace_id = %trace_id,
+                email = %target.email,
+                user = admin.as_ref().map(|Extension(u)| u.email.as_str()).unwrap_or_default(),
+            );
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 200);
+            (StatusCode::OK, Json(serde_json::json!({"success": true})))
+        }
+        Ok(false) => {
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 404);
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": "Two-factor authentication is not enrolled"})))
+        }
+        Err(e) => {
+            warn!("[{}] Failed to reset TOTP: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "DELETE", &path, timer.elapsed_ms(), 500);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"error": "Database error"})))
+        }
+    }
+}
@@ -1,24 +1,40 @@
 use axum::{
-    extract::State,
+    extract::{Path, State},
     http::{HeaderMap, StatusCode},
     response::IntoResponse,
     Json,
 };
+use chrono::Utc;
 use globset::{Glob, GlobSetBuilder};
 use hmac::{Hmac, Mac};
 use serde::{Deserialize, Serialize};
 use sha2::Sha256;
 use tracing::{info, warn};
 
-use crate::db::models::{BuildStatus, CreateBuild};
-use crate::events::Event;
+use crate::application::ports::git_provider::{parse_repo_url, GitProviderKind};
+use crate::db::models::{BuildTrigger, CreateBuild};
+use crate::application::services::BITBUCKET_TOKEN_SETTING;
+use crate::bitbucket::{BitbucketClient, BitbucketPushEvent, ChangedFiles};
+use crate::gitlab::GitLabPushEvent;
 use crate::state::AppContext;
 use crate::application::ports::repositories::{ProjectRepository, BuildRepository, SettingsRepository};
-use crate::application::events::event_bus::EventBus;
+use super::previews::teardown_preview;
 use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::infrastructure::database::{IdempotencyReservation, MAX_IDEMPOTENCY_KEY_LEN};
+use crate::infrastructure::timezone;
 
 type HmacSha256 = Hmac<Sha256>;
 
+pub(crate) const WEBHOOK_SECRET_SETTING: &str = "webhook_secret";
+/// secret 교체 후 유예 기간 동안 함께 허용하는 이전 secret
+pub(crate) const WEBHOOK_SECRET_PREVIOUS_SETTING: &str = "webhook_secret_previous";
+/// 이전 secret 만료 시각 (UTC, DB 형식)
+pub(crate) const WEBHOOK_SECRET_PREVIOUS_EXPIRES_SETTING: &str = "webhook_secret_previous_expires_at";
+
+/// X-GitHub-Delivery / X-Gitlab-Event-UUID / X-Request-UUID 중복 확인용 idempotency scope
+const WEBHOOK_IDEMPOTENCY_SCOPE: &str = "webhook";
+
+/// Push 이벤트 (GitHub payload 형식, GitLab/Bitbucket payload는 `from_gitlab`/`from_bitbucket`으로 변환)
 #[derive(Debug, Deserialize)]
 pub struct GithubWebhook {
     #[serde(rename = "ref")]
@@ -49,10 +65,49 @@ pub struct Author {
     pub email: String,
 }
 
-#[derive(Serialize)]
+/// GitHub pull_request 이벤트 (PR 프리뷰)
+#[derive(Debug, Deserialize)]
+pub struct PullRequestEvent {
+    pub action: String,
+    pub number: i64,
+    pub pull_request: PullRequest,
+    pub repository: Repository,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PullRequest {
+    pub title: String,
+    pub head: PullRequestRef,
+    pub base: PullRequestRef,
+    pub user: Option<PullRequestUser>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PullRequestRef {
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    pub sha: String,
+    /// 포크 저장소가 삭제되면 null
+    pub repo: Option<Repository>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PullRequestUser {
+    pub login: String,
+}
+
+/// delivery 중복 확인 후 처리하는 이벤트
+enum WebhookEvent {
+    Push(GithubWebhook),
+    PullRequest(PullRequestEvent),
+}
+
+#[derive(Serialize, Deserialize)]
 pub struct WebhookResponse {
     message: String,
     build_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    simulated: bool,
 }
 
 pub async fn github_webhook(
@@ -66,7 +121,7 @@ pub async fn github_webhook(
     ctx.logger.api_entry(&trace_id, "POST", "/webhook/github", "webhook_received");
 
     // Verify signature
-    if let Err(e) = verify_signature(&ctx, &headers, &body).await {
+    if let Err(e) = verify_signature(&ctx, &headers, "x-hub-signature-256", &body).await {
         warn!("[{}] Webhook signature verification failed: {}", trace_id, e);
         ctx.logger.api_exit(&trace_id, "POST", "/webhook/github", timer.elapsed_ms(), 401);
         return (
@@ -74,13 +129,20 @@ pub async fn github_webhook(
             Json(WebhookResponse {
                 message: "Invalid signature".to_string(),
                 build_id: None,
+                simulated: false,
             }),
         );
     }
 
-    // Parse webhook payload
-    let webhook: GithubWebhook = match serde_json::from_str(&body) {
-        Ok(w) => w,
+    // Parse webhook payload (pull_request는 PR 프리뷰, 나머지는 push로 처리)
+    let is_pull_request = headers.get("x-github-event").and_then(|v| v.to_str().ok()) == Some("pull_request");
+    let parsed = if is_pull_request {
+        serde_json::from_str(&body).map(WebhookEvent::PullRequest)
+    } else {
+        serde_json::from_str(&body).map(WebhookEvent::Push)
+    };
+    let event = match parsed {
+        Ok(event) => event,
         Err(e) => {
             warn!("[{}] Failed to parse webhook payload: {}", trace_id, e);
             ctx.logger.api_exit(&trace_id, "POST", "/webhook/github", timer.elapsed_ms(), 400);
@@ -89,12 +151,315 @@ pub async fn github_webhook(
                 Json(WebhookResponse {
                     message: format!("Invalid payload: {}", e),
                     build_id: None,
+                    simulated: false,
+                }),
+            );
+        }
+    };
+
+    // GitHub 재전송(같은 delivery id)은 빌드를 다시 만들지 않고 처음 응답 반환
+    let delivery_id = delivery_id(&headers, "x-github-delivery");
+    let (status, response) = process_delivery(&ctx, &trace_id, delivery_id, GitProviderKind::GitHub, event).await;
+
+    ctx.logger.api_exit(&trace_id, "POST", "/webhook/github", timer.elapsed_ms(), status.as_u16());
+    (status, Json(response))
+}
+
+/// GitLab push webhook. secret은 서명 대신 X-Gitlab-Token 헤더로 그대로 전달된다
+pub async fn gitlab_webhook(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    body: String,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/webhook/gitlab", "webhook_received");
+
+    if let Err(e) = verify_gitlab_token(&ctx, &headers).await {
+        warn!("[{}] GitLab webhook token verification failed: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "POST", "/webhook/gitlab", timer.elapsed_ms(), 401);
+        return (
+            StatusCode::UNAUTHORIZED,
+            Json(WebhookResponse {
+                message: "Invalid token".to_string(),
+                build_id: None,
+                simulated: false,
+            }),
+        );
+    }
+
+    // push 외 이벤트(tag push, merge request 등)는 무시
+    let event = headers.get("x-gitlab-event").and_then(|v| v.to_str().ok()).unwrap_or("");
+    if event != "Push Hook" {
+        info!("[{}] Ignoring GitLab event: {}", trace_id, event);
+        ctx.logger.api_exit(&trace_id, "POST", "/webhook/gitlab", timer.elapsed_ms(), 200);
+        return (
+            StatusCode::OK,
+            Json(WebhookResponse {
+                message: format!("Ignored event: {}", event),
+                build_id: None,
+                simulated: false,
+            }),
+        );
+    }
+
+    let event: GitLabPushEvent = match serde_json::from_str(&body) {
+        Ok(e) => e,
+        Err(e) => {
+            warn!("[{}] Failed to parse GitLab webhook payload: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", "/webhook/gitlab", timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(WebhookResponse {
+                    message: format!("Invalid payload: {}", e),
+                    build_id: None,
+                    simulated: false,
+                }),
+            );
+        }
+    };
+
+    let delivery_id = delivery_id(&headers, "x-gitlab-event-uuid");
+    let (status, response) = process_delivery(&ctx, &trace_id, delivery_id, GitProviderKind::GitLab, WebhookEvent::Push(from_gitlab(event))).await;
+
+    ctx.logger.api_exit(&trace_id, "POST", "/webhook/gitlab", timer.elapsed_ms(), status.as_u16());
+    (status, Json(response))
+}
+
+/// GitLab push payload를 공통 push 형식으로 변환
+fn from_gitlab(event: GitLabPushEvent) -> GithubWebhook {
+    let to_commit = |c: &crate::gitlab::GitLabPushCommit| Commit {
+        id: c.id.clone(),
+        message: c.message.clone(),
+        author: Author {
+            name: c.author.name.clone(),
+            email: c.author.email.clone(),
+        },
+        added: c.added.clone(),
+        modified: c.modified.clone(),
+        removed: c.removed.clone(),
+    };
+
+    GithubWebhook {
+        git_ref: event.git_ref.clone(),
+        repository: Repository { full_name: event.project.path_with_namespace.clone() },
+        head_commit: event.head_commit().map(to_commit),
+        commits: Some(event.commits.iter().map(to_commit).collect()),
+    }
+}
+
+/// Bitbucket Cloud push webhook. secret으로 서명한 X-Hub-Signature(sha256=...) 헤더를 검증한다
+pub async fn bitbucket_webhook(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    body: String,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+
+    ctx.logger.api_entry(&trace_id, "POST", "/webhook/bitbucket", "webhook_received");
+
+    if let Err(e) = verify_signature(&ctx, &headers, "x-hub-signature", &body).await {
+        warn!("[{}] Bitbucket webhook signature verification failed: {}", trace_id, e);
+        ctx.logger.api_exit(&trace_id, "POST", "/webhook/bitbucket", timer.elapsed_ms(), 401);
+        return (
+            StatusCode::UNAUTHORIZED,
+            Json(WebhookResponse {
+                message: "Invalid signature".to_string(),
+                build_id: None,
+                simulated: false,
+            }),
+        );
+    }
+
+    let event_key = headers.get("x-event-key").and_then(|v| v.to_str().ok()).unwrap_or("");
+    if event_key != "repo:push" {
+        info!("[{}] Ignoring Bitbucket event: {}", trace_id, event_key);
+        ctx.logger.api_exit(&trace_id, "POST", "/webhook/bitbucket", timer.elapsed_ms(), 200);
+        return (
+            StatusCode::OK,
+            Json(WebhookResponse {
+                message: format!("Ignored event: {}", event_key),
+                build_id: None,
+                simulated: false,
+            }),
+        );
+    }
+
+    let event: BitbucketPushEvent = match serde_json::from_str(&body) {
+        Ok(e) => e,
+        Err(e) => {
+            warn!("[{}] Failed to parse Bitbucket webhook payload: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", "/webhook/bitbucket", timer.elapsed_ms(), 400);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(WebhookResponse {
+                    message: format!("Invalid payload: {}", e),
+                    build_id: None,
+                    simulated: false,
                 }),
             );
         }
     };
 
-    info!("[{}] Received webhook for repo: {}", trace_id, webhook.repository.full_name);
+    // 태그 push, 브랜치 삭제는 빌드 대상이 아님
+    if event.branch_head().is_none() {
+        info!("[{}] Bitbucket push has no branch update", trace_id);
+        ctx.logger.api_exit(&trace_id, "POST", "/webhook/bitbucket", timer.elapsed_ms(), 200);
+        return (
+            StatusCode::OK,
+            Json(WebhookResponse {
+                message: "No commits".to_string(),
+                build_id: None,
+                simulated: false,
+            }),
+        );
+    }
+
+    let changed = bitbucket_changed_files(&ctx, &trace_id, &event).await;
+    let delivery_id = delivery_id(&headers, "x-request-uuid");
+    let (status, response) = process_delivery(&ctx, &trace_id, delivery_id, GitProviderKind::Bitbucket, WebhookEvent::Push(from_bitbucket(event, changed))).await;
+
+    ctx.logger.api_exit(&trace_id, "POST", "/webhook/bitbucket", timer.elapsed_ms(), status.as_u16());
+    (status, Json(response))
+}
+
+/// Bitbucket push payload에는 파일 목록이 없으므로 diffstat API로 조회
+///
+/// 토큰이 없거나 조회에 실패하면 빈 목록 (path_filter가 "*"가 아닌 프로젝트는 빌드되지 않음).
+async fn bitbucket_changed_files(ctx: &AppContext, trace_id: &str, event: &BitbucketPushEvent) -> ChangedFiles {
+    let (Some(head), Some((workspace, repo))) = (event.branch_head(), event.repository.full_name.split_once('/')) else {
+        return ChangedFiles::default();
+    };
+
+    let token = match ctx.settings_repo.get(BITBUCKET_TOKEN_SETTING).await {
+        Ok(Some(token)) => token,
+        Ok(None) => {
+            warn!("[{}] Bitbucket token not configured, changed files unavailable", trace_id);
+            return ChangedFiles::default();
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get Bitbucket token: {}", trace_id, e);
+            return ChangedFiles::default();
+        }
+    };
+
+    match BitbucketClient::new(token).changed_files(workspace, repo, &head.target.hash).await {
+        Ok(files) => files,
+        Err(e) => {
+            warn!("[{}] Failed to fetch Bitbucket diffstat for {}: {}", trace_id, head.target.hash, e);
+            ChangedFiles::default()
+        }
+    }
+}
+
+/// Bitbucket push payload를 공통 push 형식으로 변환 (head 커밋 하나만 사용)
+fn from_bitbucket(event: BitbucketPushEvent, changed: ChangedFiles) -> GithubWebhook {
+    let head = event.branch_head().map(|change| {
+        let author = change.target.author.as_ref().map(|a| a.to_commit_author());
+        (
+            format!("refs/heads/{}", change.name),
+            Commit {
+                id: change.target.hash.clone(),
+                message: change.target.message.clone(),
+                author: Author {
+                    name: author.as_ref().map(|a| a.name.clone()).unwrap_or_default(),
+                    email: author.map(|a| a.email).unwrap_or_default(),
+                },
+                added: changed.added,
+                modified: changed.modified,
+                removed: changed.removed,
+            },
+        )
+    });
+
+    let (git_ref, head_commit) = head.unzip();
+    GithubWebhook {
+        git_ref,
+        repository: Repository { full_name: event.repository.full_name.clone() },
+        head_commit,
+        commits: None,
+    }
+}
+
+/// delivery id가 있으면 중복 확인 후 push/pull_request 처리 (재전송이면 처음 응답 반환)
+async fn process_delivery(
+    ctx: &AppContext,
+    trace_id: &str,
+    delivery_id: Option<String>,
+    provider: GitProviderKind,
+    event: WebhookEvent,
+) -> (StatusCode, WebhookResponse) {
+    if let Some(delivery_id) = &delivery_id {
+        match ctx.idempotency_repo.reserve(WEBHOOK_IDEMPOTENCY_SCOPE, delivery_id).await {
+            Ok(IdempotencyReservation::Reserved) => {}
+            Ok(IdempotencyReservation::InProgress) => {
+                info!("[{}] Webhook delivery {} is already being processed", trace_id, delivery_id);
+                return (
+                    StatusCode::CONFLICT,
+                    WebhookResponse {
+                        message: "Delivery is already being processed".to_string(),
+                        build_id: None,
+                        simulated: false,
+                    },
+                );
+            }
+            Ok(IdempotencyReservation::Completed(status, response)) => {
+                info!("[{}] Duplicate webhook delivery {}, skipping", trace_id, delivery_id);
+                if let Ok(response) = serde_json::from_value::<WebhookResponse>(response) {
+                    return (StatusCode::from_u16(status).unwrap_or(StatusCode::OK), response);
+                }
+            }
+            // 중복 확인에 실패해도 webhook 처리는 계속 (빌드 누락이 중복보다 나쁨)
+            Err(e) => warn!("[{}] Failed to check webhook delivery {}: {}", trace_id, delivery_id, e),
+        }
+    }
+
+    let (status, response) = match event {
+        WebhookEvent::Push(webhook) => process_push(ctx, trace_id, provider, webhook, false).await,
+        WebhookEvent::PullRequest(event) => process_pull_request(ctx, trace_id, event).await,
+    };
+
+    if let Some(delivery_id) = &delivery_id {
+        // 실패한 처리는 redelivery로 다시 시도할 수 있게 해제
+        let stored = match serde_json::to_value(&response) {
+            Ok(value) if status.is_success() => {
+                ctx.idempotency_repo.complete(WEBHOOK_IDEMPOTENCY_SCOPE, delivery_id, status.as_u16(), &value).await
+            }
+            _ => ctx.idempotency_repo.release(WEBHOOK_IDEMPOTENCY_SCOPE, delivery_id).await,
+        };
+        if let Err(e) = stored {
+            warn!("[{}] Failed to record webhook delivery {}: {}", trace_id, delivery_id, e);
+        }
+    }
+
+    (status, response)
+}
+
+/// idempotency key로 쓸 수 있는 delivery id 헤더 값
+fn delivery_id(headers: &HeaderMap, header: &str) -> Option<String> {
+    headers
+        .get(header)
+        .and_then(|v| v.to_str().ok())
+        .filter(|id| !id.is_empty() && id.len() <= MAX_IDEMPOTENCY_KEY_LEN)
+        .map(str::to_string)
+}
+
+/// Push 이벤트 처리 (실제 webhook과 시뮬레이션이 같은 경로를 사용)
+async fn process_push(
+    ctx: &AppContext,
+    trace_id: &str,
+    provider: GitProviderKind,
+    webhook: GithubWebhook,
+    simulated: bool,
+) -> (StatusCode, WebhookResponse) {
+    info!(
+        "[{}] Received {}{} webhook for repo: {}",
+        trace_id, if simulated { "simulated " } else { "" }, provider, webhook.repository.full_name
+    );
+
+    let trigger = if simulated { BuildTrigger::SimulatedWebhook } else { BuildTrigger::Webhook };
 
     // Extract branch from ref (refs/heads/main -> main)
     let branch = webhook
@@ -108,27 +473,24 @@ pub async fn github_webhook(
         Ok(p) => p,
         Err(e) => {
             warn!("[{}] Failed to list projects: {}", trace_id, e);
-            ctx.logger.api_exit(&trace_id, "POST", "/webhook/github", timer.elapsed_ms(), 500);
             return (
                 StatusCode::INTERNAL_SERVER_ERROR,
-                Json(WebhookResponse {
+                WebhookResponse {
                     message: "Internal error".to_string(),
                     build_id: None,
-                }),
+                    simulated,
+                },
             );
         }
     };
 
     let matching_projects: Vec<&crate::db::models::Project> = projects.iter().filter(|p| {
-        // Extract owner/repo from stored URL (e.g., https://github.com/owner/repo.git -> owner/repo)
-        let repo_path = p.repo
-            .trim_end_matches(".git")
-            .trim_end_matches('/')
-            .split("github.com/")
-            .nth(1)
-            .unwrap_or("");
-
-        !repo_path.is_empty() && repo_path == webhook.repository.full_name && p.branch == branch
+        // 저장된 URL의 provider와 경로가 같아야 함 (https://gitlab.com/group/repo.git -> gitlab, group/repo)
+        let same_repo = parse_repo_url(&p.repo)
+            .is_some_and(|r| r.provider == provider && r.full_name() == webhook.repository.full_name);
+
+        // 보관된 프로젝트는 push가 와도 빌드하지 않음
+        same_repo && p.branch == branch && p.archived_at.is_none()
     }).collect();
 
     if matching_projects.is_empty() {
@@ -136,13 +498,13 @@ pub async fn github_webhook(
             "[{}] No matching project found for repo {} branch {}",
             trace_id, webhook.repository.full_name, branch
         );
-        ctx.logger.api_exit(&trace_id, "POST", "/webhook/github", timer.elapsed_ms(), 200);
         return (
             StatusCode::OK,
-            Json(WebhookResponse {
+            WebhookResponse {
                 message: "No matching project".to_string(),
                 build_id: None,
-            }),
+                simulated,
+            },
         );
     }
 
@@ -151,13 +513,13 @@ pub async fn github_webhook(
         Some(c) => c,
         None => {
             info!("[{}] No head commit in webhook", trace_id);
-            ctx.logger.api_exit(&trace_id, "POST", "/webhook/github", timer.elapsed_ms(), 200);
             return (
                 StatusCode::OK,
-                Json(WebhookResponse {
+                WebhookResponse {
                     message: "No commits".to_string(),
                     build_id: None,
-                }),
+                    simulated,
+                },
             );
         }
     };
@@ -174,6 +536,7 @@ pub async fn github_webhook(
     // Process each matching project
     let mut build_ids = Vec::new();
     let mut project_names = Vec::new();
+    let mut quota_blocked = Vec::new();
 
     for project in matching_projects {
         let matches_filter = match_path_filter(&project.path_filter, &files_changed);
@@ -186,12 +549,32 @@ pub async fn github_webhook(
             continue;
         }
 
+        // 디스크 쿼터 초과 프로젝트는 빌드 거부 (검사 실패 시에는 진행)
+        match ctx.disk_quota_service.check(trace_id, project).await {
+            Ok(status) if status.exceeded => {
+                warn!("[{}] Skipping build for project {}: {}", trace_id, project.name, status.error_message());
+                quota_blocked.push(project.name.clone());
+                continue;
+            }
+            Ok(_) => {}
+            Err(e) => warn!("[{}] Disk quota check failed for project {}: {}", trace_id, project.name, e),
+        }
+
         // Create build for this project
         let create_build = CreateBuild {
             project_id: project.id,
             commit_hash: head_commit.id.clone(),
-            commit_message: Some(head_commit.message.clone()),
+            commit_message: Some(if simulated {
+                format!("[simulated] {}", head_commit.message)
+            } else {
+                head_commit.message.clone()
+            }),
             author: Some(format!("{} <{}>", head_commit.author.name, head_commit.author.email)),
+            dry_run: false,
+            triggered_by: Some(trigger.to_string()),
+            rebuild_of: None,
+            branch: Some(branch.to_string()),
+            preview_pr: None,
         };
 
         let build = match ctx.build_repo.create(create_build).await {
@@ -208,50 +591,294 @@ pub async fn github_webhook(
         );
 
         // Enqueue build
-        ctx.build_queue.enqueue(project.id, build.id).await;
+        ctx.build_queue.enqueue_new(&ctx.event_bus, project.id, build.id).await;
 
-        // Emit event
-        ctx.event_bus.emit(Event::BuildStatus {
-            build_id: build.id,
-            project_id: project.id,
-            status: BuildStatus::Queued,
-            timestamp: Event::now(),
-        }).await;
+        tracing::info!(
+            target: "audit",
+            event = "build.triggered",
+            trace_id = %trace_id,
+            project_id = project.id,
+            build_id = build.id,
+            triggered_by = %trigger,
+        );
 
         build_ids.push(build.id);
         project_names.push(project.name.clone());
     }
 
-    ctx.logger.api_exit(&trace_id, "POST", "/webhook/github", timer.elapsed_ms(), 200);
-
     // Return response
-    if build_ids.is_empty() {
+    if build_ids.is_empty() && !quota_blocked.is_empty() {
         (
             StatusCode::OK,
-            Json(WebhookResponse {
+            WebhookResponse {
+                message: format!("Disk quota exceeded for: {}", quota_blocked.join(", ")),
+                build_id: None,
+                simulated,
+            },
+        )
+    } else if build_ids.is_empty() {
+        (
+            StatusCode::OK,
+            WebhookResponse {
                 message: "No matching path filters".to_string(),
                 build_id: None,
-            }),
+                simulated,
+            },
         )
     } else if build_ids.len() == 1 {
         (
             StatusCode::OK,
-            Json(WebhookResponse {
+            WebhookResponse {
                 message: format!("Build queued for {}", project_names[0]),
                 build_id: Some(build_ids[0]),
-            }),
+                simulated,
+            },
         )
     } else {
         (
             StatusCode::OK,
-            Json(WebhookResponse {
+            WebhookResponse {
                 message: format!("Builds queued for: {}", project_names.join(", ")),
                 build_id: Some(build_ids[0]), // Return first build ID for backward compatibility
-            }),
+                simulated,
+            },
         )
     }
 }
 
+/// 프리뷰를 만들지 않는 PR이면 이유
+///
+/// 포크 PR은 외부 코드가 서버에서 빌드/실행되므로 제외하고, 브랜치 이름은 clone 명령에 들어가므로 안전한 문자만 허용한다.
+fn preview_skip_reason(event: &PullRequestEvent) -> Option<&'static str> {
+    let head = &event.pull_request.head;
+    if head.repo.as_ref().map(|r| r.full_name.as_str()) != Some(event.repository.full_name.as_str()) {
+        return Some("Pull requests from forks are not previewed");
+    }
+    let valid_branch = !head.git_ref.is_empty()
+        && !head.git_ref.starts_with('-')
+        && head.git_ref.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/'));
+    if !valid_branch {
+        return Some("Pull request branch name is not supported for previews");
+    }
+    None
+}
+
+/// pull_request 이벤트 처리: opened/reopened/synchronize는 프리뷰 빌드, closed는 프리뷰 제거
+///
+/// pr_previews가 켜져 있고 PR의 base 브랜치가 프로젝트 브랜치인 GitHub 프로젝트만 대상
+async fn process_pull_request(
+    ctx: &AppContext,
+    trace_id: &str,
+    event: PullRequestEvent,
+) -> (StatusCode, WebhookResponse) {
+    info!(
+        "[{}] Received pull_request ({}) webhook for {} PR #{}",
+        trace_id, event.action, event.repository.full_name, event.number
+    );
+
+    let respond = |message: String, build_id: Option<i64>| {
+        (StatusCode::OK, WebhookResponse { message, build_id, simulated: false })
+    };
+
+    let projects = match ctx.project_repo.list().await {
+        Ok(p) => p,
+        Err(e) => {
+            warn!("[{}] Failed to list projects: {}", trace_id, e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                WebhookResponse {
+                    message: "Internal error".to_string(),
+                    build_id: None,
+                    simulated: false,
+                },
+            );
+        }
+    };
+
+    let base_branch = &event.pull_request.base.git_ref;
+    let matching_projects: Vec<&crate::db::models::Project> = projects.iter().filter(|p| {
+        let same_repo = parse_repo_url(&p.repo)
+            .is_some_and(|r| r.provider == GitProviderKind::GitHub && r.full_name() == event.repository.full_name);
+        same_repo && p.pr_previews && &p.branch == base_branch && p.archived_at.is_none()
+    }).collect();
+
+    if matching_projects.is_empty() {
+        return respond("No project with PR previews for this pull request".to_string(), None);
+    }
+
+    match event.action.as_str() {
+        "closed" => {
+            let mut removed = Vec::new();
+            for project in matching_projects {
+                match teardown_preview(ctx, trace_id, project.id, event.number).await {
+                    Ok(true) => removed.push(project.name.clone()),
+                    Ok(false) => {}
+                    Err(e) => warn!("[{}] Failed to remove preview PR #{} of project {}: {}", trace_id, event.number, project.name, e),
+                }
+            }
+            if removed.is_empty() {
+                respond("No preview to remove".to_string(), None)
+            } else {
+                respond(format!("Preview removed for: {}", removed.join(", ")), None)
+            }
+        }
+        "opened" | "reopened" | "synchronize" => {
+            if let Some(reason) = preview_skip_reason(&event) {
+                info!("[{}] Skipping preview of PR #{}: {}", trace_id, event.number, reason);
+                return respond(reason.to_string(), None);
+            }
+
+            let head = &event.pull_request.head;
+            let trigger = BuildTrigger::PullRequest(event.number);
+            let mut build_ids = Vec::new();
+            let mut project_names = Vec::new();
+
+            for project in matching_projects {
+                match ctx.disk_quota_service.check(trace_id, project).await {
+                    Ok(status) if status.exceeded => {
+                        warn!("[{}] Skipping preview build for project {}: {}", trace_id, project.name, status.error_message());
+                        continue;
+                    }
+                    Ok(_) => {}
+                    Err(e) => warn!("[{}] Disk quota check failed for project {}: {}", trace_id, project.name, e),
+                }
+
+                let create_build = CreateBuild {
+                    project_id: project.id,
+                    commit_hash: head.sha.clone(),
+                    commit_message: Some(format!("PR #{}: {}", event.number, event.pull_request.title)),
+                    author: event.pull_request.user.as_ref().map(|u| u.login.clone()),
+                    dry_run: false,
+                    triggered_by: Some(trigger.to_string()),
+                    rebuild_of: None,
+                    branch: Some(head.git_ref.clone()),
+                    preview_pr: Some(event.number),
+                };
+
+                let build = match ctx.build_repo.create(create_build).await {
+                    Ok(b) => b,
+                    Err(e) => {
+                        warn!("[{}] Failed to create preview build for project {}: {}", trace_id, project.name, e);
+                        continue;
+                    }
+                };
+
+                if let Err(e) = ctx.preview_repo
+                    .upsert_build(project.id, event.number, &head.git_ref, &head.sha, Some(&event.pull_request.title), build.id)
+                    .await
+                {
+                    warn!("[{}] Failed to record preview PR #{} of project {}: {}", trace_id, event.number, project.name, e);
+                }
+
+                info!(
+                    "[{}] Created preview build #{} for PR #{} of project {}",
+                    trace_id, build.build_number, event.number, project.name
+                );
+
+                ctx.build_queue.enqueue_new(&ctx.event_bus, project.id, build.id).await;
+
+                tracing::info!(
+                    target: "audit",
+                    event = "build.triggered",
+                    trace_id = %trace_id,
+                    project_id = project.id,
+                    build_id = build.id,
+                    triggered_by = %trigger,
+                );
+
+                build_ids.push(build.id);
+                project_names.push(project.name.clone());
+            }
+
+            if build_ids.is_empty() {
+                respond("No preview build queued".to_string(), None)
+            } else {
+                respond(format!("Preview build queued for: {}", project_names.join(", ")), build_ids.first().copied())
+            }
+        }
+        action => respond(format!("Ignored pull_request action: {}", action), None),
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct SimulateWebhookRequest {
+    /// 변경된 파일 목록 (path_filter 테스트용). 비어있으면 "*" 필터만 매칭됨
+    #[serde(default)]
+    pub changed_files: Vec<String>,
+    pub commit_message: Option<String>,
+}
+
+/// POST /api/projects/{id}/simulate-webhook
+/// 프로젝트 브랜치에 대한 push payload를 만들어 실제 webhook 처리 경로로 흘려보낸다.
+/// 서명 검증은 생략하고, 생성된 빌드는 simulated로 표시된다.
+pub async fn simulate_webhook(
+    State(ctx): State<AppContext>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    body: Option<Json<SimulateWebhookRequest>>,
+) -> impl IntoResponse {
+    let trace_id = TraceContext::extract_or_generate(&headers);
+    let timer = Timer::start();
+    let path = format!("/api/projects/{}/simulate-webhook", id);
+
+    ctx.logger.api_entry(&trace_id, "POST", &path, &format!("project_id={}", id));
+
+    let req = body.map(|Json(r)| r).unwrap_or_default();
+
+    let project = match ctx.project_repo.get(id).await {
+        Ok(Some(p)) => p,
+        Ok(None) => {
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 404);
+            return (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({"error": "Project not found"})),
+            );
+        }
+        Err(e) => {
+            warn!("[{}] Failed to get project: {}", trace_id, e);
+            ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), 500);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "Database error"})),
+            );
+        }
+    };
+
+    // 실제 push payload와 같은 형태로 구성 (provider와 repository.full_name은 저장된 repo URL에서 추출)
+    let (provider, full_name) = match parse_repo_url(&project.repo) {
+        Some(r) => (r.provider, r.full_name()),
+        None => (GitProviderKind::GitHub, project.repo.clone()),
+    };
+
+    let webhook = GithubWebhook {
+        git_ref: Some(format!("refs/heads/{}", project.branch)),
+        repository: Repository { full_name },
+        head_commit: Some(Commit {
+            id: "HEAD".to_string(),
+            message: req.commit_message.unwrap_or_else(|| "Simulated push".to_string()),
+            author: Author {
+                name: "easycicd".to_string(),
+                email: "simulated@easycicd.local".to_string(),
+            },
+            added: Vec::new(),
+            modified: req.changed_files,
+            removed: Vec::new(),
+        }),
+        commits: None,
+    };
+
+    tracing::info!(
+        target: "audit",
+        event = "webhook.simulated",
+        project_id = id,
+        project = %project.name,
+    );
+
+    let (status, response) = process_push(&ctx, &trace_id, provider, webhook, true).await;
+    ctx.logger.api_exit(&trace_id, "POST", &path, timer.elapsed_ms(), status.as_u16());
+    (status, Json(serde_json::json!(response)))
+}
+
 fn match_path_filter(pattern: &str, files: &[String]) -> bool {
     // Empty pattern or "*" means match all files
     if pattern.is_empty() || pattern.trim() == "*" {
@@ -291,16 +918,17 @@ fn match_path_filter(pattern: &str, files: &[String]) -> bool {
     files.iter().any(|f| globset.is_match(f))
 }
 
-async fn verify_signature(ctx: &AppContext, headers: &HeaderMap, body: &str) -> Result<(), String> {
+/// `sha256=` HMAC 서명 헤더 검증 (GitHub X-Hub-Signature-256, Bitbucket X-Hub-Signature)
+async fn verify_signature(ctx: &AppContext, headers: &HeaderMap, header: &str, body: &str) -> Result<(), String> {
     // Get webhook secret from database
-    let secret_opt: Option<String> = ctx.settings_repo.get("webhook_secret")
+    let secret_opt: Option<String> = ctx.settings_repo.get(WEBHOOK_SECRET_SETTING)
         .await
         .map_err(|e| format!("Failed to get webhook secret: {}", e))?;
     let secret = secret_opt.ok_or("Webhook secret not configured")?;
 
     // Get signature from header
     let signature_header = headers
-        .get("x-hub-signature-256")
+        .get(header)
         .and_then(|v| v.to_str().ok())
         .ok_or("Missing signature header")?;
 
@@ -309,6 +937,64 @@ async fn verify_signature(ctx: &AppContext, headers: &HeaderMap, body: &str) ->
         .strip_prefix("sha256=")
         .ok_or("Invalid signature format")?;
 
+    if signature_matches(&secret, body, signature)? {
+        return Ok(());
+    }
+
+    // 교체 직후 유예 기간 동안은 이전 secret으로 서명된 요청도 허용
+    if let Some(previous) = previous_webhook_secret(ctx).await {
+        if signature_matches(&previous, body, signature)? {
+            info!("Webhook signed with previous secret (rotation grace period)");
+            return Ok(());
+        }
+    }
+
+    Err("Signature mismatch".to_string())
+}
+
+async fn verify_gitlab_token(ctx: &AppContext, headers: &HeaderMap) -> Result<(), String> {
+    let secret = ctx.settings_repo.get(WEBHOOK_SECRET_SETTING)
+        .await
+        .map_err(|e| format!("Failed to get webhook secret: {}", e))?
+        .ok_or("Webhook secret not configured")?;
+
+    let token = headers
+        .get("x-gitlab-token")
+        .and_then(|v| v.to_str().ok())
+        .ok_or("Missing X-Gitlab-Token header")?;
+
+    if token_matches(token, &secret) {
+        return Ok(());
+    }
+
+    // 교체 직후 유예 기간 동안은 이전 secret도 허용
+    if previous_webhook_secret(ctx).await.is_some_and(|previous| token_matches(token, &previous)) {
+        info!("GitLab webhook used previous secret (rotation grace period)");
+        return Ok(());
+    }
+
+    Err("Token mismatch".to_string())
+}
+
+/// 상수 시간 비교 (타이밍으로 secret 추측 방지). 길이가 달라도 같은 시간이 걸리도록 HMAC 값끼리 비교
+fn token_matches(token: &str, secret: &str) -> bool {
+    let digest = |value: &str| {
+        let mut mac = HmacSha256::new_from_slice(b"gitlab-token").expect("HMAC accepts any key length");
+        mac.update(value.as_bytes());
+        mac
+    };
+    digest(token).verify_slice(&digest(secret).finalize().into_bytes()).is_ok()
+}
+
+/// 설정된 webhook URL을 provider의 수신 경로로 맞춤 (.../webhook/github → .../webhook/gitlab)
+pub(crate) fn provider_webhook_url(webhook_url: &str, provider: GitProviderKind) -> String {
+    match webhook_url.trim_end_matches('/').strip_suffix("/webhook/github") {
+        Some(base) => format!("{}/webhook/{}", base, provider),
+        None => webhook_url.to_string(),
+    }
+}
+
+fn signature_matches(secret: &str, body: &str, signature: &str) -> Result<bool, String> {
     // Compute HMAC
     let mut mac = HmacSha256::new_from_slice(secret.as_bytes())
         .map_err(|e| format!("Invalid key: {}", e))?;
@@ -316,10 +1002,79 @@ async fn verify_signature(ctx: &AppContext, headers: &HeaderMap, body: &str) ->
 
     // Compare
     let expected = hex::encode(mac.finalize().into_bytes());
+    Ok(signature == expected)
+}
 
-    if signature != expected {
-        return Err("Signature mismatch".to_string());
+/// 유예 기간이 남아 있는 이전 webhook secret
+async fn previous_webhook_secret(ctx: &AppContext) -> Option<String> {
+    let expires_at = ctx.settings_repo.get(WEBHOOK_SECRET_PREVIOUS_EXPIRES_SETTING).await.ok().flatten()?;
+    if timezone::parse_stored(&expires_at).is_none_or(|t| t <= Utc::now()) {
+        return None;
     }
+    ctx.settings_repo.get(WEBHOOK_SECRET_PREVIOUS_SETTING).await.ok().flatten()
+}
+
+/// 새 webhook secret 생성 (64자 영숫자)
+pub fn generate_webhook_secret() -> String {
+    use rand::Rng;
+    rand::thread_rng()
+        .sample_iter(&rand::distributions::Alphanumeric)
+        .take(64)
+        .map(char::from)
+        .collect()
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_webhook_url() {
+        assert_eq!(
+            provider_webhook_url("https://ci.example.com/webhook/github", GitProviderKind::GitLab),
+            "https://ci.example.com/webhook/gitlab"
+        );
+        assert_eq!(
+            provider_webhook_url("https://ci.example.com/webhook/github/", GitProviderKind::GitHub),
+            "https://ci.example.com/webhook/github"
+        );
+        assert_eq!(
+            provider_webhook_url("https://ci.example.com/hooks", GitProviderKind::GitLab),
+            "https://ci.example.com/hooks"
+        );
+    }
+
+    fn pull_request_event(head_repo: Option<&str>, branch: &str) -> PullRequestEvent {
+        serde_json::from_value(serde_json::json!({
+            "action": "opened",
+            "number": 7,
+            "pull_request": {
+                "title": "Add search",
+                "head": {"ref": branch, "sha": "abc123", "repo": head_repo.map(|r| serde_json::json!({"full_name": r}))},
+                "base": {"ref": "main", "sha": "def456", "repo": {"full_name": "acme/shop"}},
+                "user": {"login": "dev"}
+            },
+            "repository": {"full_name": "acme/shop"}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_token_matches() {
+        assert!(token_matches("secret", "secret"));
+        assert!(!token_matches("secreT", "secret"));
+        assert!(!token_matches("secret", "secret-longer"));
+        assert!(!token_matches("", "secret"));
+    }
+
+    #[test]
+    fn test_preview_skip_reason() {
+        assert_eq!(preview_skip_reason(&pull_request_event(Some("acme/shop"), "feature/search-2")), None);
+        assert_eq!(
+            preview_skip_reason(&pull_request_event(Some("someone/shop"), "main")),
+            Some("Pull requests from forks are not previewed")
+        );
+        assert!(preview_skip_reason(&pull_request_event(None, "main")).is_some());
+        assert!(preview_skip_reason(&pull_request_event(Some("acme/shop"), "x;rm -rf /")).is_some());
+    }
 }
@@ -1,5 +1,5 @@
 use axum::{
-    extract::{ws::WebSocket, State, WebSocketUpgrade},
+    extract::{ws::{Message, WebSocket}, State, WebSocketUpgrade},
     response::IntoResponse,
 };
 use futures_util::{SinkExt, StreamExt};
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 use tracing::{info, warn};
 
-use crate::state::{AppContext, WsSubscription};
+use crate::state::{AppContext, WsOutbound, WsSubscription};
 
 #[derive(Debug, Deserialize)]
 #[serde(tag = "type")]
@@ -27,35 +27,42 @@ pub async fn ws_handler(
 
 async fn handle_socket(socket: WebSocket, ctx: AppContext) {
     let (mut sender, mut receiver) = socket.split();
-    let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+    let (tx, mut rx) = mpsc::unbounded_channel::<WsOutbound>();
 
-    info!("WebSocket client connected");
+    // 송신 채널은 WsConnections만 보관 (heartbeat 타임아웃으로 제거되면 send_task 종료)
+    let client_id = ctx.ws_connections.register(tx).await;
+    info!("WebSocket client {} connected", client_id);
 
     // Subscribe to global events by default
-    ctx.ws_connections.subscribe(WsSubscription::Global, tx.clone()).await;
+    ctx.ws_connections.subscribe(WsSubscription::Global, client_id).await;
 
     // Task to receive messages from the channel and send to client
     let mut send_task = tokio::spawn(async move {
         while let Some(msg) = rx.recv().await {
-            if sender
-                .send(axum::extract::ws::Message::Text(msg.into()))
-                .await
-                .is_err()
-            {
+            let msg = match msg {
+                WsOutbound::Text(text) => Message::Text(text.into()),
+                WsOutbound::Ping => Message::Ping(Default::default()),
+            };
+            if sender.send(msg).await.is_err() {
                 break;
             }
         }
     });
 
     // Task to receive messages from client
-    let tx_clone = tx.clone();
     let ctx_clone = ctx.clone();
     let mut recv_task = tokio::spawn(async move {
         while let Some(Ok(msg)) = receiver.next().await {
-            if let axum::extract::ws::Message::Text(text) = msg {
-                if let Err(e) = handle_client_message(&text, &ctx_clone, &tx_clone).await {
-                    warn!("Error handling client message: {}", e);
+            // pong을 포함한 모든 수신 메시지를 생존 신호로 사용
+            ctx_clone.ws_connections.touch(client_id).await;
+            match msg {
+                Message::Text(text) => {
+                    if let Err(e) = handle_client_message(&text, &ctx_clone, client_id).await {
+                        warn!("Error handling client message: {}", e);
+                    }
                 }
+                Message::Close(_) => break,
+                _ => {}
             }
         }
     });
@@ -70,37 +77,36 @@ async fn handle_socket(socket: WebSocket, ctx: AppContext) {
         }
     }
 
-    info!("WebSocket client disconnected");
+    ctx.ws_connections.remove_client(client_id).await;
+    info!("WebSocket client {} disconnected", client_id);
+}
+
+fn parse_subscription(target: &str, id: Option<i64>) -> Result<WsSubscription, String> {
+    match target {
+        "build" => Ok(WsSubscription::Build(id.ok_or("Missing build_id")?)),
+        "project" => Ok(WsSubscription::Project(id.ok_or("Missing project_id")?)),
+        "global" => Ok(WsSubscription::Global),
+        _ => Err(format!("Unknown target: {}", target)),
+    }
 }
 
 async fn handle_client_message(
     text: &str,
     ctx: &AppContext,
-    tx: &mpsc::UnboundedSender<String>,
+    client_id: u64,
 ) -> Result<(), String> {
     let msg: ClientMessage = serde_json::from_str(text)
         .map_err(|e| format!("Failed to parse message: {}", e))?;
 
     match msg {
         ClientMessage::Subscribe { target, id } => {
-            let subscription = match target.as_str() {
-                "build" => {
-                    let build_id = id.ok_or("Missing build_id")?;
-                    WsSubscription::Build(build_id)
-                }
-                "project" => {
-                    let project_id = id.ok_or("Missing project_id")?;
-                    WsSubscription::Project(project_id)
-                }
-                "global" => WsSubscription::Global,
-                _ => return Err(format!("Unknown target: {}", target)),
-            };
-
-            ctx.ws_connections.subscribe(subscription, tx.clone()).await;
+            let subscription = parse_subscription(&target, id)?;
+            ctx.ws_connections.subscribe(subscription, client_id).await;
             info!("Client subscribed to: {:?}", target);
         }
         ClientMessage::Unsubscribe { target, id } => {
-            // Unsubscribe logic (simplified for now)
+            let subscription = parse_subscription(&target, id)?;
+            ctx.ws_connections.unsubscribe(subscription, client_id).await;
             info!("Client unsubscribed from: {:?}", target);
         }
     }
@@ -38,6 +38,10 @@ impl EventBus for BroadcastEventBus {
             Event::ContainerStatus { .. } => "ContainerStatus",
             Event::StandaloneContainerStatus { .. } => "StandaloneContainerStatus",
             Event::ContainerLog { .. } => "ContainerLog",
+            Event::BuildStep { .. } => "BuildStep",
+            Event::BuildStage { .. } => "BuildStage",
+            Event::BuildProgress { .. } => "BuildProgress",
+            Event::QueueWaitExceeded { .. } => "QueueWaitExceeded",
             Event::Error { .. } => "Error",
         };
 
@@ -0,0 +1,163 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::github::{Branch, Commit, Repository, Tree};
+
+/// 저장소 호스팅 서비스 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GitProviderKind {
+    #[default]
+    GitHub,
+    GitLab,
+    Bitbucket,
+}
+
+impl GitProviderKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GitProviderKind::GitHub => "github",
+            GitProviderKind::GitLab => "gitlab",
+            GitProviderKind::Bitbucket => "bitbucket",
+        }
+    }
+
+    /// 호스트 이름으로 provider 결정 (gitlab.com, gitlab.example.com → GitLab, bitbucket.org → Bitbucket)
+    fn from_host(host: &str) -> Option<Self> {
+        // https://user@bitbucket.org/... 형태의 clone URL
+        let host = host.rsplit('@').next().unwrap_or(host).to_ascii_lowercase();
+        if host == "github.com" {
+            Some(GitProviderKind::GitHub)
+        } else if host == "bitbucket.org" {
+            Some(GitProviderKind::Bitbucket)
+        } else if host == "gitlab.com" || host.starts_with("gitlab.") {
+            Some(GitProviderKind::GitLab)
+        } else {
+            None
+        }
+    }
+}
+
+impl std::fmt::Display for GitProviderKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 저장소 URL에서 추출한 provider와 경로
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoRef {
+    pub provider: GitProviderKind,
+    /// GitHub은 owner, GitLab은 하위 그룹을 포함한 namespace (group/subgroup), Bitbucket은 workspace
+    pub owner: String,
+    pub name: String,
+}
+
+impl RepoRef {
+    /// webhook payload의 저장소 이름과 같은 형식 (owner/repo, group/subgroup/repo)
+    pub fn full_name(&self) -> String {
+        format!("{}/{}", self.owner, self.name)
+    }
+}
+
+/// 저장소 URL 파싱
+///
+/// - `https://github.com/owner/repo(.git)`, `git@github.com:owner/repo.git`
+/// - `https://gitlab.com/group/subgroup/repo(.git)`, `git@gitlab.com:group/repo.git`
+/// - `https://bitbucket.org/workspace/repo(.git)`, `git@bitbucket.org:workspace/repo.git`
+/// - `owner/repo` (GitHub)
+pub fn parse_repo_url(repo_url: &str) -> Option<RepoRef> {
+    let cleaned = repo_url
+        .trim()
+        .trim_end_matches('/')
+        .trim_end_matches(".git");
+
+    let (provider, path) = if let Some(rest) = cleaned
+        .strip_prefix("https://")
+        .or_else(|| cleaned.strip_prefix("http://"))
+    {
+        let (host, path) = rest.split_once('/')?;
+        (GitProviderKind::from_host(host)?, path)
+    } else if let Some(rest) = cleaned.strip_prefix("git@") {
+        let (host, path) = rest.split_once(':')?;
+        (GitProviderKind::from_host(host)?, path)
+    } else {
+        (GitProviderKind::GitHub, cleaned)
+    };
+
+    let parts: Vec<&str> = path.split('/').collect();
+    if parts.iter().any(|p| p.is_empty()) {
+        return None;
+    }
+    let valid_len = match provider {
+        GitProviderKind::GitHub | GitProviderKind::Bitbucket => parts.len() == 2,
+        GitProviderKind::GitLab => parts.len() >= 2,
+    };
+    if !valid_len {
+        return None;
+    }
+
+    let (name, owner) = parts.split_last()?;
+    Some(RepoRef {
+        provider,
+        owner: owner.join("/"),
+        name: name.to_string(),
+    })
+}
+
+/// 저장소 호스팅 서비스 API (GitHub, GitLab, Bitbucket Cloud)
+///
+/// 프로젝트 감지, webhook 등록, 빌드 소스/커밋 조회가 provider에 관계없이 이 trait만 사용한다.
+/// 응답은 GitHub 모델로 통일한다 (GitLab/Bitbucket 응답은 구현에서 변환).
+#[async_trait]
+pub trait GitProvider: Send + Sync {
+    /// 토큰으로 접근 가능한 저장소 목록
+    async fn list_repositories(&self) -> Result<Vec<Repository>>;
+
+    async fn list_branches(&self, owner: &str, repo: &str) -> Result<Vec<Branch>>;
+
+    async fn get_branch(&self, owner: &str, repo: &str, branch: &str) -> Result<Branch>;
+
+    /// 커밋 기준 전체 파일 트리 (재귀)
+    async fn get_tree(&self, owner: &str, repo: &str, sha: &str) -> Result<Tree>;
+
+    async fn get_file_content(&self, owner: &str, repo: &str, path: &str, branch: &str) -> Result<String>;
+
+    /// 커밋 정보 (`git_ref`는 SHA 또는 브랜치)
+    async fn get_commit(&self, owner: &str, repo: &str, git_ref: &str) -> Result<Commit>;
+
+    /// 소스 tarball을 `dest`에 저장하고 크기(bytes) 반환. 최상위 디렉토리가 하나 있는 형태
+    async fn download_tarball(&self, owner: &str, repo: &str, git_ref: &str, dest: &Path) -> Result<u64>;
+
+    /// push webhook 등록 후 webhook ID 반환 (GitHub/GitLab은 숫자, Bitbucket은 UUID)
+    async fn create_webhook(&self, owner: &str, repo: &str, webhook_url: &str, secret: &str) -> Result<String>;
+
+    async fn delete_webhook(&self, owner: &str, repo: &str, hook_id: &str) -> Result<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repo_ref(provider: GitProviderKind, owner: &str, name: &str) -> Option<RepoRef> {
+        Some(RepoRef { provider, owner: owner.to_string(), name: name.to_string() })
+    }
+
+    #[test]
+    fn test_parse_repo_url() {
+        use GitProviderKind::*;
+
+        assert_eq!(parse_repo_url("https://github.com/o/r.git"), repo_ref(GitHub, "o", "r"));
+        assert_eq!(parse_repo_url("git@github.com:o/r.git"), repo_ref(GitHub, "o", "r"));
+        assert_eq!(parse_repo_url("o/r"), repo_ref(GitHub, "o", "r"));
+        assert_eq!(parse_repo_url("https://gitlab.com/g/sub/r.git"), repo_ref(GitLab, "g/sub", "r"));
+        assert_eq!(parse_repo_url("git@gitlab.example.com:g/r.git"), repo_ref(GitLab, "g", "r"));
+        assert_eq!(parse_repo_url("https://user@bitbucket.org/ws/r.git"), repo_ref(Bitbucket, "ws", "r"));
+        assert_eq!(parse_repo_url("https://bitbucket.org/ws/g/r"), None);
+        assert_eq!(parse_repo_url("https://github.com/o/r/extra"), None);
+        assert_eq!(parse_repo_url("https://example.com/o/r"), None);
+        assert_eq!(parse_repo_url("o"), None);
+    }
+}
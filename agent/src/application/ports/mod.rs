@@ -1,2 +1,3 @@
 pub mod repositories;
 pub mod container_runtime;
+pub mod git_provider;
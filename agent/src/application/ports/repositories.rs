@@ -2,11 +2,19 @@ use async_trait::async_trait;
 use anyhow::Result;
 use crate::db::models::{
     Project, Build, CreateProject, UpdateProject, CreateBuild, Slot, BuildStatus,
-    Container, CreateContainer, ContainerStatus,
-    User, CreateUser, Session, CreateSession,
-    GitHubPat, CreateGitHubPat,
+    Container, CreateContainer, ContainerHealth, ContainerStatus, RestartConfig,
+    User, UserRole, CreateUser, CreateGitHubUser, Session, CreateSession,
+    GitHubPat, CreateGitHubPat, TestCaseResult, BuildStageResult,
 };
 
+/// 프로젝트 설정이 다른 요청에 의해 먼저 변경됨 (`ProjectRepository::update`에서 anyhow로 반환)
+#[derive(Debug, thiserror::Error)]
+#[error("Project {id} was modified by another request (current version {current_version})")]
+pub struct ProjectVersionConflict {
+    pub id: i64,
+    pub current_version: i64,
+}
+
 /// Repository trait for Project operations
 #[async_trait]
 pub trait ProjectRepository: Send + Sync {
@@ -16,32 +24,70 @@ pub trait ProjectRepository: Send + Sync {
     /// Get a project by ID
     async fn get(&self, id: i64) -> Result<Option<Project>>;
 
-    /// Get a project by name
+    /// Get a project by name (soft delete된 프로젝트 제외)
     async fn get_by_name(&self, name: &str) -> Result<Option<Project>>;
 
-    /// List all projects
+    /// List all projects (soft delete된 프로젝트 제외)
     async fn list(&self) -> Result<Vec<Project>>;
 
+    /// List soft-deleted projects (deleted_at 오름차순)
+    async fn list_deleted(&self) -> Result<Vec<Project>>;
+
+    /// List all projects including soft-deleted ones (리소스 정리 시 유예 중인 프로젝트 보호용)
+    async fn list_with_deleted(&self) -> Result<Vec<Project>>;
+
+    /// Mark a project as deleted (이미 삭제 표시된 경우 false)
+    async fn mark_deleted(&self, id: i64) -> Result<bool>;
+
+    /// Clear the deleted mark (삭제 표시가 없었으면 false)
+    async fn restore(&self, id: i64) -> Result<bool>;
+
+    /// Set or clear archived_at (상태가 바뀌지 않았으면 false)
+    async fn set_archived(&self, id: i64, archived: bool) -> Result<bool>;
+
+    /// Assign new Blue/Green ports after the current maximum (보관 해제 시 포트가 다른 곳에서 쓰이는 경우)
+    async fn reassign_ports(&self, id: i64) -> Result<(i32, i32)>;
+
+    /// Set specific Blue/Green ports (가용성 검사는 호출하는 쪽에서)
+    async fn set_ports(&self, id: i64, blue_port: i32, green_port: i32) -> Result<()>;
+
     /// Update a project (partial update)
+    ///
+    /// `expected_version`이 현재 version과 다르거나, 병합 중 다른 변경이 끼어들면 ProjectVersionConflict
     async fn update(&self, id: i64, update: UpdateProject) -> Result<Project>;
 
     /// Update the active slot for a project
     async fn update_active_slot(&self, id: i64, slot: Slot) -> Result<()>;
 
+    /// Update the in-progress canary build and its traffic weight (None/0이면 카나리 없음)
+    async fn update_canary(&self, id: i64, build_id: Option<i64>, weight: i32) -> Result<()>;
+
     /// Update the blue container ID
     async fn update_blue_container(&self, id: i64, container_id: Option<String>) -> Result<()>;
 
     /// Update the green container ID
     async fn update_green_container(&self, id: i64, container_id: Option<String>) -> Result<()>;
 
+    /// Set dedicated network isolation and peer projects (peers: JSON array of project IDs)
+    async fn update_network(&self, id: i64, isolation: bool, peers: Option<String>) -> Result<()>;
+
+    /// Replace project notes (None clears them). Does not bump the config version
+    async fn update_notes(&self, id: i64, notes: Option<&str>, updated_by: Option<&str>) -> Result<()>;
+
     /// Delete a project
     async fn delete(&self, id: i64) -> Result<()>;
 
-    /// Update the GitHub webhook ID for a project
-    async fn update_webhook_id(&self, id: i64, webhook_id: Option<i64>) -> Result<()>;
+    /// Update the push webhook ID for a project (숫자면 github_webhook_id, 아니면 webhook_uuid. None이면 둘 다 해제)
+    async fn update_webhook_id(&self, id: i64, webhook_id: Option<&str>) -> Result<()>;
 
     /// Update the Discord webhook ID for a project
     async fn update_discord_webhook_id(&self, id: i64, webhook_id: Option<i64>) -> Result<()>;
+
+    /// Update the Slack webhook ID for a project
+    async fn update_slack_webhook_id(&self, id: i64, webhook_id: Option<i64>) -> Result<()>;
+
+    /// Update the last base image update check result (JSON, see ImageUpdateStatus)
+    async fn update_image_update_status(&self, id: i64, status: &str) -> Result<()>;
 }
 
 /// Repository trait for Build operations
@@ -65,6 +111,12 @@ pub trait BuildRepository: Send + Sync {
     /// List recent builds (all projects)
     async fn list_recent(&self, limit: i64) -> Result<Vec<Build>>;
 
+    /// List builds with a given status (all projects, oldest first)
+    async fn list_by_status(&self, status: BuildStatus) -> Result<Vec<Build>>;
+
+    /// Search builds by note, label or commit message (newest first)
+    async fn search(&self, project_id: Option<i64>, query: &str, limit: i64) -> Result<Vec<Build>>;
+
     /// Update build status
     async fn update_status(&self, id: i64, status: BuildStatus) -> Result<()>;
 
@@ -76,6 +128,66 @@ pub trait BuildRepository: Send + Sync {
 
     /// Update deploy log path
     async fn update_deploy_log_path(&self, id: i64, path: String) -> Result<()>;
+
+    /// Update test stage summary (JSON)
+    async fn update_test_summary(&self, id: i64, summary: String) -> Result<()>;
+
+    /// Update build log severity counts (JSON, LogLevelCounts)
+    async fn update_log_levels(&self, id: i64, counts: String) -> Result<()>;
+
+    /// Record the runtime image built from the project Dockerfile
+    async fn update_image_tag(&self, id: i64, image_tag: &str) -> Result<()>;
+
+    /// Delete a build record (test results and stages cascade)
+    async fn delete(&self, id: i64) -> Result<()>;
+
+    /// Record the short failure summary of a failed build
+    async fn update_failure_summary(&self, id: i64, summary: &str) -> Result<()>;
+
+    /// Attach the suggested failure diagnosis to a build
+    async fn update_diagnosis(&self, id: i64, diagnosis: &str) -> Result<()>;
+
+    /// Update build container resource usage (peak memory, CPU time)
+    async fn update_resource_usage(&self, id: i64, peak_memory_bytes: i64, cpu_time_ms: i64) -> Result<()>;
+
+    /// Set build note and labels (labels: JSON array string)
+    async fn update_annotation(&self, id: i64, note: Option<String>, labels: Option<String>) -> Result<()>;
+
+    /// Record the digest-pinned runtime image used for deployment
+    async fn update_runtime_image_digest(&self, id: i64, image: &str) -> Result<()>;
+
+    /// Record build output path and artifact checksums (JSON, see ArtifactChecksums)
+    async fn update_artifacts(&self, id: i64, output_path: &str, checksums: &str) -> Result<()>;
+
+    /// Record how long the build waited in the queue before it started
+    async fn update_queue_wait(&self, id: i64, queue_wait_ms: i64) -> Result<()>;
+
+    /// Record the resolved build environment snapshot (JSON, see BuildEnvironment)
+    async fn update_build_environment(&self, id: i64, environment: &str) -> Result<()>;
+
+    /// Record the pre-switch traffic shadowing comparison (JSON, see ShadowReport)
+    async fn update_shadow_report(&self, id: i64, report: &str) -> Result<()>;
+
+    /// Finish a successful build without deploying it (status Verified) and record why
+    async fn block_deploy(&self, id: i64, reason: &str) -> Result<()>;
+
+    /// Record remote log shipment (JSON, see LogShipment)
+    async fn update_log_shipment(&self, id: i64, shipment: &str) -> Result<()>;
+
+    /// Store per-test results of a build
+    async fn insert_test_results(&self, build_id: i64, project_id: i64, results: &[TestCaseResult]) -> Result<()>;
+
+    /// List per-test results of a build
+    async fn list_test_results(&self, build_id: i64) -> Result<Vec<TestCaseResult>>;
+
+    /// Store pipeline stage results of a build
+    async fn insert_build_stages(&self, build_id: i64, project_id: i64, stages: &[BuildStageResult]) -> Result<()>;
+
+    /// List pipeline stage results of a build in pipeline order
+    async fn list_build_stages(&self, build_id: i64) -> Result<Vec<BuildStageResult>>;
+
+    /// Test results of the project's latest `builds` builds that have results, as (build_id, result) ordered by build id
+    async fn list_test_history(&self, project_id: i64, builds: i64) -> Result<Vec<(i64, TestCaseResult)>>;
 }
 
 /// Repository trait for Settings operations
@@ -112,12 +224,27 @@ pub trait ContainerRepository: Send + Sync {
     /// Update container ID (Docker container ID)
     async fn update_container_id(&self, id: i64, container_id: Option<String>) -> Result<()>;
 
+    /// Set or clear the health check config (JSON); resets the last health result
+    async fn update_health_check(&self, id: i64, health_check: Option<String>) -> Result<()>;
+
+    /// Record the latest health result (None when stopped or unchecked)
+    async fn update_health_status(&self, id: i64, health: Option<ContainerHealth>) -> Result<()>;
+
+    /// Set log retention caps (None = default)
+    async fn update_log_retention(&self, id: i64, max_size_mb: Option<i64>, retention_days: Option<i64>) -> Result<()>;
+
+    /// Set the Docker restart policy (applied on the next start)
+    async fn update_restart_policy(&self, id: i64, restart: RestartConfig) -> Result<()>;
+
     /// Delete a container
     async fn delete(&self, id: i64) -> Result<()>;
 
     /// Allocate a port in the container range (15000-19999)
     async fn allocate_port(&self) -> Result<i32>;
 
+    /// Reserve a specific host port (false if already allocated)
+    async fn reserve_port(&self, port: i32) -> Result<bool>;
+
     /// Release a port
     async fn release_port(&self, port: i32) -> Result<()>;
 }
@@ -140,6 +267,21 @@ pub trait UserRepository: Send + Sync {
 
     /// Get user by email
     async fn get_by_email(&self, email: &str) -> Result<Option<User>>;
+
+    /// List all users
+    async fn list(&self) -> Result<Vec<User>>;
+
+    /// Change user role
+    async fn set_role(&self, id: i64, role: UserRole) -> Result<bool>;
+
+    /// Get user by linked GitHub ID
+    async fn get_by_github_id(&self, github_id: i64) -> Result<Option<User>>;
+
+    /// Link a GitHub account to an existing user (login/token 갱신)
+    async fn link_github(&self, id: i64, github: &CreateGitHubUser) -> Result<User>;
+
+    /// Create a user that signed in with GitHub only
+    async fn create_from_github(&self, github: &CreateGitHubUser) -> Result<User>;
 }
 
 /// Repository trait for Session operations
@@ -162,6 +304,9 @@ pub trait SessionRepository: Send + Sync {
 
     /// Delete all sessions for a user
     async fn delete_by_user(&self, user_id: i64) -> Result<()>;
+
+    /// Record a successful second-factor (TOTP) check on the session
+    async fn mark_mfa_verified(&self, id: &str) -> Result<()>;
 }
 
 // ============================================================================
@@ -0,0 +1,223 @@
+use anyhow::{Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair, UnparsedPublicKey, ED25519};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+use crate::db::models::ArtifactChecksums;
+
+/// 산출물 디렉토리의 모든 파일 SHA-256 (상대 경로 → hex). 심볼릭 링크는 따라가지 않고 건너뜀
+pub async fn compute_file_checksums(dir: &Path) -> Result<BTreeMap<String, String>> {
+    let mut files = BTreeMap::new();
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        let mut entries = fs::read_dir(&current)
+            .await
+            .with_context(|| format!("Failed to read {}", current.display()))?;
+        while let Some(entry) = entries.next_entry().await? {
+            let file_type = entry.file_type().await?;
+            let path = entry.path();
+            if file_type.is_dir() {
+                pending.push(path);
+            } else if file_type.is_file() {
+                let data = fs::read(&path).await.with_context(|| format!("Failed to read {}", path.display()))?;
+                let relative = path.strip_prefix(dir).unwrap_or(&path).to_string_lossy().replace('\\', "/");
+                files.insert(relative, hex::encode(Sha256::digest(&data)));
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// 파일 목록 전체의 digest. `sha256sum` 출력 형식(`{hash}  {path}\n`, 경로순)의 SHA-256
+pub fn manifest_digest(files: &BTreeMap<String, String>) -> String {
+    let manifest: String = files.iter().map(|(path, hash)| format!("{}  {}\n", hash, path)).collect();
+    hex::encode(Sha256::digest(manifest.as_bytes()))
+}
+
+/// 기록된 checksum과 현재 파일 비교. 달라진 파일이 있으면 Err(목록)
+pub fn compare_checksums(recorded: &BTreeMap<String, String>, actual: &BTreeMap<String, String>) -> Result<(), String> {
+    let mut problems = Vec::new();
+    for (path, hash) in recorded {
+        match actual.get(path) {
+            None => problems.push(format!("missing: {}", path)),
+            Some(current) if current != hash => problems.push(format!("modified: {}", path)),
+            Some(_) => {}
+        }
+    }
+    for path in actual.keys().filter(|p| !recorded.contains_key(*p)) {
+        problems.push(format!("unexpected: {}", path));
+    }
+
+    if problems.is_empty() {
+        return Ok(());
+    }
+    let total = problems.len();
+    problems.truncate(10);
+    let more = if total > problems.len() { format!(" (and {} more)", total - problems.len()) } else { String::new() };
+    Err(format!("{}{}", problems.join(", "), more))
+}
+
+/// 산출물 서명용 agent 키 (Ed25519)
+///
+/// ARTIFACT_SIGNING_KEY: PKCS#8 키 파일 경로. 파일이 없으면 새로 생성한다.
+/// 설정하지 않으면 서명 없이 checksum만 기록
+pub struct ArtifactSigner {
+    key_pair: Ed25519KeyPair,
+}
+
+impl ArtifactSigner {
+    pub async fn from_env() -> Option<Result<Self>> {
+        let path = std::env::var("ARTIFACT_SIGNING_KEY").ok().filter(|p| !p.is_empty())?;
+        Some(Self::load_or_generate(Path::new(&path)).await)
+    }
+
+    pub async fn load_or_generate(path: &Path) -> Result<Self> {
+        let pkcs8 = if path.exists() {
+            fs::read(path).await.with_context(|| format!("Failed to read signing key {}", path.display()))?
+        } else {
+            let generated = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new())
+                .map_err(|_| anyhow::anyhow!("Failed to generate signing key"))?;
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).await?;
+            }
+            // 처음부터 0600으로 생성 (쓰기 전에 다른 사용자가 읽을 틈이 없도록)
+            let mut file = fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .mode(0o600)
+                .open(path)
+                .await
+                .with_context(|| format!("Failed to create signing key {}", path.display()))?;
+            file.write_all(generated.as_ref()).await
+                .with_context(|| format!("Failed to write signing key {}", path.display()))?;
+            generated.as_ref().to_vec()
+        };
+        let key_pair = Ed25519KeyPair::from_pkcs8(&pkcs8)
+            .map_err(|_| anyhow::anyhow!("Invalid Ed25519 PKCS#8 key: {}", path.display()))?;
+        Ok(Self { key_pair })
+    }
+
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.key_pair.public_key().as_ref())
+    }
+
+    /// manifest digest 서명 (base64)
+    pub fn sign(&self, digest: &str) -> String {
+        STANDARD.encode(self.key_pair.sign(digest.as_bytes()).as_ref())
+    }
+
+    /// 이 키로 만든 서명인지 확인
+    pub fn verify(&self, digest: &str, signature: &str) -> bool {
+        let Ok(signature) = STANDARD.decode(signature) else { return false };
+        UnparsedPublicKey::new(&ED25519, self.key_pair.public_key().as_ref())
+            .verify(digest.as_bytes(), &signature)
+            .is_ok()
+    }
+}
+
+/// 빌드 산출물 checksum 계산 (+ agent 키가 있으면 서명)
+pub async fn checksum_artifacts(output_path: &Path, signer: Option<&ArtifactSigner>) -> Result<ArtifactChecksums> {
+    let files = compute_file_checksums(output_path).await?;
+    let digest = manifest_digest(&files);
+    Ok(ArtifactChecksums {
+        signature: signer.map(|s| s.sign(&digest)),
+        public_key: signer.map(|s| s.public_key_hex()),
+        digest,
+        files,
+    })
+}
+
+/// 롤백 전 산출물 검증: 파일 checksum 비교 + 서명 확인 (서명이 있고 agent 키가 설정된 경우)
+pub async fn verify_artifacts(
+    output_path: &Path,
+    recorded: &ArtifactChecksums,
+    signer: Option<&ArtifactSigner>,
+) -> Result<()> {
+    if manifest_digest(&recorded.files) != recorded.digest {
+        anyhow::bail!("Recorded artifact checksums are inconsistent (manifest digest mismatch)");
+    }
+    if let (Some(signature), Some(signer)) = (&recorded.signature, signer) {
+        if !signer.verify(&recorded.digest, signature) {
+            anyhow::bail!("Artifact signature does not match the agent signing key");
+        }
+    }
+
+    let actual = compute_file_checksums(output_path).await?;
+    compare_checksums(&recorded.files, &actual)
+        .map_err(|problems| anyhow::anyhow!("Build output does not match recorded checksums: {}", problems))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+        entries.iter().map(|(p, h)| (p.to_string(), h.to_string())).collect()
+    }
+
+    #[test]
+    fn test_manifest_digest_is_order_independent() {
+        let a = files(&[("index.html", "aa"), ("js/app.js", "bb")]);
+        let b = files(&[("js/app.js", "bb"), ("index.html", "aa")]);
+        assert_eq!(manifest_digest(&a), manifest_digest(&b));
+        assert_ne!(manifest_digest(&a), manifest_digest(&files(&[("index.html", "aa")])));
+    }
+
+    #[test]
+    fn test_compare_checksums() {
+        let recorded = files(&[("index.html", "aa"), ("app.js", "bb")]);
+        assert!(compare_checksums(&recorded, &recorded).is_ok());
+
+        let err = compare_checksums(&recorded, &files(&[("index.html", "cc"), ("evil.js", "dd")])).unwrap_err();
+        assert!(err.contains("modified: index.html"));
+        assert!(err.contains("missing: app.js"));
+        assert!(err.contains("unexpected: evil.js"));
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        let signer = ArtifactSigner { key_pair: Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap() };
+        let signature = signer.sign("abc");
+        assert!(signer.verify("abc", &signature));
+        assert!(!signer.verify("abd", &signature));
+        assert!(!signer.verify("abc", "not-base64!"));
+    }
+
+    #[tokio::test]
+    async fn test_signing_key_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("easycicd-signing-key-{}", std::process::id()));
+        let path = dir.join("keys/artifact.pk8");
+        let generated = ArtifactSigner::load_or_generate(&path).await.unwrap();
+        let mode = fs::metadata(&path).await.unwrap().permissions().mode();
+        let reloaded = ArtifactSigner::load_or_generate(&path).await.unwrap();
+        fs::remove_dir_all(&dir).await.ok();
+
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(generated.public_key_hex(), reloaded.public_key_hex());
+    }
+
+    #[tokio::test]
+    async fn test_compute_file_checksums() {
+        let dir = std::env::temp_dir().join(format!("easycicd-artifacts-{}", std::process::id()));
+        fs::create_dir_all(dir.join("js")).await.unwrap();
+        fs::write(dir.join("index.html"), b"hello").await.unwrap();
+        fs::write(dir.join("js/app.js"), b"").await.unwrap();
+
+        let checksums = compute_file_checksums(&dir).await.unwrap();
+        fs::remove_dir_all(&dir).await.ok();
+
+        assert_eq!(checksums.len(), 2);
+        assert_eq!(checksums["index.html"], "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824");
+        assert_eq!(checksums["js/app.js"], "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+    }
+}
@@ -0,0 +1,51 @@
+use tracing::warn;
+
+use crate::application::events::EventBus;
+use crate::application::ports::repositories::{BuildRepository, SettingsRepository};
+use crate::db::models::Project;
+
+use super::disk_quota_service::{DiskQuotaService, QuotaStatus};
+
+/// 새 빌드를 만들 수 없는 이유
+#[derive(Debug)]
+pub enum BuildRejection {
+    /// 보관된 프로젝트 (보관 해제 전까지 빌드하지 않음)
+    Archived,
+    /// 디스크 쿼터 초과
+    QuotaExceeded(QuotaStatus),
+}
+
+impl BuildRejection {
+    pub fn message(&self) -> String {
+        match self {
+            BuildRejection::Archived => "Project is archived. Unarchive it before building".to_string(),
+            BuildRejection::QuotaExceeded(status) => status.error_message(),
+        }
+    }
+}
+
+/// 새 빌드 허용 여부 (REST 수동 빌드/재빌드, gRPC 공용)
+///
+/// 쿼터 검사 자체가 실패하면 경고만 남기고 허용
+pub async fn check_build_allowed<BR, SR, EB>(
+    disk_quota_service: &DiskQuotaService<BR, SR, EB>,
+    trace_id: &str,
+    project: &Project,
+) -> Result<(), BuildRejection>
+where
+    BR: BuildRepository,
+    SR: SettingsRepository,
+    EB: EventBus,
+{
+    if project.archived_at.is_some() {
+        return Err(BuildRejection::Archived);
+    }
+    match disk_quota_service.check(trace_id, project).await {
+        Ok(status) if status.exceeded => Err(BuildRejection::QuotaExceeded(status)),
+        Ok(_) => Ok(()),
+        Err(e) => {
+            warn!("[{}] Disk quota check failed: {}", trace_id, e);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::application::ports::repositories::BuildRepository;
+use crate::db::models::{Build, BuildStatus};
+use crate::infrastructure::timezone;
+
+/// 평균 소요 시간에 사용하는 최근 성공 빌드 수
+pub const ESTIMATE_SAMPLE_SIZE: usize = 10;
+/// 성공 빌드를 찾기 위해 조회하는 최근 빌드 수
+pub const HISTORY_LOOKBACK: i64 = 50;
+/// 평균을 넘겨도 끝나기 전까지는 이 값으로 표시
+const MAX_RUNNING_PERCENT: u8 = 99;
+
+/// 실행 중 빌드의 예상 소요 시간과 진행률
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BuildEstimate {
+    /// 최근 성공 빌드의 평균 소요 시간 (초)
+    pub average_duration_secs: i64,
+    /// 평균에 사용한 빌드 수
+    pub sample_size: usize,
+    /// 실행 시작 후 경과 시간 (초, 큐 대기 제외)
+    pub elapsed_secs: i64,
+    /// 남은 예상 시간 (초). 평균을 넘기면 0
+    pub eta_secs: i64,
+    pub progress_percent: u8,
+}
+
+/// 끝난 빌드의 실행 시간 (초, 큐 대기 제외)
+pub fn build_duration_secs(build: &Build) -> Option<i64> {
+    let started = timezone::parse_stored(&build.started_at)?;
+    let finished = timezone::parse_stored(build.finished_at.as_deref()?)?;
+    Some(((finished - started).num_seconds() - queue_wait_secs(build)).max(0))
+}
+
+fn queue_wait_secs(build: &Build) -> i64 {
+    build.queue_wait_ms.unwrap_or(0) / 1000
+}
+
+/// 최근 성공(Success/Verified) 빌드의 평균 소요 시간 (초, 빌드 수). 같은 dry-run 여부의 빌드만 사용
+pub fn average_duration_secs(history: &[Build], dry_run: bool) -> Option<(i64, usize)> {
+    let durations: Vec<i64> = history
+        .iter()
+        .filter(|b| matches!(b.status, BuildStatus::Success | BuildStatus::Verified) && b.dry_run == dry_run)
+        .filter_map(build_duration_secs)
+        .take(ESTIMATE_SAMPLE_SIZE)
+        .collect();
+
+    if durations.is_empty() {
+        return None;
+    }
+    Some((durations.iter().sum::<i64>() / durations.len() as i64, durations.len()))
+}
+
+/// 실행 중 빌드의 예상치. `history`는 같은 프로젝트의 최근 빌드 (최신순)
+pub fn estimate(build: &Build, history: &[Build], now: DateTime<Utc>) -> Option<BuildEstimate> {
+    if build.status != BuildStatus::Building {
+        return None;
+    }
+    let (average, sample_size) = average_duration_secs(history, build.dry_run)?;
+    let started = timezone::parse_stored(&build.started_at)?;
+    let elapsed = ((now - started).num_seconds() - queue_wait_secs(build)).max(0);
+
+    let percent = if average == 0 {
+        MAX_RUNNING_PERCENT
+    } else {
+        (elapsed * 100 / average).min(MAX_RUNNING_PERCENT as i64) as u8
+    };
+
+    Some(BuildEstimate {
+        average_duration_secs: average,
+        sample_size,
+        elapsed_secs: elapsed,
+        eta_secs: (average - elapsed).max(0),
+        progress_percent: percent,
+    })
+}
+
+/// 프로젝트 이력을 조회해 예상치 계산 (이력이 없으면 None)
+pub async fn estimate_build<BR>(build_repo: &BR, build: &Build) -> Result<Option<BuildEstimate>>
+where
+    BR: BuildRepository + ?Sized,
+{
+    if build.status != BuildStatus::Building {
+        return Ok(None);
+    }
+    let history = build_repo.list_by_project(build.project_id, HISTORY_LOOKBACK).await?;
+    Ok(estimate(build, &history, Utc::now()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(id: i64, status: BuildStatus, started_at: &str, finished_at: Option<&str>) -> Build {
+        Build {
+            started_at: started_at.to_string(),
+            finished_at: finished_at.map(str::to_string),
+            ..Build::test(id, status)
+        }
+    }
+
+    #[test]
+    fn test_average_duration_uses_successful_builds() {
+        let mut queued = build(3, BuildStatus::Success, "2026-01-01 10:00:00", Some("2026-01-01 10:05:00"));
+        queued.queue_wait_ms = Some(60_000);
+        let history = vec![
+            build(5, BuildStatus::Failed, "2026-01-01 12:00:00", Some("2026-01-01 12:00:10")),
+            build(4, BuildStatus::Success, "2026-01-01 11:00:00", Some("2026-01-01 11:02:00")),
+            queued,
+            build(2, BuildStatus::Building, "2026-01-01 09:00:00", None),
+        ];
+
+        assert_eq!(average_duration_secs(&history, false), Some((180, 2)));
+        assert_eq!(average_duration_secs(&history, true), None);
+    }
+
+    #[test]
+    fn test_estimate_running_build() {
+        let history = vec![build(1, BuildStatus::Success, "2026-01-01 10:00:00", Some("2026-01-01 10:04:00"))];
+        let running = build(2, BuildStatus::Building, "2026-01-01 12:00:00", None);
+
+        let now = timezone::parse_stored("2026-01-01 12:01:00").unwrap();
+        let estimate = estimate(&running, &history, now).unwrap();
+        assert_eq!(estimate.elapsed_secs, 60);
+        assert_eq!(estimate.eta_secs, 180);
+        assert_eq!(estimate.progress_percent, 25);
+
+        // 평균을 넘기면 99%, 남은 시간 0
+        let now = timezone::parse_stored("2026-01-01 12:10:00").unwrap();
+        let estimate = super::estimate(&running, &history, now).unwrap();
+        assert_eq!(estimate.eta_secs, 0);
+        assert_eq!(estimate.progress_percent, 99);
+
+        assert_eq!(super::estimate(&history[0], &history, now), None);
+    }
+}
@@ -1,16 +1,44 @@
 use anyhow::{Context, Result};
 use serde_json;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
 use tracing::{info, warn};
 
 use crate::application::ports::repositories::{BuildRepository, ProjectRepository, SettingsRepository, GitHubPatRepository};
-use crate::application::events::{EventBus, Event};
-use crate::db::models::{BuildStatus, Project, Build};
-use crate::docker::DockerClient;
+use crate::application::events::{BuildStep, EventBus, Event};
+use crate::application::services::artifact_integrity::{checksum_artifacts, ArtifactSigner};
+use crate::application::ports::git_provider::{parse_repo_url, GitProviderKind};
+use crate::application::services::git_providers::{git_provider_for, resolve_provider_token};
+use crate::application::services::failure_summary::summarize_failure;
+use crate::application::services::log_levels::LogLevels;
+use crate::application::services::test_results::collect_junit_reports;
+use crate::db::models::{
+    BuildEnvironment, BuildNetwork, BuildStageResult, BuildStageStatus, BuildStatus, LogLevelCounts, PipelineStage, Project, Build, ProjectTestConfig, SourceFetch, TestCaseResult, TestCaseStatus, TestShardResult, TestSummary,
+};
+use crate::docker::{build_image_tag, cache_mount_path, BuildContainerOptions, BuildResult, DockerClient};
+use crate::infrastructure::database::SqliteSecretRepository;
 use crate::infrastructure::logging::{BoundaryLogger, Timer};
+use crate::infrastructure::secrets::has_secret_ref;
+
+/// 실패 요약을 만들 때 보는 빌드 로그 마지막 줄 수
+const FAILURE_SUMMARY_SCAN_LINES: usize = 2000;
+
+/// tarball 모드에서 /source에 마운트되는 파일 이름
+const SOURCE_TARBALL_NAME: &str = "source.tar.gz";
+
+/// 서버가 받아둔 소스 디렉토리. 빌드가 끝나면 (성공/실패 모두) 삭제
+struct SourceArchive {
+    dir: PathBuf,
+}
+
+impl Drop for SourceArchive {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
 
 /// BuildService - 빌드 실행을 담당하는 서비스
 ///
@@ -35,6 +63,8 @@ where
     event_bus: EB,
     docker: DockerClient,
     logger: Arc<BoundaryLogger>,
+    secret_repo: Arc<SqliteSecretRepository>,
+    log_levels: Arc<LogLevels>,
 }
 
 impl<BR, PR, SR, GPR, EB> BuildService<BR, PR, SR, GPR, EB>
@@ -45,6 +75,7 @@ where
     GPR: GitHubPatRepository,
     EB: EventBus,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         build_repo: Arc<BR>,
         project_repo: Arc<PR>,
@@ -53,6 +84,8 @@ where
         event_bus: EB,
         docker: DockerClient,
         logger: Arc<BoundaryLogger>,
+        secret_repo: Arc<SqliteSecretRepository>,
+        log_levels: Arc<LogLevels>,
     ) -> Self {
         Self {
             build_repo,
@@ -62,6 +95,8 @@ where
             event_bus,
             docker,
             logger,
+            secret_repo,
+            log_levels,
         }
     }
 
@@ -81,10 +116,29 @@ where
         // Get project
         self.logger.repo_call(trace_id, "BuildService", "ProjectRepo", "get");
         let repo_timer = Timer::start();
-        let project = self.project_repo.get(build.project_id).await?
+        let mut project = self.project_repo.get(build.project_id).await?
             .context("Project not found")?;
         self.logger.repo_done(trace_id, "BuildService", "ProjectRepo", "get", repo_timer.elapsed_ms());
 
+        // 정확한 재빌드: 현재 프로젝트 빌드 설정 대신 원본 빌드의 환경 스냅샷으로 실행
+        let reproduced = match build.rebuild_of {
+            Some(source_id) => {
+                self.logger.repo_call(trace_id, "BuildService", "BuildRepo", "get");
+                let environment = self.build_repo.get(source_id).await?
+                    .and_then(|source| source.parsed_build_environment())
+                    .with_context(|| format!("No environment snapshot recorded for build {}", source_id))?;
+                info!("[{}] Reproducing build {} at commit {}", trace_id, source_id, build.commit_hash);
+                apply_build_environment(&mut project, &environment);
+                Some(environment)
+            }
+            None => None,
+        };
+
+        // PR 프리뷰: 프로젝트 브랜치 대신 PR head 브랜치를 받음
+        if let (Some(_), Some(branch), None) = (build.preview_pr, &build.branch, &reproduced) {
+            project.branch = branch.clone();
+        }
+
         info!(
             "[{}] Executing build #{} for project {}",
             trace_id, build.build_number, project.name
@@ -94,6 +148,15 @@ where
         self.logger.repo_call(trace_id, "BuildService", "BuildRepo", "update_status");
         self.build_repo.update_status(build_id, BuildStatus::Building).await?;
 
+        // 큐 대기 시간 기록 (빌드 생성 시각 ~ 실행 시작)
+        if let Some(queued_at) = crate::infrastructure::timezone::parse_stored(&build.started_at) {
+            let queue_wait_ms = (chrono::Utc::now() - queued_at).num_milliseconds().max(0);
+            info!("[{}] Build #{} waited {} ms in queue", trace_id, build.build_number, queue_wait_ms);
+            if let Err(e) = self.build_repo.update_queue_wait(build_id, queue_wait_ms).await {
+                warn!("[{}] Failed to save queue wait time: {}", trace_id, e);
+            }
+        }
+
         // Emit event
         self.logger.event_emit(trace_id, "BuildService", "BuildStatus::Building");
         self.event_bus.emit(Event::BuildStatus {
@@ -102,6 +165,7 @@ where
             status: BuildStatus::Building,
             timestamp: Event::now(),
         }).await;
+        let mut steps = StepProgress::new(&self.event_bus, build.id, project.id);
 
         // Setup paths (workspace removed - git clone happens inside container)
         let output_path = PathBuf::from("/data/output").join(format!("build{}", build.id));
@@ -116,72 +180,18 @@ where
         }
 
         // Create fresh directories
+        steps.start(BuildStep::RestoringCache).await;
         fs::create_dir_all(&output_path).await.context("Failed to create output directory")?;
         fs::create_dir_all(&cache_path).await.context("Failed to create cache directory")?;
         fs::create_dir_all(log_path.parent().unwrap()).await.context("Failed to create log directory")?;
 
-        // Get GitHub PAT for git authentication inside container
-        // Try project-specific PAT first, then fallback to legacy global PAT
-        let github_token = if let Some(pat_id) = project.github_pat_id {
-            match self.github_pat_repo.get(pat_id).await {
-                Ok(Some(pat)) => Some(pat.token),
-                _ => self.settings_repo.get("github_pat").await.ok().flatten(),
+        // 파이프라인: 단계마다 표시 출력을 끼워 build_command 하나로 합침 (정확한 재빌드는 기록된 명령 그대로)
+        if reproduced.is_none() {
+            if let Some(stages) = project.parsed_pipeline() {
+                project.build_command = pipeline_command(&stages);
             }
-        } else {
-            self.settings_repo.get("github_pat").await.ok().flatten()
-        };
-
-        // GitHub PAT를 URL에 embed하지 않고 환경변수로 전달.
-        // 기존 방식(https://TOKEN@github.com/...)은 ps aux에서 토큰이 노출됨.
-        // 수정: 토큰을 GIT_CLONE_TOKEN env var으로 전달하고 git credential store 사용.
-        let clone_repo_url = project.repo.clone(); // 토큰 없는 원래 URL
-        let has_token = github_token.is_some();
-
-        // Construct full build command with git clone inside container
-        let working_dir_path = if let Some(wd) = &project.working_directory {
-            format!("/{}", wd)
-        } else {
-            String::new()
-        };
-
-        // Add environment variables for build
-        // - CI=true: Treat warnings as errors (standard CI behavior)
-        // - SKIP_PREFLIGHT_CHECK: Skip CRA version check (avoids false positives)
-        let mut env_vars_list = vec!["CI=true".to_string(), "SKIP_PREFLIGHT_CHECK=true".to_string()];
-
-        // GitHub PAT를 URL 대신 환경변수로 전달 (ps aux 노출 방지)
-        if let Some(token) = &github_token {
-            env_vars_list.push(format!("GIT_CLONE_TOKEN={}", token));
         }
 
-        // Parse and add user-defined build environment variables (JSON format)
-        if let Some(build_env_json) = &project.build_env_vars {
-            if let Ok(parsed) = serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(build_env_json) {
-                for (key, value) in parsed {
-                    let val_str = match value {
-                        serde_json::Value::String(s) => s,
-                        other => other.to_string().trim_matches('"').to_string(),
-                    };
-                    env_vars_list.push(format!("{}={}", key, val_str));
-                }
-            }
-        }
-
-        // 환경변수를 export 형태로 변환하여 전체 빌드 명령어에 적용되도록 함
-        let env_exports = env_vars_list.iter()
-            .map(|v| format!("export {}", v))
-            .collect::<Vec<_>>()
-            .join(" && ");
-
-        // git credential 설정: GIT_CLONE_TOKEN 환경변수를 git credential store로 등록.
-        // 토큰이 URL에 포함되지 않으므로 ps aux, git reflog에서 노출되지 않음.
-        let git_auth_setup = if has_token {
-            "git config --global credential.helper store && \
-             printf 'https://oauth2:%s@github.com\\n' \"$GIT_CLONE_TOKEN\" > /root/.git-credentials && "
-        } else {
-            ""
-        };
-
         // build_image 기반으로 프로젝트 타입 감지 (cache_type과 독립적으로 동작)
         let build_image_lower = project.build_image.to_lowercase();
         let build_cmd_lower = project.build_command.to_lowercase();
@@ -198,7 +208,10 @@ where
         // 프로젝트 타입에 따라 출력물 복사 명령어 자동 생성
         // build_command에 이미 /output/ 복사가 포함되어 있으면 중복 방지를 위해 스킵
         // 중요: fallback으로 전체 소스 복사하는 패턴 제거 - 빌드 실패 시 명확하게 에러 반환
-        let output_copy_command = if project.build_command.contains("/output/") {
+        let output_copy_command = if project.dockerfile_path.is_some() {
+            // Dockerfile 빌드: 소스 전체를 이미지 빌드 컨텍스트로 복사 (.git 제외)
+            "cp -a . /output/ && rm -rf /output/.git"
+        } else if project.build_command.contains("/output/") {
             // 이미 복사 명령이 있음 (detector.rs에서 자동 생성된 경우)
             ""
         } else if is_gradle_project {
@@ -225,32 +238,23 @@ where
         };
 
         // output_copy_command가 비어있으면 추가하지 않음 (이중 복사 방지)
-        // 순서: env_exports 먼저 (GIT_CLONE_TOKEN export 포함) → git_auth_setup → git clone → build.
-        // git_auth_setup이 $GIT_CLONE_TOKEN을 참조하므로 env_exports가 반드시 선행되어야 함.
-        let full_build_command = if output_copy_command.is_empty() {
-            format!(
-                "{} && {}git clone --depth 1 --branch {} {} /workspace && cd /workspace{} && {}",
-                env_exports,
-                git_auth_setup,
-                project.branch,
-                clone_repo_url,
-                working_dir_path,
-                project.build_command
-            )
+        // 단계 표시 출력을 끼워 넣어 컨테이너 안의 단계 전환을 실시간으로 알림
+        let build_steps = if output_copy_command.is_empty() {
+            format!("{} && {}", step_marker(BuildStep::Building), project.build_command)
+        } else if project.build_command.trim().is_empty() {
+            // Dockerfile 빌드는 build_command 없이 소스만 복사할 수 있음
+            format!("{} && {}", step_marker(BuildStep::CopyingArtifacts), output_copy_command)
         } else {
             format!(
-                "{} && {}git clone --depth 1 --branch {} {} /workspace && cd /workspace{} && {} && {}",
-                env_exports,
-                git_auth_setup,
-                project.branch,
-                clone_repo_url,
-                working_dir_path,
+                "{} && {} && {} && {}",
+                step_marker(BuildStep::Building),
                 project.build_command,
+                step_marker(BuildStep::CopyingArtifacts),
                 output_copy_command
             )
         };
 
-        info!("[{}] Build command: git clone + {}", trace_id, project.build_command);
+        info!("[{}] Build command: {} checkout ({} network) + {}", trace_id, project.source_fetch, project.build_network, project.build_command);
 
         // Open log file
         let mut log_file = fs::OpenOptions::new()
@@ -261,26 +265,155 @@ where
             .context("Failed to open log file")?;
 
         // Create nginx config if needed
-        if project.runtime_image.contains("nginx") {
+        if project.dockerfile_path.is_none() && project.runtime_image.contains("nginx") {
             self.create_nginx_config(&output_path, project.runtime_port as u16).await?;
         }
 
+        // tarball 모드: 서버에서 소스를 받아 마운트 (테스트 shard도 같은 소스 사용, 빌드가 끝나면 삭제)
+        steps.start(BuildStep::Cloning).await;
+        let git_ref = if reproduced.is_some() { &build.commit_hash } else { &project.branch };
+        let source = match self.fetch_source(trace_id, &project, git_ref, &format!("build{}", build.id)).await {
+            Ok(source) => source,
+            Err(e) => {
+                let message = format!("[SOURCE] Failed to download source tarball: {}\n", e);
+                log_file.write_all(message.as_bytes()).await.ok();
+                steps.finish(false).await;
+                return Err(e);
+            }
+        };
+        // 비밀을 참조하는 빌드 환경변수는 빌드 명령(환경 스냅샷) 대신 컨테이너 환경변수로 전달
+        self.logger.repo_call(trace_id, "BuildService", "SecretRepo", "resolve_env");
+        let secrets = match self.secret_repo.resolve_env(project.build_env_vars.as_deref()).await {
+            Ok(secrets) => secrets,
+            Err(e) => {
+                log_file.write_all(format!("[SECRETS] {}\n", e).as_bytes()).await.ok();
+                steps.finish(false).await;
+                return Err(e);
+            }
+        };
+        let container_options = BuildContainerOptions {
+            source_path: source.as_ref().map(|s| s.dir.clone()),
+            network: project.build_network,
+            docker_access: project.docker_access,
+            output_lines: None,
+            build_id: Some(build.id),
+            secrets,
+        };
+        let (checkout_command, build_steps) = match &reproduced {
+            Some(environment) => {
+                let github_token = if source.is_some() { None } else { self.clone_token(&project).await };
+                match exact_rebuild_command(environment, &build.commit_hash, github_token.as_deref()) {
+                    Ok(command) => command,
+                    Err(e) => {
+                        steps.finish(false).await;
+                        return Err(e);
+                    }
+                }
+            }
+            None => (
+                format!("{} && {}", step_marker(BuildStep::Cloning), self.checkout_command(&project, source.is_some()).await),
+                build_steps,
+            ),
+        };
+        let full_build_command = format!("{} && {}", checkout_command, build_steps);
+        let mut stages = StageProgress::new(&self.event_bus, build.id, project.id, planned_stages(&full_build_command));
+
+        // 이미지를 digest로 고정해 실행 (기록한 환경 스냅샷과 실제 실행 이미지가 같도록)
+        // 정확한 재빌드는 원본 빌드가 실행한 digest 그대로
+        let image = reproduced.as_ref()
+            .and_then(|environment| environment.image_digest.as_deref())
+            .unwrap_or(&project.build_image);
+        self.logger.external_call(trace_id, "BuildService", "Docker", "resolve_image_digest");
+        let image_digest = match self.docker.resolve_image_digest(image).await {
+            Ok(image) => Some(image),
+            Err(e) => {
+                warn!("[{}] Failed to resolve build image digest for {}: {}", trace_id, project.build_image, e);
+                None
+            }
+        };
+        let environment = build_environment(
+            &project,
+            image_digest.clone(),
+            &full_build_command,
+            &output_path,
+            &cache_path,
+            &container_options,
+        );
+        match serde_json::to_string(&environment) {
+            Ok(json) => {
+                self.logger.repo_call(trace_id, "BuildService", "BuildRepo", "update_build_environment");
+                if let Err(e) = self.build_repo.update_build_environment(build.id, &json).await {
+                    warn!("[{}] Failed to save build environment: {}", trace_id, e);
+                }
+            }
+            Err(e) => warn!("[{}] Failed to serialize build environment: {}", trace_id, e),
+        }
+
         // Run build container (with git clone command included)
         self.logger.external_call(trace_id, "BuildService", "Docker", "run_build_container");
         let docker_timer = Timer::start();
 
-        let build_result = self.docker.run_build_container(
-            &project.build_image,
-            &full_build_command,
-            output_path.clone(),
-            cache_path,
-            &project.cache_type,
-        ).await?;
+        // 컨테이너 출력에서 단계 표시를 읽어 실시간으로 단계 이벤트 발행
+        let (line_tx, mut line_rx) = mpsc::unbounded_channel();
+        let build_options = BuildContainerOptions {
+            output_lines: Some(line_tx),
+            ..container_options.clone()
+        };
+        let run = async {
+            let options = build_options;
+            self.docker.run_build_container(
+                image_digest.as_deref().unwrap_or(&project.build_image),
+                &full_build_command,
+                output_path.clone(),
+                cache_path.clone(),
+                &project.cache_type,
+                &options,
+            ).await
+        };
+        let track = async {
+            while let Some(line) = line_rx.recv().await {
+                if let Some(step) = parse_step_marker(&line) {
+                    stages.end("completed").await;
+                    steps.start(step).await;
+                } else if let Some(stage) = parse_stage_marker(&line) {
+                    stages.start(stage).await;
+                }
+            }
+        };
+        let run_result = tokio::join!(run, track).0;
+
+        let logs = run_result.as_ref().map(|r| r.logs.as_slice()).unwrap_or_default();
+        let stage_results = stages.finish(matches!(&run_result, Ok(r) if r.success), logs).await;
+        if !stage_results.is_empty() {
+            if let Err(e) = self.build_repo.insert_build_stages(build.id, project.id, &stage_results).await {
+                warn!("[{}] Failed to save build stages: {}", trace_id, e);
+            }
+        }
+
+        let build_result = match run_result {
+            Ok(result) => result,
+            Err(e) => {
+                steps.finish(false).await;
+                return Err(e);
+            }
+        };
 
         self.logger.external_done(trace_id, "BuildService", "Docker", "run_build_container", docker_timer.elapsed_ms());
 
+        let usage = build_result.resource_usage;
+        if let Err(e) = self.build_repo.update_resource_usage(
+            build.id,
+            usage.peak_memory_bytes as i64,
+            usage.cpu_time_ms as i64,
+        ).await {
+            warn!("[{}] Failed to save resource usage: {}", trace_id, e);
+        }
+
         // Write logs and emit events (always, regardless of success/failure)
+        let classifier = self.log_levels.classifier();
+        let mut log_levels = LogLevelCounts::default();
         for (idx, line) in build_result.logs.iter().enumerate() {
+            log_levels.add(classifier.classify(line));
             log_file.write_all(line.as_bytes()).await.context("Failed to write log")?;
             log_file.write_all(b"\n").await.ok();
 
@@ -296,12 +429,39 @@ where
         if let Err(e) = log_file.flush().await {
             warn!("[{}] Failed to flush log file: {}", trace_id, e);
         }
+        self.save_log_levels(trace_id, build.id, &log_levels).await;
 
         if build_result.success {
+            // Dockerfile 빌드: 복사한 소스로 런타임 이미지를 빌드 (산출물 검증 대신)
+            let mut log_lines = build_result.logs.len();
+            let image_build = match project.dockerfile_path.as_deref() {
+                Some(dockerfile) => {
+                    steps.start(BuildStep::BuildingImage).await;
+                    let result = self
+                        .build_runtime_image(trace_id, &build, &output_path, dockerfile, &mut log_file, &mut log_lines, &mut log_levels)
+                        .await;
+                    self.save_log_levels(trace_id, build.id, &log_levels).await;
+                    Some(result)
+                }
+                None => None,
+            };
+
             // Validate build output exists (with timestamp check for stale artifacts)
-            let validation_result = self.validate_build_output(&output_path, &project, build_start_time).await;
+            steps.start(BuildStep::Validating).await;
+            let validation_result = match image_build {
+                Some(result) => result,
+                None => self.validate_build_output(&output_path, &project, build_start_time).await,
+            };
             if let Err(e) = validation_result {
                 warn!("[{}] Build output validation failed: {}", trace_id, e);
+                steps.finish(false).await;
+
+                let error_msg = if project.dockerfile_path.is_some() {
+                    format!("Image build failed: {}", e)
+                } else {
+                    format!("Build output validation failed: {}", e)
+                };
+                self.save_failure_summary(trace_id, build.id, &mut log_file, &log_path, &error_msg).await;
 
                 // Update status to Failed
                 self.build_repo.update_status(build.id, BuildStatus::Failed).await?;
@@ -312,7 +472,6 @@ where
                     timestamp: Event::now(),
                 }).await;
 
-                let error_msg = format!("Build output validation failed: {}", e);
                 self.event_bus.emit(Event::Error {
                     project_id: Some(project.id),
                     build_id: Some(build.id),
@@ -323,11 +482,101 @@ where
                 anyhow::bail!("{}", error_msg);
             }
 
+            // Test stage: 설정된 경우 shard 컨테이너들에서 병렬 실행
+            if let Some(test_config) = project.parsed_test_config() {
+                steps.start(BuildStep::Testing).await;
+                let (summary, test_results) = self.run_test_shards(
+                    trace_id,
+                    &project,
+                    &build,
+                    &test_config,
+                    &checkout_command,
+                    &container_options,
+                    &cache_path,
+                    &mut log_file,
+                    log_lines,
+                    &mut log_levels,
+                ).await;
+                self.save_log_levels(trace_id, build.id, &log_levels).await;
+
+                match serde_json::to_string(&summary) {
+                    Ok(json) => {
+                        if let Err(e) = self.build_repo.update_test_summary(build.id, json).await {
+                            warn!("[{}] Failed to save test summary: {}", trace_id, e);
+                        }
+                    }
+                    Err(e) => warn!("[{}] Failed to serialize test summary: {}", trace_id, e),
+                }
+
+                if !test_results.is_empty() {
+                    if let Err(e) = self.build_repo.insert_test_results(build.id, project.id, &test_results).await {
+                        warn!("[{}] Failed to save test results: {}", trace_id, e);
+                    }
+                }
+
+                if !summary.success() {
+                    steps.finish(false).await;
+                    let error_msg = format!(
+                        "Tests failed: {} of {} shards failed",
+                        summary.failed, summary.shard_count
+                    );
+                    self.save_failure_summary(trace_id, build.id, &mut log_file, &log_path, &error_msg).await;
+
+                    self.build_repo.update_status(build.id, BuildStatus::Failed).await?;
+                    self.event_bus.emit(Event::BuildStatus {
+                        build_id: build.id,
+                        project_id: project.id,
+                        status: BuildStatus::Failed,
+                        timestamp: Event::now(),
+                    }).await;
+
+                    self.event_bus.emit(Event::Error {
+                        project_id: Some(project.id),
+                        build_id: Some(build.id),
+                        message: error_msg.clone(),
+                        timestamp: Event::now(),
+                    }).await;
+
+                    self.logger.service_error(trace_id, "API", "BuildService", "execute_build", &anyhow::anyhow!("{}", error_msg));
+                    anyhow::bail!("{}", error_msg);
+                }
+            }
+
+            // 산출물 checksum (+ agent 키 서명) 기록. 롤백 전에 변조/손상 여부를 확인하는 기준
+            let signer = match ArtifactSigner::from_env().await {
+                Some(Ok(signer)) => Some(signer),
+                Some(Err(e)) => {
+                    warn!("[{}] Artifact signing disabled: {}", trace_id, e);
+                    None
+                }
+                None => None,
+            };
+            let checksums = checksum_artifacts(&output_path, signer.as_ref())
+                .await
+                .context("Failed to compute artifact checksums")?;
+            info!(
+                "[{}] Build #{} artifacts: {} files, digest {}{}",
+                trace_id,
+                build.build_number,
+                checksums.files.len(),
+                checksums.digest,
+                if checksums.signature.is_some() { " (signed)" } else { "" }
+            );
+            self.logger.repo_call(trace_id, "BuildService", "BuildRepo", "update_artifacts");
+            self.build_repo
+                .update_artifacts(build.id, &output_path.to_string_lossy(), &serde_json::to_string(&checksums)?)
+                .await?;
+
+            steps.finish(true).await;
             info!("[{}] Build #{} completed successfully", trace_id, build.build_number);
             self.logger.service_exit(trace_id, "API", "BuildService", "execute_build", timer.elapsed_ms());
             Ok(output_path)
         } else {
             warn!("[{}] Build #{} failed with exit code: {}", trace_id, build.build_number, build_result.exit_code);
+            steps.finish(false).await;
+
+            let error_msg = format!("Build failed with exit code: {}", build_result.exit_code);
+            self.save_failure_summary(trace_id, build.id, &mut log_file, &log_path, &error_msg).await;
 
             // Update status to Failed
             self.logger.repo_call(trace_id, "BuildService", "BuildRepo", "update_status");
@@ -342,7 +591,6 @@ where
                 timestamp: Event::now(),
             }).await;
 
-            let error_msg = format!("Build failed with exit code: {}", build_result.exit_code);
             self.event_bus.emit(Event::Error {
                 project_id: Some(project.id),
                 build_id: Some(build.id),
@@ -355,6 +603,465 @@ where
         }
     }
 
+    /// Dockerfile 빌드: `context`(/output에 복사한 소스)로 런타임 이미지를 빌드하고 태그를 기록
+    ///
+    /// 출력은 빌드 로그에 이어서 기록한다 (`log_lines`는 다음 줄 번호)
+    #[allow(clippy::too_many_arguments)]
+    async fn build_runtime_image(
+        &self,
+        trace_id: &str,
+        build: &Build,
+        context: &Path,
+        dockerfile: &str,
+        log_file: &mut fs::File,
+        log_lines: &mut usize,
+        log_levels: &mut LogLevelCounts,
+    ) -> Result<()> {
+        if !context.join(dockerfile).is_file() {
+            anyhow::bail!("Dockerfile not found: {}", dockerfile);
+        }
+        let tag = build_image_tag(build.project_id, build.build_number);
+
+        self.logger.external_call(trace_id, "BuildService", "Docker", "build_image");
+        let docker_timer = Timer::start();
+        let result = self.docker.build_image(context, dockerfile, &tag, build.id).await?;
+        self.logger.external_done(trace_id, "BuildService", "Docker", "build_image", docker_timer.elapsed_ms());
+
+        let classifier = self.log_levels.classifier();
+        for line in &result.logs {
+            log_levels.add(classifier.classify(line));
+            log_file.write_all(format!("{}\n", line).as_bytes()).await.context("Failed to write log")?;
+            self.event_bus.emit(Event::Log {
+                build_id: build.id,
+                line: line.clone(),
+                line_number: *log_lines,
+                timestamp: Event::now(),
+            }).await;
+            *log_lines += 1;
+        }
+        if let Err(e) = log_file.flush().await {
+            warn!("[{}] Failed to flush log file: {}", trace_id, e);
+        }
+
+        if !result.success {
+            let reason = result.logs.iter().rev()
+                .find_map(|line| line.strip_prefix("ERROR: "))
+                .unwrap_or("docker build failed");
+            anyhow::bail!("{}", reason);
+        }
+
+        info!("[{}] Built runtime image {}", trace_id, tag);
+        self.logger.repo_call(trace_id, "BuildService", "BuildRepo", "update_image_tag");
+        self.build_repo.update_image_tag(build.id, &tag).await?;
+        Ok(())
+    }
+
+    /// 빌드 로그 심각도별 줄 수 저장 (`GET /api/builds/:id/logs?level=`로 해당 줄만 조회)
+    /// 빌드 로그 끝부분으로 실패 요약을 만들어 기록. 실패 알림이 읽을 수 있도록 BuildStatus::Failed 발행 전에 호출
+    async fn save_failure_summary(&self, trace_id: &str, build_id: i64, log_file: &mut fs::File, log_path: &Path, reason: &str) {
+        if let Err(e) = log_file.flush().await {
+            warn!("[{}] Failed to flush log file: {}", trace_id, e);
+        }
+        let content = fs::read_to_string(log_path).await.unwrap_or_else(|e| {
+            warn!("[{}] Failed to read build log for failure summary: {}", trace_id, e);
+            String::new()
+        });
+        let lines: Vec<String> = content.lines().map(str::to_string).collect();
+        let tail = &lines[lines.len().saturating_sub(FAILURE_SUMMARY_SCAN_LINES)..];
+        let summary = summarize_failure(reason, tail);
+
+        self.logger.repo_call(trace_id, "BuildService", "BuildRepo", "update_failure_summary");
+        if let Err(e) = self.build_repo.update_failure_summary(build_id, &summary).await {
+            warn!("[{}] Failed to save failure summary: {}", trace_id, e);
+        }
+    }
+
+    async fn save_log_levels(&self, trace_id: &str, build_id: i64, counts: &LogLevelCounts) {
+        let result = match serde_json::to_string(counts) {
+            Ok(json) => self.build_repo.update_log_levels(build_id, json).await,
+            Err(e) => Err(e.into()),
+        };
+        if let Err(e) = result {
+            warn!("[{}] Failed to save log level counts: {}", trace_id, e);
+        }
+    }
+
+    /// tarball 모드면 GitHub/GitLab API로 소스 tarball을 `/data/source/{name}`에 받는다 (git 모드는 None).
+    /// 네트워크 없는 빌드는 컨테이너 안에서 clone할 수 없으므로 항상 tarball을 사용한다.
+    ///
+    /// PAT는 서버에서만 쓰이고 빌드 컨테이너 환경변수/명령에 들어가지 않는다.
+    async fn fetch_source(&self, trace_id: &str, project: &Project, git_ref: &str, name: &str) -> Result<Option<SourceArchive>> {
+        if project.source_fetch != SourceFetch::Tarball && project.build_network != BuildNetwork::None {
+            return Ok(None);
+        }
+
+        let repo_ref = parse_repo_url(&project.repo)
+            .with_context(|| format!("Invalid repo URL format: {}", project.repo))?;
+        let provider = git_provider_for(self.github_pat_repo.as_ref(), self.settings_repo.as_ref(), repo_ref.provider, project.github_pat_id)
+            .await?
+            .with_context(|| format!("Tarball source fetch requires a {} token", repo_ref.provider))?;
+
+        let dir = PathBuf::from("/data/source").join(name);
+        let _ = fs::remove_dir_all(&dir).await;
+        fs::create_dir_all(&dir).await.context("Failed to create source directory")?;
+        let archive = SourceArchive { dir };
+
+        self.logger.external_call(trace_id, "BuildService", repo_ref.provider.as_str(), "download_tarball");
+        let timer = Timer::start();
+        let bytes = provider
+            .download_tarball(&repo_ref.owner, &repo_ref.name, git_ref, &archive.dir.join(SOURCE_TARBALL_NAME))
+            .await?;
+        self.logger.external_done(trace_id, "BuildService", repo_ref.provider.as_str(), "download_tarball", timer.elapsed_ms());
+        info!("[{}] Downloaded {}@{} tarball ({} bytes)", trace_id, repo_ref.full_name(), git_ref, bytes);
+
+        Ok(Some(archive))
+    }
+
+    /// 컨테이너 안 git clone에 쓸 토큰 (GitHub PAT 또는 GitLab token, 없거나 조회 실패면 None)
+    async fn clone_token(&self, project: &Project) -> Option<String> {
+        let kind = parse_repo_url(&project.repo).map(|r| r.provider).unwrap_or_default();
+        match resolve_provider_token(
+            self.github_pat_repo.as_ref(),
+            self.settings_repo.as_ref(),
+            kind,
+            project.github_pat_id,
+        ).await {
+            Ok(token) => token,
+            Err(e) => {
+                warn!("Failed to resolve {} token for project {}: {}", kind, project.name, e);
+                None
+            }
+        }
+    }
+
+    /// 컨테이너 안에서 소스를 받는 명령 (환경변수 export → git 인증 → clone → working_directory 이동)
+    ///
+    /// 빌드, 테스트 shard, 캐시 예열 컨테이너가 같은 명령을 사용한다.
+    /// `from_tarball`이면 clone 대신 /source에 마운트된 tarball을 /workspace에 푼다.
+    async fn checkout_command(&self, project: &Project, from_tarball: bool) -> String {
+        // Get GitHub PAT for git authentication inside container (tarball 모드는 컨테이너에 전달하지 않음)
+        let github_token = if from_tarball {
+            None
+        } else {
+            self.clone_token(project).await
+        };
+
+        // GitHub PAT를 URL에 embed하지 않고 환경변수로 전달.
+        // 기존 방식(https://TOKEN@github.com/...)은 ps aux에서 토큰이 노출됨.
+        // 수정: 토큰을 GIT_CLONE_TOKEN env var으로 전달하고 git credential store 사용.
+        let clone_repo_url = project.repo.clone(); // 토큰 없는 원래 URL
+        let has_token = github_token.is_some();
+
+        // Construct full build command with git clone inside container
+        let working_dir_path = if let Some(wd) = &project.working_directory {
+            format!("/{}", wd)
+        } else {
+            String::new()
+        };
+
+        // Add environment variables for build
+        // - CI=true: Treat warnings as errors (standard CI behavior)
+        // - SKIP_PREFLIGHT_CHECK: Skip CRA version check (avoids false positives)
+        let mut env_vars_list = vec!["CI=true".to_string(), "SKIP_PREFLIGHT_CHECK=true".to_string()];
+
+        // GitHub PAT를 URL 대신 환경변수로 전달 (ps aux 노출 방지)
+        if let Some(token) = &github_token {
+            env_vars_list.push(format!("GIT_CLONE_TOKEN={}", token));
+        }
+
+        // Parse and add user-defined build environment variables (JSON format)
+        if let Some(build_env_json) = &project.build_env_vars {
+            if let Ok(parsed) = serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(build_env_json) {
+                for (key, value) in parsed {
+                    if value.as_str().is_some_and(has_secret_ref) {
+                        continue;
+                    }
+                    let val_str = match value {
+                        serde_json::Value::String(s) => s,
+                        other => other.to_string().trim_matches('"').to_string(),
+                    };
+                    env_vars_list.push(format!("{}={}", key, val_str));
+                }
+            }
+        }
+
+        // 환경변수를 export 형태로 변환하여 전체 빌드 명령어에 적용되도록 함
+        let env_exports = env_vars_list.iter()
+            .map(|v| format!("export {}", v))
+            .collect::<Vec<_>>()
+            .join(" && ");
+
+        // git credential 설정: GIT_CLONE_TOKEN 환경변수를 git credential store로 등록.
+        // 토큰이 URL에 포함되지 않으므로 ps aux, git reflog에서 노출되지 않음.
+        // GitHub/GitLab은 사용자 이름 oauth2, Bitbucket access token은 x-token-auth로 HTTPS 인증
+        let git_auth_setup = if has_token {
+            format!(
+                "git config --global credential.helper store && \
+                 printf 'https://{}:%s@{}\\n' \"$GIT_CLONE_TOKEN\" > /root/.git-credentials && ",
+                credential_user(&project.repo),
+                credential_host(&project.repo)
+            )
+        } else {
+            String::new()
+        };
+
+        if from_tarball {
+            // GitHub/GitLab tarball은 최상위에 디렉토리가 하나 있음 ({owner}-{repo}-{sha}/, {repo}-{ref}-{sha}/)
+            return format!(
+                "{} && mkdir -p /workspace && tar -xzf /source/{} --strip-components=1 -C /workspace && cd /workspace{}",
+                env_exports,
+                SOURCE_TARBALL_NAME,
+                working_dir_path
+            );
+        }
+
+        // 순서: env_exports 먼저 (GIT_CLONE_TOKEN export 포함) → git_auth_setup → git clone.
+        // git_auth_setup이 $GIT_CLONE_TOKEN을 참조하므로 env_exports가 반드시 선행되어야 함.
+        format!(
+            "{} && {}git clone --depth 1 --branch {} {} /workspace && cd /workspace{}",
+            env_exports,
+            git_auth_setup,
+            project.branch,
+            clone_repo_url,
+            working_dir_path
+        )
+    }
+
+    /// 캐시 예열: 의존성 해석 단계만 실행해 /data/cache/{cache_type}을 채움 (산출물/배포 없음)
+    ///
+    /// 실행 로그는 /data/easycicd/logs/{project_id}/warm-cache.log에 덮어쓴다.
+    pub async fn warm_cache(&self, trace_id: &str, project: &Project) -> Result<BuildResult> {
+        let timer = Timer::start();
+        self.logger.service_entry(trace_id, "API", "BuildService", "warm_cache", &project.id);
+
+        let warm_command = warm_cache_command(&project.cache_type, &project.build_command)
+            .with_context(|| format!("Cache warming is not supported for cache type '{}'", project.cache_type))?;
+
+        let cache_path = PathBuf::from("/data/cache").join(&project.cache_type);
+        let scratch_path = PathBuf::from("/data/output").join(format!("warm-cache-{}", project.id));
+        fs::create_dir_all(&cache_path).await.context("Failed to create cache directory")?;
+        fs::create_dir_all(&scratch_path).await.context("Failed to create output directory")?;
+
+        let source = self.fetch_source(trace_id, project, &project.branch, &format!("warm-cache-{}", project.id)).await?;
+        // 네트워크 없는 프로젝트도 의존성은 받아야 하므로 예열만 격리 네트워크에서 실행
+        let network = match project.build_network {
+            BuildNetwork::None => BuildNetwork::Isolated,
+            network => network,
+        };
+        let container_options = BuildContainerOptions {
+            source_path: source.as_ref().map(|s| s.dir.clone()),
+            network,
+            docker_access: project.docker_access,
+            output_lines: None,
+            build_id: None,
+            secrets: self.secret_repo.resolve_env(project.build_env_vars.as_deref()).await?,
+        };
+        let command = format!("{} && {}", self.checkout_command(project, source.is_some()).await, warm_command);
+        info!("[{}] Warming {} cache for project {}: {}", trace_id, project.cache_type, project.name, warm_command);
+
+        self.logger.external_call(trace_id, "BuildService", "Docker", "run_build_container");
+        let docker_timer = Timer::start();
+        let result = self.docker.run_build_container(
+            &project.build_image,
+            &command,
+            scratch_path.clone(),
+            cache_path,
+            &project.cache_type,
+            &container_options,
+        ).await;
+        let _ = fs::remove_dir_all(&scratch_path).await;
+
+        let result = match result {
+            Ok(r) => {
+                self.logger.external_done(trace_id, "BuildService", "Docker", "run_build_container", docker_timer.elapsed_ms());
+                r
+            }
+            Err(e) => {
+                self.logger.external_error(trace_id, "BuildService", "Docker", "run_build_container", &e);
+                return Err(e);
+            }
+        };
+
+        let log_path = warm_cache_log_path(project.id);
+        if let Some(parent) = log_path.parent() {
+            fs::create_dir_all(parent).await.ok();
+        }
+        let mut content = result.logs.join("\n");
+        content.push_str(&format!("\n=== cache warming exited with code {} ===\n", result.exit_code));
+        if let Err(e) = fs::write(&log_path, content).await {
+            warn!("[{}] Failed to write cache warming log: {}", trace_id, e);
+        }
+
+        self.logger.service_exit(trace_id, "API", "BuildService", "warm_cache", timer.elapsed_ms());
+        Ok(result)
+    }
+
+    /// 테스트 명령을 shard 수만큼 컨테이너로 나눠 병렬 실행하고 결과/로그를 하나로 모음
+    ///
+    /// 로그는 shard 순서대로 빌드 로그에 이어 붙이며 `line_offset`부터 Log 이벤트를 발행한다.
+    /// shard가 /output에 남긴 JUnit 리포트는 테스트 케이스 결과로 함께 반환한다.
+    #[allow(clippy::too_many_arguments)]
+    async fn run_test_shards(
+        &self,
+        trace_id: &str,
+        project: &Project,
+        build: &Build,
+        config: &ProjectTestConfig,
+        checkout_command: &str,
+        container_options: &BuildContainerOptions,
+        cache_path: &Path,
+        log_file: &mut fs::File,
+        line_offset: usize,
+        log_levels: &mut LogLevelCounts,
+    ) -> (TestSummary, Vec<TestCaseResult>) {
+        let shard_count = config.shards;
+        info!(
+            "[{}] Running tests for build #{} across {} shard(s)",
+            trace_id, build.build_number, shard_count
+        );
+
+        self.logger.external_call(trace_id, "BuildService", "Docker", "run_test_shards");
+        let docker_timer = Timer::start();
+
+        let runs = (0..shard_count).map(|index| {
+            // shard마다 별도 /output (빌드 산출물과 섞이지 않도록)
+            let scratch_path = PathBuf::from("/data/output").join(format!("build{}-shard{}", build.id, index));
+            let shard_exports = format!(
+                "export SHARD_INDEX={} && export SHARD_NUMBER={} && export SHARD_COUNT={}",
+                index,
+                index + 1,
+                shard_count
+            );
+            async move {
+                let started = std::time::Instant::now();
+                let command = format!("{} && {} && {}", shard_exports, checkout_command, config.command);
+                let (first, mut results) = self.run_test_container(project, &command, container_options, &scratch_path, cache_path, 1).await;
+
+                // 실패한 테스트만 재실행 (설정된 경우, 실패 테스트를 리포트에서 찾은 경우에만)
+                let failed_tests: Vec<String> = results
+                    .iter()
+                    .filter(|r| r.status == TestCaseStatus::Failed)
+                    .map(|r| r.name.clone())
+                    .collect();
+                let first_failed = !first.as_ref().is_ok_and(|r| r.success);
+                let retry = match &config.retry_failed_command {
+                    Some(retry_command) if first_failed && !failed_tests.is_empty() => {
+                        let command = format!(
+                            "{} && export FAILED_TESTS={} && {} && {}",
+                            shard_exports,
+                            shell_quote(&failed_tests.join(" ")),
+                            checkout_command,
+                            retry_command
+                        );
+                        let (retry, retry_results) = self.run_test_container(project, &command, container_options, &scratch_path, cache_path, 2).await;
+                        results.extend(retry_results);
+                        Some(retry)
+                    }
+                    _ => None,
+                };
+
+                (index, first, retry, results, started.elapsed().as_millis() as u64)
+            }
+        });
+        let outcomes = futures::future::join_all(runs).await;
+
+        self.logger.external_done(trace_id, "BuildService", "Docker", "run_test_shards", docker_timer.elapsed_ms());
+
+        let classifier = self.log_levels.classifier();
+        let mut line_number = line_offset;
+        let mut shards = Vec::with_capacity(outcomes.len());
+        let mut test_results = Vec::new();
+        for (index, first, retry, results, duration_ms) in outcomes {
+            let retried = retry.is_some();
+            let mut attempts = vec![(format!("test shard {}/{}", index + 1, shard_count), first)];
+            if let Some(retry) = retry {
+                attempts.push((format!("test shard {}/{} (retry failed tests)", index + 1, shard_count), retry));
+            }
+
+            // 마지막 시도 결과가 shard 결과
+            let mut success = false;
+            let mut exit_code = -1;
+            for (label, result) in attempts {
+                let mut lines = match result {
+                    Ok(r) => {
+                        success = r.success;
+                        exit_code = r.exit_code;
+                        r.logs
+                    }
+                    Err(e) => {
+                        warn!("[{}] Test shard {} failed to run: {}", trace_id, index, e);
+                        success = false;
+                        exit_code = -1;
+                        vec![format!("ERROR: failed to run test shard: {}", e)]
+                    }
+                };
+                lines.insert(0, format!("=== {} ===", label));
+                lines.push(format!("=== {} exited with code {} ===", label, exit_code));
+
+                for line in lines {
+                    log_levels.add(classifier.classify(&line));
+                    if let Err(e) = log_file.write_all(format!("{}\n", line).as_bytes()).await {
+                        warn!("[{}] Failed to write test log: {}", trace_id, e);
+                    }
+                    self.event_bus.emit(Event::Log {
+                        build_id: build.id,
+                        line,
+                        line_number,
+                        timestamp: Event::now(),
+                    }).await;
+                    line_number += 1;
+                }
+            }
+
+            shards.push(TestShardResult { index, success, exit_code, duration_ms, retried });
+            test_results.extend(results);
+        }
+
+        if let Err(e) = log_file.flush().await {
+            warn!("[{}] Failed to flush log file: {}", trace_id, e);
+        }
+
+        let summary = TestSummary::from_shards(shards);
+        info!(
+            "[{}] Tests for build #{}: {}/{} shards passed, {} test results collected",
+            trace_id, build.build_number, summary.passed, summary.shard_count, test_results.len()
+        );
+        (summary, test_results)
+    }
+
+    /// 테스트 컨테이너 1회 실행 후 /output의 JUnit 리포트 수집 (scratch 디렉토리는 매번 비움)
+    async fn run_test_container(
+        &self,
+        project: &Project,
+        command: &str,
+        container_options: &BuildContainerOptions,
+        scratch_path: &Path,
+        cache_path: &Path,
+        attempt: i64,
+    ) -> (Result<BuildResult>, Vec<TestCaseResult>) {
+        let _ = fs::remove_dir_all(scratch_path).await;
+        if let Err(e) = fs::create_dir_all(scratch_path).await {
+            return (Err(anyhow::Error::new(e).context("Failed to create shard output directory")), Vec::new());
+        }
+
+        let result = self.docker.run_build_container(
+            &project.build_image,
+            command,
+            scratch_path.to_path_buf(),
+            cache_path.to_path_buf(),
+            &project.cache_type,
+            container_options,
+        ).await;
+
+        let report_dir = scratch_path.to_path_buf();
+        let results = tokio::task::spawn_blocking(move || collect_junit_reports(&report_dir, attempt))
+            .await
+            .unwrap_or_default();
+        let _ = fs::remove_dir_all(scratch_path).await;
+
+        (result, results)
+    }
+
     /// 빌드 산출물 검증 (강화된 버전)
     /// - 파일 존재 여부
     /// - 빌드 이후 수정된 파일 존재 여부 (stale artifact 방지)
@@ -533,3 +1240,438 @@ http {{
         Ok(())
     }
 }
+
+/// 빌드 환경 스냅샷 (run_build_container에 넘기는 값 기준)
+fn build_environment(
+    project: &Project,
+    image_digest: Option<String>,
+    command: &str,
+    output_path: &Path,
+    cache_path: &Path,
+    options: &BuildContainerOptions,
+) -> BuildEnvironment {
+    let mut mounts = vec![format!("{}:/output", output_path.display())];
+    if cache_path.exists() {
+        mounts.push(format!("{}:{}", cache_path.display(), cache_mount_path(&project.cache_type)));
+    }
+    if let Some(source_path) = &options.source_path {
+        mounts.push(format!("{}:/source:ro", source_path.display()));
+    }
+
+    BuildEnvironment {
+        image: project.build_image.clone(),
+        image_digest,
+        command: redact_build_command(command),
+        working_directory: match &project.working_directory {
+            Some(wd) => format!("/workspace/{}", wd),
+            None => "/workspace".to_string(),
+        },
+        cache_type: project.cache_type.clone(),
+        mounts,
+        network: options.network,
+        docker_access: options.docker_access,
+        source_fetch: project.source_fetch,
+    }
+}
+
+/// 빌드 명령 안에서 단계 시작을 알리는 출력 접두어 (`::easycicd-step::building`)
+const STEP_MARKER: &str = "::easycicd-step::";
+
+/// 단계 시작을 알리는 셸 명령
+fn step_marker(step: BuildStep) -> String {
+    format!("echo '{}{}'", STEP_MARKER, step)
+}
+
+/// 컨테이너 출력 라인이 단계 표시면 해당 단계
+fn parse_step_marker(line: &str) -> Option<BuildStep> {
+    line.trim().strip_prefix(STEP_MARKER).and_then(BuildStep::parse)
+}
+
+/// 파이프라인 단계 시작을 알리는 출력 접두어 (`::easycicd-stage::lint`)
+const STAGE_MARKER: &str = "::easycicd-stage::";
+
+/// 파이프라인 단계들을 순서대로 실행하는 셸 명령 (단계마다 시작 표시 출력, 실패하면 중단)
+fn pipeline_command(stages: &[PipelineStage]) -> String {
+    stages
+        .iter()
+        .map(|stage| {
+            format!(
+                "echo '{}{}' && {{ {}; }}",
+                STAGE_MARKER,
+                stage.name,
+                stage.command.trim().trim_end_matches(';')
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" && ")
+}
+
+/// 컨테이너 출력 라인이 파이프라인 단계 표시면 단계 이름
+fn parse_stage_marker(line: &str) -> Option<&str> {
+    line.trim().strip_prefix(STAGE_MARKER).filter(|name| !name.is_empty())
+}
+
+/// 빌드 명령에 들어 있는 파이프라인 단계 이름 (실행 순서). 기록된 명령으로 재빌드해도 같은 목록
+fn planned_stages(command: &str) -> Vec<String> {
+    let prefix = format!("echo '{}", STAGE_MARKER);
+    command
+        .split(prefix.as_str())
+        .skip(1)
+        .filter_map(|rest| rest.split_once('\'').map(|(name, _)| name.to_string()))
+        .collect()
+}
+
+/// 빌드 로그에서 단계별 구간 (이름, 시작 줄, 끝 줄). 다음 단계/빌드 단계 표시 직전까지
+fn stage_log_ranges(logs: &[String]) -> Vec<(String, usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut current: Option<(String, usize)> = None;
+    for (idx, line) in logs.iter().enumerate() {
+        let stage = parse_stage_marker(line);
+        if stage.is_some() || parse_step_marker(line).is_some() {
+            if let Some((name, start)) = current.take() {
+                ranges.push((name, start, idx - 1));
+            }
+        }
+        if let Some(name) = stage {
+            current = Some((name.to_string(), idx));
+        }
+    }
+    if let Some((name, start)) = current {
+        ranges.push((name, start, logs.len() - 1));
+    }
+    ranges
+}
+
+/// 파이프라인 단계 진행 기록 + 이벤트 발행. 새 단계가 시작되거나 빌드 단계가 바뀌면 진행 중이던 단계는 completed
+struct StageProgress<'a, EB: EventBus> {
+    event_bus: &'a EB,
+    build_id: i64,
+    project_id: i64,
+    planned: Vec<String>,
+    /// 시작한 단계 (started_at, 시작 시점, 끝났으면 소요 시간). 순서대로 실행되므로 index = 단계 순서
+    started: Vec<(String, std::time::Instant, Option<u64>)>,
+}
+
+impl<'a, EB: EventBus> StageProgress<'a, EB> {
+    fn new(event_bus: &'a EB, build_id: i64, project_id: i64, planned: Vec<String>) -> Self {
+        Self { event_bus, build_id, project_id, planned, started: Vec::new() }
+    }
+
+    /// 다음 순서의 단계만 시작 (사용자 명령이 같은 표시를 출력해도 무시)
+    async fn start(&mut self, name: &str) {
+        let index = self.started.len();
+        if self.planned.get(index).map(String::as_str) != Some(name) {
+            return;
+        }
+        self.end("completed").await;
+        self.event_bus.emit(Event::build_stage(self.build_id, self.project_id, name, index, "started", None)).await;
+        self.started.push((crate::infrastructure::timezone::db_now(), std::time::Instant::now(), None));
+    }
+
+    async fn end(&mut self, status: &str) {
+        let index = self.started.len();
+        if let Some((_, started_at, duration @ None)) = self.started.last_mut() {
+            let duration_ms = started_at.elapsed().as_millis() as u64;
+            *duration = Some(duration_ms);
+            let name = &self.planned[index - 1];
+            self.event_bus.emit(Event::build_stage(self.build_id, self.project_id, name, index - 1, status, Some(duration_ms))).await;
+        }
+    }
+
+    /// 진행 중인 단계를 끝내고 시작하지 못한 단계는 skipped로 기록
+    async fn finish(&mut self, success: bool, logs: &[String]) -> Vec<BuildStageResult> {
+        let failed_index = match self.started.last() {
+            Some((_, _, None)) if !success => Some(self.started.len() - 1),
+            _ => None,
+        };
+        self.end(if success { "completed" } else { "failed" }).await;
+        for index in self.started.len()..self.planned.len() {
+            let name = &self.planned[index];
+            self.event_bus.emit(Event::build_stage(self.build_id, self.project_id, name, index, "skipped", None)).await;
+        }
+
+        let ranges = stage_log_ranges(logs);
+        self.planned
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                let range = ranges.iter().find(|(n, _, _)| n == name);
+                match self.started.get(index) {
+                    Some((started_at, _, duration_ms)) => BuildStageResult {
+                        position: index as i64,
+                        name: name.clone(),
+                        status: if failed_index == Some(index) { BuildStageStatus::Failed } else { BuildStageStatus::Completed },
+                        started_at: Some(started_at.clone()),
+                        duration_ms: duration_ms.map(|ms| ms as i64),
+                        log_start_line: range.map(|(_, start, _)| *start as i64),
+                        log_end_line: range.map(|(_, _, end)| *end as i64),
+                    },
+                    None => BuildStageResult {
+                        position: index as i64,
+                        name: name.clone(),
+                        status: BuildStageStatus::Skipped,
+                        started_at: None,
+                        duration_ms: None,
+                        log_start_line: None,
+                        log_end_line: None,
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+/// 빌드 단계 진행 이벤트 발행. 새 단계를 시작하면 진행 중이던 단계는 completed로 끝남
+struct StepProgress<'a, EB: EventBus> {
+    event_bus: &'a EB,
+    build_id: i64,
+    project_id: i64,
+    current: Option<(BuildStep, std::time::Instant)>,
+}
+
+impl<'a, EB: EventBus> StepProgress<'a, EB> {
+    fn new(event_bus: &'a EB, build_id: i64, project_id: i64) -> Self {
+        Self { event_bus, build_id, project_id, current: None }
+    }
+
+    /// 단계 시작 (이미 진행 중인 단계면 무시)
+    async fn start(&mut self, step: BuildStep) {
+        if self.current.is_some_and(|(current, _)| current == step) {
+            return;
+        }
+        self.end("completed").await;
+        self.event_bus.emit(Event::build_step(self.build_id, self.project_id, step, "started", None)).await;
+        self.current = Some((step, std::time::Instant::now()));
+    }
+
+    /// 진행 중인 단계를 성공/실패로 끝냄
+    async fn finish(&mut self, success: bool) {
+        self.end(if success { "completed" } else { "failed" }).await;
+    }
+
+    async fn end(&mut self, status: &str) {
+        if let Some((step, started_at)) = self.current.take() {
+            let duration_ms = started_at.elapsed().as_millis() as u64;
+            self.event_bus.emit(Event::build_step(self.build_id, self.project_id, step, status, Some(duration_ms))).await;
+        }
+    }
+}
+
+/// 환경 스냅샷의 빌드 설정을 프로젝트에 덮어씀 (정확한 재빌드용, 빌드 명령은 exact_rebuild_command)
+fn apply_build_environment(project: &mut Project, environment: &BuildEnvironment) {
+    project.build_image = environment.image.clone();
+    project.cache_type = environment.cache_type.clone();
+    project.working_directory = environment
+        .working_directory
+        .strip_prefix("/workspace/")
+        .map(str::to_string);
+    project.build_network = environment.network;
+    project.docker_access = environment.docker_access;
+    project.source_fetch = environment.source_fetch;
+}
+
+/// 기록된 전체 명령을 (checkout, 빌드 단계)로 나누고 checkout을 원본 커밋에 고정
+///
+/// 가려진 GitHub 토큰은 현재 토큰으로 채운다. tarball 모드는 원본 커밋의 tarball을 받으므로 checkout을 그대로 쓴다.
+fn exact_rebuild_command(environment: &BuildEnvironment, commit: &str, github_token: Option<&str>) -> Result<(String, String)> {
+    const REDACTED_TOKEN: &str = "GIT_CLONE_TOKEN=***";
+
+    let marker = format!("cd {} && ", environment.working_directory);
+    let pos = environment.command.find(&marker)
+        .context("Recorded build command has no checkout step")?;
+    let (checkout, steps) = environment.command.split_at(pos + marker.len());
+    let mut checkout = checkout.trim_end_matches(" && ").to_string();
+
+    if checkout.contains(REDACTED_TOKEN) {
+        let token = github_token.context("Recorded build used a GitHub token, but none is configured now")?;
+        checkout = checkout.replace(REDACTED_TOKEN, &format!("GIT_CLONE_TOKEN={}", token));
+    }
+    if checkout.contains("git clone ") {
+        checkout = format!(
+            "{} && git fetch --depth 1 origin {} && git checkout --detach FETCH_HEAD",
+            checkout,
+            shell_quote(commit)
+        );
+    }
+
+    Ok((checkout, steps.to_string()))
+}
+
+/// 빌드 명령에서 GitHub 토큰 값을 가림 (`export GIT_CLONE_TOKEN=...`)
+fn redact_build_command(command: &str) -> String {
+    const TOKEN_PREFIX: &str = "GIT_CLONE_TOKEN=";
+    let mut redacted = String::with_capacity(command.len());
+    let mut rest = command;
+    while let Some(pos) = rest.find(TOKEN_PREFIX) {
+        let value_start = pos + TOKEN_PREFIX.len();
+        redacted.push_str(&rest[..value_start]);
+        let value_end = rest[value_start..].find(' ').map_or(rest.len(), |i| value_start + i);
+        if value_end > value_start {
+            redacted.push_str("***");
+        }
+        rest = &rest[value_end..];
+    }
+    redacted.push_str(rest);
+    redacted
+}
+
+/// git credential store에 등록할 호스트 (HTTPS 저장소 URL의 호스트, 그 외에는 github.com)
+fn credential_host(repo_url: &str) -> &str {
+    repo_url
+        .strip_prefix("https://")
+        .and_then(|rest| rest.split('/').next())
+        // https://user@bitbucket.org/... 형태의 clone URL
+        .map(|host| host.rsplit('@').next().unwrap_or(host))
+        .filter(|host| !host.is_empty())
+        .unwrap_or("github.com")
+}
+
+/// git credential store에 등록할 사용자 이름 (Bitbucket access token은 x-token-auth)
+fn credential_user(repo_url: &str) -> &'static str {
+    match parse_repo_url(repo_url).map(|r| r.provider) {
+        Some(GitProviderKind::Bitbucket) => "x-token-auth",
+        _ => "oauth2",
+    }
+}
+
+/// sh 단일 인용부호 quoting
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+/// 캐시 예열 로그 경로
+pub fn warm_cache_log_path(project_id: i64) -> PathBuf {
+    PathBuf::from("/data/easycicd/logs")
+        .join(project_id.to_string())
+        .join("warm-cache.log")
+}
+
+/// cache_type별 의존성 해석 명령. 지원하지 않는 cache_type이면 None
+pub fn warm_cache_command(cache_type: &str, build_command: &str) -> Option<String> {
+    let build_cmd = build_command.to_lowercase();
+    let command = match cache_type {
+        "npm" if build_cmd.contains("pnpm") => "pnpm install --frozen-lockfile || pnpm install",
+        "npm" if build_cmd.contains("yarn") => "yarn install --frozen-lockfile || yarn install",
+        "npm" => "npm ci || npm install",
+        "gradle" => {
+            "if [ -x ./gradlew ]; then ./gradlew dependencies --no-daemon; \
+             else gradle dependencies --no-daemon; fi"
+        }
+        "maven" => "mvn -B dependency:go-offline",
+        "pip" => "pip download -r requirements.txt -d /tmp/pip-download",
+        "cargo" => "cargo fetch",
+        _ => return None,
+    };
+    Some(command.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!(shell_quote("a b"), "'a b'");
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
+    }
+
+    #[test]
+    fn test_redact_build_command() {
+        assert_eq!(
+            redact_build_command("export CI=true && export GIT_CLONE_TOKEN=ghp_secret && git clone x"),
+            "export CI=true && export GIT_CLONE_TOKEN=*** && git clone x"
+        );
+        assert_eq!(redact_build_command("export GIT_CLONE_TOKEN=abc"), "export GIT_CLONE_TOKEN=***");
+        assert_eq!(redact_build_command("npm run build"), "npm run build");
+    }
+
+    #[test]
+    fn test_credential_host() {
+        assert_eq!(credential_host("https://github.com/o/r.git"), "github.com");
+        assert_eq!(credential_host("https://gitlab.example.com/g/sub/r.git"), "gitlab.example.com");
+        assert_eq!(credential_host("https://user@bitbucket.org/ws/r.git"), "bitbucket.org");
+        assert_eq!(credential_host("o/r"), "github.com");
+        assert_eq!(credential_user("https://bitbucket.org/ws/r.git"), "x-token-auth");
+        assert_eq!(credential_user("https://gitlab.com/g/r.git"), "oauth2");
+    }
+
+    #[test]
+    fn test_parse_step_marker() {
+        assert_eq!(parse_step_marker("::easycicd-step::building\n"), Some(BuildStep::Building));
+        assert_eq!(parse_step_marker("::easycicd-step::copying_artifacts"), Some(BuildStep::CopyingArtifacts));
+        assert_eq!(parse_step_marker("::easycicd-step::unknown"), None);
+        assert_eq!(parse_step_marker("npm run build"), None);
+        assert_eq!(step_marker(BuildStep::Cloning), "echo '::easycicd-step::cloning'");
+    }
+
+    #[test]
+    fn test_pipeline_stages() {
+        let stages = vec![
+            PipelineStage { name: "install".to_string(), command: "npm ci".to_string() },
+            PipelineStage { name: "lint".to_string(), command: "npm run lint;".to_string() },
+        ];
+        let command = pipeline_command(&stages);
+        assert_eq!(
+            command,
+            "echo '::easycicd-stage::install' && { npm ci; } && echo '::easycicd-stage::lint' && { npm run lint; }"
+        );
+        let full = format!("{} && {} && {}", step_marker(BuildStep::Building), command, step_marker(BuildStep::CopyingArtifacts));
+        assert_eq!(planned_stages(&full), vec!["install", "lint"]);
+        assert!(planned_stages("npm run build").is_empty());
+        assert_eq!(parse_stage_marker("::easycicd-stage::lint\n"), Some("lint"));
+        assert_eq!(parse_stage_marker("::easycicd-stage::"), None);
+
+        let logs: Vec<String> = [
+            "::easycicd-step::building",
+            "::easycicd-stage::install",
+            "added 10 packages",
+            "::easycicd-stage::lint",
+            "ok",
+            "::easycicd-step::copying_artifacts",
+        ]
+        .iter()
+        .map(|l| l.to_string())
+        .collect();
+        assert_eq!(
+            stage_log_ranges(&logs),
+            vec![("install".to_string(), 1, 2), ("lint".to_string(), 3, 4)]
+        );
+    }
+
+    #[test]
+    fn test_exact_rebuild_command() {
+        let mut environment = BuildEnvironment {
+            image: "node:20".to_string(),
+            image_digest: Some("node@sha256:abc".to_string()),
+            command: "export CI=true && export GIT_CLONE_TOKEN=*** && git clone --depth 1 --branch main https://github.com/o/r.git /workspace && cd /workspace/app && npm ci && npm run build".to_string(),
+            working_directory: "/workspace/app".to_string(),
+            cache_type: "npm".to_string(),
+            mounts: vec![],
+            network: BuildNetwork::Bridge,
+            docker_access: false,
+            source_fetch: SourceFetch::Git,
+        };
+
+        let (checkout, steps) = exact_rebuild_command(&environment, "deadbeef", Some("ghp_new")).unwrap();
+        assert_eq!(
+            checkout,
+            "export CI=true && export GIT_CLONE_TOKEN=ghp_new && git clone --depth 1 --branch main https://github.com/o/r.git /workspace && cd /workspace/app && git fetch --depth 1 origin 'deadbeef' && git checkout --detach FETCH_HEAD"
+        );
+        assert_eq!(steps, "npm ci && npm run build");
+        assert!(exact_rebuild_command(&environment, "deadbeef", None).is_err());
+
+        environment.command = "export CI=true && mkdir -p /workspace && tar -xzf /source/source.tar.gz --strip-components=1 -C /workspace && cd /workspace && make".to_string();
+        environment.working_directory = "/workspace".to_string();
+        let (checkout, steps) = exact_rebuild_command(&environment, "deadbeef", None).unwrap();
+        assert!(checkout.ends_with("-C /workspace && cd /workspace"));
+        assert_eq!(steps, "make");
+    }
+
+    #[test]
+    fn test_warm_cache_command() {
+        assert_eq!(warm_cache_command("npm", "npm run build").as_deref(), Some("npm ci || npm install"));
+        assert!(warm_cache_command("npm", "yarn build").unwrap().starts_with("yarn install"));
+        assert_eq!(warm_cache_command("cargo", "cargo build --release").as_deref(), Some("cargo fetch"));
+        assert!(warm_cache_command("none", "make").is_none());
+    }
+}
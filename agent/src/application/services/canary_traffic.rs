@@ -0,0 +1,258 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::application::services::traffic_shadow::sampled;
+
+/// 카나리 시작 시 새 슬롯으로 보내는 트래픽 비율 (%)
+pub const INITIAL_CANARY_WEIGHT: u8 = 10;
+/// 자동 승격 단계 (%). 100에 도달하면 새 슬롯으로 전환
+pub const CANARY_STEPS: [u8; 4] = [10, 25, 50, 100];
+/// 다음 단계로 올리기 전 현재 비율을 유지하는 시간 (초)
+pub const CANARY_STEP_INTERVAL_SECS: u64 = 600;
+/// 판단에 필요한 최소 카나리 요청 수 (트래픽이 적으면 수동으로 올림)
+pub const CANARY_MIN_REQUESTS: u64 = 50;
+/// 이 비율(%)을 넘고 운영 슬롯보다 높으면 카나리 중단
+pub const CANARY_MAX_ERROR_PERCENT: f64 = 5.0;
+
+/// 현재 비율에서 집계한 카나리/운영 슬롯 응답 (5xx와 연결 실패를 오류로 셈)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct CanaryMetrics {
+    pub weight: u8,
+    pub canary_requests: u64,
+    pub canary_errors: u64,
+    pub stable_requests: u64,
+    pub stable_errors: u64,
+    /// 현재 비율로 바뀐 뒤 지난 시간 (초)
+    pub secs_at_weight: u64,
+}
+
+impl CanaryMetrics {
+    pub fn canary_error_percent(&self) -> f64 {
+        error_percent(self.canary_errors, self.canary_requests)
+    }
+
+    pub fn stable_error_percent(&self) -> f64 {
+        error_percent(self.stable_errors, self.stable_requests)
+    }
+}
+
+fn error_percent(errors: u64, requests: u64) -> f64 {
+    if requests == 0 {
+        return 0.0;
+    }
+    errors as f64 * 100.0 / requests as f64
+}
+
+/// 헬스 지표에 따른 다음 동작
+#[derive(Debug, Clone, PartialEq)]
+pub enum CanaryVerdict {
+    /// 현재 비율 유지 (표본이 부족하거나 유지 시간이 덜 지남)
+    Continue,
+    /// 다음 단계 비율로 올림
+    Step(u8),
+    /// 새 슬롯으로 전환
+    Promote,
+    /// 카나리 중단 (이유)
+    Abort(String),
+}
+
+/// 카나리 빌드로 운영 트래픽 일부를 보내는 세션
+pub struct CanarySession {
+    pub build_id: i64,
+    /// 카나리 컨테이너 이름 (easycicd 네트워크 내부)
+    pub target: String,
+    pub port: i32,
+    weight: AtomicU8,
+    counter: AtomicU64,
+    stats: Mutex<CanaryStats>,
+}
+
+struct CanaryStats {
+    canary_requests: u64,
+    canary_errors: u64,
+    stable_requests: u64,
+    stable_errors: u64,
+    weight_changed_at: Instant,
+}
+
+impl CanaryStats {
+    fn new() -> Self {
+        Self { canary_requests: 0, canary_errors: 0, stable_requests: 0, stable_errors: 0, weight_changed_at: Instant::now() }
+    }
+}
+
+impl CanarySession {
+    pub fn weight(&self) -> u8 {
+        self.weight.load(Ordering::Relaxed)
+    }
+
+    /// 이번 요청을 카나리로 보낼지. 요청 순번 기준으로 weight 비율만큼 고르게 선택
+    pub fn should_route_to_canary(&self) -> bool {
+        sampled(self.counter.fetch_add(1, Ordering::Relaxed), self.weight())
+    }
+
+    /// 프록시가 받은 응답 기록 (`canary`면 카나리 슬롯이 응답)
+    pub fn record(&self, canary: bool, status: u16) {
+        let mut stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        let error = status >= 500;
+        if canary {
+            stats.canary_requests += 1;
+            stats.canary_errors += u64::from(error);
+        } else {
+            stats.stable_requests += 1;
+            stats.stable_errors += u64::from(error);
+        }
+    }
+
+    /// 비율 변경. 새 비율에서 다시 판단하도록 집계를 초기화
+    pub fn set_weight(&self, weight: u8) {
+        let mut stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        self.weight.store(weight.min(100), Ordering::Relaxed);
+        *stats = CanaryStats::new();
+    }
+
+    pub fn metrics(&self) -> CanaryMetrics {
+        let stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        CanaryMetrics {
+            weight: self.weight(),
+            canary_requests: stats.canary_requests,
+            canary_errors: stats.canary_errors,
+            stable_requests: stats.stable_requests,
+            stable_errors: stats.stable_errors,
+            secs_at_weight: stats.weight_changed_at.elapsed().as_secs(),
+        }
+    }
+}
+
+/// CanaryTraffic - 프로젝트별 카나리 트래픽 분할 세션
+///
+/// 배포(DeploymentService)가 카나리 컨테이너를 띄운 뒤 세션을 열고, 리버스 프록시가 세션의 비율만큼
+/// 요청을 카나리로 보내며 응답을 기록한다. 모니터 워커가 지표를 보고 단계 상향/승격/중단을 결정
+#[derive(Default)]
+pub struct CanaryTraffic {
+    sessions: Mutex<HashMap<i64, Arc<CanarySession>>>,
+}
+
+impl CanaryTraffic {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn start(&self, project_id: i64, build_id: i64, target: String, port: i32, weight: u8) -> Arc<CanarySession> {
+        let session = Arc::new(CanarySession {
+            build_id,
+            target,
+            port,
+            weight: AtomicU8::new(weight.min(100)),
+            counter: AtomicU64::new(0),
+            stats: Mutex::new(CanaryStats::new()),
+        });
+        self.sessions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(project_id, session.clone());
+        session
+    }
+
+    pub fn session(&self, project_id: i64) -> Option<Arc<CanarySession>> {
+        self.sessions.lock().unwrap_or_else(|e| e.into_inner()).get(&project_id).cloned()
+    }
+
+    pub fn finish(&self, project_id: i64) {
+        self.sessions.lock().unwrap_or_else(|e| e.into_inner()).remove(&project_id);
+    }
+}
+
+/// 현재 비율 다음의 자동 승격 단계
+pub fn next_step(weight: u8) -> u8 {
+    CANARY_STEPS.into_iter().find(|step| *step > weight).unwrap_or(100)
+}
+
+/// 지표로 다음 동작 결정
+///
+/// 카나리 오류율이 `CANARY_MAX_ERROR_PERCENT`를 넘고 운영 슬롯보다 높으면 중단,
+/// 표본이 충분한 채로 `CANARY_STEP_INTERVAL_SECS` 동안 건강하면 다음 단계 (100이면 승격)
+pub fn evaluate(metrics: &CanaryMetrics) -> CanaryVerdict {
+    if metrics.canary_requests < CANARY_MIN_REQUESTS {
+        return CanaryVerdict::Continue;
+    }
+
+    let canary = metrics.canary_error_percent();
+    let stable = metrics.stable_error_percent();
+    if canary > CANARY_MAX_ERROR_PERCENT && canary > stable {
+        return CanaryVerdict::Abort(format!(
+            "Canary error rate {:.1}% ({}/{}) exceeds {}% (stable slot {:.1}%)",
+            canary, metrics.canary_errors, metrics.canary_requests, CANARY_MAX_ERROR_PERCENT, stable
+        ));
+    }
+
+    if metrics.secs_at_weight < CANARY_STEP_INTERVAL_SECS {
+        return CanaryVerdict::Continue;
+    }
+    match next_step(metrics.weight) {
+        100 => CanaryVerdict::Promote,
+        next => CanaryVerdict::Step(next),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics(weight: u8, canary: (u64, u64), stable: (u64, u64), secs_at_weight: u64) -> CanaryMetrics {
+        CanaryMetrics {
+            weight,
+            canary_requests: canary.0,
+            canary_errors: canary.1,
+            stable_requests: stable.0,
+            stable_errors: stable.1,
+            secs_at_weight,
+        }
+    }
+
+    #[test]
+    fn test_next_step() {
+        assert_eq!(next_step(10), 25);
+        assert_eq!(next_step(30), 50);
+        assert_eq!(next_step(50), 100);
+        assert_eq!(next_step(100), 100);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        // 표본 부족, 유지 시간 부족
+        assert_eq!(evaluate(&metrics(10, (10, 5), (90, 0), 900)), CanaryVerdict::Continue);
+        assert_eq!(evaluate(&metrics(10, (100, 1), (900, 0), 60)), CanaryVerdict::Continue);
+        // 건강하면 단계 상향, 마지막 단계에서 승격
+        assert_eq!(evaluate(&metrics(10, (100, 1), (900, 0), 600)), CanaryVerdict::Step(25));
+        assert_eq!(evaluate(&metrics(50, (100, 0), (100, 0), 600)), CanaryVerdict::Promote);
+        // 오류율이 높아도 운영 슬롯이 더 나쁘면 카나리 탓이 아님
+        assert_eq!(evaluate(&metrics(25, (100, 10), (300, 60), 600)), CanaryVerdict::Step(50));
+        assert!(matches!(evaluate(&metrics(25, (100, 10), (300, 3), 60)), CanaryVerdict::Abort(_)));
+    }
+
+    #[test]
+    fn test_session_records_per_slot() {
+        let traffic = CanaryTraffic::new();
+        let session = traffic.start(1, 7, "project-1-green".to_string(), 8080, INITIAL_CANARY_WEIGHT);
+
+        let routed = (0..100).filter(|_| session.should_route_to_canary()).count();
+        assert_eq!(routed, 10);
+
+        session.record(true, 200);
+        session.record(true, 502);
+        session.record(false, 200);
+        let m = session.metrics();
+        assert_eq!((m.canary_requests, m.canary_errors, m.stable_requests), (2, 1, 1));
+
+        session.set_weight(25);
+        let m = traffic.session(1).unwrap().metrics();
+        assert_eq!((m.weight, m.canary_requests), (25, 0));
+
+        traffic.finish(1);
+        assert!(traffic.session(1).is_none());
+    }
+}
@@ -1,12 +1,13 @@
 use std::sync::Arc;
 use anyhow::{Result, Context};
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::application::ports::repositories::ContainerRepository;
-use crate::db::models::{Container, CreateContainer, ContainerStatus};
-use crate::docker::DockerClient;
+use crate::db::models::{Container, CreateContainer, ContainerHealth, ContainerStatus};
+use crate::docker::{group_network_name, DockerClient};
 use crate::infrastructure::logging::{BoundaryLogger, Timer};
 use crate::application::events::event_bus::EventBus;
+use crate::application::services::ImageProfiles;
 use crate::events::Event;
 
 pub struct ContainerService<CR, EB>
@@ -18,6 +19,7 @@ where
     docker: DockerClient,
     logger: Arc<BoundaryLogger>,
     event_bus: Arc<EB>,
+    image_profiles: Arc<ImageProfiles>,
 }
 
 impl<CR, EB> ContainerService<CR, EB>
@@ -30,12 +32,14 @@ where
         docker: DockerClient,
         logger: Arc<BoundaryLogger>,
         event_bus: Arc<EB>,
+        image_profiles: Arc<ImageProfiles>,
     ) -> Self {
         Self {
             container_repo,
             docker,
             logger,
             event_bus,
+            image_profiles,
         }
     }
 
@@ -108,6 +112,16 @@ where
 
         let container_port = container.container_port.unwrap_or(container.port);
         let persist_data = container.persist_data != 0;
+        let profile = self.image_profiles.find(&container.image);
+        // 그룹이 있으면 그룹 전용 네트워크, 없으면 기본 네트워크
+        let network = match container.network_group.as_deref() {
+            Some(group) => {
+                let name = group_network_name(group);
+                self.docker.ensure_dedicated_network(&name).await?;
+                name
+            }
+            None => self.docker.network().to_string(),
+        };
 
         // If we were pulling, now update to starting after pull completes
         let docker_container_id = match self.docker.run_standalone_container(
@@ -115,9 +129,14 @@ where
             &container.image,
             container.port,
             container_port,
+            &container.parsed_extra_ports(),
             container.env_vars.as_deref(),
             container.command.as_deref(),
+            &container.exec_form(),
+            container.restart(),
             persist_data,
+            profile.as_ref(),
+            &network,
         ).await {
             Ok(docker_id) => docker_id,
             Err(e) => {
@@ -139,6 +158,10 @@ where
         // Update DB
         self.container_repo.update_container_id(id, Some(docker_container_id.clone())).await?;
         self.container_repo.update_status(id, ContainerStatus::Running).await?;
+        if container.health_check.is_some() {
+            // 첫 헬스체크 결과가 나올 때까지 starting
+            self.container_repo.update_health_status(id, Some(ContainerHealth::Starting)).await?;
+        }
 
         // Return updated container
         let updated = self.container_repo.get(id).await?
@@ -195,6 +218,9 @@ where
         // Update DB
         self.container_repo.update_container_id(id, None).await?;
         self.container_repo.update_status(id, ContainerStatus::Stopped).await?;
+        if container.health_status.is_some() {
+            self.container_repo.update_health_status(id, None).await?;
+        }
 
         let updated = self.container_repo.get(id).await?
             .context("Container not found after update")?;
@@ -230,6 +256,16 @@ where
 
             // Delete from DB (also releases port)
             self.container_repo.delete(id).await?;
+
+            // 그룹의 마지막 컨테이너면 그룹 전용 네트워크도 정리
+            if let Some(group) = c.network_group.as_deref() {
+                let remaining = self.container_repo.list().await?;
+                if !remaining.iter().any(|r| r.network_group.as_deref() == Some(group)) {
+                    if let Err(e) = self.docker.remove_dedicated_network(&group_network_name(group)).await {
+                        warn!("[{}] Failed to remove network of container group '{}': {}", trace_id, group, e);
+                    }
+                }
+            }
         }
 
         self.logger.service_exit(trace_id, "API", "ContainerService", "delete_container", timer.elapsed_ms());
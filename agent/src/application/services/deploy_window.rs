@@ -0,0 +1,136 @@
+use chrono::{DateTime, Datelike, Duration, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+
+use crate::db::models::{DeployWindow, Project};
+use crate::infrastructure::timezone;
+
+/// 배포 창 시간 형식 ("HH:MM")
+const TIME_FORMAT: &str = "%H:%M";
+
+fn parse_time(value: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(value.trim(), TIME_FORMAT).ok()
+}
+
+fn parse_days(days: &[String]) -> Option<Vec<Weekday>> {
+    days.iter().map(|d| d.trim().parse::<Weekday>().ok()).collect()
+}
+
+/// 배포 창 유효성 검사 (요일 1개 이상, "HH:MM" 형식)
+pub fn validate_deploy_window(window: &DeployWindow) -> Result<(), String> {
+    match parse_days(&window.days) {
+        Some(days) if !days.is_empty() => {}
+        Some(_) => return Err("deploy_window.days must not be empty".to_string()),
+        None => return Err("deploy_window.days must be weekday names like \"mon\"".to_string()),
+    }
+    if parse_time(&window.start).is_none() || parse_time(&window.end).is_none() {
+        return Err("deploy_window.start and end must be HH:MM".to_string());
+    }
+    Ok(())
+}
+
+/// `now` 시점에 배포 창이 열려 있는지 (잘못된 설정은 항상 열림으로 간주)
+pub fn is_open(window: &DeployWindow, now: DateTime<Tz>) -> bool {
+    let (Some(days), Some(start), Some(end)) = (parse_days(&window.days), parse_time(&window.start), parse_time(&window.end)) else {
+        return true;
+    };
+    let today = now.weekday();
+    let time = now.time();
+
+    if start == end {
+        days.contains(&today)
+    } else if start < end {
+        days.contains(&today) && time >= start && time < end
+    } else {
+        // 자정을 넘는 창: 오늘 시작분 또는 어제 시작해서 이어지는 부분
+        (days.contains(&today) && time >= start) || (days.contains(&today.pred()) && time < end)
+    }
+}
+
+/// `now` 이후 배포 창이 처음 열리는 시각 (이미 열려 있으면 `now`, 요일이 없으면 None)
+pub fn next_open(window: &DeployWindow, now: DateTime<Tz>) -> Option<DateTime<Tz>> {
+    if is_open(window, now) {
+        return Some(now);
+    }
+    let days = parse_days(&window.days)?;
+    let start = parse_time(&window.start)?;
+    let end = parse_time(&window.end)?;
+    // 하루 종일 창은 자정에 열림
+    let open_at = if start == end { NaiveTime::MIN } else { start };
+
+    (0..=7)
+        .filter_map(|offset| now.date_naive().checked_add_signed(Duration::days(offset)))
+        .filter(|date| days.contains(&date.weekday()))
+        // DST로 없는 시각이면 그날은 건너뜀
+        .filter_map(|date| now.timezone().from_local_datetime(&date.and_time(open_at)).earliest())
+        .find(|candidate| *candidate > now)
+}
+
+/// 프로젝트가 지금 배포 가능한지 (표시 타임존 기준, 창이 없으면 항상 true)
+pub fn deploy_allowed_now(project: &Project) -> bool {
+    project
+        .parsed_deploy_window()
+        .is_none_or(|w| is_open(&w, Utc::now().with_timezone(&timezone::display_timezone())))
+}
+
+/// 다음 배포 창 시작 시각 (표시 타임존, RFC 3339). 창이 없으면 None
+pub fn next_deploy_window(project: &Project) -> Option<String> {
+    let window = project.parsed_deploy_window()?;
+    next_open(&window, Utc::now().with_timezone(&timezone::display_timezone())).map(|t| t.to_rfc3339())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(days: &[&str], start: &str, end: &str) -> DeployWindow {
+        DeployWindow {
+            days: days.iter().map(|d| d.to_string()).collect(),
+            start: start.to_string(),
+            end: end.to_string(),
+        }
+    }
+
+    fn at(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Tz> {
+        Tz::Asia__Seoul.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_validate_deploy_window() {
+        assert!(validate_deploy_window(&window(&["mon", "fri"], "09:00", "18:00")).is_ok());
+        assert!(validate_deploy_window(&window(&[], "09:00", "18:00")).is_err());
+        assert!(validate_deploy_window(&window(&["monday-ish"], "09:00", "18:00")).is_err());
+        assert!(validate_deploy_window(&window(&["mon"], "9am", "18:00")).is_err());
+    }
+
+    #[test]
+    fn test_business_hours_window() {
+        // 2026-10-12는 월요일
+        let w = window(&["mon", "tue", "wed", "thu", "fri"], "09:00", "18:00");
+        assert!(is_open(&w, at(2026, 10, 12, 9, 0)));
+        assert!(!is_open(&w, at(2026, 10, 12, 18, 0)));
+        assert!(!is_open(&w, at(2026, 10, 17, 12, 0)));
+
+        // 금요일 저녁 → 다음 월요일 09:00
+        assert_eq!(next_open(&w, at(2026, 10, 16, 19, 30)), Some(at(2026, 10, 19, 9, 0)));
+        // 월요일 이른 아침 → 같은 날 09:00
+        assert_eq!(next_open(&w, at(2026, 10, 12, 7, 0)), Some(at(2026, 10, 12, 9, 0)));
+    }
+
+    #[test]
+    fn test_overnight_window() {
+        let w = window(&["sat"], "22:00", "06:00");
+        assert!(is_open(&w, at(2026, 10, 17, 23, 0)));
+        assert!(is_open(&w, at(2026, 10, 18, 5, 59)));
+        assert!(!is_open(&w, at(2026, 10, 18, 6, 0)));
+        assert!(!is_open(&w, at(2026, 10, 17, 5, 0)));
+        assert_eq!(next_open(&w, at(2026, 10, 18, 12, 0)), Some(at(2026, 10, 24, 22, 0)));
+    }
+
+    #[test]
+    fn test_all_day_window() {
+        let w = window(&["sun"], "00:00", "00:00");
+        assert!(is_open(&w, at(2026, 10, 18, 15, 0)));
+        assert!(!is_open(&w, at(2026, 10, 17, 15, 0)));
+        assert_eq!(next_open(&w, at(2026, 10, 17, 15, 0)), Some(at(2026, 10, 18, 0, 0)));
+    }
+}
@@ -5,56 +5,79 @@ use tokio::fs;
 use tokio::io::AsyncWriteExt;
 use tracing::{info, warn};
 
-use crate::application::ports::repositories::{BuildRepository, ProjectRepository};
+use crate::application::ports::repositories::{BuildRepository, ContainerRepository, ProjectRepository};
 use crate::application::events::{EventBus, Event};
-use crate::db::models::{BuildStatus, Project, Build, Slot};
-use crate::docker::DockerClient;
+use crate::application::services::canary_traffic::{CanarySession, CanaryTraffic, INITIAL_CANARY_WEIGHT};
+use crate::application::services::artifact_integrity::{verify_artifacts, ArtifactSigner};
+use crate::application::services::port_preflight::ensure_host_port_free;
+use crate::application::services::service_discovery::{merge_runtime_env, service_discovery_env};
+use crate::application::services::traffic_shadow::ShadowTraffic;
+use crate::db::models::{BuildStatus, DeploymentStrategy, Project, Build, ShadowReport, Slot};
+use crate::docker::{project_network_name, DockerClient};
+use crate::infrastructure::database::SqliteSecretRepository;
 use crate::infrastructure::logging::{BoundaryLogger, Timer};
 
 /// DeploymentService - 배포 및 헬스체크를 담당하는 서비스
 ///
 /// 책임:
-/// - Blue-Green 배포 전략 실행
+/// - Blue-Green / 카나리 배포 전략 실행
 /// - 컨테이너 시작 및 헬스체크
 /// - 배포 로그 기록
 /// - 슬롯 전환 관리
 /// - 이벤트 발행
-pub struct DeploymentService<BR, PR, EB>
+pub struct DeploymentService<BR, PR, CR, EB>
 where
     BR: BuildRepository,
     PR: ProjectRepository,
+    CR: ContainerRepository,
     EB: EventBus,
 {
     build_repo: Arc<BR>,
     project_repo: Arc<PR>,
+    container_repo: Arc<CR>,
     event_bus: EB,
     docker: DockerClient,
     logger: Arc<BoundaryLogger>,
+    shadow_traffic: Arc<ShadowTraffic>,
+    canary_traffic: Arc<CanaryTraffic>,
+    secret_repo: Arc<SqliteSecretRepository>,
 }
 
-impl<BR, PR, EB> DeploymentService<BR, PR, EB>
+impl<BR, PR, CR, EB> DeploymentService<BR, PR, CR, EB>
 where
     BR: BuildRepository,
     PR: ProjectRepository,
+    CR: ContainerRepository,
     EB: EventBus,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         build_repo: Arc<BR>,
         project_repo: Arc<PR>,
+        container_repo: Arc<CR>,
         event_bus: EB,
         docker: DockerClient,
         logger: Arc<BoundaryLogger>,
+        shadow_traffic: Arc<ShadowTraffic>,
+        canary_traffic: Arc<CanaryTraffic>,
+        secret_repo: Arc<SqliteSecretRepository>,
     ) -> Self {
         Self {
             build_repo,
             project_repo,
+            container_repo,
             event_bus,
             docker,
             logger,
+            shadow_traffic,
+            canary_traffic,
+            secret_repo,
         }
     }
 
     /// Blue-Green 배포 실행
+    ///
+    /// 카나리 전략이고 운영 중인 컨테이너가 있으면 새 컨테이너만 띄우고 카나리를 시작한다 (전환은 `promote_canary`)
     pub async fn deploy(&self, trace_id: &str, project: &Project, build: &Build, output_path: PathBuf) -> Result<()> {
         let timer = Timer::start();
         self.logger.service_entry(trace_id, "API", "DeploymentService", "deploy", &build.id);
@@ -95,6 +118,13 @@ where
 
         write_log!(format!("Starting deployment for build #{}", build.build_number));
 
+        // 진행 중인 카나리는 새 빌드로 대체 (비활성 슬롯 컨테이너는 아래에서 교체됨)
+        if let Some(canary_build_id) = project.canary_build_id {
+            info!("[{}] Canary build {} superseded by build #{}", trace_id, canary_build_id, build.build_number);
+            write_log!(format!("Replacing in-progress canary (build id {})", canary_build_id));
+            self.clear_canary(trace_id, project).await?;
+        }
+
         // Build is already successful at this point, now starting deployment
         // Emit deployment status event
         self.logger.event_emit(trace_id, "DeploymentService", "Deployment::Deploying");
@@ -126,12 +156,9 @@ where
         write_log!(format!("Deploying to {} slot on port {}", target_slot, target_port));
 
         // Clean up target slot's old container if it exists
-        let old_container_id = match target_slot {
-            Slot::Blue => project.blue_container_id.as_ref(),
-            Slot::Green => project.green_container_id.as_ref(),
-        };
+        let old_container_id = self.slot_container(trace_id, project, target_slot).await;
 
-        if let Some(old_id) = old_container_id {
+        if let Some(ref old_id) = old_container_id {
             self.logger.external_call(trace_id, "DeploymentService", "Docker", "is_container_running");
             if self.docker.is_container_running(old_id).await {
                 info!("[{}] Stopping old {} container: {}", trace_id, target_slot, old_id);
@@ -159,8 +186,38 @@ where
             }
         }
 
+        // 태그를 digest로 고정해 기록 (롤백/재배포가 upstream 태그 변경과 무관하게 같은 이미지로 실행되도록)
+        self.logger.external_call(trace_id, "DeploymentService", "Docker", "resolve_image_digest");
+        let runtime_image = self
+            .docker
+            .resolve_image_digest(build.runtime_image(project))
+            .await
+            .context("Failed to resolve runtime image digest")?;
+        self.logger.repo_call(trace_id, "DeploymentService", "BuildRepo", "update_runtime_image_digest");
+        self.build_repo
+            .update_runtime_image_digest(build.id, &runtime_image)
+            .await?;
+
+        let runtime_env = self.runtime_env(trace_id, project).await?;
+        let (network, extra_networks) = self.runtime_networks(trace_id, project).await?;
+
+        // 내부 전용 프로젝트는 프록시 트래픽이 없으므로 카나리 대신 바로 전환
+        let canary = project.deployment_strategy == DeploymentStrategy::Canary
+            && !project.internal_only
+            && self.slot_container(trace_id, project, project.active_slot).await.is_some();
+
+        // 포트가 다른 프로세스에 잡혀 있으면 Docker 시작 실패 대신 원인과 대체 포트를 알려주고 중단
+        if project.expose_host_port {
+            self.logger.external_call(trace_id, "DeploymentService", "Host", "ensure_host_port_free");
+            if let Err(e) = ensure_host_port_free(self.project_repo.as_ref(), self.container_repo.as_ref(), project.id, target_slot, target_port).await {
+                warn!("[{}] {}", trace_id, e);
+                write_log!(format!("Port pre-check failed: {}", e));
+                return Err(e);
+            }
+        }
+
         // Start runtime container
-        write_log!(format!("Starting runtime container with image: {}", project.runtime_image));
+        write_log!(format!("Starting runtime container with image: {} ({})", build.runtime_image(project), runtime_image));
 
         self.logger.external_call(trace_id, "DeploymentService", "Docker", "run_runtime_container");
         let docker_timer = Timer::start();
@@ -168,14 +225,21 @@ where
         let container_id = self
             .docker
             .run_runtime_container(
-                &project.runtime_image,
+                &runtime_image,
                 &project.runtime_command,
-                output_path,
-                target_port,
+                &project.runtime_exec(),
+                project.runtime_restart(),
+                build.runtime_output(output_path),
+                project.expose_host_port.then_some(target_port),
                 project.runtime_port as u16,
                 project.id,
+                build.id,
                 &target_slot.to_string().to_lowercase(),
-                project.runtime_env_vars.as_deref(),
+                runtime_env.as_deref(),
+                &network,
+                // 카나리 동안 내부 alias 트래픽은 운영 컨테이너로만 (승격 시 부여)
+                if canary { Vec::new() } else { project.network_aliases() },
+                &extra_networks,
             )
             .await
             .context("Failed to start runtime container")?;
@@ -200,6 +264,38 @@ where
             }
         }
 
+        if canary {
+            self.start_canary(trace_id, project, build, target_slot).await?;
+            write_log!(format!(
+                "Canary started: {}% of traffic to {} slot (ramp up with POST /api/projects/{}/canary/weight)",
+                INITIAL_CANARY_WEIGHT, target_slot, project.id
+            ));
+            self.logger.service_exit(trace_id, "API", "DeploymentService", "deploy", timer.elapsed_ms());
+            return Ok(());
+        }
+
+        // 트래픽 섀도잉: 전환 전에 운영 요청 일부를 새 컨테이너로 복제해 응답 비교 (결과는 기록만, 전환은 계속)
+        if project.shadow_traffic_percent > 0 && !project.internal_only {
+            if self.slot_container(trace_id, project, project.active_slot).await.is_some() {
+                let duration_secs = i64::from(project.shadow_duration_secs.max(1));
+                write_log!(format!(
+                    "Shadowing {}% of production traffic to {} slot for {}s",
+                    project.shadow_traffic_percent, target_slot, duration_secs
+                ));
+                let report = self.shadow_traffic_window(project, target_slot, duration_secs).await;
+                write_log!(format!(
+                    "Shadow traffic: {} mirrored, {} status mismatches, {} errors, p95 {}ms (production {}ms)",
+                    report.mirrored, report.status_mismatches, report.shadow_errors, report.shadow_p95_ms, report.primary_p95_ms
+                ));
+                self.logger.repo_call(trace_id, "DeploymentService", "BuildRepo", "update_shadow_report");
+                self.build_repo
+                    .update_shadow_report(build.id, &serde_json::to_string(&report)?)
+                    .await?;
+            } else {
+                info!("[{}] No production container in {} slot, skipping traffic shadowing", trace_id, project.active_slot);
+            }
+        }
+
         // 빌드 성공 시 바로 배포 성공 처리 (헬스체크 없이)
         info!("[{}] Build succeeded, switching to {} slot", trace_id, target_slot);
         write_log!(format!("Build succeeded, switching to {} slot", target_slot));
@@ -244,33 +340,34 @@ where
 
         // Stop old container (the previous active slot, opposite of target_slot)
         let old_slot = project.active_slot;
-        let old_container_id = match old_slot {
-            Slot::Blue => project.blue_container_id.clone(),
-            Slot::Green => project.green_container_id.clone(),
-        };
+        let old_container_id = self.slot_container(trace_id, project, old_slot).await;
 
         if let Some(old_id) = old_container_id {
-            info!("[{}] Stopping old {} container: {}", trace_id, old_slot, old_id);
-            write_log!(format!("Stopping old {} container: {}", old_slot, old_id));
+            if self.keep_as_standby(trace_id, project, old_slot, &old_id).await {
+                write_log!(format!("Keeping old {} container {} running as warm standby", old_slot, old_id));
+            } else {
+                info!("[{}] Stopping old {} container: {}", trace_id, old_slot, old_id);
+                write_log!(format!("Stopping old {} container: {}", old_slot, old_id));
 
-            self.logger.external_call(trace_id, "DeploymentService", "Docker", "stop_container");
-            self.docker.stop_container(&old_id).await.ok();
+                self.logger.external_call(trace_id, "DeploymentService", "Docker", "stop_container");
+                self.docker.stop_container(&old_id).await.ok();
 
-            self.logger.external_call(trace_id, "DeploymentService", "Docker", "remove_container");
-            self.docker.remove_container(&old_id).await.ok();
+                self.logger.external_call(trace_id, "DeploymentService", "Docker", "remove_container");
+                self.docker.remove_container(&old_id).await.ok();
 
-            // Clear old container ID from database
-            self.logger.repo_call(trace_id, "DeploymentService", "ProjectRepo", &format!("update_{}_container", old_slot.to_string().to_lowercase()));
-            match old_slot {
-                Slot::Blue => {
-                    self.project_repo
-                        .update_blue_container(project.id, None)
-                        .await?;
-                }
-                Slot::Green => {
-                    self.project_repo
-                        .update_green_container(project.id, None)
-                        .await?;
+                // Clear old container ID from database
+                self.logger.repo_call(trace_id, "DeploymentService", "ProjectRepo", &format!("update_{}_container", old_slot.to_string().to_lowercase()));
+                match old_slot {
+                    Slot::Blue => {
+                        self.project_repo
+                            .update_blue_container(project.id, None)
+                            .await?;
+                    }
+                    Slot::Green => {
+                        self.project_repo
+                            .update_green_container(project.id, None)
+                            .await?;
+                    }
                 }
             }
         }
@@ -280,6 +377,69 @@ where
         Ok(())
     }
 
+    /// PR 프리뷰 컨테이너 실행 (`project-{id}-pr-{number}`). 컨테이너 ID 반환
+    ///
+    /// Blue/Green 슬롯과 활성 슬롯은 건드리지 않는다. 호스트 포트와 내부 alias 없이 프록시(`pr-{number}.{name}.{base_domain}`)로만 접근
+    pub async fn deploy_preview(&self, trace_id: &str, project: &Project, build: &Build, pr_number: i64, output_path: PathBuf) -> Result<String> {
+        let timer = Timer::start();
+        self.logger.service_entry(trace_id, "API", "DeploymentService", "deploy_preview", &build.id);
+
+        info!(
+            "[{}] Starting preview of PR #{} (build #{}) for project {}",
+            trace_id, pr_number, build.build_number, project.name
+        );
+
+        self.logger.external_call(trace_id, "DeploymentService", "Docker", "resolve_image_digest");
+        let runtime_image = self
+            .docker
+            .resolve_image_digest(build.runtime_image(project))
+            .await
+            .context("Failed to resolve runtime image digest")?;
+        self.build_repo
+            .update_runtime_image_digest(build.id, &runtime_image)
+            .await?;
+
+        let runtime_env = self.runtime_env(trace_id, project).await?;
+        let (network, extra_networks) = self.runtime_networks(trace_id, project).await?;
+
+        self.logger.external_call(trace_id, "DeploymentService", "Docker", "run_runtime_container");
+        let container_id = self
+            .docker
+            .run_runtime_container(
+                &runtime_image,
+                &project.runtime_command,
+                &project.runtime_exec(),
+                project.runtime_restart(),
+                build.runtime_output(output_path),
+                None,
+                project.runtime_port as u16,
+                project.id,
+                build.id,
+                &DockerClient::preview_slot(pr_number),
+                runtime_env.as_deref(),
+                &network,
+                Vec::new(),
+                &extra_networks,
+            )
+            .await
+            .context("Failed to start preview container")?;
+
+        info!("[{}] Preview container for PR #{} started: {}", trace_id, pr_number, container_id);
+        self.logger.service_exit(trace_id, "API", "DeploymentService", "deploy_preview", timer.elapsed_ms());
+        Ok(container_id)
+    }
+
+    /// PR 프리뷰 컨테이너 정지/제거 (없으면 무시)
+    pub async fn remove_preview(&self, trace_id: &str, project_id: i64, pr_number: i64) {
+        let container_name = DockerClient::preview_container_name(project_id, pr_number);
+        info!("[{}] Removing preview container {}", trace_id, container_name);
+
+        self.logger.external_call(trace_id, "DeploymentService", "Docker", "stop_container");
+        self.docker.stop_container(&container_name).await.ok();
+        self.logger.external_call(trace_id, "DeploymentService", "Docker", "remove_container");
+        self.docker.remove_container(&container_name).await.ok();
+    }
+
     /// 이전 빌드로 롤백
     pub async fn rollback(&self, trace_id: &str, project: &Project, target_build: &Build) -> Result<()> {
         let timer = Timer::start();
@@ -308,6 +468,509 @@ where
             trace_id, target_slot, output_path
         );
 
+        // 산출물이 빌드 직후 기록한 checksum/서명과 같은지 확인 (변조/손상된 산출물로 롤백 방지)
+        match target_build.parsed_artifact_checksums() {
+            Some(recorded) => {
+                let signer = match ArtifactSigner::from_env().await {
+                    Some(result) => Some(result.context("Failed to load artifact signing key")?),
+                    None => None,
+                };
+                if recorded.signature.is_some() && signer.is_none() {
+                    warn!("[{}] Build #{} artifacts are signed but ARTIFACT_SIGNING_KEY is not set, checking checksums only", trace_id, target_build.build_number);
+                }
+                verify_artifacts(&output_path_buf, &recorded, signer.as_ref())
+                    .await
+                    .context("Artifact verification failed, refusing to roll back")?;
+                info!("[{}] Build #{} artifacts verified (digest {})", trace_id, target_build.build_number, recorded.digest);
+            }
+            None => warn!("[{}] Build #{} has no recorded artifact checksums, skipping verification", trace_id, target_build.build_number),
+        }
+
+        // 웜 스탠바이가 대상 빌드를 실행 중이면 컨테이너를 새로 띄우지 않고 바로 전환
+        let deploy_slot = match self.standby(trace_id, project).await {
+            Some((container_id, build)) if build.id == target_build.id => {
+                info!("[{}] Build #{} is running as warm standby, switching instantly", trace_id, target_build.build_number);
+                self.activate_standby(trace_id, project, &container_id, &build).await
+                    .context("Failed to switch to warm standby")?
+            }
+            _ => self.switch_to_build(trace_id, project, target_build, output_path_buf, true).await
+                .context("Failed to start rollback container")?,
+        };
+
+        self.logger.event_emit(trace_id, "DeploymentService", "Rollback::Success");
+        self.event_bus.emit(Event::Deployment {
+            project_id: project.id,
+            project_name: project.name.clone(),
+            build_id: target_build.id,
+            status: "Rollback Success".to_string(),
+            slot: deploy_slot,
+            url: format!("https://app.yourdomain.com/{}/", project.name),
+            timestamp: Event::now(),
+        }).await;
+
+        info!("[{}] Rollback completed successfully", trace_id);
+        self.logger.service_exit(trace_id, "API", "DeploymentService", "rollback", timer.elapsed_ms());
+
+        Ok(())
+    }
+
+    /// 현재 활성 슬롯에서 서비스 중인 빌드
+    /// (활성 슬롯에 배포된 가장 최근 성공 빌드 중 산출물이 남아 있는 것)
+    pub async fn current_build(&self, project: &Project) -> Result<Option<Build>> {
+        let builds = self.build_repo.list_by_project(project.id, 100).await?;
+        Ok(builds.into_iter().find(|b| {
+            b.status == BuildStatus::Success
+                && b.get_deployed_slot() == Some(project.active_slot)
+                && b.output_path.as_ref().is_some_and(|p| PathBuf::from(p).exists())
+        }))
+    }
+
+    /// 현재 서비스 중인 빌드 바로 이전에 배포된 성공 빌드 (산출물이 남아 있는 것). 롤백 기본 대상
+    pub async fn previous_build(&self, project: &Project) -> Result<Option<Build>> {
+        let current = self.current_build(project).await?;
+        let builds = self.build_repo.list_by_project(project.id, 100).await?;
+        Ok(previous_deployment(&builds, current.map(|c| c.id), |b| {
+            b.output_path.as_ref().is_some_and(|p| PathBuf::from(p).exists())
+        }).cloned())
+    }
+
+    /// 현재 빌드를 비활성 슬롯에 다시 띄우고 전환 (포트 변경 후 새 포트로 옮길 때 사용, 무중단)
+    ///
+    /// `project`는 새 포트가 반영된 상태여야 한다. 서비스 중인 빌드가 없으면 Ok(None)
+    pub async fn redeploy_current(&self, trace_id: &str, project: &Project) -> Result<Option<Slot>> {
+        let timer = Timer::start();
+        self.logger.service_entry(trace_id, "API", "DeploymentService", "redeploy_current", &project.id);
+
+        let Some(build) = self.current_build(project).await? else {
+            info!("[{}] Project {} has no running build to redeploy", trace_id, project.name);
+            self.logger.service_exit(trace_id, "API", "DeploymentService", "redeploy_current", timer.elapsed_ms());
+            return Ok(None);
+        };
+        let output_path = PathBuf::from(build.output_path.clone().unwrap_or_default());
+
+        info!("[{}] Redeploying build #{} for project {}", trace_id, build.build_number, project.name);
+        // 이전 컨테이너는 바뀌기 전 포트로 떠 있으므로 스탠바이로 남기지 않음
+        let deploy_slot = self.switch_to_build(trace_id, project, &build, output_path, false).await
+            .context("Failed to start redeploy container")?;
+
+        // 다음 current_build 조회가 새 슬롯에서 이 빌드를 찾도록 갱신
+        self.build_repo.update_deployed_slot(build.id, Some(deploy_slot.to_string())).await?;
+
+        self.logger.event_emit(trace_id, "DeploymentService", "Redeploy::Success");
+        self.event_bus.emit(Event::Deployment {
+            project_id: project.id,
+            project_name: project.name.clone(),
+            build_id: build.id,
+            status: "Redeploy Success".to_string(),
+            slot: deploy_slot,
+            url: format!("https://app.yourdomain.com/{}/", project.name),
+            timestamp: Event::now(),
+        }).await;
+
+        self.logger.service_exit(trace_id, "API", "DeploymentService", "redeploy_current", timer.elapsed_ms());
+        Ok(Some(deploy_slot))
+    }
+
+    /// 비활성 슬롯의 웜 스탠바이로 즉시 전환 (컨테이너를 새로 띄우지 않음). 전환된 빌드 반환
+    ///
+    /// 비활성 슬롯 컨테이너가 실행 중이 아니거나 어떤 빌드인지 알 수 없으면 실패
+    pub async fn switch_slot(&self, trace_id: &str, project: &Project) -> Result<Build> {
+        let timer = Timer::start();
+        self.logger.service_entry(trace_id, "API", "DeploymentService", "switch_slot", &project.id);
+
+        let Some((container_id, build)) = self.standby(trace_id, project).await else {
+            anyhow::bail!("No warm standby is running in the {} slot", project.get_inactive_slot());
+        };
+
+        info!(
+            "[{}] Switching project {} to standby build #{} in {} slot",
+            trace_id, project.name, build.build_number, project.get_inactive_slot()
+        );
+        let slot = self.activate_standby(trace_id, project, &container_id, &build).await?;
+
+        self.logger.event_emit(trace_id, "DeploymentService", "SlotSwitch::Success");
+        self.event_bus.emit(Event::Deployment {
+            project_id: project.id,
+            project_name: project.name.clone(),
+            build_id: build.id,
+            status: "Slot Switched".to_string(),
+            slot,
+            url: format!("https://app.yourdomain.com/{}/", project.name),
+            timestamp: Event::now(),
+        }).await;
+
+        self.logger.service_exit(trace_id, "API", "DeploymentService", "switch_slot", timer.elapsed_ms());
+        Ok(build)
+    }
+
+    /// 비활성 슬롯에서 실행 중인 웜 스탠바이 (컨테이너 ID, 실행 중인 빌드)
+    pub async fn standby(&self, trace_id: &str, project: &Project) -> Option<(String, Build)> {
+        let slot = project.get_inactive_slot();
+        let container_id = self.slot_container(trace_id, project, slot).await?;
+        if !self.docker.is_container_running(&container_id).await {
+            return None;
+        }
+        let build_id = self.docker.container_build_id(&container_id).await?;
+        match self.build_repo.get(build_id).await {
+            Ok(build) => build.map(|b| (container_id, b)),
+            Err(e) => {
+                warn!("[{}] Failed to load standby build {}: {}", trace_id, build_id, e);
+                None
+            }
+        }
+    }
+
+    /// 웜 스탠바이 컨테이너를 활성 슬롯으로 전환. 이전 활성 컨테이너는 반대로 스탠바이가 된다
+    ///
+    /// alias는 프록시가 아직 보지 않는 컨테이너에서만 바꾼다 (스탠바이에 alias 부여 → 전환 → 이전 활성에서 제거)
+    async fn activate_standby(&self, trace_id: &str, project: &Project, container_id: &str, build: &Build) -> Result<Slot> {
+        let slot = project.get_inactive_slot();
+        self.clear_canary(trace_id, project).await?;
+
+        self.logger.external_call(trace_id, "DeploymentService", "Docker", "set_network_aliases");
+        self.docker.set_network_aliases(&self.docker.project_network(project.id, project.network_isolation), container_id, project.network_aliases()).await?;
+
+        self.logger.repo_call(trace_id, "DeploymentService", "ProjectRepo", "update_active_slot");
+        self.project_repo.update_active_slot(project.id, slot).await?;
+        self.build_repo.update_deployed_slot(build.id, Some(slot.to_string())).await?;
+
+        if let Some(old_id) = self.slot_container(trace_id, project, project.active_slot).await {
+            self.logger.external_call(trace_id, "DeploymentService", "Docker", "set_network_aliases");
+            if let Err(e) = self.docker.set_network_aliases(&self.docker.project_network(project.id, project.network_isolation), &old_id, Vec::new()).await {
+                warn!("[{}] Failed to remove network alias from {} container {}: {}", trace_id, project.active_slot, old_id, e);
+            }
+        }
+
+        tracing::info!(
+            target: "audit",
+            event = "deployment.slot_switched",
+            project_id = project.id,
+            build_id = build.id,
+            slot = %slot,
+        );
+        Ok(slot)
+    }
+
+    /// warm_standby 프로젝트면 전환으로 비활성이 된 컨테이너를 지우지 않고 alias만 떼어 스탠바이로 남김.
+    /// 스탠바이로 남겼으면 true (호출자가 정리하지 않음)
+    async fn keep_as_standby(&self, trace_id: &str, project: &Project, slot: Slot, container_id: &str) -> bool {
+        if !project.warm_standby || !self.docker.is_container_running(container_id).await {
+            return false;
+        }
+        // 공유 alias({name}.internal)로 들어오는 내부 트래픽이 이전 빌드로 가지 않도록
+        self.logger.external_call(trace_id, "DeploymentService", "Docker", "set_network_aliases");
+        match self.docker.set_network_aliases(&self.docker.project_network(project.id, project.network_isolation), container_id, Vec::new()).await {
+            Ok(()) => {
+                info!("[{}] Keeping {} container {} as warm standby", trace_id, slot, container_id);
+                true
+            }
+            Err(e) => {
+                warn!("[{}] Failed to detach alias from {} container {}, removing it instead: {}", trace_id, slot, container_id, e);
+                false
+            }
+        }
+    }
+
+    /// 비활성 슬롯에 띄운 `build`로 카나리 시작 (트래픽 INITIAL_CANARY_WEIGHT%). 빌드는 Success, 배포 슬롯은 승격 때 기록
+    async fn start_canary(&self, trace_id: &str, project: &Project, build: &Build, slot: Slot) -> Result<()> {
+        info!(
+            "[{}] Starting canary of build #{} in {} slot with {}% of traffic",
+            trace_id, build.build_number, slot, INITIAL_CANARY_WEIGHT
+        );
+
+        self.logger.repo_call(trace_id, "DeploymentService", "ProjectRepo", "update_canary");
+        self.project_repo
+            .update_canary(project.id, Some(build.id), i32::from(INITIAL_CANARY_WEIGHT))
+            .await?;
+        self.canary_traffic.start(
+            project.id,
+            build.id,
+            DockerClient::project_container_name(project.id, slot),
+            project.runtime_port,
+            INITIAL_CANARY_WEIGHT,
+        );
+
+        self.logger.repo_call(trace_id, "DeploymentService", "BuildRepo", "finish");
+        self.build_repo.finish(build.id, BuildStatus::Success).await?;
+
+        self.logger.event_emit(trace_id, "DeploymentService", "Deployment::Canary");
+        self.event_bus.emit(Event::Deployment {
+            project_id: project.id,
+            project_name: project.name.clone(),
+            build_id: build.id,
+            status: "Canary".to_string(),
+            slot,
+            url: format!("https://app.yourdomain.com/{}/", project.name),
+            timestamp: Event::now(),
+        }).await;
+        self.event_bus.emit(Event::BuildStatus {
+            build_id: build.id,
+            project_id: project.id,
+            status: BuildStatus::Success,
+            timestamp: Event::now(),
+        }).await;
+
+        tracing::info!(
+            target: "audit",
+            event = "deployment.canary_started",
+            trace_id = %trace_id,
+            project_id = project.id,
+            build_id = build.id,
+            weight = INITIAL_CANARY_WEIGHT,
+        );
+        Ok(())
+    }
+
+    /// 진행 중인 카나리의 트래픽 분할 세션. 재시작 등으로 세션이 없으면 DB에 기록된 비율로 다시 연다
+    pub fn canary_session(&self, project: &Project) -> Option<Arc<CanarySession>> {
+        let build_id = project.canary_build_id?;
+        if let Some(session) = self.canary_traffic.session(project.id).filter(|s| s.build_id == build_id) {
+            return Some(session);
+        }
+        Some(self.canary_traffic.start(
+            project.id,
+            build_id,
+            DockerClient::project_container_name(project.id, project.get_inactive_slot()),
+            project.runtime_port,
+            project.canary_weight.clamp(0, 100) as u8,
+        ))
+    }
+
+    /// 카나리 트래픽 비율 변경 (1~99). 0/100은 `abort_canary`/`promote_canary`로
+    pub async fn set_canary_weight(&self, trace_id: &str, project: &Project, weight: u8) -> Result<()> {
+        let session = self.canary_session(project).context("No canary deployment in progress")?;
+        let weight = weight.clamp(1, 99);
+
+        self.logger.repo_call(trace_id, "DeploymentService", "ProjectRepo", "update_canary");
+        self.project_repo.update_canary(project.id, Some(session.build_id), i32::from(weight)).await?;
+        session.set_weight(weight);
+
+        info!("[{}] Canary of project {} now receives {}% of traffic", trace_id, project.name, weight);
+        tracing::info!(
+            target: "audit",
+            event = "deployment.canary_weight",
+            trace_id = %trace_id,
+            project_id = project.id,
+            build_id = session.build_id,
+            weight = weight,
+        );
+        Ok(())
+    }
+
+    /// 카나리 승격: 카나리 컨테이너를 활성 슬롯으로 전환하고 이전 운영 컨테이너 정리 (warm_standby면 스탠바이로 남김)
+    pub async fn promote_canary(&self, trace_id: &str, project: &Project) -> Result<Build> {
+        let timer = Timer::start();
+        self.logger.service_entry(trace_id, "API", "DeploymentService", "promote_canary", &project.id);
+
+        let build_id = project.canary_build_id.context("No canary deployment in progress")?;
+        let build = self.build_repo.get(build_id).await?.context(format!("Canary build {} not found", build_id))?;
+        let container_id = self
+            .slot_container(trace_id, project, project.get_inactive_slot())
+            .await
+            .context("Canary container not found")?;
+
+        info!("[{}] Promoting canary build #{} of project {}", trace_id, build.build_number, project.name);
+        let slot = self.activate_standby(trace_id, project, &container_id, &build).await?;
+
+        let old_slot = project.active_slot;
+        if let Some(old_id) = self.slot_container(trace_id, project, old_slot).await {
+            if !self.keep_as_standby(trace_id, project, old_slot, &old_id).await {
+                info!("[{}] Stopping old {} container: {}", trace_id, old_slot, old_id);
+                self.docker.stop_container(&old_id).await.ok();
+                self.docker.remove_container(&old_id).await.ok();
+                self.clear_slot_container(project.id, old_slot).await?;
+            }
+        }
+
+        self.logger.event_emit(trace_id, "DeploymentService", "Deployment::Success");
+        self.event_bus.emit(Event::Deployment {
+            project_id: project.id,
+            project_name: project.name.clone(),
+            build_id: build.id,
+            status: "Success".to_string(),
+            slot,
+            url: format!("https://app.yourdomain.com/{}/", project.name),
+            timestamp: Event::now(),
+        }).await;
+
+        tracing::info!(
+            target: "audit",
+            event = "deployment.canary_promoted",
+            trace_id = %trace_id,
+            project_id = project.id,
+            build_id = build.id,
+            slot = %slot,
+        );
+        self.logger.service_exit(trace_id, "API", "DeploymentService", "promote_canary", timer.elapsed_ms());
+        Ok(build)
+    }
+
+    /// 카나리 중단: 트래픽을 운영 슬롯으로 되돌리고 카나리 컨테이너를 제거. 카나리 빌드는 Failed
+    pub async fn abort_canary(&self, trace_id: &str, project: &Project, reason: &str) -> Result<Build> {
+        let timer = Timer::start();
+        self.logger.service_entry(trace_id, "API", "DeploymentService", "abort_canary", &project.id);
+
+        let build_id = project.canary_build_id.context("No canary deployment in progress")?;
+        let build = self.build_repo.get(build_id).await?.context(format!("Canary build {} not found", build_id))?;
+
+        warn!("[{}] Aborting canary build #{} of project {}: {}", trace_id, build.build_number, project.name, reason);
+        // 컨테이너를 지우기 전에 프록시가 더 보내지 않도록 세션부터 종료
+        self.clear_canary(trace_id, project).await?;
+
+        let slot = project.get_inactive_slot();
+        if let Some(container_id) = self.slot_container(trace_id, project, slot).await {
+            self.logger.external_call(trace_id, "DeploymentService", "Docker", "stop_container");
+            self.docker.stop_container(&container_id).await.ok();
+            self.logger.external_call(trace_id, "DeploymentService", "Docker", "remove_container");
+            self.docker.remove_container(&container_id).await.ok();
+            self.clear_slot_container(project.id, slot).await?;
+        }
+
+        self.logger.repo_call(trace_id, "DeploymentService", "BuildRepo", "finish");
+        self.build_repo.finish(build.id, BuildStatus::Failed).await?;
+
+        self.logger.event_emit(trace_id, "DeploymentService", "Deployment::CanaryAborted");
+        self.event_bus.emit(Event::Deployment {
+            project_id: project.id,
+            project_name: project.name.clone(),
+            build_id: build.id,
+            status: "Canary Aborted".to_string(),
+            slot,
+            url: format!("https://app.yourdomain.com/{}/", project.name),
+            timestamp: Event::now(),
+        }).await;
+        self.event_bus.emit(Event::BuildStatus {
+            build_id: build.id,
+            project_id: project.id,
+            status: BuildStatus::Failed,
+            timestamp: Event::now(),
+        }).await;
+
+        tracing::info!(
+            target: "audit",
+            event = "deployment.canary_aborted",
+            trace_id = %trace_id,
+            project_id = project.id,
+            build_id = build.id,
+            reason = %reason,
+        );
+        self.logger.service_exit(trace_id, "API", "DeploymentService", "abort_canary", timer.elapsed_ms());
+        Ok(build)
+    }
+
+    /// 카나리 세션과 DB 기록 정리 (컨테이너는 건드리지 않음)
+    async fn clear_canary(&self, trace_id: &str, project: &Project) -> Result<()> {
+        self.canary_traffic.finish(project.id);
+        if project.canary_build_id.is_some() {
+            self.logger.repo_call(trace_id, "DeploymentService", "ProjectRepo", "update_canary");
+            self.project_repo.update_canary(project.id, None, 0).await?;
+        }
+        Ok(())
+    }
+
+    async fn clear_slot_container(&self, project_id: i64, slot: Slot) -> Result<()> {
+        match slot {
+            Slot::Blue => self.project_repo.update_blue_container(project_id, None).await,
+            Slot::Green => self.project_repo.update_green_container(project_id, None).await,
+        }
+    }
+
+    /// `slot`의 새 컨테이너로 `duration_secs` 동안 운영 요청을 복제하고 비교 결과 반환
+    async fn shadow_traffic_window(&self, project: &Project, slot: Slot, duration_secs: i64) -> ShadowReport {
+        let session = self.shadow_traffic.start(
+            project.id,
+            DockerClient::project_container_name(project.id, slot),
+            project.runtime_port,
+            project.shadow_traffic_percent.clamp(0, 100) as u8,
+        );
+        tokio::time::sleep(std::time::Duration::from_secs(duration_secs as u64)).await;
+        self.shadow_traffic.finish(project.id);
+        session.report(duration_secs)
+    }
+
+    /// 슬롯 컨테이너 ID (저장된 ID가 stale이면 이름으로 다시 찾아 DB 갱신).
+    /// Docker 조회에 실패하면 저장된 ID를 그대로 사용
+    async fn slot_container(&self, trace_id: &str, project: &Project, slot: Slot) -> Option<String> {
+        self.logger.external_call(trace_id, "DeploymentService", "Docker", "resolve_container");
+        match self.docker.resolve_container(self.project_repo.as_ref(), project.id, slot).await {
+            Ok(container_id) => container_id,
+            Err(e) => {
+                warn!("[{}] Failed to resolve {} container of project {}: {}", trace_id, slot, project.name, e);
+                match slot {
+                    Slot::Blue => project.blue_container_id.clone(),
+                    Slot::Green => project.green_container_id.clone(),
+                }
+            }
+        }
+    }
+
+    /// 런타임 환경 변수 JSON (사용자 정의 + 의존 서비스의 SERVICE_<NAME>_HOST/PORT, 비밀 참조는 값으로 치환)
+    ///
+    /// 없어진 의존 대상은 경고만 남기고 건너뛴다. 없는 비밀을 참조하면 에러
+    async fn runtime_env(&self, trace_id: &str, project: &Project) -> Result<Option<String>> {
+        let deps = project.parsed_dependencies();
+
+        let mut containers = Vec::new();
+        for name in &deps.containers {
+            self.logger.repo_call(trace_id, "DeploymentService", "ContainerRepo", "get_by_name");
+            match self.container_repo.get_by_name(name).await {
+                Ok(Some(container)) => containers.push(container),
+                Ok(None) => warn!("[{}] Dependency container '{}' of project {} not found", trace_id, name, project.name),
+                Err(e) => warn!("[{}] Failed to load dependency container '{}': {}", trace_id, name, e),
+            }
+        }
+        let mut projects = Vec::new();
+        for name in &deps.projects {
+            self.logger.repo_call(trace_id, "DeploymentService", "ProjectRepo", "get_by_name");
+            match self.project_repo.get_by_name(name).await {
+                Ok(Some(dependency)) => projects.push(dependency),
+                Ok(None) => warn!("[{}] Dependency project '{}' of project {} not found", trace_id, name, project.name),
+                Err(e) => warn!("[{}] Failed to load dependency project '{}': {}", trace_id, name, e),
+            }
+        }
+
+        let env = merge_runtime_env(project.runtime_env_vars.as_deref(), service_discovery_env(&containers, &projects));
+        self.logger.repo_call(trace_id, "DeploymentService", "SecretRepo", "resolve_env");
+        let secrets = self.secret_repo.resolve_env(env.as_deref()).await?;
+        Ok(secrets.apply(env))
+    }
+
+    /// 런타임 컨테이너의 기본 네트워크와 추가 네트워크
+    ///
+    /// 격리 프로젝트는 전용 네트워크를 만들고, 이 프로젝트를 peer로 둔 격리 프로젝트의 전용 네트워크에도 붙는다
+    async fn runtime_networks(&self, trace_id: &str, project: &Project) -> Result<(String, Vec<String>)> {
+        let network = self.docker.project_network(project.id, project.network_isolation);
+        if project.network_isolation {
+            self.docker.ensure_dedicated_network(&network).await?;
+        }
+
+        let mut extra = project.parsed_extra_networks();
+        self.logger.repo_call(trace_id, "DeploymentService", "ProjectRepo", "list");
+        for other in self.project_repo.list().await? {
+            if other.id == project.id || !other.network_isolation || !other.parsed_network_peers().contains(&project.id) {
+                continue;
+            }
+            let name = project_network_name(other.id);
+            self.docker.ensure_dedicated_network(&name).await?;
+            if !extra.contains(&name) {
+                extra.push(name);
+            }
+        }
+        Ok((network, extra))
+    }
+
+    /// 비활성 슬롯에 `build`의 산출물(`output_path`)로 컨테이너를 띄운 뒤 활성 슬롯을 전환하고 이전 컨테이너를 정리
+    /// (`allow_standby`이고 warm_standby 프로젝트면 이전 컨테이너를 스탠바이로 남김)
+    ///
+    /// 런타임 이미지는 빌드 배포 때 고정한 digest를 사용한다 (digest가 없는 이전 빌드는 현재 태그, Dockerfile 빌드는 빌드한 이미지)
+    async fn switch_to_build(
+        &self,
+        trace_id: &str,
+        project: &Project,
+        build: &Build,
+        output_path_buf: PathBuf,
+        allow_standby: bool,
+    ) -> Result<Slot> {
         // 현재 활성 슬롯이 아닌 슬롯에 배포
         let deploy_slot = match project.active_slot {
             Slot::Blue => Slot::Green,
@@ -319,20 +982,20 @@ where
             Slot::Green => project.green_port as u16,
         };
 
-        info!("[{}] Deploying rollback to {} slot on port {}", trace_id, deploy_slot, deploy_port);
+        info!("[{}] Deploying to {} slot on port {}", trace_id, deploy_slot, deploy_port);
+
+        // 비활성 슬롯의 카나리 컨테이너를 교체하므로 카나리 종료
+        self.clear_canary(trace_id, project).await?;
 
         // 기존 컨테이너 정리
-        let old_container_id = match deploy_slot {
-            Slot::Blue => project.blue_container_id.as_ref(),
-            Slot::Green => project.green_container_id.as_ref(),
-        };
+        let old_container_id = self.slot_container(trace_id, project, deploy_slot).await;
 
         if let Some(old_id) = old_container_id {
             self.logger.external_call(trace_id, "DeploymentService", "Docker", "stop_container");
-            self.docker.stop_container(old_id).await.ok();
+            self.docker.stop_container(&old_id).await.ok();
 
             self.logger.external_call(trace_id, "DeploymentService", "Docker", "remove_container");
-            self.docker.remove_container(old_id).await.ok();
+            self.docker.remove_container(&old_id).await.ok();
 
             match deploy_slot {
                 Slot::Blue => {
@@ -344,24 +1007,49 @@ where
             }
         }
 
-        // 이전 빌드의 컨테이너 시작
+        let runtime_image = match build.runtime_image_digest.as_deref() {
+            Some(image) => image,
+            None => {
+                warn!(
+                    "[{}] Build #{} has no pinned runtime image, using tag {}",
+                    trace_id, build.build_number, build.runtime_image(project)
+                );
+                build.runtime_image(project)
+            }
+        };
+
+        let runtime_env = self.runtime_env(trace_id, project).await?;
+        let (network, extra_networks) = self.runtime_networks(trace_id, project).await?;
+
+        if project.expose_host_port {
+            self.logger.external_call(trace_id, "DeploymentService", "Host", "ensure_host_port_free");
+            ensure_host_port_free(self.project_repo.as_ref(), self.container_repo.as_ref(), project.id, deploy_slot, deploy_port).await?;
+        }
+
+        // 빌드 산출물로 컨테이너 시작
+        info!("[{}] Starting {} container with image {}", trace_id, deploy_slot, runtime_image);
         self.logger.external_call(trace_id, "DeploymentService", "Docker", "run_runtime_container");
         let container_id = self
             .docker
             .run_runtime_container(
-                &project.runtime_image,
+                runtime_image,
                 &project.runtime_command,
-                output_path_buf,
-                deploy_port,
+                &project.runtime_exec(),
+                project.runtime_restart(),
+                build.runtime_output(output_path_buf),
+                project.expose_host_port.then_some(deploy_port),
                 project.runtime_port as u16,
                 project.id,
+                build.id,
                 &deploy_slot.to_string().to_lowercase(),
-                project.runtime_env_vars.as_deref(),
+                runtime_env.as_deref(),
+                &network,
+                project.network_aliases(),
+                &extra_networks,
             )
-            .await
-            .context("Failed to start rollback container")?;
+            .await?;
 
-        info!("[{}] Rollback container started: {}", trace_id, container_id);
+        info!("[{}] {} container started: {}", trace_id, deploy_slot, container_id);
 
         // 컨테이너 ID 업데이트
         match deploy_slot {
@@ -377,7 +1065,7 @@ where
             }
         }
 
-        info!("[{}] Rollback container running, switching to {} slot", trace_id, deploy_slot);
+        info!("[{}] Container running, switching to {} slot", trace_id, deploy_slot);
 
         // 슬롯 전환
         self.project_repo
@@ -386,40 +1074,65 @@ where
 
         // 이전 활성 슬롯 정리
         let old_slot = project.active_slot;
-        let old_active_container_id = match old_slot {
-            Slot::Blue => project.blue_container_id.clone(),
-            Slot::Green => project.green_container_id.clone(),
-        };
+        let old_active_container_id = self.slot_container(trace_id, project, old_slot).await;
 
         if let Some(old_id) = old_active_container_id {
-            info!("[{}] Stopping old {} container: {}", trace_id, old_slot, old_id);
-            self.docker.stop_container(&old_id).await.ok();
-            self.docker.remove_container(&old_id).await.ok();
+            if !(allow_standby && self.keep_as_standby(trace_id, project, old_slot, &old_id).await) {
+                info!("[{}] Stopping old {} container: {}", trace_id, old_slot, old_id);
+                self.docker.stop_container(&old_id).await.ok();
+                self.docker.remove_container(&old_id).await.ok();
 
-            match old_slot {
-                Slot::Blue => {
-                    self.project_repo.update_blue_container(project.id, None).await?;
-                }
-                Slot::Green => {
-                    self.project_repo.update_green_container(project.id, None).await?;
+                match old_slot {
+                    Slot::Blue => {
+                        self.project_repo.update_blue_container(project.id, None).await?;
+                    }
+                    Slot::Green => {
+                        self.project_repo.update_green_container(project.id, None).await?;
+                    }
                 }
             }
         }
 
-        self.logger.event_emit(trace_id, "DeploymentService", "Rollback::Success");
-        self.event_bus.emit(Event::Deployment {
-            project_id: project.id,
-            project_name: project.name.clone(),
-            build_id: target_build.id,
-            status: "Rollback Success".to_string(),
-            slot: deploy_slot,
-            url: format!("https://app.yourdomain.com/{}/", project.name),
-            timestamp: Event::now(),
-        }).await;
+        Ok(deploy_slot)
+    }
+}
 
-        info!("[{}] Rollback completed successfully", trace_id);
-        self.logger.service_exit(trace_id, "API", "DeploymentService", "rollback", timer.elapsed_ms());
+/// `builds`(최신순)에서 `current_id` 빌드보다 오래된 배포 이력 중 가장 최근의 성공 빌드.
+/// 서비스 중인 빌드가 없으면 가장 최근에 배포된 성공 빌드
+fn previous_deployment(builds: &[Build], current_id: Option<i64>, has_artifact: impl Fn(&Build) -> bool) -> Option<&Build> {
+    builds
+        .iter()
+        .skip_while(|b| current_id.is_some_and(|id| b.id != id))
+        .filter(|b| Some(b.id) != current_id)
+        .find(|b| b.status == BuildStatus::Success && b.get_deployed_slot().is_some() && has_artifact(b))
+}
 
-        Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(id: i64, status: BuildStatus, deployed_slot: Option<&str>) -> Build {
+        Build {
+            output_path: Some(format!("/data/output/build{}", id)),
+            deployed_slot: deployed_slot.map(str::to_string),
+            ..Build::test(id, status)
+        }
+    }
+
+    #[test]
+    fn test_previous_deployment() {
+        // 최신순: 5(실패), 4(현재), 3(dry-run 검증), 2(산출물 삭제됨), 1(배포됨)
+        let builds = vec![
+            build(5, BuildStatus::Failed, None),
+            build(4, BuildStatus::Success, Some("Blue")),
+            build(3, BuildStatus::Verified, None),
+            build(2, BuildStatus::Success, Some("Green")),
+            build(1, BuildStatus::Success, Some("Blue")),
+        ];
+        let has_artifact = |b: &Build| b.id != 2;
+
+        assert_eq!(previous_deployment(&builds, Some(4), has_artifact).map(|b| b.id), Some(1));
+        assert_eq!(previous_deployment(&builds, None, has_artifact).map(|b| b.id), Some(4));
+        assert_eq!(previous_deployment(&builds, Some(1), has_artifact).map(|b| b.id), None);
     }
 }
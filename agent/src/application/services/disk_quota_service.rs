@@ -0,0 +1,189 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::warn;
+
+use crate::application::events::{Event, EventBus};
+use crate::application::ports::repositories::{BuildRepository, SettingsRepository};
+use crate::db::models::Project;
+use crate::infrastructure::logging::{BoundaryLogger, Timer};
+
+/// 전역 기본 쿼터 설정 키 (MB)
+pub const DEFAULT_DISK_QUOTA_SETTING: &str = "default_disk_quota_mb";
+
+/// 프로젝트 디스크 사용량 (bytes)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DiskUsage {
+    pub workspace: u64,
+    pub outputs: u64,
+    pub logs: u64,
+    /// cache_type별로 여러 프로젝트가 공유하므로 쿼터 계산에는 포함하지 않음
+    pub cache: u64,
+    /// 쿼터 대상 합계 (workspace + outputs + logs)
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct QuotaStatus {
+    pub usage: DiskUsage,
+    pub quota_mb: Option<i64>,
+    pub exceeded: bool,
+}
+
+impl QuotaStatus {
+    pub fn error_message(&self) -> String {
+        format!(
+            "Disk quota exceeded: {} MB used of {} MB. Clean up old builds or raise the quota.",
+            self.usage.total / (1024 * 1024),
+            self.quota_mb.unwrap_or(0)
+        )
+    }
+}
+
+/// DiskQuotaService - 프로젝트별 디스크 사용량 집계 및 쿼터 검사
+///
+/// 책임:
+/// - workspace / build outputs / logs / cache 사용량 계산
+/// - 프로젝트 쿼터(없으면 전역 기본값) 초과 여부 판단
+/// - 초과 시 Error 이벤트 발행 (알림)
+pub struct DiskQuotaService<BR, SR, EB>
+where
+    BR: BuildRepository,
+    SR: SettingsRepository,
+    EB: EventBus,
+{
+    build_repo: Arc<BR>,
+    settings_repo: Arc<SR>,
+    event_bus: EB,
+    logger: Arc<BoundaryLogger>,
+}
+
+impl<BR, SR, EB> DiskQuotaService<BR, SR, EB>
+where
+    BR: BuildRepository,
+    SR: SettingsRepository,
+    EB: EventBus,
+{
+    pub fn new(build_repo: Arc<BR>, settings_repo: Arc<SR>, event_bus: EB, logger: Arc<BoundaryLogger>) -> Self {
+        Self {
+            build_repo,
+            settings_repo,
+            event_bus,
+            logger,
+        }
+    }
+
+    /// 프로젝트 디스크 사용량 계산
+    pub async fn usage(&self, project: &Project) -> Result<DiskUsage> {
+        let builds = self.build_repo.list_by_project(project.id, 10000).await?;
+
+        let workspace = PathBuf::from("/data/workspace").join(&project.name);
+        let logs = PathBuf::from("/data/easycicd/logs").join(project.id.to_string());
+        let cache = PathBuf::from("/data/cache").join(&project.cache_type);
+        let outputs: Vec<PathBuf> = builds
+            .iter()
+            .map(|b| PathBuf::from("/data/output").join(format!("build{}", b.id)))
+            .collect();
+
+        // 디렉토리 순회는 블로킹 작업
+        let usage = tokio::task::spawn_blocking(move || {
+            let workspace = dir_size(&workspace);
+            let logs = dir_size(&logs);
+            let cache = dir_size(&cache);
+            let outputs: u64 = outputs.iter().map(|p| dir_size(p)).sum();
+            DiskUsage {
+                workspace,
+                outputs,
+                logs,
+                cache,
+                total: workspace + outputs + logs,
+            }
+        })
+        .await?;
+
+        Ok(usage)
+    }
+
+    /// 적용되는 쿼터 (프로젝트 설정 → 전역 기본값 → 무제한)
+    pub async fn effective_quota_mb(&self, project: &Project) -> Result<Option<i64>> {
+        if let Some(quota) = project.disk_quota_mb {
+            return Ok(Some(quota));
+        }
+        let default = self.settings_repo.get(DEFAULT_DISK_QUOTA_SETTING).await?;
+        Ok(default.and_then(|v| v.parse::<i64>().ok()).filter(|q| *q > 0))
+    }
+
+    /// 쿼터 검사. 초과 시 Error 이벤트를 발행한다 (거부 여부는 호출자가 결정)
+    pub async fn check(&self, trace_id: &str, project: &Project) -> Result<QuotaStatus> {
+        let timer = Timer::start();
+        self.logger.service_entry(trace_id, "API", "DiskQuotaService", "check", &project.id);
+
+        let quota_mb = self.effective_quota_mb(project).await?;
+        let usage = self.usage(project).await?;
+        let exceeded = quota_mb.is_some_and(|q| usage.total > (q as u64) * 1024 * 1024);
+
+        let status = QuotaStatus { usage, quota_mb, exceeded };
+
+        if exceeded {
+            warn!("[{}] Project {} exceeded disk quota: {}", trace_id, project.name, status.error_message());
+            self.event_bus
+                .emit(Event::error(None, Some(project.id), status.error_message()))
+                .await;
+        }
+
+        self.logger.service_exit(trace_id, "API", "DiskQuotaService", "check", timer.elapsed_ms());
+        Ok(status)
+    }
+}
+
+/// 디렉토리 전체 크기 (심볼릭 링크는 따라가지 않음). 없으면 0
+fn dir_size(path: &Path) -> u64 {
+    let meta = match std::fs::symlink_metadata(path) {
+        Ok(m) => m,
+        Err(_) => return 0,
+    };
+    if meta.is_file() {
+        return meta.len();
+    }
+    if !meta.is_dir() {
+        return 0;
+    }
+
+    let mut total = 0;
+    let mut stack = vec![path.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(e) => e,
+            Err(_) => continue,
+        };
+        for entry in entries.flatten() {
+            let Ok(meta) = entry.metadata() else { continue };
+            if meta.is_dir() {
+                stack.push(entry.path());
+            } else if meta.is_file() {
+                total += meta.len();
+            }
+        }
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dir_size() {
+        let dir = std::env::temp_dir().join(format!("easycicd-dir-size-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        std::fs::write(dir.join("a.txt"), vec![0u8; 100]).unwrap();
+        std::fs::write(dir.join("nested/b.txt"), vec![0u8; 50]).unwrap();
+
+        assert_eq!(dir_size(&dir), 150);
+        assert_eq!(dir_size(&dir.join("a.txt")), 100);
+        assert_eq!(dir_size(&dir.join("missing")), 0);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
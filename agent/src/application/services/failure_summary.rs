@@ -0,0 +1,113 @@
+use regex::Regex;
+
+/// 요약 최대 길이 (문자). 알림 embed/커밋 상태 설명에 그대로 넣을 수 있도록 짧게
+pub const MAX_FAILURE_SUMMARY_CHARS: usize = 300;
+
+/// 원인 줄로 볼 알려진 패턴 (분류, 정규식). 앞에 있을수록 우선하고, 같은 패턴은 로그에서 처음 나온 줄을 쓴다
+/// (컴파일러는 첫 오류가 나머지 오류의 원인인 경우가 많음)
+const CAUSE_PATTERNS: &[(&str, &str)] = &[
+    ("Disk full", r"No space left on device"),
+    ("Out of memory", r"(?i)\bout of memory\b|OOMKilled|JavaScript heap out of memory"),
+    ("Command not found", r"command not found|executable file not found"),
+    ("Compile error", r"^error(\[E\d+\])?: .+"),
+    ("TypeScript error", r"\berror TS\d+: .+"),
+    ("Go compile error", r"^\S+\.go:\d+(:\d+)?: .+"),
+    ("Maven error", r"^\[ERROR\] .+"),
+    ("Gradle error", r"^FAILURE: .+"),
+    ("Module not found", r"(?i)\b(module|package) not found\b|Cannot find module|ModuleNotFoundError"),
+    ("npm error", r"^npm ERR! .+"),
+    ("Panic", r"panicked at"),
+    ("Exception", r"^(\w+\.)*\w+(Error|Exception): .+"),
+    ("Docker build error", r"^ERROR: .+"),
+];
+
+/// 테스트 러너 결과 요약 줄 (cargo, jest, pytest, go test)
+const TEST_PATTERNS: &[&str] = &[
+    r"^test result: FAILED\. .+",
+    r"^Tests:\s+\d+ failed.+",
+    r"^=+ .*\d+ failed.* =+$",
+    r"^--- FAIL: \S+",
+];
+
+/// 빌드 단계/파이프라인 단계 표시처럼 원인 후보가 아닌 줄
+fn is_noise(line: &str) -> bool {
+    line.is_empty() || line.starts_with("::easycicd-")
+}
+
+/// 실패한 빌드 로그에서 짧은 실패 요약 생성
+///
+/// `reason`(예: "Build failed with exit code: 1") 뒤에 알려진 원인 줄과 테스트 결과 줄을 붙인다.
+/// 알려진 패턴이 없으면 마지막 의미 있는 줄
+pub fn summarize_failure(reason: &str, logs: &[String]) -> String {
+    let lines: Vec<&str> = logs.iter().map(|l| l.trim()).filter(|l| !is_noise(l)).collect();
+    let compile = |pattern: &str| Regex::new(pattern).expect("failure summary patterns are valid");
+
+    let cause = CAUSE_PATTERNS.iter().find_map(|(kind, pattern)| {
+        let regex = compile(pattern);
+        lines.iter().find(|line| regex.is_match(line)).map(|line| format!("{}: {}", kind, line))
+    });
+    let tests: Vec<Regex> = TEST_PATTERNS.iter().map(|pattern| compile(pattern)).collect();
+    let tests = lines
+        .iter()
+        .rev()
+        .find(|line| tests.iter().any(|regex| regex.is_match(line)))
+        .map(|line| line.to_string());
+
+    let details: Vec<String> = match (cause, tests) {
+        (None, None) => lines.last().map(|line| line.to_string()).into_iter().collect(),
+        (cause, tests) => cause.into_iter().chain(tests).collect(),
+    };
+    let summary = if details.is_empty() {
+        reason.to_string()
+    } else {
+        format!("{} — {}", reason, details.join("; "))
+    };
+    truncate(&summary)
+}
+
+fn truncate(summary: &str) -> String {
+    if summary.chars().count() <= MAX_FAILURE_SUMMARY_CHARS {
+        return summary.to_string();
+    }
+    let mut truncated: String = summary.chars().take(MAX_FAILURE_SUMMARY_CHARS - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn logs(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn test_summarize_failure() {
+        let rust = logs(&[
+            "   Compiling app v0.1.0",
+            "error[E0308]: mismatched types",
+            " --> src/main.rs:3:5",
+            "error: could not compile `app` (bin \"app\") due to 1 previous error",
+        ]);
+        assert_eq!(
+            summarize_failure("Build failed with exit code: 101", &rust),
+            "Build failed with exit code: 101 — Compile error: error[E0308]: mismatched types"
+        );
+
+        let jest = logs(&["FAIL src/app.test.js", "Tests:       2 failed, 10 passed, 12 total", "npm ERR! Test failed."]);
+        assert_eq!(
+            summarize_failure("Tests failed", &jest),
+            "Tests failed — npm error: npm ERR! Test failed.; Tests:       2 failed, 10 passed, 12 total"
+        );
+
+        let oom = logs(&["<--- JS stacktrace --->", "FATAL ERROR: Reached heap limit Allocation failed - JavaScript heap out of memory"]);
+        assert!(summarize_failure("Build failed", &oom).contains("Out of memory"));
+
+        assert_eq!(summarize_failure("Build failed", &logs(&["step 1", "something odd", "::easycicd-stage::lint", ""])), "Build failed — something odd");
+        assert_eq!(summarize_failure("Build failed", &[]), "Build failed");
+
+        let long = logs(&[&format!("error: {}", "x".repeat(500))]);
+        assert_eq!(summarize_failure("Build failed", &long).chars().count(), MAX_FAILURE_SUMMARY_CHARS);
+    }
+}
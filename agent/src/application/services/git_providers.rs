@@ -0,0 +1,68 @@
+use anyhow::Result;
+
+use crate::application::ports::git_provider::{GitProvider, GitProviderKind};
+use crate::application::ports::repositories::{GitHubPatRepository, SettingsRepository};
+use crate::application::services::github_token::resolve_github_token;
+use crate::bitbucket::BitbucketClient;
+use crate::github::GitHubClient;
+use crate::gitlab::{GitLabClient, DEFAULT_GITLAB_URL};
+
+/// GitLab 전역 access token 설정 키
+pub const GITLAB_TOKEN_SETTING: &str = "gitlab_token";
+/// GitLab 인스턴스 주소 설정 키 (없으면 gitlab.com)
+pub const GITLAB_URL_SETTING: &str = "gitlab_url";
+/// Bitbucket Cloud access token 설정 키
+pub const BITBUCKET_TOKEN_SETTING: &str = "bitbucket_token";
+
+/// 설정된 GitLab 인스턴스 주소
+pub async fn gitlab_base_url<SR>(settings_repo: &SR) -> Result<String>
+where
+    SR: SettingsRepository + ?Sized,
+{
+    Ok(settings_repo
+        .get(GITLAB_URL_SETTING)
+        .await?
+        .unwrap_or_else(|| DEFAULT_GITLAB_URL.to_string()))
+}
+
+/// provider별 토큰 결정
+///
+/// GitHub은 프로젝트 PAT → 레거시 전역 PAT (`resolve_github_token`), GitLab/Bitbucket은 전역 token 설정.
+pub async fn resolve_provider_token<GPR, SR>(
+    github_pat_repo: &GPR,
+    settings_repo: &SR,
+    kind: GitProviderKind,
+    github_pat_id: Option<i64>,
+) -> Result<Option<String>>
+where
+    GPR: GitHubPatRepository + ?Sized,
+    SR: SettingsRepository + ?Sized,
+{
+    match kind {
+        GitProviderKind::GitHub => resolve_github_token(github_pat_repo, settings_repo, github_pat_id).await,
+        GitProviderKind::GitLab => settings_repo.get(GITLAB_TOKEN_SETTING).await,
+        GitProviderKind::Bitbucket => settings_repo.get(BITBUCKET_TOKEN_SETTING).await,
+    }
+}
+
+/// provider API 클라이언트 (토큰이 없으면 None)
+pub async fn git_provider_for<GPR, SR>(
+    github_pat_repo: &GPR,
+    settings_repo: &SR,
+    kind: GitProviderKind,
+    github_pat_id: Option<i64>,
+) -> Result<Option<Box<dyn GitProvider>>>
+where
+    GPR: GitHubPatRepository + ?Sized,
+    SR: SettingsRepository + ?Sized,
+{
+    let Some(token) = resolve_provider_token(github_pat_repo, settings_repo, kind, github_pat_id).await? else {
+        return Ok(None);
+    };
+
+    Ok(Some(match kind {
+        GitProviderKind::GitHub => Box::new(GitHubClient::new(token)),
+        GitProviderKind::GitLab => Box::new(GitLabClient::new(&gitlab_base_url(settings_repo).await?, token)),
+        GitProviderKind::Bitbucket => Box::new(BitbucketClient::new(token)),
+    }))
+}
@@ -0,0 +1,29 @@
+use anyhow::{anyhow, Result};
+
+use crate::application::ports::repositories::{GitHubPatRepository, SettingsRepository};
+
+/// 프로젝트별 PAT가 도입되기 전의 전역 PAT 설정 키
+pub const LEGACY_GITHUB_PAT_SETTING: &str = "github_pat";
+
+/// 프로젝트의 GitHub 토큰 결정
+///
+/// PAT가 지정된 프로젝트는 항상 그 PAT만 사용한다 (webhook 등록, clone, 상태 보고가 같은 계정으로 동작).
+/// 지정된 PAT가 없으면 레거시 전역 PAT, 그것도 없으면 None.
+pub async fn resolve_github_token<GPR, SR>(
+    github_pat_repo: &GPR,
+    settings_repo: &SR,
+    github_pat_id: Option<i64>,
+) -> Result<Option<String>>
+where
+    GPR: GitHubPatRepository + ?Sized,
+    SR: SettingsRepository + ?Sized,
+{
+    match github_pat_id {
+        Some(pat_id) => github_pat_repo
+            .get(pat_id)
+            .await?
+            .map(|pat| Some(pat.token))
+            .ok_or_else(|| anyhow!("GitHub PAT {} not found", pat_id)),
+        None => settings_repo.get(LEGACY_GITHUB_PAT_SETTING).await,
+    }
+}
@@ -0,0 +1,135 @@
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tracing::{info, warn};
+
+use crate::db::models::{Build, HookStage, Project};
+use crate::docker::DockerClient;
+use crate::infrastructure::logging::{BoundaryLogger, Timer};
+
+/// HookService - 프로젝트 lifecycle hook 실행을 담당하는 서비스
+///
+/// 책임:
+/// - pre-build / post-build / post-deploy-success / post-deploy-failure hook 실행
+/// - 빌드 컨텍스트 환경변수 주입
+/// - hook 출력을 빌드(또는 배포) 로그에 기록
+pub struct HookService {
+    docker: DockerClient,
+    logger: Arc<BoundaryLogger>,
+}
+
+impl HookService {
+    pub fn new(docker: DockerClient, logger: Arc<BoundaryLogger>) -> Self {
+        Self { docker, logger }
+    }
+
+    /// Hook 실행. 해당 단계에 hook이 없으면 Ok(true).
+    /// 반환값은 hook 성공 여부 (실패 처리 여부는 호출자가 결정)
+    pub async fn run(
+        &self,
+        trace_id: &str,
+        project: &Project,
+        build: &Build,
+        stage: HookStage,
+        build_status: &str,
+    ) -> Result<bool> {
+        let hooks = project.parsed_hooks();
+        let command = match hooks.command(stage) {
+            Some(cmd) => cmd.to_string(),
+            None => return Ok(true),
+        };
+
+        info!("[{}] Running {} hook for project {}", trace_id, stage, project.name);
+
+        let env = Self::hook_env(project, build, stage, build_status);
+
+        self.logger.external_call(trace_id, "HookService", "Docker", "run_hook_container");
+        let docker_timer = Timer::start();
+        let result = self.docker.run_hook_container(&project.build_image, &command, env).await;
+        let result = match result {
+            Ok(r) => {
+                self.logger.external_done(trace_id, "HookService", "Docker", "run_hook_container", docker_timer.elapsed_ms());
+                r
+            }
+            Err(e) => {
+                self.logger.external_error(trace_id, "HookService", "Docker", "run_hook_container", &e);
+                return Err(e);
+            }
+        };
+
+        // 배포 단계 hook은 배포 로그에, 나머지는 빌드 로그에 기록
+        let log_path = match stage {
+            HookStage::PostDeploySuccess | HookStage::PostDeployFailure => {
+                build.deploy_log_path.clone().unwrap_or_else(|| build.log_path.clone())
+            }
+            _ => build.log_path.clone(),
+        };
+        if let Err(e) = Self::append_log(&log_path, stage, &result.logs, result.exit_code).await {
+            warn!("[{}] Failed to write {} hook log: {}", trace_id, stage, e);
+        }
+
+        if !result.success {
+            warn!(
+                "[{}] {} hook for project {} failed with exit code {}",
+                trace_id, stage, project.name, result.exit_code
+            );
+        }
+
+        Ok(result.success)
+    }
+
+    fn hook_env(project: &Project, build: &Build, stage: HookStage, build_status: &str) -> Vec<String> {
+        let mut env = vec![
+            format!("EASYCICD_HOOK={}", stage),
+            format!("EASYCICD_PROJECT_ID={}", project.id),
+            format!("EASYCICD_PROJECT_NAME={}", project.name),
+            format!("EASYCICD_REPO={}", project.repo),
+            format!("EASYCICD_BRANCH={}", project.branch),
+            format!("EASYCICD_BUILD_ID={}", build.id),
+            format!("EASYCICD_BUILD_NUMBER={}", build.build_number),
+            format!("EASYCICD_COMMIT={}", build.commit_hash),
+            format!("EASYCICD_BUILD_STATUS={}", build_status),
+            format!("EASYCICD_ACTIVE_SLOT={}", project.active_slot),
+        ];
+
+        // 사용자 정의 빌드 환경변수도 전달 (API 토큰 등)
+        if let Some(build_env_json) = &project.build_env_vars {
+            if let Ok(parsed) = serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(build_env_json) {
+                for (key, value) in parsed {
+                    let val_str = match value {
+                        serde_json::Value::String(s) => s,
+                        other => other.to_string(),
+                    };
+                    env.push(format!("{}={}", key, val_str));
+                }
+            }
+        }
+
+        env
+    }
+
+    async fn append_log(log_path: &str, stage: HookStage, lines: &[String], exit_code: i64) -> Result<()> {
+        let path = PathBuf::from(log_path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await.context("Failed to create log directory")?;
+        }
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .await
+            .context("Failed to open log file")?;
+
+        file.write_all(format!("\n=== {} hook ===\n", stage).as_bytes()).await?;
+        for line in lines {
+            file.write_all(line.as_bytes()).await?;
+            file.write_all(b"\n").await?;
+        }
+        file.write_all(format!("=== {} hook exited with code {} ===\n", stage, exit_code).as_bytes()).await?;
+        file.flush().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+use anyhow::Result;
+use std::sync::RwLock;
+use tracing::warn;
+
+use crate::application::ports::repositories::SettingsRepository;
+use crate::db::models::ImageProfile;
+
+/// 이미지 프로필 카탈로그 설정 키 (JSON 배열, ImageProfile). 없으면 기본 카탈로그
+pub const IMAGE_PROFILES_SETTING: &str = "image_profiles";
+
+/// 카탈로그 최대 프로필 수
+pub const MAX_IMAGE_PROFILES: usize = 100;
+
+/// 기본 카탈로그: 자주 쓰는 데이터베이스/메시지 큐 이미지
+const DEFAULT_IMAGE_PROFILES: &str = r#"[
+    {
+        "name": "postgres",
+        "match_images": ["postgres", "timescale", "postgis"],
+        "data_env": {"PGDATA": "/data"},
+        "required_env": ["POSTGRES_PASSWORD|POSTGRES_HOST_AUTH_METHOD"],
+        "health_check": {"type": "command", "command": "pg_isready -U ${POSTGRES_USER:-postgres}", "interval_secs": 10, "retries": 5, "start_period_secs": 30}
+    },
+    {
+        "name": "mysql",
+        "match_images": ["mysql", "mariadb"],
+        "data_env": {"MYSQL_DATADIR": "/data"},
+        "required_env": ["MYSQL_ROOT_PASSWORD|MARIADB_ROOT_PASSWORD|MYSQL_ALLOW_EMPTY_PASSWORD|MARIADB_ALLOW_EMPTY_ROOT_PASSWORD|MYSQL_RANDOM_ROOT_PASSWORD|MARIADB_RANDOM_ROOT_PASSWORD"],
+        "health_check": {"type": "tcp", "interval_secs": 10, "retries": 5, "start_period_secs": 60}
+    },
+    {
+        "name": "mongodb",
+        "match_images": ["mongo"],
+        "data_env": {"MONGODB_DBPATH": "/data"},
+        "health_check": {"type": "tcp", "interval_secs": 10, "retries": 5, "start_period_secs": 30}
+    },
+    {
+        "name": "redis",
+        "match_images": ["redis", "valkey"],
+        "data_env": {"REDIS_DATA_DIR": "/data"},
+        "health_check": {"type": "tcp", "interval_secs": 10, "retries": 3}
+    },
+    {
+        "name": "elasticsearch",
+        "match_images": ["elasticsearch", "opensearch"],
+        "data_env": {"path.data": "/data"},
+        "health_check": {"type": "tcp", "interval_secs": 15, "retries": 5, "start_period_secs": 90}
+    },
+    {
+        "name": "cassandra",
+        "match_images": ["cassandra", "scylla"],
+        "data_env": {"CASSANDRA_DATA_DIR": "/data"}
+    },
+    {
+        "name": "couchdb",
+        "match_images": ["couchdb"],
+        "data_env": {"COUCHDB_DATA_DIR": "/data"}
+    },
+    {
+        "name": "influxdb",
+        "match_images": ["influxdb"],
+        "data_env": {"INFLUXDB_DATA_DIR": "/data", "INFLUXDB_META_DIR": "/data/meta", "INFLUXDB_WAL_DIR": "/data/wal"}
+    },
+    {
+        "name": "neo4j",
+        "match_images": ["neo4j"],
+        "data_env": {"NEO4J_DATA": "/data"}
+    },
+    {
+        "name": "rabbitmq",
+        "match_images": ["rabbitmq"],
+        "data_env": {"RABBITMQ_MNESIA_BASE": "/data"},
+        "health_check": {"type": "tcp", "interval_secs": 10, "retries": 5, "start_period_secs": 30}
+    }
+]"#;
+
+pub fn default_image_profiles() -> Vec<ImageProfile> {
+    serde_json::from_str(DEFAULT_IMAGE_PROFILES).expect("default image profiles are valid JSON")
+}
+
+/// 카탈로그 검증: 이름 중복, 빈 match_images, 절대 경로가 아닌 data_path, 잘못된 헬스체크
+pub fn validate_image_profiles(profiles: &[ImageProfile]) -> Result<(), String> {
+    if profiles.len() > MAX_IMAGE_PROFILES {
+        return Err(format!("At most {} image profiles are allowed", MAX_IMAGE_PROFILES));
+    }
+    let mut names = std::collections::HashSet::new();
+    for profile in profiles {
+        if profile.name.trim().is_empty() {
+            return Err("Profile name must not be empty".to_string());
+        }
+        if !names.insert(profile.name.as_str()) {
+            return Err(format!("Duplicate profile name '{}'", profile.name));
+        }
+        if profile.match_images.is_empty() || profile.match_images.iter().any(|m| m.trim().is_empty()) {
+            return Err(format!("Profile '{}': match_images must list non-empty image names", profile.name));
+        }
+        if !profile.data_path.starts_with('/') || profile.data_path == "/" {
+            return Err(format!("Profile '{}': data_path must be an absolute path other than /", profile.name));
+        }
+        if profile.data_env.keys().any(|k| k.is_empty() || k.contains('=')) {
+            return Err(format!("Profile '{}': invalid data_env name", profile.name));
+        }
+        if profile.required_env.iter().any(|r| r.split('|').any(|k| k.trim().is_empty())) {
+            return Err(format!("Profile '{}': invalid required_env entry", profile.name));
+        }
+        if let Some(Err(e)) = profile.health_check.as_ref().map(|h| h.validate()) {
+            return Err(format!("Profile '{}': invalid health_check: {}", profile.name, e));
+        }
+    }
+    Ok(())
+}
+
+/// 이미지에 맞는 첫 프로필
+pub fn find_image_profile<'a>(profiles: &'a [ImageProfile], image: &str) -> Option<&'a ImageProfile> {
+    let image = image.to_lowercase();
+    profiles
+        .iter()
+        .find(|p| p.match_images.iter().any(|m| image.contains(&m.to_lowercase())))
+}
+
+/// 환경변수(JSON 객체)에 없는 required_env 항목
+pub fn missing_required_env(profile: &ImageProfile, env_vars: Option<&serde_json::Value>) -> Vec<String> {
+    let present = |name: &str| {
+        env_vars
+            .and_then(|v| v.as_object())
+            .is_some_and(|obj| obj.contains_key(name.trim()))
+    };
+    profile
+        .required_env
+        .iter()
+        .filter(|required| !required.split('|').any(present))
+        .cloned()
+        .collect()
+}
+
+/// ImageProfiles - 독립 컨테이너 이미지 프로필 카탈로그
+///
+/// 시작 시 settings에서 읽고, API로 바꾸면 저장 후 교체한다
+pub struct ImageProfiles {
+    profiles: RwLock<Vec<ImageProfile>>,
+}
+
+impl Default for ImageProfiles {
+    fn default() -> Self {
+        Self { profiles: RwLock::new(default_image_profiles()) }
+    }
+}
+
+impl ImageProfiles {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// settings의 카탈로그 로드 (없거나 잘못된 값이면 기본 카탈로그)
+    pub async fn load(&self, settings_repo: &impl SettingsRepository) -> Result<()> {
+        let profiles = match settings_repo.get(IMAGE_PROFILES_SETTING).await? {
+            Some(json) => serde_json::from_str(&json).unwrap_or_else(|e| {
+                warn!("Invalid image_profiles setting, using defaults: {}", e);
+                default_image_profiles()
+            }),
+            None => default_image_profiles(),
+        };
+        self.set(profiles);
+        Ok(())
+    }
+
+    pub fn list(&self) -> Vec<ImageProfile> {
+        self.profiles.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    pub fn set(&self, profiles: Vec<ImageProfile>) {
+        *self.profiles.write().unwrap_or_else(|e| e.into_inner()) = profiles;
+    }
+
+    pub fn find(&self, image: &str) -> Option<ImageProfile> {
+        let profiles = self.profiles.read().unwrap_or_else(|e| e.into_inner());
+        find_image_profile(&profiles, image).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_image_profiles() {
+        let profiles = default_image_profiles();
+        assert!(validate_image_profiles(&profiles).is_ok());
+
+        assert_eq!(find_image_profile(&profiles, "timescale/timescaledb:latest-pg16").unwrap().name, "postgres");
+        assert_eq!(find_image_profile(&profiles, "MariaDB:11").unwrap().name, "mysql");
+        assert!(find_image_profile(&profiles, "nginx:alpine").is_none());
+
+        let postgres = find_image_profile(&profiles, "postgres:16").unwrap();
+        assert_eq!(postgres.data_env.get("PGDATA").map(String::as_str), Some("/data"));
+        assert_eq!(missing_required_env(postgres, None), vec!["POSTGRES_PASSWORD|POSTGRES_HOST_AUTH_METHOD"]);
+        let env = serde_json::json!({"POSTGRES_HOST_AUTH_METHOD": "trust"});
+        assert!(missing_required_env(postgres, Some(&env)).is_empty());
+    }
+
+    #[test]
+    fn test_validate_image_profiles() {
+        let mut profiles = default_image_profiles();
+        profiles[1].name = profiles[0].name.clone();
+        assert!(validate_image_profiles(&profiles).is_err());
+
+        let mut profiles = default_image_profiles();
+        profiles[0].data_path = "data".to_string();
+        assert!(validate_image_profiles(&profiles).is_err());
+
+        let mut profiles = default_image_profiles();
+        profiles[0].match_images.clear();
+        assert!(validate_image_profiles(&profiles).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use anyhow::Result;
+use regex::RegexSet;
+use std::sync::{Arc, RwLock};
+use tracing::warn;
+
+use crate::application::ports::repositories::SettingsRepository;
+use crate::db::models::{LogLevel, LogLevelPatterns};
+
+/// 빌드 로그 심각도 패턴 설정 키 (JSON 객체, LogLevelPatterns). 없으면 기본 패턴
+pub const LOG_LEVEL_PATTERNS_SETTING: &str = "log_level_patterns";
+
+/// 심각도별 최대 패턴 수
+pub const MAX_LOG_LEVEL_PATTERNS: usize = 50;
+
+/// 기본 패턴: 컴파일러/패키지 매니저/테스트 러너에서 흔한 오류·경고 표시
+pub fn default_log_level_patterns() -> LogLevelPatterns {
+    let patterns = |list: &[&str]| list.iter().map(|p| p.to_string()).collect();
+    LogLevelPatterns {
+        error: patterns(&[
+            r"(?i)\b(error|fatal)\b",
+            r"(?i)\bfailed\b",
+            r"(?i)\bexception\b",
+            r"npm ERR!",
+            r"panicked at",
+            r"^\[(WATCHDOG|SECRETS|SOURCE)\]",
+        ]),
+        warn: patterns(&[
+            r"(?i)\bwarn(ing)?\b",
+            r"(?i)\bdeprecated\b",
+        ]),
+    }
+}
+
+/// 패턴 검증: 개수 제한과 정규식 문법
+pub fn validate_log_level_patterns(patterns: &LogLevelPatterns) -> Result<(), String> {
+    for (level, list) in [("error", &patterns.error), ("warn", &patterns.warn)] {
+        if list.len() > MAX_LOG_LEVEL_PATTERNS {
+            return Err(format!("At most {} {} patterns are allowed", MAX_LOG_LEVEL_PATTERNS, level));
+        }
+        if let Err(e) = RegexSet::new(list) {
+            return Err(format!("Invalid {} pattern: {}", level, e));
+        }
+    }
+    Ok(())
+}
+
+/// 컴파일된 패턴으로 로그 줄 분류
+#[derive(Debug)]
+pub struct LogClassifier {
+    error: RegexSet,
+    warn: RegexSet,
+}
+
+impl LogClassifier {
+    pub fn new(patterns: &LogLevelPatterns) -> Result<Self, regex::Error> {
+        Ok(Self {
+            error: RegexSet::new(&patterns.error)?,
+            warn: RegexSet::new(&patterns.warn)?,
+        })
+    }
+
+    pub fn classify(&self, line: &str) -> LogLevel {
+        if self.error.is_match(line) {
+            LogLevel::Error
+        } else if self.warn.is_match(line) {
+            LogLevel::Warn
+        } else {
+            LogLevel::Info
+        }
+    }
+}
+
+impl Default for LogClassifier {
+    fn default() -> Self {
+        Self::new(&default_log_level_patterns()).expect("default log level patterns are valid")
+    }
+}
+
+/// LogLevels - 빌드 로그 심각도 분류 패턴
+///
+/// 시작 시 settings에서 읽고, API로 바꾸면 저장 후 교체한다
+pub struct LogLevels {
+    patterns: RwLock<(LogLevelPatterns, Arc<LogClassifier>)>,
+}
+
+impl Default for LogLevels {
+    fn default() -> Self {
+        Self { patterns: RwLock::new((default_log_level_patterns(), Arc::new(LogClassifier::default()))) }
+    }
+}
+
+impl LogLevels {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// settings의 패턴 로드 (없거나 잘못된 값이면 기본 패턴)
+    pub async fn load(&self, settings_repo: &impl SettingsRepository) -> Result<()> {
+        let patterns = match settings_repo.get(LOG_LEVEL_PATTERNS_SETTING).await? {
+            Some(json) => serde_json::from_str(&json)
+                .map_err(|e| e.to_string())
+                .and_then(|p: LogLevelPatterns| validate_log_level_patterns(&p).map(|_| p))
+                .unwrap_or_else(|e| {
+                    warn!("Invalid log_level_patterns setting, using defaults: {}", e);
+                    default_log_level_patterns()
+                }),
+            None => default_log_level_patterns(),
+        };
+        self.set(patterns)?;
+        Ok(())
+    }
+
+    pub fn patterns(&self) -> LogLevelPatterns {
+        self.patterns.read().unwrap_or_else(|e| e.into_inner()).0.clone()
+    }
+
+    pub fn classifier(&self) -> Arc<LogClassifier> {
+        self.patterns.read().unwrap_or_else(|e| e.into_inner()).1.clone()
+    }
+
+    pub fn set(&self, patterns: LogLevelPatterns) -> Result<()> {
+        let classifier = Arc::new(LogClassifier::new(&patterns)?);
+        *self.patterns.write().unwrap_or_else(|e| e.into_inner()) = (patterns, classifier);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::models::LogLevelCounts;
+
+    #[test]
+    fn test_default_classifier() {
+        let classifier = LogClassifier::default();
+        assert_eq!(classifier.classify("src/main.rs:3: error: expected `;`"), LogLevel::Error);
+        assert_eq!(classifier.classify("npm ERR! code ELIFECYCLE"), LogLevel::Error);
+        assert_eq!(classifier.classify("npm WARN deprecated request@2.88.2"), LogLevel::Warn);
+        assert_eq!(classifier.classify("Compiling app v0.1.0"), LogLevel::Info);
+        assert_eq!(classifier.classify("errorHandler.js"), LogLevel::Info);
+
+        let mut counts = LogLevelCounts::default();
+        for line in ["ok", "warning: unused", "FATAL: boom", "Build FAILED"] {
+            counts.add(classifier.classify(line));
+        }
+        assert_eq!(counts, LogLevelCounts { error: 2, warn: 1, info: 1 });
+    }
+
+    #[test]
+    fn test_validate_log_level_patterns() {
+        assert!(validate_log_level_patterns(&default_log_level_patterns()).is_ok());
+        let invalid = LogLevelPatterns { error: vec!["(unclosed".to_string()], warn: vec![] };
+        assert!(validate_log_level_patterns(&invalid).is_err());
+    }
+}
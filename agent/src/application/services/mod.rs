@@ -1,9 +1,38 @@
+pub mod artifact_integrity;
+pub mod build_admission;
+pub mod build_estimate;
 pub mod build_service;
+pub mod canary_traffic;
 pub mod container_service;
 pub mod deployment_service;
+pub mod deploy_window;
+pub mod disk_quota_service;
+pub mod failure_summary;
+pub mod git_providers;
+pub mod github_token;
+pub mod hook_service;
+pub mod image_profiles;
+pub mod log_levels;
+pub mod port_preflight;
 pub mod project_service;
+pub mod service_discovery;
+pub mod test_results;
+pub mod traffic_shadow;
 
+pub use build_admission::{check_build_allowed, BuildRejection};
+pub use build_estimate::{estimate_build, BuildEstimate};
 pub use build_service::BuildService;
+pub use canary_traffic::CanaryTraffic;
 pub use container_service::ContainerService;
 pub use deployment_service::DeploymentService;
+pub use deploy_window::{deploy_allowed_now, next_deploy_window, validate_deploy_window};
+pub use disk_quota_service::{DiskQuotaService, DEFAULT_DISK_QUOTA_SETTING};
+pub use git_providers::{git_provider_for, gitlab_base_url, BITBUCKET_TOKEN_SETTING, GITLAB_TOKEN_SETTING, GITLAB_URL_SETTING};
+pub use github_token::resolve_github_token;
+pub use hook_service::HookService;
+pub use image_profiles::ImageProfiles;
+pub use log_levels::LogLevels;
 pub use project_service::{ProjectService, ContainerOperationResult};
+pub use service_discovery::validate_dependencies;
+pub use test_results::find_flaky_tests;
+pub use traffic_shadow::ShadowTraffic;
@@ -0,0 +1,145 @@
+use anyhow::Result;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+use crate::application::ports::repositories::{ContainerRepository, ProjectRepository};
+use crate::db::models::{Container, Project, Slot};
+
+/// 대체 포트를 찾는 범위 (프로젝트 Blue/Green 포트 대역)
+const SUGGESTION_RANGE: RangeInclusive<u16> = 10000..=10999;
+/// 이전 컨테이너 제거 직후 docker-proxy가 포트를 놓을 때까지 재시도
+const BIND_RETRIES: u32 = 3;
+const BIND_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// 런타임 컨테이너를 띄울 호스트 포트가 이미 사용 중
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostPortConflict {
+    pub project_id: i64,
+    pub port: u16,
+    pub slot: Slot,
+    /// DB에 기록된 주인 (예: "project 'api' (green slot)"). None이면 easyCICD 밖의 프로세스
+    pub owner: Option<String>,
+    /// 지금 비어 있고 어디에도 배정되지 않은 포트
+    pub suggested_port: Option<u16>,
+}
+
+impl std::fmt::Display for HostPortConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let slot = self.slot.to_string().to_lowercase();
+        write!(f, "Host port {} for the {} slot is already in use", self.port, slot)?;
+        match &self.owner {
+            Some(owner) => write!(f, " by {}", owner)?,
+            None => write!(f, " by a process outside easyCICD")?,
+        }
+        match self.suggested_port {
+            Some(port) => write!(
+                f,
+                ". Free the port or reassign the slot, e.g. PUT /api/projects/{}/ports {{\"{}_port\": {}}}",
+                self.project_id, slot, port
+            ),
+            None => write!(f, ". Free the port or reassign the slot with PUT /api/projects/{}/ports", self.project_id),
+        }
+    }
+}
+
+impl std::error::Error for HostPortConflict {}
+
+/// 호스트 포트를 지금 바인딩할 수 있는지 확인 (포트 범위 밖이면 false)
+pub fn host_port_available(port: i32) -> bool {
+    u16::try_from(port)
+        .ok()
+        .is_some_and(|port| std::net::TcpListener::bind(("0.0.0.0", port)).is_ok())
+}
+
+/// DB 기준 포트 주인 (프로젝트 슬롯 또는 독립 컨테이너)
+fn port_owner(port: u16, projects: &[Project], containers: &[Container]) -> Option<String> {
+    let port = port as i32;
+    projects
+        .iter()
+        .find_map(|p| {
+            let slot = if p.blue_port == port {
+                "blue"
+            } else if p.green_port == port {
+                "green"
+            } else {
+                return None;
+            };
+            Some(format!("project '{}' ({} slot)", p.name, slot))
+        })
+        .or_else(|| containers.iter().find(|c| c.host_ports().contains(&port)).map(|c| format!("container '{}'", c.name)))
+}
+
+/// 배정되지 않았고 `is_free`인 첫 포트
+fn suggest_port(taken: &HashSet<i32>, is_free: impl Fn(u16) -> bool) -> Option<u16> {
+    SUGGESTION_RANGE.filter(|port| !taken.contains(&(*port as i32))).find(|port| is_free(*port))
+}
+
+/// 런타임 컨테이너 시작 전 호스트 포트 확인
+///
+/// 바인딩할 수 없으면 주인과 대체 포트를 담은 `HostPortConflict`로 실패한다 (Docker의 일반적인 시작 실패 대신).
+pub async fn ensure_host_port_free<PR, CR>(
+    project_repo: &PR,
+    container_repo: &CR,
+    project_id: i64,
+    slot: Slot,
+    port: u16,
+) -> Result<()>
+where
+    PR: ProjectRepository + ?Sized,
+    CR: ContainerRepository + ?Sized,
+{
+    for attempt in 0..BIND_RETRIES {
+        if host_port_available(port.into()) {
+            return Ok(());
+        }
+        if attempt + 1 < BIND_RETRIES {
+            tokio::time::sleep(BIND_RETRY_DELAY).await;
+        }
+    }
+
+    let projects = project_repo.list().await?;
+    let containers = container_repo.list().await?;
+    let taken: HashSet<i32> = projects
+        .iter()
+        .flat_map(|p| [p.blue_port, p.green_port])
+        .chain(containers.iter().flat_map(|c| c.host_ports()))
+        .collect();
+
+    Err(HostPortConflict {
+        project_id,
+        port,
+        slot,
+        owner: port_owner(port, &projects, &containers),
+        suggested_port: suggest_port(&taken, |port| host_port_available(port.into())),
+    }
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggest_port_skips_taken_and_busy() {
+        let taken: HashSet<i32> = [10000, 10001].into_iter().collect();
+        assert_eq!(suggest_port(&taken, |port| port != 10002), Some(10003));
+        assert_eq!(suggest_port(&taken, |_| false), None);
+    }
+
+    #[test]
+    fn test_conflict_message() {
+        let conflict = HostPortConflict {
+            project_id: 3,
+            port: 10005,
+            slot: Slot::Green,
+            owner: None,
+            suggested_port: Some(10010),
+        };
+        assert_eq!(
+            conflict.to_string(),
+            "Host port 10005 for the green slot is already in use by a process outside easyCICD. \
+             Free the port or reassign the slot, e.g. PUT /api/projects/3/ports {\"green_port\": 10010}"
+        );
+    }
+}
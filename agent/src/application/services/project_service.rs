@@ -1,46 +1,71 @@
 use anyhow::{Context, Result};
+use serde::Serialize;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::fs;
-use tokio::process::Command;
 use tracing::{info, warn};
 
-use crate::application::ports::repositories::{BuildRepository, ProjectRepository};
+use crate::application::ports::repositories::{BuildRepository, GitHubPatRepository, ProjectRepository, SettingsRepository};
 use crate::application::events::{EventBus, Event};
-use crate::db::models::{CreateBuild, CreateProject, Project, Build, Slot};
-use crate::docker::DockerClient;
+use crate::application::ports::git_provider::parse_repo_url;
+use crate::application::services::git_providers::git_provider_for;
+use crate::db::models::{BuildTrigger, CreateBuild, CreateProject, Project, Build, Slot};
+use crate::docker::{project_network_name, DockerClient};
 use crate::infrastructure::logging::{BoundaryLogger, Timer};
 
+/// 빌드에 기록할 커밋 정보
+#[derive(Debug, Clone)]
+pub struct HeadCommit {
+    pub hash: String,
+    pub message: Option<String>,
+    pub author: Option<String>,
+}
+
+impl HeadCommit {
+    /// 커밋을 알 수 없는 경우
+    fn unknown() -> Self {
+        Self { hash: "HEAD".to_string(), message: None, author: None }
+    }
+}
+
 /// ProjectService - 프로젝트 생명주기 관리를 담당하는 서비스
 ///
 /// 책임:
 /// - 프로젝트 CRUD 작업
 /// - 컨테이너 시작/중지/재시작
-/// - 빌드 트리거 (Git 정보 수집)
+/// - 빌드 트리거 (GitHub/GitLab API로 커밋 정보 수집)
 /// - 프로젝트 삭제 시 리소스 정리
 /// - 이벤트 발행
-pub struct ProjectService<PR, BR, EB>
+pub struct ProjectService<PR, BR, SR, GPR, EB>
 where
     PR: ProjectRepository,
     BR: BuildRepository,
+    SR: SettingsRepository,
+    GPR: GitHubPatRepository,
     EB: EventBus,
 {
     project_repo: Arc<PR>,
     build_repo: Arc<BR>,
+    settings_repo: Arc<SR>,
+    github_pat_repo: Arc<GPR>,
     event_bus: EB,
     docker: DockerClient,
     logger: Arc<BoundaryLogger>,
 }
 
-impl<PR, BR, EB> ProjectService<PR, BR, EB>
+impl<PR, BR, SR, GPR, EB> ProjectService<PR, BR, SR, GPR, EB>
 where
     PR: ProjectRepository,
     BR: BuildRepository,
+    SR: SettingsRepository,
+    GPR: GitHubPatRepository,
     EB: EventBus,
 {
     pub fn new(
         project_repo: Arc<PR>,
         build_repo: Arc<BR>,
+        settings_repo: Arc<SR>,
+        github_pat_repo: Arc<GPR>,
         event_bus: EB,
         docker: DockerClient,
         logger: Arc<BoundaryLogger>,
@@ -48,6 +73,8 @@ where
         Self {
             project_repo,
             build_repo,
+            settings_repo,
+            github_pat_repo,
             event_bus,
             docker,
             logger,
@@ -97,7 +124,7 @@ where
     }
 
     /// 빌드 트리거 (Git 정보 수집 및 빌드 생성)
-    pub async fn trigger_build(&self, trace_id: &str, project_id: i64) -> Result<Build> {
+    pub async fn trigger_build(&self, trace_id: &str, project_id: i64, dry_run: bool, trigger: BuildTrigger) -> Result<Build> {
         let timer = Timer::start();
         self.logger.service_entry(trace_id, "API", "ProjectService", "trigger_build", &project_id);
 
@@ -108,86 +135,19 @@ where
             .context("Project not found")?;
         self.logger.repo_done(trace_id, "ProjectService", "ProjectRepo", "get", repo_timer.elapsed_ms());
 
-        // Get current commit hash from workspace
-        let workspace_path = PathBuf::from("/data/workspace")
-            .join(&project.name);
-
-        self.logger.external_call(trace_id, "ProjectService", "Git", "rev-parse");
-        let git_timer = Timer::start();
-
-        let commit_hash = if workspace_path.exists() {
-            match Command::new("git")
-                .args(&["-C", workspace_path.to_str().unwrap(), "rev-parse", "HEAD"])
-                .output()
-                .await
-            {
-                Ok(output) if output.status.success() => {
-                    String::from_utf8_lossy(&output.stdout).trim().to_string()
-                }
-                _ => "HEAD".to_string(),
-            }
-        } else {
-            "HEAD".to_string()
-        };
-
-        self.logger.external_done(trace_id, "ProjectService", "Git", "rev-parse", git_timer.elapsed_ms());
-
-        // Get commit message and author
-        let (commit_message, author) = if workspace_path.exists() && commit_hash != "HEAD" {
-            self.logger.external_call(trace_id, "ProjectService", "Git", "log");
-            let git_timer = Timer::start();
-
-            let message = Command::new("git")
-                .args(&[
-                    "-C",
-                    workspace_path.to_str().unwrap(),
-                    "log",
-                    "-1",
-                    "--format=%s",
-                ])
-                .output()
-                .await
-                .ok()
-                .and_then(|o| {
-                    if o.status.success() {
-                        Some(String::from_utf8_lossy(&o.stdout).trim().to_string())
-                    } else {
-                        None
-                    }
-                });
-
-            let author = Command::new("git")
-                .args(&[
-                    "-C",
-                    workspace_path.to_str().unwrap(),
-                    "log",
-                    "-1",
-                    "--format=%an <%ae>",
-                ])
-                .output()
-                .await
-                .ok()
-                .and_then(|o| {
-                    if o.status.success() {
-                        Some(String::from_utf8_lossy(&o.stdout).trim().to_string())
-                    } else {
-                        None
-                    }
-                });
-
-            self.logger.external_done(trace_id, "ProjectService", "Git", "log", git_timer.elapsed_ms());
-
-            (message, author)
-        } else {
-            (None, None)
-        };
+        let commit = self.head_commit(trace_id, &project).await;
 
         // Create build
         let create_build = CreateBuild {
             project_id: project.id,
-            commit_hash,
-            commit_message,
-            author,
+            commit_hash: commit.hash,
+            commit_message: commit.message,
+            author: commit.author,
+            dry_run,
+            triggered_by: Some(trigger.to_string()),
+            rebuild_of: None,
+            branch: Some(project.branch.clone()),
+            preview_pr: None,
         };
 
         self.logger.repo_call(trace_id, "ProjectService", "BuildRepo", "create");
@@ -204,6 +164,45 @@ where
         Ok(build)
     }
 
+    /// 프로젝트 브랜치의 최신 커밋 정보 (GitHub/GitLab API)
+    ///
+    /// clone은 빌드 컨테이너 안에서 일어나 agent에 workspace가 없으므로 provider API로 조회한다.
+    /// 지원하지 않는 저장소이거나 토큰이 없거나 조회에 실패하면 커밋을 모르는 상태("HEAD")로 둔다.
+    pub async fn head_commit(&self, trace_id: &str, project: &Project) -> HeadCommit {
+        let Some(repo_ref) = parse_repo_url(&project.repo) else {
+            return HeadCommit::unknown();
+        };
+        let provider = match git_provider_for(self.github_pat_repo.as_ref(), self.settings_repo.as_ref(), repo_ref.provider, project.github_pat_id).await {
+            Ok(Some(provider)) => provider,
+            Ok(None) => {
+                warn!("[{}] No {} token for project {}, commit metadata unavailable", trace_id, repo_ref.provider, project.name);
+                return HeadCommit::unknown();
+            }
+            Err(e) => {
+                warn!("[{}] Failed to load {} token for project {}: {}", trace_id, repo_ref.provider, project.name, e);
+                return HeadCommit::unknown();
+            }
+        };
+
+        self.logger.external_call(trace_id, "ProjectService", repo_ref.provider.as_str(), "get_commit");
+        let provider_timer = Timer::start();
+        let result = provider.get_commit(&repo_ref.owner, &repo_ref.name, &project.branch).await;
+        self.logger.external_done(trace_id, "ProjectService", repo_ref.provider.as_str(), "get_commit", provider_timer.elapsed_ms());
+
+        match result {
+            Ok(commit) => HeadCommit {
+                hash: commit.sha,
+                // 첫 줄만 (git log --format=%s와 같게)
+                message: commit.commit.message.lines().next().map(str::to_string).filter(|m| !m.is_empty()),
+                author: commit.commit.author.map(|a| format!("{} <{}>", a.name, a.email)),
+            },
+            Err(e) => {
+                warn!("[{}] Failed to get {} head commit for project {}: {}", trace_id, project.branch, project.name, e);
+                HeadCommit::unknown()
+            }
+        }
+    }
+
     /// 프로젝트 삭제 (컨테이너, 파일, DB 정리)
     pub async fn delete_project(&self, trace_id: &str, id: i64) -> Result<()> {
         let timer = Timer::start();
@@ -242,6 +241,21 @@ where
             self.docker.stop_container(&container_name).await.ok();
         }
 
+        // 전용 네트워크 정리 (격리를 끈 뒤에도 남아 있을 수 있음)
+        self.logger.external_call(trace_id, "ProjectService", "Docker", "remove_dedicated_network");
+        if let Err(e) = self.docker.remove_dedicated_network(&project_network_name(project.id)).await {
+            warn!("[{}] Failed to remove network of project {}: {}", trace_id, project.name, e);
+        }
+
+        // Dockerfile 빌드 이미지 정리 (롤백용으로 남겨둔 이전 빌드 이미지 포함)
+        if project.dockerfile_path.is_some() {
+            self.logger.external_call(trace_id, "ProjectService", "Docker", "remove_project_images");
+            match self.docker.remove_project_images(project.id).await {
+                Ok(removed) => info!("[{}] Removed {} build images of project {}", trace_id, removed, project.name),
+                Err(e) => warn!("[{}] Failed to remove build images of project {}: {}", trace_id, project.name, e),
+            }
+        }
+
         // Remove directories
         let workspace_path = PathBuf::from("/data/workspace").join(&project.name);
         let output_base = PathBuf::from("/data/output");
@@ -310,8 +324,14 @@ where
                 Ok(_) => {
                     self.logger.external_done(trace_id, "ProjectService", "Docker", "start_container", docker_timer.elapsed_ms());
                     info!("[{}] Started container: {}", trace_id, container_name);
+                    self.event_bus.emit(Event::container_status(
+                        project.id,
+                        container_name.clone(),
+                        slot,
+                        "running".to_string(),
+                    )).await;
                     results.push(ContainerOperationResult {
-                        slot: slot.to_string(),
+                        slot: slot.to_string().to_lowercase(),
                         status: "started".to_string(),
                         container: container_name,
                         error: None,
@@ -321,7 +341,7 @@ where
                     self.logger.external_error(trace_id, "ProjectService", "Docker", "start_container", &e);
                     warn!("[{}] Failed to start container {}: {}", trace_id, container_name, e);
                     results.push(ContainerOperationResult {
-                        slot: slot.to_string(),
+                        slot: slot.to_string().to_lowercase(),
                         status: "error".to_string(),
                         container: container_name,
                         error: Some(e.to_string()),
@@ -362,8 +382,14 @@ where
                 Ok(_) => {
                     self.logger.external_done(trace_id, "ProjectService", "Docker", "stop_container", docker_timer.elapsed_ms());
                     info!("[{}] Stopped container: {}", trace_id, container_name);
+                    self.event_bus.emit(Event::container_status(
+                        project.id,
+                        container_name.clone(),
+                        slot,
+                        "stopped".to_string(),
+                    )).await;
                     results.push(ContainerOperationResult {
-                        slot: slot.to_string(),
+                        slot: slot.to_string().to_lowercase(),
                         status: "stopped".to_string(),
                         container: container_name,
                         error: None,
@@ -373,7 +399,7 @@ where
                     self.logger.external_error(trace_id, "ProjectService", "Docker", "stop_container", &e);
                     warn!("[{}] Failed to stop container {}: {}", trace_id, container_name, e);
                     results.push(ContainerOperationResult {
-                        slot: slot.to_string(),
+                        slot: slot.to_string().to_lowercase(),
                         status: "error".to_string(),
                         container: container_name,
                         error: Some(e.to_string()),
@@ -414,8 +440,14 @@ where
                 Ok(_) => {
                     self.logger.external_done(trace_id, "ProjectService", "Docker", "restart_container", docker_timer.elapsed_ms());
                     info!("[{}] Restarted container: {}", trace_id, container_name);
+                    self.event_bus.emit(Event::container_status(
+                        project.id,
+                        container_name.clone(),
+                        slot,
+                        "running".to_string(),
+                    )).await;
                     results.push(ContainerOperationResult {
-                        slot: slot.to_string(),
+                        slot: slot.to_string().to_lowercase(),
                         status: "restarted".to_string(),
                         container: container_name,
                         error: None,
@@ -425,7 +457,7 @@ where
                     self.logger.external_error(trace_id, "ProjectService", "Docker", "restart_container", &e);
                     warn!("[{}] Failed to restart container {}: {}", trace_id, container_name, e);
                     results.push(ContainerOperationResult {
-                        slot: slot.to_string(),
+                        slot: slot.to_string().to_lowercase(),
                         status: "error".to_string(),
                         container: container_name,
                         error: Some(e.to_string()),
@@ -440,10 +472,11 @@ where
 }
 
 /// 컨테이너 작업 결과
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ContainerOperationResult {
     pub slot: String,
     pub status: String,
     pub container: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
 }
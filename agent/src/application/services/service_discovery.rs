@@ -0,0 +1,108 @@
+use crate::db::models::{Container, Project, ProjectDependencies};
+use crate::proxy::routes::container_target;
+
+/// 의존 대상 이름 → 환경 변수 접두사 (`orders-db` → `SERVICE_ORDERS_DB`)
+pub fn service_env_prefix(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect();
+    format!("SERVICE_{}", name)
+}
+
+/// 의존 설정 유효성 검사 (빈 이름, 중복, 자기 자신 참조)
+pub fn validate_dependencies(project_name: &str, deps: &ProjectDependencies) -> Result<(), String> {
+    let mut prefixes = std::collections::HashSet::new();
+    for name in deps.containers.iter().chain(deps.projects.iter()) {
+        if name.trim().is_empty() {
+            return Err("dependency names must not be empty".to_string());
+        }
+        // 컨테이너와 프로젝트가 같은 변수 이름으로 겹치면 어느 쪽인지 알 수 없음
+        if !prefixes.insert(service_env_prefix(name)) {
+            return Err(format!("dependency '{}' is listed more than once (or maps to the same variable name)", name));
+        }
+    }
+    if deps.projects.iter().any(|p| p == project_name) {
+        return Err("a project cannot depend on itself".to_string());
+    }
+    Ok(())
+}
+
+/// 의존 대상의 `SERVICE_<NAME>_HOST` / `SERVICE_<NAME>_PORT` (easycicd 네트워크 안의 주소)
+///
+/// - 독립 컨테이너: `container-{name}`, 컨테이너 포트 (없으면 호스트 포트)
+/// - 프로젝트: network alias (`{name}.internal`), runtime_port
+pub fn service_discovery_env(containers: &[Container], projects: &[Project]) -> Vec<(String, String)> {
+    let mut env = Vec::new();
+    for container in containers {
+        let (host, port) = container_target(container);
+        let prefix = service_env_prefix(&container.name);
+        env.push((format!("{}_HOST", prefix), host));
+        env.push((format!("{}_PORT", prefix), port.to_string()));
+    }
+    for project in projects {
+        let Some(host) = project.network_aliases().into_iter().next() else { continue };
+        let prefix = service_env_prefix(&project.name);
+        env.push((format!("{}_HOST", prefix), host));
+        env.push((format!("{}_PORT", prefix), project.runtime_port.to_string()));
+    }
+    env
+}
+
+/// 사용자 정의 런타임 환경 변수(JSON)에 service discovery 변수를 합침.
+/// 사용자가 같은 이름을 직접 지정했으면 그 값을 우선한다
+pub fn merge_runtime_env(runtime_env_vars: Option<&str>, service_env: Vec<(String, String)>) -> Option<String> {
+    if service_env.is_empty() {
+        return runtime_env_vars.map(str::to_string);
+    }
+    let mut merged = runtime_env_vars
+        .and_then(|json| serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(json).ok())
+        .unwrap_or_default();
+    for (key, value) in service_env {
+        merged.entry(key).or_insert(serde_json::Value::String(value));
+    }
+    serde_json::to_string(&merged).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deps(containers: &[&str], projects: &[&str]) -> ProjectDependencies {
+        ProjectDependencies {
+            containers: containers.iter().map(|c| c.to_string()).collect(),
+            projects: projects.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_service_env_prefix() {
+        assert_eq!(service_env_prefix("orders-db"), "SERVICE_ORDERS_DB");
+        assert_eq!(service_env_prefix("redis"), "SERVICE_REDIS");
+    }
+
+    #[test]
+    fn test_validate_dependencies() {
+        assert!(validate_dependencies("web", &deps(&["postgres"], &["orders-api"])).is_ok());
+        assert!(validate_dependencies("web", &deps(&[""], &[])).is_err());
+        assert!(validate_dependencies("web", &deps(&[], &["web"])).is_err());
+        // orders-db와 orders_db는 같은 변수 이름
+        assert!(validate_dependencies("web", &deps(&["orders-db"], &["orders_db"])).is_err());
+    }
+
+    #[test]
+    fn test_merge_runtime_env() {
+        let service_env = vec![
+            ("SERVICE_DB_HOST".to_string(), "container-db".to_string()),
+            ("SERVICE_DB_PORT".to_string(), "5432".to_string()),
+        ];
+        let merged = merge_runtime_env(Some(r#"{"SERVICE_DB_HOST":"custom","NODE_ENV":"production"}"#), service_env.clone()).unwrap();
+        let merged: serde_json::Value = serde_json::from_str(&merged).unwrap();
+        assert_eq!(merged["SERVICE_DB_HOST"], "custom");
+        assert_eq!(merged["SERVICE_DB_PORT"], "5432");
+        assert_eq!(merged["NODE_ENV"], "production");
+
+        assert_eq!(merge_runtime_env(Some("{}"), Vec::new()).as_deref(), Some("{}"));
+        assert!(merge_runtime_env(None, service_env).unwrap().contains("SERVICE_DB_PORT"));
+    }
+}
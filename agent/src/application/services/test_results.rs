@@ -0,0 +1,259 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::db::models::{TestCaseResult, TestCaseStatus};
+
+/// 테스트 shard의 /output 아래 JUnit XML 리포트(*.xml)를 모두 읽어 테스트 결과로 변환
+pub fn collect_junit_reports(dir: &Path, attempt: i64) -> Vec<TestCaseResult> {
+    let mut results = Vec::new();
+    let mut stack = vec![dir.to_path_buf()];
+    while let Some(current) = stack.pop() {
+        let Ok(entries) = std::fs::read_dir(&current) else { continue };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(meta) = entry.metadata() else { continue };
+            if meta.is_dir() {
+                stack.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "xml") {
+                if let Ok(xml) = std::fs::read_to_string(&path) {
+                    results.extend(parse_junit(&xml, attempt));
+                }
+            }
+        }
+    }
+    results
+}
+
+/// JUnit XML의 `<testcase>` 요소 파싱
+///
+/// `<failure>`/`<error>`가 있으면 failed, `<skipped>`가 있으면 skipped, 나머지는 passed.
+/// 이름은 `classname.name` (classname이 없으면 name).
+pub fn parse_junit(xml: &str, attempt: i64) -> Vec<TestCaseResult> {
+    let mut results = Vec::new();
+    let mut rest = xml;
+
+    while let Some(start) = rest.find("<testcase") {
+        rest = &rest[start + "<testcase".len()..];
+        // <testcases> 같은 다른 태그 제외
+        if !rest.starts_with(|c: char| c.is_whitespace() || c == '>' || c == '/') {
+            continue;
+        }
+
+        let Some(tag_end) = find_tag_end(rest) else { break };
+        let attrs = &rest[..tag_end];
+        let self_closing = attrs.trim_end().ends_with('/');
+        rest = &rest[tag_end + 1..];
+
+        let body = if self_closing {
+            ""
+        } else {
+            let end = rest.find("</testcase>").unwrap_or(rest.len());
+            let body = &rest[..end];
+            rest = &rest[end..];
+            body
+        };
+
+        let Some(name) = attr(attrs, "name") else { continue };
+        let full_name = match attr(attrs, "classname") {
+            Some(class) if !class.is_empty() => format!("{}.{}", class, name),
+            _ => name,
+        };
+        let duration_ms = attr(attrs, "time")
+            .and_then(|t| t.parse::<f64>().ok())
+            .map(|secs| (secs * 1000.0).round() as i64)
+            .unwrap_or(0);
+        let status = if body.contains("<failure") || body.contains("<error") {
+            TestCaseStatus::Failed
+        } else if body.contains("<skipped") {
+            TestCaseStatus::Skipped
+        } else {
+            TestCaseStatus::Passed
+        };
+
+        results.push(TestCaseResult {
+            name: full_name,
+            status,
+            duration_ms,
+            attempt,
+        });
+    }
+
+    results
+}
+
+/// 따옴표 안의 '>'는 무시하고 시작 태그의 끝 위치 반환
+fn find_tag_end(s: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn attr(attrs: &str, key: &str) -> Option<String> {
+    let mut rest = attrs;
+    loop {
+        let pos = rest.find(key)?;
+        let before_ok = pos == 0 || rest[..pos].ends_with(|c: char| c.is_whitespace());
+        let after = rest[pos + key.len()..].trim_start();
+        if before_ok {
+            if let Some(value) = after.strip_prefix('=') {
+                let value = value.trim_start();
+                let quote = value.chars().next()?;
+                if quote == '"' || quote == '\'' {
+                    let inner = &value[1..];
+                    let end = inner.find(quote)?;
+                    return Some(unescape(&inner[..end]));
+                }
+            }
+        }
+        rest = &rest[pos + key.len()..];
+    }
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// 최근 빌드 기준 flaky 테스트 판정 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct FlakyTest {
+    pub name: String,
+    /// 결과가 있는 빌드 수 (skipped 제외)
+    pub runs: u32,
+    pub passes: u32,
+    pub failures: u32,
+    /// 빌드 간 pass ↔ fail 전환 횟수
+    pub flips: u32,
+    /// 같은 빌드에서 실패 후 재실행으로 통과한 횟수
+    pub retry_passes: u32,
+    pub last_status: TestCaseStatus,
+    pub last_build_id: i64,
+}
+
+/// 테스트 이력(빌드 id 오름차순)에서 flaky 테스트 찾기
+///
+/// 빌드 간 pass/fail 전환이 `min_flips` 이상이거나, 한 빌드 안에서 재실행으로 통과한 적이 있으면 flaky.
+pub fn find_flaky_tests(history: &[(i64, TestCaseResult)], min_flips: u32) -> Vec<FlakyTest> {
+    // 테스트별 → 빌드별 (첫 시도 실패 여부, 최종 통과 여부)
+    let mut per_test: HashMap<&str, Vec<(i64, bool, bool)>> = HashMap::new();
+
+    for (build_id, result) in history {
+        if result.status == TestCaseStatus::Skipped {
+            continue;
+        }
+        let runs = per_test.entry(result.name.as_str()).or_default();
+        let passed = result.status == TestCaseStatus::Passed;
+        match runs.last_mut() {
+            Some((id, failed_first, final_pass)) if id == build_id => {
+                if result.attempt == 1 {
+                    *failed_first |= !passed;
+                }
+                *final_pass |= passed;
+            }
+            _ => runs.push((*build_id, result.attempt == 1 && !passed, passed)),
+        }
+    }
+
+    let mut flaky: Vec<FlakyTest> = per_test
+        .into_iter()
+        .filter_map(|(name, runs)| {
+            let passes = runs.iter().filter(|(_, _, pass)| *pass).count() as u32;
+            let flips = runs.windows(2).filter(|w| w[0].2 != w[1].2).count() as u32;
+            let retry_passes = runs.iter().filter(|(_, failed_first, pass)| *failed_first && *pass).count() as u32;
+            let (last_build_id, _, last_pass) = *runs.last()?;
+
+            if flips < min_flips && retry_passes == 0 {
+                return None;
+            }
+
+            Some(FlakyTest {
+                name: name.to_string(),
+                runs: runs.len() as u32,
+                passes,
+                failures: runs.len() as u32 - passes,
+                flips,
+                retry_passes,
+                last_status: if last_pass { TestCaseStatus::Passed } else { TestCaseStatus::Failed },
+                last_build_id,
+            })
+        })
+        .collect();
+
+    flaky.sort_by(|a, b| {
+        (b.flips + b.retry_passes)
+            .cmp(&(a.flips + a.retry_passes))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    flaky
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_junit() {
+        let xml = r#"<?xml version="1.0"?>
+<testsuites>
+  <testsuite name="suite" tests="3">
+    <testcase classname="com.example.FooTest" name="passes" time="0.012"/>
+    <testcase classname="com.example.FooTest" name="fails &amp; breaks" time="1.5">
+      <failure message="expected 1 > 2">boom</failure>
+    </testcase>
+    <testcase name="skipped one"><skipped/></testcase>
+  </testsuite>
+</testsuites>"#;
+
+        let results = parse_junit(xml, 1);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].name, "com.example.FooTest.passes");
+        assert_eq!(results[0].status, TestCaseStatus::Passed);
+        assert_eq!(results[0].duration_ms, 12);
+        assert_eq!(results[1].name, "com.example.FooTest.fails & breaks");
+        assert_eq!(results[1].status, TestCaseStatus::Failed);
+        assert_eq!(results[1].duration_ms, 1500);
+        assert_eq!(results[2].name, "skipped one");
+        assert_eq!(results[2].status, TestCaseStatus::Skipped);
+    }
+
+    fn run(build_id: i64, name: &str, status: TestCaseStatus, attempt: i64) -> (i64, TestCaseResult) {
+        (build_id, TestCaseResult { name: name.to_string(), status, duration_ms: 0, attempt })
+    }
+
+    #[test]
+    fn test_find_flaky_tests() {
+        use TestCaseStatus::*;
+        let history = vec![
+            run(1, "stable", Passed, 1),
+            run(1, "alternating", Passed, 1),
+            run(1, "broken", Passed, 1),
+            run(2, "stable", Passed, 1),
+            run(2, "alternating", Failed, 1),
+            run(2, "broken", Failed, 1),
+            run(3, "stable", Passed, 1),
+            run(3, "alternating", Passed, 1),
+            run(3, "broken", Failed, 1),
+            run(3, "retried", Failed, 1),
+            run(3, "retried", Passed, 2),
+        ];
+
+        let flaky = find_flaky_tests(&history, 2);
+        let names: Vec<&str> = flaky.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["alternating", "retried"]);
+        assert_eq!(flaky[0].flips, 2);
+        assert_eq!(flaky[1].retry_passes, 1);
+        assert_eq!(flaky[1].last_status, Passed);
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::db::models::ShadowReport;
+
+/// 세션당 보관하는 최대 샘플 수 (미러링이 길어져도 메모리가 늘지 않도록)
+const MAX_SAMPLES: usize = 10_000;
+
+/// 미러링한 요청 하나의 운영/섀도 응답 비교
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShadowSample {
+    pub primary_status: u16,
+    pub primary_ms: u64,
+    /// 섀도 요청이 실패(연결 오류/타임아웃)하면 None
+    pub shadow_status: Option<u16>,
+    pub shadow_ms: u64,
+}
+
+/// 배포 중인 비활성 슬롯으로 요청을 복제하는 세션
+pub struct ShadowSession {
+    /// 섀도 컨테이너 이름 (easycicd 네트워크 내부)
+    pub target: String,
+    pub port: i32,
+    percent: u8,
+    counter: AtomicU64,
+    samples: Mutex<Vec<ShadowSample>>,
+}
+
+impl ShadowSession {
+    /// 이번 요청을 미러링할지. 요청 순번 기준으로 percent 비율만큼 고르게 선택
+    pub fn should_mirror(&self) -> bool {
+        sampled(self.counter.fetch_add(1, Ordering::Relaxed), self.percent)
+    }
+
+    pub fn record(&self, sample: ShadowSample) {
+        let mut samples = self.samples.lock().unwrap_or_else(|e| e.into_inner());
+        if samples.len() < MAX_SAMPLES {
+            samples.push(sample);
+        }
+    }
+
+    pub fn report(&self, duration_secs: i64) -> ShadowReport {
+        let samples = self.samples.lock().unwrap_or_else(|e| e.into_inner());
+        summarize(&samples, self.percent, duration_secs)
+    }
+}
+
+/// n번째 요청이 percent 비율 선택에 들어가는지 (100개마다 정확히 percent개가 고르게 선택됨)
+pub(crate) fn sampled(n: u64, percent: u8) -> bool {
+    let percent = u64::from(percent.min(100));
+    (n + 1) * percent / 100 > n * percent / 100
+}
+
+/// ShadowTraffic - 프로젝트별 트래픽 섀도잉 세션
+///
+/// 배포(DeploymentService)가 전환 전에 세션을 열고, 리버스 프록시가 세션이 있는 프로젝트의
+/// 요청 일부를 섀도 컨테이너로 복제해 결과를 기록한다
+#[derive(Default)]
+pub struct ShadowTraffic {
+    sessions: Mutex<HashMap<i64, Arc<ShadowSession>>>,
+}
+
+impl ShadowTraffic {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn start(&self, project_id: i64, target: String, port: i32, percent: u8) -> Arc<ShadowSession> {
+        let session = Arc::new(ShadowSession {
+            target,
+            port,
+            percent,
+            counter: AtomicU64::new(0),
+            samples: Mutex::new(Vec::new()),
+        });
+        self.sessions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(project_id, session.clone());
+        session
+    }
+
+    pub fn session(&self, project_id: i64) -> Option<Arc<ShadowSession>> {
+        self.sessions.lock().unwrap_or_else(|e| e.into_inner()).get(&project_id).cloned()
+    }
+
+    pub fn finish(&self, project_id: i64) {
+        self.sessions.lock().unwrap_or_else(|e| e.into_inner()).remove(&project_id);
+    }
+}
+
+/// 정렬된 값의 백분위 (nearest-rank)
+pub fn percentile(sorted: &[u64], p: f64) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+pub fn summarize(samples: &[ShadowSample], percent: u8, duration_secs: i64) -> ShadowReport {
+    let mut primary_ms: Vec<u64> = samples.iter().map(|s| s.primary_ms).collect();
+    let mut shadow_ms: Vec<u64> = samples.iter().filter(|s| s.shadow_status.is_some()).map(|s| s.shadow_ms).collect();
+    primary_ms.sort_unstable();
+    shadow_ms.sort_unstable();
+
+    let mut mismatches = BTreeMap::new();
+    for sample in samples {
+        if let Some(shadow) = sample.shadow_status {
+            if shadow != sample.primary_status {
+                *mismatches.entry(format!("{} -> {}", sample.primary_status, shadow)).or_insert(0) += 1;
+            }
+        }
+    }
+
+    ShadowReport {
+        percent: i64::from(percent),
+        duration_secs,
+        mirrored: samples.len(),
+        shadow_errors: samples.iter().filter(|s| s.shadow_status.is_none()).count(),
+        status_mismatches: mismatches.values().sum(),
+        primary_5xx: samples.iter().filter(|s| s.primary_status >= 500).count(),
+        shadow_5xx: samples.iter().filter(|s| s.shadow_status.is_some_and(|c| c >= 500)).count(),
+        primary_p50_ms: percentile(&primary_ms, 50.0),
+        primary_p95_ms: percentile(&primary_ms, 95.0),
+        shadow_p50_ms: percentile(&shadow_ms, 50.0),
+        shadow_p95_ms: percentile(&shadow_ms, 95.0),
+        mismatches,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(primary_status: u16, primary_ms: u64, shadow_status: Option<u16>, shadow_ms: u64) -> ShadowSample {
+        ShadowSample { primary_status, primary_ms, shadow_status, shadow_ms }
+    }
+
+    #[test]
+    fn test_sampled() {
+        for percent in [0u8, 1, 10, 33, 100] {
+            let count = (0..100).filter(|n| sampled(*n, percent)).count();
+            assert_eq!(count, percent as usize);
+        }
+        // 10%면 10개 요청마다 하나씩
+        assert_eq!((0..20).filter(|n| sampled(*n, 10)).collect::<Vec<_>>(), vec![9, 19]);
+    }
+
+    #[test]
+    fn test_summarize() {
+        let samples = vec![
+            sample(200, 10, Some(200), 12),
+            sample(200, 20, Some(500), 40),
+            sample(404, 30, Some(404), 25),
+            sample(200, 40, None, 5000),
+        ];
+        let report = summarize(&samples, 10, 60);
+
+        assert_eq!(report.mirrored, 4);
+        assert_eq!(report.shadow_errors, 1);
+        assert_eq!(report.status_mismatches, 1);
+        assert_eq!(report.mismatches.get("200 -> 500"), Some(&1));
+        assert_eq!(report.shadow_5xx, 1);
+        assert_eq!(report.primary_5xx, 0);
+        assert_eq!(report.primary_p50_ms, 20);
+        assert_eq!(report.primary_p95_ms, 40);
+        assert_eq!(report.shadow_p95_ms, 40);
+    }
+}
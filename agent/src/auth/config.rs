@@ -41,3 +41,36 @@ impl OAuthConfig {
         })
     }
 }
+
+/// OAuth2 configuration for GitHub login (OAuth App)
+#[derive(Clone)]
+pub struct GitHubOAuthConfig {
+    pub client: BasicClient,
+}
+
+impl GitHubOAuthConfig {
+    /// Load GitHub OAuth config from environment variables
+    pub fn from_env() -> Result<Self, String> {
+        let client_id = env::var("GITHUB_CLIENT_ID")
+            .map_err(|_| "GITHUB_CLIENT_ID not set")?;
+        let client_secret = env::var("GITHUB_CLIENT_SECRET")
+            .map_err(|_| "GITHUB_CLIENT_SECRET not set")?;
+        let redirect_uri = env::var("GITHUB_REDIRECT_URI")
+            .map_err(|_| "GITHUB_REDIRECT_URI not set")?;
+
+        let client = BasicClient::new(
+            ClientId::new(client_id),
+            Some(ClientSecret::new(client_secret)),
+            AuthUrl::new("https://github.com/login/oauth/authorize".to_string())
+                .map_err(|e| format!("Invalid auth URL: {}", e))?,
+            Some(TokenUrl::new("https://github.com/login/oauth/access_token".to_string())
+                .map_err(|e| format!("Invalid token URL: {}", e))?),
+        )
+        .set_redirect_uri(
+            RedirectUrl::new(redirect_uri)
+                .map_err(|e| format!("Invalid redirect URI: {}", e))?,
+        );
+
+        Ok(Self { client })
+    }
+}
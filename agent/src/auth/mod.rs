@@ -1,3 +1,3 @@
 pub mod config;
 
-pub use config::OAuthConfig;
+pub use config::{GitHubOAuthConfig, OAuthConfig};
@@ -0,0 +1,259 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder, Response, Url};
+use serde::de::DeserializeOwned;
+use std::path::Path;
+use tokio::io::AsyncWriteExt;
+
+use super::models::*;
+use crate::application::ports::git_provider::GitProvider;
+use crate::github::{Branch, Commit, Repository, Tree};
+
+const API_BASE: &str = "https://api.bitbucket.org/2.0";
+
+/// 목록 API 최대 페이지 수
+const MAX_PAGES: u32 = 10;
+/// src API는 디렉토리 단위로 페이지가 나뉘므로 더 많이 허용
+const MAX_SRC_PAGES: u32 = 50;
+/// 트리 조회 시 하위 디렉토리 깊이
+const SRC_MAX_DEPTH: &str = "20";
+
+/// Bitbucket Cloud REST API 2.0 클라이언트 (repository/workspace access token, Bearer 인증)
+#[derive(Debug, Clone)]
+pub struct BitbucketClient {
+    client: Client,
+    token: String,
+}
+
+impl BitbucketClient {
+    pub fn new(token: String) -> Self {
+        Self {
+            client: Client::new(),
+            token,
+        }
+    }
+
+    /// `/repositories/{workspace}/{repo}/...` URL (각 segment는 인코딩됨)
+    fn repo_url(&self, workspace: &str, repo: &str, segments: &[&str]) -> Result<Url> {
+        let mut url = Url::parse(API_BASE)?;
+        url.path_segments_mut()
+            .map_err(|_| anyhow!("Invalid Bitbucket API URL"))?
+            .extend(["repositories", workspace, repo])
+            .extend(segments);
+        Ok(url)
+    }
+
+    async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        let response = request
+            .bearer_auth(&self.token)
+            .header("User-Agent", "EasyCI CD")
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await?;
+            return Err(anyhow!("Bitbucket API error ({}): {}", status, body));
+        }
+
+        Ok(response)
+    }
+
+    /// `next` 링크를 따라 모든 페이지 조회
+    async fn get_all<T: DeserializeOwned>(&self, url: Url, query: &[(&str, &str)], max_pages: u32) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        let mut request = self.client.get(url.clone()).query(query);
+
+        for _ in 0..max_pages {
+            let page: Paginated<T> = self.send(request).await?.json().await?;
+            items.extend(page.values);
+
+            match page.next {
+                // next에는 쿼리가 모두 포함되어 있음
+                Some(next) => request = self.client.get(next),
+                None => return Ok(items),
+            }
+        }
+
+        tracing::warn!("Reached safety limit of {} pages for {}", max_pages, url);
+        Ok(items)
+    }
+
+    /// 토큰 유효성 확인 (접근 가능한 저장소 1개 조회)
+    pub async fn check_token(&self) -> Result<()> {
+        let url = Url::parse(&format!("{}/repositories", API_BASE))?;
+        self.send(self.client.get(url).query(&[("role", "member"), ("pagelen", "1")])).await?;
+        Ok(())
+    }
+
+    /// 멤버로 속한 저장소 목록 (최근 업데이트 순)
+    pub async fn list_repositories(&self) -> Result<Vec<Repository>> {
+        let url = Url::parse(&format!("{}/repositories", API_BASE))?;
+        let repos: Vec<BitbucketRepository> = self
+            .get_all(url, &[("role", "member"), ("sort", "-updated_on"), ("pagelen", "100")], MAX_PAGES)
+            .await?;
+        Ok(repos.into_iter().map(Repository::from).collect())
+    }
+
+    pub async fn list_branches(&self, workspace: &str, repo: &str) -> Result<Vec<Branch>> {
+        let url = self.repo_url(workspace, repo, &["refs", "branches"])?;
+        let branches: Vec<BitbucketBranch> = self.get_all(url, &[("pagelen", "100")], MAX_PAGES).await?;
+        Ok(branches.into_iter().map(Branch::from).collect())
+    }
+
+    pub async fn get_branch(&self, workspace: &str, repo: &str, branch: &str) -> Result<Branch> {
+        let url = self.repo_url(workspace, repo, &["refs", "branches", branch])?;
+        let branch: BitbucketBranch = self.send(self.client.get(url)).await?.json().await?;
+        Ok(branch.into())
+    }
+
+    /// 재귀 파일 트리 (src API의 max_depth 사용)
+    pub async fn get_tree(&self, workspace: &str, repo: &str, sha: &str) -> Result<Tree> {
+        // 끝의 빈 segment로 디렉토리 목록 요청 (/src/{commit}/)
+        let url = self.repo_url(workspace, repo, &["src", sha, ""])?;
+        let entries: Vec<BitbucketSrcEntry> = self
+            .get_all(url, &[("max_depth", SRC_MAX_DEPTH), ("pagelen", "100")], MAX_SRC_PAGES)
+            .await?;
+        Ok(Tree {
+            sha: sha.to_string(),
+            tree: entries.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    pub async fn get_file_content(&self, workspace: &str, repo: &str, path: &str, branch: &str) -> Result<String> {
+        let mut segments = vec!["src", branch];
+        segments.extend(path.split('/'));
+        let url = self.repo_url(workspace, repo, &segments)?;
+        Ok(self.send(self.client.get(url)).await?.text().await?)
+    }
+
+    pub async fn get_commit(&self, workspace: &str, repo: &str, git_ref: &str) -> Result<Commit> {
+        let url = self.repo_url(workspace, repo, &["commit", git_ref])?;
+        let commit: BitbucketCommit = self.send(self.client.get(url)).await?.json().await?;
+        Ok(commit.into())
+    }
+
+    /// 커밋이 첫 번째 부모 대비 바꾼 파일 (push payload에 파일 목록이 없어 path_filter 확인에 사용)
+    pub async fn changed_files(&self, workspace: &str, repo: &str, commit: &str) -> Result<ChangedFiles> {
+        let url = self.repo_url(workspace, repo, &["diffstat", commit])?;
+        let stats: Vec<BitbucketDiffStat> = self.get_all(url, &[("pagelen", "500")], MAX_PAGES).await?;
+        Ok(ChangedFiles::from_diffstat(stats))
+    }
+
+    /// tar.gz 아카이브 다운로드 (최상위에 {workspace}-{repo}-{short sha}/ 디렉토리가 하나 있음)
+    pub async fn download_tarball(&self, workspace: &str, repo: &str, git_ref: &str, dest: &Path) -> Result<u64> {
+        let mut url = Url::parse("https://bitbucket.org")?;
+        url.path_segments_mut()
+            .map_err(|_| anyhow!("Invalid Bitbucket URL"))?
+            .extend([workspace, repo, "get", &format!("{}.tar.gz", git_ref)]);
+
+        // 웹 다운로드 경로는 access token을 x-token-auth 사용자로 받음
+        let mut response = self.client
+            .get(url)
+            .basic_auth("x-token-auth", Some(&self.token))
+            .header("User-Agent", "EasyCI CD")
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await?;
+            return Err(anyhow!("Bitbucket download error ({}): {}", status, body));
+        }
+
+        let mut file = tokio::fs::File::create(dest).await?;
+        let mut written = 0u64;
+        while let Some(chunk) = response.chunk().await? {
+            file.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        file.flush().await?;
+
+        Ok(written)
+    }
+
+    fn hook_request(webhook_url: &str, secret: &str) -> CreateBitbucketHookRequest {
+        CreateBitbucketHookRequest {
+            description: "easyCICD".to_string(),
+            url: webhook_url.to_string(),
+            active: true,
+            events: vec!["repo:push".to_string()],
+            secret: secret.to_string(),
+        }
+    }
+
+    /// repo:push webhook 등록 (secret으로 X-Hub-Signature 서명)
+    pub async fn create_webhook(&self, workspace: &str, repo: &str, webhook_url: &str, secret: &str) -> Result<BitbucketHook> {
+        let url = self.repo_url(workspace, repo, &["hooks"])?;
+        let request = Self::hook_request(webhook_url, secret);
+        Ok(self.send(self.client.post(url).json(&request)).await?.json().await?)
+    }
+
+    /// webhook URL과 secret 갱신 (secret 교체 시)
+    pub async fn update_webhook(&self, workspace: &str, repo: &str, hook_uuid: &str, webhook_url: &str, secret: &str) -> Result<()> {
+        let url = self.repo_url(workspace, repo, &["hooks", hook_uuid])?;
+        let request = Self::hook_request(webhook_url, secret);
+        self.send(self.client.put(url).json(&request)).await?;
+        Ok(())
+    }
+
+    pub async fn delete_webhook(&self, workspace: &str, repo: &str, hook_uuid: &str) -> Result<()> {
+        let url = self.repo_url(workspace, repo, &["hooks", hook_uuid])?;
+        self.send(self.client.delete(url)).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GitProvider for BitbucketClient {
+    async fn list_repositories(&self) -> Result<Vec<Repository>> {
+        BitbucketClient::list_repositories(self).await
+    }
+
+    async fn list_branches(&self, owner: &str, repo: &str) -> Result<Vec<Branch>> {
+        BitbucketClient::list_branches(self, owner, repo).await
+    }
+
+    async fn get_branch(&self, owner: &str, repo: &str, branch: &str) -> Result<Branch> {
+        BitbucketClient::get_branch(self, owner, repo, branch).await
+    }
+
+    async fn get_tree(&self, owner: &str, repo: &str, sha: &str) -> Result<Tree> {
+        BitbucketClient::get_tree(self, owner, repo, sha).await
+    }
+
+    async fn get_file_content(&self, owner: &str, repo: &str, path: &str, branch: &str) -> Result<String> {
+        BitbucketClient::get_file_content(self, owner, repo, path, branch).await
+    }
+
+    async fn get_commit(&self, owner: &str, repo: &str, git_ref: &str) -> Result<Commit> {
+        BitbucketClient::get_commit(self, owner, repo, git_ref).await
+    }
+
+    async fn download_tarball(&self, owner: &str, repo: &str, git_ref: &str, dest: &Path) -> Result<u64> {
+        BitbucketClient::download_tarball(self, owner, repo, git_ref, dest).await
+    }
+
+    async fn create_webhook(&self, owner: &str, repo: &str, webhook_url: &str, secret: &str) -> Result<String> {
+        Ok(BitbucketClient::create_webhook(self, owner, repo, webhook_url, secret).await?.uuid)
+    }
+
+    async fn delete_webhook(&self, owner: &str, repo: &str, hook_id: &str) -> Result<()> {
+        BitbucketClient::delete_webhook(self, owner, repo, hook_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repo_url_encodes_segments() {
+        let client = BitbucketClient::new("t".to_string());
+        let url = client.repo_url("ws", "app", &["hooks", "{abc-123}"]).unwrap();
+        assert_eq!(url.as_str(), "https://api.bitbucket.org/2.0/repositories/ws/app/hooks/%7Babc-123%7D");
+
+        let url = client.repo_url("ws", "app", &["src", "deadbeef", ""]).unwrap();
+        assert_eq!(url.as_str(), "https://api.bitbucket.org/2.0/repositories/ws/app/src/deadbeef/");
+    }
+}
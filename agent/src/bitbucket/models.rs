@@ -0,0 +1,296 @@
+use serde::{Deserialize, Serialize};
+
+use crate::github::{Branch, BranchCommit, Commit, CommitAuthor, CommitDetail, CommitVerification, Repository, TreeItem};
+
+/// Bitbucket 목록 응답 (다음 페이지는 `next` URL)
+#[derive(Debug, Clone, Deserialize)]
+pub struct Paginated<T> {
+    pub values: Vec<T>,
+    pub next: Option<String>,
+}
+
+/// GET /repositories
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitbucketRepository {
+    pub uuid: String,
+    pub name: String,
+    pub full_name: String,
+    #[serde(default)]
+    pub is_private: bool,
+    pub mainbranch: Option<BitbucketMainBranch>,
+    pub links: BitbucketRepositoryLinks,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitbucketMainBranch {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitbucketRepositoryLinks {
+    #[serde(default)]
+    pub clone: Vec<BitbucketLink>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitbucketLink {
+    pub name: String,
+    pub href: String,
+}
+
+impl From<BitbucketRepository> for Repository {
+    fn from(r: BitbucketRepository) -> Self {
+        // https clone URL에는 사용자 이름이 들어 있음 (https://user@bitbucket.org/ws/repo.git)
+        let clone_url = format!("https://bitbucket.org/{}.git", r.full_name);
+        Repository {
+            id: stable_id(&r.uuid),
+            name: r.name,
+            full_name: r.full_name,
+            clone_url,
+            default_branch: r.mainbranch.map(|b| b.name).unwrap_or_else(|| "main".to_string()),
+            private: r.is_private,
+        }
+    }
+}
+
+/// 숫자 ID가 없는 Bitbucket 저장소용 안정적인 ID (uuid의 FNV-1a 해시)
+fn stable_id(uuid: &str) -> u64 {
+    uuid.bytes().fold(0xcbf29ce484222325, |hash, b| (hash ^ b as u64).wrapping_mul(0x100000001b3))
+}
+
+/// GET /repositories/{workspace}/{repo}/refs/branches
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitbucketBranch {
+    pub name: String,
+    pub target: BitbucketTarget,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitbucketTarget {
+    pub hash: String,
+}
+
+impl From<BitbucketBranch> for Branch {
+    fn from(b: BitbucketBranch) -> Self {
+        Branch {
+            name: b.name,
+            commit: BranchCommit { sha: b.target.hash },
+            protected: false,
+        }
+    }
+}
+
+/// GET /repositories/{workspace}/{repo}/src/{commit}/ (type: commit_directory, commit_file)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitbucketSrcEntry {
+    pub path: String,
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    pub commit: BitbucketTarget,
+}
+
+impl From<BitbucketSrcEntry> for TreeItem {
+    fn from(e: BitbucketSrcEntry) -> Self {
+        TreeItem {
+            path: e.path,
+            item_type: if e.entry_type == "commit_directory" { "tree" } else { "blob" }.to_string(),
+            sha: e.commit.hash,
+        }
+    }
+}
+
+/// GET /repositories/{workspace}/{repo}/commit/{ref}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitbucketCommit {
+    pub hash: String,
+    #[serde(default)]
+    pub message: String,
+    pub author: Option<BitbucketAuthor>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitbucketAuthor {
+    /// "Name <email>"
+    pub raw: String,
+}
+
+impl BitbucketAuthor {
+    /// raw ("Name <email>")를 이름과 이메일로 분리
+    pub fn to_commit_author(&self) -> CommitAuthor {
+        match self.raw.rsplit_once('<') {
+            Some((name, email)) => CommitAuthor {
+                name: name.trim().to_string(),
+                email: email.trim_end_matches('>').trim().to_string(),
+            },
+            None => CommitAuthor { name: self.raw.trim().to_string(), email: String::new() },
+        }
+    }
+}
+
+impl From<BitbucketCommit> for Commit {
+    fn from(c: BitbucketCommit) -> Self {
+        Commit {
+            sha: c.hash,
+            commit: CommitDetail {
+                message: c.message,
+                author: c.author.as_ref().map(BitbucketAuthor::to_commit_author),
+                // 서명 검증은 GitHub에서만 지원
+                verification: CommitVerification { verified: false, reason: "unsupported".to_string() },
+            },
+        }
+    }
+}
+
+/// GET /repositories/{workspace}/{repo}/diffstat/{spec}
+#[derive(Debug, Clone, Deserialize)]
+pub struct BitbucketDiffStat {
+    /// added, removed, modified, renamed
+    pub status: String,
+    pub old: Option<BitbucketDiffPath>,
+    pub new: Option<BitbucketDiffPath>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BitbucketDiffPath {
+    pub path: String,
+}
+
+/// diffstat 결과를 GitHub push payload처럼 added/modified/removed로 분류
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangedFiles {
+    pub added: Vec<String>,
+    pub modified: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl ChangedFiles {
+    pub fn from_diffstat(stats: Vec<BitbucketDiffStat>) -> Self {
+        let mut files = ChangedFiles::default();
+        for stat in stats {
+            let old = stat.old.map(|p| p.path);
+            let new = stat.new.map(|p| p.path);
+            match (stat.status.as_str(), old, new) {
+                ("added", _, Some(new)) => files.added.push(new),
+                ("removed", Some(old), _) => files.removed.push(old),
+                // 이름 변경은 이전 경로 삭제 + 새 경로 추가
+                ("renamed", Some(old), Some(new)) => {
+                    files.removed.push(old);
+                    files.added.push(new);
+                }
+                (_, _, Some(new)) => files.modified.push(new),
+                (_, Some(old), None) => files.removed.push(old),
+                _ => {}
+            }
+        }
+        files
+    }
+}
+
+/// POST /repositories/{workspace}/{repo}/hooks 요청 본문
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateBitbucketHookRequest {
+    pub description: String,
+    pub url: String,
+    pub active: bool,
+    pub events: Vec<String>,
+    /// 설정하면 X-Hub-Signature(sha256=HMAC) 헤더로 서명됨
+    pub secret: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitbucketHook {
+    /// "{xxxxxxxx-...}"
+    pub uuid: String,
+}
+
+/// repo:push payload (X-Event-Key: repo:push)
+#[derive(Debug, Clone, Deserialize)]
+pub struct BitbucketPushEvent {
+    pub repository: BitbucketPushRepository,
+    pub push: BitbucketPush,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BitbucketPushRepository {
+    pub full_name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BitbucketPush {
+    #[serde(default)]
+    pub changes: Vec<BitbucketChange>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BitbucketChange {
+    /// 삭제된 ref면 null
+    pub new: Option<BitbucketRef>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BitbucketRef {
+    /// branch, tag
+    #[serde(rename = "type")]
+    pub ref_type: String,
+    pub name: String,
+    pub target: BitbucketCommit,
+}
+
+impl BitbucketPushEvent {
+    /// push된 첫 번째 브랜치의 새 head (태그/삭제는 제외)
+    pub fn branch_head(&self) -> Option<&BitbucketRef> {
+        self.push
+            .changes
+            .iter()
+            .filter_map(|c| c.new.as_ref())
+            .find(|r| r.ref_type == "branch")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_author_raw_parsing() {
+        let author = BitbucketAuthor { raw: "Jane Doe <jane@example.com>".to_string() }.to_commit_author();
+        assert_eq!(author.name, "Jane Doe");
+        assert_eq!(author.email, "jane@example.com");
+
+        let author = BitbucketAuthor { raw: "ci-bot".to_string() }.to_commit_author();
+        assert_eq!(author.name, "ci-bot");
+        assert_eq!(author.email, "");
+    }
+
+    #[test]
+    fn test_changed_files_from_diffstat() {
+        let stats: Vec<BitbucketDiffStat> = serde_json::from_value(serde_json::json!([
+            {"status": "added", "old": null, "new": {"path": "a.txt"}},
+            {"status": "modified", "old": {"path": "src/lib.rs"}, "new": {"path": "src/lib.rs"}},
+            {"status": "removed", "old": {"path": "old.txt"}, "new": null},
+            {"status": "renamed", "old": {"path": "x.rs"}, "new": {"path": "y.rs"}}
+        ])).unwrap();
+
+        let files = ChangedFiles::from_diffstat(stats);
+        assert_eq!(files.added, vec!["a.txt", "y.rs"]);
+        assert_eq!(files.modified, vec!["src/lib.rs"]);
+        assert_eq!(files.removed, vec!["old.txt", "x.rs"]);
+    }
+
+    #[test]
+    fn test_push_event_branch_head() {
+        let event: BitbucketPushEvent = serde_json::from_value(serde_json::json!({
+            "repository": {"full_name": "ws/app"},
+            "push": {"changes": [
+                {"new": {"type": "tag", "name": "v1", "target": {"hash": "t1", "message": "tag"}}},
+                {"new": {"type": "branch", "name": "main", "target": {
+                    "hash": "abc123", "message": "fix\n", "author": {"raw": "A <a@x>"}
+                }}}
+            ]}
+        })).unwrap();
+
+        let head = event.branch_head().unwrap();
+        assert_eq!(head.name, "main");
+        assert_eq!(head.target.hash, "abc123");
+    }
+}
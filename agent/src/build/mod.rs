@@ -3,4 +3,6 @@
 // mod deployer;
 mod worker;
 
-pub use worker::run_build_worker;
+pub use worker::{
+    abort_canary_build, promote_canary_build, release_held_build, resume_build_queue, run_build_worker, validate_concurrency_group, ConcurrencyGroupLimits, CONCURRENCY_GROUPS_SETTING,
+};
@@ -1,56 +1,182 @@
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::time::Duration;
+use tokio::task::JoinSet;
 use tokio::time::sleep;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 use uuid::Uuid;
 
-use crate::state::AppContext;
-use crate::application::ports::repositories::{ProjectRepository, BuildRepository};
+use crate::state::{AppContext, DeploymentOperation};
+use crate::application::events::{Event, EventBus};
+use crate::application::ports::repositories::{ProjectRepository, BuildRepository, SettingsRepository};
+use crate::application::services::{deploy_allowed_now, next_deploy_window, resolve_github_token};
+use crate::db::models::{Build, BuildStatus, HookStage, Project};
+use crate::infrastructure::database::PreviewStatus;
+use crate::github::{parse_repo_owner_name, CommitVerification, GitHubClient};
+use crate::workers::stale_build_watchdog::fail_abandoned_build;
+
+/// 동시 실행 그룹 한도 설정 키 (JSON, ConcurrencyGroupLimits)
+pub const CONCURRENCY_GROUPS_SETTING: &str = "build_concurrency_groups";
+
+/// 전체 동시 실행 빌드 수 기본값 (max_concurrent가 없을 때)
+pub const DEFAULT_MAX_CONCURRENT_BUILDS: usize = 4;
+
+/// 전체/동시 실행 그룹별 최대 병렬 빌드 수
+///
+/// ```json
+/// { "max_concurrent": 4, "groups": { "heavy-java": 1, "node": 3 } }
+/// ```
+///
+/// 프로젝트별로는 항상 한 번에 하나. 한도가 없는 그룹은 전체 한도 외에 제한 없음
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConcurrencyGroupLimits {
+    /// 전체 동시 실행 빌드 수 (None이면 DEFAULT_MAX_CONCURRENT_BUILDS)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent: Option<usize>,
+    #[serde(default)]
+    pub groups: HashMap<String, usize>,
+}
+
+impl ConcurrencyGroupLimits {
+    pub fn max_concurrent(&self) -> usize {
+        self.max_concurrent.unwrap_or(DEFAULT_MAX_CONCURRENT_BUILDS)
+    }
+
+    /// 그룹에서 `running`개가 실행 중일 때 빌드를 하나 더 시작할 수 있는지
+    pub fn allows(&self, group: Option<&str>, running: usize) -> bool {
+        match group.and_then(|g| self.groups.get(g)) {
+            Some(max) => running < *max,
+            None => true,
+        }
+    }
+
+    pub async fn load(context: &AppContext) -> ConcurrencyGroupLimits {
+        match context.settings_repo.get(CONCURRENCY_GROUPS_SETTING).await {
+            Ok(Some(json)) => serde_json::from_str(&json).unwrap_or_else(|e| {
+                warn!("Invalid build_concurrency_groups setting, ignoring: {}", e);
+                ConcurrencyGroupLimits::default()
+            }),
+            Ok(None) => ConcurrencyGroupLimits::default(),
+            Err(e) => {
+                warn!("Failed to load build_concurrency_groups setting: {}", e);
+                ConcurrencyGroupLimits::default()
+            }
+        }
+    }
+}
+
+/// 그룹 이름 유효성 검사. 허용 문자: 소문자/숫자, '-', '_' (1~64자)
+pub fn validate_concurrency_group(group: &str) -> bool {
+    !group.is_empty()
+        && group.len() <= 64
+        && group.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+}
+
+/// 재시작 전 상태 복구 (빌드 워커 시작 전에 호출). (다시 큐에 넣은 빌드 수, 중단 처리한 빌드 수)
+///
+/// - Queued 빌드는 메모리 큐에만 있었으므로 먼저 들어온 순서대로 다시 큐에 넣는다
+/// - Building 빌드는 실행하던 프로세스가 없으므로 컨테이너를 정리하고 Failed로 처리한다
+///   (배포 도중이었을 수 있어 자동으로 다시 실행하지 않음)
+pub async fn resume_build_queue(context: &AppContext) -> Result<(usize, usize)> {
+    let queued = context.build_repo.list_by_status(BuildStatus::Queued).await?;
+    for build in &queued {
+        // 재시작 전에 이미 Queued로 알린 빌드라 이벤트 없이 다시 넣음
+        context.build_queue.enqueue(build.project_id, build.id).await;
+    }
+
+    let interrupted = context.build_repo.list_by_status(BuildStatus::Building).await?;
+    for build in &interrupted {
+        let message = "Build was interrupted by an agent restart. Marked as failed; trigger a new build to retry".to_string();
+        match fail_abandoned_build(context, build, message).await {
+            Ok(removed) => {
+                tracing::info!(
+                    target: "audit",
+                    event = "build.interrupted",
+                    build_id = build.id,
+                    project_id = build.project_id,
+                    removed_containers = removed,
+                );
+            }
+            Err(e) => warn!("Failed to mark interrupted build #{} as failed: {}", build.build_number, e),
+        }
+    }
+
+    Ok((queued.len(), interrupted.len()))
+}
 
 pub async fn run_build_worker(context: AppContext) -> Result<()> {
     info!("Build worker started");
 
+    // 실행 중인 빌드 태스크 (task id -> project_id, panic으로 끝나도 처리 중 표시를 해제하기 위해)
+    let mut tasks = JoinSet::new();
+    let mut task_projects: HashMap<tokio::task::Id, (i64, i64)> = HashMap::new();
+
     loop {
-        // Get all queued builds
-        let queued_builds = context.build_queue.get_all_queued_builds().await;
+        while let Some(result) = tasks.try_join_next_with_id() {
+            match result {
+                Ok((id, ())) => {
+                    task_projects.remove(&id);
+                }
+                Err(e) => {
+                    if let Some((project_id, build_id)) = task_projects.remove(&e.id()) {
+                        error!("Build #{} task for project {} aborted: {}", build_id, project_id, e);
+                        if let Err(update_err) = context.build_repo.finish(build_id, BuildStatus::Failed).await {
+                            error!("Failed to update build status: {}", update_err);
+                        }
+                        context.build_queue.finish_processing(project_id).await;
+                    }
+                }
+            }
+        }
 
-        for (project_id, _build_ids) in queued_builds {
-            // Skip if already processing this project
+        // 대기 중인 프로젝트의 동시 실행 그룹 (한도에 걸리면 다음 주기까지 Queued 유지)
+        let mut groups = HashMap::new();
+        for (project_id, _build_ids) in context.build_queue.get_all_queued_builds().await {
             if context.build_queue.is_processing(project_id).await {
                 continue;
             }
+            match context.project_repo.get(project_id).await {
+                Ok(project) => {
+                    if let Some(group) = project.and_then(|p| p.concurrency_group) {
+                        groups.insert(project_id, group);
+                    }
+                }
+                Err(e) => warn!("Failed to load project {} for concurrency check: {}", project_id, e),
+            }
+        }
+        let limits = ConcurrencyGroupLimits::load(&context).await;
+        let scheduled = context
+            .build_queue
+            .schedule(limits.max_concurrent(), &groups, |group, running| limits.allows(Some(group), running))
+            .await;
 
-            // Dequeue next build for this project
-            if let Some(build_id) = context.build_queue.dequeue(project_id).await {
-                info!("Processing build #{} for project {}", build_id, project_id);
-
-                // Mark as processing
-                context.build_queue.start_processing(project_id, build_id).await;
-
-                // Spawn task to handle build
-                let ctx = context.clone();
+        for (project_id, build_id) in scheduled {
+            info!("Processing build #{} for project {}", build_id, project_id);
+            let ctx = context.clone();
 
-                tokio::spawn(async move {
-                    // Generate trace ID for this build process
-                    let trace_id = format!("worker-{}-{}", project_id, Uuid::new_v4());
+            let handle = tasks.spawn(async move {
+                // Generate trace ID for this build process
+                let trace_id = format!("worker-{}-{}", project_id, Uuid::new_v4());
 
-                    if let Err(e) = process_build(ctx.clone(), &trace_id, project_id, build_id).await
-                    {
-                        error!("[{}] Build #{} failed: {}", trace_id, build_id, e);
+                if let Err(e) = process_build(ctx.clone(), &trace_id, project_id, build_id).await
+                {
+                    error!("[{}] Build #{} failed: {}", trace_id, build_id, e);
 
-                        // Update build status to Failed
-                        if let Err(update_err) = ctx.build_repo.finish(build_id, crate::db::models::BuildStatus::Failed).await {
-                            error!("[{}] Failed to update build status: {}", trace_id, update_err);
-                        }
+                    // Update build status to Failed
+                    if let Err(update_err) = ctx.build_repo.finish(build_id, BuildStatus::Failed).await {
+                        error!("[{}] Failed to update build status: {}", trace_id, update_err);
                     }
+                }
 
-                    // Mark as finished and add small delay to prevent immediate re-processing
-                    ctx.build_queue.finish_processing(project_id).await;
+                // Mark as finished and add small delay to prevent immediate re-processing
+                ctx.build_queue.finish_processing(project_id).await;
 
-                    // Small delay to ensure state consistency before next build
-                    sleep(Duration::from_millis(100)).await;
-                });
-            }
+                // Small delay to ensure state consistency before next build
+                sleep(Duration::from_millis(100)).await;
+            });
+            task_projects.insert(handle.id(), (project_id, build_id));
         }
 
         // Sleep for a bit before checking again
@@ -82,11 +208,180 @@ async fn process_build(
         trace_id, build.build_number, project.name
     );
 
+    // Pre-build hook: 실패하면 빌드 중단
+    if !ctx.hook_service.run(trace_id, &project, &build, HookStage::PreBuild, "Queued").await? {
+        anyhow::bail!("pre-build hook failed");
+    }
+
     // Execute build using BuildService
-    let output_path = ctx
-        .build_service
-        .execute_build(trace_id, build_id)
-        .await?;
+    let output_path = match ctx.build_service.execute_build(trace_id, build_id).await {
+        Ok(path) => path,
+        Err(e) => {
+            run_hook_logged(&ctx, trace_id, &project, &build, HookStage::PostBuild, "Failed").await;
+            if let Some(pr_number) = build.preview_pr {
+                if let Err(update_err) = ctx.preview_repo.update_status(project_id, pr_number, build_id, PreviewStatus::Failed, None).await {
+                    warn!("[{}] Failed to update preview PR #{}: {}", trace_id, pr_number, update_err);
+                }
+            }
+            return Err(e);
+        }
+    };
+    run_hook_logged(&ctx, trace_id, &project, &build, HookStage::PostBuild, "Success").await;
+
+    // PR 프리뷰: 운영 슬롯 대신 프리뷰 컨테이너로 실행 (서명 정책/배포 창/배포 잠금과 무관)
+    if let Some(pr_number) = build.preview_pr {
+        return deploy_preview_build(&ctx, trace_id, &project, &build, pr_number, output_path).await;
+    }
+
+    // Dry-run: 빌드/산출물 검증까지만 하고 배포와 슬롯 전환은 생략
+    if build.dry_run {
+        ctx.build_repo.finish(build_id, BuildStatus::Verified).await?;
+        ctx.event_bus.emit(Event::build_status(build_id, project_id, BuildStatus::Verified)).await;
+
+        info!(
+            "[{}] Dry-run build #{} for project '{}' verified (deployment skipped, output: {})",
+            trace_id, build.build_number, project.name, output_path.display()
+        );
+        return Ok(());
+    }
+
+    // 서명 정책: 검증되지 않은 커밋은 빌드만 하고 배포하지 않음
+    if project.require_signed_commits {
+        if let Some(reason) = commit_signature_block_reason(&ctx, &project, &build).await {
+            warn!("[{}] Deployment of build #{} blocked: {}", trace_id, build.build_number, reason);
+            ctx.build_repo.block_deploy(build_id, &reason).await?;
+            ctx.event_bus.emit(Event::error(Some(build_id), Some(project_id), format!("Deployment blocked: {}", reason))).await;
+            ctx.event_bus.emit(Event::build_status(build_id, project_id, BuildStatus::Verified)).await;
+            return Ok(());
+        }
+        info!("[{}] Commit {} has a verified signature", trace_id, build.commit_hash);
+    }
+
+    // 배포 허용 시간대 밖이면 Held로 두고 다음 창이 열릴 때 배포 (deploy_window_release 워커)
+    if !deploy_allowed_now(&project) {
+        ctx.build_repo.update_status(build_id, BuildStatus::Held).await?;
+        ctx.event_bus.emit(Event::build_status(build_id, project_id, BuildStatus::Held)).await;
+
+        info!(
+            "[{}] Build #{} for project '{}' held until next deploy window ({})",
+            trace_id,
+            build.build_number,
+            project.name,
+            next_deploy_window(&project).unwrap_or_else(|| "unknown".to_string())
+        );
+        return Ok(());
+    }
+
+    // 같은 프로젝트의 롤백/재배포가 진행 중이면 끝날 때까지 대기
+    if let Some(holder) = ctx.deployment_locks.holder(project_id) {
+        info!(
+            "[{}] Waiting for {} (trace_id: {}) to finish before deploying project '{}'",
+            trace_id, holder.operation, holder.trace_id, project.name
+        );
+    }
+    let _deployment_guard = ctx.deployment_locks.acquire(project_id, DeploymentOperation::Deploy, trace_id).await;
+
+    deploy_build(&ctx, trace_id, project_id, &build, output_path).await?;
+
+    info!(
+        "[{}] Build #{} for project '{}' completed successfully",
+        trace_id, build.build_number, project.name
+    );
+
+    Ok(())
+}
+
+/// 프리뷰 빌드 산출물로 PR 프리뷰 컨테이너 실행. 빌드는 Verified로 끝난다 (Blue/Green 배포/롤백 대상이 아님)
+///
+/// 빌드하는 동안 PR이 닫혔으면 컨테이너를 띄우지 않는다.
+async fn deploy_preview_build(
+    ctx: &AppContext,
+    trace_id: &str,
+    project: &Project,
+    build: &Build,
+    pr_number: i64,
+    output_path: PathBuf,
+) -> Result<()> {
+    if ctx.preview_repo.get(project.id, pr_number).await?.is_none() {
+        info!("[{}] PR #{} of project '{}' was closed, skipping preview", trace_id, pr_number, project.name);
+    } else {
+        let container_id = match ctx.deployment_service.deploy_preview(trace_id, project, build, pr_number, output_path).await {
+            Ok(container_id) => container_id,
+            Err(e) => {
+                if let Err(update_err) = ctx.preview_repo.update_status(project.id, pr_number, build.id, PreviewStatus::Failed, None).await {
+                    warn!("[{}] Failed to update preview PR #{}: {}", trace_id, pr_number, update_err);
+                }
+                return Err(e);
+            }
+        };
+
+        // 컨테이너를 띄우는 동안 PR이 닫혀 기록이 지워졌으면 바로 정리
+        if !ctx.preview_repo.update_status(project.id, pr_number, build.id, PreviewStatus::Running, Some(&container_id)).await? {
+            info!("[{}] PR #{} of project '{}' was closed during deployment, removing preview", trace_id, pr_number, project.name);
+            ctx.deployment_service.remove_preview(trace_id, project.id, pr_number).await;
+        } else {
+            info!(
+                "[{}] Preview of PR #{} for project '{}' is running (build #{})",
+                trace_id, pr_number, project.name, build.build_number
+            );
+        }
+    }
+
+    ctx.build_repo.finish(build.id, BuildStatus::Verified).await?;
+    ctx.event_bus.emit(Event::build_status(build.id, project.id, BuildStatus::Verified)).await;
+    Ok(())
+}
+
+/// require_signed_commits 프로젝트의 커밋 서명을 GitHub API로 확인. 배포를 막아야 하면 이유 반환
+///
+/// 확인할 수 없는 경우(GitHub 저장소가 아님, 토큰 없음, API 오류)도 배포하지 않는다.
+async fn commit_signature_block_reason(ctx: &AppContext, project: &Project, build: &Build) -> Option<String> {
+    if build.commit_hash.is_empty() || build.commit_hash == "HEAD" {
+        return Some("commit is unknown, signature cannot be verified".to_string());
+    }
+    let Some((owner, repo)) = parse_repo_owner_name(&project.repo) else {
+        return Some("repository is not on GitHub, signature cannot be verified".to_string());
+    };
+    let token = match resolve_github_token(ctx.github_pat_repo.as_ref(), ctx.settings_repo.as_ref(), project.github_pat_id).await {
+        Ok(Some(token)) => token,
+        Ok(None) => return Some("no GitHub token configured, signature cannot be verified".to_string()),
+        Err(e) => return Some(format!("failed to load GitHub token: {}", e)),
+    };
+
+    match GitHubClient::new(token).get_commit(&owner, &repo, &build.commit_hash).await {
+        Ok(commit) => signature_block_reason(&build.commit_hash, &commit.commit.verification),
+        Err(e) => Some(format!("failed to verify commit signature: {}", e)),
+    }
+}
+
+fn signature_block_reason(commit_hash: &str, verification: &CommitVerification) -> Option<String> {
+    if verification.verified {
+        return None;
+    }
+    let short = &commit_hash[..commit_hash.len().min(7)];
+    Some(format!(
+        "commit {} does not have a verified signature (GitHub: {})",
+        short, verification.reason
+    ))
+}
+
+/// 빌드 산출물을 배포하고 post-deploy hook 실행
+///
+/// 호출자가 프로젝트 배포 잠금을 잡고 있어야 한다. 배포에 성공하면 이 빌드보다 먼저 대기 중이던
+/// Held 빌드는 더 이상 배포할 필요가 없으므로 Verified로 정리한다.
+pub async fn deploy_build(
+    ctx: &AppContext,
+    trace_id: &str,
+    project_id: i64,
+    build: &Build,
+    output_path: PathBuf,
+) -> Result<()> {
+    // 빌드/대기하는 동안 롤백 등으로 active_slot이 바뀌었을 수 있으므로 다시 조회
+    let project = ctx
+        .project_repo
+        .get(project_id)
+        .await?
+        .context(format!("Project not found: {}", project_id))?;
 
     info!(
         "[{}] Build completed, starting deployment for project '{}'",
@@ -94,14 +389,141 @@ async fn process_build(
     );
 
     // Deploy using DeploymentService
-    ctx.deployment_service
-        .deploy(trace_id, &project, &build, output_path)
-        .await?;
+    if let Err(e) = ctx.deployment_service.deploy(trace_id, &project, build, output_path).await {
+        ctx.event_bus.emit(Event::deployment(
+            project.id,
+            project.name.clone(),
+            build.id,
+            "Failed".to_string(),
+            project.get_inactive_slot(),
+            format!("http://{}:{}", "localhost", project.get_inactive_port()),
+        )).await;
+        run_hook_logged(ctx, trace_id, &project, build, HookStage::PostDeployFailure, "Failed").await;
+        return Err(e);
+    }
 
-    info!(
-        "[{}] Build #{} for project '{}' completed successfully",
-        trace_id, build.build_number, project.name
-    );
+    // 배포 후 active_slot이 바뀌었으므로 최신 프로젝트로 hook 실행
+    let project = ctx.project_repo.get(project_id).await?.unwrap_or(project);
+    if project.canary_build_id == Some(build.id) {
+        // 카나리는 승격/중단 때 post-deploy hook 실행
+        info!("[{}] Build #{} is running as canary for project '{}'", trace_id, build.build_number, project.name);
+    } else {
+        run_hook_logged(ctx, trace_id, &project, build, HookStage::PostDeploySuccess, "Success").await;
+    }
+
+    match ctx.build_repo.list_by_status(BuildStatus::Held).await {
+        Ok(held) => {
+            for stale in held.iter().filter(|b| b.project_id == project_id && b.id < build.id) {
+                info!(
+                    "[{}] Held build #{} superseded by build #{} for project '{}'",
+                    trace_id, stale.build_number, build.build_number, project.name
+                );
+                if let Err(e) = ctx.build_repo.finish(stale.id, BuildStatus::Verified).await {
+                    warn!("[{}] Failed to clear held build #{}: {}", trace_id, stale.build_number, e);
+                    continue;
+                }
+                ctx.event_bus.emit(Event::build_status(stale.id, project_id, BuildStatus::Verified)).await;
+            }
+        }
+        Err(e) => warn!("[{}] Failed to list held builds: {}", trace_id, e),
+    }
 
     Ok(())
 }
+
+/// 진행 중인 카나리를 승격하고 post-deploy-success hook 실행. 호출자가 프로젝트 배포 잠금을 잡고 있어야 한다
+pub async fn promote_canary_build(ctx: &AppContext, trace_id: &str, project: &Project) -> Result<Build> {
+    let build = ctx.deployment_service.promote_canary(trace_id, project).await?;
+    let project = ctx.project_repo.get(project.id).await?.unwrap_or_else(|| project.clone());
+    run_hook_logged(ctx, trace_id, &project, &build, HookStage::PostDeploySuccess, "Success").await;
+    Ok(build)
+}
+
+/// 진행 중인 카나리를 중단하고 post-deploy-failure hook 실행. 호출자가 프로젝트 배포 잠금을 잡고 있어야 한다
+pub async fn abort_canary_build(ctx: &AppContext, trace_id: &str, project: &Project, reason: &str) -> Result<Build> {
+    let build = ctx.deployment_service.abort_canary(trace_id, project, reason).await?;
+    run_hook_logged(ctx, trace_id, project, &build, HookStage::PostDeployFailure, "Failed").await;
+    Ok(build)
+}
+
+/// Held 빌드 배포 (배포 창이 열렸거나 수동 release). 호출자가 프로젝트 배포 잠금을 잡고 있어야 한다
+///
+/// 배포에 실패하면 빌드를 Failed로 처리한다.
+pub async fn release_held_build(ctx: &AppContext, trace_id: &str, build: &Build) -> Result<()> {
+    let output_path = build
+        .output_path
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("/data/output").join(format!("build{}", build.id)));
+
+    let result = if output_path.exists() {
+        deploy_build(ctx, trace_id, build.project_id, build, output_path).await
+    } else {
+        Err(anyhow::anyhow!("Build output not found: {}", output_path.display()))
+    };
+
+    if let Err(ref e) = result {
+        error!("[{}] Failed to release held build #{}: {}", trace_id, build.build_number, e);
+        if let Err(update_err) = ctx.build_repo.finish(build.id, BuildStatus::Failed).await {
+            error!("[{}] Failed to update build status: {}", trace_id, update_err);
+        }
+        ctx.event_bus.emit(Event::build_status(build.id, build.project_id, BuildStatus::Failed)).await;
+    }
+    result
+}
+
+/// post-* hook 실행 (실패해도 빌드/배포 결과에는 영향 없음)
+async fn run_hook_logged(
+    ctx: &AppContext,
+    trace_id: &str,
+    project: &Project,
+    build: &Build,
+    stage: HookStage,
+    build_status: &str,
+) {
+    match ctx.hook_service.run(trace_id, project, build, stage, build_status).await {
+        Ok(true) => {}
+        Ok(false) => warn!("[{}] {} hook failed for project '{}'", trace_id, stage, project.name),
+        Err(e) => warn!("[{}] Failed to run {} hook for project '{}': {}", trace_id, stage, project.name, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concurrency_group_limits() {
+        let limits: ConcurrencyGroupLimits = serde_json::from_str(r#"{"groups": {"heavy-java": 1}}"#).unwrap();
+
+        assert!(limits.allows(Some("heavy-java"), 0));
+        assert!(!limits.allows(Some("heavy-java"), 1));
+        assert!(limits.allows(Some("node"), 5));
+        assert!(limits.allows(None, 5));
+        assert_eq!(limits.max_concurrent(), DEFAULT_MAX_CONCURRENT_BUILDS);
+
+        let limits: ConcurrencyGroupLimits = serde_json::from_str(r#"{"max_concurrent": 2}"#).unwrap();
+        assert_eq!(limits.max_concurrent(), 2);
+    }
+
+    #[test]
+    fn test_signature_block_reason() {
+        let verified = CommitVerification { verified: true, reason: "valid".to_string() };
+        assert_eq!(signature_block_reason("abc1234def", &verified), None);
+
+        let unsigned = CommitVerification { verified: false, reason: "unsigned".to_string() };
+        assert_eq!(
+            signature_block_reason("abc1234def", &unsigned).as_deref(),
+            Some("commit abc1234 does not have a verified signature (GitHub: unsigned)")
+        );
+    }
+
+    #[test]
+    fn test_validate_concurrency_group() {
+        assert!(validate_concurrency_group("heavy-java"));
+        assert!(validate_concurrency_group("group_2"));
+        assert!(!validate_concurrency_group(""));
+        assert!(!validate_concurrency_group("Heavy Java"));
+        assert!(!validate_concurrency_group(&"a".repeat(65)));
+    }
+}
@@ -34,6 +34,10 @@ pub enum BuildStatus {
     Building,
     Success,
     Failed,
+    /// dry-run 빌드 성공 (배포 없이 빌드/산출물 검증만 완료)
+    Verified,
+    /// 빌드 성공, 배포 허용 시간대 밖이라 다음 창이 열릴 때까지 배포 대기
+    Held,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -51,6 +55,8 @@ impl std::fmt::Display for BuildStatus {
             BuildStatus::Building => write!(f, "Building"),
             BuildStatus::Success => write!(f, "Success"),
             BuildStatus::Failed => write!(f, "Failed"),
+            BuildStatus::Verified => write!(f, "Verified"),
+            BuildStatus::Held => write!(f, "Held"),
         }
     }
 }
@@ -64,6 +70,8 @@ impl std::str::FromStr for BuildStatus {
             "Building" => Ok(BuildStatus::Building),
             "Success" => Ok(BuildStatus::Success),
             "Failed" => Ok(BuildStatus::Failed),
+            "Verified" => Ok(BuildStatus::Verified),
+            "Held" => Ok(BuildStatus::Held),
             // 하위 호환성: 기존 Deploying 상태는 미완료로 간주하여 Failed로 처리
             // (배포 중 크래시/중단된 경우이므로 성공이 아님)
             "Deploying" => Ok(BuildStatus::Failed),
@@ -148,18 +156,830 @@ pub struct Project {
     // GitHub PAT
     pub github_pat_id: Option<i64>,
 
-    // GitHub webhook
+    // GitHub/GitLab webhook
     pub github_webhook_id: Option<i64>,
 
     // Discord webhook
     pub discord_webhook_id: Option<i64>,
 
+    // Slack webhook (Discord webhook과 함께 사용 가능)
+    pub slack_webhook_id: Option<i64>,
+
+    // Lifecycle hooks (JSON string, see ProjectHooks)
+    pub hooks: Option<String>,
+
+    // Disk quota (MB, None = 전역 기본값)
+    pub disk_quota_mb: Option<i64>,
+
+    // Test sharding (JSON string, see ProjectTestConfig)
+    pub test_config: Option<String>,
+
+    // 설정 버전 (낙관적 동시성 제어, 설정 변경마다 증가)
+    pub version: i64,
+
+    // Soft delete 시각 (None이면 활성, 유예 기간 후 purge)
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize_option")]
+    pub deleted_at: Option<String>,
+
+    // 보관 시각 (None이면 활성). 보관 중에는 컨테이너가 없고 빌드/webhook이 비활성
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize_option")]
+    pub archived_at: Option<String>,
+
+    // 소스 가져오기 방식 (see SourceFetch)
+    #[sqlx(try_from = "String")]
+    pub source_fetch: SourceFetch,
+
+    // 빌드 컨테이너 네트워크 (see BuildNetwork)
+    #[sqlx(try_from = "String")]
+    pub build_network: BuildNetwork,
+
+    // 빌드 컨테이너에서 docker 명령 허용 (DOOD, socket proxy 경유). 기본 false
+    pub docker_access: bool,
+
+    // 빌드 동시 실행 그룹. 같은 그룹의 빌드는 그룹 한도(settings)까지만 동시에 실행
+    pub concurrency_group: Option<String>,
+
+    // 배포 허용 시간대 (JSON string, see DeployWindow). None이면 항상 배포
+    pub deploy_window: Option<String>,
+
+    // GitHub commit status 보고 설정 (JSON string, see ProjectCommitStatus). None이면 보고 안 함
+    pub commit_status: Option<String>,
+
+    // 빌드/런타임 이미지의 upstream digest가 바뀌면 자동 재빌드. 기본 false
+    pub auto_rebuild_on_image_update: bool,
+
+    // 마지막 베이스 이미지 업데이트 확인 결과 (JSON string, see ImageUpdateStatus). None이면 아직 확인 안 함
+    pub image_update_status: Option<String>,
+
+    // 런타임 컨테이너의 blue/green 포트를 호스트에 바인딩할지. false면 프록시(Docker 네트워크)로만 접근. 기본 true
+    pub expose_host_port: bool,
+
+    // 내부 전용. 프록시 라우팅/서브도메인 없이 easycicd 네트워크에서 alias로만 접근 (see network_aliases). 기본 false
+    pub internal_only: bool,
+
+    // 의존하는 독립 컨테이너/프로젝트 (JSON string, see ProjectDependencies). 배포 시 SERVICE_* 환경 변수로 주입
+    pub dependencies: Option<String>,
+
+    // GitHub에서 서명이 검증된(GPG/SSH) 커밋만 배포. 검증되지 않은 커밋은 빌드만 하고 배포 단계에서 막음. 기본 false
+    pub require_signed_commits: bool,
+
+    // 웜 스탠바이: 전환 후 이전 빌드 컨테이너를 비활성 슬롯에 계속 실행해 두어 수동 전환/롤백을 즉시 처리. 기본 false
+    pub warm_standby: bool,
+
+    // 트래픽 섀도잉: 배포 전환 전 shadow_duration_secs 동안 운영 요청의 shadow_traffic_percent%(GET/HEAD/OPTIONS)를
+    // 새 컨테이너로 복제해 응답 비교. 0이면 사용 안 함
+    pub shadow_traffic_percent: i32,
+    pub shadow_duration_secs: i32,
+
+    // Bitbucket webhook (hook UUID)
+    pub webhook_uuid: Option<String>,
+
+    // PR 프리뷰: GitHub pull_request 이벤트마다 PR 브랜치를 project-{id}-pr-{number} 컨테이너로 실행. 기본 false
+    pub pr_previews: bool,
+
+    // 배포 전략 (see DeploymentStrategy)
+    #[sqlx(try_from = "String")]
+    pub deployment_strategy: DeploymentStrategy,
+
+    // 진행 중인 카나리 빌드 (비활성 슬롯)와 그 빌드로 보내는 트래픽 비율(%). 카나리가 없으면 None/0
+    pub canary_build_id: Option<i64>,
+    pub canary_weight: i32,
+
+    // 런타임 컨테이너를 추가로 연결할 사용자 Docker 네트워크 (JSON 배열, see parsed_extra_networks)
+    pub extra_networks: Option<String>,
+
+    // 빌드 파이프라인 (JSON 배열, see parsed_pipeline). 있으면 build_command 대신 단계별로 실행
+    pub pipeline: Option<String>,
+
+    // 런타임 컨테이너 exec 형식 ENTRYPOINT/CMD (JSON 배열, see runtime_exec). 있으면 runtime_command 대신 사용
+    pub runtime_entrypoint: Option<String>,
+    pub runtime_args: Option<String>,
+
+    // 런타임 컨테이너 재시작 정책 (see runtime_restart, 다음 배포부터 적용)
+    #[sqlx(try_from = "String")]
+    pub runtime_restart_policy: RestartPolicy,
+    pub runtime_restart_max_retries: Option<i64>,
+
+    // true면 런타임 컨테이너를 기본 네트워크 대신 전용 네트워크(easycicd-project-{id})에 연결 (다음 배포부터 적용)
+    pub network_isolation: bool,
+    // 전용 네트워크에 함께 붙는 프로젝트 ID (JSON 배열, see parsed_network_peers)
+    pub network_peers: Option<String>,
+
+    // Dockerfile 경로 (working_directory 기준). 있으면 소스로 런타임 이미지를 빌드해 그대로 실행
+    pub dockerfile_path: Option<String>,
+
+    // 빌드 보존 정책 (build_retention 워커). 최근 성공 빌드 수 / 최대 보존 일수, 둘 다 None이면 정리 안 함
+    pub build_retention_count: Option<i64>,
+    pub build_retention_days: Option<i64>,
+
+    // 프로젝트 노트 (Markdown: 배포 런북, 온콜 정보)와 마지막 수정 시각/수정자
+    pub notes: Option<String>,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize_option")]
+    pub notes_updated_at: Option<String>,
+    pub notes_updated_by: Option<String>,
+
+    // 이메일 알림 수신자 (JSON 배열, see parsed_notification_emails). 비어 있으면 메일 안 보냄
+    pub notification_emails: Option<String>,
+
     // Timestamps
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
     pub created_at: String,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
     pub updated_at: String,
 }
 
-impl Project {
+/// 프로젝트 lifecycle hook 명령어 (projects.hooks 컬럼의 JSON)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectHooks {
+    pub pre_build: Option<String>,
+    pub post_build: Option<String>,
+    pub post_deploy_success: Option<String>,
+    pub post_deploy_failure: Option<String>,
+}
+
+impl ProjectHooks {
+    pub fn command(&self, stage: HookStage) -> Option<&str> {
+        let cmd = match stage {
+            HookStage::PreBuild => &self.pre_build,
+            HookStage::PostBuild => &self.post_build,
+            HookStage::PostDeploySuccess => &self.post_deploy_success,
+            HookStage::PostDeployFailure => &self.post_deploy_failure,
+        };
+        cmd.as_deref().filter(|c| !c.trim().is_empty())
+    }
+}
+
+/// 성공한 빌드를 운영에 반영하는 방식
+///
+/// - BlueGreen: 비활성 슬롯에 띄운 뒤 트래픽을 한 번에 전환
+/// - Canary: 비활성 슬롯에 띄운 뒤 트래픽 일부만 보내고, 비율을 단계적으로 올려 100%에서 전환 (오류율이 높으면 중단)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DeploymentStrategy {
+    #[default]
+    BlueGreen,
+    Canary,
+}
+
+impl std::fmt::Display for DeploymentStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeploymentStrategy::BlueGreen => write!(f, "blue_green"),
+            DeploymentStrategy::Canary => write!(f, "canary"),
+        }
+    }
+}
+
+impl std::str::FromStr for DeploymentStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "blue_green" => Ok(DeploymentStrategy::BlueGreen),
+            "canary" => Ok(DeploymentStrategy::Canary),
+            _ => Err(format!("Invalid deployment strategy: {}", s)),
+        }
+    }
+}
+
+impl TryFrom<String> for DeploymentStrategy {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// 컨테이너 종료 시 Docker 재시작 정책 (Docker 이름 그대로 저장)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum RestartPolicy {
+    No,
+    OnFailure,
+    #[default]
+    UnlessStopped,
+    Always,
+}
+
+impl std::fmt::Display for RestartPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestartPolicy::No => write!(f, "no"),
+            RestartPolicy::OnFailure => write!(f, "on-failure"),
+            RestartPolicy::UnlessStopped => write!(f, "unless-stopped"),
+            RestartPolicy::Always => write!(f, "always"),
+        }
+    }
+}
+
+impl std::str::FromStr for RestartPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "no" => Ok(RestartPolicy::No),
+            "on-failure" => Ok(RestartPolicy::OnFailure),
+            "unless-stopped" => Ok(RestartPolicy::UnlessStopped),
+            "always" => Ok(RestartPolicy::Always),
+            _ => Err(format!("Invalid restart policy: {}", s)),
+        }
+    }
+}
+
+impl TryFrom<String> for RestartPolicy {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// on-failure 최대 재시도 횟수 상한
+pub const MAX_RESTART_RETRIES: i64 = 100;
+
+/// 재시작 정책과 on-failure 최대 재시도 횟수 (None이면 무제한, 다른 정책에서는 무시)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RestartConfig {
+    pub policy: RestartPolicy,
+    pub max_retries: Option<i64>,
+}
+
+impl RestartConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_retries.is_some_and(|n| !(1..=MAX_RESTART_RETRIES).contains(&n)) {
+            return Err(format!("restart_max_retries must be between 1 and {}", MAX_RESTART_RETRIES));
+        }
+        Ok(())
+    }
+}
+
+/// 빌드 컨테이너로 소스를 가져오는 방식
+///
+/// - Git: 컨테이너 안에서 git clone (PAT는 GIT_CLONE_TOKEN 환경변수로 전달)
+/// - Tarball: 서버가 GitHub API로 tarball을 받아 읽기 전용으로 마운트 (PAT가 컨테이너에 들어가지 않음)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SourceFetch {
+    #[default]
+    Git,
+    Tarball,
+}
+
+impl std::fmt::Display for SourceFetch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SourceFetch::Git => write!(f, "git"),
+            SourceFetch::Tarball => write!(f, "tarball"),
+        }
+    }
+}
+
+impl std::str::FromStr for SourceFetch {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "git" => Ok(SourceFetch::Git),
+            "tarball" => Ok(SourceFetch::Tarball),
+            _ => Err(format!("Invalid source fetch mode: {}", s)),
+        }
+    }
+}
+
+impl TryFrom<String> for SourceFetch {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// 빌드/테스트 컨테이너의 네트워크
+///
+/// - Bridge: 기본 bridge (docker_access가 켜져 있으면 easycicd 네트워크에도 붙어 socket proxy로 docker build 가능)
+/// - Isolated: 빌드 전용 격리 네트워크. 외부 인터넷만 가능하고 socket proxy/다른 컨테이너에 접근 불가
+/// - None: 네트워크 없음. 의존성은 캐시 예열로 미리 받아두고, 소스는 항상 tarball로 마운트
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BuildNetwork {
+    #[default]
+    Bridge,
+    Isolated,
+    None,
+}
+
+impl std::fmt::Display for BuildNetwork {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildNetwork::Bridge => write!(f, "bridge"),
+            BuildNetwork::Isolated => write!(f, "isolated"),
+            BuildNetwork::None => write!(f, "none"),
+        }
+    }
+}
+
+impl std::str::FromStr for BuildNetwork {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bridge" => Ok(BuildNetwork::Bridge),
+            "isolated" => Ok(BuildNetwork::Isolated),
+            "none" => Ok(BuildNetwork::None),
+            _ => Err(format!("Invalid build network: {}", s)),
+        }
+    }
+}
+
+impl TryFrom<String> for BuildNetwork {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// 최대 테스트 shard 수 (shard마다 빌드 컨테이너 하나)
+pub const MAX_TEST_SHARDS: u32 = 16;
+
+/// 테스트 단계 설정 (projects.test_config 컬럼의 JSON)
+///
+/// 빌드 성공 후 `command`를 `shards`개의 컨테이너에서 병렬 실행한다.
+/// 각 컨테이너에는 SHARD_INDEX(0부터), SHARD_NUMBER(1부터), SHARD_COUNT가 주어진다.
+///
+/// `retry_failed_command`가 있으면 실패한 shard에서 한 번 더 실행한다.
+/// 이때 JUnit 리포트에서 찾은 실패 테스트 이름이 FAILED_TESTS(공백 구분)로 주어진다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectTestConfig {
+    pub command: String,
+    #[serde(default = "default_test_shards")]
+    pub shards: u32,
+    #[serde(default)]
+    pub retry_failed_command: Option<String>,
+}
+
+fn default_test_shards() -> u32 {
+    1
+}
+
+/// exec 형식 인자 최대 개수
+pub const MAX_EXEC_ARGS: usize = 100;
+
+/// exec 형식 실행 설정: `/bin/sh -c` 없이 ENTRYPOINT/CMD를 그대로 지정
+///
+/// 둘 중 하나라도 있으면 셸 명령(command/runtime_command) 대신 사용한다.
+/// entrypoint만 지정하면 CMD는 비어 있고, args만 지정하면 이미지 ENTRYPOINT에 인자로 전달된다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecForm {
+    pub entrypoint: Option<Vec<String>>,
+    pub args: Option<Vec<String>>,
+}
+
+impl ExecForm {
+    /// JSON 배열 컬럼에서 읽음 (잘못된 값은 없는 것으로)
+    pub fn from_json(entrypoint: Option<&str>, args: Option<&str>) -> Self {
+        let parse = |json: Option<&str>| json.and_then(|j| serde_json::from_str::<Vec<String>>(j).ok());
+        Self { entrypoint: parse(entrypoint), args: parse(args) }
+    }
+
+    pub fn is_set(&self) -> bool {
+        self.entrypoint.is_some() || self.args.is_some()
+    }
+}
+
+/// exec 형식 배열 검증: 개수, NUL 문자. entrypoint는 빈 배열 불가
+pub fn validate_exec_args(field: &str, values: &[String], allow_empty: bool) -> Result<(), String> {
+    if values.is_empty() && !allow_empty {
+        return Err(format!("{} must not be empty", field));
+    }
+    if values.len() > MAX_EXEC_ARGS {
+        return Err(format!("{} can have at most {} items", field, MAX_EXEC_ARGS));
+    }
+    if values.iter().any(|v| v.contains('\0')) {
+        return Err(format!("{} must not contain NUL characters", field));
+    }
+    Ok(())
+}
+
+/// Dockerfile 경로 검사 (working_directory 기준 상대 경로, 상위 디렉토리 참조 불가)
+pub fn validate_dockerfile_path(path: &str) -> Result<(), String> {
+    let valid = !path.trim().is_empty()
+        && path.len() <= 255
+        && !path.starts_with('/')
+        && !path.contains('\0')
+        && path.split('/').all(|part| part != "..");
+    if !valid {
+        return Err(format!("invalid dockerfile_path '{}' (relative path inside the repository)", path));
+    }
+    Ok(())
+}
+
+/// 최대 파이프라인 단계 수
+pub const MAX_PIPELINE_STAGES: usize = 20;
+
+/// 파이프라인 단계 하나 (projects.pipeline 컬럼의 JSON 배열 원소)
+///
+/// 단계들은 같은 빌드 컨테이너에서 순서대로 실행되어 작업 디렉토리를 공유한다.
+/// 한 단계가 실패하면 나머지 단계는 실행하지 않는다 (skipped).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PipelineStage {
+    pub name: String,
+    pub command: String,
+}
+
+/// 파이프라인 검증: 단계 수, 이름 형식 ([A-Za-z0-9_-], 중복 불가), 빈 명령
+pub fn validate_pipeline(stages: &[PipelineStage]) -> Result<(), String> {
+    if stages.is_empty() {
+        return Err("pipeline must have at least one stage".to_string());
+    }
+    if stages.len() > MAX_PIPELINE_STAGES {
+        return Err(format!("pipeline can have at most {} stages", MAX_PIPELINE_STAGES));
+    }
+    let mut names = std::collections::HashSet::new();
+    for stage in stages {
+        if stage.name.is_empty()
+            || stage.name.len() > 64
+            || !stage.name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err(format!("Invalid stage name '{}': use 1-64 letters, digits, '_' or '-'", stage.name));
+        }
+        if !names.insert(stage.name.as_str()) {
+            return Err(format!("Duplicate stage name '{}'", stage.name));
+        }
+        if stage.command.trim().is_empty() {
+            return Err(format!("Stage '{}' has an empty command", stage.name));
+        }
+    }
+    Ok(())
+}
+
+/// GitHub commit status 보고 설정 (projects.commit_status 컬럼의 JSON)
+///
+/// branch protection에서 required check로 지정할 context 이름을 정한다.
+/// `separate_deploy`가 false면 `context` 하나로 빌드~배포 전체 결과를 보고하고,
+/// true면 `{context}/build`와 `{context}/deploy`를 따로 보고한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectCommitStatus {
+    #[serde(default = "default_commit_status_context")]
+    pub context: String,
+    #[serde(default)]
+    pub separate_deploy: bool,
+}
+
+fn default_commit_status_context() -> String {
+    "easyCICD".to_string()
+}
+
+impl ProjectCommitStatus {
+    /// 빌드 결과를 보고할 context
+    pub fn build_context(&self) -> String {
+        if self.separate_deploy {
+            format!("{}/build", self.context)
+        } else {
+            self.context.clone()
+        }
+    }
+
+    /// 배포 결과를 보고할 context
+    pub fn deploy_context(&self) -> String {
+        if self.separate_deploy {
+            format!("{}/deploy", self.context)
+        } else {
+            self.context.clone()
+        }
+    }
+}
+
+/// 프로젝트가 의존하는 서비스 (projects.dependencies 컬럼의 JSON)
+///
+/// 배포할 때 각 대상의 `SERVICE_<NAME>_HOST`/`SERVICE_<NAME>_PORT`가 런타임 환경 변수로 주입된다
+/// (이름은 대문자, 영숫자 외 문자는 '_'). 사용자가 runtime_env_vars에 같은 이름을 지정하면 그 값이 우선.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectDependencies {
+    /// 독립 컨테이너 이름
+    #[serde(default)]
+    pub containers: Vec<String>,
+    /// 프로젝트 이름
+    #[serde(default)]
+    pub projects: Vec<String>,
+}
+
+/// 베이스 이미지 업데이트 확인 결과 (projects.image_update_status 컬럼의 JSON)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageUpdateStatus {
+    /// 확인 시각 (UTC, `YYYY-MM-DD HH:MM:SS`)
+    pub checked_at: String,
+    pub images: Vec<ImageUpdate>,
+}
+
+impl ImageUpdateStatus {
+    pub fn update_available(&self) -> bool {
+        self.images.iter().any(|i| i.update_available)
+    }
+}
+
+/// 이미지 하나의 업데이트 확인 결과
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImageUpdate {
+    /// "build" 또는 "runtime"
+    pub kind: String,
+    pub image: String,
+    /// 현재 사용 중인 digest (`sha256:...`). runtime은 서비스 중인 빌드에 고정된 digest
+    pub current_digest: Option<String>,
+    /// 레지스트리의 최신 digest (조회 실패 시 None)
+    pub latest_digest: Option<String>,
+    pub update_available: bool,
+}
+
+impl ImageUpdate {
+    pub fn new(kind: &str, image: &str, current_digest: Option<String>, latest_digest: Option<String>) -> Self {
+        let update_available = matches!((&current_digest, &latest_digest), (Some(c), Some(l)) if c != l);
+        Self {
+            kind: kind.to_string(),
+            image: image.to_string(),
+            current_digest,
+            latest_digest,
+            update_available,
+        }
+    }
+}
+
+/// 배포 허용 시간대 (projects.deploy_window 컬럼의 JSON)
+///
+/// 표시 타임존 기준. `days`는 "mon".."sun", `start`/`end`는 "HH:MM".
+/// `end`가 `start`보다 이르면 다음 날로 넘어가는 창 (예: 22:00~06:00), 같으면 하루 종일.
+/// 창 밖에서 성공한 빌드는 Held 상태로 대기하다가 다음 창이 열리면 자동 배포된다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeployWindow {
+    pub days: Vec<String>,
+    pub start: String,
+    pub end: String,
+}
+
+/// shard 하나의 실행 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestShardResult {
+    pub index: u32,
+    pub success: bool,
+    pub exit_code: i64,
+    pub duration_ms: u64,
+    /// 실패한 테스트만 재실행했는지 여부
+    #[serde(default)]
+    pub retried: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TestCaseStatus {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+impl std::fmt::Display for TestCaseStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TestCaseStatus::Passed => write!(f, "passed"),
+            TestCaseStatus::Failed => write!(f, "failed"),
+            TestCaseStatus::Skipped => write!(f, "skipped"),
+        }
+    }
+}
+
+impl std::str::FromStr for TestCaseStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "passed" => Ok(TestCaseStatus::Passed),
+            "failed" => Ok(TestCaseStatus::Failed),
+            "skipped" => Ok(TestCaseStatus::Skipped),
+            _ => Err(format!("Invalid test case status: {}", s)),
+        }
+    }
+}
+
+/// 테스트 케이스 하나의 결과 (test_results 테이블)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestCaseResult {
+    pub name: String,
+    pub status: TestCaseStatus,
+    pub duration_ms: i64,
+    /// 1 = 최초 실행, 2 = 실패 테스트 재실행
+    pub attempt: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BuildStageStatus {
+    Completed,
+    Failed,
+    Skipped,
+}
+
+impl std::fmt::Display for BuildStageStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildStageStatus::Completed => write!(f, "completed"),
+            BuildStageStatus::Failed => write!(f, "failed"),
+            BuildStageStatus::Skipped => write!(f, "skipped"),
+        }
+    }
+}
+
+impl std::str::FromStr for BuildStageStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "completed" => Ok(BuildStageStatus::Completed),
+            "failed" => Ok(BuildStageStatus::Failed),
+            "skipped" => Ok(BuildStageStatus::Skipped),
+            _ => Err(format!("Invalid build stage status: {}", s)),
+        }
+    }
+}
+
+/// 파이프라인 단계 하나의 실행 기록 (build_stages 테이블)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildStageResult {
+    pub position: i64,
+    pub name: String,
+    pub status: BuildStageStatus,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize_option")]
+    pub started_at: Option<String>,
+    pub duration_ms: Option<i64>,
+    /// 빌드 로그 안의 단계 구간 (0부터, 양끝 포함)
+    pub log_start_line: Option<i64>,
+    pub log_end_line: Option<i64>,
+}
+
+/// 빌드의 테스트 단계 결과 (builds.test_summary 컬럼의 JSON)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestSummary {
+    pub shard_count: u32,
+    pub passed: u32,
+    pub failed: u32,
+    pub shards: Vec<TestShardResult>,
+}
+
+impl TestSummary {
+    pub fn from_shards(mut shards: Vec<TestShardResult>) -> Self {
+        shards.sort_by_key(|s| s.index);
+        let passed = shards.iter().filter(|s| s.success).count() as u32;
+        Self {
+            shard_count: shards.len() as u32,
+            passed,
+            failed: shards.len() as u32 - passed,
+            shards,
+        }
+    }
+
+    pub fn success(&self) -> bool {
+        self.failed == 0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookStage {
+    PreBuild,
+    PostBuild,
+    PostDeploySuccess,
+    PostDeployFailure,
+}
+
+impl std::fmt::Display for HookStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HookStage::PreBuild => write!(f, "pre-build"),
+            HookStage::PostBuild => write!(f, "post-build"),
+            HookStage::PostDeploySuccess => write!(f, "post-deploy-success"),
+            HookStage::PostDeployFailure => write!(f, "post-deploy-failure"),
+        }
+    }
+}
+
+impl Project {
+    /// 등록된 push webhook ID (GitHub/GitLab은 숫자, Bitbucket은 UUID)
+    pub fn webhook_id(&self) -> Option<String> {
+        self.github_webhook_id
+            .map(|id| id.to_string())
+            .or_else(|| self.webhook_uuid.clone())
+    }
+
+    /// hooks JSON 파싱 (없거나 잘못된 경우 빈 설정)
+    pub fn parsed_hooks(&self) -> ProjectHooks {
+        self.hooks
+            .as_deref()
+            .and_then(|h| serde_json::from_str(h).ok())
+            .unwrap_or_default()
+    }
+
+    /// test_config JSON 파싱 (없거나 명령이 비어 있으면 None)
+    pub fn parsed_test_config(&self) -> Option<ProjectTestConfig> {
+        self.test_config
+            .as_deref()
+            .and_then(|c| serde_json::from_str::<ProjectTestConfig>(c).ok())
+            .filter(|c| !c.command.trim().is_empty())
+            .map(|mut c| {
+                c.shards = c.shards.clamp(1, MAX_TEST_SHARDS);
+                c
+            })
+    }
+
+    /// deploy_window JSON 파싱 (없거나 잘못된 값이면 None = 항상 배포)
+    pub fn parsed_deploy_window(&self) -> Option<DeployWindow> {
+        self.deploy_window
+            .as_deref()
+            .and_then(|w| serde_json::from_str::<DeployWindow>(w).ok())
+    }
+
+    /// commit_status JSON 파싱 (없거나 잘못된 값이면 None = 보고 안 함)
+    pub fn parsed_commit_status(&self) -> Option<ProjectCommitStatus> {
+        self.commit_status
+            .as_deref()
+            .and_then(|c| serde_json::from_str::<ProjectCommitStatus>(c).ok())
+    }
+
+    /// dependencies JSON 파싱 (없거나 잘못된 값이면 빈 의존)
+    pub fn parsed_dependencies(&self) -> ProjectDependencies {
+        self.dependencies
+            .as_deref()
+            .and_then(|d| serde_json::from_str::<ProjectDependencies>(d).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn parsed_extra_networks(&self) -> Vec<String> {
+        self.extra_networks
+            .as_deref()
+            .and_then(|n| serde_json::from_str::<Vec<String>>(n).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn parsed_notification_emails(&self) -> Vec<String> {
+        self.notification_emails
+            .as_deref()
+            .and_then(|e| serde_json::from_str::<Vec<String>>(e).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn runtime_exec(&self) -> ExecForm {
+        ExecForm::from_json(self.runtime_entrypoint.as_deref(), self.runtime_args.as_deref())
+    }
+
+    pub fn parsed_network_peers(&self) -> Vec<i64> {
+        self.network_peers
+            .as_deref()
+            .and_then(|p| serde_json::from_str(p).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn runtime_restart(&self) -> RestartConfig {
+        RestartConfig { policy: self.runtime_restart_policy, max_retries: self.runtime_restart_max_retries }
+    }
+
+    /// pipeline JSON 파싱 (없거나 잘못된 값이면 None = build_command 사용)
+    pub fn parsed_pipeline(&self) -> Option<Vec<PipelineStage>> {
+        self.pipeline
+            .as_deref()
+            .and_then(|p| serde_json::from_str::<Vec<PipelineStage>>(p).ok())
+            .filter(|stages| validate_pipeline(stages).is_ok())
+    }
+
+    pub fn parsed_image_update_status(&self) -> Option<ImageUpdateStatus> {
+        self.image_update_status
+            .as_deref()
+            .and_then(|s| serde_json::from_str::<ImageUpdateStatus>(s).ok())
+    }
+
+    /// 목록 필터/대시보드용 상태 요약
+    /// (archived, healthy, unhealthy, deploying, not_deployed)
+    pub fn health_state(&self) -> &'static str {
+        if self.archived_at.is_some() {
+            return "archived";
+        }
+        match self.deployment_status {
+            DeploymentStatus::Deployed => "healthy",
+            DeploymentStatus::Failed => "unhealthy",
+            DeploymentStatus::Deploying => "deploying",
+            DeploymentStatus::NotDeployed => "not_deployed",
+        }
+    }
+
+    /// easycicd 네트워크에서 서비스 중인 컨테이너를 가리키는 DNS alias (`{name}.internal`)
+    ///
+    /// 배포할 때 새 슬롯 컨테이너에 붙으므로 슬롯이 바뀌어도 같은 이름으로 접근할 수 있다.
+    /// 프로젝트 이름은 DNS 라벨로 정규화 (소문자, 영숫자 외 문자는 '-')
+    pub fn network_aliases(&self) -> Vec<String> {
+        let label: String = self
+            .name
+            .to_ascii_lowercase()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect();
+        vec![format!("{}.internal", label.trim_matches('-'))]
+    }
+
     pub fn get_active_port(&self) -> i32 {
         match self.active_slot {
             Slot::Blue => self.blue_port,
@@ -214,7 +1034,70 @@ pub struct Build {
 
     pub deployed_slot: Option<String>,
 
+    /// dry-run 빌드 여부 (true면 배포/슬롯 전환 생략)
+    pub dry_run: bool,
+
+    /// 테스트 단계 결과 (JSON string, see TestSummary)
+    pub test_summary: Option<String>,
+
+    /// 빌드를 시작한 주체 (see BuildTrigger). 이전 빌드는 None
+    pub triggered_by: Option<String>,
+
+    /// 빌드 컨테이너 최대 메모리 사용량 (bytes). 측정 전 빌드는 None
+    pub peak_memory_bytes: Option<i64>,
+    /// 빌드 컨테이너 누적 CPU 시간 (ms)
+    pub cpu_time_ms: Option<i64>,
+
+    /// 사용자 메모 (예: "hotfix for incident #12")
+    pub note: Option<String>,
+    /// 라벨 (JSON 배열 문자열, see parsed_labels)
+    pub labels: Option<String>,
+
+    /// 배포 시점에 digest로 고정한 런타임 이미지 (예: node@sha256:...). 배포 전/이전 빌드는 None
+    pub runtime_image_digest: Option<String>,
+
+    /// 원격 로그 전송 기록 (JSON string, see LogShipment). 전송하지 않은 빌드는 None
+    pub log_shipment: Option<String>,
+
+    /// 빌드 산출물 checksum/서명 (JSON string, see ArtifactChecksums). 성공 빌드만 기록
+    pub artifact_checksums: Option<String>,
+
+    /// 빌드는 성공했지만 배포가 막힌 이유 (예: 커밋 서명 미검증). 이 경우 상태는 Verified
+    pub deploy_blocked_reason: Option<String>,
+
+    /// Queued 상태로 기다린 시간 (ms). 실행 전/이전 빌드는 None
+    pub queue_wait_ms: Option<i64>,
+
+    /// 배포 전 트래픽 섀도잉 결과 (JSON string, see ShadowReport). 섀도잉하지 않은 빌드는 None
+    pub shadow_report: Option<String>,
+
+    /// 실제로 실행한 빌드 환경 (JSON string, see BuildEnvironment). 이후 프로젝트 설정이 바뀌어도 유지
+    pub build_environment: Option<String>,
+
+    /// 정확한 재빌드(rebuild-exact)면 환경 스냅샷과 커밋을 가져온 원본 빌드 ID
+    pub rebuild_of: Option<i64>,
+
+    /// 빌드한 브랜치 (webhook ref 또는 트리거 시점의 프로젝트 브랜치). 이전 빌드는 None
+    pub branch: Option<String>,
+
+    /// PR 프리뷰 빌드면 PR 번호. 프리뷰 컨테이너로만 실행되고 Blue/Green 배포와 롤백 대상에서 제외
+    pub preview_pr: Option<i64>,
+
+    /// 빌드 로그 줄 심각도별 개수 (JSON string, see LogLevelCounts). 분류 전/이전 빌드는 None
+    pub log_levels: Option<String>,
+
+    /// Dockerfile 빌드로 만든 런타임 이미지 태그 (`project-{id}:build-{n}`). 산출물 마운트 빌드는 None
+    pub image_tag: Option<String>,
+
+    /// 실패 원인 요약 (마지막 오류 줄, 알려진 컴파일러/테스트 패턴). 실패 알림에 포함. 성공/이전 빌드는 None
+    pub failure_summary: Option<String>,
+
+    /// LLM이 제안한 실패 진단 (FAILURE_DIAGNOSIS_URL 설정 시). 진단하지 않은 빌드는 None
+    pub diagnosis: Option<String>,
+
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
     pub started_at: String,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize_option")]
     pub finished_at: Option<String>,
 }
 
@@ -222,6 +1105,253 @@ impl Build {
     pub fn get_deployed_slot(&self) -> Option<Slot> {
         self.deployed_slot.as_ref().and_then(|s| s.parse().ok())
     }
+
+    /// 런타임 컨테이너 이미지: Dockerfile 빌드면 빌드한 이미지, 아니면 프로젝트 runtime_image
+    pub fn runtime_image<'a>(&'a self, project: &'a Project) -> &'a str {
+        self.image_tag.as_deref().unwrap_or(&project.runtime_image)
+    }
+
+    /// 런타임 컨테이너 /app에 마운트할 산출물 (Dockerfile 빌드는 이미지에 들어 있어 None)
+    pub fn runtime_output(&self, output_path: std::path::PathBuf) -> Option<std::path::PathBuf> {
+        self.image_tag.is_none().then_some(output_path)
+    }
+
+    /// log_shipment JSON 파싱
+    pub fn parsed_log_shipment(&self) -> Option<LogShipment> {
+        self.log_shipment.as_deref().and_then(|s| serde_json::from_str(s).ok())
+    }
+
+    /// build_environment JSON 파싱
+    pub fn parsed_build_environment(&self) -> Option<BuildEnvironment> {
+        self.build_environment.as_deref().and_then(|s| serde_json::from_str(s).ok())
+    }
+
+    /// log_levels JSON 파싱
+    pub fn parsed_log_levels(&self) -> Option<LogLevelCounts> {
+        self.log_levels.as_deref().and_then(|s| serde_json::from_str(s).ok())
+    }
+
+    /// shadow_report JSON 파싱
+    pub fn parsed_shadow_report(&self) -> Option<ShadowReport> {
+        self.shadow_report.as_deref().and_then(|s| serde_json::from_str(s).ok())
+    }
+
+    /// artifact_checksums JSON 파싱
+    pub fn parsed_artifact_checksums(&self) -> Option<ArtifactChecksums> {
+        self.artifact_checksums.as_deref().and_then(|s| serde_json::from_str(s).ok())
+    }
+
+    /// labels JSON 파싱 (없거나 잘못된 값이면 빈 목록)
+    pub fn parsed_labels(&self) -> Vec<String> {
+        self.labels
+            .as_deref()
+            .and_then(|l| serde_json::from_str(l).ok())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+impl Build {
+    /// 테스트용 빌드 (project 1, 나머지는 비어 있음). 필요한 필드는 `..Build::test(id, status)`로 덮어씀
+    pub fn test(id: i64, status: BuildStatus) -> Self {
+        Build {
+            id,
+            project_id: 1,
+            build_number: id,
+            commit_hash: "abc".to_string(),
+            commit_message: None,
+            author: None,
+            status,
+            log_path: String::new(),
+            deploy_log_path: None,
+            output_path: None,
+            deployed_slot: None,
+            dry_run: false,
+            test_summary: None,
+            triggered_by: None,
+            peak_memory_bytes: None,
+            cpu_time_ms: None,
+            note: None,
+            labels: None,
+            runtime_image_digest: None,
+            log_shipment: None,
+            artifact_checksums: None,
+            deploy_blocked_reason: None,
+            queue_wait_ms: None,
+            shadow_report: None,
+            build_environment: None,
+            rebuild_of: None,
+            branch: None,
+            preview_pr: None,
+            log_levels: None,
+            image_tag: None,
+            failure_summary: None,
+            diagnosis: None,
+            started_at: String::new(),
+            finished_at: None,
+        }
+    }
+}
+
+/// 빌드 로그 줄 심각도 (Info < Warn < Error)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Info,
+    #[serde(alias = "warning")]
+    Warn,
+    Error,
+}
+
+impl std::fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogLevel::Info => write!(f, "info"),
+            LogLevel::Warn => write!(f, "warn"),
+            LogLevel::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl std::str::FromStr for LogLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "info" => Ok(LogLevel::Info),
+            "warn" | "warning" => Ok(LogLevel::Warn),
+            "error" => Ok(LogLevel::Error),
+            _ => Err(format!("Invalid log level: {}", s)),
+        }
+    }
+}
+
+/// 빌드 로그 줄 심각도별 개수 (builds.log_levels)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogLevelCounts {
+    pub error: u64,
+    pub warn: u64,
+    pub info: u64,
+}
+
+impl LogLevelCounts {
+    pub fn add(&mut self, level: LogLevel) {
+        match level {
+            LogLevel::Info => self.info += 1,
+            LogLevel::Warn => self.warn += 1,
+            LogLevel::Error => self.error += 1,
+        }
+    }
+}
+
+/// 빌드 로그 심각도 분류 정규식 (settings의 log_level_patterns JSON). error를 먼저 검사하고 어디에도 맞지 않으면 info
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogLevelPatterns {
+    #[serde(default)]
+    pub error: Vec<String>,
+    #[serde(default)]
+    pub warn: Vec<String>,
+}
+
+/// 빌드 산출물 checksum (builds.artifact_checksums)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArtifactChecksums {
+    /// 파일 목록 전체의 SHA-256 (`sha256sum` 형식 manifest)
+    pub digest: String,
+    /// 산출물 디렉토리 기준 상대 경로 → SHA-256 (hex)
+    pub files: std::collections::BTreeMap<String, String>,
+    /// agent 키(Ed25519)로 digest에 서명한 값 (base64). 키가 없으면 None
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    /// 서명한 agent 공개키 (hex)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+}
+
+/// 원격 저장소로 보낸 로그 파일 하나
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShippedLogFile {
+    /// 파일 이름 (예: "build.log.gz")
+    pub file: String,
+    /// 원격 위치 (`https://...` 또는 `s3://bucket/key`)
+    pub location: String,
+    /// 원본 로그 SHA-256 (hex). 원격 사본의 변조 여부를 확인하는 기준
+    pub sha256: String,
+    /// gzip 압축본 SHA-256 (hex)
+    pub compressed_sha256: String,
+}
+
+/// 빌드 로그 원격 전송 기록 (builds.log_shipment)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogShipment {
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
+    pub shipped_at: String,
+    pub files: Vec<ShippedLogFile>,
+}
+
+/// 빌드 환경 스냅샷 (builds.build_environment). 빌드 컨테이너를 띄울 때의 값 그대로
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildEnvironment {
+    /// 프로젝트에 설정된 빌드 이미지 (태그)
+    pub image: String,
+    /// 실제로 실행한 이미지 (digest 고정 참조). 확인하지 못했으면 None
+    pub image_digest: Option<String>,
+    /// 컨테이너에서 실행한 전체 명령 (환경 변수 export, checkout, 산출물 복사 포함. 토큰은 가림)
+    pub command: String,
+    pub working_directory: String,
+    pub cache_type: String,
+    /// `{agent 경로}:{컨테이너 경로}[:ro]`
+    pub mounts: Vec<String>,
+    pub network: BuildNetwork,
+    pub docker_access: bool,
+    pub source_fetch: SourceFetch,
+}
+
+/// 배포 전 트래픽 섀도잉 결과 (builds.shadow_report). 운영 응답과 새 슬롯 응답 비교
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShadowReport {
+    pub percent: i64,
+    pub duration_secs: i64,
+    /// 복제한 요청 수
+    pub mirrored: usize,
+    /// 새 슬롯 요청 실패 (연결 오류/타임아웃)
+    pub shadow_errors: usize,
+    /// 상태 코드가 다른 응답 수
+    pub status_mismatches: usize,
+    pub primary_5xx: usize,
+    pub shadow_5xx: usize,
+    pub primary_p50_ms: u64,
+    pub primary_p95_ms: u64,
+    pub shadow_p50_ms: u64,
+    pub shadow_p95_ms: u64,
+    /// "운영 상태 -> 새 슬롯 상태" → 횟수
+    pub mismatches: std::collections::BTreeMap<String, usize>,
+}
+
+/// 빌드 메모 최대 길이
+pub const MAX_BUILD_NOTE_LEN: usize = 2000;
+/// 프로젝트 노트 최대 길이 (문자)
+pub const MAX_PROJECT_NOTES_LEN: usize = 65536;
+/// 프로젝트당 최대 이메일 알림 수신자 수
+pub const MAX_NOTIFICATION_EMAILS: usize = 20;
+/// 빌드당 최대 라벨 수
+pub const MAX_BUILD_LABELS: usize = 10;
+
+/// 라벨 정리: 앞뒤 공백 제거, 빈 값/중복 제거. 라벨은 1~32자
+pub fn normalize_build_labels(labels: &[String]) -> Result<Vec<String>, String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for label in labels.iter().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        if label.chars().count() > 32 {
+            return Err(format!("Label too long (max 32 characters): {}", label));
+        }
+        if !normalized.iter().any(|l| l == label) {
+            normalized.push(label.to_string());
+        }
+    }
+    if normalized.len() > MAX_BUILD_LABELS {
+        return Err(format!("Too many labels (max {})", MAX_BUILD_LABELS));
+    }
+    Ok(normalized)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -244,6 +1374,9 @@ pub struct CreateProject {
     pub runtime_env_vars: Option<String>,
     pub github_pat_id: Option<i64>,
     pub discord_webhook_id: Option<i64>,
+    pub hooks: Option<String>,
+    #[serde(default)]
+    pub dockerfile_path: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -268,6 +1401,68 @@ pub struct UpdateProject {
     pub github_pat_id: Option<Option<i64>>,
     #[serde(default)]
     pub discord_webhook_id: Option<Option<i64>>,
+    #[serde(default)]
+    pub slack_webhook_id: Option<Option<i64>>,
+    pub hooks: Option<String>,
+    #[serde(default)]
+    pub disk_quota_mb: Option<Option<i64>>,
+    #[serde(default)]
+    pub test_config: Option<Option<String>>,
+    #[serde(default)]
+    pub source_fetch: Option<SourceFetch>,
+    #[serde(default)]
+    pub build_network: Option<BuildNetwork>,
+    #[serde(default)]
+    pub docker_access: Option<bool>,
+    #[serde(default)]
+    pub concurrency_group: Option<Option<String>>,
+    #[serde(default)]
+    pub deploy_window: Option<Option<String>>,
+    #[serde(default)]
+    pub commit_status: Option<Option<String>>,
+    #[serde(default)]
+    pub auto_rebuild_on_image_update: Option<bool>,
+    #[serde(default)]
+    pub expose_host_port: Option<bool>,
+    #[serde(default)]
+    pub internal_only: Option<bool>,
+    #[serde(default)]
+    pub dependencies: Option<Option<String>>,
+    #[serde(default)]
+    pub require_signed_commits: Option<bool>,
+    #[serde(default)]
+    pub warm_standby: Option<bool>,
+    #[serde(default)]
+    pub shadow_traffic_percent: Option<i32>,
+    #[serde(default)]
+    pub shadow_duration_secs: Option<i32>,
+    #[serde(default)]
+    pub pr_previews: Option<bool>,
+    #[serde(default)]
+    pub deployment_strategy: Option<DeploymentStrategy>,
+    #[serde(default)]
+    pub extra_networks: Option<Option<String>>,
+    #[serde(default)]
+    pub pipeline: Option<Option<String>>,
+    #[serde(default)]
+    pub runtime_entrypoint: Option<Option<String>>,
+    #[serde(default)]
+    pub runtime_args: Option<Option<String>>,
+    #[serde(default)]
+    pub runtime_restart_policy: Option<RestartPolicy>,
+    #[serde(default)]
+    pub runtime_restart_max_retries: Option<Option<i64>>,
+    #[serde(default)]
+    pub dockerfile_path: Option<Option<String>>,
+    #[serde(default)]
+    pub build_retention_count: Option<Option<i64>>,
+    #[serde(default)]
+    pub build_retention_days: Option<Option<i64>>,
+    #[serde(default)]
+    pub notification_emails: Option<Option<String>>,
+    /// 클라이언트가 마지막으로 본 version. 다르면 ProjectVersionConflict (None이면 검사 생략)
+    #[serde(default)]
+    pub expected_version: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -276,6 +1471,52 @@ pub struct CreateBuild {
     pub commit_hash: String,
     pub commit_message: Option<String>,
     pub author: Option<String>,
+    #[serde(default)]
+    pub dry_run: bool,
+    #[serde(default)]
+    pub triggered_by: Option<String>,
+    /// 원본 빌드의 환경 스냅샷과 커밋으로 재실행 (see Build::rebuild_of)
+    #[serde(default)]
+    pub rebuild_of: Option<i64>,
+    #[serde(default)]
+    pub branch: Option<String>,
+    /// PR 프리뷰 빌드 (see Build::preview_pr)
+    #[serde(default)]
+    pub preview_pr: Option<i64>,
+}
+
+/// 빌드 트리거 주체. `builds.triggered_by`에 문자열로 저장된다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildTrigger {
+    /// GitHub push webhook
+    Webhook,
+    /// POST /api/projects/{id}/simulate-webhook
+    SimulatedWebhook,
+    /// UI/API에서 수동 실행 (로그인 사용자 이메일, 모르면 None)
+    Manual(Option<String>),
+    /// 토큰 인증 API (gRPC 등)
+    ApiToken(String),
+    /// Slack/Discord slash command (채팅 서비스, 연결된 사용자 이메일)
+    Chat { provider: String, email: String },
+    /// 베이스 이미지 업데이트 감지 후 자동 재빌드
+    ImageUpdate,
+    /// GitHub pull_request webhook (PR 프리뷰 빌드)
+    PullRequest(i64),
+}
+
+impl std::fmt::Display for BuildTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildTrigger::Webhook => write!(f, "webhook"),
+            BuildTrigger::SimulatedWebhook => write!(f, "webhook:simulated"),
+            BuildTrigger::Manual(Some(email)) => write!(f, "manual:{}", email),
+            BuildTrigger::Manual(None) => write!(f, "manual"),
+            BuildTrigger::ApiToken(name) => write!(f, "api-token:{}", name),
+            BuildTrigger::Chat { provider, email } => write!(f, "chat:{}:{}", provider, email),
+            BuildTrigger::ImageUpdate => write!(f, "image-update"),
+            BuildTrigger::PullRequest(number) => write!(f, "pull-request:{}", number),
+        }
+    }
 }
 
 // Try conversion for Slot from String (for sqlx)
@@ -478,10 +1719,236 @@ pub struct Container {
     pub protocol_type: ProtocolType,  // tcp or http
     #[sqlx(try_from = "String")]
     pub status: ContainerStatus,
+    pub health_check: Option<String>,  // JSON (ContainerHealthCheck)
+    pub health_status: Option<String>,  // ContainerHealth (헬스체크가 없거나 중지 상태면 NULL)
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize_option")]
+    pub health_checked_at: Option<String>,
+    pub log_max_size_mb: Option<i64>,  // 로그 보관 용량 (NULL이면 기본값)
+    pub log_retention_days: Option<i64>,  // 로그 보관 기간 (NULL이면 기본값)
+    pub extra_ports: Option<String>,  // JSON 배열 (PortMapping), 기본 port/container_port 외 추가 매핑
+    pub entrypoint: Option<String>,  // JSON 배열, exec 형식 ENTRYPOINT (see exec_form)
+    pub args: Option<String>,  // JSON 배열, exec 형식 CMD
+    #[sqlx(try_from = "String")]
+    pub restart_policy: RestartPolicy,
+    pub restart_max_retries: Option<i64>,  // on-failure 최대 재시도 (NULL이면 무제한)
+    pub network_group: Option<String>,  // 전용 네트워크 그룹 (easycicd-group-{name}, NULL이면 기본 네트워크)
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
     pub created_at: String,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
     pub updated_at: String,
 }
 
+impl Container {
+    pub fn exec_form(&self) -> ExecForm {
+        ExecForm::from_json(self.entrypoint.as_deref(), self.args.as_deref())
+    }
+
+    pub fn restart(&self) -> RestartConfig {
+        RestartConfig { policy: self.restart_policy, max_retries: self.restart_max_retries }
+    }
+
+    pub fn parsed_extra_ports(&self) -> Vec<PortMapping> {
+        self.extra_ports
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default()
+    }
+
+    /// 컨테이너가 쓰는 호스트 포트 (기본 포트 + 추가 매핑)
+    pub fn host_ports(&self) -> Vec<i32> {
+        std::iter::once(self.port)
+            .chain(self.parsed_extra_ports().into_iter().map(|m| m.host_port))
+            .collect()
+    }
+}
+
+/// 포트 매핑 전송 프로토콜
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum PortProtocol {
+    #[default]
+    Tcp,
+    Udp,
+}
+
+impl std::fmt::Display for PortProtocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PortProtocol::Tcp => write!(f, "tcp"),
+            PortProtocol::Udp => write!(f, "udp"),
+        }
+    }
+}
+
+/// 독립 컨테이너 추가 포트 매핑 (host_port:container_port/protocol)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PortMapping {
+    pub host_port: i32,
+    pub container_port: i32,
+    #[serde(default)]
+    pub protocol: PortProtocol,
+}
+
+/// 추가 포트 매핑 요청. host_port가 없으면 컨테이너 포트 범위(15000-19999)에서 할당
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PortMappingRequest {
+    #[serde(default)]
+    pub host_port: Option<i32>,
+    pub container_port: i32,
+    #[serde(default)]
+    pub protocol: PortProtocol,
+}
+
+/// 컨테이너당 최대 추가 포트 매핑 수
+pub const MAX_EXTRA_PORTS: usize = 32;
+
+/// 추가 포트 매핑 검증: 포트 범위, 같은 컨테이너 포트/프로토콜 중복 (기본 매핑은 tcp), 지정한 호스트 포트 중복
+pub fn validate_port_mappings(container_port: i32, mappings: &[PortMappingRequest]) -> Result<(), String> {
+    if mappings.len() > MAX_EXTRA_PORTS {
+        return Err(format!("At most {} extra port mappings are allowed", MAX_EXTRA_PORTS));
+    }
+    let mut container_ports = std::collections::HashSet::from([(container_port, PortProtocol::Tcp)]);
+    let mut host_ports = std::collections::HashSet::new();
+    for mapping in mappings {
+        if !(1..=65535).contains(&mapping.container_port) {
+            return Err(format!("Invalid container port {}", mapping.container_port));
+        }
+        if !container_ports.insert((mapping.container_port, mapping.protocol)) {
+            return Err(format!("Container port {}/{} is mapped more than once", mapping.container_port, mapping.protocol));
+        }
+        if let Some(host_port) = mapping.host_port {
+            if !(1024..=65535).contains(&host_port) {
+                return Err(format!("Host port {} must be between 1024 and 65535", host_port));
+            }
+            if !host_ports.insert(host_port) {
+                return Err(format!("Host port {} is mapped more than once", host_port));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 독립 컨테이너 헬스체크 방식
+///
+/// - tcp: 컨테이너 포트에 TCP 연결
+/// - http: `http://container-{name}:{port}{path}`에 GET (2xx/3xx 또는 `expected_status`)
+/// - command: 컨테이너 안에서 `sh -c`로 실행해 exit code 0이면 성공
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum HealthProbe {
+    Tcp,
+    Http {
+        #[serde(default = "default_health_path")]
+        path: String,
+        #[serde(default)]
+        expected_status: Option<u16>,
+    },
+    Command {
+        command: String,
+    },
+}
+
+fn default_health_path() -> String {
+    "/".to_string()
+}
+
+/// 독립 컨테이너 헬스체크 설정 (Docker HEALTHCHECK와 같은 의미)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ContainerHealthCheck {
+    #[serde(flatten)]
+    pub probe: HealthProbe,
+    #[serde(default = "default_health_interval")]
+    pub interval_secs: u64,
+    #[serde(default = "default_health_timeout")]
+    pub timeout_secs: u64,
+    /// 연속 실패가 이 횟수에 도달하면 unhealthy
+    #[serde(default = "default_health_retries")]
+    pub retries: u32,
+    /// 시작 직후 이 시간 동안의 실패는 세지 않음 (starting)
+    #[serde(default)]
+    pub start_period_secs: u64,
+}
+
+fn default_health_interval() -> u64 {
+    30
+}
+
+fn default_health_timeout() -> u64 {
+    5
+}
+
+fn default_health_retries() -> u32 {
+    3
+}
+
+impl ContainerHealthCheck {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(5..=3600).contains(&self.interval_secs) {
+            return Err("interval_secs must be between 5 and 3600".to_string());
+        }
+        if self.timeout_secs == 0 || self.timeout_secs > self.interval_secs {
+            return Err("timeout_secs must be between 1 and interval_secs".to_string());
+        }
+        if self.retries == 0 || self.retries > 100 {
+            return Err("retries must be between 1 and 100".to_string());
+        }
+        match &self.probe {
+            HealthProbe::Http { path, .. } if !path.starts_with('/') => {
+                Err("http path must start with '/'".to_string())
+            }
+            HealthProbe::Command { command } if command.trim().is_empty() => {
+                Err("command must not be empty".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// 이미지 계열별 독립 컨테이너 설정 (settings의 image_profiles JSON 배열 원소)
+///
+/// 이미지 이름에 `match_images` 중 하나가 들어 있으면 적용된다 (먼저 나온 프로필 우선).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ImageProfile {
+    pub name: String,
+    /// 이미지 이름에 포함되는 문자열 (소문자, 예: "postgres", "timescale")
+    pub match_images: Vec<String>,
+    /// persist_data일 때 호스트 데이터 디렉토리를 마운트할 컨테이너 경로
+    #[serde(default = "default_profile_data_path")]
+    pub data_path: String,
+    /// persist_data일 때 추가할 환경변수
+    #[serde(default)]
+    pub data_env: std::collections::BTreeMap<String, String>,
+    /// 생성 시 있어야 하는 환경변수. `A|B`는 둘 중 하나
+    #[serde(default)]
+    pub required_env: Vec<String>,
+    /// 생성 시 헬스체크를 지정하지 않으면 사용할 헬스체크
+    #[serde(default)]
+    pub health_check: Option<ContainerHealthCheck>,
+}
+
+fn default_profile_data_path() -> String {
+    "/data".to_string()
+}
+
+/// 헬스체크 결과 상태
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ContainerHealth {
+    Starting,
+    Healthy,
+    Unhealthy,
+}
+
+impl std::fmt::Display for ContainerHealth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContainerHealth::Starting => write!(f, "starting"),
+            ContainerHealth::Healthy => write!(f, "healthy"),
+            ContainerHealth::Unhealthy => write!(f, "unhealthy"),
+        }
+    }
+}
+
 // Create container request
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateContainer {
@@ -493,12 +1960,82 @@ pub struct CreateContainer {
     pub persist_data: bool,  // 데이터 영구 저장 여부
     #[serde(default)]
     pub protocol_type: ProtocolType,  // tcp or http (기본값: tcp)
+    #[serde(default)]
+    pub health_check: Option<String>,  // JSON (ContainerHealthCheck)
+    #[serde(default)]
+    pub extra_ports: Vec<PortMappingRequest>,
+    /// exec 형식 ENTRYPOINT/CMD (command와 함께 쓸 수 없음)
+    #[serde(default)]
+    pub entrypoint: Option<Vec<String>>,
+    #[serde(default)]
+    pub args: Option<Vec<String>>,
+    #[serde(default)]
+    pub restart: RestartConfig,
+    #[serde(default)]
+    pub network_group: Option<String>,
 }
 
 // ============================================================================
 // Authentication Models
 // ============================================================================
 
+/// 사용자 역할
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum UserRole {
+    /// 전체 권한 (설정, PAT, 로그인 허용 목록, 사용자 역할 관리)
+    Admin,
+    /// 배정된 프로젝트의 빌드/배포, 프로젝트 생성
+    Developer,
+    /// 조회만 가능
+    Viewer,
+}
+
+impl std::fmt::Display for UserRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UserRole::Admin => write!(f, "admin"),
+            UserRole::Developer => write!(f, "developer"),
+            UserRole::Viewer => write!(f, "viewer"),
+        }
+    }
+}
+
+impl std::str::FromStr for UserRole {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "admin" => Ok(UserRole::Admin),
+            "developer" => Ok(UserRole::Developer),
+            "viewer" => Ok(UserRole::Viewer),
+            _ => Err(format!("Unknown role: {}", s)),
+        }
+    }
+}
+
+impl sqlx::Type<sqlx::Sqlite> for UserRole {
+    fn type_info() -> sqlx::sqlite::SqliteTypeInfo {
+        <String as sqlx::Type<sqlx::Sqlite>>::type_info()
+    }
+}
+
+impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for UserRole {
+    fn encode_by_ref(&self, args: &mut Vec<sqlx::sqlite::SqliteArgumentValue<'q>>) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Send + Sync>> {
+        args.push(sqlx::sqlite::SqliteArgumentValue::Text(
+            std::borrow::Cow::Owned(self.to_string()),
+        ));
+        Ok(sqlx::encode::IsNull::No)
+    }
+}
+
+impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for UserRole {
+    fn decode(value: sqlx::sqlite::SqliteValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        let s: String = <String as sqlx::Decode<sqlx::Sqlite>>::decode(value)?;
+        s.parse().map_err(|e: String| Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, e)) as Box<dyn std::error::Error + Send + Sync>)
+    }
+}
+
 /// User model (Google OAuth)
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct User {
@@ -507,8 +2044,18 @@ pub struct User {
     pub email: String,
     pub name: String,
     pub picture: Option<String>,
+    /// 첫 사용자는 admin, 이후 새 사용자는 developer
+    pub role: UserRole,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
     pub created_at: String,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
     pub updated_at: String,
+    /// GitHub 로그인으로 연결된 계정
+    pub github_id: Option<i64>,
+    pub github_login: Option<String>,
+    /// GitHub OAuth access token (PAT를 지정하지 않은 저장소 조회에 사용)
+    #[serde(skip_serializing)]
+    pub github_token: Option<String>,
 }
 
 /// Create user request
@@ -520,13 +2067,29 @@ pub struct CreateUser {
     pub picture: Option<String>,
 }
 
+/// GitHub 로그인으로 확인한 계정 (email은 기본 주소 중 인증된 것)
+#[derive(Debug, Clone)]
+pub struct CreateGitHubUser {
+    pub github_id: i64,
+    pub github_login: String,
+    pub github_token: String,
+    pub email: String,
+    pub name: String,
+    pub picture: Option<String>,
+}
+
 /// Session model (server-side session)
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Session {
     pub id: String,
     pub user_id: i64,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
     pub expires_at: String,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
     pub created_at: String,
+    /// 마지막 2단계(TOTP) 인증 시각
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize_option")]
+    pub mfa_verified_at: Option<String>,
 }
 
 /// Create session request
@@ -548,7 +2111,9 @@ pub struct GitHubPat {
     pub label: String,
     pub token: String,
     pub github_username: Option<String>,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
     pub created_at: String,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
     pub updated_at: String,
 }
 
@@ -567,7 +2132,9 @@ pub struct GitHubPatSummary {
     pub label: String,
     pub github_username: Option<String>,
     pub token_preview: String,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
     pub created_at: String,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
     pub updated_at: String,
 }
 
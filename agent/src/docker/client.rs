@@ -10,16 +10,161 @@ use futures_util::StreamExt;
 use serde_json;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
+use tokio::sync::mpsc;
 use tokio::time::timeout;
 use tracing::{debug, info, warn};
 
+use crate::application::ports::repositories::ProjectRepository;
+use crate::db::models::{BuildNetwork, ExecForm, ImageProfile, PortMapping, PortProtocol, RestartConfig, RestartPolicy, Slot};
+use crate::infrastructure::secrets::SecretEnv;
+
+/// 런타임 컨테이너에 실행 중인 빌드 ID를 기록하는 label
+const BUILD_ID_LABEL: &str = "easycicd.build_id";
+
+/// 빌드/테스트 컨테이너에 빌드 ID를 기록하는 label (stale 빌드의 컨테이너 정리용)
+const BUILD_CONTAINER_LABEL: &str = "easycicd.build_container";
+
+/// 런타임/독립 컨테이너와 agent가 함께 붙는 기본 네트워크 (DOCKER_NETWORK로 변경)
+pub const DEFAULT_DOCKER_NETWORK: &str = "easycicd_easycicd";
+
+/// 프로젝트당 추가로 연결할 수 있는 사용자 네트워크 수
+pub const MAX_EXTRA_NETWORKS: usize = 8;
+
+/// BuildNetwork::Isolated 빌드 컨테이너가 붙는 네트워크 (컨테이너 간 통신 차단)
+const ISOLATED_BUILD_NETWORK: &str = "easycicd_build_isolated";
+
+/// 빌드 컨테이너 리소스 사용량 샘플링 주기
+const BUILD_STATS_INTERVAL: Duration = Duration::from_secs(2);
+
 /// Build container execution result
 pub struct BuildResult {
     pub success: bool,
     pub exit_code: i64,
     pub logs: Vec<String>,
     pub container_id: String,
+    /// 실행 중 docker stats 샘플링으로 측정한 사용량 (빌드 컨테이너만)
+    pub resource_usage: ResourceUsage,
+}
+
+/// 컨테이너 하나가 실행 동안 사용한 리소스
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResourceUsage {
+    /// 최대 메모리 사용량 (page cache 제외, bytes)
+    pub peak_memory_bytes: u64,
+    /// 누적 CPU 시간 (ms, 모든 코어 합계)
+    pub cpu_time_ms: u64,
+}
+
+impl ResourceUsage {
+    /// 샘플 반영. 메모리는 최대값, CPU 시간은 누적 카운터라 마지막(최대) 값
+    pub fn record(&mut self, memory_bytes: u64, cpu_total_ns: u64) {
+        self.peak_memory_bytes = self.peak_memory_bytes.max(memory_bytes);
+        self.cpu_time_ms = self.cpu_time_ms.max(cpu_total_ns / 1_000_000);
+    }
+}
+
+/// 빌드 컨테이너 실행 옵션
+#[derive(Debug, Clone, Default)]
+pub struct BuildContainerOptions {
+    /// 서버가 미리 받아둔 소스 디렉토리 (/source에 읽기 전용으로 마운트)
+    pub source_path: Option<PathBuf>,
+    /// 네트워크 모드. Bridge가 아니면 socket proxy(DOCKER_HOST)도 제공하지 않음
+    pub network: BuildNetwork,
+    /// socket proxy(DOCKER_HOST) 제공 여부. false면 빌드 명령이 Docker daemon에 접근할 수 없음
+    pub docker_access: bool,
+    /// 컨테이너 출력 라인을 실시간으로 받을 채널 (빌드 단계 진행 표시용)
+    pub output_lines: Option<mpsc::UnboundedSender<String>>,
+    /// 컨테이너 label로 남길 빌드 ID (캐시 예열은 None)
+    pub build_id: Option<i64>,
+    /// 빌드 명령 대신 컨테이너 환경변수로 넣는 비밀 (출력 로그에서는 값을 가림)
+    pub secrets: SecretEnv,
+}
+
+/// 컨테이너 리소스 사용량 샘플
+#[derive(Debug, Clone, Copy)]
+pub struct ContainerStats {
+    /// CPU 사용률 (%; 코어 1개 = 100)
+    pub cpu_percent: f64,
+    /// 메모리 사용량 (page cache 제외, bytes)
+    pub memory_bytes: u64,
+    pub memory_limit: u64,
+}
+
+/// 빌드 컨테이너에서 cache_type별 캐시가 마운트되는 경로
+pub fn cache_mount_path(cache_type: &str) -> &'static str {
+    match cache_type {
+        "gradle" => "/root/.gradle",
+        "maven" => "/root/.m2",
+        "npm" => "/root/.npm",
+        "pip" => "/root/.cache/pip",
+        "cargo" => "/usr/local/cargo/registry",
+        _ => "/cache",
+    }
+}
+
+/// digest(`repo@sha256:...`) 또는 이미지 ID(`sha256:...`)로 고정된 참조인지
+fn is_pinned_image(image: &str) -> bool {
+    image.contains("@sha256:") || image.starts_with("sha256:")
+}
+
+/// 고정 참조에서 digest 부분 (`node@sha256:abc` → `sha256:abc`). 태그 참조는 None
+pub fn image_digest(reference: &str) -> Option<&str> {
+    match reference.split_once('@') {
+        Some((_, digest)) => Some(digest),
+        None if reference.starts_with("sha256:") => Some(reference),
+        None => None,
+    }
+}
+
+/// `image` 태그에 해당하는 고정 참조 선택.
+/// 같은 repository의 RepoDigest를 우선하고, 없으면 첫 RepoDigest, 그것도 없으면 이미지 ID
+fn pinned_image_reference(image: &str, repo_digests: &[String], image_id: Option<&str>) -> Option<String> {
+    // "registry:5000/app:1.0" → "registry:5000/app" (포트의 ':'는 마지막 '/' 앞)
+    let repository = match image.rfind(':') {
+        Some(i) if !image[i..].contains('/') => &image[..i],
+        _ => image,
+    };
+    repo_digests
+        .iter()
+        .find(|d| d.split('@').next() == Some(repository))
+        .or_else(|| repo_digests.first())
+        .cloned()
+        .or_else(|| image_id.map(str::to_string))
+}
+
+/// `image`을 (repository, tag)로 분리. 태그가 없으면 "latest"
+fn split_image_tag(image: &str) -> (&str, &str) {
+    // "registry:5000/app" 처럼 포트의 ':'는 태그가 아님
+    match image.rfind(':') {
+        Some(i) if !image[i..].contains('/') => (&image[..i], &image[i + 1..]),
+        _ => (image, "latest"),
+    }
+}
+
+/// Docker Hub 이미지를 레지스트리 미러 참조로 변환 (`node:20` → `{mirror}/library/node:20`).
+/// 다른 레지스트리 이미지와 digest 고정 참조는 미러를 쓰지 않음 (None)
+pub fn mirror_reference(image: &str, mirror: &str) -> Option<String> {
+    if is_pinned_image(image) {
+        return None;
+    }
+    let image = image.strip_prefix("docker.io/").unwrap_or(image);
+    let (repository, tag) = split_image_tag(image);
+    let path = match repository.split_once('/') {
+        // 첫 부분이 호스트(`ghcr.io`, `registry:5000`, `localhost`)면 Docker Hub가 아님
+        Some((first, _)) if first.contains('.') || first.contains(':') || first == "localhost" => return None,
+        Some(_) => repository.to_string(),
+        None => format!("library/{}", repository),
+    };
+    Some(format!("{}/{}:{}", mirror.trim_end_matches('/'), path, tag))
+}
+
+fn configured_registry_mirror() -> Option<String> {
+    std::env::var("REGISTRY_MIRROR")
+        .ok()
+        .map(|m| m.trim().trim_start_matches("https://").trim_start_matches("http://").trim_end_matches('/').to_string())
+        .filter(|m| !m.is_empty())
 }
 
 #[derive(Clone)]
@@ -28,8 +173,82 @@ pub struct DockerClient {
     host_data_path: Option<String>,
     gateway_ip: String,
     /// 빌드 컨테이너가 사용할 Docker socket proxy 주소 (TCP, "host:port" 형태).
-    /// 환경변수 SOCKET_PROXY_HOST로 설정. 비어있으면 빌드 컨테이너에 Docker 미제공.
+    /// 환경변수 SOCKET_PROXY_HOST로 설정. 비어있거나 프로젝트의 docker_access가 꺼져 있으면 빌드 컨테이너에 Docker 미제공.
     socket_proxy_host: String,
+    /// 런타임/독립 컨테이너가 붙는 네트워크 (환경변수 DOCKER_NETWORK, 기본 easycicd_easycicd)
+    network: String,
+    /// Docker Hub 이미지 pull에 사용할 미러 (환경변수 REGISTRY_MIRROR, 예 `127.0.0.1:5000`)
+    registry_mirror: Option<String>,
+    /// agent 자신의 컨테이너 ID (전용 네트워크에 함께 붙어 프록시가 접근). 컨테이너 밖에서 실행하면 None
+    self_container_id: Option<String>,
+}
+
+/// Docker 네트워크 이름으로 쓸 수 있는지 (영문/숫자로 시작, 영문/숫자/`_.-`, 최대 64자)
+pub fn valid_network_name(name: &str) -> bool {
+    name.len() <= 64
+        && name.chars().next().is_some_and(|c| c.is_ascii_alphanumeric())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
+/// 프로젝트 추가 네트워크 설정 검사 (이름 형식, 중복, 기본 네트워크, 개수)
+pub fn validate_extra_networks(networks: &[String], default_network: &str) -> Result<(), String> {
+    if networks.len() > MAX_EXTRA_NETWORKS {
+        return Err(format!("extra_networks can list at most {} networks", MAX_EXTRA_NETWORKS));
+    }
+    let mut seen = std::collections::HashSet::new();
+    for name in networks {
+        if !valid_network_name(name) {
+            return Err(format!("invalid network name '{}'", name));
+        }
+        if name == default_network {
+            return Err(format!("'{}' is the default network and is always attached", name));
+        }
+        if !seen.insert(name.as_str()) {
+            return Err(format!("network '{}' is listed more than once", name));
+        }
+    }
+    Ok(())
+}
+
+/// 프로젝트 전용 네트워크 이름
+pub fn project_network_name(project_id: i64) -> String {
+    format!("easycicd-project-{}", project_id)
+}
+
+/// Dockerfile 빌드로 만든 런타임 이미지 태그
+pub fn build_image_tag(project_id: i64, build_number: i64) -> String {
+    format!("{}:build-{}", project_image_repository(project_id), build_number)
+}
+
+/// 프로젝트 런타임 이미지 저장소 이름 (빌드마다 build-{n} 태그)
+fn project_image_repository(project_id: i64) -> String {
+    format!("project-{}", project_id)
+}
+
+/// 독립 컨테이너 그룹 전용 네트워크 이름
+pub fn group_network_name(group: &str) -> String {
+    format!("easycicd-group-{}", group)
+}
+
+/// 독립 컨테이너 네트워크 그룹 이름 검사 (영문 소문자/숫자/하이픈, 최대 32자)
+pub fn validate_network_group(group: &str) -> Result<(), String> {
+    let valid = !group.is_empty()
+        && group.len() <= 32
+        && group.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !group.starts_with('-')
+        && !group.ends_with('-');
+    if !valid {
+        return Err(format!("invalid network group '{}' (lowercase letters, digits and '-', up to 32 chars)", group));
+    }
+    Ok(())
+}
+
+fn configured_network() -> String {
+    std::env::var("DOCKER_NETWORK")
+        .ok()
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(|| DEFAULT_DOCKER_NETWORK.to_string())
 }
 
 impl DockerClient {
@@ -43,6 +262,9 @@ impl DockerClient {
             host_data_path: None,
             gateway_ip: "172.17.0.1".to_string(),
             socket_proxy_host,
+            network: configured_network(),
+            registry_mirror: configured_registry_mirror(),
+            self_container_id: None,
         })
     }
 
@@ -58,6 +280,9 @@ impl DockerClient {
             host_data_path: None,
             gateway_ip: "172.17.0.1".to_string(),
             socket_proxy_host,
+            network: configured_network(),
+            registry_mirror: configured_registry_mirror(),
+            self_container_id: None,
         };
 
         // Detect host path and gateway IP by inspecting our own container
@@ -66,6 +291,8 @@ impl DockerClient {
             info!("Detecting host path for container: {}", container_id);
 
             if let Ok(inspect) = docker.inspect_container(container_id, None::<InspectContainerOptions>).await {
+                client.self_container_id = inspect.id.clone();
+
                 // Detect host data path
                 if let Some(mounts) = inspect.mounts {
                     for mount in mounts {
@@ -120,6 +347,12 @@ impl DockerClient {
 
     /// Pull image if not exists
     pub async fn ensure_image(&self, image: &str) -> Result<()> {
+        // digest/ID로 고정된 참조는 repo_tags에 나오지 않으므로 직접 조회
+        if is_pinned_image(image) && self.docker.inspect_image(image).await.is_ok() {
+            info!("Image {} already exists locally", image);
+            return Ok(());
+        }
+
         // Check if image already exists locally
         let images = self.docker.list_images(None::<bollard::image::ListImagesOptions<String>>).await?;
         let image_with_tag = if image.contains(':') {
@@ -138,6 +371,33 @@ impl DockerClient {
             return Ok(());
         }
 
+        self.pull_image(image).await
+    }
+
+    /// Pull image (로컬에 있어도 레지스트리의 최신 태그로 갱신).
+    /// REGISTRY_MIRROR가 있으면 Docker Hub 이미지는 미러에서 받아 원래 이름으로 태그 (실패 시 Docker Hub)
+    pub async fn pull_image(&self, image: &str) -> Result<()> {
+        if let Some(mirrored) = self.registry_mirror.as_deref().and_then(|mirror| mirror_reference(image, mirror)) {
+            match self.pull_from_mirror(image, &mirrored).await {
+                Ok(()) => return Ok(()),
+                Err(e) => warn!("Registry mirror pull failed for {}, falling back to Docker Hub: {}", image, e),
+            }
+        }
+        self.pull_reference(image).await
+    }
+
+    async fn pull_from_mirror(&self, image: &str, mirrored: &str) -> Result<()> {
+        self.pull_reference(mirrored).await?;
+        let (repository, tag) = split_image_tag(image.strip_prefix("docker.io/").unwrap_or(image));
+        let options = bollard::query_parameters::TagImageOptionsBuilder::new().repo(repository).tag(tag).build();
+        self.docker
+            .tag_image(mirrored, Some(options))
+            .await
+            .with_context(|| format!("Failed to tag {} as {}", mirrored, image))?;
+        Ok(())
+    }
+
+    async fn pull_reference(&self, image: &str) -> Result<()> {
         info!("Pulling image: {} (this may take a while...)", image);
 
         let mut stream = self.docker.create_image(
@@ -196,6 +456,173 @@ impl DockerClient {
         Ok(())
     }
 
+    /// 이미지 태그를 digest 참조(`repo@sha256:...`)로 고정 (로컬에 없으면 pull).
+    /// 레지스트리 digest가 없는 로컬 전용 이미지는 이미지 ID(`sha256:...`)를 반환
+    pub async fn resolve_image_digest(&self, image: &str) -> Result<String> {
+        if is_pinned_image(image) {
+            return Ok(image.to_string());
+        }
+        self.ensure_image(image).await?;
+
+        let inspect = self.docker.inspect_image(image).await
+            .with_context(|| format!("Failed to inspect image {}", image))?;
+        pinned_image_reference(image, inspect.repo_digests.as_deref().unwrap_or_default(), inspect.id.as_deref())
+            .with_context(|| format!("Image {} has no digest or ID", image))
+    }
+
+    /// 로컬 이미지의 레지스트리 digest (`sha256:...`). 로컬에 없거나 레지스트리 digest가 없는 이미지는 None
+    pub async fn local_image_digest(&self, image: &str) -> Result<Option<String>> {
+        let inspect = match self.docker.inspect_image(image).await {
+            Ok(inspect) => inspect,
+            Err(bollard::errors::Error::DockerResponseServerError { status_code: 404, .. }) => return Ok(None),
+            Err(e) => return Err(e).with_context(|| format!("Failed to inspect image {}", image)),
+        };
+        let repo_digests = inspect.repo_digests.unwrap_or_default();
+        Ok(pinned_image_reference(image, &repo_digests, None)
+            .and_then(|reference| image_digest(&reference).map(str::to_string)))
+    }
+
+    /// 레지스트리에 있는 태그의 현재 digest (`sha256:...`, pull 없이 manifest만 조회).
+    /// private/없는 이미지는 None
+    pub async fn registry_image_digest(&self, image: &str) -> Result<Option<String>> {
+        match self.docker.inspect_registry_image(image, None).await {
+            Ok(inspect) => Ok(inspect.descriptor.digest),
+            // 401: private/non-existent repository, 404: unknown tag
+            Err(bollard::errors::Error::DockerResponseServerError { status_code: 401 | 404, .. }) => Ok(None),
+            Err(e) => Err(e).context("Failed to inspect registry image"),
+        }
+    }
+
+    /// 런타임/독립 컨테이너가 붙는 네트워크 이름
+    pub fn network(&self) -> &str {
+        &self.network
+    }
+
+    pub fn registry_mirror(&self) -> Option<&str> {
+        self.registry_mirror.as_deref()
+    }
+
+    pub async fn network_exists(&self, name: &str) -> bool {
+        self.docker
+            .inspect_network(name, None::<bollard::query_parameters::InspectNetworkOptions>)
+            .await
+            .is_ok()
+    }
+
+    /// 기본 네트워크가 없으면 bridge로 생성 (agent 시작 시)
+    pub async fn ensure_network(&self) -> Result<()> {
+        self.create_network_if_missing(&self.network, HashMap::new()).await
+    }
+
+    /// 프로젝트 런타임 컨테이너가 기본으로 붙는 네트워크 (전용 네트워크 또는 공용 네트워크)
+    pub fn project_network(&self, project_id: i64, isolated: bool) -> String {
+        if isolated { project_network_name(project_id) } else { self.network.clone() }
+    }
+
+    /// 전용 네트워크가 없으면 생성하고 agent를 연결 (리버스 프록시가 컨테이너 이름으로 접근)
+    pub async fn ensure_dedicated_network(&self, name: &str) -> Result<()> {
+        self.create_network_if_missing(name, HashMap::new()).await?;
+        if let Some(agent_id) = &self.self_container_id {
+            if !self.network_has_container(name, agent_id).await {
+                info!("Connecting agent to network {}", name);
+                self.connect_container_network(name, agent_id).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 전용 네트워크 삭제 (agent와 peer 컨테이너 연결을 먼저 끊음). 없으면 무시
+    pub async fn remove_dedicated_network(&self, name: &str) -> Result<()> {
+        let Ok(network) = self
+            .docker
+            .inspect_network(name, None::<bollard::query_parameters::InspectNetworkOptions>)
+            .await
+        else {
+            return Ok(());
+        };
+        for container_id in network.containers.unwrap_or_default().keys() {
+            self.disconnect_container_network(name, container_id).await.ok();
+        }
+        info!("Removing Docker network: {}", name);
+        self.docker
+            .remove_network(name)
+            .await
+            .context(format!("Failed to remove network {}", name))?;
+        Ok(())
+    }
+
+    async fn network_has_container(&self, name: &str, container_id: &str) -> bool {
+        self.docker
+            .inspect_network(name, None::<bollard::query_parameters::InspectNetworkOptions>)
+            .await
+            .ok()
+            .and_then(|n| n.containers)
+            .is_some_and(|containers| containers.contains_key(container_id))
+    }
+
+    /// 실행 중인 컨테이너를 네트워크에 연결 (이미 연결되어 있으면 무시)
+    pub async fn connect_container_network(&self, network: &str, container_id: &str) -> Result<()> {
+        if self.network_has_container(network, container_id).await {
+            return Ok(());
+        }
+        self.docker
+            .connect_network(
+                network,
+                bollard::network::ConnectNetworkOptions {
+                    container: container_id,
+                    ..Default::default()
+                },
+            )
+            .await
+            .context(format!("Failed to connect container to network {}", network))?;
+        Ok(())
+    }
+
+    pub async fn disconnect_container_network(&self, network: &str, container_id: &str) -> Result<()> {
+        self.docker
+            .disconnect_network(
+                network,
+                bollard::network::DisconnectNetworkOptions {
+                    container: container_id,
+                    force: true,
+                },
+            )
+            .await
+            .context(format!("Failed to disconnect container from network {}", network))?;
+        Ok(())
+    }
+
+    /// 격리 빌드 네트워크가 없으면 생성.
+    /// 일반 bridge와 같이 외부로는 나갈 수 있지만 같은 네트워크의 컨테이너끼리는 통신할 수 없음 (icc 비활성화)
+    async fn ensure_isolated_build_network(&self) -> Result<()> {
+        self.create_network_if_missing(
+            ISOLATED_BUILD_NETWORK,
+            HashMap::from([("com.docker.network.bridge.enable_icc".to_string(), "false".to_string())]),
+        )
+        .await
+    }
+
+    async fn create_network_if_missing(&self, name: &str, options: HashMap<String, String>) -> Result<()> {
+        if self.network_exists(name).await {
+            return Ok(());
+        }
+
+        info!("Creating Docker network: {}", name);
+        let request = bollard::models::NetworkCreateRequest {
+            name: name.to_string(),
+            driver: Some("bridge".to_string()),
+            options: Some(options),
+            ..Default::default()
+        };
+        if let Err(e) = self.docker.create_network(request).await {
+            // 동시에 시작된 다른 빌드가 먼저 만들었을 수 있음
+            if !self.network_exists(name).await {
+                return Err(e).context(format!("Failed to create network {}", name));
+            }
+        }
+        Ok(())
+    }
+
     /// Run build container (git clone happens inside container)
     /// Returns BuildResult with success/failure status and logs
     pub async fn run_build_container(
@@ -205,6 +632,7 @@ impl DockerClient {
         output_path: PathBuf,
         cache_path: PathBuf,
         cache_type: &str,
+        options: &BuildContainerOptions,
     ) -> Result<BuildResult> {
         self.ensure_image(image).await?;
 
@@ -227,32 +655,53 @@ impl DockerClient {
 
         // Add cache mount if provided
         if cache_path.exists() {
-            let cache_mount = match cache_type {
-                "gradle" => "/root/.gradle",
-                "maven" => "/root/.m2",
-                "npm" => "/root/.npm",
-                "pip" => "/root/.cache/pip",
-                "cargo" => "/usr/local/cargo/registry",
-                _ => "/cache",
-            };
-            binds.push(format!("{}:{}", host_cache.display(), cache_mount));
+            binds.push(format!("{}:{}", host_cache.display(), cache_mount_path(cache_type)));
+        }
+
+        if let Some(source_path) = &options.source_path {
+            let host_source = self.to_host_path(source_path);
+            info!("  Source: {} (host: {})", source_path.display(), host_source.display());
+            binds.push(format!("{}:/source:ro", host_source.display()));
         }
 
+        // DOOD는 프로젝트가 명시적으로 허용한 경우에만 제공.
+        // isolated/none은 easycicd 네트워크에 붙지 않으므로 socket proxy에 도달할 수 없음
+        if options.docker_access && options.network != BuildNetwork::Bridge {
+            warn!("Docker access is ignored on {} build network", options.network);
+        }
+        let use_socket_proxy = options.docker_access
+            && options.network == BuildNetwork::Bridge
+            && !self.socket_proxy_host.is_empty();
+        let network_mode = match options.network {
+            BuildNetwork::Bridge => None,
+            BuildNetwork::Isolated => {
+                self.ensure_isolated_build_network().await?;
+                Some(ISOLATED_BUILD_NETWORK.to_string())
+            }
+            BuildNetwork::None => Some("none".to_string()),
+        };
+        info!("  Network: {}", options.network);
+
         // 빌드 컨테이너 환경변수:
         // DOCKER_HOST: socket proxy TCP 주소 (docker build/push만 허용, container 생성 차단)
         let mut container_env = Vec::new();
-        if !self.socket_proxy_host.is_empty() {
+        if use_socket_proxy {
             container_env.push(format!("DOCKER_HOST=tcp://{}", self.socket_proxy_host));
             info!("Build container will use socket proxy: tcp://{}", self.socket_proxy_host);
         }
+        container_env.extend(options.secrets.docker_env());
 
         let config = Config {
             image: Some(image.to_string()),
             cmd: Some(vec!["/bin/sh".to_string(), "-c".to_string(), command.to_string()]),
             working_dir: Some("/".to_string()),  // Start at root, git clone creates /workspace
             env: if container_env.is_empty() { None } else { Some(container_env) },
+            labels: options
+                .build_id
+                .map(|id| HashMap::from([(BUILD_CONTAINER_LABEL.to_string(), id.to_string())])),
             host_config: Some(bollard::models::HostConfig {
                 binds: Some(binds),
+                network_mode,
                 auto_remove: Some(false),
                 // 리소스 제한: 채굴 등 자원 소진 공격 방지
                 memory: Some(2 * 1024 * 1024 * 1024),      // 메모리 최대 2GB
@@ -284,10 +733,10 @@ impl DockerClient {
 
         // socket proxy에 접근할 수 있도록 easycicd 네트워크에 연결.
         // 빌드 컨테이너는 이 네트워크를 통해 socket-proxy:2375에 도달함.
-        if !self.socket_proxy_host.is_empty() {
+        if use_socket_proxy {
             if let Err(e) = self.docker
                 .connect_network(
-                    "easycicd_easycicd",
+                    &self.network,
                     bollard::network::ConnectNetworkOptions {
                         container: container_id.as_str(),
                         ..Default::default()
@@ -305,6 +754,24 @@ impl DockerClient {
             .await
             .context("Failed to start container")?;
 
+        // 빌드가 끝날 때까지 리소스 사용량 샘플링 (컨테이너 삭제 전에 중단)
+        let usage = Arc::new(Mutex::new(ResourceUsage::default()));
+        let sampler = {
+            let client = self.clone();
+            let container_id = container_id.clone();
+            let usage = usage.clone();
+            tokio::spawn(async move {
+                loop {
+                    match client.sample_usage(&container_id).await {
+                        Ok(Some((memory, cpu_ns))) => usage.lock().unwrap().record(memory, cpu_ns),
+                        Ok(None) => {}
+                        Err(e) => debug!("Failed to sample build container {} stats: {}", container_id, e),
+                    }
+                    tokio::time::sleep(BUILD_STATS_INTERVAL).await;
+                }
+            })
+        };
+
         // Collect logs with timeout (30 minutes max for long builds)
         let build_timeout = Duration::from_secs(30 * 60);
         let max_log_lines = 100_000;  // Prevent memory exhaustion
@@ -329,8 +796,12 @@ impl DockerClient {
                             LogOutput::StdErr { message } => String::from_utf8_lossy(&message).to_string(),
                             _ => continue,
                         };
+                        let line = options.secrets.mask(&line);
                         // Print log immediately to stdout for real-time visibility
                         println!("[BUILD {}] {}", container_id, line.trim_end());
+                        if let Some(tx) = &options.output_lines {
+                            let _ = tx.send(line.clone());
+                        }
                         logs.push(line);
 
                         // Prevent memory exhaustion
@@ -401,6 +872,13 @@ impl DockerClient {
             }
         };
 
+        sampler.abort();
+        let resource_usage = *usage.lock().unwrap();
+        info!(
+            "Build container {} used peak memory {} bytes, CPU time {} ms",
+            container_id, resource_usage.peak_memory_bytes, resource_usage.cpu_time_ms
+        );
+
         // Remove build container (cleanup)
         if let Err(e) = self
             .docker
@@ -421,6 +899,120 @@ impl DockerClient {
             exit_code,
             logs,
             container_id,
+            resource_usage,
+        })
+    }
+
+    /// Run short-lived hook container (pre/post build, post deploy)
+    /// 빌드 컨테이너와 같은 보안 제한을 적용하되, 볼륨 마운트 없이 실행하고 5분 후 강제 종료
+    pub async fn run_hook_container(
+        &self,
+        image: &str,
+        command: &str,
+        env: Vec<String>,
+    ) -> Result<BuildResult> {
+        self.ensure_image(image).await?;
+
+        let container_name = format!("hook-{}", uuid::Uuid::new_v4());
+
+        let config = Config {
+            image: Some(image.to_string()),
+            cmd: Some(vec!["/bin/sh".to_string(), "-c".to_string(), command.to_string()]),
+            env: Some(env),
+            host_config: Some(bollard::models::HostConfig {
+                auto_remove: Some(false),
+                memory: Some(512 * 1024 * 1024),
+                memory_swap: Some(512 * 1024 * 1024),
+                nano_cpus: Some(1_000_000_000i64),
+                pids_limit: Some(200i64),
+                cap_drop: Some(vec!["ALL".to_string()]),
+                security_opt: Some(vec!["no-new-privileges:true".to_string()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let container = self
+            .docker
+            .create_container(
+                Some(CreateContainerOptions {
+                    name: container_name.as_str(),
+                    ..Default::default()
+                }),
+                config,
+            )
+            .await
+            .context("Failed to create hook container")?;
+        let container_id = container.id;
+
+        self.docker
+            .start_container(&container_id, None::<StartContainerOptions<&str>>)
+            .await
+            .context("Failed to start hook container")?;
+
+        let mut log_stream = self.docker.logs(
+            &container_id,
+            Some(bollard::container::LogsOptions::<String> {
+                follow: true,
+                stdout: true,
+                stderr: true,
+                ..Default::default()
+            }),
+        );
+
+        let mut logs = Vec::new();
+        let log_collection = async {
+            while let Some(Ok(output)) = log_stream.next().await {
+                match output {
+                    LogOutput::StdOut { message } | LogOutput::StdErr { message } => {
+                        logs.push(String::from_utf8_lossy(&message).trim_end().to_string());
+                    }
+                    _ => {}
+                }
+                if logs.len() >= 10_000 {
+                    logs.push("... truncated after 10000 lines".to_string());
+                    break;
+                }
+            }
+        };
+
+        let hook_timeout = Duration::from_secs(5 * 60);
+        let exit_code = if timeout(hook_timeout, log_collection).await.is_err() {
+            logs.push("ERROR: Hook timed out after 5 minutes".to_string());
+            let _ = self.docker.stop_container(&container_id, Some(StopContainerOptions { t: 5 })).await;
+            -2
+        } else {
+            match timeout(
+                Duration::from_secs(30),
+                self.docker
+                    .wait_container(&container_id, None::<bollard::container::WaitContainerOptions<&str>>)
+                    .next(),
+            ).await {
+                Ok(Some(Ok(result))) => result.status_code,
+                _ => self.get_container_exit_code(&container_id).await,
+            }
+        };
+
+        if let Err(e) = self
+            .docker
+            .remove_container(
+                &container_id,
+                Some(RemoveContainerOptions {
+                    force: true,
+                    ..Default::default()
+                }),
+            )
+            .await
+        {
+            warn!("Failed to remove hook container {}: {}", container_id, e);
+        }
+
+        Ok(BuildResult {
+            success: exit_code == 0,
+            exit_code,
+            logs,
+            container_id,
+            resource_usage: ResourceUsage::default(),
         })
     }
 
@@ -429,12 +1021,18 @@ impl DockerClient {
         &self,
         image: &str,
         command: &str,
-        output_path: PathBuf,
-        port: u16,
+        exec: &ExecForm,
+        restart: RestartConfig,
+        output_path: Option<PathBuf>,
+        host_port: Option<u16>,
         runtime_port: u16,
         project_id: i64,
+        build_id: i64,
         slot: &str,
         env_vars: Option<&str>,
+        network: &str,
+        network_aliases: Vec<String>,
+        extra_networks: &[String],
     ) -> Result<String> {
         self.ensure_image(image).await?;
 
@@ -444,20 +1042,25 @@ impl DockerClient {
         let _ = self.stop_container(&container_name).await;
         let _ = self.remove_container(&container_name).await;
 
-        // Convert container path to host path for DOOD
-        let host_output = self.to_host_path(&output_path);
-        info!("Runtime container mount: {} (host: {})", output_path.display(), host_output.display());
+        // Convert container path to host path for DOOD (Dockerfile 빌드 이미지는 산출물이 이미지에 들어 있어 마운트 없음)
+        let binds = output_path.as_ref().map(|output_path| {
+            let host_output = self.to_host_path(output_path);
+            info!("Runtime container mount: {} (host: {})", output_path.display(), host_output.display());
+            vec![format!("{}:/app:ro", host_output.display())]
+        });
 
         let container_port_str = format!("{}/tcp", runtime_port);
 
-        let mut port_bindings = HashMap::new();
-        port_bindings.insert(
-            container_port_str.clone(),
-            Some(vec![bollard::models::PortBinding {
-                host_ip: Some("0.0.0.0".to_string()),
-                host_port: Some(port.to_string()),
-            }]),
-        );
+        // host_port가 None이면 바인딩 없이 easycicd 네트워크(프록시)로만 접근
+        let port_bindings = host_port.map(|port| {
+            HashMap::from([(
+                container_port_str.clone(),
+                Some(vec![bollard::models::PortBinding {
+                    host_ip: Some("0.0.0.0".to_string()),
+                    host_port: Some(port.to_string()),
+                }]),
+            )])
+        });
 
         // Build environment variables list
         let mut env = vec![format!("PORT={}", runtime_port)];
@@ -475,18 +1078,21 @@ impl DockerClient {
             }
         }
 
+        // 빌드 이미지는 runtime_command가 비어 있으면 이미지의 CMD/WORKDIR 그대로
+        let shell_command = Some(command).filter(|c| binds.is_some() || !c.trim().is_empty());
+        let (entrypoint, cmd) = entrypoint_and_cmd(exec, shell_command);
         let config = Config {
             image: Some(image.to_string()),
-            cmd: Some(vec!["/bin/sh".to_string(), "-c".to_string(), command.to_string()]),
-            working_dir: Some("/app".to_string()),
+            entrypoint,
+            cmd,
+            working_dir: binds.is_some().then(|| "/app".to_string()),
             env: Some(env),
+            // 웜 스탠바이 슬롯에서 어떤 빌드가 실행 중인지 확인하는 데 사용
+            labels: Some(HashMap::from([(BUILD_ID_LABEL.to_string(), build_id.to_string())])),
             host_config: Some(bollard::models::HostConfig {
-                binds: Some(vec![format!("{}:/app:ro", host_output.display())]),
-                port_bindings: Some(port_bindings),
-                restart_policy: Some(bollard::models::RestartPolicy {
-                    name: Some(bollard::models::RestartPolicyNameEnum::UNLESS_STOPPED),
-                    ..Default::default()
-                }),
+                binds,
+                port_bindings,
+                restart_policy: Some(docker_restart_policy(restart)),
                 // 리소스 제한: 런타임 컨테이너가 호스트 자원을 독점하지 못하도록
                 memory: Some(1024 * 1024 * 1024),   // 메모리 최대 1GB
                 nano_cpus: Some(1_000_000_000i64),  // CPU 최대 1코어
@@ -516,29 +1122,50 @@ impl DockerClient {
 
         let container_id = container.id.clone();
 
-        // Connect to easycicd network
-        info!("Connecting runtime container to easycicd network");
+        // Connect to easycicd network (alias로 다른 컨테이너가 슬롯과 무관하게 접근)
+        info!("Connecting runtime container to network {} (aliases: {:?})", network, network_aliases);
         self.docker
             .connect_network(
-                "easycicd_easycicd",
+                network,
                 bollard::network::ConnectNetworkOptions {
                     container: container_id.as_str(),
-                    ..Default::default()
+                    endpoint_config: bollard::models::EndpointSettings {
+                        aliases: Some(network_aliases),
+                        ..Default::default()
+                    },
                 },
             )
             .await
             .context("Failed to connect container to network")?;
 
+        // 프로젝트가 지정한 사용자 네트워크 (DB 등 easyCICD 밖의 컨테이너와 통신)
+        for network in extra_networks {
+            info!("Connecting runtime container to network {}", network);
+            self.docker
+                .connect_network(
+                    network.as_str(),
+                    bollard::network::ConnectNetworkOptions {
+                        container: container_id.as_str(),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .context(format!("Failed to connect container to network {}", network))?;
+        }
+
         info!("Starting runtime container: {}", container_id);
         if let Err(e) = self.docker
             .start_container(&container_id, None::<StartContainerOptions<&str>>)
             .await
         {
-            return Err(anyhow::anyhow!(
-                "Failed to start runtime container '{}' on port {}: {}. \
-                Check if port is already in use or if there are configuration issues.",
-                container_name, port, e
-            ));
+            return Err(match host_port {
+                Some(port) => anyhow::anyhow!(
+                    "Failed to start runtime container '{}' on port {}: {}. \
+                    Check if port is already in use or if there are configuration issues.",
+                    container_name, port, e
+                ),
+                None => anyhow::anyhow!("Failed to start runtime container '{}': {}", container_name, e),
+            });
         }
 
         Ok(container_id)
@@ -561,15 +1188,128 @@ impl DockerClient {
         })
     }
 
+    /// Check whether an image is available without pulling it.
+    /// 로컬에 있으면 바로 true, 없으면 레지스트리 manifest 조회로 존재 여부 확인
+    pub async fn image_exists(&self, image: &str) -> Result<bool> {
+        if !self.needs_image_pull(image).await {
+            return Ok(true);
+        }
+
+        match self.docker.inspect_registry_image(image, None).await {
+            Ok(_) => Ok(true),
+            // 401: private/non-existent repository, 404: unknown tag
+            Err(bollard::errors::Error::DockerResponseServerError { status_code: 401 | 404, .. }) => Ok(false),
+            Err(e) => Err(e).context("Failed to inspect registry image"),
+        }
+    }
+
+    /// `context` 디렉토리를 빌드 컨텍스트로 이미지를 빌드해 `tag`를 붙임 (.git 제외, 30분 제한)
+    ///
+    /// 빌드 출력은 BuildResult.logs로 돌려준다. 실패해도 Err 대신 success=false
+    pub async fn build_image(&self, context: &Path, dockerfile: &str, tag: &str, build_id: i64) -> Result<BuildResult> {
+        let archive = tokio::process::Command::new("tar")
+            .args(["-cf", "-", "--exclude=./.git", "-C"])
+            .arg(context)
+            .arg(".")
+            .output()
+            .await
+            .context("Failed to run tar for build context")?;
+        if !archive.status.success() {
+            anyhow::bail!("Failed to archive build context: {}", String::from_utf8_lossy(&archive.stderr).trim());
+        }
+        info!("Building image {} from {} ({} bytes context)", tag, context.display(), archive.stdout.len());
+
+        let options = bollard::query_parameters::BuildImageOptionsBuilder::new()
+            .dockerfile(dockerfile)
+            .t(tag)
+            .rm(true)
+            .forcerm(true)
+            .labels(&HashMap::from([(BUILD_ID_LABEL, build_id.to_string())]))
+            .build();
+        let mut stream = self.docker.build_image(
+            options,
+            None,
+            Some(bollard::body_full(hyper::body::Bytes::from(archive.stdout))),
+        );
+
+        let mut logs = Vec::new();
+        let mut success = true;
+        let collect = async {
+            while let Some(item) = stream.next().await {
+                match item {
+                    Ok(info) => {
+                        if let Some(output) = info.stream.as_deref() {
+                            logs.extend(output.lines().filter(|l| !l.trim().is_empty()).map(str::to_string));
+                        }
+                        if let Some(error) = info.error {
+                            logs.push(format!("ERROR: {}", error));
+                            success = false;
+                        }
+                    }
+                    Err(e) => {
+                        logs.push(format!("ERROR: {}", e));
+                        success = false;
+                        break;
+                    }
+                }
+            }
+        };
+        if timeout(Duration::from_secs(30 * 60), collect).await.is_err() {
+            logs.push("ERROR: Image build timed out after 30 minutes".to_string());
+            success = false;
+        }
+
+        Ok(BuildResult {
+            success,
+            exit_code: if success { 0 } else { 1 },
+            logs,
+            container_id: String::new(),
+            resource_usage: ResourceUsage::default(),
+        })
+    }
+
+    /// 프로젝트의 Dockerfile 빌드 이미지 전부 삭제 (프로젝트 삭제 시). 삭제한 태그 수
+    /// 이미지 태그 제거 (다른 태그가 있으면 태그만 떼고, 컨테이너가 쓰는 중이면 실패)
+    pub async fn remove_image(&self, tag: &str) -> Result<()> {
+        self.docker
+            .remove_image(tag, None::<bollard::query_parameters::RemoveImageOptions>, None)
+            .await
+            .with_context(|| format!("Failed to remove image {}", tag))?;
+        Ok(())
+    }
+
+    pub async fn remove_project_images(&self, project_id: i64) -> Result<usize> {
+        let repository = project_image_repository(project_id);
+        let options = bollard::query_parameters::ListImagesOptionsBuilder::new()
+            .filters(&HashMap::from([("reference", vec![format!("{}:*", repository)])]))
+            .build();
+        let images = self.docker.list_images(Some(options)).await.context("Failed to list project images")?;
+
+        let mut removed = 0;
+        for tag in images.iter().flat_map(|image| &image.repo_tags) {
+            let options = bollard::query_parameters::RemoveImageOptionsBuilder::new().force(true).build();
+            match self.docker.remove_image(tag, Some(options), None).await {
+                Ok(_) => removed += 1,
+                Err(e) => warn!("Failed to remove image {}: {}", tag, e),
+            }
+        }
+        Ok(removed)
+    }
+
     pub async fn run_standalone_container(
         &self,
         name: &str,
         image: &str,
         host_port: i32,
         container_port: i32,
+        extra_ports: &[PortMapping],
         env_vars: Option<&str>,
         command: Option<&str>,
+        exec: &ExecForm,
+        restart: RestartConfig,
         persist_data: bool,
+        profile: Option<&ImageProfile>,
+        network: &str,
     ) -> Result<String> {
         self.ensure_image(image).await?;
 
@@ -592,60 +1332,17 @@ impl DockerClient {
                 })
         }).unwrap_or_default();
 
-        // Add data directory env vars for known databases if persist_data is enabled
+        // persist_data: 이미지 프로필의 데이터 환경변수 추가 (프로필이 없으면 /data 마운트만)
         if persist_data {
-            let image_lower = image.to_lowercase();
-            if image_lower.contains("postgres") || image_lower.contains("timescale") {
-                // PostgreSQL/TimescaleDB: Set PGDATA to use /data directory
-                env.push("PGDATA=/data".to_string());
-                info!("Added PGDATA=/data for PostgreSQL/TimescaleDB");
-            } else if image_lower.contains("mysql") || image_lower.contains("mariadb") {
-                // MySQL/MariaDB: Set data directory
-                env.push("MYSQL_DATADIR=/data".to_string());
-                info!("Added MYSQL_DATADIR=/data for MySQL/MariaDB");
-            } else if image_lower.contains("mongo") {
-                // MongoDB: Set data directory
-                env.push("MONGODB_DBPATH=/data".to_string());
-                info!("Added MONGODB_DBPATH=/data for MongoDB");
-            } else if image_lower.contains("redis") {
-                // Redis: Set data directory and enable persistence
-                env.push("REDIS_DATA_DIR=/data".to_string());
-                info!("Added REDIS_DATA_DIR=/data for Redis");
-            } else if image_lower.contains("elasticsearch") || image_lower.contains("opensearch") {
-                // Elasticsearch/OpenSearch: Set data path
-                env.push("path.data=/data".to_string());
-                info!("Added path.data=/data for Elasticsearch/OpenSearch");
-            } else if image_lower.contains("cassandra") || image_lower.contains("scylla") {
-                // Cassandra/ScyllaDB: Set data directory
-                env.push("CASSANDRA_DATA_DIR=/data".to_string());
-                info!("Added CASSANDRA_DATA_DIR=/data for Cassandra/ScyllaDB");
-            } else if image_lower.contains("couchdb") {
-                // CouchDB: Set data directory
-                env.push("COUCHDB_DATA_DIR=/data".to_string());
-                info!("Added COUCHDB_DATA_DIR=/data for CouchDB");
-            } else if image_lower.contains("influxdb") {
-                // InfluxDB: Set data paths
-                env.push("INFLUXDB_DATA_DIR=/data".to_string());
-                env.push("INFLUXDB_META_DIR=/data/meta".to_string());
-                env.push("INFLUXDB_WAL_DIR=/data/wal".to_string());
-                info!("Added INFLUXDB_*_DIR=/data for InfluxDB");
-            } else if image_lower.contains("neo4j") {
-                // Neo4j: Data already in /data by default
-                env.push("NEO4J_DATA=/data".to_string());
-                info!("Added NEO4J_DATA=/data for Neo4j");
-            } else if image_lower.contains("rabbitmq") {
-                // RabbitMQ: Set data directory
-                env.push("RABBITMQ_MNESIA_BASE=/data".to_string());
-                info!("Added RABBITMQ_MNESIA_BASE=/data for RabbitMQ");
+            if let Some(profile) = profile {
+                env.extend(profile.data_env.iter().map(|(k, v)| format!("{}={}", k, v)));
+                info!("Applied data settings of image profile '{}'", profile.name);
             }
         }
 
         let env_option = if env.is_empty() { None } else { Some(env) };
 
-        // Parse command if provided
-        let cmd: Option<Vec<String>> = command.map(|c| {
-            vec!["/bin/sh".to_string(), "-c".to_string(), c.to_string()]
-        });
+        let (entrypoint, cmd) = entrypoint_and_cmd(exec, command);
 
         // Setup volume binds if persist_data is enabled
         let binds: Option<Vec<String>> = if persist_data {
@@ -653,37 +1350,24 @@ impl DockerClient {
             let data_dir = format!("/data/easycicd/containers/{}/data", name);
             std::fs::create_dir_all(&data_dir).ok();
 
-            // Mount to /data in container
-            info!("Mounting persistent data: {} -> /data", data_dir);
-            Some(vec![format!("{}:/data", data_dir)])
+            // Mount to the profile's data path (/data by default)
+            let data_path = profile.map(|p| p.data_path.as_str()).unwrap_or("/data");
+            info!("Mounting persistent data: {} -> {}", data_dir, data_path);
+            Some(vec![format!("{}:{}", data_dir, data_path)])
         } else {
             None
         };
 
         let config = Config {
             image: Some(image.to_string()),
+            entrypoint,
             cmd,
             env: env_option,
             host_config: Some(bollard::models::HostConfig {
-                port_bindings: Some({
-                    let mut port_bindings = HashMap::new();
-                    // Map host_port:container_port
-                    info!("Port mapping: {}:{}", host_port, container_port);
-                    port_bindings.insert(
-                        format!("{}/tcp", container_port),
-                        Some(vec![bollard::models::PortBinding {
-                            host_ip: Some("0.0.0.0".to_string()),
-                            host_port: Some(host_port.to_string()),
-                        }]),
-                    );
-                    port_bindings
-                }),
+                port_bindings: Some(standalone_port_bindings(host_port, container_port, extra_ports)),
                 binds,
                 publish_all_ports: Some(true),
-                restart_policy: Some(bollard::models::RestartPolicy {
-                    name: Some(bollard::models::RestartPolicyNameEnum::UNLESS_STOPPED),
-                    ..Default::default()
-                }),
+                restart_policy: Some(docker_restart_policy(restart)),
                 ..Default::default()
             }),
             ..Default::default()
@@ -704,11 +1388,11 @@ impl DockerClient {
 
         let container_id = container.id.clone();
 
-        // Connect to easycicd network
-        info!("Connecting standalone container to easycicd network");
+        // Connect to easycicd network (또는 그룹 전용 네트워크)
+        info!("Connecting standalone container to network {}", network);
         self.docker
             .connect_network(
-                "easycicd_easycicd",
+                network,
                 bollard::network::ConnectNetworkOptions {
                     container: container_id.as_str(),
                     ..Default::default()
@@ -741,6 +1425,22 @@ impl DockerClient {
         &self.docker
     }
 
+    /// 사용되지 않는 dangling 이미지 정리. (삭제된 이미지 수, 회수한 bytes) 반환
+    pub async fn prune_dangling_images(&self) -> Result<(usize, i64)> {
+        let filters = HashMap::from([("dangling", vec!["true"])]);
+        let options = bollard::query_parameters::PruneImagesOptionsBuilder::new()
+            .filters(&filters)
+            .build();
+
+        let response = self.docker
+            .prune_images(Some(options))
+            .await
+            .context("Failed to prune images")?;
+
+        let deleted = response.images_deleted.map(|d| d.len()).unwrap_or(0);
+        Ok((deleted, response.space_reclaimed.unwrap_or(0)))
+    }
+
     /// Start container
     pub async fn start_container(&self, container_id: &str) -> Result<()> {
         info!("Starting container: {}", container_id);
@@ -820,6 +1520,22 @@ impl DockerClient {
         Ok(())
     }
 
+    /// 실행 중인 컨테이너의 재시작 정책 변경 (재생성 없이 적용)
+    pub async fn update_restart_policy(&self, container_id: &str, restart: RestartConfig) -> Result<()> {
+        info!("Updating restart policy of {}: {}", container_id, restart.policy);
+        self.docker
+            .update_container(
+                container_id,
+                bollard::models::ContainerUpdateBody {
+                    restart_policy: Some(docker_restart_policy(restart)),
+                    ..Default::default()
+                },
+            )
+            .await
+            .context("Failed to update restart policy")?;
+        Ok(())
+    }
+
     /// Remove container (logs error but doesn't fail for cleanup scenarios)
     pub async fn remove_container(&self, container_id: &str) -> Result<()> {
         info!("Removing container: {}", container_id);
@@ -839,6 +1555,201 @@ impl DockerClient {
         Ok(())
     }
 
+    /// 빌드의 빌드/테스트 컨테이너를 모두 강제 삭제. 삭제한 컨테이너 수
+    pub async fn remove_build_containers(&self, build_id: i64) -> Result<usize> {
+        #[allow(deprecated)]
+        let containers = self.docker
+            .list_containers(Some(bollard::container::ListContainersOptions::<String> {
+                all: true,
+                filters: HashMap::from([(
+                    "label".to_string(),
+                    vec![format!("{}={}", BUILD_CONTAINER_LABEL, build_id)],
+                )]),
+                ..Default::default()
+            }))
+            .await
+            .context("Failed to list build containers")?;
+
+        let mut removed = 0;
+        for id in containers.into_iter().filter_map(|c| c.id) {
+            self.remove_container(&id).await?;
+            removed += 1;
+        }
+        Ok(removed)
+    }
+
+    /// 컨테이너 CPU/메모리 사용량 1회 샘플링 (docker stats, non-stream).
+    /// 실행 중이 아니면 Ok(None)
+    pub async fn container_stats(&self, container_id: &str) -> Result<Option<ContainerStats>> {
+        let options = bollard::query_parameters::StatsOptions {
+            stream: false,
+            one_shot: false,  // precpu_stats를 채우기 위해 2회 측정
+        };
+
+        let mut stream = self.docker.stats(container_id, Some(options));
+        let stats = match stream.next().await {
+            Some(Ok(s)) => s,
+            Some(Err(e)) => return Err(e).context("Failed to get container stats"),
+            None => return Ok(None),
+        };
+
+        let (cpu, precpu) = match (stats.cpu_stats, stats.precpu_stats) {
+            (Some(c), Some(p)) => (c, p),
+            _ => return Ok(None),
+        };
+        let total = |s: &bollard::models::ContainerCpuStats| {
+            s.cpu_usage.as_ref().and_then(|u| u.total_usage).unwrap_or(0)
+        };
+
+        let cpu_delta = total(&cpu).saturating_sub(total(&precpu)) as f64;
+        let system_delta = cpu.system_cpu_usage.unwrap_or(0)
+            .saturating_sub(precpu.system_cpu_usage.unwrap_or(0)) as f64;
+        let online_cpus = cpu.online_cpus.unwrap_or(1).max(1) as f64;
+        let cpu_percent = if system_delta > 0.0 {
+            cpu_delta / system_delta * online_cpus * 100.0
+        } else {
+            0.0
+        };
+
+        let memory = stats.memory_stats.unwrap_or_default();
+        // docker CLI와 같은 방식: cgroup v2는 inactive_file, v1은 cache를 제외
+        let cache = memory.stats.as_ref()
+            .and_then(|m| m.get("inactive_file").or_else(|| m.get("cache")).copied())
+            .unwrap_or(0);
+        let memory_bytes = memory.usage.unwrap_or(0).saturating_sub(cache);
+
+        Ok(Some(ContainerStats {
+            cpu_percent,
+            memory_bytes,
+            memory_limit: memory.limit.unwrap_or(0),
+        }))
+    }
+
+    /// 빌드 사용량 측정용 1회 샘플: (메모리 bytes, 누적 CPU ns). 실행 중이 아니면 Ok(None)
+    async fn sample_usage(&self, container_id: &str) -> Result<Option<(u64, u64)>> {
+        let options = bollard::query_parameters::StatsOptions {
+            stream: false,
+            one_shot: true,  // 누적 카운터만 필요하므로 precpu 측정 생략
+        };
+
+        let stats = match self.docker.stats(container_id, Some(options)).next().await {
+            Some(Ok(s)) => s,
+            Some(Err(e)) => return Err(e).context("Failed to get container stats"),
+            None => return Ok(None),
+        };
+
+        let cpu_ns = stats.cpu_stats
+            .and_then(|c| c.cpu_usage)
+            .and_then(|u| u.total_usage)
+            .unwrap_or(0);
+        let memory = stats.memory_stats.unwrap_or_default();
+        let cache = memory.stats.as_ref()
+            .and_then(|m| m.get("inactive_file").or_else(|| m.get("cache")).copied())
+            .unwrap_or(0);
+        let memory_bytes = memory.usage.unwrap_or(0).saturating_sub(cache);
+
+        Ok(Some((memory_bytes, cpu_ns)))
+    }
+
+    /// 프로젝트 슬롯 컨테이너 이름 (run_runtime_container가 사용하는 이름)
+    pub fn project_container_name(project_id: i64, slot: Slot) -> String {
+        format!("project-{}-{}", project_id, slot.to_string().to_lowercase())
+    }
+
+    /// PR 프리뷰 컨테이너 슬롯 이름 (컨테이너 이름은 `project-{id}-pr-{number}`)
+    pub fn preview_slot(pr_number: i64) -> String {
+        format!("pr-{}", pr_number)
+    }
+
+    pub fn preview_container_name(project_id: i64, pr_number: i64) -> String {
+        format!("project-{}-{}", project_id, Self::preview_slot(pr_number))
+    }
+
+    /// 런타임 컨테이너가 실행 중인 빌드 ID (`easycicd.build_id` label). 라벨이 없는 이전 컨테이너는 None
+    pub async fn container_build_id(&self, container_id: &str) -> Option<i64> {
+        let info = self.docker.inspect_container(container_id, None::<InspectContainerOptions>).await.ok()?;
+        info.config?.labels?.get(BUILD_ID_LABEL)?.parse().ok()
+    }
+
+    /// 네트워크에서 컨테이너의 alias 교체 (재연결). 연결이 잠깐 끊기므로
+    /// 프록시가 트래픽을 보내지 않는 컨테이너에만 사용
+    pub async fn set_network_aliases(&self, network: &str, container_id: &str, aliases: Vec<String>) -> Result<()> {
+        self.docker
+            .disconnect_network(
+                network,
+                bollard::network::DisconnectNetworkOptions {
+                    container: container_id,
+                    force: false,
+                },
+            )
+            .await
+            .context("Failed to disconnect container from network")?;
+        self.docker
+            .connect_network(
+                network,
+                bollard::network::ConnectNetworkOptions {
+                    container: container_id,
+                    endpoint_config: bollard::models::EndpointSettings {
+                        aliases: Some(aliases),
+                        ..Default::default()
+                    },
+                },
+            )
+            .await
+            .context("Failed to reconnect container to network")?;
+        Ok(())
+    }
+
+    /// 이름 또는 ID로 컨테이너 ID 조회. 없으면 Ok(None)
+    async fn inspect_container_id(&self, name_or_id: &str) -> Result<Option<String>> {
+        match self.docker.inspect_container(name_or_id, None::<InspectContainerOptions>).await {
+            Ok(info) => Ok(info.id),
+            Err(bollard::errors::Error::DockerResponseServerError { status_code: 404, .. }) => Ok(None),
+            Err(e) => Err(e).context("Failed to inspect container"),
+        }
+    }
+
+    /// 프로젝트 슬롯의 실제 컨테이너 ID
+    ///
+    /// 수동 docker 조작(재생성, rm 등)으로 저장된 ID가 사라졌으면 이름(`project-{id}-{slot}`)으로
+    /// 다시 찾아 DB를 갱신하고, 이름으로도 없으면 DB에서 지운다. 컨테이너가 없으면 Ok(None).
+    /// 정지된 컨테이너도 반환하므로 실행 여부는 호출자가 확인한다.
+    pub async fn resolve_container<PR: ProjectRepository + ?Sized>(
+        &self,
+        project_repo: &PR,
+        project_id: i64,
+        slot: Slot,
+    ) -> Result<Option<String>> {
+        let Some(project) = project_repo.get(project_id).await? else {
+            return Ok(None);
+        };
+        let stored = match slot {
+            Slot::Blue => project.blue_container_id,
+            Slot::Green => project.green_container_id,
+        };
+
+        if let Some(ref id) = stored {
+            if let Some(actual) = self.inspect_container_id(id).await? {
+                return Ok(Some(actual));
+            }
+        }
+
+        let resolved = self.inspect_container_id(&Self::project_container_name(project_id, slot)).await?;
+        if resolved == stored {
+            return Ok(resolved);
+        }
+
+        info!(
+            "Project {} {} container reconciled by name: {:?} -> {:?}",
+            project_id, slot, stored, resolved
+        );
+        match slot {
+            Slot::Blue => project_repo.update_blue_container(project_id, resolved.clone()).await?,
+            Slot::Green => project_repo.update_green_container(project_id, resolved.clone()).await?,
+        }
+        Ok(resolved)
+    }
+
     /// Check if container is running
     pub async fn is_container_running(&self, container_id: &str) -> bool {
         match self.docker.inspect_container(container_id, None::<bollard::container::InspectContainerOptions>).await {
@@ -891,6 +1802,30 @@ impl DockerClient {
         }))
     }
 
+    /// 컨테이너 로그 스트리밍 (`since` unix 초 이후 로그부터, 없으면 처음부터)
+    pub async fn stream_container_logs_since(&self, container_id: &str, since: Option<i64>) -> Result<impl futures_util::Stream<Item = Result<Vec<u8>, bollard::errors::Error>>> {
+        use bollard::container::LogsOptions;
+
+        let options = Some(LogsOptions::<String> {
+            follow: true,
+            stdout: true,
+            stderr: true,
+            since: since.unwrap_or(0),
+            tail: "all".to_string(),
+            ..Default::default()
+        });
+
+        let stream = self.docker.logs(container_id, options);
+
+        Ok(stream.map(|result| {
+            result.map(|output| match output {
+                LogOutput::StdOut { message } => message.to_vec(),
+                LogOutput::StdErr { message } => message.to_vec(),
+                _ => Vec::new(),
+            })
+        }))
+    }
+
     /// 컨테이너 내부에서 명령 실행 (exec 세션 생성)
     pub async fn create_exec_session(
         &self,
@@ -925,6 +1860,35 @@ impl DockerClient {
         Ok((exec_instance.id, output))
     }
 
+    /// 컨테이너 안에서 `sh -c`로 명령을 실행하고 exit code 반환 (헬스체크용, 출력은 버림)
+    pub async fn exec_exit_code(&self, container_id: &str, command: &str) -> Result<i64> {
+        let exec_config = CreateExecOptions {
+            attach_stdout: Some(true),
+            attach_stderr: Some(true),
+            cmd: Some(vec!["/bin/sh".to_string(), "-c".to_string(), command.to_string()]),
+            ..Default::default()
+        };
+
+        let exec_instance = self.docker
+            .create_exec(container_id, exec_config)
+            .await
+            .context("Failed to create exec instance")?;
+
+        if let StartExecResults::Attached { mut output, .. } = self.docker
+            .start_exec(&exec_instance.id, None::<StartExecOptions>)
+            .await
+            .context("Failed to start exec")?
+        {
+            while output.next().await.is_some() {}
+        }
+
+        let inspect = self.docker
+            .inspect_exec(&exec_instance.id)
+            .await
+            .context("Failed to inspect exec")?;
+        inspect.exit_code.context("Exec finished without exit code")
+    }
+
     /// exec PTY 크기 조정
     pub async fn resize_exec_tty(
         &self,
@@ -943,3 +1907,158 @@ impl DockerClient {
         Ok(())
     }
 }
+
+/// Docker RestartPolicy (최대 재시도 횟수는 on-failure에서만 전달, Docker가 다른 정책에서는 거부)
+fn docker_restart_policy(restart: RestartConfig) -> bollard::models::RestartPolicy {
+    use bollard::models::RestartPolicyNameEnum;
+    let name = match restart.policy {
+        RestartPolicy::No => RestartPolicyNameEnum::NO,
+        RestartPolicy::OnFailure => RestartPolicyNameEnum::ON_FAILURE,
+        RestartPolicy::UnlessStopped => RestartPolicyNameEnum::UNLESS_STOPPED,
+        RestartPolicy::Always => RestartPolicyNameEnum::ALWAYS,
+    };
+    bollard::models::RestartPolicy {
+        name: Some(name),
+        maximum_retry_count: restart.max_retries.filter(|_| restart.policy == RestartPolicy::OnFailure),
+    }
+}
+
+/// 컨테이너 (ENTRYPOINT, CMD): exec 형식이 있으면 그대로, 없으면 셸 명령을 `/bin/sh -c`로 감쌈
+fn entrypoint_and_cmd(exec: &ExecForm, shell_command: Option<&str>) -> (Option<Vec<String>>, Option<Vec<String>>) {
+    if exec.is_set() {
+        return (exec.entrypoint.clone(), exec.args.clone());
+    }
+    let cmd = shell_command.map(|c| vec!["/bin/sh".to_string(), "-c".to_string(), c.to_string()]);
+    (None, cmd)
+}
+
+/// 독립 컨테이너 포트 바인딩: 기본 host_port:container_port/tcp + 추가 매핑
+fn standalone_port_bindings(
+    host_port: i32,
+    container_port: i32,
+    extra_ports: &[PortMapping],
+) -> HashMap<String, Option<Vec<bollard::models::PortBinding>>> {
+    let primary = PortMapping { host_port, container_port, protocol: PortProtocol::Tcp };
+    std::iter::once(&primary)
+        .chain(extra_ports)
+        .map(|mapping| {
+            info!("Port mapping: {}:{}/{}", mapping.host_port, mapping.container_port, mapping.protocol);
+            (
+                format!("{}/{}", mapping.container_port, mapping.protocol),
+                Some(vec![bollard::models::PortBinding {
+                    host_ip: Some("0.0.0.0".to_string()),
+                    host_port: Some(mapping.host_port.to_string()),
+                }]),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_docker_restart_policy() {
+        let default = docker_restart_policy(RestartConfig::default());
+        assert_eq!(default.name, Some(bollard::models::RestartPolicyNameEnum::UNLESS_STOPPED));
+        assert_eq!(default.maximum_retry_count, None);
+
+        let on_failure = docker_restart_policy(RestartConfig { policy: RestartPolicy::OnFailure, max_retries: Some(5) });
+        assert_eq!(on_failure.name, Some(bollard::models::RestartPolicyNameEnum::ON_FAILURE));
+        assert_eq!(on_failure.maximum_retry_count, Some(5));
+
+        let always = docker_restart_policy(RestartConfig { policy: RestartPolicy::Always, max_retries: Some(5) });
+        assert_eq!(always.maximum_retry_count, None);
+    }
+
+    #[test]
+    fn test_entrypoint_and_cmd() {
+        let shell = entrypoint_and_cmd(&ExecForm::default(), Some("node index.js"));
+        assert_eq!(shell, (None, Some(vec!["/bin/sh".to_string(), "-c".to_string(), "node index.js".to_string()])));
+        assert_eq!(entrypoint_and_cmd(&ExecForm::default(), None), (None, None));
+
+        let exec = ExecForm { entrypoint: None, args: Some(vec!["--config".to_string(), "/etc/app.yml".to_string()]) };
+        assert_eq!(entrypoint_and_cmd(&exec, Some("ignored")), (None, exec.args.clone()));
+
+        let exec = ExecForm { entrypoint: Some(vec!["/docker-entrypoint.sh".to_string()]), args: None };
+        assert_eq!(entrypoint_and_cmd(&exec, None), (exec.entrypoint.clone(), None));
+    }
+
+    #[test]
+    fn test_standalone_port_bindings() {
+        let extra = vec![
+            PortMapping { host_port: 15001, container_port: 9093, protocol: PortProtocol::Tcp },
+            PortMapping { host_port: 5060, container_port: 5060, protocol: PortProtocol::Udp },
+        ];
+        let bindings = standalone_port_bindings(15000, 9092, &extra);
+        assert_eq!(bindings.len(), 3);
+        let host_port = |key: &str| bindings[key].as_ref().unwrap()[0].host_port.clone();
+        assert_eq!(host_port("9092/tcp").as_deref(), Some("15000"));
+        assert_eq!(host_port("9093/tcp").as_deref(), Some("15001"));
+        assert_eq!(host_port("5060/udp").as_deref(), Some("5060"));
+    }
+
+    #[test]
+    fn test_pinned_image_reference() {
+        let digests = vec![
+            "mirror.local/node@sha256:aaa".to_string(),
+            "node@sha256:bbb".to_string(),
+        ];
+        assert_eq!(pinned_image_reference("node:20-slim", &digests, Some("sha256:id")).as_deref(), Some("node@sha256:bbb"));
+        assert_eq!(pinned_image_reference("other:1", &digests, None).as_deref(), Some("mirror.local/node@sha256:aaa"));
+        // 레지스트리 포트가 있는 이미지
+        let digests = vec!["registry:5000/app@sha256:ccc".to_string()];
+        assert_eq!(pinned_image_reference("registry:5000/app:1.0", &digests, None).as_deref(), Some("registry:5000/app@sha256:ccc"));
+        // 로컬 전용 이미지는 ID
+        assert_eq!(pinned_image_reference("local-app", &[], Some("sha256:id")).as_deref(), Some("sha256:id"));
+
+        assert!(is_pinned_image("node@sha256:bbb"));
+        assert!(is_pinned_image("sha256:id"));
+        assert!(!is_pinned_image("node:20-slim"));
+    }
+
+    #[test]
+    fn test_mirror_reference() {
+        let mirror = "127.0.0.1:5000";
+        assert_eq!(mirror_reference("node:20", mirror).as_deref(), Some("127.0.0.1:5000/library/node:20"));
+        assert_eq!(mirror_reference("docker.io/bitnami/redis", mirror).as_deref(), Some("127.0.0.1:5000/bitnami/redis:latest"));
+        assert_eq!(mirror_reference("ghcr.io/org/app:1.0", mirror), None);
+        assert_eq!(mirror_reference("registry:5000/app:1.0", mirror), None);
+        assert_eq!(mirror_reference("node@sha256:abc", mirror), None);
+    }
+
+    #[test]
+    fn test_build_image_tag() {
+        assert_eq!(build_image_tag(3, 12), "project-3:build-12");
+    }
+
+    #[test]
+    fn test_validate_network_group() {
+        assert!(validate_network_group("kafka-cluster").is_ok());
+        assert!(validate_network_group("").is_err());
+        assert!(validate_network_group("Kafka").is_err());
+        assert!(validate_network_group("-db").is_err());
+        assert!(validate_network_group(&"a".repeat(33)).is_err());
+        assert_eq!(group_network_name("db"), "easycicd-group-db");
+        assert_eq!(project_network_name(7), "easycicd-project-7");
+    }
+
+    #[test]
+    fn test_validate_extra_networks() {
+        let networks = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+        assert!(validate_extra_networks(&networks(&["db_backend", "monitoring.net"]), DEFAULT_DOCKER_NETWORK).is_ok());
+        assert!(validate_extra_networks(&networks(&["-bad"]), DEFAULT_DOCKER_NETWORK).is_err());
+        assert!(validate_extra_networks(&networks(&["a b"]), DEFAULT_DOCKER_NETWORK).is_err());
+        assert!(validate_extra_networks(&networks(&["db", "db"]), DEFAULT_DOCKER_NETWORK).is_err());
+        assert!(validate_extra_networks(&networks(&[DEFAULT_DOCKER_NETWORK]), DEFAULT_DOCKER_NETWORK).is_err());
+    }
+
+    #[test]
+    fn test_image_digest() {
+        assert_eq!(image_digest("node@sha256:bbb"), Some("sha256:bbb"));
+        assert_eq!(image_digest("sha256:id"), Some("sha256:id"));
+        assert_eq!(image_digest("node:20-slim"), None);
+    }
+}
@@ -1,3 +1,3 @@
 pub mod client;
 
-pub use client::DockerClient;
+pub use client::{build_image_tag, cache_mount_path, group_network_name, image_digest, project_network_name, BuildContainerOptions, BuildResult, ContainerStats, DockerClient, validate_extra_networks, validate_network_group};
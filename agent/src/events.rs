@@ -1,5 +1,60 @@
 use serde::{Deserialize, Serialize};
 use crate::db::{BuildStatus, Slot};
+use crate::application::services::BuildEstimate;
+
+/// 빌드 진행 단계 (UI 단계 타임라인용)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BuildStep {
+    /// 캐시 디렉토리 준비 (/data/cache/{cache_type} 마운트)
+    RestoringCache,
+    /// 소스 받기 (tarball 다운로드 또는 컨테이너 안 git clone)
+    Cloning,
+    /// 프로젝트 build_command
+    Building,
+    /// 산출물을 /output으로 복사
+    CopyingArtifacts,
+    /// /output의 소스로 런타임 이미지 빌드 (Dockerfile 프로젝트만)
+    BuildingImage,
+    /// 산출물 검증과 checksum 기록
+    Validating,
+    /// 테스트 shard 실행 (test_config가 있을 때만)
+    Testing,
+}
+
+impl BuildStep {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BuildStep::RestoringCache => "restoring_cache",
+            BuildStep::Cloning => "cloning",
+            BuildStep::Building => "building",
+            BuildStep::CopyingArtifacts => "copying_artifacts",
+            BuildStep::BuildingImage => "building_image",
+            BuildStep::Validating => "validating",
+            BuildStep::Testing => "testing",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        [
+            BuildStep::RestoringCache,
+            BuildStep::Cloning,
+            BuildStep::Building,
+            BuildStep::CopyingArtifacts,
+            BuildStep::BuildingImage,
+            BuildStep::Validating,
+            BuildStep::Testing,
+        ]
+        .into_iter()
+        .find(|step| step.as_str() == s)
+    }
+}
+
+impl std::fmt::Display for BuildStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -69,6 +124,58 @@ pub enum Event {
         timestamp: String,
     },
 
+    /// 빌드 단계 진행. 새 단계가 시작되면 이전 단계는 completed로 끝난다
+    #[serde(rename = "build_step")]
+    BuildStep {
+        build_id: i64,
+        project_id: i64,
+        step: BuildStep,
+        /// started, completed, failed
+        status: String,
+        /// 단계 소요 시간 (completed/failed만)
+        duration_ms: Option<u64>,
+        timestamp: String,
+    },
+
+    /// 파이프라인 단계 진행 (projects.pipeline). 실패한 단계 뒤의 단계는 skipped
+    #[serde(rename = "build_stage")]
+    BuildStage {
+        build_id: i64,
+        project_id: i64,
+        stage: String,
+        /// 파이프라인 안의 순서 (0부터)
+        index: usize,
+        /// started, completed, failed, skipped
+        status: String,
+        /// 단계 소요 시간 (completed/failed만)
+        duration_ms: Option<u64>,
+        timestamp: String,
+    },
+
+    /// 실행 중 빌드의 진행률 (최근 성공 빌드 평균 소요 시간 기준)
+    #[serde(rename = "build_progress")]
+    BuildProgress {
+        build_id: i64,
+        project_id: i64,
+        elapsed_secs: i64,
+        eta_secs: i64,
+        progress_percent: u8,
+        average_duration_secs: i64,
+        timestamp: String,
+    },
+
+    /// 빌드가 알림 기준보다 오래 Queued 상태로 대기 중
+    #[serde(rename = "queue_wait_exceeded")]
+    QueueWaitExceeded {
+        build_id: i64,
+        project_id: i64,
+        wait_secs: i64,
+        threshold_secs: i64,
+        /// 전체 대기 중인 빌드 수
+        queue_depth: usize,
+        timestamp: String,
+    },
+
     #[serde(rename = "error")]
     Error {
         build_id: Option<i64>,
@@ -80,7 +187,25 @@ pub enum Event {
 
 impl Event {
     pub fn now() -> String {
-        chrono::Local::now().to_rfc3339()
+        crate::infrastructure::timezone::now_display()
+    }
+
+    /// 직렬화 시 "type" 태그와 동일한 이벤트 이름
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            Event::BuildStatus { .. } => "build_status",
+            Event::Log { .. } => "log",
+            Event::Deployment { .. } => "deployment",
+            Event::HealthCheck { .. } => "health_check",
+            Event::ContainerStatus { .. } => "container_status",
+            Event::StandaloneContainerStatus { .. } => "standalone_container_status",
+            Event::ContainerLog { .. } => "container_log",
+            Event::BuildStep { .. } => "build_step",
+            Event::BuildStage { .. } => "build_stage",
+            Event::BuildProgress { .. } => "build_progress",
+            Event::QueueWaitExceeded { .. } => "queue_wait_exceeded",
+            Event::Error { .. } => "error",
+        }
     }
 
     pub fn build_status(build_id: i64, project_id: i64, status: BuildStatus) -> Self {
@@ -173,6 +298,52 @@ impl Event {
         }
     }
 
+    pub fn build_step(build_id: i64, project_id: i64, step: BuildStep, status: &str, duration_ms: Option<u64>) -> Self {
+        Event::BuildStep {
+            build_id,
+            project_id,
+            step,
+            status: status.to_string(),
+            duration_ms,
+            timestamp: Self::now(),
+        }
+    }
+
+    pub fn build_stage(build_id: i64, project_id: i64, stage: &str, index: usize, status: &str, duration_ms: Option<u64>) -> Self {
+        Event::BuildStage {
+            build_id,
+            project_id,
+            stage: stage.to_string(),
+            index,
+            status: status.to_string(),
+            duration_ms,
+            timestamp: Self::now(),
+        }
+    }
+
+    pub fn build_progress(build_id: i64, project_id: i64, estimate: &BuildEstimate) -> Self {
+        Event::BuildProgress {
+            build_id,
+            project_id,
+            elapsed_secs: estimate.elapsed_secs,
+            eta_secs: estimate.eta_secs,
+            progress_percent: estimate.progress_percent,
+            average_duration_secs: estimate.average_duration_secs,
+            timestamp: Self::now(),
+        }
+    }
+
+    pub fn queue_wait_exceeded(build_id: i64, project_id: i64, wait_secs: i64, threshold_secs: i64, queue_depth: usize) -> Self {
+        Event::QueueWaitExceeded {
+            build_id,
+            project_id,
+            wait_secs,
+            threshold_secs,
+            queue_depth,
+            timestamp: Self::now(),
+        }
+    }
+
     pub fn error(build_id: Option<i64>, project_id: Option<i64>, message: String) -> Self {
         Event::Error {
             build_id,
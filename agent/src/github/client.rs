@@ -1,6 +1,50 @@
 use anyhow::{Result, anyhow};
+use async_trait::async_trait;
 use reqwest::Client;
+use std::path::Path;
+use tokio::io::AsyncWriteExt;
 use super::models::*;
+use crate::application::ports::git_provider::GitProvider;
+
+/// Parse owner and repo name from various repo URL formats
+pub fn parse_repo_owner_name(repo_url: &str) -> Option<(String, String)> {
+    // Handle formats like:
+    // - "owner/repo"
+    // - "https://github.com/owner/repo"
+    // - "https://github.com/owner/repo.git"
+    // - "git@github.com:owner/repo.git"
+
+    let cleaned = repo_url
+        .trim()
+        .trim_end_matches(".git")
+        .trim_end_matches('/');
+
+    // Try to extract from URL format
+    if cleaned.contains("github.com") {
+        // HTTPS format: https://github.com/owner/repo
+        if let Some(path) = cleaned.strip_prefix("https://github.com/") {
+            let parts: Vec<&str> = path.split('/').collect();
+            if parts.len() >= 2 {
+                return Some((parts[0].to_string(), parts[1].to_string()));
+            }
+        }
+        // SSH format: git@github.com:owner/repo
+        if let Some(path) = cleaned.strip_prefix("git@github.com:") {
+            let parts: Vec<&str> = path.split('/').collect();
+            if parts.len() >= 2 {
+                return Some((parts[0].to_string(), parts[1].to_string()));
+            }
+        }
+    }
+
+    // Simple format: owner/repo (git@gitlab.com:group/repo 같은 다른 호스트의 SSH URL 제외)
+    let parts: Vec<&str> = cleaned.split('/').collect();
+    if parts.len() == 2 && !parts[0].is_empty() && !parts[1].is_empty() && !parts[0].contains(':') {
+        return Some((parts[0].to_string(), parts[1].to_string()));
+    }
+
+    None
+}
 
 #[derive(Debug, Clone)]
 pub struct GitHubClient {
@@ -110,6 +154,26 @@ impl GitHubClient {
         Ok(response.json().await?)
     }
 
+    /// Get a single branch (used to check that a branch exists)
+    pub async fn get_branch(&self, owner: &str, repo: &str, branch: &str) -> Result<Branch> {
+        let url = format!("https://api.github.com/repos/{}/{}/branches/{}", owner, repo, branch);
+        let response = self.client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("User-Agent", "EasyCI CD")
+            .header("Accept", "application/vnd.github.v3+json")
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await?;
+            return Err(anyhow!("GitHub API error ({}): {}", status, body));
+        }
+
+        Ok(response.json().await?)
+    }
+
     /// Get repository tree (for folder structure)
     pub async fn get_tree(&self, owner: &str, repo: &str, sha: &str) -> Result<Tree> {
         let url = format!("https://api.github.com/repos/{}/{}/git/trees/{}?recursive=1", owner, repo, sha);
@@ -143,7 +207,7 @@ impl GitHubClient {
         let request = CreateWebhookRequest {
             name: "web".to_string(),
             active: true,
-            events: vec!["push".to_string()],
+            events: vec!["push".to_string(), "pull_request".to_string()],
             config: WebhookConfig {
                 url: webhook_url.to_string(),
                 content_type: "json".to_string(),
@@ -204,6 +268,36 @@ impl GitHubClient {
         }
     }
 
+    /// Download the repository tarball for a ref (branch, tag or commit) into `dest`
+    pub async fn download_tarball(&self, owner: &str, repo: &str, git_ref: &str, dest: &Path) -> Result<u64> {
+        let url = format!("https://api.github.com/repos/{}/{}/tarball/{}", owner, repo, git_ref);
+
+        // codeload.github.com으로 redirect되며, 임시 토큰이 URL에 포함되어 Authorization 없이 받음
+        let mut response = self.client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("User-Agent", "EasyCI CD")
+            .header("Accept", "application/vnd.github.v3+json")
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await?;
+            return Err(anyhow!("GitHub API error ({}): {}", status, body));
+        }
+
+        let mut file = tokio::fs::File::create(dest).await?;
+        let mut written = 0u64;
+        while let Some(chunk) = response.chunk().await? {
+            file.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        file.flush().await?;
+
+        Ok(written)
+    }
+
     /// Delete webhook
     pub async fn delete_webhook(&self, owner: &str, repo: &str, hook_id: u64) -> Result<()> {
         let url = format!("https://api.github.com/repos/{}/{}/hooks/{}", owner, repo, hook_id);
@@ -225,6 +319,144 @@ impl GitHubClient {
         Ok(())
     }
 
+    /// List organization team members (팀 slug 기준, 하위 팀 멤버 포함)
+    pub async fn list_team_members(&self, org: &str, team_slug: &str) -> Result<Vec<User>> {
+        let url = format!("https://api.github.com/orgs/{}/teams/{}/members", org, team_slug);
+        let per_page = 100;
+        let mut members = Vec::new();
+
+        // 최대 10페이지 (1000명)
+        for page in 1..=10 {
+            let response = self.client
+                .get(&url)
+                .query(&[("per_page", per_page.to_string()), ("page", page.to_string())])
+                .header("Authorization", format!("Bearer {}", self.token))
+                .header("User-Agent", "EasyCI CD")
+                .header("Accept", "application/vnd.github.v3+json")
+                .send()
+                .await?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await?;
+                return Err(anyhow!("GitHub API error ({}): {}", status, body));
+            }
+
+            let page_members: Vec<User> = response.json().await?;
+            let count = page_members.len();
+            members.extend(page_members);
+            if count < per_page {
+                break;
+            }
+        }
+
+        Ok(members)
+    }
+
+    /// Get a user's public profile
+    pub async fn get_user_profile(&self, login: &str) -> Result<UserProfile> {
+        let url = format!("https://api.github.com/users/{}", login);
+        let response = self.client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("User-Agent", "EasyCI CD")
+            .header("Accept", "application/vnd.github.v3+json")
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await?;
+            return Err(anyhow!("GitHub API error ({}): {}", status, body));
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Get a single commit (서명 검증 결과 포함). `sha` 대신 브랜치 이름을 주면 브랜치 최신 커밋
+    pub async fn get_commit(&self, owner: &str, repo: &str, sha: &str) -> Result<Commit> {
+        let url = format!("https://api.github.com/repos/{}/{}/commits/{}", owner, repo, sha);
+        let response = self.client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("User-Agent", "EasyCI CD")
+            .header("Accept", "application/vnd.github.v3+json")
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await?;
+            return Err(anyhow!("GitHub API error ({}): {}", status, body));
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Create a commit status (branch protection의 required check 대상)
+    pub async fn create_commit_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        status: &CreateCommitStatusRequest,
+    ) -> Result<()> {
+        let url = format!("https://api.github.com/repos/{}/{}/statuses/{}", owner, repo, sha);
+
+        let response = self.client
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("User-Agent", "EasyCI CD")
+            .header("Accept", "application/vnd.github.v3+json")
+            .json(status)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await?;
+            return Err(anyhow!("GitHub API error ({}): {}", status, body));
+        }
+
+        Ok(())
+    }
+
+    /// Update webhook config (URL, secret)
+    pub async fn update_webhook_config(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: u64,
+        webhook_url: &str,
+        secret: &str,
+    ) -> Result<()> {
+        let url = format!("https://api.github.com/repos/{}/{}/hooks/{}/config", owner, repo, hook_id);
+
+        let config = WebhookConfig {
+            url: webhook_url.to_string(),
+            content_type: "json".to_string(),
+            secret: secret.to_string(),
+            insecure_ssl: "0".to_string(),
+        };
+
+        let response = self.client
+            .patch(&url)
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("User-Agent", "EasyCI CD")
+            .header("Accept", "application/vnd.github.v3+json")
+            .json(&config)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await?;
+            return Err(anyhow!("GitHub API error ({}): {}", status, body));
+        }
+
+        Ok(())
+    }
+
     /// List webhooks for a repository
     pub async fn list_webhooks(&self, owner: &str, repo: &str) -> Result<Vec<Webhook>> {
         let url = format!("https://api.github.com/repos/{}/{}/hooks", owner, repo);
@@ -246,3 +478,43 @@ impl GitHubClient {
         Ok(response.json().await?)
     }
 }
+
+#[async_trait]
+impl GitProvider for GitHubClient {
+    async fn list_repositories(&self) -> Result<Vec<Repository>> {
+        GitHubClient::list_repositories(self).await
+    }
+
+    async fn list_branches(&self, owner: &str, repo: &str) -> Result<Vec<Branch>> {
+        GitHubClient::list_branches(self, owner, repo).await
+    }
+
+    async fn get_branch(&self, owner: &str, repo: &str, branch: &str) -> Result<Branch> {
+        GitHubClient::get_branch(self, owner, repo, branch).await
+    }
+
+    async fn get_tree(&self, owner: &str, repo: &str, sha: &str) -> Result<Tree> {
+        GitHubClient::get_tree(self, owner, repo, sha).await
+    }
+
+    async fn get_file_content(&self, owner: &str, repo: &str, path: &str, branch: &str) -> Result<String> {
+        GitHubClient::get_file_content(self, owner, repo, path, branch).await
+    }
+
+    async fn get_commit(&self, owner: &str, repo: &str, git_ref: &str) -> Result<Commit> {
+        GitHubClient::get_commit(self, owner, repo, git_ref).await
+    }
+
+    async fn download_tarball(&self, owner: &str, repo: &str, git_ref: &str, dest: &Path) -> Result<u64> {
+        GitHubClient::download_tarball(self, owner, repo, git_ref, dest).await
+    }
+
+    async fn create_webhook(&self, owner: &str, repo: &str, webhook_url: &str, secret: &str) -> Result<String> {
+        Ok(GitHubClient::create_webhook(self, owner, repo, webhook_url, secret).await?.id.to_string())
+    }
+
+    async fn delete_webhook(&self, owner: &str, repo: &str, hook_id: &str) -> Result<()> {
+        let hook_id = hook_id.parse().map_err(|_| anyhow!("Invalid webhook ID: {}", hook_id))?;
+        GitHubClient::delete_webhook(self, owner, repo, hook_id).await
+    }
+}
@@ -39,6 +39,7 @@ impl ConfigBuilder {
             health_check_url,
             working_directory: None, // detector가 별도로 설정
             runtime_port: plan.detected_port.unwrap_or_else(|| Self::determine_default_port(&plan.project_type)),
+            dockerfile_path: None,
         })
     }
 
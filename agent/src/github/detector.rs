@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
-use super::GitHubClient;
+use std::sync::Arc;
+use crate::application::ports::git_provider::GitProvider;
 use super::workflow_parser::WorkflowParser;
 use super::config_builder::ConfigBuilder;
 
@@ -14,15 +15,18 @@ pub struct ProjectConfig {
     pub health_check_url: String,
     pub working_directory: Option<String>,
     pub runtime_port: u16,  // 컨테이너 내부에서 앱이 listen하는 포트
+    #[serde(default)]
+    pub dockerfile_path: Option<String>,  // 있으면 Dockerfile로 런타임 이미지 빌드
 }
 
-#[derive(Debug, Clone)]
+/// 저장소 파일 구성으로 빌드 설정을 추정 (GitHub, GitLab 공통)
+#[derive(Clone)]
 pub struct ProjectDetector {
-    client: GitHubClient,
+    client: Arc<dyn GitProvider>,
 }
 
 impl ProjectDetector {
-    pub fn new(client: GitHubClient) -> Self {
+    pub fn new(client: Arc<dyn GitProvider>) -> Self {
         Self { client }
     }
 
@@ -106,8 +110,13 @@ impl ProjectDetector {
         }
 
         // Priority 2: Dockerfile
-        if files.iter().any(|f| f.ends_with("Dockerfile") || f.ends_with("dockerfile")) {
-            let mut config = self.detect_from_dockerfile(owner, repo, branch, path_filter).await?;
+        if let Some(dockerfile) = files.iter().find(|f| f.ends_with("Dockerfile") || f.ends_with("dockerfile")) {
+            // working_directory 기준 상대 경로
+            let dockerfile = match &working_directory {
+                Some(wd) => dockerfile.strip_prefix(&format!("{}/", wd)).unwrap_or(dockerfile),
+                None => dockerfile.as_str(),
+            };
+            let mut config = self.detect_from_dockerfile(dockerfile).await?;
             config.working_directory = working_directory.clone();
             return Ok(config);
         }
@@ -180,6 +189,7 @@ impl ProjectDetector {
                 health_check_url: "/".to_string(),
                 working_directory: None,
                 runtime_port: 8080,  // Nginx 기본 포트 (Vite/React/Vue 빌드 산출물의 nginx.conf 기본값)
+                dockerfile_path: None,
             })
         } else {
             // Backend project (Express, NestJS, etc.)
@@ -193,6 +203,7 @@ impl ProjectDetector {
                 health_check_url: "/health".to_string(),
                 working_directory: None,
                 runtime_port: 3000,
+                dockerfile_path: None,
             })
         }
     }
@@ -208,6 +219,7 @@ impl ProjectDetector {
             health_check_url: "/actuator/health".to_string(),
             working_directory: None,
             runtime_port: 8080,
+            dockerfile_path: None,
         })
     }
 
@@ -222,6 +234,7 @@ impl ProjectDetector {
             health_check_url: "/actuator/health".to_string(),
             working_directory: None,
             runtime_port: 8080,
+            dockerfile_path: None,
         })
     }
 
@@ -236,6 +249,7 @@ impl ProjectDetector {
             health_check_url: "/health".to_string(),
             working_directory: None,
             runtime_port: 8080,
+            dockerfile_path: None,
         })
     }
 
@@ -250,6 +264,7 @@ impl ProjectDetector {
             health_check_url: "/health".to_string(),
             working_directory: None,
             runtime_port: 8080,
+            dockerfile_path: None,
         })
     }
 
@@ -278,6 +293,7 @@ impl ProjectDetector {
             health_check_url: "/health".to_string(),
             working_directory: None,
             runtime_port: 8000,
+            dockerfile_path: None,
         })
     }
 
@@ -293,28 +309,25 @@ impl ProjectDetector {
             health_check_url: "/".to_string(),
             working_directory: None,
             runtime_port: 8080,  // Nginx 기본 포트 (nginx.conf 기본값)
+            dockerfile_path: None,
         })
     }
 
     /// Detect from Dockerfile
-    async fn detect_from_dockerfile(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _branch: &str,
-        _path_filter: Option<&str>,
-    ) -> Result<ProjectConfig, String> {
-        // For Dockerfile-based projects, we use docker build
+    ///
+    /// 빌드 컨테이너는 소스만 /output으로 복사하고, agent가 그 소스로 런타임 이미지를 빌드해 실행한다
+    async fn detect_from_dockerfile(&self, dockerfile: &str) -> Result<ProjectConfig, String> {
         Ok(ProjectConfig {
             project_type: "Dockerfile".to_string(),
-            build_image: "docker:24-cli".to_string(),
-            build_command: "docker build -t app .".to_string(),
+            build_image: "docker:24-cli".to_string(),  // git 포함 (소스 clone용)
+            build_command: "".to_string(),
             cache_type: "none".to_string(),
-            runtime_image: "app".to_string(),
-            runtime_command: "".to_string(),
+            runtime_image: "scratch".to_string(),  // 사용하지 않음 (빌드한 이미지로 실행)
+            runtime_command: "".to_string(),  // 비어 있으면 이미지 CMD
             health_check_url: "/".to_string(),
             working_directory: None,
             runtime_port: 8080,  // Dockerfile 기반 프로젝트 기본 포트
+            dockerfile_path: Some(dockerfile.to_string()),
         })
     }
 
@@ -5,6 +5,6 @@ pub mod workflow_parser;
 pub mod workflow_interpreter;
 pub mod config_builder;
 
-pub use client::GitHubClient;
+pub use client::{parse_repo_owner_name, GitHubClient};
 pub use models::*;
 pub use detector::{ProjectDetector, ProjectConfig};
@@ -43,6 +43,13 @@ pub struct User {
     pub avatar_url: String,
 }
 
+/// 사용자 공개 프로필 (email은 사용자가 공개한 경우만)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserProfile {
+    pub login: String,
+    pub email: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateWebhookRequest {
     pub name: String,
@@ -51,6 +58,47 @@ pub struct CreateWebhookRequest {
     pub config: WebhookConfig,
 }
 
+/// POST /repos/{owner}/{repo}/statuses/{sha} 요청 본문
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCommitStatusRequest {
+    /// pending, success, failure, error
+    pub state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_url: Option<String>,
+    pub description: String,
+    pub context: String,
+}
+
+/// GET /repos/{owner}/{repo}/commits/{sha} 응답 중 필요한 부분
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Commit {
+    pub sha: String,
+    pub commit: CommitDetail,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitDetail {
+    #[serde(default)]
+    pub message: String,
+    #[serde(default)]
+    pub author: Option<CommitAuthor>,
+    pub verification: CommitVerification,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitAuthor {
+    pub name: String,
+    pub email: String,
+}
+
+/// GitHub의 커밋 서명 검증 결과 (GPG/SSH/S/MIME)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitVerification {
+    pub verified: bool,
+    /// valid, unsigned, unknown_key, bad_email, expired_key, ...
+    pub reason: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WebhookConfig {
     pub url: String,
@@ -0,0 +1,252 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder, Response, Url};
+use serde::de::DeserializeOwned;
+use std::path::Path;
+use tokio::io::AsyncWriteExt;
+
+use super::models::*;
+use crate::application::ports::git_provider::GitProvider;
+use crate::github::{Branch, Commit, Repository, Tree};
+
+/// gitlab_url 설정이 없을 때 사용하는 GitLab 주소
+pub const DEFAULT_GITLAB_URL: &str = "https://gitlab.com";
+
+/// 목록 API 최대 페이지 수 (per_page=100)
+const MAX_PAGES: u32 = 10;
+/// 트리 API는 파일 단위라 더 많이 허용
+const MAX_TREE_PAGES: u32 = 50;
+
+#[derive(Debug, Clone)]
+pub struct GitLabClient {
+    client: Client,
+    base_url: String,
+    token: String,
+}
+
+impl GitLabClient {
+    /// `base_url`은 GitLab 인스턴스 주소 (예: https://gitlab.com, https://gitlab.example.com)
+    pub fn new(base_url: &str, token: String) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token,
+        }
+    }
+
+    /// `/api/v4/...` URL. 각 segment는 인코딩되므로 "group/repo" 같은 project ID도 그대로 넘긴다
+    fn api_url(&self, segments: &[&str]) -> Result<Url> {
+        let mut url = Url::parse(&format!("{}/api/v4", self.base_url))
+            .map_err(|e| anyhow!("Invalid GitLab URL {}: {}", self.base_url, e))?;
+        url.path_segments_mut()
+            .map_err(|_| anyhow!("Invalid GitLab URL {}", self.base_url))?
+            .extend(segments);
+        Ok(url)
+    }
+
+    fn project_url(&self, owner: &str, repo: &str, segments: &[&str]) -> Result<Url> {
+        let id = format!("{}/{}", owner, repo);
+        let mut all = vec!["projects", id.as_str()];
+        all.extend_from_slice(segments);
+        self.api_url(&all)
+    }
+
+    async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        let response = request
+            .header("PRIVATE-TOKEN", &self.token)
+            .header("User-Agent", "EasyCI CD")
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await?;
+            return Err(anyhow!("GitLab API error ({}): {}", status, body));
+        }
+
+        Ok(response)
+    }
+
+    /// x-next-page 헤더를 따라 모든 페이지 조회
+    async fn get_all<T: DeserializeOwned>(&self, url: Url, query: &[(&str, &str)], max_pages: u32) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        let mut page = "1".to_string();
+
+        for _ in 0..max_pages {
+            let response = self
+                .send(self.client.get(url.clone()).query(query).query(&[("per_page", "100"), ("page", page.as_str())]))
+                .await?;
+            let next = response
+                .headers()
+                .get("x-next-page")
+                .and_then(|v| v.to_str().ok())
+                .filter(|v| !v.is_empty())
+                .map(str::to_string);
+
+            let batch: Vec<T> = response.json().await?;
+            items.extend(batch);
+
+            match next {
+                Some(next) => page = next,
+                None => return Ok(items),
+            }
+        }
+
+        tracing::warn!("Reached safety limit of {} pages for {}", max_pages, url);
+        Ok(items)
+    }
+
+    /// Get authenticated user info
+    pub async fn get_user(&self) -> Result<GitLabUser> {
+        let url = self.api_url(&["user"])?;
+        Ok(self.send(self.client.get(url)).await?.json().await?)
+    }
+
+    /// 멤버로 속한 프로젝트 목록 (최근 활동 순)
+    pub async fn list_repositories(&self) -> Result<Vec<Repository>> {
+        let url = self.api_url(&["projects"])?;
+        let projects: Vec<GitLabProject> = self
+            .get_all(url, &[("membership", "true"), ("order_by", "last_activity_at"), ("simple", "true")], MAX_PAGES)
+            .await?;
+        Ok(projects.into_iter().map(Repository::from).collect())
+    }
+
+    pub async fn list_branches(&self, owner: &str, repo: &str) -> Result<Vec<Branch>> {
+        let url = self.project_url(owner, repo, &["repository", "branches"])?;
+        let branches: Vec<GitLabBranch> = self.get_all(url, &[], MAX_PAGES).await?;
+        Ok(branches.into_iter().map(Branch::from).collect())
+    }
+
+    pub async fn get_branch(&self, owner: &str, repo: &str, branch: &str) -> Result<Branch> {
+        let url = self.project_url(owner, repo, &["repository", "branches", branch])?;
+        let branch: GitLabBranch = self.send(self.client.get(url)).await?.json().await?;
+        Ok(branch.into())
+    }
+
+    /// 재귀 파일 트리. GitLab은 트리 SHA가 없으므로 요청한 ref를 `sha`로 둔다
+    pub async fn get_tree(&self, owner: &str, repo: &str, sha: &str) -> Result<Tree> {
+        let url = self.project_url(owner, repo, &["repository", "tree"])?;
+        let items: Vec<GitLabTreeItem> = self
+            .get_all(url, &[("ref", sha), ("recursive", "true")], MAX_TREE_PAGES)
+            .await?;
+        Ok(Tree {
+            sha: sha.to_string(),
+            tree: items.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    pub async fn get_file_content(&self, owner: &str, repo: &str, path: &str, branch: &str) -> Result<String> {
+        let url = self.project_url(owner, repo, &["repository", "files", path, "raw"])?;
+        Ok(self.send(self.client.get(url).query(&[("ref", branch)])).await?.text().await?)
+    }
+
+    pub async fn get_commit(&self, owner: &str, repo: &str, git_ref: &str) -> Result<Commit> {
+        let url = self.project_url(owner, repo, &["repository", "commits", git_ref])?;
+        let commit: GitLabCommit = self.send(self.client.get(url)).await?.json().await?;
+        Ok(commit.into())
+    }
+
+    /// tar.gz 아카이브 다운로드 (최상위에 {repo}-{ref}-{sha}/ 디렉토리가 하나 있음)
+    pub async fn download_tarball(&self, owner: &str, repo: &str, git_ref: &str, dest: &Path) -> Result<u64> {
+        let url = self.project_url(owner, repo, &["repository", "archive.tar.gz"])?;
+        let mut response = self.send(self.client.get(url).query(&[("sha", git_ref)])).await?;
+
+        let mut file = tokio::fs::File::create(dest).await?;
+        let mut written = 0u64;
+        while let Some(chunk) = response.chunk().await? {
+            file.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        file.flush().await?;
+
+        Ok(written)
+    }
+
+    /// push 이벤트 webhook 등록. secret은 X-Gitlab-Token 헤더로 전달된다
+    pub async fn create_webhook(&self, owner: &str, repo: &str, webhook_url: &str, secret: &str) -> Result<GitLabHook> {
+        let url = self.project_url(owner, repo, &["hooks"])?;
+        let request = CreateGitLabHookRequest {
+            url: webhook_url.to_string(),
+            token: secret.to_string(),
+            push_events: true,
+            enable_ssl_verification: true,
+        };
+        Ok(self.send(self.client.post(url).json(&request)).await?.json().await?)
+    }
+
+    /// webhook URL과 secret 갱신 (secret 교체 시)
+    pub async fn update_webhook(&self, owner: &str, repo: &str, hook_id: u64, webhook_url: &str, secret: &str) -> Result<()> {
+        let url = self.project_url(owner, repo, &["hooks", &hook_id.to_string()])?;
+        let request = CreateGitLabHookRequest {
+            url: webhook_url.to_string(),
+            token: secret.to_string(),
+            push_events: true,
+            enable_ssl_verification: true,
+        };
+        self.send(self.client.put(url).json(&request)).await?;
+        Ok(())
+    }
+
+    pub async fn delete_webhook(&self, owner: &str, repo: &str, hook_id: u64) -> Result<()> {
+        let url = self.project_url(owner, repo, &["hooks", &hook_id.to_string()])?;
+        self.send(self.client.delete(url)).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GitProvider for GitLabClient {
+    async fn list_repositories(&self) -> Result<Vec<Repository>> {
+        GitLabClient::list_repositories(self).await
+    }
+
+    async fn list_branches(&self, owner: &str, repo: &str) -> Result<Vec<Branch>> {
+        GitLabClient::list_branches(self, owner, repo).await
+    }
+
+    async fn get_branch(&self, owner: &str, repo: &str, branch: &str) -> Result<Branch> {
+        GitLabClient::get_branch(self, owner, repo, branch).await
+    }
+
+    async fn get_tree(&self, owner: &str, repo: &str, sha: &str) -> Result<Tree> {
+        GitLabClient::get_tree(self, owner, repo, sha).await
+    }
+
+    async fn get_file_content(&self, owner: &str, repo: &str, path: &str, branch: &str) -> Result<String> {
+        GitLabClient::get_file_content(self, owner, repo, path, branch).await
+    }
+
+    async fn get_commit(&self, owner: &str, repo: &str, git_ref: &str) -> Result<Commit> {
+        GitLabClient::get_commit(self, owner, repo, git_ref).await
+    }
+
+    async fn download_tarball(&self, owner: &str, repo: &str, git_ref: &str, dest: &Path) -> Result<u64> {
+        GitLabClient::download_tarball(self, owner, repo, git_ref, dest).await
+    }
+
+    async fn create_webhook(&self, owner: &str, repo: &str, webhook_url: &str, secret: &str) -> Result<String> {
+        Ok(GitLabClient::create_webhook(self, owner, repo, webhook_url, secret).await?.id.to_string())
+    }
+
+    async fn delete_webhook(&self, owner: &str, repo: &str, hook_id: &str) -> Result<()> {
+        let hook_id = hook_id.parse().map_err(|_| anyhow!("Invalid webhook ID: {}", hook_id))?;
+        GitLabClient::delete_webhook(self, owner, repo, hook_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_url_encodes_namespace_and_path() {
+        let client = GitLabClient::new("https://gitlab.example.com/", "t".to_string());
+        let url = client
+            .project_url("group/sub", "app", &["repository", "files", "src/main.rs", "raw"])
+            .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://gitlab.example.com/api/v4/projects/group%2Fsub%2Fapp/repository/files/src%2Fmain.rs/raw"
+        );
+    }
+}
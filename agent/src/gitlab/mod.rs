@@ -0,0 +1,5 @@
+pub mod client;
+pub mod models;
+
+pub use client::{GitLabClient, DEFAULT_GITLAB_URL};
+pub use models::*;
@@ -0,0 +1,197 @@
+use serde::{Deserialize, Serialize};
+
+use crate::github::{Branch, BranchCommit, Commit, CommitAuthor, CommitDetail, CommitVerification, Repository, TreeItem};
+
+/// GET /user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabUser {
+    pub id: u64,
+    pub username: String,
+}
+
+/// GET /projects
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabProject {
+    pub id: u64,
+    pub name: String,
+    pub path_with_namespace: String,
+    pub http_url_to_repo: String,
+    /// 빈 저장소는 null
+    pub default_branch: Option<String>,
+    /// public, internal, private
+    #[serde(default)]
+    pub visibility: String,
+}
+
+impl From<GitLabProject> for Repository {
+    fn from(p: GitLabProject) -> Self {
+        Repository {
+            id: p.id,
+            name: p.name,
+            full_name: p.path_with_namespace,
+            clone_url: p.http_url_to_repo,
+            default_branch: p.default_branch.unwrap_or_else(|| "main".to_string()),
+            private: p.visibility != "public",
+        }
+    }
+}
+
+/// GET /projects/:id/repository/branches
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabBranch {
+    pub name: String,
+    pub commit: GitLabBranchCommit,
+    #[serde(default)]
+    pub protected: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabBranchCommit {
+    pub id: String,
+}
+
+impl From<GitLabBranch> for Branch {
+    fn from(b: GitLabBranch) -> Self {
+        Branch {
+            name: b.name,
+            commit: BranchCommit { sha: b.commit.id },
+            protected: b.protected,
+        }
+    }
+}
+
+/// GET /projects/:id/repository/tree (type: tree, blob, commit)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabTreeItem {
+    pub id: String,
+    pub path: String,
+    #[serde(rename = "type")]
+    pub item_type: String,
+}
+
+impl From<GitLabTreeItem> for TreeItem {
+    fn from(item: GitLabTreeItem) -> Self {
+        TreeItem {
+            path: item.path,
+            item_type: item.item_type,
+            sha: item.id,
+        }
+    }
+}
+
+/// GET /projects/:id/repository/commits/:sha
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabCommit {
+    pub id: String,
+    #[serde(default)]
+    pub message: String,
+    pub author_name: String,
+    pub author_email: String,
+}
+
+impl From<GitLabCommit> for Commit {
+    fn from(c: GitLabCommit) -> Self {
+        Commit {
+            sha: c.id,
+            commit: CommitDetail {
+                message: c.message,
+                author: Some(CommitAuthor { name: c.author_name, email: c.author_email }),
+                // 서명 검증은 GitHub에서만 지원
+                verification: CommitVerification { verified: false, reason: "unsupported".to_string() },
+            },
+        }
+    }
+}
+
+/// POST /projects/:id/hooks 요청 본문
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateGitLabHookRequest {
+    pub url: String,
+    /// X-Gitlab-Token 헤더로 그대로 전달됨
+    pub token: String,
+    pub push_events: bool,
+    pub enable_ssl_verification: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabHook {
+    pub id: u64,
+    pub url: String,
+}
+
+/// Push Hook payload (X-Gitlab-Event: Push Hook)
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitLabPushEvent {
+    #[serde(rename = "ref")]
+    pub git_ref: Option<String>,
+    /// 브랜치 삭제 push면 null
+    pub checkout_sha: Option<String>,
+    pub project: GitLabPushProject,
+    #[serde(default)]
+    pub commits: Vec<GitLabPushCommit>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitLabPushProject {
+    pub path_with_namespace: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitLabPushCommit {
+    pub id: String,
+    pub message: String,
+    pub author: GitLabPushAuthor,
+    #[serde(default)]
+    pub added: Vec<String>,
+    #[serde(default)]
+    pub modified: Vec<String>,
+    #[serde(default)]
+    pub removed: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitLabPushAuthor {
+    pub name: String,
+    pub email: String,
+}
+
+impl GitLabPushEvent {
+    /// push 후 브랜치가 가리키는 커밋 (GitHub의 head_commit에 해당)
+    pub fn head_commit(&self) -> Option<&GitLabPushCommit> {
+        let sha = self.checkout_sha.as_deref()?;
+        self.commits.iter().find(|c| c.id == sha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_event_head_commit() {
+        let event: GitLabPushEvent = serde_json::from_value(serde_json::json!({
+            "object_kind": "push",
+            "ref": "refs/heads/main",
+            "checkout_sha": "bbb",
+            "project": {"path_with_namespace": "group/sub/app"},
+            "commits": [
+                {"id": "aaa", "message": "first", "author": {"name": "a", "email": "a@x"}, "added": ["a.txt"]},
+                {"id": "bbb", "message": "second", "author": {"name": "b", "email": "b@x"}, "modified": ["src/main.rs"]}
+            ]
+        })).unwrap();
+
+        let head = event.head_commit().unwrap();
+        assert_eq!(head.message, "second");
+        assert_eq!(head.modified, vec!["src/main.rs"]);
+        assert!(head.added.is_empty());
+
+        let deleted: GitLabPushEvent = serde_json::from_value(serde_json::json!({
+            "object_kind": "push",
+            "ref": "refs/heads/old",
+            "checkout_sha": null,
+            "project": {"path_with_namespace": "group/app"},
+            "commits": []
+        })).unwrap();
+        assert!(deleted.head_commit().is_none());
+    }
+}
@@ -0,0 +1,7 @@
+mod service;
+
+pub mod proto {
+    tonic::include_proto!("easycicd.v1");
+}
+
+pub use service::{run_grpc_server, GrpcConfig};
@@ -0,0 +1,429 @@
+use anyhow::{Context, Result};
+use std::net::SocketAddr;
+use tokio::sync::{broadcast, mpsc};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{service::Interceptor, transport::Server, Request, Response, Status};
+use tracing::{info, warn};
+
+use crate::application::ports::repositories::{BuildRepository, ProjectRepository};
+use crate::application::services::{check_build_allowed, BuildRejection};
+use crate::db::models::{Build, BuildStatus, BuildTrigger, Project};
+use crate::events::Event;
+use crate::infrastructure::logging::{TraceContext, Timer};
+use crate::infrastructure::timezone;
+use crate::state::AppContext;
+
+use super::proto::management_server::{Management, ManagementServer};
+use super::proto;
+
+/// gRPC 관리 API 설정
+///
+/// - GRPC_AUTH_TOKEN: 필수. 없으면 gRPC 서버를 띄우지 않음
+///   (클라이언트는 `authorization: Bearer <token>` 메타데이터 전송)
+/// - GRPC_PORT: 기본 50051
+#[derive(Debug, Clone)]
+pub struct GrpcConfig {
+    pub port: u16,
+    pub auth_token: String,
+}
+
+impl GrpcConfig {
+    pub fn from_env() -> Option<Result<Self>> {
+        let auth_token = std::env::var("GRPC_AUTH_TOKEN").ok().filter(|t| !t.is_empty())?;
+        let port = match std::env::var("GRPC_PORT") {
+            Ok(p) => match p.parse::<u16>().context("Invalid GRPC_PORT") {
+                Ok(p) => p,
+                Err(e) => return Some(Err(e)),
+            },
+            Err(_) => 50051,
+        };
+        Some(Ok(Self { port, auth_token }))
+    }
+}
+
+/// gRPC 서버 실행 (REST API와 같은 AppContext 공유)
+pub async fn run_grpc_server(ctx: AppContext, config: GrpcConfig) -> Result<()> {
+    let addr = SocketAddr::from(([0, 0, 0, 0], config.port));
+    let auth = BearerAuth { token: config.auth_token };
+
+    let service = ManagementServer::with_interceptor(ManagementService { ctx }, auth);
+
+    info!("gRPC management API listening on {}", addr);
+
+    Server::builder()
+        .add_service(service)
+        .serve(addr)
+        .await
+        .context("gRPC server failed")
+}
+
+/// `authorization: Bearer <token>` 메타데이터 확인
+#[derive(Clone)]
+struct BearerAuth {
+    token: String,
+}
+
+impl Interceptor for BearerAuth {
+    fn call(&mut self, req: Request<()>) -> Result<Request<()>, Status> {
+        let authorized = req
+            .metadata()
+            .get("authorization")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .map(|t| constant_time_eq(t.as_bytes(), self.token.as_bytes()))
+            .unwrap_or(false);
+
+        if authorized {
+            Ok(req)
+        } else {
+            Err(Status::unauthenticated("Invalid or missing token"))
+        }
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn is_terminal(status: &BuildStatus) -> bool {
+    matches!(status, BuildStatus::Success | BuildStatus::Failed | BuildStatus::Verified)
+}
+
+impl From<Project> for proto::Project {
+    fn from(p: Project) -> Self {
+        Self {
+            id: p.id,
+            name: p.name,
+            repo: p.repo,
+            branch: p.branch,
+            build_image: p.build_image,
+            runtime_image: p.runtime_image,
+            runtime_port: p.runtime_port,
+            blue_port: p.blue_port,
+            green_port: p.green_port,
+            active_slot: p.active_slot.to_string(),
+            deployment_status: p.deployment_status.to_string(),
+            last_build_status: None,
+        }
+    }
+}
+
+impl From<Build> for proto::Build {
+    fn from(b: Build) -> Self {
+        Self {
+            id: b.id,
+            project_id: b.project_id,
+            build_number: b.build_number,
+            commit_hash: b.commit_hash,
+            commit_message: b.commit_message,
+            author: b.author,
+            status: b.status.to_string(),
+            deployed_slot: b.deployed_slot,
+            dry_run: b.dry_run,
+            started_at: timezone::to_display(&b.started_at),
+            finished_at: b.finished_at.as_deref().map(timezone::to_display),
+            triggered_by: b.triggered_by,
+        }
+    }
+}
+
+struct ManagementService {
+    ctx: AppContext,
+}
+
+impl ManagementService {
+    /// REST 핸들러와 같은 trace_id / boundary 로그 규칙 적용
+    fn begin<T>(&self, req: &Request<T>, method: &str, detail: &str) -> (String, Timer) {
+        let headers = req.metadata().clone().into_headers();
+        let trace_id = TraceContext::extract_or_generate(&headers);
+        self.ctx.logger.api_entry(&trace_id, "GRPC", method, detail);
+        (trace_id, Timer::start())
+    }
+
+    fn end(&self, trace_id: &str, method: &str, timer: &Timer, status: u16) {
+        self.ctx.logger.api_exit(trace_id, "GRPC", method, timer.elapsed_ms(), status);
+    }
+}
+
+#[tonic::async_trait]
+impl Management for ManagementService {
+    async fn list_projects(
+        &self,
+        request: Request<proto::ListProjectsRequest>,
+    ) -> Result<Response<proto::ListProjectsResponse>, Status> {
+        let (trace_id, timer) = self.begin(&request, "ListProjects", "");
+
+        let projects = match self.ctx.project_repo.list().await {
+            Ok(p) => p,
+            Err(e) => {
+                warn!("[{}] Failed to list projects: {}", trace_id, e);
+                self.end(&trace_id, "ListProjects", &timer, 500);
+                return Err(Status::internal("Database error"));
+            }
+        };
+
+        let mut result = Vec::with_capacity(projects.len());
+        for project in projects {
+            let last_build_status = match self.ctx.build_repo.get_latest_by_project(project.id).await {
+                Ok(Some(build)) => Some(build.status.to_string()),
+                _ => None,
+            };
+            let mut p = proto::Project::from(project);
+            p.last_build_status = last_build_status;
+            result.push(p);
+        }
+
+        self.end(&trace_id, "ListProjects", &timer, 200);
+        Ok(Response::new(proto::ListProjectsResponse { projects: result }))
+    }
+
+    async fn get_project(
+        &self,
+        request: Request<proto::GetProjectRequest>,
+    ) -> Result<Response<proto::Project>, Status> {
+        let id = request.get_ref().id;
+        let (trace_id, timer) = self.begin(&request, "GetProject", &format!("project_id={}", id));
+
+        match self.ctx.project_service.get_project(&trace_id, id).await {
+            Ok(Some(project)) => {
+                self.end(&trace_id, "GetProject", &timer, 200);
+                Ok(Response::new(project.into()))
+            }
+            Ok(None) => {
+                self.end(&trace_id, "GetProject", &timer, 404);
+                Err(Status::not_found("Project not found"))
+            }
+            Err(e) => {
+                warn!("[{}] Failed to get project: {}", trace_id, e);
+                self.end(&trace_id, "GetProject", &timer, 500);
+                Err(Status::internal("Database error"))
+            }
+        }
+    }
+
+    async fn trigger_build(
+        &self,
+        request: Request<proto::TriggerBuildRequest>,
+    ) -> Result<Response<proto::Build>, Status> {
+        let req = *request.get_ref();
+        let (trace_id, timer) = self.begin(
+            &request,
+            "TriggerBuild",
+            &format!("project_id={}, dry_run={}", req.project_id, req.dry_run),
+        );
+
+        let project = match self.ctx.project_repo.get(req.project_id).await {
+            Ok(Some(p)) if p.deleted_at.is_none() => p,
+            Ok(_) => {
+                self.end(&trace_id, "TriggerBuild", &timer, 404);
+                return Err(Status::not_found("Project not found"));
+            }
+            Err(e) => {
+                warn!("[{}] Failed to get project: {}", trace_id, e);
+                self.end(&trace_id, "TriggerBuild", &timer, 500);
+                return Err(Status::internal("Database error"));
+            }
+        };
+
+        match check_build_allowed(&self.ctx.disk_quota_service, &trace_id, &project).await {
+            Ok(()) => {}
+            Err(rejection @ BuildRejection::Archived) => {
+                self.end(&trace_id, "TriggerBuild", &timer, 409);
+                return Err(Status::failed_precondition(rejection.message()));
+            }
+            Err(rejection @ BuildRejection::QuotaExceeded(_)) => {
+                self.end(&trace_id, "TriggerBuild", &timer, 507);
+                return Err(Status::resource_exhausted(rejection.message()));
+            }
+        }
+
+        let trigger = BuildTrigger::ApiToken("grpc".to_string());
+        let build = match self.ctx.project_service.trigger_build(&trace_id, req.project_id, req.dry_run, trigger).await {
+            Ok(b) => b,
+            Err(e) => {
+                warn!("[{}] Failed to create build: {}", trace_id, e);
+                self.end(&trace_id, "TriggerBuild", &timer, 500);
+                return Err(Status::internal("Failed to create build"));
+            }
+        };
+
+        self.ctx.build_queue.enqueue_new(&self.ctx.event_bus, build.project_id, build.id).await;
+
+        tracing::info!(
+            target: "audit",
+            event = "build.triggered",
+            trace_id = %trace_id,
+            project_id = build.project_id,
+            build_id = build.id,
+            dry_run = build.dry_run,
+            triggered_by = build.triggered_by.as_deref().unwrap_or_default(),
+        );
+
+        self.end(&trace_id, "TriggerBuild", &timer, 201);
+        Ok(Response::new(build.into()))
+    }
+
+    async fn list_builds(
+        &self,
+        request: Request<proto::ListBuildsRequest>,
+    ) -> Result<Response<proto::ListBuildsResponse>, Status> {
+        let req = *request.get_ref();
+        let (trace_id, timer) = self.begin(
+            &request,
+            "ListBuilds",
+            &format!("project_id={}, limit={}", req.project_id, req.limit),
+        );
+
+        let limit = if req.limit > 0 { req.limit.min(500) } else { 50 };
+        let result = if req.project_id > 0 {
+            self.ctx.build_repo.list_by_project(req.project_id, limit).await
+        } else {
+            self.ctx.build_repo.list(limit).await
+        };
+
+        match result {
+            Ok(builds) => {
+                self.end(&trace_id, "ListBuilds", &timer, 200);
+                Ok(Response::new(proto::ListBuildsResponse {
+                    builds: builds.into_iter().map(Into::into).collect(),
+                }))
+            }
+            Err(e) => {
+                warn!("[{}] Failed to list builds: {}", trace_id, e);
+                self.end(&trace_id, "ListBuilds", &timer, 500);
+                Err(Status::internal("Database error"))
+            }
+        }
+    }
+
+    async fn get_build(
+        &self,
+        request: Request<proto::GetBuildRequest>,
+    ) -> Result<Response<proto::Build>, Status> {
+        let id = request.get_ref().id;
+        let (trace_id, timer) = self.begin(&request, "GetBuild", &format!("build_id={}", id));
+
+        match self.ctx.build_repo.get(id).await {
+            Ok(Some(build)) => {
+                self.end(&trace_id, "GetBuild", &timer, 200);
+                Ok(Response::new(build.into()))
+            }
+            Ok(None) => {
+                self.end(&trace_id, "GetBuild", &timer, 404);
+                Err(Status::not_found("Build not found"))
+            }
+            Err(e) => {
+                warn!("[{}] Failed to get build: {}", trace_id, e);
+                self.end(&trace_id, "GetBuild", &timer, 500);
+                Err(Status::internal("Database error"))
+            }
+        }
+    }
+
+    type StreamBuildLogsStream = ReceiverStream<Result<proto::LogLine, Status>>;
+
+    async fn stream_build_logs(
+        &self,
+        request: Request<proto::StreamBuildLogsRequest>,
+    ) -> Result<Response<Self::StreamBuildLogsStream>, Status> {
+        let build_id = request.get_ref().build_id;
+        let (trace_id, timer) = self.begin(&request, "StreamBuildLogs", &format!("build_id={}", build_id));
+
+        let build = match self.ctx.build_repo.get(build_id).await {
+            Ok(Some(b)) => b,
+            Ok(None) => {
+                self.end(&trace_id, "StreamBuildLogs", &timer, 404);
+                return Err(Status::not_found("Build not found"));
+            }
+            Err(e) => {
+                warn!("[{}] Failed to get build: {}", trace_id, e);
+                self.end(&trace_id, "StreamBuildLogs", &timer, 500);
+                return Err(Status::internal("Database error"));
+            }
+        };
+
+        // 파일을 읽기 전에 구독해야 그 사이에 기록된 라인을 놓치지 않음
+        let event_rx = self.ctx.subscribe_events();
+        let (tx, rx) = mpsc::channel(256);
+        tokio::spawn(stream_logs(build, event_rx, tx));
+
+        self.end(&trace_id, "StreamBuildLogs", &timer, 200);
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+}
+
+/// 기존 로그 파일 전송 후, 빌드가 끝날 때까지 Log 이벤트를 중계
+async fn stream_logs(
+    build: Build,
+    mut event_rx: broadcast::Receiver<Event>,
+    tx: mpsc::Sender<Result<proto::LogLine, Status>>,
+) {
+    let content = tokio::fs::read_to_string(&build.log_path).await.unwrap_or_default();
+    let mut sent = 0usize;
+    for line in content.lines() {
+        sent += 1;
+        let msg = proto::LogLine {
+            build_id: build.id,
+            line_number: sent as u64,
+            line: line.to_string(),
+            timestamp: String::new(),
+        };
+        if tx.send(Ok(msg)).await.is_err() {
+            return;  // 클라이언트 연결 종료
+        }
+    }
+
+    if is_terminal(&build.status) {
+        return;
+    }
+
+    loop {
+        match event_rx.recv().await {
+            Ok(Event::Log { build_id, line, line_number, timestamp }) if build_id == build.id => {
+                // 파일에서 이미 보낸 라인은 건너뜀
+                if line_number <= sent {
+                    continue;
+                }
+                let msg = proto::LogLine {
+                    build_id,
+                    line_number: line_number as u64,
+                    line,
+                    timestamp,
+                };
+                if tx.send(Ok(msg)).await.is_err() {
+                    return;
+                }
+            }
+            Ok(Event::BuildStatus { build_id, status, .. }) if build_id == build.id && is_terminal(&status) => {
+                return;
+            }
+            Ok(_) => {}
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!("Log stream for build {} lagged, skipped {} events", build.id, skipped);
+            }
+            Err(broadcast::error::RecvError::Closed) => return,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secret2"));
+    }
+
+    #[test]
+    fn test_is_terminal() {
+        assert!(is_terminal(&BuildStatus::Success));
+        assert!(is_terminal(&BuildStatus::Failed));
+        assert!(is_terminal(&BuildStatus::Verified));
+        assert!(!is_terminal(&BuildStatus::Queued));
+        assert!(!is_terminal(&BuildStatus::Building));
+        assert!(!is_terminal(&BuildStatus::Held));
+    }
+}
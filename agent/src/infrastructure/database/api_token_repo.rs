@@ -0,0 +1,159 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::{FromRow, SqlitePool};
+
+/// API 토큰 접두사 (Authorization 헤더에서 세션/다른 토큰과 구분)
+pub const API_TOKEN_PREFIX: &str = "ecd_";
+
+/// 사용자당 최대 토큰 수
+pub const MAX_API_TOKENS_PER_USER: usize = 20;
+
+/// API 토큰 권한 범위. 토큰 소유자의 역할을 넘을 수는 없음
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ApiTokenScope {
+    /// 조회(GET)만
+    Read,
+    /// 조회 + 빌드 실행
+    TriggerBuilds,
+    /// 소유자 역할이 허용하는 모든 요청
+    Admin,
+}
+
+/// 새 토큰 원문 (`ecd_` + 64자리 hex)
+pub fn generate_api_token() -> String {
+    use rand::RngCore;
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    format!("{}{}", API_TOKEN_PREFIX, hex::encode(bytes))
+}
+
+/// 저장/조회용 SHA-256 해시 (토큰 자체가 충분히 무작위라 salt 불필요)
+pub fn hash_api_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+/// API 토큰 (token_hash 컬럼은 읽지 않음)
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ApiToken {
+    pub id: i64,
+    pub user_id: i64,
+    pub name: String,
+    pub token_prefix: String,
+    /// JSON 배열
+    pub scopes: String,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize_option")]
+    pub expires_at: Option<String>,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize_option")]
+    pub last_used_at: Option<String>,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
+    pub created_at: String,
+}
+
+impl ApiToken {
+    pub fn parsed_scopes(&self) -> Vec<ApiTokenScope> {
+        serde_json::from_str(&self.scopes).unwrap_or_default()
+    }
+}
+
+#[derive(Clone)]
+pub struct SqliteApiTokenRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteApiTokenRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list_by_user(&self, user_id: i64) -> Result<Vec<ApiToken>> {
+        let rows = sqlx::query_as::<_, ApiToken>("SELECT * FROM api_tokens WHERE user_id = ? ORDER BY created_at, id")
+            .bind(user_id)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows)
+    }
+
+    /// 토큰 원문으로 조회 (만료된 토큰은 None)
+    pub async fn find_valid(&self, token: &str) -> Result<Option<ApiToken>> {
+        let row = sqlx::query_as::<_, ApiToken>(
+            "SELECT * FROM api_tokens WHERE token_hash = ? AND (expires_at IS NULL OR expires_at > datetime('now'))"
+        )
+        .bind(hash_api_token(token))
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row)
+    }
+
+    /// 토큰 생성. 같은 이름이 이미 있으면 None
+    pub async fn create(
+        &self,
+        user_id: i64,
+        name: &str,
+        token: &str,
+        scopes: &[ApiTokenScope],
+        expires_at: Option<&str>,
+    ) -> Result<Option<ApiToken>> {
+        let result = sqlx::query(
+            "INSERT OR IGNORE INTO api_tokens (user_id, name, token_hash, token_prefix, scopes, expires_at) VALUES (?, ?, ?, ?, ?, ?)"
+        )
+        .bind(user_id)
+        .bind(name)
+        .bind(hash_api_token(token))
+        .bind(&token[..token.len().min(API_TOKEN_PREFIX.len() + 8)])
+        .bind(serde_json::to_string(scopes)?)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        let token = sqlx::query_as::<_, ApiToken>("SELECT * FROM api_tokens WHERE id = ?")
+            .bind(result.last_insert_rowid())
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(Some(token))
+    }
+
+    pub async fn touch(&self, id: i64) -> Result<()> {
+        sqlx::query("UPDATE api_tokens SET last_used_at = datetime('now') WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn delete(&self, user_id: i64, id: i64) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM api_tokens WHERE id = ? AND user_id = ?")
+            .bind(id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_and_hash_token() {
+        let token = generate_api_token();
+        assert!(token.starts_with(API_TOKEN_PREFIX));
+        assert_eq!(token.len(), API_TOKEN_PREFIX.len() + 64);
+        assert_ne!(token, generate_api_token());
+
+        assert_eq!(hash_api_token(&token), hash_api_token(&token));
+        assert_eq!(
+            hash_api_token("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+
+        let scopes: Vec<ApiTokenScope> = serde_json::from_str(r#"["read", "trigger-builds"]"#).unwrap();
+        assert_eq!(scopes, vec![ApiTokenScope::Read, ApiTokenScope::TriggerBuilds]);
+    }
+}
@@ -0,0 +1,176 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::{FromRow, SqlitePool};
+
+/// 계정 연결 코드 유효 시간 (분)
+pub const LINK_CODE_TTL_MINUTES: i64 = 10;
+
+/// 새 계정 연결 코드 (10자리 대문자 hex)
+pub fn generate_link_code() -> String {
+    use rand::RngCore;
+    let mut bytes = [0u8; 5];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    hex::encode_upper(bytes)
+}
+
+/// 저장/조회용 해시 (앞뒤 공백, 대소문자 무시)
+fn hash_link_code(code: &str) -> String {
+    hex::encode(Sha256::digest(code.trim().to_uppercase().as_bytes()))
+}
+
+/// slash command를 보내는 채팅 서비스
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatProvider {
+    Slack,
+    Discord,
+}
+
+impl std::fmt::Display for ChatProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChatProvider::Slack => write!(f, "slack"),
+            ChatProvider::Discord => write!(f, "discord"),
+        }
+    }
+}
+
+/// 채팅 사용자와 easyCICD 사용자 연결
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ChatAccount {
+    pub id: i64,
+    pub provider: String,
+    pub external_user_id: String,
+    pub user_id: i64,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
+    pub created_at: String,
+}
+
+/// 연결 코드 사용 결과
+#[derive(Debug)]
+pub enum LinkCodeRedemption {
+    Linked(ChatAccount),
+    /// 코드가 없거나 만료됨
+    InvalidCode,
+    /// 채팅 계정이 이미 다른 사용자(또는 본인)에 연결됨
+    AlreadyLinked,
+}
+
+#[derive(Clone)]
+pub struct SqliteChatAccountRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteChatAccountRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list_by_user(&self, user_id: i64) -> Result<Vec<ChatAccount>> {
+        let rows = sqlx::query_as::<_, ChatAccount>(
+            "SELECT * FROM chat_accounts WHERE user_id = ? ORDER BY provider, created_at"
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// 연결된 easyCICD 사용자 ID
+    pub async fn find_user_id(&self, provider: ChatProvider, external_user_id: &str) -> Result<Option<i64>> {
+        let user_id = sqlx::query_scalar(
+            "SELECT user_id FROM chat_accounts WHERE provider = ? AND external_user_id = ?"
+        )
+        .bind(provider.to_string())
+        .bind(external_user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(user_id)
+    }
+
+    /// 채팅 계정의 연결 코드 발급. 이전 코드와 만료된 코드는 삭제
+    pub async fn issue_link_code(&self, provider: ChatProvider, external_user_id: &str, code: &str) -> Result<()> {
+        sqlx::query("DELETE FROM chat_link_codes WHERE expires_at <= datetime('now')")
+            .execute(&self.pool)
+            .await?;
+        sqlx::query(
+            "INSERT OR REPLACE INTO chat_link_codes (code_hash, provider, external_user_id, expires_at) VALUES (?, ?, ?, datetime('now', ?))"
+        )
+        .bind(hash_link_code(code))
+        .bind(provider.to_string())
+        .bind(external_user_id)
+        .bind(format!("+{} minutes", LINK_CODE_TTL_MINUTES))
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 연결 코드를 사용해 채팅 계정을 사용자에 연결 (코드는 한 번만 사용 가능)
+    pub async fn redeem_link_code(&self, code: &str, user_id: i64) -> Result<LinkCodeRedemption> {
+        let code_hash = hash_link_code(code);
+        let mut tx = self.pool.begin().await?;
+
+        let pending = sqlx::query_as::<_, (String, String)>(
+            "SELECT provider, external_user_id FROM chat_link_codes WHERE code_hash = ? AND expires_at > datetime('now')"
+        )
+        .bind(&code_hash)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some((provider, external_user_id)) = pending else {
+            return Ok(LinkCodeRedemption::InvalidCode);
+        };
+
+        sqlx::query("DELETE FROM chat_link_codes WHERE code_hash = ?")
+            .bind(&code_hash)
+            .execute(&mut *tx)
+            .await?;
+        let result = sqlx::query(
+            "INSERT OR IGNORE INTO chat_accounts (provider, external_user_id, user_id) VALUES (?, ?, ?)"
+        )
+        .bind(&provider)
+        .bind(&external_user_id)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        let redemption = if result.rows_affected() == 0 {
+            LinkCodeRedemption::AlreadyLinked
+        } else {
+            let account = sqlx::query_as::<_, ChatAccount>("SELECT * FROM chat_accounts WHERE id = ?")
+                .bind(result.last_insert_rowid())
+                .fetch_one(&mut *tx)
+                .await?;
+            LinkCodeRedemption::Linked(account)
+        };
+        tx.commit().await?;
+        Ok(redemption)
+    }
+
+    /// 본인 연결만 해제
+    pub async fn unlink(&self, id: i64, user_id: i64) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM chat_accounts WHERE id = ? AND user_id = ?")
+            .bind(id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_code() {
+        let code = generate_link_code();
+        assert_eq!(code.len(), 10);
+        assert!(code.bytes().all(|b| b.is_ascii_digit() || b.is_ascii_uppercase()));
+        assert_ne!(code, generate_link_code());
+
+        // 채팅 앱에서 복사할 때 섞인 공백/소문자는 같은 코드
+        assert_eq!(hash_link_code(&code), hash_link_code(&format!(" {} ", code.to_lowercase())));
+        assert_ne!(hash_link_code(&code), hash_link_code(&generate_link_code()));
+    }
+}
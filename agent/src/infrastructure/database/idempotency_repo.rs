@@ -0,0 +1,91 @@
+use anyhow::Result;
+use sqlx::SqlitePool;
+
+/// Idempotency key 보관 기간
+pub const IDEMPOTENCY_TTL_HOURS: i64 = 24;
+
+/// 최대 key 길이 (헤더로 받은 임의 문자열 저장 제한)
+pub const MAX_IDEMPOTENCY_KEY_LEN: usize = 255;
+
+/// key 예약 결과
+#[derive(Debug, Clone, PartialEq)]
+pub enum IdempotencyReservation {
+    /// 처음 보는 key. 처리 후 `complete` 또는 실패 시 `release` 호출
+    Reserved,
+    /// 같은 key의 요청이 아직 처리 중
+    InProgress,
+    /// 이미 처리된 요청. 저장된 (status code, 응답 JSON)
+    Completed(u16, serde_json::Value),
+}
+
+#[derive(Clone)]
+pub struct SqliteIdempotencyRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteIdempotencyRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// key 예약. 만료된 key는 먼저 정리하므로 TTL이 지나면 같은 key로 다시 처리된다.
+    pub async fn reserve(&self, scope: &str, key: &str) -> Result<IdempotencyReservation> {
+        sqlx::query("DELETE FROM idempotency_keys WHERE expires_at <= datetime('now')")
+            .execute(&self.pool)
+            .await?;
+
+        let inserted = sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO idempotency_keys (scope, key, expires_at)
+            VALUES (?, ?, datetime('now', ?))
+            "#
+        )
+        .bind(scope)
+        .bind(key)
+        .bind(format!("+{} hours", IDEMPOTENCY_TTL_HOURS))
+        .execute(&self.pool)
+        .await?;
+
+        if inserted.rows_affected() > 0 {
+            return Ok(IdempotencyReservation::Reserved);
+        }
+
+        let row: Option<(Option<i64>, Option<String>)> = sqlx::query_as(
+            "SELECT status_code, response FROM idempotency_keys WHERE scope = ? AND key = ?"
+        )
+        .bind(scope)
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(match row {
+            Some((Some(status), Some(response))) => IdempotencyReservation::Completed(
+                status as u16,
+                serde_json::from_str(&response).unwrap_or(serde_json::Value::Null),
+            ),
+            _ => IdempotencyReservation::InProgress,
+        })
+    }
+
+    /// 처리 결과 저장 (이후 같은 key 요청에 그대로 반환)
+    pub async fn complete(&self, scope: &str, key: &str, status_code: u16, response: &serde_json::Value) -> Result<()> {
+        sqlx::query("UPDATE idempotency_keys SET status_code = ?, response = ? WHERE scope = ? AND key = ?")
+            .bind(status_code as i64)
+            .bind(response.to_string())
+            .bind(scope)
+            .bind(key)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// 처리 실패 시 예약 해제 (클라이언트가 같은 key로 재시도할 수 있도록)
+    pub async fn release(&self, scope: &str, key: &str) -> Result<()> {
+        sqlx::query("DELETE FROM idempotency_keys WHERE scope = ? AND key = ? AND response IS NULL")
+            .bind(scope)
+            .bind(key)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use anyhow::Result;
+use serde::Serialize;
+use sqlx::{FromRow, SqlitePool};
+
+use crate::db::models::Slot;
+use crate::docker::ContainerStats;
+
+/// 수집 버킷 크기 (초)
+pub const METRICS_BUCKET_SECS: i64 = 300;
+
+/// 시계열 한 지점 (버킷 단위 집계)
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct MetricsPoint {
+    pub slot: String,
+    pub bucket_start: i64,
+    pub samples: i64,
+    pub cpu_avg: f64,
+    pub cpu_max: f64,
+    pub memory_avg: i64,
+    pub memory_max: i64,
+    pub memory_limit: i64,
+}
+
+#[derive(Clone)]
+pub struct SqliteMetricsRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMetricsRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 샘플을 해당 버킷에 누적 (평균은 샘플 수 가중 평균으로 갱신)
+    pub async fn record_sample(&self, project_id: i64, slot: Slot, timestamp: i64, stats: &ContainerStats) -> Result<()> {
+        let bucket_start = timestamp - timestamp.rem_euclid(METRICS_BUCKET_SECS);
+        let memory = stats.memory_bytes as i64;
+
+        sqlx::query(
+            r#"
+            INSERT INTO container_metrics
+                (project_id, slot, bucket_start, samples, cpu_avg, cpu_max, memory_avg, memory_max, memory_limit)
+            VALUES (?, ?, ?, 1, ?, ?, ?, ?, ?)
+            ON CONFLICT(project_id, slot, bucket_start) DO UPDATE SET
+                cpu_avg = (cpu_avg * samples + excluded.cpu_avg) / (samples + 1),
+                cpu_max = MAX(cpu_max, excluded.cpu_max),
+                memory_avg = (memory_avg * samples + excluded.memory_avg) / (samples + 1),
+                memory_max = MAX(memory_max, excluded.memory_max),
+                memory_limit = excluded.memory_limit,
+                samples = samples + 1
+            "#,
+        )
+        .bind(project_id)
+        .bind(slot.to_string())
+        .bind(bucket_start)
+        .bind(stats.cpu_percent)
+        .bind(stats.cpu_percent)
+        .bind(memory)
+        .bind(memory)
+        .bind(stats.memory_limit as i64)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// `since` 이후 시계열 조회. `step_secs` 단위로 버킷을 다시 묶음 (METRICS_BUCKET_SECS의 배수)
+    pub async fn list(&self, project_id: i64, since: i64, step_secs: i64) -> Result<Vec<MetricsPoint>> {
+        let step = step_secs.max(METRICS_BUCKET_SECS);
+
+        let points = sqlx::query_as::<_, MetricsPoint>(
+            r#"
+            SELECT
+                slot,
+                (bucket_start / ?1) * ?1 AS bucket_start,
+                SUM(samples) AS samples,
+                SUM(cpu_avg * samples) / SUM(samples) AS cpu_avg,
+                MAX(cpu_max) AS cpu_max,
+                CAST(SUM(memory_avg * samples) / SUM(samples) AS INTEGER) AS memory_avg,
+                MAX(memory_max) AS memory_max,
+                MAX(memory_limit) AS memory_limit
+            FROM container_metrics
+            WHERE project_id = ?2 AND bucket_start >= ?3 AND samples > 0
+            GROUP BY slot, (bucket_start / ?1)
+            ORDER BY bucket_start ASC, slot ASC
+            "#,
+        )
+        .bind(step)
+        .bind(project_id)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(points)
+    }
+
+    /// 보존 기간이 지난 버킷 삭제
+    pub async fn delete_before(&self, timestamp: i64) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM container_metrics WHERE bucket_start < ?")
+            .bind(timestamp)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
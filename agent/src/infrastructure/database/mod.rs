@@ -1,5 +1,16 @@
 pub mod sqlite_repo;
 pub mod discord_webhook_repo;
+pub mod slack_webhook_repo;
+pub mod metrics_repo;
+pub mod idempotency_repo;
+pub mod port_allocation_repo;
+pub mod chat_account_repo;
+pub mod preview_repo;
+pub mod secret_repo;
+pub mod project_permission_repo;
+pub mod outbound_webhook_repo;
+pub mod api_token_repo;
+pub mod totp_repo;
 
 pub use sqlite_repo::{
     SqliteProjectRepository, SqliteBuildRepository, SqliteSettingsRepository, SqliteContainerRepository,
@@ -8,3 +19,20 @@ pub use sqlite_repo::{
 pub use discord_webhook_repo::{
     SqliteDiscordWebhookRepository, CreateDiscordWebhook, UpdateDiscordWebhook,
 };
+pub use slack_webhook_repo::{SqliteSlackWebhookRepository, CreateSlackWebhook, UpdateSlackWebhook};
+pub use metrics_repo::{SqliteMetricsRepository, METRICS_BUCKET_SECS};
+pub use idempotency_repo::{
+    SqliteIdempotencyRepository, IdempotencyReservation, MAX_IDEMPOTENCY_KEY_LEN,
+};
+pub use port_allocation_repo::{SqlitePortAllocationRepository, PortAllocation, PortOwner};
+pub use chat_account_repo::{SqliteChatAccountRepository, ChatProvider, LinkCodeRedemption, generate_link_code, LINK_CODE_TTL_MINUTES};
+pub use preview_repo::{SqlitePreviewRepository, PreviewEnvironment, PreviewStatus};
+pub use secret_repo::SqliteSecretRepository;
+pub use project_permission_repo::{SqliteProjectPermissionRepository, ProjectAccess, ProjectPermission};
+pub use outbound_webhook_repo::{
+    SqliteOutboundWebhookRepository, OutboundWebhook, DeliveryStatus, OUTBOUND_WEBHOOK_EVENTS,
+};
+pub use api_token_repo::{
+    SqliteApiTokenRepository, ApiTokenScope, generate_api_token, MAX_API_TOKENS_PER_USER,
+};
+pub use totp_repo::{SqliteTotpRepository, UserTotp};
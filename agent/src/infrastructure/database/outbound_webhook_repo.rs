@@ -0,0 +1,236 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+
+/// webhook마다 보관하는 최근 전송 기록 수
+pub const MAX_DELIVERIES_PER_WEBHOOK: i64 = 100;
+
+/// outbound webhook으로 보낼 수 있는 이벤트 (이벤트 버스의 "type" 태그와 같은 이름)
+pub const OUTBOUND_WEBHOOK_EVENTS: &[&str] = &["build_status", "deployment", "error"];
+
+/// 프로젝트 outbound webhook (secret은 생성 시 응답에만 포함)
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct OutboundWebhook {
+    pub id: i64,
+    pub project_id: i64,
+    pub url: String,
+    #[serde(skip_serializing)]
+    pub secret: String,
+    /// JSON 배열
+    pub events: String,
+    pub enabled: bool,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
+    pub created_at: String,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
+    pub updated_at: String,
+}
+
+impl OutboundWebhook {
+    pub fn parsed_events(&self) -> Vec<String> {
+        serde_json::from_str(&self.events).unwrap_or_default()
+    }
+
+    pub fn wants(&self, event_type: &str) -> bool {
+        self.enabled && self.parsed_events().iter().any(|e| e == event_type)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeliveryStatus {
+    Pending,
+    Success,
+    Failed,
+}
+
+impl std::fmt::Display for DeliveryStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeliveryStatus::Pending => write!(f, "pending"),
+            DeliveryStatus::Success => write!(f, "success"),
+            DeliveryStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct WebhookDelivery {
+    pub id: i64,
+    pub webhook_id: i64,
+    pub event_type: String,
+    pub payload: String,
+    pub status: String,
+    pub attempts: i64,
+    pub response_status: Option<i64>,
+    pub error: Option<String>,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
+    pub created_at: String,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
+    pub updated_at: String,
+}
+
+#[derive(Clone)]
+pub struct SqliteOutboundWebhookRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteOutboundWebhookRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list_by_project(&self, project_id: i64) -> Result<Vec<OutboundWebhook>> {
+        let rows = sqlx::query_as::<_, OutboundWebhook>(
+            "SELECT * FROM project_webhooks WHERE project_id = ? ORDER BY id"
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn get(&self, project_id: i64, id: i64) -> Result<Option<OutboundWebhook>> {
+        let row = sqlx::query_as::<_, OutboundWebhook>(
+            "SELECT * FROM project_webhooks WHERE id = ? AND project_id = ?"
+        )
+        .bind(id)
+        .bind(project_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row)
+    }
+
+    pub async fn create(&self, project_id: i64, url: &str, secret: &str, events: &[String]) -> Result<OutboundWebhook> {
+        let result = sqlx::query(
+            "INSERT INTO project_webhooks (project_id, url, secret, events) VALUES (?, ?, ?, ?)"
+        )
+        .bind(project_id)
+        .bind(url)
+        .bind(secret)
+        .bind(serde_json::to_string(events)?)
+        .execute(&self.pool)
+        .await?;
+
+        self.get(project_id, result.last_insert_rowid())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Failed to fetch created webhook"))
+    }
+
+    /// 지정한 값만 변경. 없는 webhook이면 None
+    pub async fn update(
+        &self,
+        project_id: i64,
+        id: i64,
+        url: Option<&str>,
+        events: Option<&[String]>,
+        enabled: Option<bool>,
+    ) -> Result<Option<OutboundWebhook>> {
+        let events = events.map(serde_json::to_string).transpose()?;
+        let result = sqlx::query(
+            "UPDATE project_webhooks
+             SET url = COALESCE(?, url), events = COALESCE(?, events), enabled = COALESCE(?, enabled),
+                 updated_at = datetime('now')
+             WHERE id = ? AND project_id = ?"
+        )
+        .bind(url)
+        .bind(events)
+        .bind(enabled)
+        .bind(id)
+        .bind(project_id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+        self.get(project_id, id).await
+    }
+
+    pub async fn delete(&self, project_id: i64, id: i64) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM project_webhooks WHERE id = ? AND project_id = ?")
+            .bind(id)
+            .bind(project_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// pending 전송 기록 추가 (오래된 기록은 MAX_DELIVERIES_PER_WEBHOOK건만 남김)
+    pub async fn create_delivery(&self, webhook_id: i64, event_type: &str, payload: &str) -> Result<i64> {
+        let id = sqlx::query(
+            "INSERT INTO webhook_deliveries (webhook_id, event_type, payload) VALUES (?, ?, ?)"
+        )
+        .bind(webhook_id)
+        .bind(event_type)
+        .bind(payload)
+        .execute(&self.pool)
+        .await?
+        .last_insert_rowid();
+
+        sqlx::query(
+            "DELETE FROM webhook_deliveries WHERE webhook_id = ? AND id NOT IN
+             (SELECT id FROM webhook_deliveries WHERE webhook_id = ? ORDER BY id DESC LIMIT ?)"
+        )
+        .bind(webhook_id)
+        .bind(webhook_id)
+        .bind(MAX_DELIVERIES_PER_WEBHOOK)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(id)
+    }
+
+    /// 시도 결과 기록
+    pub async fn record_attempt(
+        &self,
+        delivery_id: i64,
+        status: DeliveryStatus,
+        attempts: u32,
+        response_status: Option<u16>,
+        error: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query(
+            "UPDATE webhook_deliveries
+             SET status = ?, attempts = ?, response_status = ?, error = ?, updated_at = datetime('now')
+             WHERE id = ?"
+        )
+        .bind(status.to_string())
+        .bind(attempts)
+        .bind(response_status)
+        .bind(error)
+        .bind(delivery_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn list_deliveries(&self, webhook_id: i64, limit: i64) -> Result<Vec<WebhookDelivery>> {
+        let rows = sqlx::query_as::<_, WebhookDelivery>(
+            "SELECT * FROM webhook_deliveries WHERE webhook_id = ? ORDER BY id DESC LIMIT ?"
+        )
+        .bind(webhook_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// 재시작으로 중단된 pending 전송
+    pub async fn list_pending_deliveries(&self) -> Result<Vec<WebhookDelivery>> {
+        let rows = sqlx::query_as::<_, WebhookDelivery>(
+            "SELECT * FROM webhook_deliveries WHERE status = 'pending' ORDER BY id"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// 전송 대상 webhook (프로젝트 조건 없이 ID로 조회)
+    pub async fn get_by_id(&self, id: i64) -> Result<Option<OutboundWebhook>> {
+        let row = sqlx::query_as::<_, OutboundWebhook>("SELECT * FROM project_webhooks WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row)
+    }
+}
@@ -0,0 +1,131 @@
+use anyhow::Result;
+use serde::Serialize;
+use sqlx::{FromRow, SqlitePool};
+
+/// port_allocations 테이블의 한 행
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct PortAllocation {
+    pub port: i32,
+    pub port_type: String,
+    /// "allocated" 또는 "used_by_system"
+    pub status: String,
+    pub owner_type: Option<String>,
+    pub owner_id: Option<i64>,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
+    pub last_checked_at: String,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
+    pub created_at: String,
+}
+
+/// 실제로 포트를 쓰는 주체 (프로젝트 blue/green 포트 또는 컨테이너 포트)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, FromRow)]
+pub struct PortOwner {
+    pub port: i32,
+    /// "application" (프로젝트) 또는 "container"
+    pub port_type: String,
+    /// "project" 또는 "container"
+    pub owner_type: String,
+    pub owner_id: i64,
+    pub name: String,
+}
+
+#[derive(Clone)]
+pub struct SqlitePortAllocationRepository {
+    pool: SqlitePool,
+}
+
+impl SqlitePortAllocationRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list(&self) -> Result<Vec<PortAllocation>> {
+        let rows = sqlx::query_as::<_, PortAllocation>(
+            r#"
+            SELECT port, port_type, status, owner_type, owner_id, last_checked_at, created_at
+            FROM port_allocations ORDER BY port
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// 포트를 쓰는 프로젝트/컨테이너 목록 (컨테이너 추가 포트 매핑 포함).
+    /// 보관된 프로젝트는 포트를 반납한 것으로 보고, 삭제 유예 중인 프로젝트는 복원될 수 있으므로 포함
+    pub async fn owners(&self) -> Result<Vec<PortOwner>> {
+        let rows = sqlx::query_as::<_, PortOwner>(
+            r#"
+            SELECT blue_port AS port, 'application' AS port_type, 'project' AS owner_type, id AS owner_id, name
+            FROM projects WHERE archived_at IS NULL
+            UNION ALL
+            SELECT green_port, 'application', 'project', id, name
+            FROM projects WHERE archived_at IS NULL
+            UNION ALL
+            SELECT port, 'container', 'container', id, name
+            FROM containers
+            UNION ALL
+            SELECT json_extract(ports.value, '$.host_port'), 'container', 'container', containers.id, containers.name
+            FROM containers, json_each(containers.extra_ports) AS ports
+            WHERE containers.extra_ports IS NOT NULL
+            ORDER BY port
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// 주인 없는 할당 해제. `min_age_secs`보다 최근에 만든 행은 건너뜀
+    /// (컨테이너 생성 중 포트 할당과 containers INSERT 사이의 빈틈 보호)
+    pub async fn release_if_older(&self, port: i32, min_age_secs: i64) -> Result<bool> {
+        let result = sqlx::query(
+            "DELETE FROM port_allocations WHERE port = ? AND created_at <= datetime('now', ?)"
+        )
+        .bind(port)
+        .bind(format!("-{} seconds", min_age_secs))
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 프로젝트/컨테이너가 쓰는 포트를 'allocated'로 등록 (기존 행은 주인 정보로 덮어씀)
+    pub async fn register(&self, owner: &PortOwner) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO port_allocations (port, port_type, status, owner_type, owner_id, last_checked_at)
+            VALUES (?, ?, 'allocated', ?, ?, datetime('now'))
+            ON CONFLICT(port) DO UPDATE SET
+                port_type = excluded.port_type,
+                status = 'allocated',
+                owner_type = excluded.owner_type,
+                owner_id = excluded.owner_id,
+                last_checked_at = excluded.last_checked_at
+            "#
+        )
+        .bind(owner.port)
+        .bind(&owner.port_type)
+        .bind(&owner.owner_type)
+        .bind(owner.owner_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 외부 프로그램이 쓰는 포트로 기록 (할당 대상에서 제외됨)
+    pub async fn mark_external(&self, port: i32, port_type: &str) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO port_allocations (port, port_type, status, owner_type, last_checked_at)
+            VALUES (?, ?, 'used_by_system', 'external', datetime('now'))
+            ON CONFLICT(port) DO UPDATE SET
+                last_checked_at = excluded.last_checked_at
+            "#
+        )
+        .bind(port)
+        .bind(port_type)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
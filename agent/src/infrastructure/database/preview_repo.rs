@@ -0,0 +1,152 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+
+/// PR 프리뷰 환경 상태
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PreviewStatus {
+    /// 프리뷰 빌드가 대기/실행 중
+    Building,
+    Running,
+    /// 빌드 또는 컨테이너 시작 실패 (다음 push에서 다시 시도)
+    Failed,
+}
+
+impl std::fmt::Display for PreviewStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PreviewStatus::Building => write!(f, "building"),
+            PreviewStatus::Running => write!(f, "running"),
+            PreviewStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+/// PR 하나의 프리뷰 환경 (`project-{id}-pr-{number}` 컨테이너)
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct PreviewEnvironment {
+    pub id: i64,
+    pub project_id: i64,
+    pub pr_number: i64,
+    pub branch: String,
+    pub head_sha: String,
+    pub title: Option<String>,
+    pub status: String,
+    pub build_id: Option<i64>,
+    pub container_id: Option<String>,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
+    pub created_at: String,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
+    pub updated_at: String,
+}
+
+#[derive(Clone)]
+pub struct SqlitePreviewRepository {
+    pool: SqlitePool,
+}
+
+impl SqlitePreviewRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list(&self) -> Result<Vec<PreviewEnvironment>> {
+        let rows = sqlx::query_as::<_, PreviewEnvironment>(
+            "SELECT * FROM preview_environments ORDER BY project_id, pr_number"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn list_by_project(&self, project_id: i64) -> Result<Vec<PreviewEnvironment>> {
+        let rows = sqlx::query_as::<_, PreviewEnvironment>(
+            "SELECT * FROM preview_environments WHERE project_id = ? ORDER BY pr_number"
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn get(&self, project_id: i64, pr_number: i64) -> Result<Option<PreviewEnvironment>> {
+        let row = sqlx::query_as::<_, PreviewEnvironment>(
+            "SELECT * FROM preview_environments WHERE project_id = ? AND pr_number = ?"
+        )
+        .bind(project_id)
+        .bind(pr_number)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row)
+    }
+
+    /// PR의 새 프리뷰 빌드 기록 (없으면 생성, 있으면 브랜치/커밋/빌드 갱신). 상태는 building
+    pub async fn upsert_build(
+        &self,
+        project_id: i64,
+        pr_number: i64,
+        branch: &str,
+        head_sha: &str,
+        title: Option<&str>,
+        build_id: i64,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO preview_environments (project_id, pr_number, branch, head_sha, title, status, build_id)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT (project_id, pr_number) DO UPDATE SET
+                branch = excluded.branch,
+                head_sha = excluded.head_sha,
+                title = excluded.title,
+                status = excluded.status,
+                build_id = excluded.build_id,
+                updated_at = datetime('now')
+            "#
+        )
+        .bind(project_id)
+        .bind(pr_number)
+        .bind(branch)
+        .bind(head_sha)
+        .bind(title)
+        .bind(PreviewStatus::Building.to_string())
+        .bind(build_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 프리뷰 빌드 결과 기록 (상태, 마지막 빌드, 실행 중인 컨테이너). PR이 이미 닫혀 행이 없으면 false
+    pub async fn update_status(
+        &self,
+        project_id: i64,
+        pr_number: i64,
+        build_id: i64,
+        status: PreviewStatus,
+        container_id: Option<&str>,
+    ) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE preview_environments SET status = ?, build_id = ?, container_id = ?, updated_at = datetime('now')
+            WHERE project_id = ? AND pr_number = ?
+            "#
+        )
+        .bind(status.to_string())
+        .bind(build_id)
+        .bind(container_id)
+        .bind(project_id)
+        .bind(pr_number)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn delete(&self, project_id: i64, pr_number: i64) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM preview_environments WHERE project_id = ? AND pr_number = ?")
+            .bind(project_id)
+            .bind(pr_number)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
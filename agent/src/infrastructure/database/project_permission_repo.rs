@@ -0,0 +1,202 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+
+/// 프로젝트 단위로 부여하는 권한 (서로 포함 관계 없음)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectPermission {
+    /// 빌드 실행, 배포/롤백, 슬롯 전환, 컨테이너 시작/중지
+    Deploy,
+    /// 빌드/배포 로그, 런타임 로그, 터미널(읽기)
+    ViewLogs,
+    /// 프로젝트 설정 변경/삭제, 권한 관리
+    ManageSettings,
+}
+
+impl std::fmt::Display for ProjectPermission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProjectPermission::Deploy => write!(f, "deploy"),
+            ProjectPermission::ViewLogs => write!(f, "view_logs"),
+            ProjectPermission::ManageSettings => write!(f, "manage_settings"),
+        }
+    }
+}
+
+impl std::str::FromStr for ProjectPermission {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "deploy" => Ok(ProjectPermission::Deploy),
+            "view_logs" => Ok(ProjectPermission::ViewLogs),
+            "manage_settings" => Ok(ProjectPermission::ManageSettings),
+            _ => Err(format!("Unknown permission: {}", s)),
+        }
+    }
+}
+
+/// 사용자의 프로젝트 접근 수준
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProjectAccess {
+    /// 권한이 설정되지 않은 프로젝트 (기존 동작: 모든 사용자 전체 권한)
+    Full,
+    /// 부여된 권한만. 프로젝트 기본 정보 조회는 항상 허용
+    Limited(Vec<ProjectPermission>),
+    Denied,
+}
+
+impl ProjectAccess {
+    /// `required`가 None이면 프로젝트 기본 정보 조회
+    pub fn allows(&self, required: Option<ProjectPermission>) -> bool {
+        match self {
+            ProjectAccess::Full => true,
+            ProjectAccess::Limited(granted) => required.is_none_or(|p| granted.contains(&p)),
+            ProjectAccess::Denied => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ProjectPermissionGrant {
+    pub id: i64,
+    pub project_id: i64,
+    pub user_email: String,
+    pub permission: String,
+    pub created_by: Option<String>,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
+    pub created_at: String,
+}
+
+#[derive(Clone)]
+pub struct SqliteProjectPermissionRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteProjectPermissionRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list_by_project(&self, project_id: i64) -> Result<Vec<ProjectPermissionGrant>> {
+        let rows = sqlx::query_as::<_, ProjectPermissionGrant>(
+            "SELECT * FROM project_permissions WHERE project_id = ? ORDER BY user_email, permission"
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// 사용자에게 부여된 권한 (프로젝트 멤버십)
+    pub async fn list_by_email(&self, email: &str) -> Result<Vec<ProjectPermissionGrant>> {
+        let rows = sqlx::query_as::<_, ProjectPermissionGrant>(
+            "SELECT * FROM project_permissions WHERE user_email = ? ORDER BY project_id, permission"
+        )
+        .bind(email.to_lowercase())
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// 특정 주체(created_by)가 부여한 권한 (예: GitHub 팀 동기화)
+    pub async fn list_created_by(&self, created_by: &str) -> Result<Vec<ProjectPermissionGrant>> {
+        let rows = sqlx::query_as::<_, ProjectPermissionGrant>(
+            "SELECT * FROM project_permissions WHERE created_by = ? ORDER BY id"
+        )
+        .bind(created_by)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// 사용자의 접근 수준 (email이 None이면 로그인 사용자를 확인할 수 없는 경우)
+    pub async fn access(&self, project_id: i64, email: Option<&str>) -> Result<ProjectAccess> {
+        let grants = self.list_by_project(project_id).await?;
+        if grants.is_empty() {
+            return Ok(ProjectAccess::Full);
+        }
+
+        let Some(email) = email else { return Ok(ProjectAccess::Denied) };
+        let granted: Vec<ProjectPermission> = grants
+            .iter()
+            .filter(|g| g.user_email.eq_ignore_ascii_case(email))
+            .filter_map(|g| g.permission.parse().ok())
+            .collect();
+        if granted.is_empty() {
+            Ok(ProjectAccess::Denied)
+        } else {
+            Ok(ProjectAccess::Limited(granted))
+        }
+    }
+
+    /// 사용자가 접근할 수 없는 프로젝트 ID (권한이 설정됐지만 본인 권한이 없는 프로젝트)
+    pub async fn hidden_project_ids(&self, email: Option<&str>) -> Result<Vec<i64>> {
+        let ids = sqlx::query_scalar(
+            "SELECT DISTINCT project_id FROM project_permissions
+             WHERE project_id NOT IN (SELECT project_id FROM project_permissions WHERE user_email = ?)"
+        )
+        .bind(email.unwrap_or_default().to_lowercase())
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(ids)
+    }
+
+    /// 권한 부여. 이미 있는 권한이면 None
+    pub async fn grant(
+        &self,
+        project_id: i64,
+        email: &str,
+        permission: ProjectPermission,
+        created_by: Option<&str>,
+    ) -> Result<Option<ProjectPermissionGrant>> {
+        let result = sqlx::query(
+            "INSERT OR IGNORE INTO project_permissions (project_id, user_email, permission, created_by) VALUES (?, ?, ?, ?)"
+        )
+        .bind(project_id)
+        .bind(email.to_lowercase())
+        .bind(permission.to_string())
+        .bind(created_by)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        let grant = sqlx::query_as::<_, ProjectPermissionGrant>("SELECT * FROM project_permissions WHERE id = ?")
+            .bind(result.last_insert_rowid())
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(Some(grant))
+    }
+
+    pub async fn revoke(&self, project_id: i64, id: i64) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM project_permissions WHERE id = ? AND project_id = ?")
+            .bind(id)
+            .bind(project_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_access_allows() {
+        let deployer = ProjectAccess::Limited(vec![ProjectPermission::Deploy]);
+        assert!(deployer.allows(None));
+        assert!(deployer.allows(Some(ProjectPermission::Deploy)));
+        assert!(!deployer.allows(Some(ProjectPermission::ViewLogs)));
+        assert!(!deployer.allows(Some(ProjectPermission::ManageSettings)));
+
+        assert!(ProjectAccess::Full.allows(Some(ProjectPermission::ManageSettings)));
+        assert!(!ProjectAccess::Denied.allows(None));
+        assert_eq!("view_logs".parse::<ProjectPermission>(), Ok(ProjectPermission::ViewLogs));
+        assert!("admin".parse::<ProjectPermission>().is_err());
+    }
+}
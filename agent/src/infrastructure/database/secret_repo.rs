@@ -0,0 +1,126 @@
+use anyhow::Result;
+use serde::Serialize;
+use sqlx::{FromRow, SqlitePool};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+use crate::infrastructure::secrets::{env_secret_refs, SecretCipher, SecretEnv};
+
+/// 비밀 메타데이터 (값은 API 응답에 포함하지 않음)
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct Secret {
+    pub id: i64,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
+    pub created_at: String,
+    #[serde(serialize_with = "crate::infrastructure::timezone::serialize")]
+    pub updated_at: String,
+}
+
+/// SqliteSecretRepository - 비밀 값을 암호화해 저장하고 주입 시점에만 복호화
+#[derive(Clone)]
+pub struct SqliteSecretRepository {
+    pool: SqlitePool,
+    cipher: Arc<SecretCipher>,
+}
+
+impl SqliteSecretRepository {
+    pub fn new(pool: SqlitePool, cipher: Arc<SecretCipher>) -> Self {
+        Self { pool, cipher }
+    }
+
+    pub async fn list(&self) -> Result<Vec<Secret>> {
+        let rows = sqlx::query_as::<_, Secret>(
+            "SELECT id, name, description, created_at, updated_at FROM secrets ORDER BY name"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn get(&self, name: &str) -> Result<Option<Secret>> {
+        let row = sqlx::query_as::<_, Secret>(
+            "SELECT id, name, description, created_at, updated_at FROM secrets WHERE name = ?"
+        )
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row)
+    }
+
+    /// 비밀 추가. 같은 이름이 있으면 None
+    pub async fn create(&self, name: &str, value: &str, description: Option<&str>) -> Result<Option<Secret>> {
+        let encrypted = self.cipher.encrypt(name, value)?;
+        let result = sqlx::query(
+            "INSERT OR IGNORE INTO secrets (name, encrypted_value, description) VALUES (?, ?, ?)"
+        )
+        .bind(name)
+        .bind(&encrypted)
+        .bind(description)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+        self.get(name).await
+    }
+
+    /// 값/설명 변경 (None이면 유지). 없는 비밀이면 None
+    pub async fn update(&self, name: &str, value: Option<&str>, description: Option<&str>) -> Result<Option<Secret>> {
+        let encrypted = value.map(|v| self.cipher.encrypt(name, v)).transpose()?;
+        let result = sqlx::query(
+            r#"
+            UPDATE secrets SET
+                encrypted_value = COALESCE(?, encrypted_value),
+                description = COALESCE(?, description),
+                updated_at = datetime('now')
+            WHERE name = ?
+            "#
+        )
+        .bind(encrypted)
+        .bind(description)
+        .bind(name)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+        self.get(name).await
+    }
+
+    pub async fn delete(&self, name: &str) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM secrets WHERE name = ?")
+            .bind(name)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 이름별 복호화한 값 (없는 이름은 결과에서 빠짐)
+    pub async fn reveal(&self, names: &BTreeSet<String>) -> Result<HashMap<String, String>> {
+        let mut values = HashMap::new();
+        for name in names {
+            let encrypted: Option<String> = sqlx::query_scalar("SELECT encrypted_value FROM secrets WHERE name = ?")
+                .bind(name)
+                .fetch_optional(&self.pool)
+                .await?;
+            if let Some(encrypted) = encrypted {
+                values.insert(name.clone(), self.cipher.decrypt(name, &encrypted)?);
+            }
+        }
+        Ok(values)
+    }
+
+    /// 환경변수 JSON에서 비밀을 참조하는 항목을 복호화한 값으로 치환
+    pub async fn resolve_env(&self, env_json: Option<&str>) -> Result<SecretEnv> {
+        let names = env_secret_refs(env_json);
+        if names.is_empty() {
+            return Ok(SecretEnv::default());
+        }
+        let values = self.reveal(&names).await?;
+        SecretEnv::resolve(env_json, &values).map_err(anyhow::Error::msg)
+    }
+}
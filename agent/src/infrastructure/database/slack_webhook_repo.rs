@@ -0,0 +1,187 @@
+use anyhow::Result;
+use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
+use crate::infrastructure::notifications::SlackWebhookConfig;
+
+const SELECT_COLUMNS: &str = r#"
+    SELECT
+        id, label, webhook_url, enabled,
+        notify_on_build_start, notify_on_build_success, notify_on_build_failure,
+        notify_on_deploy_start, notify_on_deploy_success, notify_on_deploy_failure,
+        mention_user_ids, mention_group_ids, mention_on_failure_only
+    FROM slack_webhooks
+"#;
+
+#[derive(Clone)]
+pub struct SqliteSlackWebhookRepository {
+    pool: SqlitePool,
+}
+
+fn config_from_row(row: &SqliteRow) -> SlackWebhookConfig {
+    let ids = |column: &str| -> Vec<String> {
+        row.try_get::<Option<String>, _>(column)
+            .ok()
+            .flatten()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    };
+    let flag = |column: &str| row.try_get::<i32, _>(column).unwrap_or(0) != 0;
+
+    SlackWebhookConfig {
+        id: row.try_get("id").unwrap_or(0),
+        label: row.try_get("label").unwrap_or_default(),
+        webhook_url: row.try_get("webhook_url").unwrap_or_default(),
+        enabled: flag("enabled"),
+        notify_on_build_start: flag("notify_on_build_start"),
+        notify_on_build_success: flag("notify_on_build_success"),
+        notify_on_build_failure: flag("notify_on_build_failure"),
+        notify_on_deploy_start: flag("notify_on_deploy_start"),
+        notify_on_deploy_success: flag("notify_on_deploy_success"),
+        notify_on_deploy_failure: flag("notify_on_deploy_failure"),
+        mention_user_ids: ids("mention_user_ids"),
+        mention_group_ids: ids("mention_group_ids"),
+        mention_on_failure_only: flag("mention_on_failure_only"),
+    }
+}
+
+impl SqliteSlackWebhookRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn get(&self, id: i64) -> Result<Option<SlackWebhookConfig>> {
+        let row = sqlx::query(&format!("{} WHERE id = ?", SELECT_COLUMNS))
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.as_ref().map(config_from_row))
+    }
+
+    pub async fn list_all(&self) -> Result<Vec<SlackWebhookConfig>> {
+        let rows = sqlx::query(&format!("{} ORDER BY created_at DESC", SELECT_COLUMNS))
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.iter().map(config_from_row).collect())
+    }
+
+    pub async fn create(&self, config: CreateSlackWebhook) -> Result<SlackWebhookConfig> {
+        let mention_user_ids_json = serde_json::to_string(&config.mention_user_ids)?;
+        let mention_group_ids_json = serde_json::to_string(&config.mention_group_ids)?;
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO slack_webhooks (
+                label, webhook_url, enabled,
+                notify_on_build_start, notify_on_build_success, notify_on_build_failure,
+                notify_on_deploy_start, notify_on_deploy_success, notify_on_deploy_failure,
+                mention_user_ids, mention_group_ids, mention_on_failure_only
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#
+        )
+        .bind(&config.label)
+        .bind(&config.webhook_url)
+        .bind(config.enabled)
+        .bind(config.notify_on_build_start)
+        .bind(config.notify_on_build_success)
+        .bind(config.notify_on_build_failure)
+        .bind(config.notify_on_deploy_start)
+        .bind(config.notify_on_deploy_success)
+        .bind(config.notify_on_deploy_failure)
+        .bind(&mention_user_ids_json)
+        .bind(&mention_group_ids_json)
+        .bind(config.mention_on_failure_only)
+        .execute(&self.pool)
+        .await?;
+
+        let id = result.last_insert_rowid();
+        self.get(id).await?.ok_or_else(|| anyhow::anyhow!("Failed to retrieve created webhook"))
+    }
+
+    pub async fn update(&self, id: i64, config: UpdateSlackWebhook) -> Result<SlackWebhookConfig> {
+        let current = self.get(id).await?
+            .ok_or_else(|| anyhow::anyhow!("Slack webhook not found"))?;
+
+        let mention_user_ids_json = serde_json::to_string(&config.mention_user_ids.unwrap_or(current.mention_user_ids))?;
+        let mention_group_ids_json = serde_json::to_string(&config.mention_group_ids.unwrap_or(current.mention_group_ids))?;
+
+        sqlx::query(
+            r#"
+            UPDATE slack_webhooks SET
+                label = ?,
+                webhook_url = ?,
+                enabled = ?,
+                notify_on_build_start = ?,
+                notify_on_build_success = ?,
+                notify_on_build_failure = ?,
+                notify_on_deploy_start = ?,
+                notify_on_deploy_success = ?,
+                notify_on_deploy_failure = ?,
+                mention_user_ids = ?,
+                mention_group_ids = ?,
+                mention_on_failure_only = ?,
+                updated_at = datetime('now')
+            WHERE id = ?
+            "#
+        )
+        .bind(config.label.unwrap_or(current.label))
+        .bind(config.webhook_url.unwrap_or(current.webhook_url))
+        .bind(config.enabled.unwrap_or(current.enabled))
+        .bind(config.notify_on_build_start.unwrap_or(current.notify_on_build_start))
+        .bind(config.notify_on_build_success.unwrap_or(current.notify_on_build_success))
+        .bind(config.notify_on_build_failure.unwrap_or(current.notify_on_build_failure))
+        .bind(config.notify_on_deploy_start.unwrap_or(current.notify_on_deploy_start))
+        .bind(config.notify_on_deploy_success.unwrap_or(current.notify_on_deploy_success))
+        .bind(config.notify_on_deploy_failure.unwrap_or(current.notify_on_deploy_failure))
+        .bind(&mention_user_ids_json)
+        .bind(&mention_group_ids_json)
+        .bind(config.mention_on_failure_only.unwrap_or(current.mention_on_failure_only))
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        self.get(id).await?.ok_or_else(|| anyhow::anyhow!("Slack webhook not found after update"))
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<()> {
+        sqlx::query("DELETE FROM slack_webhooks WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+/// Create Slack webhook request
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CreateSlackWebhook {
+    pub label: String,
+    pub webhook_url: String,
+    pub enabled: bool,
+    pub notify_on_build_start: bool,
+    pub notify_on_build_success: bool,
+    pub notify_on_build_failure: bool,
+    pub notify_on_deploy_start: bool,
+    pub notify_on_deploy_success: bool,
+    pub notify_on_deploy_failure: bool,
+    #[serde(default)]
+    pub mention_user_ids: Vec<String>,
+    #[serde(default)]
+    pub mention_group_ids: Vec<String>,
+    pub mention_on_failure_only: bool,
+}
+
+/// Update Slack webhook request
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct UpdateSlackWebhook {
+    pub label: Option<String>,
+    pub webhook_url: Option<String>,
+    pub enabled: Option<bool>,
+    pub notify_on_build_start: Option<bool>,
+    pub notify_on_build_success: Option<bool>,
+    pub notify_on_build_failure: Option<bool>,
+    pub notify_on_deploy_start: Option<bool>,
+    pub notify_on_deploy_success: Option<bool>,
+    pub notify_on_deploy_failure: Option<bool>,
+    pub mention_user_ids: Option<Vec<String>>,
+    pub mention_group_ids: Option<Vec<String>>,
+    pub mention_on_failure_only: Option<bool>,
+}
@@ -42,8 +42,8 @@ impl ProjectRepository for SqliteProjectRepository {
                 name, repo, path_filter, branch,
                 build_image, build_command, cache_type, working_directory, build_env_vars,
                 runtime_image, runtime_command, health_check_url, runtime_port, runtime_env_vars,
-                blue_port, green_port, active_slot, github_pat_id, discord_webhook_id
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 'Blue', ?, ?)
+                blue_port, green_port, active_slot, github_pat_id, discord_webhook_id, hooks, dockerfile_path
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 'Blue', ?, ?, ?, ?)
             "#
         )
         .bind(&project.name)
@@ -64,6 +64,8 @@ impl ProjectRepository for SqliteProjectRepository {
         .bind(green_port)
         .bind(&project.github_pat_id)
         .bind(&project.discord_webhook_id)
+        .bind(&project.hooks)
+        .bind(&project.dockerfile_path)
         .execute(&self.pool)
         .await?;
 
@@ -85,7 +87,7 @@ impl ProjectRepository for SqliteProjectRepository {
     }
 
     async fn get_by_name(&self, name: &str) -> Result<Option<Project>> {
-        let project = sqlx::query_as::<_, Project>("SELECT * FROM projects WHERE name = ?")
+        let project = sqlx::query_as::<_, Project>("SELECT * FROM projects WHERE name = ? AND deleted_at IS NULL")
             .bind(name)
             .fetch_optional(&self.pool)
             .await?;
@@ -93,17 +95,93 @@ impl ProjectRepository for SqliteProjectRepository {
     }
 
     async fn list(&self) -> Result<Vec<Project>> {
+        let projects = sqlx::query_as::<_, Project>("SELECT * FROM projects WHERE deleted_at IS NULL ORDER BY created_at DESC")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(projects)
+    }
+
+    async fn list_deleted(&self) -> Result<Vec<Project>> {
+        let projects = sqlx::query_as::<_, Project>("SELECT * FROM projects WHERE deleted_at IS NOT NULL ORDER BY deleted_at ASC")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(projects)
+    }
+
+    async fn list_with_deleted(&self) -> Result<Vec<Project>> {
         let projects = sqlx::query_as::<_, Project>("SELECT * FROM projects ORDER BY created_at DESC")
             .fetch_all(&self.pool)
             .await?;
         Ok(projects)
     }
 
+    async fn mark_deleted(&self, id: i64) -> Result<bool> {
+        let result = sqlx::query("UPDATE projects SET deleted_at = datetime('now') WHERE id = ? AND deleted_at IS NULL")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn restore(&self, id: i64) -> Result<bool> {
+        let result = sqlx::query("UPDATE projects SET deleted_at = NULL WHERE id = ? AND deleted_at IS NOT NULL")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn set_archived(&self, id: i64, archived: bool) -> Result<bool> {
+        let query = if archived {
+            "UPDATE projects SET archived_at = datetime('now') WHERE id = ? AND archived_at IS NULL"
+        } else {
+            "UPDATE projects SET archived_at = NULL WHERE id = ? AND archived_at IS NOT NULL"
+        };
+        let result = sqlx::query(query)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn reassign_ports(&self, id: i64) -> Result<(i32, i32)> {
+        // create()와 같은 규칙 (MAX(green_port) + 1)
+        let max_port: Option<i32> = sqlx::query_scalar("SELECT MAX(green_port) FROM projects")
+            .fetch_optional(&self.pool)
+            .await?
+            .flatten();
+        let blue_port = match max_port {
+            Some(port) => port + 1,
+            None => 10002,
+        };
+        let green_port = blue_port + 1;
+
+        self.set_ports(id, blue_port, green_port).await?;
+        Ok((blue_port, green_port))
+    }
+
+    async fn set_ports(&self, id: i64, blue_port: i32, green_port: i32) -> Result<()> {
+        sqlx::query("UPDATE projects SET blue_port = ?, green_port = ? WHERE id = ?")
+            .bind(blue_port)
+            .bind(green_port)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
     async fn update(&self, id: i64, update: UpdateProject) -> Result<Project> {
         // Get current project
         let current = self.get(id).await?
             .ok_or_else(|| anyhow::anyhow!("Project not found"))?;
 
+        if let Some(expected) = update.expected_version {
+            if expected != current.version {
+                return Err(ProjectVersionConflict { id, current_version: current.version }.into());
+            }
+        }
+        let base_version = current.version;
+
         // Merge with update values (use existing value if update is None)
         let name = update.name.unwrap_or(current.name);
         let repo = update.repo.unwrap_or(current.repo);
@@ -127,8 +205,87 @@ impl ProjectRepository for SqliteProjectRepository {
             Some(new_val) => new_val,       // Explicitly provided (Some(id) or None to clear)
             None => current.discord_webhook_id,  // Not provided, keep current
         };
+        let slack_webhook_id = match update.slack_webhook_id {
+            Some(new_val) => new_val,
+            None => current.slack_webhook_id,
+        };
+        let hooks = update.hooks.or(current.hooks);
+        let disk_quota_mb = match update.disk_quota_mb {
+            Some(new_val) => new_val,
+            None => current.disk_quota_mb,
+        };
+        let test_config = match update.test_config {
+            Some(new_val) => new_val,
+            None => current.test_config,
+        };
+        let source_fetch = update.source_fetch.unwrap_or(current.source_fetch);
+        let build_network = update.build_network.unwrap_or(current.build_network);
+        let docker_access = update.docker_access.unwrap_or(current.docker_access);
+        let concurrency_group = match update.concurrency_group {
+            Some(new_val) => new_val,
+            None => current.concurrency_group,
+        };
+        let deploy_window = match update.deploy_window {
+            Some(new_val) => new_val,
+            None => current.deploy_window,
+        };
+        let commit_status = match update.commit_status {
+            Some(new_val) => new_val,
+            None => current.commit_status,
+        };
+        let auto_rebuild_on_image_update = update.auto_rebuild_on_image_update.unwrap_or(current.auto_rebuild_on_image_update);
+        let expose_host_port = update.expose_host_port.unwrap_or(current.expose_host_port);
+        let internal_only = update.internal_only.unwrap_or(current.internal_only);
+        let dependencies = match update.dependencies {
+            Some(new_val) => new_val,
+            None => current.dependencies,
+        };
+        let require_signed_commits = update.require_signed_commits.unwrap_or(current.require_signed_commits);
+        let warm_standby = update.warm_standby.unwrap_or(current.warm_standby);
+        let shadow_traffic_percent = update.shadow_traffic_percent.unwrap_or(current.shadow_traffic_percent);
+        let shadow_duration_secs = update.shadow_duration_secs.unwrap_or(current.shadow_duration_secs);
+        let pr_previews = update.pr_previews.unwrap_or(current.pr_previews);
+        let deployment_strategy = update.deployment_strategy.unwrap_or(current.deployment_strategy);
+        let extra_networks = match update.extra_networks {
+            Some(new_val) => new_val,
+            None => current.extra_networks,
+        };
+        let pipeline = match update.pipeline {
+            Some(new_val) => new_val,
+            None => current.pipeline,
+        };
+        let runtime_entrypoint = match update.runtime_entrypoint {
+            Some(new_val) => new_val,
+            None => current.runtime_entrypoint,
+        };
+        let runtime_args = match update.runtime_args {
+            Some(new_val) => new_val,
+            None => current.runtime_args,
+        };
+        let runtime_restart_policy = update.runtime_restart_policy.unwrap_or(current.runtime_restart_policy);
+        let runtime_restart_max_retries = match update.runtime_restart_max_retries {
+            Some(new_val) => new_val,
+            None => current.runtime_restart_max_retries,
+        };
+        let dockerfile_path = match update.dockerfile_path {
+            Some(new_val) => new_val,
+            None => current.dockerfile_path,
+        };
+        let build_retention_count = match update.build_retention_count {
+            Some(new_val) => new_val,
+            None => current.build_retention_count,
+        };
+        let build_retention_days = match update.build_retention_days {
+            Some(new_val) => new_val,
+            None => current.build_retention_days,
+        };
+        let notification_emails = match update.notification_emails {
+            Some(new_val) => new_val,
+            None => current.notification_emails,
+        };
 
-        sqlx::query(
+        // 읽은 뒤 다른 요청이 먼저 저장했다면 병합 결과로 덮어쓰지 않도록 version 조건으로 갱신
+        let result = sqlx::query(
             r#"
             UPDATE projects SET
                 name = ?,
@@ -147,8 +304,39 @@ impl ProjectRepository for SqliteProjectRepository {
                 runtime_env_vars = ?,
                 github_pat_id = ?,
                 discord_webhook_id = ?,
+                slack_webhook_id = ?,
+                hooks = ?,
+                disk_quota_mb = ?,
+                test_config = ?,
+                source_fetch = ?,
+                build_network = ?,
+                docker_access = ?,
+                concurrency_group = ?,
+                deploy_window = ?,
+                commit_status = ?,
+                auto_rebuild_on_image_update = ?,
+                expose_host_port = ?,
+                internal_only = ?,
+                dependencies = ?,
+                require_signed_commits = ?,
+                warm_standby = ?,
+                shadow_traffic_percent = ?,
+                shadow_duration_secs = ?,
+                pr_previews = ?,
+                deployment_strategy = ?,
+                extra_networks = ?,
+                pipeline = ?,
+                runtime_entrypoint = ?,
+                runtime_args = ?,
+                runtime_restart_policy = ?,
+                runtime_restart_max_retries = ?,
+                dockerfile_path = ?,
+                build_retention_count = ?,
+                build_retention_days = ?,
+                notification_emails = ?,
+                version = version + 1,
                 updated_at = datetime('now')
-            WHERE id = ?
+            WHERE id = ? AND version = ?
             "#
         )
         .bind(&name)
@@ -167,10 +355,46 @@ impl ProjectRepository for SqliteProjectRepository {
         .bind(&runtime_env_vars)
         .bind(&github_pat_id)
         .bind(&discord_webhook_id)
+        .bind(slack_webhook_id)
+        .bind(&hooks)
+        .bind(disk_quota_mb)
+        .bind(&test_config)
+        .bind(source_fetch.to_string())
+        .bind(build_network.to_string())
+        .bind(docker_access)
+        .bind(&concurrency_group)
+        .bind(&deploy_window)
+        .bind(&commit_status)
+        .bind(auto_rebuild_on_image_update)
+        .bind(expose_host_port)
+        .bind(internal_only)
+        .bind(&dependencies)
+        .bind(require_signed_commits)
+        .bind(warm_standby)
+        .bind(shadow_traffic_percent)
+        .bind(shadow_duration_secs)
+        .bind(pr_previews)
+        .bind(deployment_strategy.to_string())
+        .bind(&extra_networks)
+        .bind(&pipeline)
+        .bind(&runtime_entrypoint)
+        .bind(&runtime_args)
+        .bind(runtime_restart_policy.to_string())
+        .bind(runtime_restart_max_retries)
+        .bind(&dockerfile_path)
+        .bind(build_retention_count)
+        .bind(build_retention_days)
+        .bind(&notification_emails)
         .bind(id)
+        .bind(base_version)
         .execute(&self.pool)
         .await?;
 
+        if result.rows_affected() == 0 {
+            let current_version = self.get(id).await?.map(|p| p.version).unwrap_or(base_version);
+            return Err(ProjectVersionConflict { id, current_version }.into());
+        }
+
         // Return updated project
         self.get(id).await?.ok_or_else(|| anyhow::anyhow!("Project not found after update"))
     }
@@ -184,6 +408,16 @@ impl ProjectRepository for SqliteProjectRepository {
         Ok(())
     }
 
+    async fn update_canary(&self, id: i64, build_id: Option<i64>, weight: i32) -> Result<()> {
+        sqlx::query("UPDATE projects SET canary_build_id = ?, canary_weight = ? WHERE id = ?")
+            .bind(build_id)
+            .bind(weight)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
     async fn update_blue_container(&self, id: i64, container_id: Option<String>) -> Result<()> {
         sqlx::query("UPDATE projects SET blue_container_id = ? WHERE id = ?")
             .bind(container_id)
@@ -193,6 +427,26 @@ impl ProjectRepository for SqliteProjectRepository {
         Ok(())
     }
 
+    async fn update_network(&self, id: i64, isolation: bool, peers: Option<String>) -> Result<()> {
+        sqlx::query("UPDATE projects SET network_isolation = ?, network_peers = ?, version = version + 1, updated_at = datetime('now') WHERE id = ?")
+            .bind(isolation)
+            .bind(peers)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_notes(&self, id: i64, notes: Option<&str>, updated_by: Option<&str>) -> Result<()> {
+        sqlx::query("UPDATE projects SET notes = ?, notes_updated_at = datetime('now'), notes_updated_by = ? WHERE id = ?")
+            .bind(notes)
+            .bind(updated_by)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
     async fn update_green_container(&self, id: i64, container_id: Option<String>) -> Result<()> {
         sqlx::query("UPDATE projects SET green_container_id = ? WHERE id = ?")
             .bind(container_id)
@@ -210,9 +464,12 @@ impl ProjectRepository for SqliteProjectRepository {
         Ok(())
     }
 
-    async fn update_webhook_id(&self, id: i64, webhook_id: Option<i64>) -> Result<()> {
-        sqlx::query("UPDATE projects SET github_webhook_id = ? WHERE id = ?")
-            .bind(webhook_id)
+    async fn update_webhook_id(&self, id: i64, webhook_id: Option<&str>) -> Result<()> {
+        let numeric = webhook_id.and_then(|w| w.parse::<i64>().ok());
+        let uuid = webhook_id.filter(|_| numeric.is_none());
+        sqlx::query("UPDATE projects SET github_webhook_id = ?, webhook_uuid = ? WHERE id = ?")
+            .bind(numeric)
+            .bind(uuid)
             .bind(id)
             .execute(&self.pool)
             .await?;
@@ -227,6 +484,25 @@ impl ProjectRepository for SqliteProjectRepository {
             .await?;
         Ok(())
     }
+
+    async fn update_slack_webhook_id(&self, id: i64, webhook_id: Option<i64>) -> Result<()> {
+        sqlx::query("UPDATE projects SET slack_webhook_id = ? WHERE id = ?")
+            .bind(webhook_id)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_image_update_status(&self, id: i64, status: &str) -> Result<()> {
+        // 설정 변경이 아니므로 version은 올리지 않음
+        sqlx::query("UPDATE projects SET image_update_status = ? WHERE id = ?")
+            .bind(status)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
 }
 
 /// SQLite implementation of BuildRepository
@@ -254,14 +530,14 @@ impl BuildRepository for SqliteBuildRepository {
 
         let log_path = format!("/data/easycicd/logs/{}/{}.log", build.project_id, build_number);
         let deploy_log_path = format!("/data/easycicd/logs/{}/{}_deploy.log", build.project_id, build_number);
-        let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let now = crate::infrastructure::timezone::db_now();
 
         let result = sqlx::query(
             r#"
             INSERT INTO builds (
                 project_id, build_number, commit_hash, commit_message, author,
-                status, log_path, deploy_log_path, started_at
-            ) VALUES (?, ?, ?, ?, ?, 'Queued', ?, ?, ?)
+                status, log_path, deploy_log_path, dry_run, triggered_by, rebuild_of, branch, preview_pr, started_at
+            ) VALUES (?, ?, ?, ?, ?, 'Queued', ?, ?, ?, ?, ?, ?, ?, ?)
             "#
         )
         .bind(build.project_id)
@@ -271,6 +547,11 @@ impl BuildRepository for SqliteBuildRepository {
         .bind(&build.author)
         .bind(&log_path)
         .bind(&deploy_log_path)
+        .bind(build.dry_run)
+        .bind(&build.triggered_by)
+        .bind(build.rebuild_of)
+        .bind(&build.branch)
+        .bind(build.preview_pr)
         .bind(&now)
         .execute(&self.pool)
         .await?;
@@ -333,6 +614,40 @@ impl BuildRepository for SqliteBuildRepository {
         Ok(builds)
     }
 
+    async fn list_by_status(&self, status: BuildStatus) -> Result<Vec<Build>> {
+        let builds = sqlx::query_as::<_, Build>(
+            "SELECT * FROM builds WHERE status = ? ORDER BY id ASC"
+        )
+        .bind(status.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(builds)
+    }
+
+    async fn search(&self, project_id: Option<i64>, query: &str, limit: i64) -> Result<Vec<Build>> {
+        // LIKE 와일드카드는 검색어에서 그대로 취급
+        let escaped = query.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+        let pattern = format!("%{}%", escaped);
+        let builds = sqlx::query_as::<_, Build>(
+            r#"
+            SELECT * FROM builds
+            WHERE (? IS NULL OR project_id = ?)
+              AND (note LIKE ? ESCAPE '\' OR labels LIKE ? ESCAPE '\' OR commit_message LIKE ? ESCAPE '\')
+            ORDER BY started_at DESC
+            LIMIT ?
+            "#
+        )
+        .bind(project_id)
+        .bind(project_id)
+        .bind(&pattern)
+        .bind(&pattern)
+        .bind(&pattern)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(builds)
+    }
+
     async fn update_status(&self, id: i64, status: BuildStatus) -> Result<()> {
         sqlx::query("UPDATE builds SET status = ? WHERE id = ?")
             .bind(status.to_string())
@@ -343,7 +658,7 @@ impl BuildRepository for SqliteBuildRepository {
     }
 
     async fn finish(&self, id: i64, status: BuildStatus) -> Result<()> {
-        let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let now = crate::infrastructure::timezone::db_now();
         sqlx::query(
             "UPDATE builds SET status = ?, finished_at = ? WHERE id = ?"
         )
@@ -372,6 +687,259 @@ impl BuildRepository for SqliteBuildRepository {
             .await?;
         Ok(())
     }
+
+    async fn update_test_summary(&self, id: i64, summary: String) -> Result<()> {
+        sqlx::query("UPDATE builds SET test_summary = ? WHERE id = ?")
+            .bind(summary)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_log_levels(&self, id: i64, counts: String) -> Result<()> {
+        sqlx::query("UPDATE builds SET log_levels = ? WHERE id = ?")
+            .bind(counts)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_image_tag(&self, id: i64, image_tag: &str) -> Result<()> {
+        sqlx::query("UPDATE builds SET image_tag = ? WHERE id = ?")
+            .bind(image_tag)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn delete(&self, id: i64) -> Result<()> {
+        sqlx::query("DELETE FROM builds WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_failure_summary(&self, id: i64, summary: &str) -> Result<()> {
+        sqlx::query("UPDATE builds SET failure_summary = ? WHERE id = ?")
+            .bind(summary)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_diagnosis(&self, id: i64, diagnosis: &str) -> Result<()> {
+        sqlx::query("UPDATE builds SET diagnosis = ? WHERE id = ?")
+            .bind(diagnosis)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_resource_usage(&self, id: i64, peak_memory_bytes: i64, cpu_time_ms: i64) -> Result<()> {
+        sqlx::query("UPDATE builds SET peak_memory_bytes = ?, cpu_time_ms = ? WHERE id = ?")
+            .bind(peak_memory_bytes)
+            .bind(cpu_time_ms)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_annotation(&self, id: i64, note: Option<String>, labels: Option<String>) -> Result<()> {
+        sqlx::query("UPDATE builds SET note = ?, labels = ? WHERE id = ?")
+            .bind(note)
+            .bind(labels)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_runtime_image_digest(&self, id: i64, image: &str) -> Result<()> {
+        sqlx::query("UPDATE builds SET runtime_image_digest = ? WHERE id = ?")
+            .bind(image)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_artifacts(&self, id: i64, output_path: &str, checksums: &str) -> Result<()> {
+        sqlx::query("UPDATE builds SET output_path = ?, artifact_checksums = ? WHERE id = ?")
+            .bind(output_path)
+            .bind(checksums)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_queue_wait(&self, id: i64, queue_wait_ms: i64) -> Result<()> {
+        sqlx::query("UPDATE builds SET queue_wait_ms = ? WHERE id = ?")
+            .bind(queue_wait_ms)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_build_environment(&self, id: i64, environment: &str) -> Result<()> {
+        sqlx::query("UPDATE builds SET build_environment = ? WHERE id = ?")
+            .bind(environment)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_shadow_report(&self, id: i64, report: &str) -> Result<()> {
+        sqlx::query("UPDATE builds SET shadow_report = ? WHERE id = ?")
+            .bind(report)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn block_deploy(&self, id: i64, reason: &str) -> Result<()> {
+        let now = crate::infrastructure::timezone::db_now();
+        sqlx::query("UPDATE builds SET status = 'Verified', deploy_blocked_reason = ?, finished_at = ? WHERE id = ?")
+            .bind(reason)
+            .bind(&now)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_log_shipment(&self, id: i64, shipment: &str) -> Result<()> {
+        sqlx::query("UPDATE builds SET log_shipment = ? WHERE id = ?")
+            .bind(shipment)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn insert_test_results(&self, build_id: i64, project_id: i64, results: &[TestCaseResult]) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        for result in results {
+            sqlx::query(
+                "INSERT INTO test_results (build_id, project_id, name, status, duration_ms, attempt) VALUES (?, ?, ?, ?, ?, ?)"
+            )
+            .bind(build_id)
+            .bind(project_id)
+            .bind(&result.name)
+            .bind(result.status.to_string())
+            .bind(result.duration_ms)
+            .bind(result.attempt)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn list_test_results(&self, build_id: i64) -> Result<Vec<TestCaseResult>> {
+        let rows = sqlx::query_as::<_, (String, String, i64, i64)>(
+            "SELECT name, status, duration_ms, attempt FROM test_results WHERE build_id = ? ORDER BY attempt ASC, name ASC"
+        )
+        .bind(build_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(name, status, duration_ms, attempt)| {
+                Some(TestCaseResult { name, status: status.parse().ok()?, duration_ms, attempt })
+            })
+            .collect())
+    }
+
+    async fn insert_build_stages(&self, build_id: i64, project_id: i64, stages: &[BuildStageResult]) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        for stage in stages {
+            sqlx::query(
+                r#"
+                INSERT INTO build_stages (build_id, project_id, position, name, status, started_at, duration_ms, log_start_line, log_end_line)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#
+            )
+            .bind(build_id)
+            .bind(project_id)
+            .bind(stage.position)
+            .bind(&stage.name)
+            .bind(stage.status.to_string())
+            .bind(&stage.started_at)
+            .bind(stage.duration_ms)
+            .bind(stage.log_start_line)
+            .bind(stage.log_end_line)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn list_build_stages(&self, build_id: i64) -> Result<Vec<BuildStageResult>> {
+        let rows = sqlx::query_as::<_, (i64, String, String, Option<String>, Option<i64>, Option<i64>, Option<i64>)>(
+            r#"
+            SELECT position, name, status, started_at, duration_ms, log_start_line, log_end_line
+            FROM build_stages WHERE build_id = ? ORDER BY position ASC
+            "#
+        )
+        .bind(build_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(position, name, status, started_at, duration_ms, log_start_line, log_end_line)| {
+                Some(BuildStageResult {
+                    position,
+                    name,
+                    status: status.parse().ok()?,
+                    started_at,
+                    duration_ms,
+                    log_start_line,
+                    log_end_line,
+                })
+            })
+            .collect())
+    }
+
+    async fn list_test_history(&self, project_id: i64, builds: i64) -> Result<Vec<(i64, TestCaseResult)>> {
+        let rows = sqlx::query_as::<_, (i64, String, String, i64, i64)>(
+            r#"
+            SELECT build_id, name, status, duration_ms, attempt
+            FROM test_results
+            WHERE build_id IN (
+                SELECT DISTINCT build_id FROM test_results
+                WHERE project_id = ?
+                ORDER BY build_id DESC
+                LIMIT ?
+            )
+            ORDER BY build_id ASC, attempt ASC
+            "#
+        )
+        .bind(project_id)
+        .bind(builds)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(build_id, name, status, duration_ms, attempt)| {
+                Some((build_id, TestCaseResult { name, status: status.parse().ok()?, duration_ms, attempt }))
+            })
+            .collect())
+    }
 }
 
 /// SQLite implementation of SettingsRepository
@@ -437,12 +1005,39 @@ impl ContainerRepository for SqliteContainerRepository {
         // Allocate port
         let port = self.allocate_port().await?;
 
+        // 추가 포트: 지정한 호스트 포트는 예약, 없으면 범위에서 할당 (실패하면 이미 잡은 포트 반납)
+        let mut extra_ports = Vec::with_capacity(container.extra_ports.len());
+        for mapping in &container.extra_ports {
+            let host_port = match mapping.host_port {
+                Some(host_port) => self.reserve_port(host_port).await.and_then(|reserved| {
+                    if reserved { Ok(host_port) } else { Err(anyhow::anyhow!("Host port {} is already allocated", host_port)) }
+                }),
+                None => self.allocate_port().await,
+            };
+            match host_port {
+                Ok(host_port) => extra_ports.push(PortMapping {
+                    host_port,
+                    container_port: mapping.container_port,
+                    protocol: mapping.protocol,
+                }),
+                Err(e) => {
+                    for allocated in std::iter::once(port).chain(extra_ports.iter().map(|m| m.host_port)) {
+                        self.release_port(allocated).await.ok();
+                    }
+                    return Err(e);
+                }
+            }
+        }
+        let extra_ports_json = if extra_ports.is_empty() { None } else { Some(serde_json::to_string(&extra_ports)?) };
+        let entrypoint_json = container.entrypoint.as_ref().map(serde_json::to_string).transpose()?;
+        let args_json = container.args.as_ref().map(serde_json::to_string).transpose()?;
+
         let persist_data_i64 = if container.persist_data { 1 } else { 0 };
 
         let result = sqlx::query(
             r#"
-            INSERT INTO containers (name, port, container_port, image, env_vars, command, persist_data, protocol_type, status)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, 'stopped')
+            INSERT INTO containers (name, port, container_port, image, env_vars, command, persist_data, protocol_type, health_check, extra_ports, entrypoint, args, restart_policy, restart_max_retries, network_group, status)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 'stopped')
             "#
         )
         .bind(&container.name)
@@ -453,6 +1048,13 @@ impl ContainerRepository for SqliteContainerRepository {
         .bind(&container.command)
         .bind(persist_data_i64)
         .bind(container.protocol_type.to_string())
+        .bind(&container.health_check)
+        .bind(&extra_ports_json)
+        .bind(&entrypoint_json)
+        .bind(&args_json)
+        .bind(container.restart.policy.to_string())
+        .bind(container.restart.max_retries)
+        .bind(&container.network_group)
         .execute(&self.pool)
         .await?;
 
@@ -518,6 +1120,45 @@ impl ContainerRepository for SqliteContainerRepository {
         Ok(())
     }
 
+    async fn update_health_check(&self, id: i64, health_check: Option<String>) -> Result<()> {
+        // 설정이 바뀌면 이전 결과는 의미가 없으므로 함께 초기화
+        sqlx::query("UPDATE containers SET health_check = ?, health_status = NULL, health_checked_at = NULL WHERE id = ?")
+            .bind(health_check)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_health_status(&self, id: i64, health: Option<ContainerHealth>) -> Result<()> {
+        sqlx::query("UPDATE containers SET health_status = ?, health_checked_at = datetime('now') WHERE id = ?")
+            .bind(health.map(|h| h.to_string()))
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_log_retention(&self, id: i64, max_size_mb: Option<i64>, retention_days: Option<i64>) -> Result<()> {
+        sqlx::query("UPDATE containers SET log_max_size_mb = ?, log_retention_days = ? WHERE id = ?")
+            .bind(max_size_mb)
+            .bind(retention_days)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn update_restart_policy(&self, id: i64, restart: RestartConfig) -> Result<()> {
+        sqlx::query("UPDATE containers SET restart_policy = ?, restart_max_retries = ? WHERE id = ?")
+            .bind(restart.policy.to_string())
+            .bind(restart.max_retries)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
     async fn delete(&self, id: i64) -> Result<()> {
         // Get port before deleting
         let container = self.get(id).await?;
@@ -529,15 +1170,17 @@ impl ContainerRepository for SqliteContainerRepository {
                 .execute(&self.pool)
                 .await?;
 
-            // Release port
-            self.release_port(c.port).await?;
+            // Release ports
+            for port in c.host_ports() {
+                self.release_port(port).await?;
+            }
         }
 
         Ok(())
     }
 
     async fn allocate_port(&self) -> Result<i32> {
-        let now = chrono::Local::now().to_rfc3339();
+        let now = chrono::Utc::now().to_rfc3339();
 
         // Get all unavailable ports (allocated or used by system)
         let unavailable_ports: Vec<i32> = sqlx::query_scalar(
@@ -573,6 +1216,21 @@ impl ContainerRepository for SqliteContainerRepository {
         anyhow::bail!("No available ports in Container range (15000-19999)")
     }
 
+    async fn reserve_port(&self, port: i32) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO port_allocations
+            (port, port_type, status, owner_type, last_checked_at)
+            VALUES (?, 'container', 'allocated', 'container', ?)
+            "#
+        )
+        .bind(port)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
     async fn release_port(&self, port: i32) -> Result<()> {
         sqlx::query(
             "DELETE FROM port_allocations WHERE port = ? AND owner_type = 'container'"
@@ -603,22 +1261,31 @@ impl SqliteUserRepository {
 #[async_trait]
 impl UserRepository for SqliteUserRepository {
     async fn upsert(&self, user: CreateUser) -> Result<User> {
-        // Insert or update based on google_id
+        // Insert or update based on google_id (새 사용자 역할: 첫 사용자는 admin, 이후 developer)
+        // GitHub로만 가입한 같은 이메일 사용자는 Google 계정에 연결
         sqlx::query(
             r#"
-            INSERT INTO users (google_id, email, name, picture)
-            VALUES (?, ?, ?, ?)
+            INSERT INTO users (google_id, email, name, picture, role)
+            VALUES (?, ?, ?, ?, (SELECT CASE WHEN COUNT(*) = 0 THEN ? ELSE ? END FROM users))
             ON CONFLICT(google_id) DO UPDATE SET
                 email = excluded.email,
                 name = excluded.name,
                 picture = excluded.picture,
                 updated_at = datetime('now')
+            ON CONFLICT(email) DO UPDATE SET
+                google_id = excluded.google_id,
+                name = excluded.name,
+                picture = excluded.picture,
+                updated_at = datetime('now')
+            WHERE users.google_id LIKE 'github:%'
             "#
         )
         .bind(&user.google_id)
         .bind(&user.email)
         .bind(&user.name)
         .bind(&user.picture)
+        .bind(UserRole::Admin)
+        .bind(UserRole::Developer)
         .execute(&self.pool)
         .await?;
 
@@ -649,6 +1316,69 @@ impl UserRepository for SqliteUserRepository {
             .await?;
         Ok(user)
     }
+
+    async fn list(&self) -> Result<Vec<User>> {
+        let users = sqlx::query_as::<_, User>("SELECT * FROM users ORDER BY email")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(users)
+    }
+
+    async fn set_role(&self, id: i64, role: UserRole) -> Result<bool> {
+        let result = sqlx::query("UPDATE users SET role = ? WHERE id = ?")
+            .bind(role)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn get_by_github_id(&self, github_id: i64) -> Result<Option<User>> {
+        let user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE github_id = ?")
+            .bind(github_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(user)
+    }
+
+    async fn link_github(&self, id: i64, github: &CreateGitHubUser) -> Result<User> {
+        sqlx::query(
+            "UPDATE users SET github_id = ?, github_login = ?, github_token = ?, updated_at = datetime('now') WHERE id = ?"
+        )
+        .bind(github.github_id)
+        .bind(&github.github_login)
+        .bind(&github.github_token)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        self.get(id).await?
+            .ok_or_else(|| anyhow::anyhow!("User not found after linking GitHub"))
+    }
+
+    async fn create_from_github(&self, github: &CreateGitHubUser) -> Result<User> {
+        // google_id는 NOT NULL UNIQUE라 GitHub ID로 자리 표시 (나중에 Google 로그인하면 교체)
+        let result = sqlx::query(
+            r#"
+            INSERT INTO users (google_id, email, name, picture, role, github_id, github_login, github_token)
+            VALUES (?, ?, ?, ?, (SELECT CASE WHEN COUNT(*) = 0 THEN ? ELSE ? END FROM users), ?, ?, ?)
+            "#
+        )
+        .bind(format!("github:{}", github.github_id))
+        .bind(&github.email)
+        .bind(&github.name)
+        .bind(&github.picture)
+        .bind(UserRole::Admin)
+        .bind(UserRole::Developer)
+        .bind(github.github_id)
+        .bind(&github.github_login)
+        .bind(&github.github_token)
+        .execute(&self.pool)
+        .await?;
+
+        self.get(result.last_insert_rowid()).await?
+            .ok_or_else(|| anyhow::anyhow!("User not found after create"))
+    }
 }
 
 /// SQLite implementation of SessionRepository
@@ -730,6 +1460,14 @@ impl SessionRepository for SqliteSessionRepository {
             .await?;
         Ok(())
     }
+
+    async fn mark_mfa_verified(&self, id: &str) -> Result<()> {
+        sqlx::query("UPDATE sessions SET mfa_verified_at = datetime('now') WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -0,0 +1,109 @@
+use anyhow::Result;
+use sqlx::{FromRow, SqlitePool};
+use std::sync::Arc;
+
+use crate::infrastructure::secrets::SecretCipher;
+
+#[derive(Debug, FromRow)]
+struct UserTotpRow {
+    secret: String,
+    enabled_at: Option<String>,
+}
+
+/// 사용자 TOTP 등록 정보 (secret은 복호화된 Base32)
+#[derive(Debug, Clone)]
+pub struct UserTotp {
+    pub secret: String,
+    pub enabled: bool,
+}
+
+#[derive(Clone)]
+pub struct SqliteTotpRepository {
+    pool: SqlitePool,
+    cipher: Arc<SecretCipher>,
+}
+
+fn aad(user_id: i64) -> String {
+    format!("totp:{}", user_id)
+}
+
+impl SqliteTotpRepository {
+    pub fn new(pool: SqlitePool, cipher: Arc<SecretCipher>) -> Self {
+        Self { pool, cipher }
+    }
+
+    pub async fn get(&self, user_id: i64) -> Result<Option<UserTotp>> {
+        let row = sqlx::query_as::<_, UserTotpRow>(
+            "SELECT secret, enabled_at FROM user_totp WHERE user_id = ?"
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|row| {
+            Ok(UserTotp {
+                secret: self.cipher.decrypt(&aad(user_id), &row.secret)?,
+                enabled: row.enabled_at.is_some(),
+            })
+        })
+        .transpose()
+    }
+
+    pub async fn is_enabled(&self, user_id: i64) -> Result<bool> {
+        let enabled: Option<i64> = sqlx::query_scalar(
+            "SELECT 1 FROM user_totp WHERE user_id = ? AND enabled_at IS NOT NULL"
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(enabled.is_some())
+    }
+
+    /// 등록 시작 (이미 활성화된 경우 false, 등록 중이면 새 비밀로 교체)
+    pub async fn set_pending(&self, user_id: i64, secret: &str) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO user_totp (user_id, secret) VALUES (?, ?)
+            ON CONFLICT(user_id) DO UPDATE SET
+                secret = excluded.secret,
+                last_used_step = NULL,
+                created_at = datetime('now')
+            WHERE user_totp.enabled_at IS NULL
+            "#
+        )
+        .bind(user_id)
+        .bind(self.cipher.encrypt(&aad(user_id), secret)?)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 코드 사용 기록. 같은/이전 간격의 코드면 false (재사용)
+    pub async fn use_step(&self, user_id: i64, step: i64) -> Result<bool> {
+        let result = sqlx::query(
+            "UPDATE user_totp SET last_used_step = ? WHERE user_id = ? AND (last_used_step IS NULL OR last_used_step < ?)"
+        )
+        .bind(step)
+        .bind(user_id)
+        .bind(step)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn enable(&self, user_id: i64) -> Result<()> {
+        sqlx::query("UPDATE user_totp SET enabled_at = datetime('now') WHERE user_id = ?")
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn delete(&self, user_id: i64) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM user_totp WHERE user_id = ?")
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
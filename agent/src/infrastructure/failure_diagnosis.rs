@@ -0,0 +1,162 @@
+use anyhow::{Context, Result};
+use std::time::Duration;
+
+/// 기본으로 보내는 빌드 로그 마지막 줄 수
+const DEFAULT_LOG_LINES: usize = 200;
+
+/// 보낼 수 있는 최대 로그 줄 수
+const MAX_LOG_LINES: usize = 2000;
+
+/// LLM 응답 대기 시간
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// 저장하는 진단 최대 길이 (문자)
+pub const MAX_DIAGNOSIS_CHARS: usize = 4000;
+
+const SYSTEM_PROMPT: &str = "You are a CI/CD assistant. Given the failure summary and the last lines of a failed build log, \
+explain the most likely root cause and suggest a concrete fix. Answer in at most a few short paragraphs.";
+
+/// 빌드 실패 진단 설정 (OpenAI 호환 chat completions API)
+///
+/// - FAILURE_DIAGNOSIS_URL: API base URL (예 `http://localhost:11434/v1`, `https://api.openai.com/v1`).
+///   `{url}/chat/completions`로 POST
+/// - FAILURE_DIAGNOSIS_MODEL: 모델 이름 (필수)
+/// - FAILURE_DIAGNOSIS_API_KEY: Bearer 토큰 (선택, 로컬 서버는 보통 불필요)
+/// - FAILURE_DIAGNOSIS_LOG_LINES: 함께 보낼 빌드 로그 마지막 줄 수 (기본 200, 최대 2000)
+///
+/// 빌드 로그가 외부로 나가므로 기본 비활성화. 자체 호스팅 모델이면 로그가 서버 밖으로 나가지 않는다.
+#[derive(Debug, Clone)]
+pub struct FailureDiagnosisConfig {
+    pub url: String,
+    pub model: String,
+    pub api_key: Option<String>,
+    pub log_lines: usize,
+}
+
+impl FailureDiagnosisConfig {
+    /// 환경변수에서 로드. FAILURE_DIAGNOSIS_URL이 없으면 None (진단 비활성화)
+    pub fn from_env() -> Option<Result<Self>> {
+        let url = std::env::var("FAILURE_DIAGNOSIS_URL").ok().filter(|u| !u.is_empty())?;
+        let env = |key: &str| std::env::var(key).ok().filter(|v| !v.is_empty());
+        Some(Self::parse(
+            &url,
+            env("FAILURE_DIAGNOSIS_MODEL"),
+            env("FAILURE_DIAGNOSIS_API_KEY"),
+            env("FAILURE_DIAGNOSIS_LOG_LINES"),
+        ))
+    }
+
+    pub fn parse(url: &str, model: Option<String>, api_key: Option<String>, log_lines: Option<String>) -> Result<Self> {
+        let parsed = reqwest::Url::parse(url).context("Invalid FAILURE_DIAGNOSIS_URL")?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            anyhow::bail!("FAILURE_DIAGNOSIS_URL must use http or https");
+        }
+        let model = model.context("FAILURE_DIAGNOSIS_MODEL is required")?;
+        let log_lines = match log_lines {
+            Some(lines) => lines
+                .parse::<usize>()
+                .ok()
+                .filter(|n| (1..=MAX_LOG_LINES).contains(n))
+                .with_context(|| format!("FAILURE_DIAGNOSIS_LOG_LINES must be 1-{}", MAX_LOG_LINES))?,
+            None => DEFAULT_LOG_LINES,
+        };
+
+        Ok(Self {
+            url: url.trim_end_matches('/').to_string(),
+            model,
+            api_key,
+            log_lines,
+        })
+    }
+
+    pub fn endpoint(&self) -> String {
+        format!("{}/chat/completions", self.url)
+    }
+}
+
+/// chat completions 요청 본문
+fn request_body(config: &FailureDiagnosisConfig, project_name: &str, summary: &str, log_tail: &[&str]) -> serde_json::Value {
+    let prompt = format!(
+        "Project: {}\nFailure summary: {}\n\nLast {} log lines:\n```\n{}\n```",
+        project_name,
+        summary,
+        log_tail.len(),
+        log_tail.join("\n")
+    );
+    serde_json::json!({
+        "model": config.model,
+        "messages": [
+            {"role": "system", "content": SYSTEM_PROMPT},
+            {"role": "user", "content": prompt},
+        ],
+        "temperature": 0.2,
+    })
+}
+
+/// 응답의 첫 선택지 내용 (최대 MAX_DIAGNOSIS_CHARS)
+fn parse_response(body: &serde_json::Value) -> Option<String> {
+    let content = body["choices"][0]["message"]["content"].as_str()?.trim();
+    if content.is_empty() {
+        return None;
+    }
+    Some(content.chars().take(MAX_DIAGNOSIS_CHARS).collect())
+}
+
+/// 실패 요약과 로그 끝부분을 보내고 제안된 진단을 받음
+pub async fn diagnose(
+    client: &reqwest::Client,
+    config: &FailureDiagnosisConfig,
+    project_name: &str,
+    summary: &str,
+    log: &str,
+) -> Result<String> {
+    let lines: Vec<&str> = log.lines().collect();
+    let tail = &lines[lines.len().saturating_sub(config.log_lines)..];
+
+    let mut request = client
+        .post(config.endpoint())
+        .timeout(REQUEST_TIMEOUT)
+        .json(&request_body(config, project_name, summary, tail));
+    if let Some(api_key) = &config.api_key {
+        request = request.bearer_auth(api_key);
+    }
+
+    let response = request.send().await.context("Failed to reach diagnosis endpoint")?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        anyhow::bail!("Diagnosis endpoint returned {}: {}", status, body.chars().take(200).collect::<String>());
+    }
+    let body: serde_json::Value = response.json().await.context("Invalid diagnosis response")?;
+    parse_response(&body).context("Diagnosis response has no message content")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config = FailureDiagnosisConfig::parse("http://localhost:11434/v1/", Some("llama3".to_string()), None, None).unwrap();
+        assert_eq!(config.endpoint(), "http://localhost:11434/v1/chat/completions");
+        assert_eq!(config.log_lines, DEFAULT_LOG_LINES);
+
+        assert!(FailureDiagnosisConfig::parse("http://localhost:11434/v1", None, None, None).is_err());
+        assert!(FailureDiagnosisConfig::parse("ftp://host/v1", Some("m".to_string()), None, None).is_err());
+        assert!(FailureDiagnosisConfig::parse("https://host/v1", Some("m".to_string()), None, Some("0".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_request_and_response() {
+        let config = FailureDiagnosisConfig::parse("https://host/v1", Some("gpt-4o-mini".to_string()), None, None).unwrap();
+        let body = request_body(&config, "web", "Build failed with exit code: 1", &["npm ERR! missing script: build"]);
+        assert_eq!(body["model"], "gpt-4o-mini");
+        let prompt = body["messages"][1]["content"].as_str().unwrap();
+        assert!(prompt.contains("Failure summary: Build failed with exit code: 1"));
+        assert!(prompt.contains("npm ERR! missing script: build"));
+
+        let response = serde_json::json!({"choices": [{"message": {"role": "assistant", "content": " Add a build script. "}}]});
+        assert_eq!(parse_response(&response).as_deref(), Some("Add a build script."));
+        assert_eq!(parse_response(&serde_json::json!({"choices": []})), None);
+    }
+}
@@ -0,0 +1,343 @@
+use anyhow::{Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::process::Command;
+
+use crate::db::models::ShippedLogFile;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// 빌드 로그 원격 전송 대상
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogShippingTarget {
+    /// HTTPS collector. `{url}/{project}/{build_number}/{file}`로 PUT
+    Https { url: String, token: Option<String> },
+    /// S3 호환 스토리지 (path-style, SigV4, SSE-S3 암호화)
+    S3 {
+        endpoint: String,
+        bucket: String,
+        prefix: String,
+        region: String,
+        access_key: String,
+        secret_key: String,
+    },
+}
+
+/// 완료된 빌드 로그 원격 전송 설정
+///
+/// - LOG_SHIPPING_URL: `https://collector.example.com/ci-logs` 또는 `s3://bucket/prefix`
+/// - LOG_SHIPPING_TOKEN: HTTPS collector Bearer 토큰 (선택)
+/// - S3: AWS_ACCESS_KEY_ID, AWS_SECRET_ACCESS_KEY, LOG_SHIPPING_S3_REGION (기본 us-east-1),
+///   LOG_SHIPPING_S3_ENDPOINT (기본 `https://s3.{region}.amazonaws.com`, MinIO 등)
+///
+/// 전송은 항상 TLS (http:// 대상은 거부). 로그는 gzip으로 압축하고 SHA-256 checksum을 함께 보낸다.
+#[derive(Debug, Clone)]
+pub struct LogShippingConfig {
+    pub target: LogShippingTarget,
+}
+
+impl LogShippingConfig {
+    /// 환경변수에서 로드. LOG_SHIPPING_URL이 없으면 None (전송 비활성화)
+    pub fn from_env() -> Option<Result<Self>> {
+        let url = std::env::var("LOG_SHIPPING_URL").ok().filter(|u| !u.is_empty())?;
+        let env = |key: &str| std::env::var(key).ok().filter(|v| !v.is_empty());
+        Some(Self::parse(
+            &url,
+            env("LOG_SHIPPING_TOKEN"),
+            env("LOG_SHIPPING_S3_ENDPOINT"),
+            env("LOG_SHIPPING_S3_REGION"),
+            env("AWS_ACCESS_KEY_ID").zip(env("AWS_SECRET_ACCESS_KEY")),
+        ))
+    }
+
+    pub fn parse(
+        url: &str,
+        token: Option<String>,
+        s3_endpoint: Option<String>,
+        s3_region: Option<String>,
+        s3_credentials: Option<(String, String)>,
+    ) -> Result<Self> {
+        let parsed = reqwest::Url::parse(url).context("Invalid LOG_SHIPPING_URL")?;
+
+        let target = match parsed.scheme() {
+            "https" => LogShippingTarget::Https {
+                url: url.trim_end_matches('/').to_string(),
+                token,
+            },
+            "s3" => {
+                let bucket = parsed.host_str().context("LOG_SHIPPING_URL has no bucket")?.to_string();
+                let (access_key, secret_key) = s3_credentials
+                    .context("AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY are required for s3:// log shipping")?;
+                let region = s3_region.unwrap_or_else(|| "us-east-1".to_string());
+                let endpoint = s3_endpoint
+                    .unwrap_or_else(|| format!("https://s3.{}.amazonaws.com", region))
+                    .trim_end_matches('/')
+                    .to_string();
+                if !endpoint.starts_with("https://") {
+                    anyhow::bail!("LOG_SHIPPING_S3_ENDPOINT must use https");
+                }
+                LogShippingTarget::S3 {
+                    endpoint,
+                    bucket,
+                    prefix: parsed.path().trim_matches('/').to_string(),
+                    region,
+                    access_key,
+                    secret_key,
+                }
+            }
+            "http" => anyhow::bail!("LOG_SHIPPING_URL must use https (logs are only shipped over TLS)"),
+            other => anyhow::bail!("Unsupported log shipping scheme: {}", other),
+        };
+
+        Ok(Self { target })
+    }
+
+    /// 로그 파일의 원격 위치 (`https://...` 또는 `s3://bucket/key`)
+    pub fn location(&self, object_key: &str) -> String {
+        match &self.target {
+            LogShippingTarget::Https { url, .. } => format!("{}/{}", url, object_key),
+            LogShippingTarget::S3 { bucket, prefix, .. } => format!("s3://{}/{}", bucket, s3_key(prefix, object_key)),
+        }
+    }
+}
+
+pub fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+/// 로그 파일을 gzip으로 압축 (`gzip -n`: 파일명/시각을 넣지 않아 같은 로그는 같은 결과)
+async fn gzip_file(path: &Path) -> Result<Vec<u8>> {
+    let output = Command::new("gzip")
+        .args(["-c", "-n", "-9"])
+        .arg(path)
+        .stdin(Stdio::null())
+        .output()
+        .await
+        .context("Failed to run gzip")?;
+    if !output.status.success() {
+        anyhow::bail!("gzip failed: {}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+    Ok(output.stdout)
+}
+
+/// 로그 파일 하나를 압축해 전송. `object_key`는 `{project}/{build_number}/build.log.gz` 형식
+pub async fn ship_log_file(
+    client: &reqwest::Client,
+    config: &LogShippingConfig,
+    path: &Path,
+    object_key: &str,
+    metadata: &[(&str, String)],
+) -> Result<ShippedLogFile> {
+    let original = tokio::fs::read(path).await.with_context(|| format!("Failed to read {}", path.display()))?;
+    let compressed = gzip_file(path).await?;
+    let shipped = ShippedLogFile {
+        file: object_key.rsplit('/').next().unwrap_or(object_key).to_string(),
+        sha256: sha256_hex(&original),
+        compressed_sha256: sha256_hex(&compressed),
+        location: config.location(object_key),
+    };
+
+    let request = match &config.target {
+        LogShippingTarget::Https { url, token } => {
+            let mut request = client
+                .put(format!("{}/{}", url, object_key))
+                .header("Content-Type", "application/gzip")
+                .header("X-Checksum-Sha256", &shipped.compressed_sha256)
+                .header("X-Original-Sha256", &shipped.sha256);
+            for (name, value) in metadata {
+                request = request.header(format!("X-EasyCICD-{}", name), value);
+            }
+            if let Some(token) = token {
+                request = request.bearer_auth(token);
+            }
+            request
+        }
+        LogShippingTarget::S3 { endpoint, bucket, prefix, region, access_key, secret_key } => {
+            let endpoint_url = reqwest::Url::parse(endpoint)?;
+            let host = match (endpoint_url.host_str(), endpoint_url.port()) {
+                (Some(host), Some(port)) => format!("{}:{}", host, port),
+                (Some(host), None) => host.to_string(),
+                (None, _) => anyhow::bail!("Invalid S3 endpoint: {}", endpoint),
+            };
+            let path = format!("/{}/{}", uri_encode(bucket), uri_encode_path(&s3_key(prefix, object_key)));
+
+            let mut headers = vec![
+                ("content-type".to_string(), "application/gzip".to_string()),
+                // S3가 업로드 시 무결성 검증
+                ("x-amz-checksum-sha256".to_string(), STANDARD.encode(Sha256::digest(&compressed))),
+                ("x-amz-content-sha256".to_string(), shipped.compressed_sha256.clone()),
+                ("x-amz-date".to_string(), amz_date(Utc::now())),
+                ("x-amz-meta-original-sha256".to_string(), shipped.sha256.clone()),
+                ("x-amz-server-side-encryption".to_string(), "AES256".to_string()),
+            ];
+            for (name, value) in metadata {
+                headers.push((format!("x-amz-meta-{}", name.to_ascii_lowercase()), value.clone()));
+            }
+            let authorization = sigv4_authorization("PUT", &host, &path, &headers, region, access_key, secret_key);
+
+            let mut request = client.put(format!("{}{}", endpoint, path));
+            for (name, value) in &headers {
+                request = request.header(name.as_str(), value.as_str());
+            }
+            request.header("Authorization", authorization)
+        }
+    };
+
+    let response = request
+        .body(compressed)
+        .timeout(Duration::from_secs(60))
+        .send()
+        .await
+        .context("Failed to send log")?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        anyhow::bail!("Log upload rejected with {}: {}", status, body.chars().take(200).collect::<String>());
+    }
+
+    Ok(shipped)
+}
+
+fn s3_key(prefix: &str, object_key: &str) -> String {
+    if prefix.is_empty() {
+        object_key.to_string()
+    } else {
+        format!("{}/{}", prefix, object_key)
+    }
+}
+
+/// SigV4 URI 인코딩 (unreserved 문자 외 모두 %XX)
+fn uri_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// 경로 구분자 '/'는 남기고 세그먼트별로 인코딩
+fn uri_encode_path(path: &str) -> String {
+    path.split('/').map(uri_encode).collect::<Vec<_>>().join("/")
+}
+
+fn amz_date(now: DateTime<Utc>) -> String {
+    now.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// AWS SigV4 서명 키 (`AWS4{secret}` → 날짜 → 리전 → 서비스 → `aws4_request`)
+fn signing_key(secret_key: &str, date: &str, region: &str, service: &str) -> Vec<u8> {
+    let key = hmac_sha256(format!("AWS4{}", secret_key).as_bytes(), date);
+    let key = hmac_sha256(&key, region);
+    let key = hmac_sha256(&key, service);
+    hmac_sha256(&key, "aws4_request")
+}
+
+/// S3 요청의 SigV4 Authorization 헤더 (query string 없음, `headers`는 소문자 이름, x-amz-date/x-amz-content-sha256 포함)
+fn sigv4_authorization(
+    method: &str,
+    host: &str,
+    path: &str,
+    headers: &[(String, String)],
+    region: &str,
+    access_key: &str,
+    secret_key: &str,
+) -> String {
+    let mut signed: Vec<(String, String)> = headers.to_vec();
+    signed.push(("host".to_string(), host.to_string()));
+    signed.sort();
+
+    let amz_date = signed.iter().find(|(k, _)| k == "x-amz-date").map(|(_, v)| v.clone()).unwrap_or_default();
+    let payload_hash = signed
+        .iter()
+        .find(|(k, _)| k == "x-amz-content-sha256")
+        .map(|(_, v)| v.clone())
+        .unwrap_or_else(|| "UNSIGNED-PAYLOAD".to_string());
+    let date = &amz_date[..amz_date.len().min(8)];
+
+    let canonical_headers: String = signed.iter().map(|(k, v)| format!("{}:{}\n", k, v.trim())).collect();
+    let signed_headers = signed.iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>().join(";");
+    let canonical_request = format!(
+        "{}\n{}\n\n{}\n{}\n{}",
+        method, path, canonical_headers, signed_headers, payload_hash
+    );
+
+    let scope = format!("{}/{}/s3/aws4_request", date, region);
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        amz_date,
+        scope,
+        sha256_hex(canonical_request.as_bytes())
+    );
+    let signature = hex::encode(hmac_sha256(&signing_key(secret_key, date, region, "s3"), &string_to_sign));
+
+    format!(
+        "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+        access_key, scope, signed_headers, signature
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_https() {
+        let cfg = LogShippingConfig::parse("https://logs.example.com/ci/", Some("t".to_string()), None, None, None).unwrap();
+        assert_eq!(
+            cfg.target,
+            LogShippingTarget::Https { url: "https://logs.example.com/ci".to_string(), token: Some("t".to_string()) }
+        );
+        assert_eq!(cfg.location("app/3/build.log.gz"), "https://logs.example.com/ci/app/3/build.log.gz");
+    }
+
+    #[test]
+    fn test_parse_s3() {
+        let creds = Some(("AKID".to_string(), "secret".to_string()));
+        let cfg = LogShippingConfig::parse("s3://ci-logs/easycicd", None, None, Some("ap-northeast-2".to_string()), creds.clone()).unwrap();
+        match &cfg.target {
+            LogShippingTarget::S3 { endpoint, bucket, prefix, .. } => {
+                assert_eq!(endpoint, "https://s3.ap-northeast-2.amazonaws.com");
+                assert_eq!(bucket, "ci-logs");
+                assert_eq!(prefix, "easycicd");
+            }
+            other => panic!("unexpected target {:?}", other),
+        }
+        assert_eq!(cfg.location("app/3/build.log.gz"), "s3://ci-logs/easycicd/app/3/build.log.gz");
+
+        // 자격 증명 없음, 평문 endpoint
+        assert!(LogShippingConfig::parse("s3://ci-logs", None, None, None, None).is_err());
+        assert!(LogShippingConfig::parse("s3://ci-logs", None, Some("http://minio:9000".to_string()), None, creds).is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_plain_http() {
+        assert!(LogShippingConfig::parse("http://logs.example.com", None, None, None, None).is_err());
+        assert!(LogShippingConfig::parse("ftp://logs.example.com", None, None, None, None).is_err());
+    }
+
+    #[test]
+    fn test_uri_encode() {
+        assert_eq!(uri_encode("my app"), "my%20app");
+        assert_eq!(uri_encode_path("prefix/my app/1/build.log.gz"), "prefix/my%20app/1/build.log.gz");
+    }
+
+    #[test]
+    fn test_signing_key() {
+        // AWS SigV4 문서의 서명 키 예제
+        let key = signing_key("wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY", "20150830", "us-east-1", "iam");
+        assert_eq!(hex::encode(key), "c4afb1cc5771d871763a393e44b703571b55cc28424d1a5e86da6ed3c154a4b9");
+    }
+}
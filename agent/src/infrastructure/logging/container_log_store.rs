@@ -0,0 +1,278 @@
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::fs::{self, File, OpenOptions};
+use tokio::io::AsyncWriteExt;
+
+/// 독립 컨테이너 로그 보관 디렉토리. 컨테이너마다 `{id}/current.log`, `{id}/previous.log`
+pub const CONTAINER_LOG_DIR: &str = "/data/easycicd/logs/containers";
+
+/// 컨테이너별 설정이 없을 때 보관 한도
+pub const DEFAULT_LOG_MAX_SIZE_MB: i64 = 10;
+pub const DEFAULT_LOG_RETENTION_DAYS: i64 = 7;
+
+const CURRENT_SEGMENT: &str = "current.log";
+const PREVIOUS_SEGMENT: &str = "previous.log";
+
+/// 컨테이너 하나의 로그 보관 한도
+///
+/// 용량은 세그먼트 두 개(각 절반)로 나눠 current가 차면 previous를 버리고 돌린다.
+/// 기간은 세그먼트 단위로 적용: 마지막 기록이 `max_age`보다 오래된 세그먼트를 비운다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogRetention {
+    pub max_bytes: u64,
+    pub max_age: Duration,
+}
+
+impl Default for LogRetention {
+    fn default() -> Self {
+        Self::new(None, None)
+    }
+}
+
+impl LogRetention {
+    /// 컨테이너 설정(MB, 일)으로 생성. None이면 기본값
+    pub fn new(max_size_mb: Option<i64>, retention_days: Option<i64>) -> Self {
+        let max_size_mb = max_size_mb.unwrap_or(DEFAULT_LOG_MAX_SIZE_MB).max(1) as u64;
+        let retention_days = retention_days.unwrap_or(DEFAULT_LOG_RETENTION_DAYS).max(1) as u64;
+        Self {
+            max_bytes: max_size_mb * 1024 * 1024,
+            max_age: Duration::from_secs(retention_days * 24 * 60 * 60),
+        }
+    }
+
+    fn segment_bytes(&self) -> u64 {
+        (self.max_bytes / 2).max(1)
+    }
+}
+
+/// 컨테이너 로그 링 파일 저장소 (log streamer가 기록, 로그 API가 조회)
+///
+/// Docker 로그는 컨테이너를 지우면 사라지고 에이전트 재시작 후에는 tail만 볼 수 있으므로
+/// 최근 로그를 파일로 남겨 다운로드/검색에 사용한다.
+#[derive(Debug, Clone)]
+pub struct ContainerLogStore {
+    dir: PathBuf,
+}
+
+impl Default for ContainerLogStore {
+    fn default() -> Self {
+        Self::new(PathBuf::from(CONTAINER_LOG_DIR))
+    }
+}
+
+impl ContainerLogStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn container_dir(&self, container_id: i64) -> PathBuf {
+        self.dir.join(container_id.to_string())
+    }
+
+    /// 기록용 writer (current 세그먼트 뒤에 이어 씀)
+    pub async fn writer(&self, container_id: i64, retention: &LogRetention) -> Result<ContainerLogWriter> {
+        let dir = self.container_dir(container_id);
+        fs::create_dir_all(&dir).await?;
+        let (file, size) = open_segment(&dir.join(CURRENT_SEGMENT)).await?;
+        Ok(ContainerLogWriter {
+            dir,
+            file,
+            size,
+            max_segment_bytes: retention.segment_bytes(),
+        })
+    }
+
+    /// 마지막 기록이 `max_age`보다 오래된 세그먼트를 비움. 비운 bytes 반환
+    pub async fn enforce_age(&self, container_id: i64, max_age: Duration) -> Result<u64> {
+        let dir = self.container_dir(container_id);
+        let Some(cutoff) = SystemTime::now().checked_sub(max_age) else {
+            return Ok(0);
+        };
+
+        let mut freed = 0;
+        for segment in [PREVIOUS_SEGMENT, CURRENT_SEGMENT] {
+            let path = dir.join(segment);
+            let Ok(meta) = fs::metadata(&path).await else { continue };
+            if meta.len() > 0 && meta.modified()? < cutoff {
+                freed += meta.len();
+                clear_segment(&path, segment).await?;
+            }
+        }
+        Ok(freed)
+    }
+
+    /// 보관된 로그 전체 삭제 (스트리밍 중이면 이후 로그부터 다시 쌓임). 비운 bytes 반환
+    pub async fn purge(&self, container_id: i64) -> Result<u64> {
+        let dir = self.container_dir(container_id);
+        let mut freed = 0;
+        for segment in [PREVIOUS_SEGMENT, CURRENT_SEGMENT] {
+            let path = dir.join(segment);
+            let Ok(meta) = fs::metadata(&path).await else { continue };
+            freed += meta.len();
+            clear_segment(&path, segment).await?;
+        }
+        Ok(freed)
+    }
+
+    /// 마지막으로 기록한 시각 (unix 초). 스트림 재연결 시 이후 로그만 받는 데 사용
+    pub async fn last_written(&self, container_id: i64) -> Option<i64> {
+        let meta = fs::metadata(self.container_dir(container_id).join(CURRENT_SEGMENT)).await.ok()?;
+        let modified = meta.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+        Some(modified.as_secs() as i64)
+    }
+
+    /// 보관된 로그 전체 (오래된 순)
+    pub async fn read_lines(&self, container_id: i64) -> Result<Vec<String>> {
+        let dir = self.container_dir(container_id);
+        let mut lines = Vec::new();
+        for segment in [PREVIOUS_SEGMENT, CURRENT_SEGMENT] {
+            match fs::read(dir.join(segment)).await {
+                Ok(bytes) => lines.extend(String::from_utf8_lossy(&bytes).lines().map(str::to_string)),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(lines)
+    }
+
+    /// 더 이상 없는 컨테이너의 로그 삭제. 삭제한 디렉토리 수 반환
+    pub async fn prune(&self, existing_ids: &[i64]) -> Result<usize> {
+        let mut entries = match fs::read_dir(&self.dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut removed = 0;
+        while let Some(entry) = entries.next_entry().await? {
+            let Some(id) = entry.file_name().to_str().and_then(|n| n.parse::<i64>().ok()) else {
+                continue;
+            };
+            if !existing_ids.contains(&id) {
+                fs::remove_dir_all(entry.path()).await?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+}
+
+/// previous는 삭제, current는 writer가 열고 있을 수 있으므로 지우지 않고 비움 (append 모드라 이어 쓰기 안전)
+async fn clear_segment(path: &Path, segment: &str) -> Result<()> {
+    if segment == CURRENT_SEGMENT {
+        OpenOptions::new().write(true).open(path).await?.set_len(0).await?;
+    } else {
+        fs::remove_file(path).await?;
+    }
+    Ok(())
+}
+
+async fn open_segment(path: &Path) -> Result<(File, u64)> {
+    let file = OpenOptions::new().create(true).append(true).open(path).await?;
+    let size = file.metadata().await?.len();
+    Ok((file, size))
+}
+
+/// 컨테이너 하나의 로그 기록기. current가 가득 차면 previous로 돌리고 새로 시작
+pub struct ContainerLogWriter {
+    dir: PathBuf,
+    file: File,
+    size: u64,
+    max_segment_bytes: u64,
+}
+
+impl ContainerLogWriter {
+    pub async fn write_line(&mut self, line: &str) -> Result<()> {
+        let line = line.trim_end_matches('\n');
+        let len = line.len() as u64 + 1;
+
+        if self.size > 0 && self.size + len > self.max_segment_bytes {
+            self.rotate().await?;
+        }
+
+        self.file.write_all(line.as_bytes()).await?;
+        self.file.write_all(b"\n").await?;
+        // 조회 API가 바로 읽을 수 있도록 줄마다 flush
+        self.file.flush().await?;
+        self.size += len;
+        Ok(())
+    }
+
+    async fn rotate(&mut self) -> Result<()> {
+        self.file.flush().await?;
+        fs::rename(self.dir.join(CURRENT_SEGMENT), self.dir.join(PREVIOUS_SEGMENT)).await?;
+        let (file, size) = open_segment(&self.dir.join(CURRENT_SEGMENT)).await?;
+        self.file = file;
+        self.size = size;
+        Ok(())
+    }
+}
+
+/// 대소문자 구분 없이 `query`를 포함한 줄만 남김 (빈 검색어면 전체)
+pub fn filter_lines(lines: Vec<String>, query: Option<&str>) -> Vec<String> {
+    let Some(query) = query.map(str::trim).filter(|q| !q.is_empty()) else {
+        return lines;
+    };
+    let query = query.to_lowercase();
+    lines
+        .into_iter()
+        .filter(|line| line.to_lowercase().contains(&query))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rotation_keeps_previous_segment() {
+        let dir = std::env::temp_dir().join(format!("easycicd-container-logs-{}", std::process::id()));
+        let store = ContainerLogStore::new(dir.clone());
+        let retention = LogRetention { max_bytes: 32, max_age: Duration::from_secs(3600) };
+
+        let mut writer = store.writer(1, &retention).await.unwrap();
+        for line in ["line-1", "line-2", "line-3", "line-4"] {
+            writer.write_line(line).await.unwrap();
+        }
+        drop(writer);
+
+        // 세그먼트당 두 줄: line-3에서 line-1/2가 previous로 넘어감
+        assert_eq!(store.read_lines(1).await.unwrap(), vec!["line-1", "line-2", "line-3", "line-4"]);
+
+        // 다시 열어도 이어 쓰고, 다음 회전에서 가장 오래된 세그먼트가 밀려남
+        let mut writer = store.writer(1, &retention).await.unwrap();
+        writer.write_line("line-5\n").await.unwrap();
+        assert_eq!(store.read_lines(1).await.unwrap(), vec!["line-3", "line-4", "line-5"]);
+        assert_eq!(store.enforce_age(1, retention.max_age).await.unwrap(), 0);
+
+        // 비운 뒤에도 열려 있던 writer로 계속 기록
+        assert_eq!(store.purge(1).await.unwrap(), 21);
+        assert!(store.read_lines(1).await.unwrap().is_empty());
+        writer.write_line("line-6").await.unwrap();
+        drop(writer);
+        assert_eq!(store.read_lines(1).await.unwrap(), vec!["line-6"]);
+
+        assert_eq!(store.prune(&[2]).await.unwrap(), 1);
+        assert!(store.read_lines(1).await.unwrap().is_empty());
+
+        fs::remove_dir_all(&dir).await.unwrap();
+    }
+
+    #[test]
+    fn test_retention_defaults() {
+        let retention = LogRetention::default();
+        assert_eq!(retention.max_bytes, 10 * 1024 * 1024);
+        assert_eq!(retention.max_age, Duration::from_secs(7 * 24 * 60 * 60));
+        assert_eq!(LogRetention::new(Some(1), Some(1)).segment_bytes(), 512 * 1024);
+    }
+
+    #[test]
+    fn test_filter_lines() {
+        let lines = vec!["INFO started".to_string(), "ERROR failed".to_string(), "info done".to_string()];
+
+        assert_eq!(filter_lines(lines.clone(), Some("info")), vec!["INFO started", "info done"]);
+        assert_eq!(filter_lines(lines.clone(), Some("  ")), lines);
+        assert_eq!(filter_lines(lines.clone(), None), lines);
+    }
+}
@@ -1,5 +1,7 @@
 pub mod boundary_logger;
+pub mod container_log_store;
 pub mod trace_context;
 
 pub use boundary_logger::{BoundaryLogger, Timer};
+pub use container_log_store::ContainerLogStore;
 pub use trace_context::TraceContext;
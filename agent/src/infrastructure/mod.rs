@@ -1,4 +1,12 @@
 pub mod logging;
+pub mod acme;
 pub mod database;
+pub mod event_sink;
+pub mod failure_diagnosis;
+pub mod log_shipping;
+pub mod timezone;
 pub mod docker;
 pub mod notifications;
+pub mod plugins;
+pub mod secrets;
+pub mod totp;
@@ -27,6 +27,43 @@ pub struct DiscordMessage {
     pub avatar_url: Option<String>,
 }
 
+impl DiscordMessage {
+    /// 빌드 메모/라벨을 첫 embed 필드로 추가 (둘 다 없으면 그대로)
+    pub fn with_annotation(mut self, note: Option<&str>, labels: &[String]) -> Self {
+        if let Some(embed) = self.embeds.as_mut().and_then(|e| e.first_mut()) {
+            let fields = embed.fields.get_or_insert_with(Vec::new);
+            if let Some(note) = note {
+                // Discord embed 필드 값은 최대 1024자
+                fields.push(EmbedField {
+                    name: "메모".to_string(),
+                    value: note.chars().take(1024).collect(),
+                    inline: Some(false),
+                });
+            }
+            if !labels.is_empty() {
+                fields.push(EmbedField {
+                    name: "라벨".to_string(),
+                    value: labels.iter().map(|l| format!("`{}`", l)).collect::<Vec<_>>().join(" "),
+                    inline: Some(false),
+                });
+            }
+        }
+        self
+    }
+
+    /// 프로젝트 노트(런북) 링크를 embed 필드로 추가 (노트가 없으면 그대로)
+    pub fn with_notes_link(mut self, notes_url: Option<&str>) -> Self {
+        if let (Some(url), Some(embed)) = (notes_url, self.embeds.as_mut().and_then(|e| e.first_mut())) {
+            embed.fields.get_or_insert_with(Vec::new).push(EmbedField {
+                name: "런북".to_string(),
+                value: format!("[프로젝트 노트 보기]({})", url),
+                inline: Some(false),
+            });
+        }
+        self
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct DiscordEmbed {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -118,6 +155,16 @@ impl DiscordClient {
         Ok(())
     }
 
+    /// 연결 테스트 메시지 (설정 확인용)
+    pub fn test_message(&self, label: &str) -> DiscordMessage {
+        DiscordMessage {
+            content: Some(format!("✅ Easy CI/CD 연결 테스트: 웹훅 **{}**이(가) 정상적으로 설정되었습니다.", label)),
+            embeds: None,
+            username: Some("Easy CI/CD".to_string()),
+            avatar_url: None,
+        }
+    }
+
     /// 빌드 시작 알림
     pub fn build_started_message(
         &self,
@@ -126,6 +173,7 @@ impl DiscordClient {
         branch: &str,
         commit_hash: &str,
         author: Option<&str>,
+        triggered_by: Option<&str>,
     ) -> DiscordMessage {
         let embed = DiscordEmbed {
             title: Some(format!("🔨 빌드 #{} 시작", build_number)),
@@ -147,6 +195,11 @@ impl DiscordClient {
                     value: author.unwrap_or("Unknown").to_string(),
                     inline: Some(true),
                 },
+                EmbedField {
+                    name: "트리거".to_string(),
+                    value: triggered_by.unwrap_or("Unknown").to_string(),
+                    inline: Some(true),
+                },
             ]),
             timestamp: Some(chrono::Utc::now().to_rfc3339()),
             footer: Some(EmbedFooter {
@@ -315,6 +368,42 @@ impl DiscordClient {
         }
     }
 
+    /// 프로젝트 경고 알림 (빌드와 무관한 오류, 예: 디스크 쿼터 초과)
+    pub fn project_alert_message(
+        &self,
+        project_name: &str,
+        message: &str,
+        mentions: Vec<String>,
+    ) -> DiscordMessage {
+        let embed = DiscordEmbed {
+            title: Some("⚠️ 프로젝트 경고".to_string()),
+            description: Some(format!("프로젝트 **{}**", project_name)),
+            color: Some(EmbedColor::WARNING),
+            fields: Some(vec![EmbedField {
+                name: "내용".to_string(),
+                value: format!("`{}`", message),
+                inline: Some(false),
+            }]),
+            timestamp: Some(chrono::Utc::now().to_rfc3339()),
+            footer: Some(EmbedFooter {
+                text: "Easy CI/CD".to_string(),
+                icon_url: None,
+            }),
+            author: None,
+        };
+
+        DiscordMessage {
+            content: if mentions.is_empty() {
+                None
+            } else {
+                Some(mentions.join(" "))
+            },
+            embeds: Some(vec![embed]),
+            username: Some("Easy CI/CD".to_string()),
+            avatar_url: None,
+        }
+    }
+
     /// 배포 실패 알림
     pub fn deployment_failure_message(
         &self,
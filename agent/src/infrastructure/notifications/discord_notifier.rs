@@ -5,8 +5,9 @@ use tracing::{debug, error, info, warn};
 
 use crate::application::events::Event;
 use crate::application::ports::repositories::{BuildRepository, ProjectRepository};
-use crate::db::models::BuildStatus;
+use crate::db::models::{BuildStatus, Project};
 use crate::infrastructure::notifications::DiscordClient;
+use crate::infrastructure::timezone;
 use crate::infrastructure::database::{SqliteBuildRepository, SqliteProjectRepository};
 
 /// Discord 알림 설정
@@ -93,6 +94,11 @@ pub async fn run_discord_notifier(
     Ok(())
 }
 
+/// 프로젝트 노트 페이지 링크 (노트가 있는 경우만)
+fn notes_url(base_url: &str, project: &Project) -> Option<String> {
+    project.notes.as_ref().map(|_| format!("{}/projects/{}/notes", base_url, project.id))
+}
+
 async fn handle_event(
     client: &DiscordClient,
     event: &Event,
@@ -144,6 +150,7 @@ async fn handle_event(
             }
 
             let build_url = format!("{}/builds/{}", base_url, build_id);
+            let notes_url = notes_url(base_url, &project);
 
             match status {
                 BuildStatus::Building => {
@@ -154,7 +161,9 @@ async fn handle_event(
                             &project.branch,
                             &build.commit_hash,
                             build.author.as_deref(),
-                        );
+                            build.triggered_by.as_deref(),
+                        )
+                        .with_annotation(build.note.as_deref(), &build.parsed_labels());
                         client.send_message(&config.webhook_url, message).await?;
                     }
                 }
@@ -164,9 +173,8 @@ async fn handle_event(
                             .finished_at
                             .as_deref()
                             .and_then(|finished| {
-                                let started =
-                                    chrono::DateTime::parse_from_rfc3339(&build.started_at).ok()?;
-                                let finished = chrono::DateTime::parse_from_rfc3339(finished).ok()?;
+                                let started = timezone::parse_stored(&build.started_at)?;
+                                let finished = timezone::parse_stored(finished)?;
                                 Some((finished - started).num_seconds() as u64)
                             })
                             .unwrap_or(0);
@@ -177,7 +185,8 @@ async fn handle_event(
                             &project.branch,
                             duration,
                             Some(&build_url),
-                        );
+                        )
+                        .with_annotation(build.note.as_deref(), &build.parsed_labels());
                         client.send_message(&config.webhook_url, message).await?;
                     }
                 }
@@ -188,10 +197,12 @@ async fn handle_event(
                             &project.name,
                             build.build_number,
                             &project.branch,
-                            Some("빌드 실패 - 로그를 확인하세요"),
+                            Some(build.failure_summary.as_deref().unwrap_or("빌드 실패 - 로그를 확인하세요")),
                             Some(&build_url),
                             mentions,
-                        );
+                        )
+                        .with_annotation(build.note.as_deref(), &build.parsed_labels())
+                        .with_notes_link(notes_url.as_deref());
                         client.send_message(&config.webhook_url, message).await?;
                     }
                 }
@@ -256,7 +267,8 @@ async fn handle_event(
                             build.build_number,
                             &slot.to_string(),
                             Some(url),
-                        );
+                        )
+                        .with_annotation(build.note.as_deref(), &build.parsed_labels());
                         client.send_message(&config.webhook_url, message).await?;
                     }
                 }
@@ -273,7 +285,9 @@ async fn handle_event(
                             build.build_number,
                             Some("배포 실패 - 로그를 확인하세요"),
                             mentions,
-                        );
+                        )
+                        .with_annotation(build.note.as_deref(), &build.parsed_labels())
+                        .with_notes_link(notes_url(base_url, &project).as_deref());
                         client.send_message(&config.webhook_url, message).await?;
                     }
                 }
@@ -281,6 +295,72 @@ async fn handle_event(
             }
         },
 
+        // 빌드와 무관한 프로젝트 오류 (빌드 오류는 BuildStatus::Failed로 알림)
+        Event::Error {
+            build_id: None,
+            project_id: Some(project_id),
+            message,
+            ..
+        } => {
+            let project = project_repo
+                .get(*project_id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("Project not found"))?;
+
+            let webhook_id = match project.discord_webhook_id {
+                Some(id) => id,
+                None => return Ok(()),
+            };
+
+            let config = match webhook_repo.get(webhook_id).await? {
+                Some(c) if c.enabled => c,
+                _ => return Ok(()),
+            };
+
+            if config.notify_on_build_failure {
+                let mentions = config.get_mentions(true);
+                let message = client
+                    .project_alert_message(&project.name, message, mentions)
+                    .with_notes_link(notes_url(base_url, &project).as_deref());
+                client.send_message(&config.webhook_url, message).await?;
+            }
+        }
+
+        Event::QueueWaitExceeded {
+            build_id,
+            project_id,
+            wait_secs,
+            queue_depth,
+            ..
+        } => {
+            let project = project_repo
+                .get(*project_id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("Project not found"))?;
+
+            let webhook_id = match project.discord_webhook_id {
+                Some(id) => id,
+                None => return Ok(()),
+            };
+
+            let config = match webhook_repo.get(webhook_id).await? {
+                Some(c) if c.enabled => c,
+                _ => return Ok(()),
+            };
+
+            if config.notify_on_build_start {
+                let build_number = build_repo.get(*build_id).await?.map(|b| b.build_number).unwrap_or(*build_id);
+                let message = format!(
+                    "빌드 #{}이(가) {}분째 대기 중입니다 (대기 중인 빌드 {}개). 빌드 동시 실행 수를 늘리는 것을 고려하세요",
+                    build_number,
+                    wait_secs / 60,
+                    queue_depth
+                );
+                let message = client.project_alert_message(&project.name, &message, config.get_mentions(false));
+                client.send_message(&config.webhook_url, message).await?;
+            }
+        }
+
         _ => {
             // 다른 이벤트는 무시
         }
@@ -0,0 +1,298 @@
+use anyhow::{Context, Result};
+use lettre::message::{header::ContentType, Mailbox};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tracing::debug;
+
+use crate::application::ports::repositories::SettingsRepository;
+
+/// SMTP 설정 키 (JSON, see SmtpSettings)
+pub const SMTP_SETTING: &str = "smtp";
+
+/// 프로젝트당 시간당 최대 메일 수 기본값
+pub const DEFAULT_MAX_EMAILS_PER_HOUR: u32 = 30;
+
+/// SMTP 연결/전송 대기 시간
+const SMTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// SMTP 연결 보안 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpSecurity {
+    /// 평문 연결 후 STARTTLS로 업그레이드 (보통 587)
+    #[default]
+    Starttls,
+    /// 처음부터 TLS (SMTPS, 보통 465)
+    Tls,
+    /// 암호화 없음 (로컬 릴레이 전용)
+    None,
+}
+
+fn default_port() -> u16 {
+    587
+}
+
+fn default_max_emails_per_hour() -> u32 {
+    DEFAULT_MAX_EMAILS_PER_HOUR
+}
+
+/// 이메일 알림 SMTP 설정
+///
+/// ```json
+/// { "host": "smtp.example.com", "port": 587, "security": "starttls",
+///   "username": "ci", "password": "...", "from": "Easy CI/CD <ci@example.com>",
+///   "max_emails_per_hour": 30 }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmtpSettings {
+    pub host: String,
+    #[serde(default = "default_port")]
+    pub port: u16,
+    #[serde(default)]
+    pub security: SmtpSecurity,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+    pub from: String,
+    /// 프로젝트당 시간당 최대 메일 수 (넘으면 해당 시간 동안 알림 생략)
+    #[serde(default = "default_max_emails_per_hour")]
+    pub max_emails_per_hour: u32,
+}
+
+impl SmtpSettings {
+    /// 저장된 설정. 없으면 None (이메일 알림 비활성화)
+    pub async fn load(settings_repo: &impl SettingsRepository) -> Result<Option<Self>> {
+        settings_repo
+            .get(SMTP_SETTING)
+            .await?
+            .map(|json| serde_json::from_str(&json).context("Invalid smtp setting"))
+            .transpose()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.host.trim().is_empty() || self.host.contains(char::is_whitespace) {
+            return Err("host is required".to_string());
+        }
+        if self.port == 0 {
+            return Err("port must be 1-65535".to_string());
+        }
+        if self.from.parse::<Mailbox>().is_err() {
+            return Err(format!("Invalid from address: {}", self.from));
+        }
+        if self.password.is_some() && self.username.is_none() {
+            return Err("username is required when password is set".to_string());
+        }
+        if !(1..=1000).contains(&self.max_emails_per_hour) {
+            return Err("max_emails_per_hour must be 1-1000".to_string());
+        }
+        Ok(())
+    }
+
+    /// SMTP 서버 연결/인증 확인 (메일은 보내지 않음)
+    pub async fn test_connection(&self) -> Result<()> {
+        if !self.transport()?.test_connection().await.context("SMTP connection failed")? {
+            anyhow::bail!("SMTP server did not accept the connection");
+        }
+        Ok(())
+    }
+
+    fn transport(&self) -> Result<AsyncSmtpTransport<Tokio1Executor>> {
+        let builder = match self.security {
+            SmtpSecurity::Starttls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&self.host)?,
+            SmtpSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&self.host)?,
+            SmtpSecurity::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&self.host),
+        };
+        let mut builder = builder.port(self.port).timeout(Some(SMTP_TIMEOUT));
+        if let Some(username) = &self.username {
+            builder = builder.credentials(Credentials::new(username.clone(), self.password.clone().unwrap_or_default()));
+        }
+        Ok(builder.build())
+    }
+}
+
+/// 제목 + HTML 본문
+#[derive(Debug, Clone)]
+pub struct EmailContent {
+    pub subject: String,
+    pub html: String,
+}
+
+/// HTML 특수 문자 이스케이프
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// 공통 레이아웃: 색 띠가 있는 제목, 항목 표, 링크 버튼. 값은 이스케이프해서 넣는다
+fn render(title: &str, color: &str, intro: &str, rows: &[(&str, String)], links: &[(&str, &str)]) -> String {
+    let rows: String = rows
+        .iter()
+        .map(|(name, value)| {
+            format!(
+                r#"<tr><td style="padding:4px 12px 4px 0;color:#666;white-space:nowrap;vertical-align:top">{}</td><td style="padding:4px 0"><code>{}</code></td></tr>"#,
+                escape(name),
+                escape(value)
+            )
+        })
+        .collect();
+    let links: String = links
+        .iter()
+        .map(|(text, url)| {
+            format!(
+                r#"<a href="{}" style="display:inline-block;margin-right:8px;padding:8px 14px;background:#333;color:#fff;text-decoration:none;border-radius:4px">{}</a>"#,
+                escape(url),
+                escape(text)
+            )
+        })
+        .collect();
+
+    format!(
+        r#"<!DOCTYPE html>
+<html><body style="margin:0;padding:24px;background:#f5f5f5;font-family:-apple-system,Segoe UI,sans-serif;font-size:14px;color:#222">
+<div style="max-width:640px;margin:0 auto;background:#fff;border-top:4px solid {color};border-radius:4px;padding:20px">
+<h2 style="margin:0 0 12px">{title}</h2>
+<p style="margin:0 0 16px">{intro}</p>
+<table style="border-collapse:collapse;margin-bottom:16px">{rows}</table>
+<div>{links}</div>
+<p style="margin:24px 0 0;color:#999;font-size:12px">Easy CI/CD</p>
+</div>
+</body></html>"#,
+        color = color,
+        title = escape(title),
+        intro = escape(intro),
+        rows = rows,
+        links = links,
+    )
+}
+
+/// 연결 테스트 메일
+pub fn test_email(host: &str) -> EmailContent {
+    let title = "✅ Easy CI/CD 메일 테스트";
+    EmailContent {
+        subject: title.to_string(),
+        html: render(title, "#2e7d32", "SMTP 설정이 정상적으로 동작합니다.", &[("SMTP 서버", host.to_string())], &[]),
+    }
+}
+
+/// 빌드 실패 메일
+pub fn build_failure_email(
+    project_name: &str,
+    build_number: i64,
+    branch: &str,
+    commit_hash: &str,
+    failure_summary: Option<&str>,
+    build_url: &str,
+    notes_url: Option<&str>,
+) -> EmailContent {
+    let title = format!("❌ 빌드 #{} 실패", build_number);
+    let mut links = vec![("빌드 로그 보기", build_url)];
+    links.extend(notes_url.map(|url| ("런북 보기", url)));
+
+    EmailContent {
+        subject: format!("[{}] {}", project_name, title),
+        html: render(
+            &title,
+            "#d32f2f",
+            &format!("프로젝트 {}의 빌드가 실패했습니다.", project_name),
+            &[
+                ("브랜치", branch.to_string()),
+                ("커밋", commit_hash.chars().take(7).collect()),
+                ("에러", failure_summary.unwrap_or("로그를 확인해주세요").to_string()),
+            ],
+            &links,
+        ),
+    }
+}
+
+/// 배포 결과 메일 (성공/실패)
+pub fn deployment_email(
+    project_name: &str,
+    build_number: i64,
+    slot: &str,
+    success: bool,
+    app_url: &str,
+    build_url: &str,
+    notes_url: Option<&str>,
+) -> EmailContent {
+    let (title, color, intro) = if success {
+        (format!("🚀 배포 완료 (빌드 #{})", build_number), "#2e7d32", format!("프로젝트 {}가 성공적으로 배포되었습니다.", project_name))
+    } else {
+        (format!("🔥 배포 실패 (빌드 #{})", build_number), "#d32f2f", format!("프로젝트 {}의 배포가 실패했습니다.", project_name))
+    };
+    let mut links = Vec::new();
+    if success {
+        links.push(("앱 열기", app_url));
+    }
+    links.push(("빌드 보기", build_url));
+    if !success {
+        links.extend(notes_url.map(|url| ("런북 보기", url)));
+    }
+
+    EmailContent {
+        subject: format!("[{}] {}", project_name, title),
+        html: render(&title, color, &intro, &[("슬롯", slot.to_string())], &links),
+    }
+}
+
+/// 수신자 전원에게 한 통으로 전송
+pub async fn send_email(settings: &SmtpSettings, recipients: &[String], content: EmailContent) -> Result<()> {
+    let mut builder = Message::builder()
+        .from(settings.from.parse::<Mailbox>().context("Invalid from address")?)
+        .subject(content.subject)
+        .header(ContentType::TEXT_HTML);
+    for recipient in recipients {
+        builder = builder.to(recipient.parse::<Mailbox>().with_context(|| format!("Invalid recipient: {}", recipient))?);
+    }
+    let message = builder.body(content.html).context("Failed to build email")?;
+
+    debug!("Sending email to {} recipient(s) via {}:{}", recipients.len(), settings.host, settings.port);
+    settings.transport()?.send(message).await.context("SMTP send failed")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failure_email_escapes_content() {
+        let email = build_failure_email(
+            "web",
+            12,
+            "main",
+            "0123456789abcdef",
+            Some("error: expected `<T>` & found `()`"),
+            "http://ci/builds/3",
+            Some("http://ci/projects/1/notes"),
+        );
+        assert_eq!(email.subject, "[web] ❌ 빌드 #12 실패");
+        assert!(email.html.contains("<code>0123456</code>"));
+        assert!(email.html.contains("error: expected `&lt;T&gt;` &amp; found `()`"));
+        assert!(email.html.contains(r#"href="http://ci/projects/1/notes""#));
+
+        let deployed = deployment_email("web", 12, "Blue", true, "http://web.example.com", "http://ci/builds/3", Some("http://ci/projects/1/notes"));
+        assert!(deployed.html.contains("앱 열기"));
+        assert!(!deployed.html.contains("런북 보기"));
+    }
+
+    #[test]
+    fn test_validate_settings() {
+        let settings: SmtpSettings = serde_json::from_str(r#"{"host": "smtp.example.com", "from": "CI <ci@example.com>"}"#).unwrap();
+        assert_eq!(settings.port, 587);
+        assert_eq!(settings.security, SmtpSecurity::Starttls);
+        assert!(settings.validate().is_ok());
+
+        let invalid = SmtpSettings { from: "not an address".to_string(), ..settings.clone() };
+        assert!(invalid.validate().is_err());
+        let invalid = SmtpSettings { password: Some("secret".to_string()), ..settings.clone() };
+        assert!(invalid.validate().is_err());
+        let invalid = SmtpSettings { max_emails_per_hour: 0, ..settings };
+        assert!(invalid.validate().is_err());
+    }
+}
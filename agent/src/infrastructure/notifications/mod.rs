@@ -1,5 +1,10 @@
 pub mod discord_client;
 pub mod discord_notifier;
+pub mod email_client;
+pub mod slack_client;
+pub mod slack_notifier;
 
 pub use discord_client::{DiscordClient, DiscordMessage, DiscordEmbed, EmbedColor};
 pub use discord_notifier::{run_discord_notifier, DiscordWebhookConfig};
+pub use slack_client::SlackClient;
+pub use slack_notifier::SlackWebhookConfig;
@@ -0,0 +1,350 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+use tracing::{debug, warn};
+
+/// Slack section 텍스트 최대 길이 (Block Kit 제한 3000자)
+const MAX_SECTION_CHARS: usize = 3000;
+
+/// Slack section 필드 텍스트 최대 길이 (Block Kit 제한 2000자)
+const MAX_FIELD_CHARS: usize = 2000;
+
+/// Slack incoming webhook 메시지 (Block Kit)
+///
+/// `text`는 모바일 푸시/스크린 리더에 쓰이는 대체 텍스트
+#[derive(Debug, Serialize)]
+pub struct SlackMessage {
+    pub text: String,
+    pub blocks: Vec<SlackBlock>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SlackBlock {
+    Header {
+        text: SlackText,
+    },
+    Section {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        text: Option<SlackText>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        fields: Option<Vec<SlackText>>,
+    },
+    Context {
+        elements: Vec<SlackText>,
+    },
+    Actions {
+        elements: Vec<SlackButton>,
+    },
+}
+
+#[derive(Debug, Serialize)]
+pub struct SlackText {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub text: String,
+}
+
+impl SlackText {
+    pub fn plain(text: impl Into<String>) -> Self {
+        Self { kind: "plain_text", text: text.into() }
+    }
+
+    pub fn mrkdwn(text: impl Into<String>) -> Self {
+        Self { kind: "mrkdwn", text: text.into() }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SlackButton {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub text: SlackText,
+    pub url: String,
+}
+
+impl SlackButton {
+    pub fn link(text: &str, url: &str) -> Self {
+        Self { kind: "button", text: SlackText::plain(text), url: url.to_string() }
+    }
+}
+
+/// mrkdwn 제어 문자 이스케이프 (사용자 입력을 그대로 넣으면 링크/멘션으로 해석됨)
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(max - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// `*이름*\n값` 형태의 section 필드
+fn field(name: &str, value: &str) -> SlackText {
+    SlackText::mrkdwn(truncate(&format!("*{}*\n{}", name, value), MAX_FIELD_CHARS))
+}
+
+impl SlackMessage {
+    /// 헤더 + 설명 section + 필드 section + footer로 구성된 기본 메시지
+    fn new(header: String, description: String, fields: Vec<SlackText>, mentions: Vec<String>) -> Self {
+        let description = if mentions.is_empty() {
+            description
+        } else {
+            format!("{} {}", mentions.join(" "), description)
+        };
+
+        let mut blocks = vec![
+            SlackBlock::Header { text: SlackText::plain(header.clone()) },
+            SlackBlock::Section {
+                text: Some(SlackText::mrkdwn(truncate(&description, MAX_SECTION_CHARS))),
+                fields: None,
+            },
+        ];
+        if !fields.is_empty() {
+            blocks.push(SlackBlock::Section { text: None, fields: Some(fields) });
+        }
+        blocks.push(SlackBlock::Context {
+            elements: vec![SlackText::mrkdwn(format!("Easy CI/CD · {}", chrono::Utc::now().format("%Y-%m-%d %H:%M UTC")))],
+        });
+
+        Self { text: header, blocks }
+    }
+
+    /// 링크 버튼 추가 (actions 블록이 없으면 footer 앞에 생성)
+    pub fn with_link(mut self, text: &str, url: Option<&str>) -> Self {
+        let Some(url) = url else { return self };
+        let button = SlackButton::link(text, url);
+        if let Some(SlackBlock::Actions { elements }) = self.blocks.iter_mut().find(|b| matches!(b, SlackBlock::Actions { .. })) {
+            elements.push(button);
+        } else {
+            let at = self.blocks.len().saturating_sub(1);
+            self.blocks.insert(at, SlackBlock::Actions { elements: vec![button] });
+        }
+        self
+    }
+
+    /// 빌드 메모/라벨을 section으로 추가 (둘 다 없으면 그대로)
+    pub fn with_annotation(mut self, note: Option<&str>, labels: &[String]) -> Self {
+        let mut fields = Vec::new();
+        if let Some(note) = note {
+            fields.push(field("메모", &escape(note)));
+        }
+        if !labels.is_empty() {
+            fields.push(field("라벨", &labels.iter().map(|l| format!("`{}`", escape(l))).collect::<Vec<_>>().join(" ")));
+        }
+        if !fields.is_empty() {
+            let at = self.blocks.len().saturating_sub(1);
+            self.blocks.insert(at, SlackBlock::Section { text: None, fields: Some(fields) });
+        }
+        self
+    }
+
+    /// 프로젝트 노트(런북) 링크 버튼 추가 (노트가 없으면 그대로)
+    pub fn with_notes_link(self, notes_url: Option<&str>) -> Self {
+        self.with_link("런북 보기", notes_url)
+    }
+}
+
+/// Slack 클라이언트
+#[derive(Clone)]
+pub struct SlackClient {
+    client: reqwest::Client,
+}
+
+impl SlackClient {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(10))
+                .build()
+                .expect("Failed to create HTTP client"),
+        }
+    }
+
+    /// Slack incoming webhook으로 메시지 전송
+    pub async fn send_message(&self, webhook_url: &str, message: SlackMessage) -> Result<()> {
+        debug!("Sending Slack message to webhook");
+
+        let response = self
+            .client
+            .post(webhook_url)
+            .json(&message)
+            .send()
+            .await
+            .context("Failed to send Slack webhook")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            warn!("Slack webhook failed: {} - {}", status, body);
+            anyhow::bail!("Slack webhook returned {}: {}", status, body);
+        }
+
+        debug!("Slack message sent successfully");
+        Ok(())
+    }
+
+    /// 연결 테스트 메시지 (설정 확인용)
+    pub fn test_message(&self, label: &str) -> SlackMessage {
+        SlackMessage::new(
+            "✅ Easy CI/CD 연결 테스트".to_string(),
+            format!("웹훅 *{}*이(가) 정상적으로 설정되었습니다.", escape(label)),
+            Vec::new(),
+            Vec::new(),
+        )
+    }
+
+    /// 빌드 시작 알림
+    pub fn build_started_message(
+        &self,
+        project_name: &str,
+        build_number: i64,
+        branch: &str,
+        commit_hash: &str,
+        author: Option<&str>,
+        triggered_by: Option<&str>,
+    ) -> SlackMessage {
+        SlackMessage::new(
+            format!("🔨 빌드 #{} 시작", build_number),
+            format!("프로젝트 *{}*의 빌드가 시작되었습니다.", escape(project_name)),
+            vec![
+                field("브랜치", &format!("`{}`", escape(branch))),
+                field("커밋", &format!("`{}`", &commit_hash[..7.min(commit_hash.len())])),
+                field("작성자", &escape(author.unwrap_or("Unknown"))),
+                field("트리거", &escape(triggered_by.unwrap_or("Unknown"))),
+            ],
+            Vec::new(),
+        )
+    }
+
+    /// 빌드 성공 알림
+    pub fn build_success_message(
+        &self,
+        project_name: &str,
+        build_number: i64,
+        branch: &str,
+        duration_seconds: u64,
+        build_url: Option<&str>,
+    ) -> SlackMessage {
+        SlackMessage::new(
+            format!("✅ 빌드 #{} 성공", build_number),
+            format!("프로젝트 *{}*의 빌드가 성공했습니다!", escape(project_name)),
+            vec![
+                field("브랜치", &format!("`{}`", escape(branch))),
+                field("빌드 시간", &format!("{}초", duration_seconds)),
+            ],
+            Vec::new(),
+        )
+        .with_link("빌드 로그 보기", build_url)
+    }
+
+    /// 빌드 실패 알림 (멘션 포함)
+    pub fn build_failure_message(
+        &self,
+        project_name: &str,
+        build_number: i64,
+        branch: &str,
+        error_message: Option<&str>,
+        build_url: Option<&str>,
+        mentions: Vec<String>,
+    ) -> SlackMessage {
+        SlackMessage::new(
+            format!("❌ 빌드 #{} 실패", build_number),
+            format!("프로젝트 *{}*의 빌드가 실패했습니다.", escape(project_name)),
+            vec![
+                field("브랜치", &format!("`{}`", escape(branch))),
+                field(
+                    "에러",
+                    &error_message
+                        .map(|e| format!("`{}`", escape(e)))
+                        .unwrap_or_else(|| "로그를 확인해주세요".to_string()),
+                ),
+            ],
+            mentions,
+        )
+        .with_link("빌드 로그 보기", build_url)
+    }
+
+    /// 배포 성공 알림
+    pub fn deployment_success_message(
+        &self,
+        project_name: &str,
+        build_number: i64,
+        slot: &str,
+        app_url: Option<&str>,
+    ) -> SlackMessage {
+        SlackMessage::new(
+            format!("🚀 배포 완료 (빌드 #{})", build_number),
+            format!("프로젝트 *{}*가 성공적으로 배포되었습니다!", escape(project_name)),
+            vec![field("슬롯", &format!("`{}`", slot))],
+            Vec::new(),
+        )
+        .with_link("앱 열기", app_url)
+    }
+
+    /// 배포 실패 알림
+    pub fn deployment_failure_message(
+        &self,
+        project_name: &str,
+        build_number: i64,
+        error_message: Option<&str>,
+        mentions: Vec<String>,
+    ) -> SlackMessage {
+        SlackMessage::new(
+            format!("🔥 배포 실패 (빌드 #{})", build_number),
+            format!("프로젝트 *{}*의 배포가 실패했습니다.", escape(project_name)),
+            vec![field(
+                "에러",
+                &error_message
+                    .map(|e| format!("`{}`", escape(e)))
+                    .unwrap_or_else(|| "로그를 확인해주세요".to_string()),
+            )],
+            mentions,
+        )
+    }
+
+    /// 프로젝트 경고 알림 (빌드와 무관한 오류, 예: 디스크 쿼터 초과)
+    pub fn project_alert_message(&self, project_name: &str, message: &str, mentions: Vec<String>) -> SlackMessage {
+        SlackMessage::new(
+            "⚠️ 프로젝트 경고".to_string(),
+            format!("프로젝트 *{}*", escape(project_name)),
+            vec![field("내용", &format!("`{}`", escape(message)))],
+            mentions,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_failure_blocks() {
+        let message = SlackClient::new()
+            .build_failure_message(
+                "web",
+                7,
+                "main",
+                Some("error: <unexpected> & more"),
+                Some("http://ci/builds/7"),
+                vec!["<@U123>".to_string()],
+            )
+            .with_annotation(Some("hotfix"), &["release".to_string()])
+            .with_notes_link(Some("http://ci/projects/1/notes"));
+        let json = serde_json::to_value(&message).unwrap();
+        let blocks = json["blocks"].as_array().unwrap();
+
+        assert_eq!(json["text"], "❌ 빌드 #7 실패");
+        let types: Vec<&str> = blocks.iter().map(|b| b["type"].as_str().unwrap()).collect();
+        assert_eq!(types, vec!["header", "section", "section", "actions", "section", "context"]);
+        assert_eq!(blocks[1]["text"]["text"], "<@U123> 프로젝트 *web*의 빌드가 실패했습니다.");
+        assert_eq!(blocks[2]["fields"][1]["text"], "*에러*\n`error: &lt;unexpected&gt; &amp; more`");
+        assert_eq!(blocks[3]["elements"][0]["url"], "http://ci/builds/7");
+        assert_eq!(blocks[3]["elements"][1]["text"]["text"], "런북 보기");
+        assert_eq!(blocks[4]["fields"][1]["text"], "*라벨*\n`release`");
+        assert!(blocks[1].get("fields").is_none());
+    }
+}
@@ -0,0 +1,217 @@
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tracing::{debug, error, info, warn};
+
+use crate::application::events::Event;
+use crate::application::ports::repositories::{BuildRepository, ProjectRepository};
+use crate::db::models::{BuildStatus, Project};
+use crate::infrastructure::database::{SqliteBuildRepository, SqliteProjectRepository, SqliteSlackWebhookRepository};
+use crate::infrastructure::notifications::SlackClient;
+use crate::infrastructure::timezone;
+
+/// Slack 알림 설정
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SlackWebhookConfig {
+    pub id: i64,
+    pub label: String,
+    pub webhook_url: String,
+    pub enabled: bool,
+    pub notify_on_build_start: bool,
+    pub notify_on_build_success: bool,
+    pub notify_on_build_failure: bool,
+    pub notify_on_deploy_start: bool,
+    pub notify_on_deploy_success: bool,
+    pub notify_on_deploy_failure: bool,
+    pub mention_user_ids: Vec<String>,
+    pub mention_group_ids: Vec<String>,
+    pub mention_on_failure_only: bool,
+}
+
+impl SlackWebhookConfig {
+    /// 멘션 문자열 생성
+    pub fn get_mentions(&self, is_failure: bool) -> Vec<String> {
+        if !is_failure && self.mention_on_failure_only {
+            return Vec::new();
+        }
+
+        // 사용자 멘션: <@U123>, user group 멘션: <!subteam^S123>
+        self.mention_user_ids
+            .iter()
+            .map(|id| format!("<@{}>", id))
+            .chain(self.mention_group_ids.iter().map(|id| format!("<!subteam^{}>", id)))
+            .collect()
+    }
+}
+
+/// Slack 알림 워커
+///
+/// 프로젝트의 slack_webhook_id로 라우팅하며 Discord 알림(discord_webhook_id)과 독립적으로 동작
+pub async fn run_slack_notifier(
+    webhook_repo: Arc<SqliteSlackWebhookRepository>,
+    build_repo: Arc<SqliteBuildRepository>,
+    project_repo: Arc<SqliteProjectRepository>,
+    mut event_rx: broadcast::Receiver<Event>,
+    base_url: Option<String>,
+) -> Result<()> {
+    info!("Starting Slack notifier");
+
+    let slack_client = SlackClient::new();
+    let base_url = base_url.unwrap_or_else(|| "http://localhost:10000".to_string());
+
+    loop {
+        match event_rx.recv().await {
+            Ok(event) => {
+                if let Err(e) = handle_event(&slack_client, &event, &webhook_repo, &build_repo, &project_repo, &base_url).await {
+                    error!("Failed to send Slack notification: {}", e);
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!("Slack notifier lagged, skipped {} events", skipped);
+            }
+            Err(broadcast::error::RecvError::Closed) => {
+                info!("Event bus closed, Slack notifier stopping");
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 프로젝트에 연결된 활성 Slack webhook (없거나 비활성화면 None)
+async fn project_webhook(
+    webhook_repo: &SqliteSlackWebhookRepository,
+    project: &Project,
+) -> Result<Option<SlackWebhookConfig>> {
+    let Some(webhook_id) = project.slack_webhook_id else {
+        debug!("Project {} has no Slack webhook configured", project.name);
+        return Ok(None);
+    };
+
+    match webhook_repo.get(webhook_id).await? {
+        Some(config) if config.enabled => Ok(Some(config)),
+        Some(config) => {
+            debug!("Slack webhook '{}' is disabled", config.label);
+            Ok(None)
+        }
+        None => {
+            warn!("Slack webhook {} not found for project {}", webhook_id, project.name);
+            Ok(None)
+        }
+    }
+}
+
+/// 프로젝트 노트 페이지 링크 (노트가 있는 경우만)
+fn notes_url(base_url: &str, project: &Project) -> Option<String> {
+    project.notes.as_ref().map(|_| format!("{}/projects/{}/notes", base_url, project.id))
+}
+
+async fn handle_event(
+    client: &SlackClient,
+    event: &Event,
+    webhook_repo: &SqliteSlackWebhookRepository,
+    build_repo: &SqliteBuildRepository,
+    project_repo: &SqliteProjectRepository,
+    base_url: &str,
+) -> Result<()> {
+    let project_id = match event {
+        Event::BuildStatus { project_id, status: BuildStatus::Building | BuildStatus::Success | BuildStatus::Failed, .. }
+        | Event::Deployment { project_id, .. }
+        | Event::Error { build_id: None, project_id: Some(project_id), .. } => *project_id,
+        _ => return Ok(()),
+    };
+
+    let project = project_repo
+        .get(project_id)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Project not found"))?;
+    let Some(config) = project_webhook(webhook_repo, &project).await? else {
+        return Ok(());
+    };
+    let notes_url = notes_url(base_url, &project);
+
+    let message = match event {
+        Event::BuildStatus { build_id, status, .. } => {
+            let build = build_repo
+                .get(*build_id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("Build not found"))?;
+            let build_url = format!("{}/builds/{}", base_url, build_id);
+
+            match status {
+                BuildStatus::Building if config.notify_on_build_start => client.build_started_message(
+                    &project.name,
+                    build.build_number,
+                    &project.branch,
+                    &build.commit_hash,
+                    build.author.as_deref(),
+                    build.triggered_by.as_deref(),
+                ),
+                BuildStatus::Success if config.notify_on_build_success => {
+                    let duration = build
+                        .finished_at
+                        .as_deref()
+                        .and_then(|finished| {
+                            let started = timezone::parse_stored(&build.started_at)?;
+                            let finished = timezone::parse_stored(finished)?;
+                            Some((finished - started).num_seconds() as u64)
+                        })
+                        .unwrap_or(0);
+                    client.build_success_message(&project.name, build.build_number, &project.branch, duration, Some(&build_url))
+                }
+                BuildStatus::Failed if config.notify_on_build_failure => client
+                    .build_failure_message(
+                        &project.name,
+                        build.build_number,
+                        &project.branch,
+                        Some(build.failure_summary.as_deref().unwrap_or("빌드 실패 - 로그를 확인하세요")),
+                        Some(&build_url),
+                        config.get_mentions(true),
+                    )
+                    .with_notes_link(notes_url.as_deref()),
+                _ => return Ok(()),
+            }
+            .with_annotation(build.note.as_deref(), &build.parsed_labels())
+        }
+
+        Event::Deployment { project_name, build_id, status, slot, url, .. } => {
+            let is_failure = status.contains("fail") || status.contains("Fail");
+            let wanted = match status.as_str() {
+                "Success" => config.notify_on_deploy_success,
+                _ if is_failure => config.notify_on_deploy_failure,
+                _ => false,
+            };
+            if !wanted {
+                return Ok(());
+            }
+
+            let build = build_repo
+                .get(*build_id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("Build not found"))?;
+            let message = if is_failure {
+                client
+                    .deployment_failure_message(
+                        project_name,
+                        build.build_number,
+                        Some("배포 실패 - 로그를 확인하세요"),
+                        config.get_mentions(true),
+                    )
+                    .with_notes_link(notes_url.as_deref())
+            } else {
+                client.deployment_success_message(project_name, build.build_number, &slot.to_string(), Some(url))
+            };
+            message.with_annotation(build.note.as_deref(), &build.parsed_labels())
+        }
+
+        // 빌드와 무관한 프로젝트 오류 (빌드 오류는 BuildStatus::Failed로 알림)
+        Event::Error { message, .. } if config.notify_on_build_failure => client
+            .project_alert_message(&project.name, message, config.get_mentions(true))
+            .with_notes_link(notes_url.as_deref()),
+
+        _ => return Ok(()),
+    };
+
+    client.send_message(&config.webhook_url, message).await
+}
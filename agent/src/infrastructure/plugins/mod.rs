@@ -0,0 +1,3 @@
+pub mod plugin_host;
+
+pub use plugin_host::{discover_plugins, run_plugin_host, PLUGIN_DIR};
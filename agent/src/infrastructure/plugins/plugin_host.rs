@@ -0,0 +1,444 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+use crate::application::ports::repositories::ProjectRepository;
+use crate::db::models::BuildStatus;
+use crate::events::Event;
+use crate::state::AppContext;
+
+/// 플러그인 검색 디렉토리. 하위 디렉토리마다 plugin.json 하나.
+pub const PLUGIN_DIR: &str = "/data/easycicd/plugins";
+
+/// 플러그인 재검색 주기 (재시작 없이 플러그인 추가/삭제 반영)
+const RESCAN_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 지원하는 runtime
+const RUNTIMES: [&str; 2] = ["exec", "wasm"];
+
+/// WASM 플러그인을 실행할 WASI 런타임 (wasmtime CLI 호환, 기본 `wasmtime`)
+const WASM_RUNTIME_ENV: &str = "PLUGIN_WASM_RUNTIME";
+
+/// notifier 플러그인이 받는 EASYCICD_EVENT_TYPE
+const NOTIFICATION_EVENT_TYPE: &str = "notification";
+
+/// plugin.json
+///
+/// ```json
+/// {
+///   "name": "pagerduty",
+///   "command": "./notify.sh",
+///   "events": ["build_status", "deployment"],
+///   "timeout_secs": 30,
+///   "runtime": "exec",
+///   "notifier": true
+/// }
+/// ```
+///
+/// 이벤트는 JSON으로 stdin에 전달되고, EASYCICD_EVENT_TYPE 환경변수에 이벤트 이름이 들어간다.
+/// - runtime: "exec"는 외부 실행 파일, "wasm"은 `command`의 WASI 모듈을 PLUGIN_WASM_RUNTIME으로 실행
+///   (파일 시스템 접근 없이 stdin/stdout과 EASYCICD_* 환경변수만 전달)
+/// - notifier: true면 알림 대상. 원본 이벤트 대신 PluginNotification을 받음
+///   (EASYCICD_EVENT_TYPE=notification, `events`가 비어 있으면 모든 알림)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginManifest {
+    pub name: String,
+    /// 플러그인 디렉토리 기준 상대 경로 또는 절대 경로
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// 구독할 이벤트 이름 (Event "type" 태그). "*"는 전체
+    #[serde(default)]
+    pub events: Vec<String>,
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default = "default_runtime")]
+    pub runtime: String,
+    #[serde(default)]
+    pub notifier: bool,
+
+    /// 플러그인 디렉토리 (manifest 로드 시 채워짐)
+    #[serde(skip_deserializing)]
+    pub dir: PathBuf,
+}
+
+fn default_timeout_secs() -> u64 {
+    30
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_runtime() -> String {
+    "exec".to_string()
+}
+
+impl PluginManifest {
+    pub fn subscribes_to(&self, event_type: &str) -> bool {
+        self.events.iter().any(|e| e == "*" || e == event_type)
+    }
+
+    /// notifier 플러그인이 이 이벤트의 알림을 받는지
+    fn receives_notification(&self, event_type: &str) -> bool {
+        self.notifier && (self.events.is_empty() || self.subscribes_to(event_type))
+    }
+
+    fn command_path(&self) -> PathBuf {
+        let cmd = Path::new(&self.command);
+        if cmd.is_absolute() {
+            cmd.to_path_buf()
+        } else {
+            self.dir.join(cmd)
+        }
+    }
+
+    /// 실행할 프로그램과 인자 (wasm은 WASI 런타임에 환경변수를 명시적으로 넘김)
+    fn program(&self, event_type: &str) -> (PathBuf, Vec<String>) {
+        match self.runtime.as_str() {
+            "wasm" => {
+                let runtime = std::env::var(WASM_RUNTIME_ENV).unwrap_or_else(|_| "wasmtime".to_string());
+                let mut args = vec![
+                    "run".to_string(),
+                    "--env".to_string(),
+                    format!("EASYCICD_EVENT_TYPE={}", event_type),
+                    "--env".to_string(),
+                    format!("EASYCICD_PLUGIN_NAME={}", self.name),
+                    self.command_path().to_string_lossy().into_owned(),
+                ];
+                args.extend(self.args.iter().cloned());
+                (PathBuf::from(runtime), args)
+            }
+            _ => (self.command_path(), self.args.clone()),
+        }
+    }
+}
+
+/// notifier 플러그인에 전달하는 알림
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PluginNotification {
+    /// info, success, failure
+    pub level: &'static str,
+    pub title: String,
+    pub message: String,
+    pub project_id: Option<i64>,
+    pub project_name: Option<String>,
+    pub build_id: Option<i64>,
+    pub url: Option<String>,
+    /// 알림을 만든 이벤트 이름
+    pub source_event: &'static str,
+}
+
+/// 알림으로 보낼 이벤트면 PluginNotification (빌드 시작/성공/실패, 배포 성공/실패, 프로젝트 오류)
+fn notification_for(event: &Event, project_name: Option<&str>, base_url: &str) -> Option<PluginNotification> {
+    let project_label = |id: i64| project_name.map(str::to_string).unwrap_or_else(|| format!("project {}", id));
+    match event {
+        Event::BuildStatus { build_id, project_id, status, .. } => {
+            let (level, verb) = match status {
+                BuildStatus::Building => ("info", "started"),
+                BuildStatus::Success => ("success", "succeeded"),
+                BuildStatus::Failed => ("failure", "failed"),
+                _ => return None,
+            };
+            Some(PluginNotification {
+                level,
+                title: format!("Build #{} {}", build_id, verb),
+                message: format!("Build #{} of {} {}", build_id, project_label(*project_id), verb),
+                project_id: Some(*project_id),
+                project_name: project_name.map(str::to_string),
+                build_id: Some(*build_id),
+                url: Some(format!("{}/builds/{}", base_url, build_id)),
+                source_event: event.event_type(),
+            })
+        }
+        Event::Deployment { project_id, project_name, build_id, status, slot, url, .. } => {
+            let (level, verb) = match status.as_str() {
+                "Success" => ("success", "deployed"),
+                s if s.to_lowercase().contains("fail") => ("failure", "failed to deploy"),
+                _ => return None,
+            };
+            Some(PluginNotification {
+                level,
+                title: format!("{} {}", project_name, verb),
+                message: format!("Build #{} of {} {} ({} slot)", build_id, project_name, verb, slot),
+                project_id: Some(*project_id),
+                project_name: Some(project_name.clone()),
+                build_id: Some(*build_id),
+                url: Some(url.clone()),
+                source_event: event.event_type(),
+            })
+        }
+        Event::Error { build_id, project_id: Some(project_id), message, .. } => Some(PluginNotification {
+            level: "failure",
+            title: format!("Error in {}", project_label(*project_id)),
+            message: message.clone(),
+            project_id: Some(*project_id),
+            project_name: project_name.map(str::to_string),
+            build_id: *build_id,
+            url: build_id.map(|id| format!("{}/builds/{}", base_url, id)),
+            source_event: event.event_type(),
+        }),
+        _ => None,
+    }
+}
+
+/// 플러그인 디렉토리 스캔. 잘못된 manifest는 경고 후 건너뜀
+pub async fn discover_plugins(dir: &Path) -> Vec<PluginManifest> {
+    let mut plugins = Vec::new();
+
+    let mut entries = match fs::read_dir(dir).await {
+        Ok(e) => e,
+        Err(_) => return plugins,  // 디렉토리가 없으면 플러그인 없음
+    };
+
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let plugin_dir = entry.path();
+        let manifest_path = plugin_dir.join("plugin.json");
+        if !manifest_path.is_file() {
+            continue;
+        }
+
+        match load_manifest(&manifest_path, &plugin_dir).await {
+            Ok(manifest) if !RUNTIMES.contains(&manifest.runtime.as_str()) => {
+                warn!("Plugin '{}' uses unsupported runtime '{}', skipping", manifest.name, manifest.runtime);
+            }
+            Ok(manifest) => plugins.push(manifest),
+            Err(e) => warn!("Invalid plugin manifest {}: {}", manifest_path.display(), e),
+        }
+    }
+
+    plugins.sort_by(|a, b| a.name.cmp(&b.name));
+    plugins
+}
+
+async fn load_manifest(path: &Path, plugin_dir: &Path) -> Result<PluginManifest> {
+    let content = fs::read_to_string(path).await.context("Failed to read plugin.json")?;
+    let mut manifest: PluginManifest = serde_json::from_str(&content).context("Failed to parse plugin.json")?;
+    manifest.dir = plugin_dir.to_path_buf();
+    Ok(manifest)
+}
+
+/// 플러그인 호스트 워커
+/// 이벤트 버스를 구독하고, 이벤트를 구독 중인 플러그인 프로세스로 전달한다.
+/// notifier 플러그인에는 알림으로 바꿔 전달한다.
+pub async fn run_plugin_host(context: AppContext) -> Result<()> {
+    let mut event_rx = context.subscribe_events();
+    let base_url = std::env::var("BASE_URL").unwrap_or_else(|_| "http://localhost:10000".to_string());
+    let plugin_dir = PathBuf::from(PLUGIN_DIR);
+    let mut plugins = discover_plugins(&plugin_dir).await;
+    let mut last_scan = Instant::now();
+
+    info!("Plugin host started ({} plugins in {})", plugins.len(), plugin_dir.display());
+
+    loop {
+        match event_rx.recv().await {
+            Ok(event) => {
+                if last_scan.elapsed() >= RESCAN_INTERVAL {
+                    plugins = discover_plugins(&plugin_dir).await;
+                    last_scan = Instant::now();
+                }
+
+                let event_type = event.event_type();
+                let hooks: Vec<&PluginManifest> = plugins
+                    .iter()
+                    .filter(|p| p.enabled && !p.notifier && p.subscribes_to(event_type))
+                    .collect();
+                if !hooks.is_empty() {
+                    match serde_json::to_vec(&event) {
+                        Ok(payload) => dispatch(&hooks, event_type, payload),
+                        Err(e) => warn!("Failed to serialize event for plugins: {}", e),
+                    }
+                }
+
+                let notifiers: Vec<&PluginManifest> = plugins
+                    .iter()
+                    .filter(|p| p.enabled && p.receives_notification(event_type))
+                    .collect();
+                if !notifiers.is_empty() {
+                    let project_name = match &event {
+                        Event::BuildStatus { project_id, .. } | Event::Error { project_id: Some(project_id), .. } => {
+                            context.project_repo.get(*project_id).await.ok().flatten().map(|p| p.name)
+                        }
+                        _ => None,
+                    };
+                    if let Some(notification) = notification_for(&event, project_name.as_deref(), &base_url) {
+                        match serde_json::to_vec(&notification) {
+                            Ok(payload) => dispatch(&notifiers, NOTIFICATION_EVENT_TYPE, payload),
+                            Err(e) => warn!("Failed to serialize notification for plugins: {}", e),
+                        }
+                    }
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!("Plugin host lagged, skipped {} events", skipped);
+            }
+            Err(broadcast::error::RecvError::Closed) => {
+                info!("Event bus closed, plugin host stopping");
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 플러그인마다 별도 태스크로 실행 (느린 플러그인이 이벤트 처리를 막지 않음)
+fn dispatch(targets: &[&PluginManifest], event_type: &'static str, payload: Vec<u8>) {
+    for plugin in targets {
+        let plugin = (*plugin).clone();
+        let payload = payload.clone();
+        tokio::spawn(async move {
+            if let Err(e) = invoke_plugin(&plugin, event_type, &payload).await {
+                warn!("Plugin '{}' failed on {}: {}", plugin.name, event_type, e);
+            }
+        });
+    }
+}
+
+/// 플러그인 실행. stdin 쓰기부터 종료까지 전체에 timeout_secs 적용 (초과하면 프로세스 종료)
+async fn invoke_plugin(plugin: &PluginManifest, event_type: &str, payload: &[u8]) -> Result<()> {
+    let (program, args) = plugin.program(event_type);
+    let run = async {
+        let mut child = Command::new(program)
+            .args(&args)
+            .current_dir(&plugin.dir)
+            .env("EASYCICD_EVENT_TYPE", event_type)
+            .env("EASYCICD_PLUGIN_NAME", &plugin.name)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .context("Failed to spawn plugin")?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(payload).await.context("Failed to write event to plugin")?;
+            // stdin을 닫아 EOF 전달
+        }
+
+        child.wait_with_output().await.context("Failed to wait for plugin")
+    };
+
+    let output = tokio::time::timeout(Duration::from_secs(plugin.timeout_secs), run)
+        .await
+        .map_err(|_| anyhow::anyhow!("timed out after {}s", plugin.timeout_secs))??;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    debug!(
+        "Plugin '{}' handled {}: {}",
+        plugin.name,
+        event_type,
+        String::from_utf8_lossy(&output.stdout).trim()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(events: &[&str]) -> PluginManifest {
+        PluginManifest {
+            name: "test".to_string(),
+            command: "./run.sh".to_string(),
+            args: Vec::new(),
+            events: events.iter().map(|e| e.to_string()).collect(),
+            timeout_secs: 30,
+            enabled: true,
+            runtime: "exec".to_string(),
+            notifier: false,
+            dir: PathBuf::from("/data/easycicd/plugins/test"),
+        }
+    }
+
+    #[test]
+    fn test_subscribes_to() {
+        let plugin = manifest(&["build_status", "deployment"]);
+        assert!(plugin.subscribes_to("build_status"));
+        assert!(plugin.subscribes_to("deployment"));
+        assert!(!plugin.subscribes_to("log"));
+
+        assert!(manifest(&["*"]).subscribes_to("log"));
+        assert!(!manifest(&[]).subscribes_to("build_status"));
+    }
+
+    #[test]
+    fn test_command_path() {
+        let plugin = manifest(&[]);
+        assert_eq!(plugin.command_path(), PathBuf::from("/data/easycicd/plugins/test/./run.sh"));
+
+        let mut abs = manifest(&[]);
+        abs.command = "/usr/bin/notify".to_string();
+        assert_eq!(abs.command_path(), PathBuf::from("/usr/bin/notify"));
+    }
+
+    #[test]
+    fn test_manifest_defaults() {
+        let m: PluginManifest = serde_json::from_str(r#"{"name": "x", "command": "run"}"#).unwrap();
+        assert_eq!(m.timeout_secs, 30);
+        assert!(m.enabled);
+        assert_eq!(m.runtime, "exec");
+        assert!(!m.notifier);
+        assert!(m.events.is_empty());
+    }
+
+    #[test]
+    fn test_wasm_program() {
+        let mut plugin = manifest(&[]);
+        plugin.runtime = "wasm".to_string();
+        plugin.command = "notify.wasm".to_string();
+        plugin.args = vec!["--verbose".to_string()];
+
+        let (program, args) = plugin.program("build_status");
+        assert_eq!(program, PathBuf::from(std::env::var(WASM_RUNTIME_ENV).unwrap_or_else(|_| "wasmtime".to_string())));
+        assert_eq!(args, vec![
+            "run",
+            "--env",
+            "EASYCICD_EVENT_TYPE=build_status",
+            "--env",
+            "EASYCICD_PLUGIN_NAME=test",
+            "/data/easycicd/plugins/test/notify.wasm",
+            "--verbose",
+        ]);
+        assert_eq!(manifest(&[]).program("x").0, PathBuf::from("/data/easycicd/plugins/test/./run.sh"));
+    }
+
+    #[test]
+    fn test_notifications() {
+        let mut notifier = manifest(&[]);
+        notifier.notifier = true;
+        assert!(notifier.receives_notification("deployment"));
+        assert!(!manifest(&["deployment"]).receives_notification("deployment"));
+        notifier.events = vec!["build_status".to_string()];
+        assert!(!notifier.receives_notification("deployment"));
+
+        let failed = notification_for(&Event::build_status(7, 3, BuildStatus::Failed), Some("api"), "https://ci").unwrap();
+        assert_eq!(failed.level, "failure");
+        assert_eq!(failed.message, "Build #7 of api failed");
+        assert_eq!(failed.url.as_deref(), Some("https://ci/builds/7"));
+        assert_eq!(failed.source_event, "build_status");
+        assert!(notification_for(&Event::build_status(7, 3, BuildStatus::Queued), None, "https://ci").is_none());
+
+        let deployed = Event::deployment(3, "api".to_string(), 7, "Success".to_string(), crate::db::models::Slot::Blue, "http://localhost:8081".to_string());
+        let deployed = notification_for(&deployed, None, "https://ci").unwrap();
+        assert_eq!((deployed.level, deployed.title.as_str()), ("success", "api deployed"));
+
+        assert!(notification_for(&Event::error(None, None, "disk".to_string()), None, "https://ci").is_none());
+    }
+}
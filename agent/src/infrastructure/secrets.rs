@@ -0,0 +1,267 @@
+use anyhow::{Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeSet, HashMap};
+use std::io::Write;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::Path;
+use tracing::info;
+
+/// 비밀 값 암호화 키를 만드는 환경변수 (임의 문자열, SHA-256으로 32바이트 키 유도)
+pub const SECRETS_KEY_ENV: &str = "SECRETS_KEY";
+
+/// SECRETS_KEY가 없을 때 쓰는 키 파일 (처음 시작 시 무작위 32바이트를 hex로 생성, 0600)
+pub const SECRETS_KEY_PATH: &str = "/data/easycicd/secrets.key";
+
+/// 환경변수 값에서 비밀을 참조하는 형식: `${secret:NAME}`
+const SECRET_REF_PREFIX: &str = "${secret:";
+
+/// 비밀 이름 최대 길이
+pub const MAX_SECRET_NAME_LEN: usize = 128;
+
+/// 비밀 값 최대 크기 (bytes)
+pub const MAX_SECRET_VALUE_LEN: usize = 64 * 1024;
+
+/// 로그에서 비밀 값을 가릴 때 쓰는 문자열
+pub const SECRET_MASK: &str = "***";
+
+/// 비밀 이름 검증 (영숫자, `_`, `-`, `.`)
+pub fn validate_secret_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.len() > MAX_SECRET_NAME_LEN {
+        return Err(format!("Secret name must be 1-{} characters", MAX_SECRET_NAME_LEN));
+    }
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')) {
+        return Err("Secret name may only contain letters, digits, '_', '-' and '.'".to_string());
+    }
+    Ok(())
+}
+
+/// SecretCipher - 비밀 값 AES-256-GCM 암호화
+///
+/// 저장 형식은 base64(nonce || ciphertext+tag)이고, 비밀 이름을 AAD로 묶어
+/// 다른 이름의 행으로 옮긴 값은 복호화되지 않는다
+pub struct SecretCipher {
+    key: LessSafeKey,
+    rng: SystemRandom,
+}
+
+impl SecretCipher {
+    pub fn new(key: [u8; 32]) -> Self {
+        let key = LessSafeKey::new(UnboundKey::new(&AES_256_GCM, &key).expect("AES-256 key is 32 bytes"));
+        Self { key, rng: SystemRandom::new() }
+    }
+
+    /// SECRETS_KEY 환경변수, 없으면 키 파일에서 키를 읽음 (키 파일이 없으면 생성)
+    pub fn load() -> Result<Self> {
+        if let Some(passphrase) = std::env::var(SECRETS_KEY_ENV).ok().filter(|v| !v.is_empty()) {
+            return Ok(Self::new(Sha256::digest(passphrase.as_bytes()).into()));
+        }
+        Self::load_key_file(Path::new(SECRETS_KEY_PATH))
+    }
+
+    fn load_key_file(path: &Path) -> Result<Self> {
+        let hex_key = match std::fs::read_to_string(path) {
+            Ok(contents) => contents.trim().to_string(),
+            Err(_) => {
+                let mut key = [0u8; 32];
+                SystemRandom::new().fill(&mut key).map_err(|_| anyhow::anyhow!("Failed to generate secrets key"))?;
+                let hex_key = hex::encode(key);
+                let mut file = std::fs::OpenOptions::new()
+                    .write(true)
+                    .create_new(true)
+                    .mode(0o600)
+                    .open(path)
+                    .with_context(|| format!("Failed to create {}", path.display()))?;
+                file.write_all(hex_key.as_bytes())?;
+                info!("Created secrets encryption key at {}", path.display());
+                hex_key
+            }
+        };
+        let key: [u8; 32] = hex::decode(&hex_key)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .with_context(|| format!("{} must contain a 32-byte hex key", path.display()))?;
+        Ok(Self::new(key))
+    }
+
+    pub fn encrypt(&self, name: &str, value: &str) -> Result<String> {
+        let mut nonce = [0u8; NONCE_LEN];
+        self.rng.fill(&mut nonce).map_err(|_| anyhow::anyhow!("Failed to generate nonce"))?;
+        let mut sealed = value.as_bytes().to_vec();
+        self.key
+            .seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::from(name.as_bytes()), &mut sealed)
+            .map_err(|_| anyhow::anyhow!("Failed to encrypt secret"))?;
+
+        let mut stored = nonce.to_vec();
+        stored.extend_from_slice(&sealed);
+        Ok(STANDARD.encode(stored))
+    }
+
+    pub fn decrypt(&self, name: &str, stored: &str) -> Result<String> {
+        let bytes = STANDARD.decode(stored).context("Invalid encrypted secret")?;
+        if bytes.len() < NONCE_LEN {
+            anyhow::bail!("Invalid encrypted secret");
+        }
+        let (nonce, sealed) = bytes.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| anyhow::anyhow!("Invalid nonce"))?;
+        let mut sealed = sealed.to_vec();
+        let plain = self.key
+            .open_in_place(nonce, Aad::from(name.as_bytes()), &mut sealed)
+            .map_err(|_| anyhow::anyhow!("Failed to decrypt secret '{}' (wrong key?)", name))?;
+        String::from_utf8(plain.to_vec()).context("Secret is not UTF-8")
+    }
+}
+
+/// 값이 비밀을 참조하는지
+pub fn has_secret_ref(value: &str) -> bool {
+    value.contains(SECRET_REF_PREFIX)
+}
+
+/// 값에서 참조하는 비밀 이름들 (닫히지 않은 참조는 무시)
+pub fn secret_refs(value: &str) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    let mut rest = value;
+    while let Some(start) = rest.find(SECRET_REF_PREFIX) {
+        let after = &rest[start + SECRET_REF_PREFIX.len()..];
+        let Some(end) = after.find('}') else { break };
+        names.insert(after[..end].to_string());
+        rest = &after[end + 1..];
+    }
+    names
+}
+
+/// 환경변수 JSON 객체(`{"KEY": "value"}`)에서 참조하는 비밀 이름들
+pub fn env_secret_refs(env_json: Option<&str>) -> BTreeSet<String> {
+    env_json
+        .and_then(|json| serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(json).ok())
+        .map(|env| {
+            env.values()
+                .filter_map(|v| v.as_str())
+                .flat_map(secret_refs)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `${secret:NAME}`를 값으로 치환. 없는 비밀을 참조하면 그 이름으로 에러
+pub fn resolve_secret_refs(value: &str, secrets: &HashMap<String, String>) -> Result<String, String> {
+    let mut resolved = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find(SECRET_REF_PREFIX) {
+        let after = &rest[start + SECRET_REF_PREFIX.len()..];
+        let Some(end) = after.find('}') else { break };
+        let name = &after[..end];
+        let secret = secrets.get(name).ok_or_else(|| name.to_string())?;
+        resolved.push_str(&rest[..start]);
+        resolved.push_str(secret);
+        rest = &after[end + 1..];
+    }
+    resolved.push_str(rest);
+    Ok(resolved)
+}
+
+/// 컨테이너에 주입할 비밀 환경변수와 로그에서 가릴 값
+#[derive(Clone, Default)]
+pub struct SecretEnv {
+    /// 비밀을 참조하는 항목을 치환한 (이름, 값)
+    pub env: Vec<(String, String)>,
+    /// 참조한 비밀의 평문 값
+    pub values: Vec<String>,
+}
+
+/// Debug 출력에는 이름만 남김
+impl std::fmt::Debug for SecretEnv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.env.iter().map(|(key, _)| key)).finish()
+    }
+}
+
+impl SecretEnv {
+    /// 환경변수 JSON 객체에서 비밀을 참조하는 항목만 골라 값을 치환. 없는 비밀을 참조하면 에러 메시지
+    pub fn resolve(env_json: Option<&str>, secrets: &HashMap<String, String>) -> Result<Self, String> {
+        let Some(env) = env_json.and_then(|json| serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(json).ok()) else {
+            return Ok(Self::default());
+        };
+        let env = env.iter()
+            .filter_map(|(key, value)| value.as_str().filter(|v| has_secret_ref(v)).map(|v| (key, v)))
+            .map(|(key, value)| {
+                resolve_secret_refs(value, secrets)
+                    .map(|resolved| (key.clone(), resolved))
+                    .map_err(|name| format!("Environment variable {} references unknown secret '{}'", key, name))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { env, values: secrets.values().cloned().collect() })
+    }
+
+    /// 환경변수 JSON 객체의 비밀 참조 항목을 치환한 값으로 바꿈
+    pub fn apply(&self, env_json: Option<String>) -> Option<String> {
+        if self.env.is_empty() {
+            return env_json;
+        }
+        let mut env: serde_json::Map<String, serde_json::Value> = env_json
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+        for (key, value) in &self.env {
+            env.insert(key.clone(), serde_json::Value::String(value.clone()));
+        }
+        serde_json::to_string(&env).ok()
+    }
+
+    /// `KEY=VALUE` 목록 (Docker 컨테이너 환경변수)
+    pub fn docker_env(&self) -> Vec<String> {
+        self.env.iter().map(|(key, value)| format!("{}={}", key, value)).collect()
+    }
+
+    /// 로그 줄에서 비밀 값을 가림 (짧은 값은 일반 문자열과 겹치므로 4자 이상만)
+    pub fn mask(&self, line: &str) -> String {
+        self.values
+            .iter()
+            .filter(|v| v.len() >= 4)
+            .fold(line.to_string(), |line, value| line.replace(value.as_str(), SECRET_MASK))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_cipher() {
+        let cipher = SecretCipher::new([7u8; 32]);
+        let stored = cipher.encrypt("DB_PASSWORD", "hunter2").unwrap();
+        assert!(!stored.contains("hunter2"));
+        assert_ne!(stored, cipher.encrypt("DB_PASSWORD", "hunter2").unwrap());
+        assert_eq!(cipher.decrypt("DB_PASSWORD", &stored).unwrap(), "hunter2");
+
+        // 다른 이름(AAD)이나 다른 키로는 복호화 불가
+        assert!(cipher.decrypt("OTHER", &stored).is_err());
+        assert!(SecretCipher::new([8u8; 32]).decrypt("DB_PASSWORD", &stored).is_err());
+    }
+
+    #[test]
+    fn test_resolve_secret_refs() {
+        let secrets = HashMap::from([("db-pass".to_string(), "s3cret".to_string())]);
+        assert_eq!(
+            resolve_secret_refs("postgres://app:${secret:db-pass}@db/app", &secrets).unwrap(),
+            "postgres://app:s3cret@db/app"
+        );
+        assert_eq!(resolve_secret_refs("plain", &secrets).unwrap(), "plain");
+        assert_eq!(resolve_secret_refs("${secret:missing}", &secrets).unwrap_err(), "missing");
+
+        assert_eq!(
+            env_secret_refs(Some(r#"{"A": "${secret:x}-${secret:y}", "B": "z", "C": 1}"#)).into_iter().collect::<Vec<_>>(),
+            vec!["x", "y"]
+        );
+        let resolved = SecretEnv::resolve(Some(r#"{"DB_URL": "x:${secret:db-pass}", "PLAIN": "y"}"#), &secrets).unwrap();
+        assert_eq!(resolved.docker_env(), vec!["DB_URL=x:s3cret"]);
+        assert_eq!(
+            resolved.apply(Some(r#"{"DB_URL": "x:${secret:db-pass}", "PLAIN": "y"}"#.to_string())).as_deref(),
+            Some(r#"{"DB_URL":"x:s3cret","PLAIN":"y"}"#)
+        );
+        assert_eq!(resolved.mask("password is s3cret"), "password is ***");
+        assert!(SecretEnv::resolve(Some(r#"{"A": "${secret:nope}"}"#), &secrets).is_err());
+    }
+}
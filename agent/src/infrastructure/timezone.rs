@@ -0,0 +1,155 @@
+use anyhow::Result;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono_tz::Tz;
+use serde::Serializer;
+use sqlx::SqlitePool;
+use std::sync::RwLock;
+use tracing::{info, warn};
+
+use crate::application::ports::repositories::SettingsRepository;
+
+/// API 응답에 쓸 표시용 타임존 (IANA 이름, 예: "Asia/Seoul")
+pub const DISPLAY_TIMEZONE_SETTING: &str = "display_timezone";
+
+/// 빌드 타임스탬프를 UTC로 변환했는지 표시 (한 번만 실행)
+const UTC_MIGRATION_SETTING: &str = "timestamps_utc_migrated";
+
+/// DB 저장 형식. SQLite `datetime('now')`와 같은 UTC 문자열
+pub const DB_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+static DISPLAY_TZ: RwLock<Tz> = RwLock::new(Tz::UTC);
+
+pub fn display_timezone() -> Tz {
+    *DISPLAY_TZ.read().unwrap_or_else(|e| e.into_inner())
+}
+
+pub fn set_display_timezone(tz: Tz) {
+    *DISPLAY_TZ.write().unwrap_or_else(|e| e.into_inner()) = tz;
+}
+
+pub fn parse_timezone(name: &str) -> Result<Tz, String> {
+    name.trim()
+        .parse::<Tz>()
+        .map_err(|_| format!("Unknown timezone '{}' (use an IANA name like Asia/Seoul or UTC)", name))
+}
+
+/// 시작 시 settings의 표시 타임존 로드 (없거나 잘못된 값이면 UTC)
+pub async fn load_display_timezone<R: SettingsRepository + ?Sized>(repo: &R) {
+    match repo.get(DISPLAY_TIMEZONE_SETTING).await {
+        Ok(Some(name)) => match parse_timezone(&name) {
+            Ok(tz) => {
+                set_display_timezone(tz);
+                info!("Display timezone: {}", tz);
+            }
+            Err(e) => warn!("{}, using UTC", e),
+        },
+        Ok(None) => {}
+        Err(e) => warn!("Failed to load display timezone: {}", e),
+    }
+}
+
+/// DB에 저장할 현재 시각 (UTC)
+pub fn db_now() -> String {
+    Utc::now().format(DB_FORMAT).to_string()
+}
+
+/// 표시 타임존 기준 현재 시각 (RFC 3339, offset 포함)
+pub fn now_display() -> String {
+    Utc::now().with_timezone(&display_timezone()).to_rfc3339()
+}
+
+/// 저장된 타임스탬프 파싱. offset이 없는 DB 형식은 UTC로 간주
+pub fn parse_stored(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f"))
+        .ok()
+        .map(|naive| naive.and_utc())
+}
+
+/// 저장된 타임스탬프를 지정한 타임존의 RFC 3339로 변환. 파싱할 수 없으면 그대로 반환
+pub fn format_in(value: &str, tz: Tz) -> String {
+    parse_stored(value)
+        .map(|dt| dt.with_timezone(&tz).to_rfc3339())
+        .unwrap_or_else(|| value.to_string())
+}
+
+pub fn to_display(value: &str) -> String {
+    format_in(value, display_timezone())
+}
+
+/// Unix 초를 표시 타임존의 RFC 3339로 변환
+pub fn unix_to_display(secs: i64) -> Option<String> {
+    DateTime::from_timestamp(secs, 0).map(|dt| dt.with_timezone(&display_timezone()).to_rfc3339())
+}
+
+/// `#[serde(serialize_with = "...::serialize")]` 용: 응답에서 표시 타임존 ISO-8601로 변환
+pub fn serialize<S: Serializer, T: AsRef<str>>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&to_display(value.as_ref()))
+}
+
+pub fn serialize_option<S: Serializer>(value: &Option<String>, serializer: S) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(v) => serializer.serialize_str(&to_display(v)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// 이전 버전은 빌드 시작/종료 시각을 컨테이너 로컬 시간으로 저장했다.
+/// 현재 로컬 offset 기준으로 한 번만 UTC로 변환한다 (DST 구간이 섞인 이력은 근사치).
+pub async fn migrate_local_build_timestamps(pool: &SqlitePool) -> Result<()> {
+    let done: Option<String> = sqlx::query_scalar("SELECT value FROM settings WHERE key = ?")
+        .bind(UTC_MIGRATION_SETTING)
+        .fetch_optional(pool)
+        .await?;
+    if done.is_some() {
+        return Ok(());
+    }
+
+    let offset_secs = chrono::Local::now().offset().local_minus_utc();
+    if offset_secs != 0 {
+        let modifier = format!("{} seconds", -offset_secs);
+        let result = sqlx::query(
+            "UPDATE builds SET started_at = datetime(started_at, ?), finished_at = datetime(finished_at, ?)"
+        )
+        .bind(&modifier)
+        .bind(&modifier)
+        .execute(pool)
+        .await?;
+        info!("Converted {} build timestamp(s) from local time ({}s offset) to UTC", result.rows_affected(), offset_secs);
+    }
+
+    sqlx::query("INSERT OR REPLACE INTO settings (key, value, updated_at) VALUES (?, '1', datetime('now'))")
+        .bind(UTC_MIGRATION_SETTING)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_in_db_format_is_utc() {
+        let seoul: Tz = "Asia/Seoul".parse().unwrap();
+        assert_eq!(format_in("2024-05-01 03:00:00", seoul), "2024-05-01T12:00:00+09:00");
+        assert_eq!(format_in("2024-05-01 03:00:00", Tz::UTC), "2024-05-01T03:00:00+00:00");
+    }
+
+    #[test]
+    fn test_format_in_rfc3339_keeps_instant() {
+        let seoul: Tz = "Asia/Seoul".parse().unwrap();
+        assert_eq!(format_in("2024-05-01T05:00:00+02:00", seoul), "2024-05-01T12:00:00+09:00");
+        assert_eq!(format_in("not a date", seoul), "not a date");
+    }
+
+    #[test]
+    fn test_parse_timezone() {
+        assert!(parse_timezone("Europe/Berlin").is_ok());
+        assert!(parse_timezone(" UTC ").is_ok());
+        assert!(parse_timezone("Mars/Olympus").is_err());
+    }
+}
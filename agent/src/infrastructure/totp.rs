@@ -0,0 +1,93 @@
+use data_encoding::BASE32_NOPAD;
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+
+/// 코드 자릿수
+pub const TOTP_DIGITS: u32 = 6;
+
+/// 시간 간격 (초)
+pub const TOTP_STEP_SECS: i64 = 30;
+
+/// 시계 오차 허용 (앞뒤 간격 수)
+const TOTP_SKEW: i64 = 1;
+
+/// otpauth URI의 발급자 이름
+pub const TOTP_ISSUER: &str = "EasyCICD";
+
+/// 새 TOTP 비밀 (20바이트, Base32)
+pub fn generate_totp_secret() -> String {
+    use rand::RngCore;
+    let mut bytes = [0u8; 20];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    BASE32_NOPAD.encode(&bytes)
+}
+
+/// 주어진 간격의 코드 (RFC 6238, HMAC-SHA1)
+fn code_at(key: &[u8], step: i64) -> u32 {
+    let mut mac = Hmac::<Sha1>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(&step.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([hash[offset] & 0x7f, hash[offset + 1], hash[offset + 2], hash[offset + 3]]);
+    binary % 10u32.pow(TOTP_DIGITS)
+}
+
+/// 코드가 맞으면 일치한 간격 번호 (재사용 방지에 사용). 비밀이 잘못되면 None
+pub fn verify_totp(secret: &str, code: &str, unix_secs: i64) -> Option<i64> {
+    let code = code.trim().replace(' ', "");
+    if code.len() != TOTP_DIGITS as usize || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let code: u32 = code.parse().ok()?;
+    let key = BASE32_NOPAD.decode(secret.trim_end_matches('=').as_bytes()).ok()?;
+    let current = unix_secs.div_euclid(TOTP_STEP_SECS);
+    (current - TOTP_SKEW..=current + TOTP_SKEW).find(|step| code_at(&key, *step) == code)
+}
+
+/// URI 구성 요소 퍼센트 인코딩 (RFC 3986 unreserved 외 모두)
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// 인증 앱 등록용 otpauth URI (QR 코드로 표시)
+pub fn otpauth_uri(account: &str, secret: &str) -> String {
+    format!(
+        "otpauth://totp/{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+        percent_encode(&format!("{}:{}", TOTP_ISSUER, account)),
+        secret,
+        percent_encode(TOTP_ISSUER),
+        TOTP_DIGITS,
+        TOTP_STEP_SECS
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_totp_rfc6238_vectors() {
+        // RFC 6238 부록 B의 SHA1 비밀 "12345678901234567890" (8자리 값의 뒤 6자리)
+        let secret = BASE32_NOPAD.encode(b"12345678901234567890");
+        assert_eq!(verify_totp(&secret, "287082", 59), Some(1));
+        assert_eq!(verify_totp(&secret, "081804", 1111111109), Some(37037036));
+        assert_eq!(verify_totp(&secret, "005924", 1234567890), Some(41152263));
+
+        // 앞뒤 한 간격까지 허용
+        assert_eq!(verify_totp(&secret, "287082", 59 + TOTP_STEP_SECS), Some(1));
+        assert_eq!(verify_totp(&secret, "287082", 59 + 2 * TOTP_STEP_SECS), None);
+        assert_eq!(verify_totp(&secret, "28708", 59), None);
+        assert_eq!(verify_totp("not base32!", "287082", 59), None);
+
+        let generated = generate_totp_secret();
+        assert_eq!(generated.len(), 32);
+        assert!(otpauth_uri("dev@example.com", &generated)
+            .starts_with("otpauth://totp/EasyCICD%3Adev%40example.com?secret="));
+    }
+}
@@ -4,9 +4,12 @@ mod state;
 mod docker;
 mod build;
 mod api;
+mod grpc;
 mod proxy;
 mod ws_broadcaster;
 mod github;
+mod gitlab;
+mod bitbucket;
 mod application;
 mod infrastructure;
 mod workers;
@@ -22,14 +25,18 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, Layer};
 
 use sqlx::SqlitePool;
 use state::AppContext;
-use build::run_build_worker;
-use api::{api_routes, admin_routes, github_webhook, ws_handler, auth_routes};
-use api::middleware::require_auth;
+use build::{resume_build_queue, run_build_worker};
+use api::{api_routes, admin_routes, github_webhook, gitlab_webhook, bitbucket_webhook, ws_handler, auth_routes, chatops_routes};
+use api::middleware::{limit_login_attempts, require_auth, require_project_permission};
 use proxy::run_reverse_proxy;
 use ws_broadcaster::run_ws_broadcaster;
 use docker::DockerClient;
-use application::ports::repositories::ProjectRepository;
-use infrastructure::notifications::discord_notifier;
+use application::ports::repositories::{ProjectRepository, SettingsRepository};
+use infrastructure::notifications::{discord_notifier, slack_notifier};
+use infrastructure::plugins;
+use infrastructure::event_sink;
+use infrastructure::failure_diagnosis;
+use infrastructure::log_shipping;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -105,6 +112,7 @@ async fn main() -> Result<()> {
 
     info!("Running database migrations");
     sqlx::migrate!("./migrations").run(&pool).await?;
+    infrastructure::timezone::migrate_local_build_timestamps(&pool).await?;
 
     // Initialize webhook secret (generate if not exists)
     let webhook_secret: Option<String> = sqlx::query_scalar(
@@ -114,12 +122,7 @@ async fn main() -> Result<()> {
     .await?;
 
     if webhook_secret.is_none() {
-        use rand::Rng;
-        let secret: String = rand::thread_rng()
-            .sample_iter(&rand::distributions::Alphanumeric)
-            .take(64)
-            .map(char::from)
-            .collect();
+        let secret = api::generate_webhook_secret();
         sqlx::query("INSERT INTO settings (key, value) VALUES ('webhook_secret', ?)")
             .bind(&secret)
             .execute(&pool)
@@ -129,6 +132,13 @@ async fn main() -> Result<()> {
 
     // Initialize Docker client to get gateway IP
     let docker = DockerClient::new_with_host_path_detection().await?;
+    // 런타임 컨테이너가 붙을 네트워크 (compose 밖에서 실행하거나 DOCKER_NETWORK로 이름을 바꾼 경우)
+    if let Err(e) = docker.ensure_network().await {
+        tracing::warn!("Docker network {} is not available: {}", docker.network(), e);
+    }
+    if let Some(mirror) = docker.registry_mirror() {
+        info!("Pulling Docker Hub images through registry mirror {}", mirror);
+    }
     let gateway_ip = docker.gateway_ip().to_string();
 
     // Load base domain from database (defaults to albl.cloud)
@@ -146,33 +156,70 @@ async fn main() -> Result<()> {
 
     info!("Application context initialized");
 
+    infrastructure::timezone::load_display_timezone(context.settings_repo.as_ref()).await;
+
+    // HTTPS 설정/저장된 인증서 로드
+    let tls_enabled = context.settings_repo.get(proxy::tls::TLS_ENABLED_SETTING).await?.as_deref() == Some("true");
+    context.tls.set_enabled(tls_enabled);
+    let loaded = context.tls.load_from_disk().await;
+    info!("TLS {} ({} certificates loaded)", if tls_enabled { "enabled" } else { "disabled" }, loaded);
+
+    if let Err(e) = context.image_profiles.load(context.settings_repo.as_ref()).await {
+        tracing::warn!("Failed to load image profiles, using defaults: {}", e);
+    }
+    if let Err(e) = context.log_levels.load(context.settings_repo.as_ref()).await {
+        tracing::warn!("Failed to load log level patterns, using defaults: {}", e);
+    }
+
     // Synchronize container states with database on startup
     info!("Synchronizing container states...");
     synchronize_container_states(&context, &docker).await?;
     info!("Container state synchronization complete");
 
+    // 재시작 전 Queued 빌드를 다시 큐에 넣고, 실행 중이던 빌드는 중단 처리
+    match resume_build_queue(&context).await {
+        Ok((0, 0)) => {}
+        Ok((resumed, interrupted)) => info!("Build queue restored: {} queued builds resumed, {} interrupted builds failed", resumed, interrupted),
+        Err(e) => error!("Failed to restore build queue: {}", e),
+    }
+
     // Log OAuth config status
     if context.oauth_config.is_some() {
         info!("Google OAuth2 configured");
     } else {
         info!("Google OAuth2 not configured (GOOGLE_CLIENT_ID not set)");
     }
+    if context.github_oauth_config.is_some() {
+        info!("GitHub OAuth2 configured");
+    } else {
+        info!("GitHub OAuth2 not configured (GITHUB_CLIENT_ID not set)");
+    }
+    if context.login_guard.trusted_proxies().is_empty() {
+        info!("No trusted proxies (TRUSTED_PROXIES not set), ignoring X-Forwarded-For");
+    }
 
     // Build API server routes
     let app = Router::new()
         // Webhook (no auth required - GitHub sends requests)
         .route("/webhook/github", post(github_webhook))
+        .route("/webhook/gitlab", post(gitlab_webhook))
+        .route("/webhook/bitbucket", post(bitbucket_webhook))
+        // Slack/Discord slash command (no auth required - 서명으로 검증)
+        .nest("/chatops", chatops_routes())
         // WebSocket (auth checked via session in handler if needed)
         .route("/ws", get(ws_handler))
         // Auth routes (no auth required)
-        .nest("/auth", auth_routes())
+        .nest("/auth", auth_routes()
+            .layer(middleware::from_fn_with_state(context.clone(), limit_login_attempts)))
         // Admin routes (requires authentication)
         // 초기 설정: 화이트리스트가 비어있으면 모든 Google 계정으로 로그인 가능.
         // 관리자가 첫 로그인 후 인증된 상태로 이메일을 추가하면 됨.
         .nest("/admin", admin_routes()
             .layer(middleware::from_fn_with_state(context.clone(), require_auth)))
         // Protected API routes (auth middleware applied)
+        // 프로젝트별 권한은 인증 이후에 확인 (나중에 추가한 layer가 먼저 실행됨)
         .nest("/api", api_routes()
+            .layer(middleware::from_fn_with_state(context.clone(), require_project_permission))
             .layer(middleware::from_fn_with_state(context.clone(), require_auth)))
         // Serve static files from /app/frontend directory
         .fallback_service(ServeDir::new("frontend"))
@@ -188,7 +235,8 @@ async fn main() -> Result<()> {
                 .await
                 .expect("Failed to bind port 3000");
             info!("API server listening on 0.0.0.0:3000");
-            if let Err(e) = axum::serve(listener, app).await {
+            // 로그인 시도 제한에 클라이언트 주소 사용
+            if let Err(e) = axum::serve(listener, app.into_make_service_with_connect_info::<std::net::SocketAddr>()).await {
                 error!("API server failed: {}", e);
             }
         }
@@ -256,14 +304,34 @@ async fn main() -> Result<()> {
 
     // Start Session Cleanup worker
     let session_cleanup = tokio::spawn({
-        let session_repo = context.session_repo.clone();
+        let context = context.clone();
         async move {
-            if let Err(e) = workers::run_session_cleanup(session_repo).await {
+            if let Err(e) = workers::run_session_cleanup(context).await {
                 tracing::error!("Session cleanup worker error: {}", e);
             }
         }
     });
 
+    // Start Project Purge worker
+    let project_purge = tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = workers::run_project_purge(context).await {
+                tracing::error!("Project purge worker error: {}", e);
+            }
+        }
+    });
+
+    // Start Deploy Window Release worker
+    let deploy_window_release = tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = workers::run_deploy_window_release(context).await {
+                tracing::error!("Deploy window release worker error: {}", e);
+            }
+        }
+    });
+
     // Start Container Health Monitor worker
     let container_health_monitor = tokio::spawn({
         let context = context.clone();
@@ -274,6 +342,26 @@ async fn main() -> Result<()> {
         }
     });
 
+    // Start Metrics collector worker
+    let metrics_collector = tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = workers::run_metrics_collector(context).await {
+                tracing::error!("Metrics collector error: {}", e);
+            }
+        }
+    });
+
+    // Start Cache eviction worker
+    let cache_eviction = tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = workers::run_cache_eviction(context).await {
+                tracing::error!("Cache eviction worker error: {}", e);
+            }
+        }
+    });
+
     // Start Discord Notifier worker
     let discord_notifier = tokio::spawn({
         let webhook_repo = context.discord_webhook_repo.clone();
@@ -297,6 +385,228 @@ async fn main() -> Result<()> {
         }
     });
 
+    // Start Slack Notifier worker
+    let slack_notifier = tokio::spawn({
+        let webhook_repo = context.slack_webhook_repo.clone();
+        let build_repo = context.build_repo.clone();
+        let project_repo = context.project_repo.clone();
+        let event_rx = context.subscribe_events();
+        let base_url = std::env::var("BASE_URL").ok();
+
+        async move {
+            if let Err(e) = slack_notifier::run_slack_notifier(
+                webhook_repo,
+                build_repo,
+                project_repo,
+                event_rx,
+                base_url,
+            )
+            .await
+            {
+                tracing::error!("Slack notifier error: {}", e);
+            }
+        }
+    });
+
+    // Start email notifier (SMTP 설정이 있을 때만 전송)
+    let email_notifier = tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = workers::run_email_notifier(context).await {
+                tracing::error!("Email notifier error: {}", e);
+            }
+        }
+    });
+
+    // Start outbound webhook dispatcher (프로젝트별 등록된 endpoint로 이벤트 전송)
+    let outbound_webhooks = tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = workers::run_outbound_webhooks(context).await {
+                tracing::error!("Outbound webhook dispatcher error: {}", e);
+            }
+        }
+    });
+
+    // Start GitHub team sync (github_team_sync 설정이 있을 때만 동기화)
+    let github_team_sync = tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = workers::run_github_team_sync(context).await {
+                tracing::error!("GitHub team sync error: {}", e);
+            }
+        }
+    });
+
+    // Start GitHub commit status reporter
+    let github_status_reporter = tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = workers::run_github_status_reporter(context).await {
+                tracing::error!("GitHub commit status reporter error: {}", e);
+            }
+        }
+    });
+
+    // Start base image update check worker
+    let image_update_check = tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = workers::run_image_update_check(context).await {
+                tracing::error!("Image update check worker error: {}", e);
+            }
+        }
+    });
+
+    // Start queue wait monitor
+    let queue_wait_monitor = tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = workers::run_queue_wait_monitor(context).await {
+                tracing::error!("Queue wait monitor error: {}", e);
+            }
+        }
+    });
+
+    // Start stale build watchdog
+    let stale_build_watchdog = tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = workers::run_stale_build_watchdog(context).await {
+                tracing::error!("Stale build watchdog error: {}", e);
+            }
+        }
+    });
+
+    // Start build retention worker
+    let build_retention = tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = workers::run_build_retention(context).await {
+                tracing::error!("Build retention worker error: {}", e);
+            }
+        }
+    });
+
+    // Start build progress monitor
+    let build_progress_monitor = tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = workers::run_build_progress_monitor(context).await {
+                tracing::error!("Build progress monitor error: {}", e);
+            }
+        }
+    });
+
+    // Start canary monitor
+    let canary_monitor = tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = workers::run_canary_monitor(context).await {
+                tracing::error!("Canary monitor error: {}", e);
+            }
+        }
+    });
+
+    // Start certificate renewal worker (ACME)
+    let cert_renewal = tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = workers::run_cert_renewal(context).await {
+                tracing::error!("Certificate renewal worker error: {}", e);
+            }
+        }
+    });
+
+    // Start Plugin host worker
+    let plugin_host = tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = plugins::run_plugin_host(context).await {
+                tracing::error!("Plugin host error: {}", e);
+            }
+        }
+    });
+
+    // Start Build log shipper (LOG_SHIPPING_URL이 설정된 경우에만)
+    let build_log_shipper = tokio::spawn({
+        let context = context.clone();
+        async move {
+            match log_shipping::LogShippingConfig::from_env() {
+                Some(Ok(config)) => {
+                    if let Err(e) = workers::run_build_log_shipper(config, context).await {
+                        tracing::error!("Build log shipper error: {}", e);
+                    }
+                }
+                Some(Err(e)) => {
+                    tracing::error!("Build log shipping disabled, invalid configuration: {}", e);
+                    std::future::pending::<()>().await;
+                }
+                None => std::future::pending::<()>().await,
+            }
+        }
+    });
+
+    // Start Failure diagnosis worker (FAILURE_DIAGNOSIS_URL이 설정된 경우에만)
+    let failure_diagnosis = tokio::spawn({
+        let context = context.clone();
+        async move {
+            match failure_diagnosis::FailureDiagnosisConfig::from_env() {
+                Some(Ok(config)) => {
+                    if let Err(e) = workers::run_failure_diagnosis(config, context).await {
+                        tracing::error!("Failure diagnosis worker error: {}", e);
+                    }
+                }
+                Some(Err(e)) => {
+                    tracing::error!("Failure diagnosis disabled, invalid configuration: {}", e);
+                    std::future::pending::<()>().await;
+                }
+                None => std::future::pending::<()>().await,
+            }
+        }
+    });
+
+    // Start Event sink worker (EVENT_SINK_URL이 설정된 경우에만)
+    let event_sink = tokio::spawn({
+        let event_rx = context.subscribe_events();
+        async move {
+            match event_sink::EventSinkConfig::from_env() {
+                Some(Ok(config)) => {
+                    if let Err(e) = event_sink::run_event_sink(config, event_rx).await {
+                        tracing::error!("Event sink error: {}", e);
+                    }
+                }
+                Some(Err(e)) => {
+                    tracing::error!("Event sink disabled, invalid configuration: {}", e);
+                    std::future::pending::<()>().await;
+                }
+                None => {
+                    drop(event_rx);
+                    std::future::pending::<()>().await;
+                }
+            }
+        }
+    });
+
+    // Start gRPC management API (GRPC_AUTH_TOKEN이 설정된 경우에만)
+    let grpc_server = tokio::spawn({
+        let context = context.clone();
+        async move {
+            match grpc::GrpcConfig::from_env() {
+                Some(Ok(config)) => {
+                    if let Err(e) = grpc::run_grpc_server(context, config).await {
+                        tracing::error!("gRPC server error: {}", e);
+                    }
+                }
+                Some(Err(e)) => {
+                    tracing::error!("gRPC server disabled, invalid configuration: {}", e);
+                    std::future::pending::<()>().await;
+                }
+                None => std::future::pending::<()>().await,
+            }
+        }
+    });
+
     info!("All services started successfully");
 
     // Keep the application running
@@ -328,12 +638,75 @@ async fn main() -> Result<()> {
         _ = session_cleanup => {
             info!("Session cleanup worker stopped");
         }
+        _ = project_purge => {
+            info!("Project purge worker stopped");
+        }
+        _ = deploy_window_release => {
+            info!("Deploy window release worker stopped");
+        }
         _ = container_health_monitor => {
             info!("Container health monitor stopped");
         }
+        _ = metrics_collector => {
+            info!("Metrics collector stopped");
+        }
+        _ = cache_eviction => {
+            info!("Cache eviction worker stopped");
+        }
+        _ = github_status_reporter => {
+            info!("GitHub commit status reporter stopped");
+        }
+        _ = image_update_check => {
+            info!("Image update check worker stopped");
+        }
+        _ = queue_wait_monitor => {
+            info!("Queue wait monitor stopped");
+        }
+        _ = stale_build_watchdog => {
+            info!("Stale build watchdog stopped");
+        }
+        _ = build_retention => {
+            info!("Build retention worker stopped");
+        }
+        _ = build_progress_monitor => {
+            info!("Build progress monitor stopped");
+        }
+        _ = canary_monitor => {
+            info!("Canary monitor stopped");
+        }
+        _ = cert_renewal => {
+            info!("Certificate renewal worker stopped");
+        }
         _ = discord_notifier => {
             info!("Discord notifier stopped");
         }
+        _ = slack_notifier => {
+            info!("Slack notifier stopped");
+        }
+        _ = email_notifier => {
+            info!("Email notifier stopped");
+        }
+        _ = outbound_webhooks => {
+            info!("Outbound webhook dispatcher stopped");
+        }
+        _ = github_team_sync => {
+            info!("GitHub team sync stopped");
+        }
+        _ = plugin_host => {
+            info!("Plugin host stopped");
+        }
+        _ = event_sink => {
+            info!("Event sink stopped");
+        }
+        _ = build_log_shipper => {
+            info!("Build log shipper stopped");
+        }
+        _ = failure_diagnosis => {
+            info!("Failure diagnosis worker stopped");
+        }
+        _ = grpc_server => {
+            info!("gRPC server stopped");
+        }
     }
 
     info!("Shutting down...");
@@ -345,7 +718,7 @@ async fn main() -> Result<()> {
 /// 1. Removes container IDs from DB if containers don't exist
 /// 2. Removes orphan containers (containers without matching projects in DB)
 async fn synchronize_container_states(context: &AppContext, docker: &DockerClient) -> Result<()> {
-    let projects: Vec<crate::db::models::Project> = context.project_repo.list().await?;
+    let projects: Vec<crate::db::models::Project> = context.project_repo.list_with_deleted().await?;
 
     // Step 1: Check DB projects and clean up dead containers from DB
     for project in &projects {
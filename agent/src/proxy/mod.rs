@@ -1,3 +1,6 @@
 mod router;
+pub mod routes;
+pub mod stats;
+pub mod tls;
 
 pub use router::run_reverse_proxy;
@@ -7,15 +7,31 @@ use hyper_util::rt::TokioIo;
 use http_body_util::{BodyExt, Full};
 use hyper::body::Bytes;
 use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::net::TcpListener;
-use tracing::{info, warn};
+use tokio::sync::oneshot;
+use tokio_rustls::TlsAcceptor;
+use tracing::{debug, info, warn};
 use uuid::Uuid;
 
-use crate::db::models::{Slot, ContainerStatus};
+use crate::application::services::canary_traffic::CanarySession;
+use crate::application::services::traffic_shadow::{ShadowSample, ShadowSession};
+use crate::db::models::ContainerStatus;
+use crate::infrastructure::database::PreviewStatus;
+use super::routes::{container_target, parse_preview_subdomain, preview_target, project_target};
+use super::stats::RouteKey;
 use crate::state::AppContext;
 use crate::application::ports::repositories::{ProjectRepository, ContainerRepository};
 use crate::infrastructure::logging::{TraceContext, Timer};
 
+/// HTTPS 리스너 포트 (compose에서 443으로 매핑)
+const HTTPS_PORT: u16 = 8443;
+const ACME_CHALLENGE_PREFIX: &str = "/.well-known/acme-challenge/";
+
+/// 섀도 요청 타임아웃 (느린 새 슬롯이 task를 오래 붙잡지 않도록)
+const SHADOW_TIMEOUT: Duration = Duration::from_secs(10);
+
 // Helper to create error responses safely
 fn error_response(status: StatusCode, message: &str) -> Result<Response<Full<Bytes>>, hyper::Error> {
     match Response::builder()
@@ -38,6 +54,15 @@ pub async fn run_reverse_proxy(context: AppContext) -> Result<()> {
 
     info!("Reverse proxy listening on {}", addr);
 
+    tokio::spawn({
+        let context = context.clone();
+        async move {
+            if let Err(e) = run_https_proxy(context).await {
+                warn!("HTTPS reverse proxy error: {}", e);
+            }
+        }
+    });
+
     loop {
         let (stream, _) = listener.accept().await?;
         let io = TokioIo::new(stream);
@@ -60,11 +85,59 @@ pub async fn run_reverse_proxy(context: AppContext) -> Result<()> {
     }
 }
 
+/// HTTPS 리버스 프록시 (8443). 설정에서 TLS를 켠 동안만 연결을 받고, SNI에 맞는 ACME 인증서로 종료
+async fn run_https_proxy(context: AppContext) -> Result<()> {
+    let addr = SocketAddr::from(([0, 0, 0, 0], HTTPS_PORT));
+    let listener = TcpListener::bind(addr).await?;
+    let acceptor = TlsAcceptor::from(context.tls.server_config()?);
+
+    info!("HTTPS reverse proxy listening on {}", addr);
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        if !context.tls.enabled() {
+            continue;
+        }
+        let acceptor = acceptor.clone();
+        let ctx = context.clone();
+
+        tokio::spawn(async move {
+            let stream = match acceptor.accept(stream).await {
+                Ok(stream) => stream,
+                Err(err) => {
+                    debug!("TLS handshake failed: {}", err);
+                    return;
+                }
+            };
+            if let Err(err) = http1::Builder::new()
+                .serve_connection(
+                    TokioIo::new(stream),
+                    service_fn(move |req| {
+                        let ctx = ctx.clone();
+                        async move { handle_request(req, ctx).await }
+                    }),
+                )
+                .await
+            {
+                warn!("Error serving TLS connection: {:?}", err);
+            }
+        });
+    }
+}
+
 async fn handle_request(
     mut req: Request<Incoming>,
     ctx: AppContext,
 ) -> Result<Response<Full<Bytes>>, hyper::Error> {
     let path = req.uri().path().to_string();
+
+    // ACME HTTP-01 챌린지 (발급 중인 토큰만 응답)
+    if let Some(token) = path.strip_prefix(ACME_CHALLENGE_PREFIX) {
+        return match ctx.tls.challenges.key_authorization(token) {
+            Some(key_authorization) => Ok(Response::new(Full::new(Bytes::from(key_authorization)))),
+            None => error_response(StatusCode::NOT_FOUND, "Not found"),
+        };
+    }
     let method = req.method().clone();
     let headers = req.headers().clone();
 
@@ -78,7 +151,8 @@ async fn handle_request(
 
     // Check if this is a subdomain request (e.g., sermo-back-app.albl.cloud)
     // Subdomain requests should go to the target project/container, not internal API
-    let is_subdomain_request = if let Some(ref base_domain) = ctx.base_domain {
+    let base_domain = ctx.base_domain();
+    let is_subdomain_request = if let Some(ref base_domain) = base_domain {
         let hostname = host_header.split(':').next().unwrap_or(host_header);
         let domain_suffix = format!(".{}", base_domain);
         hostname.ends_with(&domain_suffix)
@@ -111,6 +185,8 @@ async fn handle_request(
     // Routing result: either Project or Container
     enum RouteTarget {
         Project { name: String, is_subdomain: bool },
+        /// PR 프리뷰 (서브도메인 전용)
+        Preview { name: String, pr_number: i64 },
         Container { name: String, is_subdomain: bool },
     }
 
@@ -121,14 +197,19 @@ async fn handle_request(
             let hostname = host_str.split(':').next().unwrap_or(host_str);
 
             // Check if subdomain routing should be used
-            if let Some(ref base_domain) = ctx.base_domain {
+            if let Some(ref base_domain) = base_domain {
                 let domain_suffix = format!(".{}", base_domain);
 
                 if hostname.ends_with(&domain_suffix) {
                     let subdomain = hostname.trim_end_matches(&domain_suffix);
 
+                    // PR 프리뷰: pr-{number}.{name}
+                    if let Some((project_name, pr_number)) = parse_preview_subdomain(subdomain) {
+                        info!("Subdomain routing: {} -> project '{}' PR #{}", hostname, project_name, pr_number);
+                        RouteTarget::Preview { name: project_name.to_string(), pr_number }
+                    }
                     // Check for project pattern: {name}-app
-                    if subdomain.ends_with("-app") {
+                    else if subdomain.ends_with("-app") {
                         let project_name = subdomain.trim_end_matches("-app");
                         info!("Subdomain routing: {} -> project '{}'", hostname, project_name);
                         RouteTarget::Project { name: project_name.to_string(), is_subdomain: true }
@@ -168,7 +249,10 @@ async fn handle_request(
     };
 
     // Route to target (either project or standalone container)
-    let (target_container_name, target_port, is_subdomain_routing) = match route_target {
+    // 라우트별 지연 시간/5xx 집계 (Host 포트 제외)
+    let route_host = host_header.split(':').next().unwrap_or(host_header).to_ascii_lowercase();
+
+    let (target_container_name, target_port, is_subdomain_routing, shadow, route_key, canary) = match route_target {
         RouteTarget::Project { name: project_name, is_subdomain } => {
             // Get project from database
             info!("[{}] Routing request → project: '{}'", trace_id, project_name);
@@ -191,13 +275,83 @@ async fn handle_request(
                 }
             };
 
+            // 내부 전용 프로젝트는 외부에 노출하지 않음 (존재 여부도 알리지 않도록 404)
+            if project.internal_only {
+                warn!("[{}] Project {} is internal-only, not routing", trace_id, project_name);
+                ctx.logger.api_exit(&trace_id, method.as_str(), &format!("PROXY {}", path), timer.elapsed_ms(), 404);
+                return error_response(StatusCode::NOT_FOUND, "Project not found");
+            }
+
             // Determine container name and internal port based on active slot
-            let container_name = match project.active_slot {
-                Slot::Blue => format!("project-{}-blue", project.id),
-                Slot::Green => format!("project-{}-green", project.id),
+            let (container_name, target_port) = project_target(&project);
+
+            // 카나리 배포 중이면 가중치만큼 새 슬롯으로 보내고, 응답을 슬롯별로 기록
+            let canary: Option<(Arc<CanarySession>, bool)> = ctx.deployment_service.canary_session(&project).map(|session| {
+                let to_canary = session.should_route_to_canary();
+                (session, to_canary)
+            });
+            let (container_name, target_port) = match &canary {
+                Some((session, true)) => (session.target.clone(), session.port),
+                _ => (container_name, target_port),
             };
 
-            (container_name, project.runtime_port, is_subdomain)
+            // 배포 전 트래픽 섀도잉 중이면 안전한(부작용 없는) 요청 일부를 새 슬롯으로 복제
+            let shadow = ctx
+                .shadow_traffic
+                .session(project.id)
+                .filter(|_| matches!(method, Method::GET | Method::HEAD | Method::OPTIONS))
+                .filter(|session| session.should_mirror());
+
+            let route_key = RouteKey { project_id: Some(project.id), target: project.name.clone(), host: route_host };
+
+            (container_name, target_port, is_subdomain, shadow, route_key, canary)
+        }
+
+        RouteTarget::Preview { name: project_name, pr_number } => {
+            info!("[{}] Routing request → project: '{}' PR #{}", trace_id, project_name, pr_number);
+            ctx.logger.repo_call(&trace_id, "Proxy", "ProjectRepo", "get_by_name");
+
+            // 내부 전용이거나 프리뷰를 끈 프로젝트는 존재 여부도 알리지 않도록 404
+            let project = match ctx.project_repo.get_by_name(&project_name).await {
+                Ok(Some(p)) if p.pr_previews && !p.internal_only => p,
+                Ok(_) => {
+                    ctx.logger.api_exit(&trace_id, method.as_str(), &format!("PROXY {}", path), timer.elapsed_ms(), 404);
+                    return error_response(StatusCode::NOT_FOUND, "Preview not found");
+                }
+                Err(e) => {
+                    warn!("[{}] Failed to get project {}: {}", trace_id, project_name, e);
+                    ctx.logger.api_exit(&trace_id, method.as_str(), &format!("PROXY {}", path), timer.elapsed_ms(), 500);
+                    return error_response(StatusCode::INTERNAL_SERVER_ERROR, "Internal error");
+                }
+            };
+
+            ctx.logger.repo_call(&trace_id, "Proxy", "PreviewRepo", "get");
+            match ctx.preview_repo.get(project.id, pr_number).await {
+                Ok(Some(preview)) if preview.status == PreviewStatus::Running.to_string() => {}
+                Ok(Some(preview)) => {
+                    warn!("[{}] Preview PR #{} of {} is not running (status: {})", trace_id, pr_number, project_name, preview.status);
+                    ctx.logger.api_exit(&trace_id, method.as_str(), &format!("PROXY {}", path), timer.elapsed_ms(), 503);
+                    return error_response(StatusCode::SERVICE_UNAVAILABLE, "Preview is not running");
+                }
+                Ok(None) => {
+                    ctx.logger.api_exit(&trace_id, method.as_str(), &format!("PROXY {}", path), timer.elapsed_ms(), 404);
+                    return error_response(StatusCode::NOT_FOUND, "Preview not found");
+                }
+                Err(e) => {
+                    warn!("[{}] Failed to get preview PR #{} of {}: {}", trace_id, pr_number, project_name, e);
+                    ctx.logger.api_exit(&trace_id, method.as_str(), &format!("PROXY {}", path), timer.elapsed_ms(), 500);
+                    return error_response(StatusCode::INTERNAL_SERVER_ERROR, "Internal error");
+                }
+            }
+
+            let (container_name, target_port) = preview_target(&project, pr_number);
+            let route_key = RouteKey {
+                project_id: Some(project.id),
+                target: format!("{} PR #{}", project.name, pr_number),
+                host: route_host,
+            };
+
+            (container_name, target_port, true, None, route_key, None)
         }
 
         RouteTarget::Container { name: container_name, is_subdomain } => {
@@ -229,13 +383,12 @@ async fn handle_request(
                 return error_response(StatusCode::SERVICE_UNAVAILABLE, "Container is not running");
             }
 
-            // Use the actual Docker container name format: container-{name}
-            let docker_container_name = format!("container-{}", container.name);
+            // Docker container name (container-{name}), container_port if specified, otherwise port
+            let (docker_container_name, target_port) = container_target(&container);
 
-            // Use container_port if specified, otherwise use port
-            let target_port = container.container_port.unwrap_or(container.port);
+            let route_key = RouteKey { project_id: None, target: container.name.clone(), host: route_host };
 
-            (docker_container_name, target_port, is_subdomain)
+            (docker_container_name, target_port, is_subdomain, None, route_key, None)
         }
     };
 
@@ -253,11 +406,11 @@ async fn handle_request(
     };
 
     // Preserve query string - route directly to container by name on the same Docker network
-    let target_uri = if let Some(query) = req.uri().query() {
-        format!("http://{}:{}{}?{}", target_container_name, target_port, target_path, query)
-    } else {
-        format!("http://{}:{}{}", target_container_name, target_port, target_path)
+    let path_and_query = match req.uri().query() {
+        Some(query) => format!("{}?{}", target_path, query),
+        None => target_path,
     };
+    let target_uri = format!("http://{}:{}{}", target_container_name, target_port, path_and_query);
 
     info!(
         "Proxying {} {} -> {}",
@@ -299,27 +452,47 @@ async fn handle_request(
         }
     };
 
-    // Build request with headers
-    let mut req_builder = client.request(reqwest_method, &target_uri);
-
     // Copy headers except Host and content-length
-    for (name, value) in headers.iter() {
-        if name != "host" && name != "content-length" {
-            if let Ok(value_str) = value.to_str() {
-                req_builder = req_builder.header(name.as_str(), value_str);
-            }
-        }
-    }
+    let mut forward_headers: Vec<(String, String)> = headers
+        .iter()
+        .filter(|(name, _)| *name != "host" && *name != "content-length")
+        .filter_map(|(name, value)| value.to_str().ok().map(|v| (name.as_str().to_string(), v.to_string())))
+        .collect();
 
     // Add proxy headers for OAuth and proper URL generation
     let original_host = headers.get("host").and_then(|h| h.to_str().ok()).unwrap_or("");
-    req_builder = req_builder
-        .header("X-Forwarded-Host", original_host)
-        .header("X-Forwarded-Proto", "https")
-        .header("X-Real-IP", headers.get("x-forwarded-for").and_then(|h| h.to_str().ok()).unwrap_or(""));
+    forward_headers.push(("X-Forwarded-Host".to_string(), original_host.to_string()));
+    forward_headers.push(("X-Forwarded-Proto".to_string(), "https".to_string()));
+    forward_headers.push((
+        "X-Real-IP".to_string(),
+        headers.get("x-forwarded-for").and_then(|h| h.to_str().ok()).unwrap_or("").to_string(),
+    ));
+
+    // 섀도 요청은 응답을 기다리지 않음 (운영 응답 결과를 받아 비교만 기록)
+    let primary_result = shadow.map(|session| {
+        let (tx, rx) = oneshot::channel();
+        let shadow_uri = format!("http://{}:{}{}", session.target, session.port, path_and_query);
+        tokio::spawn(mirror_to_shadow(
+            client.clone(),
+            session,
+            reqwest_method.clone(),
+            shadow_uri,
+            forward_headers.clone(),
+            body_bytes.clone(),
+            rx,
+        ));
+        tx
+    });
+
+    // Build request with headers
+    let mut req_builder = client.request(reqwest_method, &target_uri);
+    for (name, value) in &forward_headers {
+        req_builder = req_builder.header(name.as_str(), value.as_str());
+    }
 
     info!("[{}] Forwarding to backend: {}", trace_id, target_uri);
 
+    let primary_started = Instant::now();
     let response = match req_builder
         .body(body_bytes.to_vec())
         .send()
@@ -328,10 +501,20 @@ async fn handle_request(
         Ok(res) => res,
         Err(e) => {
             warn!("[{}] Backend request failed: {}", trace_id, e);
+            if let Some(tx) = primary_result {
+                let _ = tx.send((StatusCode::BAD_GATEWAY.as_u16(), primary_started.elapsed().as_millis() as u64));
+            }
+            if let Some((session, to_canary)) = &canary {
+                session.record(*to_canary, 502);
+            }
+            ctx.proxy_stats.record(route_key, 502, timer.elapsed_ms() as u64);
             ctx.logger.api_exit(&trace_id, method.as_str(), &format!("PROXY {}", path), timer.elapsed_ms(), 502);
             return error_response(StatusCode::BAD_GATEWAY, "Service unavailable");
         }
     };
+    if let Some(tx) = primary_result {
+        let _ = tx.send((response.status().as_u16(), primary_started.elapsed().as_millis() as u64));
+    }
 
     // Convert response
     let status = response.status();
@@ -342,6 +525,10 @@ async fn handle_request(
         Ok(b) => b,
         Err(e) => {
             warn!("[{}] Failed to read response body: {}", trace_id, e);
+            if let Some((session, to_canary)) = &canary {
+                session.record(*to_canary, 502);
+            }
+            ctx.proxy_stats.record(route_key, 502, timer.elapsed_ms() as u64);
             ctx.logger.api_exit(&trace_id, method.as_str(), &format!("PROXY {}", path), timer.elapsed_ms(), 502);
             return error_response(StatusCode::BAD_GATEWAY, "Error reading response");
         }
@@ -366,6 +553,10 @@ async fn handle_request(
             }
         }
     }
+    if let Some((session, to_canary)) = &canary {
+        session.record(*to_canary, status.as_u16());
+    }
+    ctx.proxy_stats.record(route_key, status.as_u16(), timer.elapsed_ms() as u64);
     ctx.logger.api_exit(&trace_id, method.as_str(), &format!("PROXY {}", path), timer.elapsed_ms(), status.as_u16());
 
     match response_builder.body(Full::new(body.clone())) {
@@ -378,6 +569,37 @@ async fn handle_request(
     }
 }
 
+/// 트래픽 섀도잉: 같은 요청을 새 슬롯으로 보내고 운영 응답과 비교해 세션에 기록 (응답 본문은 버림)
+async fn mirror_to_shadow(
+    client: reqwest::Client,
+    session: Arc<ShadowSession>,
+    method: reqwest::Method,
+    uri: String,
+    headers: Vec<(String, String)>,
+    body: Bytes,
+    primary: oneshot::Receiver<(u16, u64)>,
+) {
+    let mut req_builder = client.request(method, &uri).timeout(SHADOW_TIMEOUT);
+    for (name, value) in &headers {
+        req_builder = req_builder.header(name.as_str(), value.as_str());
+    }
+    req_builder = req_builder.header("X-EasyCICD-Shadow", "1");
+
+    let started = Instant::now();
+    let shadow_status = match req_builder.body(body.to_vec()).send().await {
+        Ok(response) => Some(response.status().as_u16()),
+        Err(e) => {
+            debug!("Shadow request {} failed: {}", uri, e);
+            None
+        }
+    };
+    let shadow_ms = started.elapsed().as_millis() as u64;
+
+    // 운영 요청이 끝나지 않고 취소되면 비교할 수 없으므로 기록하지 않음
+    let Ok((primary_status, primary_ms)) = primary.await else { return };
+    session.record(ShadowSample { primary_status, primary_ms, shadow_status, shadow_ms });
+}
+
 /// Proxy request to backend API server
 async fn proxy_to_backend(
     trace_id: &str,
@@ -0,0 +1,147 @@
+use serde::Serialize;
+
+use crate::db::models::{Container, ContainerStatus, Project, Slot};
+use crate::docker::DockerClient;
+use crate::infrastructure::database::{PreviewEnvironment, PreviewStatus};
+
+/// 프로젝트 요청이 전달되는 컨테이너 이름과 내부 포트 (활성 슬롯 기준)
+pub fn project_target(project: &Project) -> (String, i32) {
+    let container_name = match project.active_slot {
+        Slot::Blue => format!("project-{}-blue", project.id),
+        Slot::Green => format!("project-{}-green", project.id),
+    };
+    (container_name, project.runtime_port)
+}
+
+/// 독립 컨테이너 요청이 전달되는 Docker 컨테이너 이름과 포트 (container_port가 없으면 호스트 포트)
+pub fn container_target(container: &Container) -> (String, i32) {
+    (
+        format!("container-{}", container.name),
+        container.container_port.unwrap_or(container.port),
+    )
+}
+
+/// PR 프리뷰 요청이 전달되는 컨테이너 이름과 내부 포트
+pub fn preview_target(project: &Project, pr_number: i64) -> (String, i32) {
+    (DockerClient::preview_container_name(project.id, pr_number), project.runtime_port)
+}
+
+/// PR 프리뷰 서브도메인 호스트 (`pr-{number}.{name}.{base_domain}`)
+pub fn preview_host(base_domain: &str, project_name: &str, pr_number: i64) -> String {
+    format!("pr-{}.{}.{}", pr_number, project_name, base_domain)
+}
+
+/// base_domain을 뗀 서브도메인이 `pr-{number}.{name}`이면 (프로젝트 이름, PR 번호)
+pub fn parse_preview_subdomain(subdomain: &str) -> Option<(&str, i64)> {
+    let (pr, project_name) = subdomain.split_once('.')?;
+    let pr_number = pr.strip_prefix("pr-")?.parse().ok()?;
+    (!project_name.is_empty() && !project_name.contains('.')).then_some((project_name, pr_number))
+}
+
+/// 리버스 프록시 라우팅 한 항목
+#[derive(Debug, Clone, Serialize)]
+pub struct ProxyRoute {
+    /// "project", "preview" 또는 "container"
+    pub kind: &'static str,
+    pub id: i64,
+    pub name: String,
+    /// 서브도메인 라우팅 호스트 (base_domain이 설정된 경우)
+    pub host: Option<String>,
+    /// 경로 기반 라우팅 prefix (프로젝트만)
+    pub path_prefix: Option<String>,
+    pub active_slot: Option<Slot>,
+    /// 프록시가 요청을 보내는 주소 (Docker 네트워크 내부 "컨테이너이름:포트")
+    pub target: String,
+    /// 호스트에 노출된 포트 (프로젝트는 활성 슬롯 포트, 호스트 포트를 노출하지 않는 프로젝트는 None)
+    pub host_port: Option<i32>,
+    /// 라우팅 대상 컨테이너가 있는지 (없으면 프록시가 502/503 반환)
+    pub ready: bool,
+    pub status: String,
+}
+
+/// 현재 DB 상태로 프록시가 사용하는 라우팅 표를 만든다 (`proxy::router::handle_request`와 같은 규칙)
+///
+/// - 프로젝트: `{name}-app.{base_domain}` 또는 `/{name}/...` → 활성 슬롯 컨테이너 (내부 전용 프로젝트 제외)
+/// - PR 프리뷰: `pr-{number}.{name}.{base_domain}` → 프리뷰 컨테이너 (서브도메인 전용)
+/// - 독립 컨테이너: `{name}.{base_domain}` → 실행 중일 때만
+pub fn route_table(
+    base_domain: Option<&str>,
+    projects: &[Project],
+    previews: &[PreviewEnvironment],
+    containers: &[Container],
+) -> Vec<ProxyRoute> {
+    let mut routes = Vec::with_capacity(projects.len() + previews.len() + containers.len());
+
+    for project in projects.iter().filter(|p| !p.internal_only) {
+        let (container_name, port) = project_target(project);
+        let active_container = match project.active_slot {
+            Slot::Blue => &project.blue_container_id,
+            Slot::Green => &project.green_container_id,
+        };
+        routes.push(ProxyRoute {
+            kind: "project",
+            id: project.id,
+            name: project.name.clone(),
+            host: base_domain.map(|d| format!("{}-app.{}", project.name, d)),
+            path_prefix: Some(format!("/{}/", project.name)),
+            active_slot: Some(project.active_slot),
+            target: format!("{}:{}", container_name, port),
+            host_port: project.expose_host_port.then(|| project.get_active_port()),
+            ready: active_container.is_some() && project.archived_at.is_none(),
+            status: project.health_state().to_string(),
+        });
+    }
+
+    for preview in previews {
+        let Some(project) = projects.iter().find(|p| p.id == preview.project_id && p.pr_previews && !p.internal_only) else {
+            continue;
+        };
+        let (container_name, port) = preview_target(project, preview.pr_number);
+        routes.push(ProxyRoute {
+            kind: "preview",
+            id: preview.id,
+            name: format!("{} PR #{}", project.name, preview.pr_number),
+            host: base_domain.map(|d| preview_host(d, &project.name, preview.pr_number)),
+            path_prefix: None,
+            active_slot: None,
+            target: format!("{}:{}", container_name, port),
+            host_port: None,
+            ready: preview.status == PreviewStatus::Running.to_string(),
+            status: preview.status.clone(),
+        });
+    }
+
+    for container in containers {
+        let (container_name, port) = container_target(container);
+        routes.push(ProxyRoute {
+            kind: "container",
+            id: container.id,
+            name: container.name.clone(),
+            host: base_domain.map(|d| format!("{}.{}", container.name, d)),
+            path_prefix: None,
+            active_slot: None,
+            target: format!("{}:{}", container_name, port),
+            host_port: Some(container.port),
+            ready: container.status == ContainerStatus::Running,
+            status: container.status.to_string(),
+        });
+    }
+
+    routes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_preview_subdomain() {
+        assert_eq!(parse_preview_subdomain("pr-42.shop"), Some(("shop", 42)));
+        assert_eq!(preview_host("example.com", "shop", 42), "pr-42.shop.example.com");
+
+        assert_eq!(parse_preview_subdomain("shop-app"), None);
+        assert_eq!(parse_preview_subdomain("pr-x.shop"), None);
+        assert_eq!(parse_preview_subdomain("pr-42."), None);
+        assert_eq!(parse_preview_subdomain("pr-42.a.b"), None);
+    }
+}
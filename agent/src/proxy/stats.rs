@@ -0,0 +1,207 @@
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::application::services::traffic_shadow::percentile;
+
+/// 지연 시간/오류율 집계 구간 (최근 5분)
+pub const STATS_WINDOW: Duration = Duration::from_secs(300);
+
+/// 라우트당 보관하는 최대 샘플 수
+const MAX_SAMPLES_PER_ROUTE: usize = 20_000;
+
+/// 최대 라우트 수. 넘으면 새 호스트는 "other"로 묶음 (임의 Host 헤더로 메모리가 늘지 않도록)
+const MAX_ROUTES: usize = 500;
+
+/// 집계 단위: 프록시 대상(프로젝트/독립 컨테이너) + 요청 Host
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RouteKey {
+    /// 프로젝트 요청이면 프로젝트 ID, 독립 컨테이너면 None
+    pub project_id: Option<i64>,
+    pub target: String,
+    pub host: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    at: Instant,
+    latency_ms: u64,
+    status: u16,
+}
+
+/// 최근 STATS_WINDOW 동안의 라우트 통계
+#[derive(Debug, Clone, Serialize)]
+pub struct RouteStats {
+    /// "project" 또는 "container"
+    pub kind: &'static str,
+    pub project_id: Option<i64>,
+    pub target: String,
+    pub host: String,
+    pub requests: usize,
+    pub requests_per_sec: f64,
+    pub p50_ms: u64,
+    pub p95_ms: u64,
+    pub errors_5xx: usize,
+    /// 5xx 비율 (0.0 ~ 1.0)
+    pub error_rate: f64,
+}
+
+/// ProxyStats - 리버스 프록시 라우트별 지연 시간/5xx 비율 (메모리, 최근 5분)
+///
+/// 자동 롤백/카나리 판단과 `/api/proxy/stats`, `/api/metrics`에서 사용
+#[derive(Default)]
+pub struct ProxyStats {
+    routes: Mutex<HashMap<RouteKey, VecDeque<Sample>>>,
+}
+
+impl ProxyStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, key: RouteKey, status: u16, latency_ms: u64) {
+        self.record_at(key, status, latency_ms, Instant::now());
+    }
+
+    fn record_at(&self, mut key: RouteKey, status: u16, latency_ms: u64, at: Instant) {
+        let mut routes = self.routes.lock().unwrap_or_else(|e| e.into_inner());
+        if !routes.contains_key(&key) && routes.len() >= MAX_ROUTES {
+            routes.retain(|_, samples| samples.back().is_some_and(|s| at.saturating_duration_since(s.at) < STATS_WINDOW));
+            if routes.len() >= MAX_ROUTES {
+                key.host = "other".to_string();
+            }
+        }
+
+        let samples = routes.entry(key).or_default();
+        prune(samples, at);
+        if samples.len() >= MAX_SAMPLES_PER_ROUTE {
+            samples.pop_front();
+        }
+        samples.push_back(Sample { at, latency_ms, status });
+    }
+
+    /// 모든 라우트 통계 (요청이 없는 라우트 제외, 대상/호스트순)
+    pub fn snapshot(&self) -> Vec<RouteStats> {
+        self.snapshot_at(Instant::now())
+    }
+
+    fn snapshot_at(&self, now: Instant) -> Vec<RouteStats> {
+        let mut routes = self.routes.lock().unwrap_or_else(|e| e.into_inner());
+        routes.retain(|_, samples| {
+            prune(samples, now);
+            !samples.is_empty()
+        });
+        let mut stats: Vec<RouteStats> = routes
+            .iter()
+            .map(|(key, samples)| summarize(key.project_id, &key.target, &key.host, samples.iter()))
+            .collect();
+        stats.sort_by(|a, b| (&a.target, &a.host).cmp(&(&b.target, &b.host)));
+        stats
+    }
+
+    /// 프로젝트 전체(모든 호스트) 통계. 최근 요청이 없으면 None
+    pub fn project_stats(&self, project_id: i64) -> Option<RouteStats> {
+        self.project_stats_at(project_id, Instant::now())
+    }
+
+    fn project_stats_at(&self, project_id: i64, now: Instant) -> Option<RouteStats> {
+        let mut routes = self.routes.lock().unwrap_or_else(|e| e.into_inner());
+        let mut target = None;
+        let mut samples = Vec::new();
+        for (key, route) in routes.iter_mut().filter(|(key, _)| key.project_id == Some(project_id)) {
+            prune(route, now);
+            target.get_or_insert_with(|| key.target.clone());
+            samples.extend(route.iter().copied());
+        }
+        if samples.is_empty() {
+            return None;
+        }
+        Some(summarize(Some(project_id), &target.unwrap_or_default(), "*", samples.iter()))
+    }
+}
+
+fn prune(samples: &mut VecDeque<Sample>, now: Instant) {
+    while samples.front().is_some_and(|s| now.saturating_duration_since(s.at) >= STATS_WINDOW) {
+        samples.pop_front();
+    }
+}
+
+fn summarize<'a>(
+    project_id: Option<i64>,
+    target: &str,
+    host: &str,
+    samples: impl Iterator<Item = &'a Sample>,
+) -> RouteStats {
+    let mut latencies = Vec::new();
+    let mut errors_5xx = 0;
+    for sample in samples {
+        latencies.push(sample.latency_ms);
+        if sample.status >= 500 {
+            errors_5xx += 1;
+        }
+    }
+    latencies.sort_unstable();
+    let requests = latencies.len();
+
+    RouteStats {
+        kind: if project_id.is_some() { "project" } else { "container" },
+        project_id,
+        target: target.to_string(),
+        host: host.to_string(),
+        requests,
+        requests_per_sec: requests as f64 / STATS_WINDOW.as_secs_f64(),
+        p50_ms: percentile(&latencies, 50.0),
+        p95_ms: percentile(&latencies, 95.0),
+        errors_5xx,
+        error_rate: if requests == 0 { 0.0 } else { errors_5xx as f64 / requests as f64 },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(project_id: Option<i64>, target: &str, host: &str) -> RouteKey {
+        RouteKey { project_id, target: target.to_string(), host: host.to_string() }
+    }
+
+    #[test]
+    fn test_snapshot_and_window() {
+        let stats = ProxyStats::new();
+        let start = Instant::now();
+        // 창 밖으로 밀려날 오래된 요청
+        stats.record_at(key(Some(1), "web", "web-app.example.com"), 500, 9000, start);
+
+        let now = start + STATS_WINDOW + Duration::from_secs(1);
+        for (i, status) in [200u16, 200, 200, 503].iter().enumerate() {
+            stats.record_at(key(Some(1), "web", "web-app.example.com"), *status, (i as u64 + 1) * 10, now);
+        }
+        stats.record_at(key(None, "redis-ui", "redis-ui.example.com"), 200, 5, now);
+
+        let snapshot = stats.snapshot_at(now);
+        assert_eq!(snapshot.len(), 2);
+        let web = snapshot.iter().find(|s| s.target == "web").unwrap();
+        assert_eq!(web.kind, "project");
+        assert_eq!(web.requests, 4);
+        assert_eq!(web.errors_5xx, 1);
+        assert_eq!(web.error_rate, 0.25);
+        assert_eq!(web.p50_ms, 20);
+        assert_eq!(web.p95_ms, 40);
+        assert_eq!(snapshot.iter().find(|s| s.target == "redis-ui").unwrap().kind, "container");
+    }
+
+    #[test]
+    fn test_project_stats_merges_hosts() {
+        let stats = ProxyStats::new();
+        let now = Instant::now();
+        stats.record_at(key(Some(1), "web", "a.example.com"), 200, 10, now);
+        stats.record_at(key(Some(1), "web", "b.example.com"), 502, 30, now);
+        stats.record_at(key(Some(2), "api", "api-app.example.com"), 200, 10, now);
+
+        let web = stats.project_stats_at(1, now).unwrap();
+        assert_eq!(web.requests, 2);
+        assert_eq!(web.errors_5xx, 1);
+        assert!(stats.project_stats_at(3, now).is_none());
+    }
+}
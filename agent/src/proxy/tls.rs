@@ -0,0 +1,317 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::{ClientHello, ResolvesServerCert};
+use rustls::sign::CertifiedKey;
+use rustls::ServerConfig;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use tokio::sync::Notify;
+use tracing::{info, warn};
+
+use crate::infrastructure::acme::{der_read, Http01Challenges};
+
+/// 인증서/계정 키 저장 위치 (`{domain}.crt`, `{domain}.key`, `account.key`)
+pub const CERT_DIR: &str = "/data/easycicd/certs";
+pub const TLS_ENABLED_SETTING: &str = "tls_enabled";
+pub const ACME_EMAIL_SETTING: &str = "acme_email";
+/// 만료까지 이 기간(일)보다 적게 남으면 갱신
+pub const RENEW_BEFORE_DAYS: i64 = 30;
+
+/// 조회용 인증서 정보
+#[derive(Debug, Clone, Serialize)]
+pub struct CertificateInfo {
+    pub domain: String,
+    pub expires_at: DateTime<Utc>,
+    pub days_remaining: i64,
+}
+
+struct StoredCertificate {
+    key: Arc<CertifiedKey>,
+    not_after: DateTime<Utc>,
+}
+
+/// TlsManager - 리버스 프록시 HTTPS 인증서
+///
+/// 도메인별 인증서를 SNI로 골라 제공하고, 발급 중인 HTTP-01 챌린지를 보관한다.
+/// 발급/갱신은 cert_renewal 워커가 ACME로 처리
+#[derive(Default)]
+pub struct TlsManager {
+    enabled: AtomicBool,
+    certificates: RwLock<HashMap<String, StoredCertificate>>,
+    /// 도메인별 마지막 발급 실패 이유
+    errors: Mutex<HashMap<String, String>>,
+    renewal: Notify,
+    pub challenges: Http01Challenges,
+}
+
+impl TlsManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// 갱신 워커를 주기와 상관없이 바로 깨움
+    pub fn request_renewal(&self) {
+        self.renewal.notify_one();
+    }
+
+    pub async fn renewal_requested(&self) {
+        self.renewal.notified().await;
+    }
+
+    /// CERT_DIR의 인증서를 캐시에 올림 (시작 시). 읽은 개수 반환
+    pub async fn load_from_disk(&self) -> usize {
+        let mut entries = match tokio::fs::read_dir(CERT_DIR).await {
+            Ok(entries) => entries,
+            Err(_) => return 0,
+        };
+        let mut loaded = 0;
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("crt") {
+                continue;
+            }
+            let Some(domain) = path.file_stem().and_then(|s| s.to_str()).map(|s| s.to_string()) else {
+                continue;
+            };
+            let (chain, key) = match (tokio::fs::read_to_string(&path).await, tokio::fs::read_to_string(path.with_extension("key")).await) {
+                (Ok(chain), Ok(key)) => (chain, key),
+                _ => continue,
+            };
+            match self.cache(&domain, &chain, &key) {
+                Ok(()) => loaded += 1,
+                Err(e) => warn!("Skipping certificate {}: {}", path.display(), e),
+            }
+        }
+        loaded
+    }
+
+    /// 새로 발급한 인증서를 저장하고 바로 사용
+    pub async fn install(&self, domain: &str, chain_pem: &str, key_pem: &str) -> Result<()> {
+        self.cache(domain, chain_pem, key_pem)?;
+        tokio::fs::create_dir_all(CERT_DIR).await.context("Failed to create certificate directory")?;
+        let (chain_path, key_path) = certificate_paths(domain);
+        write_private(&key_path, key_pem).await?;
+        tokio::fs::write(&chain_path, chain_pem).await.with_context(|| format!("Failed to write {}", chain_path.display()))?;
+        self.errors.lock().unwrap_or_else(|e| e.into_inner()).remove(domain);
+        Ok(())
+    }
+
+    fn cache(&self, domain: &str, chain_pem: &str, key_pem: &str) -> Result<()> {
+        let (key, not_after) = parse_certified_key(chain_pem, key_pem)?;
+        self.certificates
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(domain.to_ascii_lowercase(), StoredCertificate { key, not_after });
+        Ok(())
+    }
+
+    /// 인증서가 없거나 RENEW_BEFORE_DAYS 안에 만료되면 true
+    pub fn needs_renewal(&self, domain: &str, now: DateTime<Utc>) -> bool {
+        self.certificates
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(domain)
+            .is_none_or(|cert| (cert.not_after - now).num_days() < RENEW_BEFORE_DAYS)
+    }
+
+    pub fn certificates(&self, now: DateTime<Utc>) -> Vec<CertificateInfo> {
+        let mut list: Vec<CertificateInfo> = self
+            .certificates
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|(domain, cert)| CertificateInfo {
+                domain: domain.clone(),
+                expires_at: cert.not_after,
+                days_remaining: (cert.not_after - now).num_days(),
+            })
+            .collect();
+        list.sort_by(|a, b| a.domain.cmp(&b.domain));
+        list
+    }
+
+    pub fn set_error(&self, domain: &str, error: String) {
+        self.errors.lock().unwrap_or_else(|e| e.into_inner()).insert(domain.to_string(), error);
+    }
+
+    pub fn errors(&self) -> HashMap<String, String> {
+        self.errors.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// SNI로 인증서를 고르는 rustls 서버 설정 (HTTP/1.1)
+    pub fn server_config(self: &Arc<Self>) -> Result<Arc<ServerConfig>> {
+        let mut config = ServerConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .context("Failed to configure TLS protocol versions")?
+            .with_no_client_auth()
+            .with_cert_resolver(Arc::new(CertResolver(self.clone())));
+        config.alpn_protocols = vec![b"http/1.1".to_vec()];
+        Ok(Arc::new(config))
+    }
+}
+
+struct CertResolver(Arc<TlsManager>);
+
+impl std::fmt::Debug for CertResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CertResolver")
+    }
+}
+
+impl ResolvesServerCert for CertResolver {
+    fn resolve(&self, client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        let server_name = client_hello.server_name()?.to_ascii_lowercase();
+        self.0
+            .certificates
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&server_name)
+            .map(|cert| cert.key.clone())
+    }
+}
+
+/// 인증서를 받을 도메인: base_domain과 프로젝트 서브도메인(`{name}-app.{base_domain}`)
+pub fn tls_domains<'a>(base_domain: &str, project_names: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let base_domain = base_domain.to_ascii_lowercase();
+    let mut domains = vec![base_domain.clone()];
+    domains.extend(project_names.into_iter().map(|name| format!("{}-app.{}", name.to_ascii_lowercase(), base_domain)));
+    domains
+}
+
+/// 계정 키 로드 (없으면 만들어 저장). PKCS#8 DER 반환
+pub async fn load_or_create_account_key() -> Result<Vec<u8>> {
+    let path = Path::new(CERT_DIR).join("account.key");
+    let pem = match tokio::fs::read_to_string(&path).await {
+        Ok(pem) => pem,
+        Err(_) => {
+            tokio::fs::create_dir_all(CERT_DIR).await.context("Failed to create certificate directory")?;
+            let pem = crate::infrastructure::acme::generate_account_key()?;
+            write_private(&path, &pem).await?;
+            info!("Created ACME account key at {}", path.display());
+            pem
+        }
+    };
+    match PrivateKeyDer::from_pem_slice(pem.as_bytes()).context("Invalid ACME account key")? {
+        PrivateKeyDer::Pkcs8(key) => Ok(key.secret_pkcs8_der().to_vec()),
+        _ => anyhow::bail!("ACME account key must be PKCS#8"),
+    }
+}
+
+fn certificate_paths(domain: &str) -> (PathBuf, PathBuf) {
+    let dir = Path::new(CERT_DIR);
+    (dir.join(format!("{}.crt", domain)), dir.join(format!("{}.key", domain)))
+}
+
+/// 개인키는 소유자만 읽도록 0600으로 저장
+async fn write_private(path: &Path, contents: &str) -> Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut file = tokio::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)
+        .await
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    file.write_all(contents.as_bytes()).await?;
+    Ok(())
+}
+
+fn parse_certified_key(chain_pem: &str, key_pem: &str) -> Result<(Arc<CertifiedKey>, DateTime<Utc>)> {
+    let chain = CertificateDer::pem_slice_iter(chain_pem.as_bytes())
+        .collect::<Result<Vec<_>, _>>()
+        .context("Invalid certificate chain")?;
+    let leaf = chain.first().context("Certificate chain is empty")?;
+    let not_after = certificate_not_after(leaf).context("Failed to read certificate expiry")?;
+    let key = PrivateKeyDer::from_pem_slice(key_pem.as_bytes()).context("Invalid private key")?;
+    let signing_key = rustls::crypto::ring::sign::any_supported_type(&key).context("Unsupported private key")?;
+    Ok((Arc::new(CertifiedKey::new(chain, signing_key)), not_after))
+}
+
+/// X.509 인증서(DER)의 notAfter
+pub fn certificate_not_after(der: &[u8]) -> Option<DateTime<Utc>> {
+    let (_, certificate, _) = der_read(der)?;
+    let (_, tbs, _) = der_read(certificate)?;
+    // [0] version은 생략될 수 있음 → serialNumber, signature, issuer 다음이 validity
+    let (tag, _, mut rest) = der_read(tbs)?;
+    if tag == 0xA0 {
+        rest = der_read(rest)?.2;
+    }
+    let rest = der_read(rest)?.2;
+    let rest = der_read(rest)?.2;
+    let (_, validity, _) = der_read(rest)?;
+    let (_, _, rest) = der_read(validity)?;
+    let (tag, not_after, _) = der_read(rest)?;
+    parse_asn1_time(tag, std::str::from_utf8(not_after).ok()?)
+}
+
+/// UTCTime(YYMMDDHHMMSSZ, 50 미만은 20xx) 또는 GeneralizedTime(YYYYMMDDHHMMSSZ)
+fn parse_asn1_time(tag: u8, value: &str) -> Option<DateTime<Utc>> {
+    let full = match tag {
+        0x17 => {
+            let year: u32 = value.get(..2)?.parse().ok()?;
+            format!("{}{}", if year < 50 { "20" } else { "19" }, value)
+        }
+        0x18 => value.to_string(),
+        _ => return None,
+    };
+    NaiveDateTime::parse_from_str(&full, "%Y%m%d%H%M%SZ").ok().map(|t| t.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::acme::der;
+    use chrono::TimeZone;
+
+    /// validity만 의미 있는 최소 인증서 구조
+    fn fake_certificate(with_version: bool, not_after: (u8, &str)) -> Vec<u8> {
+        let mut tbs = Vec::new();
+        if with_version {
+            tbs.extend(der(0xA0, &der(0x02, &[2])));
+        }
+        tbs.extend(der(0x02, &[1]));
+        tbs.extend(der(0x30, &[]));
+        tbs.extend(der(0x30, &[]));
+        let validity = [der(0x17, b"250101000000Z"), der(not_after.0, not_after.1.as_bytes())].concat();
+        tbs.extend(der(0x30, &validity));
+        der(0x30, &[der(0x30, &tbs), der(0x30, &[])].concat())
+    }
+
+    #[test]
+    fn test_certificate_not_after() {
+        let expected = Utc.with_ymd_and_hms(2026, 3, 1, 12, 30, 0).unwrap();
+        assert_eq!(certificate_not_after(&fake_certificate(true, (0x17, "260301123000Z"))), Some(expected));
+        assert_eq!(certificate_not_after(&fake_certificate(false, (0x18, "20260301123000Z"))), Some(expected));
+        assert_eq!(certificate_not_after(&[0x30, 0x05, 0x30]), None);
+    }
+
+    #[test]
+    fn test_tls_domains() {
+        assert_eq!(
+            tls_domains("Example.com", ["api", "web"]),
+            vec!["example.com", "api-app.example.com", "web-app.example.com"]
+        );
+    }
+
+    #[test]
+    fn test_needs_renewal_without_certificate() {
+        let manager = TlsManager::new();
+        assert!(manager.needs_renewal("example.com", Utc::now()));
+        assert!(manager.certificates(Utc::now()).is_empty());
+    }
+}
@@ -5,15 +5,21 @@ use tokio::sync::broadcast;
 
 use crate::application::events::{BroadcastEventBus, Event};
 use crate::application::events::event_bus::EventBus;
-use crate::application::services::{BuildService, ContainerService, DeploymentService, ProjectService};
+use crate::application::services::{BuildService, ContainerService, DeploymentService, CanaryTraffic, DiskQuotaService, HookService, ImageProfiles, LogLevels, ProjectService, ShadowTraffic};
 use crate::docker::DockerClient;
 use crate::infrastructure::database::{
     SqliteBuildRepository, SqliteContainerRepository, SqliteProjectRepository, SqliteSettingsRepository,
     SqliteUserRepository, SqliteSessionRepository, SqliteGitHubPatRepository, SqliteDiscordWebhookRepository,
+    SqliteSlackWebhookRepository, SqliteMetricsRepository, SqliteIdempotencyRepository, SqlitePortAllocationRepository,
+    SqliteChatAccountRepository, SqlitePreviewRepository, SqliteSecretRepository, SqliteProjectPermissionRepository,
+    SqliteOutboundWebhookRepository, SqliteApiTokenRepository, SqliteTotpRepository,
 };
 use crate::infrastructure::logging::BoundaryLogger;
-use crate::state::{BuildQueue, WsConnections};
-use crate::auth::OAuthConfig;
+use crate::infrastructure::secrets::SecretCipher;
+use crate::proxy::stats::ProxyStats;
+use crate::proxy::tls::TlsManager;
+use crate::state::{BuildQueue, DeploymentLocks, LoginGuard, TrustedProxies, WsConnections};
+use crate::auth::{GitHubOAuthConfig, OAuthConfig};
 
 /// AppContext - 서비스 기반 DI 컨테이너 (AppState 완전 대체)
 ///
@@ -27,6 +33,8 @@ pub struct AppContext {
         ProjectService<
             SqliteProjectRepository,
             SqliteBuildRepository,
+            SqliteSettingsRepository,
+            SqliteGitHubPatRepository,
             BroadcastEventBus,
         >,
     >,
@@ -43,6 +51,7 @@ pub struct AppContext {
         DeploymentService<
             SqliteBuildRepository,
             SqliteProjectRepository,
+            SqliteContainerRepository,
             BroadcastEventBus,
         >,
     >,
@@ -52,6 +61,14 @@ pub struct AppContext {
             BroadcastEventBus,
         >,
     >,
+    pub hook_service: Arc<HookService>,
+    pub disk_quota_service: Arc<
+        DiskQuotaService<
+            SqliteBuildRepository,
+            SqliteSettingsRepository,
+            BroadcastEventBus,
+        >,
+    >,
 
     // Repositories (Infrastructure Layer)
     pub project_repo: Arc<SqliteProjectRepository>,
@@ -62,20 +79,48 @@ pub struct AppContext {
     pub session_repo: Arc<SqliteSessionRepository>,
     pub github_pat_repo: Arc<SqliteGitHubPatRepository>,
     pub discord_webhook_repo: Arc<SqliteDiscordWebhookRepository>,
+    pub slack_webhook_repo: Arc<SqliteSlackWebhookRepository>,
+    pub metrics_repo: Arc<SqliteMetricsRepository>,
+    pub idempotency_repo: Arc<SqliteIdempotencyRepository>,
+    pub port_allocation_repo: Arc<SqlitePortAllocationRepository>,
+    pub chat_account_repo: Arc<SqliteChatAccountRepository>,
+    pub preview_repo: Arc<SqlitePreviewRepository>,
+    pub secret_repo: Arc<SqliteSecretRepository>,
+    pub project_permission_repo: Arc<SqliteProjectPermissionRepository>,
+    pub outbound_webhook_repo: Arc<SqliteOutboundWebhookRepository>,
+    pub api_token_repo: Arc<SqliteApiTokenRepository>,
+    pub totp_repo: Arc<SqliteTotpRepository>,
 
     // Infrastructure
     pub event_bus: BroadcastEventBus,
     pub build_queue: Arc<BuildQueue>,
+    /// 프로젝트별 배포/롤백 잠금
+    pub deployment_locks: Arc<DeploymentLocks>,
+    /// 배포 전 트래픽 섀도잉 세션 (리버스 프록시가 요청 복제)
+    pub shadow_traffic: Arc<ShadowTraffic>,
+    /// 리버스 프록시 라우트별 지연 시간/5xx 비율 (최근 5분)
+    pub proxy_stats: Arc<ProxyStats>,
+    /// 리버스 프록시 HTTPS 인증서와 ACME 챌린지
+    pub tls: Arc<TlsManager>,
+    /// 독립 컨테이너 이미지 프로필 카탈로그 (데이터 경로, 필수 환경변수, 기본 헬스체크)
+    pub image_profiles: Arc<ImageProfiles>,
+    /// 빌드 로그 심각도 분류 패턴 (error/warn 정규식)
+    pub log_levels: Arc<LogLevels>,
     pub ws_connections: Arc<WsConnections>,
+    /// 인증 엔드포인트 요청 한도와 로그인 실패 잠금
+    pub login_guard: Arc<LoginGuard>,
     pub docker: DockerClient,
     pub logger: Arc<BoundaryLogger>,
 
     // Config
     pub gateway_ip: String,
-    pub base_domain: Option<String>,
+    /// 리버스 프록시 서브도메인 라우팅 기준 도메인 (settings의 base_domain, 변경 시 갱신)
+    base_domain: Arc<std::sync::RwLock<Option<String>>>,
 
     // OAuth config (optional)
     pub oauth_config: Option<OAuthConfig>,
+    /// GitHub 로그인 (GITHUB_CLIENT_ID 등이 없으면 None)
+    pub github_oauth_config: Option<GitHubOAuthConfig>,
 }
 
 impl AppContext {
@@ -95,18 +140,37 @@ impl AppContext {
         let session_repo = Arc::new(SqliteSessionRepository::new(pool.clone()));
         let github_pat_repo = Arc::new(SqliteGitHubPatRepository::new(pool.clone()));
         let discord_webhook_repo = Arc::new(SqliteDiscordWebhookRepository::new(pool.clone()));
+        let slack_webhook_repo = Arc::new(SqliteSlackWebhookRepository::new(pool.clone()));
+        let metrics_repo = Arc::new(SqliteMetricsRepository::new(pool.clone()));
+        let idempotency_repo = Arc::new(SqliteIdempotencyRepository::new(pool.clone()));
+        let port_allocation_repo = Arc::new(SqlitePortAllocationRepository::new(pool.clone()));
+        let chat_account_repo = Arc::new(SqliteChatAccountRepository::new(pool.clone()));
+        let preview_repo = Arc::new(SqlitePreviewRepository::new(pool.clone()));
+        let cipher = Arc::new(SecretCipher::load()?);
+        let secret_repo = Arc::new(SqliteSecretRepository::new(pool.clone(), cipher.clone()));
+        let project_permission_repo = Arc::new(SqliteProjectPermissionRepository::new(pool.clone()));
+        let outbound_webhook_repo = Arc::new(SqliteOutboundWebhookRepository::new(pool.clone()));
+        let api_token_repo = Arc::new(SqliteApiTokenRepository::new(pool.clone()));
+        let totp_repo = Arc::new(SqliteTotpRepository::new(pool.clone(), cipher));
 
         // Load OAuth config (optional - don't fail if not configured)
         let oauth_config = OAuthConfig::from_env().ok();
+        let github_oauth_config = GitHubOAuthConfig::from_env().ok();
 
         // 2. Create Infrastructure components
         let logger = Arc::new(BoundaryLogger::new());
         let event_bus = BroadcastEventBus::new_default(logger.clone());
 
+        let shadow_traffic = Arc::new(ShadowTraffic::new());
+        let image_profiles = Arc::new(ImageProfiles::new());
+        let log_levels = Arc::new(LogLevels::new());
+
         // 3. Create Services with dependency injection
-        let project_service = Arc::new(ProjectService::<SqliteProjectRepository, SqliteBuildRepository, BroadcastEventBus>::new(
+        let project_service = Arc::new(ProjectService::<SqliteProjectRepository, SqliteBuildRepository, SqliteSettingsRepository, SqliteGitHubPatRepository, BroadcastEventBus>::new(
             project_repo.clone(),
             build_repo.clone(),
+            settings_repo.clone(),
+            github_pat_repo.clone(),
             event_bus.clone(),
             docker.clone(),
             logger.clone(),
@@ -120,14 +184,20 @@ impl AppContext {
             event_bus.clone(),
             docker.clone(),
             logger.clone(),
+            secret_repo.clone(),
+            log_levels.clone(),
         ));
 
-        let deployment_service = Arc::new(DeploymentService::<SqliteBuildRepository, SqliteProjectRepository, BroadcastEventBus>::new(
+        let deployment_service = Arc::new(DeploymentService::<SqliteBuildRepository, SqliteProjectRepository, SqliteContainerRepository, BroadcastEventBus>::new(
             build_repo.clone(),
             project_repo.clone(),
+            container_repo.clone(),
             event_bus.clone(),
             docker.clone(),
             logger.clone(),
+            shadow_traffic.clone(),
+            Arc::new(CanaryTraffic::new()),
+            secret_repo.clone(),
         ));
 
         let container_service = Arc::new(ContainerService::<SqliteContainerRepository, BroadcastEventBus>::new(
@@ -135,6 +205,16 @@ impl AppContext {
             docker.clone(),
             logger.clone(),
             Arc::new(event_bus.clone()),
+            image_profiles.clone(),
+        ));
+
+        let hook_service = Arc::new(HookService::new(docker.clone(), logger.clone()));
+
+        let disk_quota_service = Arc::new(DiskQuotaService::<SqliteBuildRepository, SqliteSettingsRepository, BroadcastEventBus>::new(
+            build_repo.clone(),
+            settings_repo.clone(),
+            event_bus.clone(),
+            logger.clone(),
         ));
 
         Ok(Self {
@@ -142,6 +222,8 @@ impl AppContext {
             build_service,
             deployment_service,
             container_service,
+            hook_service,
+            disk_quota_service,
             project_repo,
             build_repo,
             settings_repo,
@@ -150,17 +232,44 @@ impl AppContext {
             session_repo,
             github_pat_repo,
             discord_webhook_repo,
+            slack_webhook_repo,
+            metrics_repo,
+            idempotency_repo,
+            port_allocation_repo,
+            chat_account_repo,
+            preview_repo,
+            secret_repo,
+            project_permission_repo,
+            outbound_webhook_repo,
+            api_token_repo,
+            totp_repo,
             event_bus,
             build_queue: Arc::new(BuildQueue::new()),
+            deployment_locks: Arc::new(DeploymentLocks::new()),
+            shadow_traffic,
+            proxy_stats: Arc::new(ProxyStats::new()),
+            tls: Arc::new(TlsManager::new()),
+            image_profiles,
+            log_levels,
             ws_connections: Arc::new(WsConnections::new()),
+            login_guard: Arc::new(LoginGuard::new(TrustedProxies::from_env()?)),
             docker,
             logger,
             gateway_ip,
-            base_domain,
+            base_domain: Arc::new(std::sync::RwLock::new(base_domain)),
             oauth_config,
+            github_oauth_config,
         })
     }
 
+    pub fn base_domain(&self) -> Option<String> {
+        self.base_domain.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    pub fn set_base_domain(&self, domain: Option<String>) {
+        *self.base_domain.write().unwrap_or_else(|e| e.into_inner()) = domain;
+    }
+
     /// Subscribe to event bus (compatibility method for existing code)
     pub fn subscribe_events(&self) -> broadcast::Receiver<Event> {
         self.event_bus.subscribe()
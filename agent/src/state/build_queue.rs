@@ -1,17 +1,23 @@
 use std::collections::HashMap;
 use tokio::sync::RwLock;
 
-/// BuildQueue - 프로젝트별 빌드 큐 관리
+use crate::application::events::event_bus::EventBus;
+use crate::db::models::BuildStatus;
+use crate::events::Event;
+
+/// BuildQueue - 프로젝트별 빌드 큐 관리 + 스케줄링
 ///
 /// 책임:
 /// - 프로젝트별로 빌드를 큐잉
-/// - 동일 프로젝트는 순차 실행, 다른 프로젝트는 병렬 실행
-/// - 현재 처리 중인 빌드 추적
+/// - 동일 프로젝트는 순차 실행 (배포도 프로젝트별로 순차), 다른 프로젝트는 전체 한도 안에서 병렬 실행
+/// - 현재 처리 중인 빌드 추적 (동시 실행 그룹 포함)
 pub struct BuildQueue {
     // project_id -> queue of build_ids
     queues: RwLock<HashMap<i64, Vec<i64>>>,
     // Currently processing builds per project
     processing: RwLock<HashMap<i64, i64>>,
+    // project_id -> concurrency group of the processing build
+    processing_groups: RwLock<HashMap<i64, String>>,
 }
 
 impl BuildQueue {
@@ -19,6 +25,7 @@ impl BuildQueue {
         Self {
             queues: RwLock::new(HashMap::new()),
             processing: RwLock::new(HashMap::new()),
+            processing_groups: RwLock::new(HashMap::new()),
         }
     }
 
@@ -27,14 +34,72 @@ impl BuildQueue {
         queues.entry(project_id).or_insert_with(Vec::new).push(build_id);
     }
 
-    pub async fn dequeue(&self, project_id: i64) -> Option<i64> {
+    /// 새로 만든 빌드를 큐에 넣고 Queued 이벤트 발행 (대시보드, commit status `pending`)
+    ///
+    /// 모든 트리거(수동, 재빌드, webhook, gRPC, 이미지 업데이트)가 사용. 재시작 복구처럼
+    /// 이미 알린 빌드를 다시 넣을 때만 enqueue를 직접 호출
+    pub async fn enqueue_new(&self, event_bus: &impl EventBus, project_id: i64, build_id: i64) {
+        self.enqueue(project_id, build_id).await;
+        event_bus.emit(Event::build_status(build_id, project_id, BuildStatus::Queued)).await;
+    }
+
+    /// 큐에서 빌드 제거 (대기 중이었으면 true)
+    pub async fn remove(&self, project_id: i64, build_id: i64) -> bool {
         let mut queues = self.queues.write().await;
-        if let Some(queue) = queues.get_mut(&project_id) {
-            if !queue.is_empty() {
-                return Some(queue.remove(0));
+        match queues.get_mut(&project_id) {
+            Some(queue) => {
+                let before = queue.len();
+                queue.retain(|id| *id != build_id);
+                queue.len() != before
+            }
+            None => false,
+        }
+    }
+
+    /// 지금 시작할 빌드를 골라 큐에서 빼고 처리 중으로 기록. (project_id, build_id) 목록
+    ///
+    /// 먼저 들어온 빌드부터 고르며 다음 빌드는 건너뛴다 (Queued 유지):
+    /// - 같은 프로젝트의 빌드가 처리 중
+    /// - 전체 처리 중 빌드가 `max_concurrent`개
+    /// - `groups`의 동시 실행 그룹에서 `allows(group, 실행 중 수)`가 false
+    pub async fn schedule(
+        &self,
+        max_concurrent: usize,
+        groups: &HashMap<i64, String>,
+        allows: impl Fn(&str, usize) -> bool,
+    ) -> Vec<(i64, i64)> {
+        let mut queues = self.queues.write().await;
+        let mut processing = self.processing.write().await;
+        let mut processing_groups = self.processing_groups.write().await;
+
+        let mut candidates: Vec<(i64, i64)> = queues
+            .iter()
+            .filter_map(|(project_id, queue)| queue.first().map(|build_id| (*project_id, *build_id)))
+            .collect();
+        candidates.sort_by_key(|(_, build_id)| *build_id);
+
+        let mut started = Vec::new();
+        for (project_id, build_id) in candidates {
+            if processing.len() >= max_concurrent {
+                break;
             }
+            if processing.contains_key(&project_id) {
+                continue;
+            }
+            if let Some(group) = groups.get(&project_id) {
+                let running = processing_groups.values().filter(|g| *g == group).count();
+                if !allows(group, running) {
+                    continue;
+                }
+                processing_groups.insert(project_id, group.clone());
+            }
+            if let Some(queue) = queues.get_mut(&project_id) {
+                queue.remove(0);
+            }
+            processing.insert(project_id, build_id);
+            started.push((project_id, build_id));
         }
-        None
+        started
     }
 
     pub async fn is_processing(&self, project_id: i64) -> bool {
@@ -42,14 +107,40 @@ impl BuildQueue {
         processing.contains_key(&project_id)
     }
 
-    pub async fn start_processing(&self, project_id: i64, build_id: i64) {
+    /// 프로젝트에서 현재 처리 중인 빌드 ID
+    pub async fn processing_build(&self, project_id: i64) -> Option<i64> {
+        let processing = self.processing.read().await;
+        processing.get(&project_id).copied()
+    }
+
+    #[cfg(test)]
+    pub async fn start_processing(&self, project_id: i64, build_id: i64, group: Option<&str>) {
         let mut processing = self.processing.write().await;
         processing.insert(project_id, build_id);
+        if let Some(group) = group {
+            self.processing_groups.write().await.insert(project_id, group.to_string());
+        }
     }
 
     pub async fn finish_processing(&self, project_id: i64) {
         let mut processing = self.processing.write().await;
         processing.remove(&project_id);
+        self.processing_groups.write().await.remove(&project_id);
+    }
+
+    /// 동시 실행 그룹에서 현재 실행 중인 빌드 수
+    #[cfg(test)]
+    pub async fn processing_in_group(&self, group: &str) -> usize {
+        self.processing_groups.read().await.values().filter(|g| g.as_str() == group).count()
+    }
+
+    /// 그룹별 실행 중인 빌드 수
+    pub async fn processing_by_group(&self) -> HashMap<String, usize> {
+        let mut counts = HashMap::new();
+        for group in self.processing_groups.read().await.values() {
+            *counts.entry(group.clone()).or_insert(0) += 1;
+        }
+        counts
     }
 
     pub async fn get_queue_length(&self, project_id: i64) -> usize {
@@ -57,8 +148,83 @@ impl BuildQueue {
         queues.get(&project_id).map(|q| q.len()).unwrap_or(0)
     }
 
+    /// 현재 실행 중인 빌드 수 (프로젝트당 최대 1개)
+    pub async fn processing_count(&self) -> usize {
+        self.processing.read().await.len()
+    }
+
+    /// 전체 프로젝트의 대기 중인 빌드 수
+    pub async fn queued_count(&self) -> usize {
+        self.queues.read().await.values().map(|q| q.len()).sum()
+    }
+
     pub async fn get_all_queued_builds(&self) -> Vec<(i64, Vec<i64>)> {
         let queues = self.queues.read().await;
         queues.iter().map(|(k, v)| (*k, v.clone())).collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::events::BroadcastEventBus;
+    use crate::infrastructure::logging::BoundaryLogger;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_enqueue_new_emits_queued() {
+        let queue = BuildQueue::new();
+        let bus = BroadcastEventBus::new_default(Arc::new(BoundaryLogger::new()));
+        let mut rx = bus.subscribe();
+
+        // 수동 트리거(POST /api/projects/{id}/builds)와 같은 경로
+        queue.enqueue_new(&bus, 3, 30).await;
+
+        assert_eq!(queue.get_queue_length(3).await, 1);
+        match rx.recv().await.unwrap() {
+            Event::BuildStatus { build_id, project_id, status, .. } => {
+                assert_eq!((build_id, project_id, status), (30, 3, BuildStatus::Queued));
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_processing_groups() {
+        let queue = BuildQueue::new();
+        queue.start_processing(1, 10, Some("heavy-java")).await;
+        queue.start_processing(2, 20, Some("heavy-java")).await;
+        queue.start_processing(3, 30, None).await;
+
+        assert_eq!(queue.processing_in_group("heavy-java").await, 2);
+        assert_eq!(queue.processing_in_group("node").await, 0);
+        assert_eq!(queue.processing_count().await, 3);
+
+        queue.finish_processing(1).await;
+        assert_eq!(queue.processing_in_group("heavy-java").await, 1);
+        assert_eq!(queue.processing_by_group().await, HashMap::from([("heavy-java".to_string(), 1)]));
+    }
+
+    #[tokio::test]
+    async fn test_schedule() {
+        let queue = BuildQueue::new();
+        queue.enqueue(1, 10).await;
+        queue.enqueue(1, 11).await;
+        queue.enqueue(2, 20).await;
+        queue.enqueue(3, 30).await;
+        queue.enqueue(4, 40).await;
+        let groups = HashMap::from([(2, "heavy-java".to_string()), (3, "heavy-java".to_string())]);
+        let allows = |group: &str, running: usize| group != "heavy-java" || running < 1;
+
+        // 프로젝트당 하나, 그룹 한도 1 → 1, 2, 4 (3은 같은 그룹이라 대기)
+        assert_eq!(queue.schedule(8, &groups, allows).await, vec![(1, 10), (2, 20), (4, 40)]);
+        assert_eq!(queue.schedule(8, &groups, allows).await, vec![]);
+
+        queue.finish_processing(1).await;
+        queue.finish_processing(2).await;
+        // 전체 한도 2 (4가 실행 중) → 먼저 들어온 11만 시작
+        assert_eq!(queue.schedule(2, &groups, allows).await, vec![(1, 11)]);
+        assert_eq!(queue.get_queue_length(3).await, 1);
+        assert_eq!(queue.processing_count().await, 2);
+    }
+}
@@ -0,0 +1,142 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::OwnedMutexGuard;
+
+use crate::infrastructure::timezone;
+
+/// 프로젝트 배포 작업 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeploymentOperation {
+    Deploy,
+    Rollback,
+    Redeploy,
+    SwitchSlot,
+    Canary,
+}
+
+impl std::fmt::Display for DeploymentOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeploymentOperation::Deploy => write!(f, "deploy"),
+            DeploymentOperation::Rollback => write!(f, "rollback"),
+            DeploymentOperation::Redeploy => write!(f, "redeploy"),
+            DeploymentOperation::SwitchSlot => write!(f, "switch_slot"),
+            DeploymentOperation::Canary => write!(f, "canary"),
+        }
+    }
+}
+
+/// 현재 프로젝트 잠금을 잡고 있는 작업 (409 응답에 포함)
+#[derive(Debug, Clone, Serialize)]
+pub struct DeploymentHolder {
+    pub operation: DeploymentOperation,
+    pub trace_id: String,
+    #[serde(serialize_with = "timezone::serialize")]
+    pub started_at: String,
+}
+
+/// DeploymentLocks - 프로젝트별 배포 잠금
+///
+/// 배포/롤백/재배포는 슬롯 컨테이너와 active_slot을 바꾸므로 같은 프로젝트에서 겹치면 안 된다.
+/// - API(롤백, 재배포): `try_acquire` 실패 시 409와 잠금을 가진 작업의 trace_id 반환
+/// - 빌드 워커(배포): `acquire`로 앞선 작업이 끝날 때까지 대기
+#[derive(Default)]
+pub struct DeploymentLocks {
+    locks: Mutex<HashMap<i64, Arc<tokio::sync::Mutex<()>>>>,
+    holders: Arc<Mutex<HashMap<i64, DeploymentHolder>>>,
+}
+
+impl DeploymentLocks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn project_lock(&self, project_id: i64) -> Arc<tokio::sync::Mutex<()>> {
+        let mut locks = self.locks.lock().unwrap_or_else(|e| e.into_inner());
+        locks.entry(project_id).or_default().clone()
+    }
+
+    /// 잠금 시도. 이미 다른 작업이 진행 중이면 그 작업 정보를 Err로 반환
+    /// (잠금 직후 정보가 기록되기 전이면 None)
+    pub fn try_acquire(
+        &self,
+        project_id: i64,
+        operation: DeploymentOperation,
+        trace_id: &str,
+    ) -> Result<DeploymentGuard, Option<DeploymentHolder>> {
+        match self.project_lock(project_id).try_lock_owned() {
+            Ok(guard) => Ok(self.guard(project_id, operation, trace_id, guard)),
+            Err(_) => Err(self.holder(project_id)),
+        }
+    }
+
+    /// 앞선 작업이 끝날 때까지 기다렸다가 잠금
+    pub async fn acquire(&self, project_id: i64, operation: DeploymentOperation, trace_id: &str) -> DeploymentGuard {
+        let guard = self.project_lock(project_id).lock_owned().await;
+        self.guard(project_id, operation, trace_id, guard)
+    }
+
+    /// 프로젝트 잠금을 잡고 있는 작업
+    pub fn holder(&self, project_id: i64) -> Option<DeploymentHolder> {
+        self.holders.lock().unwrap_or_else(|e| e.into_inner()).get(&project_id).cloned()
+    }
+
+    fn guard(
+        &self,
+        project_id: i64,
+        operation: DeploymentOperation,
+        trace_id: &str,
+        guard: OwnedMutexGuard<()>,
+    ) -> DeploymentGuard {
+        let holder = DeploymentHolder {
+            operation,
+            trace_id: trace_id.to_string(),
+            started_at: timezone::db_now(),
+        };
+        self.holders.lock().unwrap_or_else(|e| e.into_inner()).insert(project_id, holder);
+        DeploymentGuard {
+            project_id,
+            holders: self.holders.clone(),
+            _guard: guard,
+        }
+    }
+}
+
+/// drop되면 잠금 해제
+pub struct DeploymentGuard {
+    project_id: i64,
+    holders: Arc<Mutex<HashMap<i64, DeploymentHolder>>>,
+    _guard: OwnedMutexGuard<()>,
+}
+
+impl Drop for DeploymentGuard {
+    fn drop(&mut self) {
+        // 다음 작업이 잠금을 얻기 전에 정보부터 지움 (필드 drop은 이 함수 이후)
+        self.holders.lock().unwrap_or_else(|e| e.into_inner()).remove(&self.project_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_try_acquire_reports_holder() {
+        let locks = DeploymentLocks::new();
+
+        let guard = locks.try_acquire(1, DeploymentOperation::Rollback, "trace-a").unwrap();
+        let holder = locks.try_acquire(1, DeploymentOperation::Deploy, "trace-b").err().flatten().unwrap();
+        assert_eq!(holder.operation, DeploymentOperation::Rollback);
+        assert_eq!(holder.trace_id, "trace-a");
+
+        // 다른 프로젝트는 영향 없음
+        assert!(locks.try_acquire(2, DeploymentOperation::Deploy, "trace-c").is_ok());
+
+        drop(guard);
+        assert!(locks.holder(1).is_none());
+        let _guard = locks.acquire(1, DeploymentOperation::Deploy, "trace-b").await;
+        assert_eq!(locks.holder(1).unwrap().trace_id, "trace-b");
+    }
+}
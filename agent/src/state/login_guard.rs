@@ -0,0 +1,300 @@
+use axum::http::HeaderMap;
+use std::collections::{HashMap, VecDeque};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 로그인 실패를 세는 구간
+const FAILURE_WINDOW: Duration = Duration::from_secs(15 * 60);
+
+/// 잠금 시간
+const LOCKOUT: Duration = Duration::from_secs(15 * 60);
+
+/// FAILURE_WINDOW 동안 IP별 최대 실패 횟수 (여러 계정 시도, 잘못된 API 토큰 포함)
+const MAX_FAILURES_PER_IP: usize = 20;
+
+/// FAILURE_WINDOW 동안 이메일별 최대 실패 횟수
+const MAX_FAILURES_PER_EMAIL: usize = 5;
+
+/// 인증 엔드포인트 요청 한도 (IP별, 1분 슬라이딩 윈도우)
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+const MAX_REQUESTS_PER_IP: usize = 30;
+
+/// 이 수를 넘으면 오래된 항목 정리
+const PRUNE_THRESHOLD: usize = 1024;
+
+/// 실패를 세는 대상
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LoginKey {
+    Ip(IpAddr),
+    Email(String),
+}
+
+impl LoginKey {
+    pub fn email(email: &str) -> Self {
+        LoginKey::Email(email.trim().to_lowercase())
+    }
+
+    fn max_failures(&self) -> usize {
+        match self {
+            LoginKey::Ip(_) => MAX_FAILURES_PER_IP,
+            LoginKey::Email(_) => MAX_FAILURES_PER_EMAIL,
+        }
+    }
+}
+
+impl std::fmt::Display for LoginKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoginKey::Ip(ip) => write!(f, "ip:{}", ip),
+            LoginKey::Email(email) => write!(f, "email:{}", email),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Failures {
+    at: VecDeque<Instant>,
+    locked_until: Option<Instant>,
+}
+
+impl Failures {
+    fn is_stale(&self, now: Instant) -> bool {
+        self.locked_until.is_none_or(|until| until <= now)
+            && self.at.back().is_none_or(|t| now.duration_since(*t) >= FAILURE_WINDOW)
+    }
+}
+
+/// 요청 확장에 넣는 클라이언트 IP (limit_auth_requests에서 설정)
+#[derive(Debug, Clone, Copy)]
+pub struct ClientIp(pub IpAddr);
+
+/// 리버스 프록시로 신뢰하는 주소 (TRUSTED_PROXIES, 쉼표로 구분한 IP 또는 CIDR)
+///
+/// 여기에 포함된 주소에서 온 요청만 X-Forwarded-For/X-Real-IP를 사용. 비어 있으면 헤더를 무시
+#[derive(Debug, Clone, Default)]
+pub struct TrustedProxies(Vec<(IpAddr, u8)>);
+
+impl TrustedProxies {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let mut ranges = Vec::new();
+        for entry in value.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (addr, prefix) = match entry.split_once('/') {
+                Some((addr, prefix)) => (addr, Some(prefix)),
+                None => (entry, None),
+            };
+            let addr: IpAddr = addr.parse().map_err(|_| format!("Invalid trusted proxy address: {}", entry))?;
+            let max = if addr.is_ipv4() { 32 } else { 128 };
+            let prefix = match prefix {
+                Some(p) => p.parse::<u8>().ok().filter(|p| *p <= max).ok_or_else(|| format!("Invalid trusted proxy prefix: {}", entry))?,
+                None => max,
+            };
+            ranges.push((addr, prefix));
+        }
+        Ok(Self(ranges))
+    }
+
+    pub fn from_env() -> anyhow::Result<Self> {
+        let value = std::env::var("TRUSTED_PROXIES").unwrap_or_default();
+        Self::parse(&value).map_err(anyhow::Error::msg)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        let ip = match ip {
+            IpAddr::V6(v6) => v6.to_ipv4_mapped().map(IpAddr::V4).unwrap_or(ip),
+            v4 => v4,
+        };
+        self.0.iter().any(|(net, prefix)| match (net, ip) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - *prefix as u32).unwrap_or(0);
+                u32::from(*net) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - *prefix as u32).unwrap_or(0);
+                u128::from(*net) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        })
+    }
+
+    /// 클라이언트 IP. 직접 연결한 주소가 신뢰하는 프록시면 X-Forwarded-For를 오른쪽부터 읽어
+    /// 신뢰하지 않는 첫 주소를 사용 (왼쪽 값은 클라이언트가 마음대로 넣을 수 있음).
+    /// X-Forwarded-For가 없으면 X-Real-IP
+    pub fn client_ip(&self, headers: &HeaderMap, peer: Option<SocketAddr>) -> Option<IpAddr> {
+        let peer = peer.map(|addr| addr.ip())?;
+        if !self.contains(peer) {
+            return Some(peer);
+        }
+
+        let hops: Vec<&str> = headers
+            .get_all("x-forwarded-for")
+            .iter()
+            .filter_map(|h| h.to_str().ok())
+            .flat_map(|v| v.split(','))
+            .map(str::trim)
+            .collect();
+        if hops.is_empty() {
+            return headers
+                .get("x-real-ip")
+                .and_then(|h| h.to_str().ok())
+                .and_then(|v| v.trim().parse().ok())
+                .or(Some(peer));
+        }
+
+        let mut client = peer;
+        for hop in hops.iter().rev() {
+            // 파싱할 수 없는 값은 마지막으로 확인한 hop이 넣은 것으로 보고 중단
+            let Ok(ip) = hop.parse::<IpAddr>() else { break };
+            client = ip;
+            if !self.contains(ip) {
+                break;
+            }
+        }
+        Some(client)
+    }
+}
+
+/// LoginGuard - 인증 엔드포인트 무차별 대입 방지
+///
+/// 책임:
+/// - IP별 인증 요청 수 제한 (분당 MAX_REQUESTS_PER_IP)
+/// - IP/이메일별 실패 횟수가 한도를 넘으면 LOCKOUT 동안 잠금
+/// - 성공하면 해당 IP/이메일의 실패 기록 초기화
+///
+/// 메모리에만 보관하므로 재시작하면 초기화됨
+#[derive(Default)]
+pub struct LoginGuard {
+    trusted_proxies: TrustedProxies,
+    failures: Mutex<HashMap<LoginKey, Failures>>,
+    requests: Mutex<HashMap<IpAddr, VecDeque<Instant>>>,
+}
+
+impl LoginGuard {
+    pub fn new(trusted_proxies: TrustedProxies) -> Self {
+        Self { trusted_proxies, ..Self::default() }
+    }
+
+    /// 요청의 클라이언트 IP (TRUSTED_PROXIES에서 온 요청만 프록시 헤더 사용)
+    pub fn client_ip(&self, headers: &HeaderMap, peer: Option<SocketAddr>) -> Option<IpAddr> {
+        self.trusted_proxies.client_ip(headers, peer)
+    }
+
+    pub fn trusted_proxies(&self) -> &TrustedProxies {
+        &self.trusted_proxies
+    }
+
+    /// 요청 한도 안이면 기록하고 true
+    pub fn allow_request(&self, ip: IpAddr, now: Instant) -> bool {
+        let mut requests = self.requests.lock().unwrap_or_else(|e| e.into_inner());
+        if requests.len() > PRUNE_THRESHOLD {
+            requests.retain(|_, at| at.back().is_some_and(|t| now.duration_since(*t) < RATE_WINDOW));
+        }
+        let at = requests.entry(ip).or_default();
+        while at.front().is_some_and(|t| now.duration_since(*t) >= RATE_WINDOW) {
+            at.pop_front();
+        }
+        if at.len() >= MAX_REQUESTS_PER_IP {
+            return false;
+        }
+        at.push_back(now);
+        true
+    }
+
+    /// 잠겨 있으면 남은 시간
+    pub fn locked_for(&self, key: &LoginKey, now: Instant) -> Option<Duration> {
+        let failures = self.failures.lock().unwrap_or_else(|e| e.into_inner());
+        failures
+            .get(key)
+            .and_then(|f| f.locked_until)
+            .filter(|until| *until > now)
+            .map(|until| until - now)
+    }
+
+    /// 실패 기록. 이번 실패로 잠기면 (실패 횟수, 잠금 시간)
+    pub fn record_failure(&self, key: LoginKey, now: Instant) -> Option<(usize, Duration)> {
+        let mut failures = self.failures.lock().unwrap_or_else(|e| e.into_inner());
+        if failures.len() > PRUNE_THRESHOLD {
+            failures.retain(|_, f| !f.is_stale(now));
+        }
+        let max = key.max_failures();
+        let entry = failures.entry(key).or_default();
+        while entry.at.front().is_some_and(|t| now.duration_since(*t) >= FAILURE_WINDOW) {
+            entry.at.pop_front();
+        }
+        entry.at.push_back(now);
+
+        let already_locked = entry.locked_until.is_some_and(|until| until > now);
+        if entry.at.len() >= max && !already_locked {
+            entry.locked_until = Some(now + LOCKOUT);
+            return Some((entry.at.len(), LOCKOUT));
+        }
+        None
+    }
+
+    pub fn record_success(&self, key: &LoginKey) {
+        self.failures.lock().unwrap_or_else(|e| e.into_inner()).remove(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lockout_and_rate_limit() {
+        let guard = LoginGuard::default();
+        let now = Instant::now();
+        let email = LoginKey::email("Dev@Example.com");
+
+        for _ in 0..MAX_FAILURES_PER_EMAIL - 1 {
+            assert_eq!(guard.record_failure(email.clone(), now), None);
+        }
+        assert_eq!(guard.locked_for(&email, now), None);
+        assert_eq!(guard.record_failure(email.clone(), now), Some((MAX_FAILURES_PER_EMAIL, LOCKOUT)));
+        assert_eq!(guard.locked_for(&LoginKey::email("dev@example.com"), now), Some(LOCKOUT));
+        assert_eq!(guard.locked_for(&email, now + LOCKOUT), None);
+
+        guard.record_success(&email);
+        assert_eq!(guard.record_failure(email.clone(), now + LOCKOUT), None);
+
+        let ip: IpAddr = "203.0.113.7".parse().unwrap();
+        for _ in 0..MAX_REQUESTS_PER_IP {
+            assert!(guard.allow_request(ip, now));
+        }
+        assert!(!guard.allow_request(ip, now));
+        assert!(guard.allow_request(ip, now + RATE_WINDOW));
+    }
+
+    #[test]
+    fn test_client_ip_trusted_proxies() {
+        let trusted = TrustedProxies::parse("127.0.0.1, 10.0.0.0/8, ::1").unwrap();
+        assert!(trusted.contains("10.1.2.3".parse().unwrap()));
+        assert!(trusted.contains("::ffff:127.0.0.1".parse().unwrap()));
+        assert!(!trusted.contains("192.168.0.1".parse().unwrap()));
+        assert!(TrustedProxies::parse("10.0.0.0/33").is_err());
+        assert!(TrustedProxies::parse("proxy").is_err());
+
+        let proxy: SocketAddr = "127.0.0.1:40000".parse().unwrap();
+        let direct: SocketAddr = "203.0.113.7:40000".parse().unwrap();
+        let private: SocketAddr = "192.168.0.5:40000".parse().unwrap();
+
+        // 클라이언트가 넣은 왼쪽 값은 무시하고, 신뢰하는 hop을 오른쪽부터 건너뛴 첫 주소
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", "1.2.3.4, 198.51.100.1, 10.0.0.2".parse().unwrap());
+        assert_eq!(trusted.client_ip(&headers, Some(proxy)), Some("198.51.100.1".parse().unwrap()));
+
+        // 신뢰하지 않는 주소(사설망 포함)에서 온 위조 헤더는 무시
+        assert_eq!(trusted.client_ip(&headers, Some(direct)), Some(direct.ip()));
+        assert_eq!(trusted.client_ip(&headers, Some(private)), Some(private.ip()));
+        assert_eq!(TrustedProxies::default().client_ip(&headers, Some(proxy)), Some(proxy.ip()));
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-real-ip", "198.51.100.9".parse().unwrap());
+        assert_eq!(trusted.client_ip(&headers, Some(proxy)), Some("198.51.100.9".parse().unwrap()));
+        assert_eq!(trusted.client_ip(&headers, Some(direct)), Some(direct.ip()));
+    }
+}
@@ -1,7 +1,11 @@
 pub mod app_context;
 pub mod build_queue;
+pub mod deployment_locks;
 pub mod ws_connections;
+pub mod login_guard;
 
 pub use app_context::AppContext;
 pub use build_queue::BuildQueue;
-pub use ws_connections::{WsConnections, WsSubscription};
+pub use deployment_locks::{DeploymentHolder, DeploymentLocks, DeploymentOperation};
+pub use ws_connections::{WsConnections, WsOutbound, WsSubscription};
+pub use login_guard::{ClientIp, LoginGuard, LoginKey, TrustedProxies};
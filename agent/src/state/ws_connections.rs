@@ -1,4 +1,6 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 use tokio::sync::{RwLock, mpsc};
 use crate::events::Event;
 
@@ -11,20 +13,39 @@ pub enum WsSubscription {
     Global,
 }
 
+/// 연결별 송신 채널로 보내는 메시지
+#[derive(Debug, Clone, PartialEq)]
+pub enum WsOutbound {
+    Text(String),
+    /// 서버가 보내는 heartbeat ping (클라이언트 pong으로 생존 확인)
+    Ping,
+}
+
+struct WsClient {
+    tx: mpsc::UnboundedSender<WsOutbound>,
+    /// 마지막으로 클라이언트에서 메시지(pong 포함)를 받은 시각
+    last_seen: Instant,
+}
+
 /// WsConnections - WebSocket 연결 관리
 ///
 /// 책임:
 /// - WebSocket 클라이언트 연결 추적
 /// - 구독 타입별 메시지 브로드캐스트
-/// - 끊긴 연결 자동 정리
+/// - 끊긴 연결 및 응답 없는 연결 정리
 pub struct WsConnections {
-    // Subscription -> list of senders
-    connections: RwLock<HashMap<String, Vec<mpsc::UnboundedSender<String>>>>,
+    next_client_id: AtomicU64,
+    // client_id -> sender
+    clients: RwLock<HashMap<u64, WsClient>>,
+    // Subscription -> subscribed client ids
+    connections: RwLock<HashMap<String, HashSet<u64>>>,
 }
 
 impl WsConnections {
     pub fn new() -> Self {
         Self {
+            next_client_id: AtomicU64::new(1),
+            clients: RwLock::new(HashMap::new()),
             connections: RwLock::new(HashMap::new()),
         }
     }
@@ -38,30 +59,106 @@ impl WsConnections {
         }
     }
 
-    pub async fn subscribe(&self, sub: WsSubscription, tx: mpsc::UnboundedSender<String>) {
+    /// 새 연결 등록. 송신 채널은 여기서만 보관하므로 연결이 제거되면 채널이 닫힌다
+    pub async fn register(&self, tx: mpsc::UnboundedSender<WsOutbound>) -> u64 {
+        let client_id = self.next_client_id.fetch_add(1, Ordering::Relaxed);
+        let mut clients = self.clients.write().await;
+        clients.insert(client_id, WsClient { tx, last_seen: Instant::now() });
+        client_id
+    }
+
+    /// 클라이언트에서 메시지를 받았을 때 호출 (생존 확인)
+    pub async fn touch(&self, client_id: u64) {
+        let mut clients = self.clients.write().await;
+        if let Some(client) = clients.get_mut(&client_id) {
+            client.last_seen = Instant::now();
+        }
+    }
+
+    pub async fn remove_client(&self, client_id: u64) {
+        self.remove_clients(&[client_id]).await;
+    }
+
+    async fn remove_clients(&self, client_ids: &[u64]) {
+        if client_ids.is_empty() {
+            return;
+        }
+        let mut clients = self.clients.write().await;
+        let mut connections = self.connections.write().await;
+        for client_id in client_ids {
+            clients.remove(client_id);
+        }
+        connections.retain(|_, subscribers| {
+            subscribers.retain(|id| !client_ids.contains(id));
+            !subscribers.is_empty()
+        });
+    }
+
+    pub async fn subscribe(&self, sub: WsSubscription, client_id: u64) {
         let key = Self::subscription_key(&sub);
         let mut connections = self.connections.write().await;
-        connections.entry(key).or_insert_with(Vec::new).push(tx);
+        connections.entry(key).or_default().insert(client_id);
     }
 
-    pub async fn unsubscribe(&self, sub: WsSubscription, tx_id: usize) {
+    pub async fn unsubscribe(&self, sub: WsSubscription, client_id: u64) {
         let key = Self::subscription_key(&sub);
         let mut connections = self.connections.write().await;
-        if let Some(senders) = connections.get_mut(&key) {
-            if tx_id < senders.len() {
-                senders.remove(tx_id);
+        if let Some(subscribers) = connections.get_mut(&key) {
+            subscribers.remove(&client_id);
+            if subscribers.is_empty() {
+                connections.remove(&key);
             }
         }
     }
 
     pub async fn broadcast(&self, sub: WsSubscription, message: String) {
         let key = Self::subscription_key(&sub);
-        let mut connections = self.connections.write().await;
+        let closed: Vec<u64> = {
+            // remove_clients와 같은 순서로 lock (clients -> connections)
+            let clients = self.clients.read().await;
+            let connections = self.connections.read().await;
+            let Some(subscribers) = connections.get(&key) else { return };
+            subscribers
+                .iter()
+                .filter(|id| {
+                    clients
+                        .get(id)
+                        .is_none_or(|client| client.tx.send(WsOutbound::Text(message.clone())).is_err())
+                })
+                .copied()
+                .collect()
+        };
 
-        if let Some(senders) = connections.get_mut(&key) {
-            // Remove closed connections and send to active ones
-            senders.retain(|tx| tx.send(message.clone()).is_ok());
-        }
+        // Remove closed connections
+        self.remove_clients(&closed).await;
+    }
+
+    /// 모든 연결에 ping 전송. 채널이 닫힌 연결은 제거
+    pub async fn ping_all(&self) {
+        let closed: Vec<u64> = {
+            let clients = self.clients.read().await;
+            clients
+                .iter()
+                .filter(|(_, client)| client.tx.send(WsOutbound::Ping).is_err())
+                .map(|(id, _)| *id)
+                .collect()
+        };
+        self.remove_clients(&closed).await;
+    }
+
+    /// `timeout` 동안 아무 응답이 없는 연결 제거 (노트북 절전, 네트워크 단절 등).
+    /// 송신 채널이 닫히면서 연결 task도 종료된다. 제거한 연결 수 반환
+    pub async fn reap_stale(&self, timeout: Duration) -> usize {
+        let stale: Vec<u64> = {
+            let clients = self.clients.read().await;
+            clients
+                .iter()
+                .filter(|(_, client)| client.last_seen.elapsed() > timeout)
+                .map(|(id, _)| *id)
+                .collect()
+        };
+        self.remove_clients(&stale).await;
+        stale.len()
     }
 
     pub async fn broadcast_event(&self, event: &Event) {
@@ -96,6 +193,22 @@ impl WsConnections {
             Event::ContainerLog { container_db_id, .. } => {
                 self.broadcast(WsSubscription::Container(*container_db_id), message).await;
             },
+            Event::BuildStep { build_id, project_id, .. } => {
+                self.broadcast(WsSubscription::Build(*build_id), message.clone()).await;
+                self.broadcast(WsSubscription::Project(*project_id), message).await;
+            },
+            Event::BuildStage { build_id, project_id, .. } => {
+                self.broadcast(WsSubscription::Build(*build_id), message.clone()).await;
+                self.broadcast(WsSubscription::Project(*project_id), message).await;
+            },
+            Event::BuildProgress { build_id, project_id, .. } => {
+                self.broadcast(WsSubscription::Build(*build_id), message.clone()).await;
+                self.broadcast(WsSubscription::Project(*project_id), message).await;
+            },
+            Event::QueueWaitExceeded { build_id, project_id, .. } => {
+                self.broadcast(WsSubscription::Build(*build_id), message.clone()).await;
+                self.broadcast(WsSubscription::Project(*project_id), message).await;
+            },
             Event::Error { project_id, build_id, .. } => {
                 if let Some(bid) = build_id {
                     self.broadcast(WsSubscription::Build(*bid), message.clone()).await;
@@ -108,7 +221,40 @@ impl WsConnections {
     }
 
     pub async fn connection_count(&self) -> usize {
-        let connections = self.connections.read().await;
-        connections.values().map(|v| v.len()).sum()
+        self.clients.read().await.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reap_stale_closes_channel() {
+        let connections = WsConnections::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let client_id = connections.register(tx).await;
+        connections.subscribe(WsSubscription::Global, client_id).await;
+
+        assert_eq!(connections.reap_stale(Duration::from_secs(60)).await, 0);
+        connections.ping_all().await;
+        assert_eq!(rx.recv().await, Some(WsOutbound::Ping));
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(connections.reap_stale(Duration::from_millis(10)).await, 1);
+        assert_eq!(connections.connection_count().await, 0);
+        assert_eq!(rx.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_removes_closed_connections() {
+        let connections = WsConnections::new();
+        let (tx, rx) = mpsc::unbounded_channel();
+        let client_id = connections.register(tx).await;
+        connections.subscribe(WsSubscription::Build(1), client_id).await;
+        drop(rx);
+
+        connections.broadcast(WsSubscription::Build(1), "msg".to_string()).await;
+        assert_eq!(connections.connection_count().await, 0);
     }
 }
@@ -0,0 +1,158 @@
+use anyhow::Result;
+use std::path::Path;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+use crate::application::events::Event;
+use crate::application::ports::repositories::{BuildRepository, ProjectRepository};
+use crate::db::models::{BuildStatus, LogShipment};
+use crate::infrastructure::log_shipping::{ship_log_file, LogShippingConfig};
+use crate::state::AppContext;
+
+/// 전송 실패 시 재시도 횟수
+const MAX_ATTEMPTS: u32 = 3;
+
+/// 로그가 더 이상 쓰이지 않는 빌드 상태
+fn is_finished(status: &BuildStatus) -> bool {
+    matches!(
+        status,
+        BuildStatus::Success | BuildStatus::Failed | BuildStatus::Verified | BuildStatus::Held
+    )
+}
+
+/// 원격 저장소의 객체 이름 (`{project}/{build_number}/{file}`)
+fn object_key(project_name: &str, build_number: i64, file: &str) -> String {
+    format!("{}/{}/{}", project_name, build_number, file)
+}
+
+/// Build log shipper
+///
+/// Responsibilities:
+/// - Forward build/deploy logs of finished builds to LOG_SHIPPING_URL (gzip + SHA-256)
+/// - Record locations and checksums on the build (builds.log_shipment) so remote
+///   copies can be verified later
+pub async fn run_build_log_shipper(config: LogShippingConfig, context: AppContext) -> Result<()> {
+    info!("Build log shipper started");
+
+    let mut event_rx = context.subscribe_events();
+    let client = reqwest::Client::new();
+
+    loop {
+        match event_rx.recv().await {
+            Ok(Event::BuildStatus { build_id, status, .. }) if is_finished(&status) => {
+                // 재시도 대기 중에도 다른 이벤트를 놓치지 않도록 빌드별로 분리
+                let (context, client, config) = (context.clone(), client.clone(), config.clone());
+                tokio::spawn(async move {
+                    if let Err(e) = ship_build_logs(&context, &client, &config, build_id).await {
+                        warn!("Failed to ship logs of build {}: {}", build_id, e);
+                    }
+                });
+            }
+            Ok(_) => {}
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!("Build log shipper lagged, skipped {} events", skipped);
+            }
+            Err(broadcast::error::RecvError::Closed) => {
+                info!("Event bus closed, build log shipper stopping");
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn ship_build_logs(
+    ctx: &AppContext,
+    client: &reqwest::Client,
+    config: &LogShippingConfig,
+    build_id: i64,
+) -> Result<()> {
+    let Some(build) = ctx.build_repo.get(build_id).await? else { return Ok(()) };
+    let Some(project) = ctx.project_repo.get(build.project_id).await? else { return Ok(()) };
+
+    // Held 빌드는 배포 창이 열리면 Success/Failed로 다시 끝나므로 배포 로그까지 다시 보냄
+    let mut logs = vec![("build.log.gz", build.log_path.clone())];
+    if let Some(deploy_log_path) = &build.deploy_log_path {
+        logs.push(("deploy.log.gz", deploy_log_path.clone()));
+    }
+
+    let metadata = [
+        ("Project", project.name.clone()),
+        ("Build-Number", build.build_number.to_string()),
+        ("Commit", build.commit_hash.clone()),
+        ("Status", build.status.to_string()),
+    ];
+
+    let mut files = Vec::new();
+    for (file, path) in logs {
+        let path = Path::new(&path);
+        if !path.exists() {
+            debug!("Skipping missing log {} of build {}", path.display(), build_id);
+            continue;
+        }
+        let key = object_key(&project.name, build.build_number, file);
+
+        let mut attempt = 1;
+        let shipped = loop {
+            match ship_log_file(client, config, path, &key, &metadata).await {
+                Ok(shipped) => break shipped,
+                Err(e) if attempt < MAX_ATTEMPTS => {
+                    warn!("Log upload {} failed (attempt {}/{}): {}", key, attempt, MAX_ATTEMPTS, e);
+                    tokio::time::sleep(Duration::from_secs(2u64.pow(attempt))).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        };
+        files.push(shipped);
+    }
+
+    if files.is_empty() {
+        return Ok(());
+    }
+
+    let shipment = LogShipment {
+        shipped_at: crate::infrastructure::timezone::db_now(),
+        files,
+    };
+    ctx.build_repo.update_log_shipment(build.id, &serde_json::to_string(&shipment)?).await?;
+
+    for file in &shipment.files {
+        tracing::info!(
+            target: "audit",
+            event = "build.log_shipped",
+            project_id = build.project_id,
+            build_id = build.id,
+            location = %file.location,
+            sha256 = %file.sha256,
+        );
+    }
+    info!(
+        "Shipped {} log file(s) of build #{} for project '{}'",
+        shipment.files.len(),
+        build.build_number,
+        project.name
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_finished() {
+        assert!(is_finished(&BuildStatus::Success));
+        assert!(is_finished(&BuildStatus::Failed));
+        assert!(!is_finished(&BuildStatus::Building));
+        assert!(!is_finished(&BuildStatus::Queued));
+    }
+
+    #[test]
+    fn test_object_key() {
+        assert_eq!(object_key("web", 12, "build.log.gz"), "web/12/build.log.gz");
+    }
+}
@@ -0,0 +1,62 @@
+use anyhow::Result;
+use chrono::Utc;
+use std::collections::{hash_map::Entry, HashMap};
+use tokio::time::{interval, Duration, MissedTickBehavior};
+use tracing::{info, warn};
+
+use crate::application::events::{Event, EventBus};
+use crate::application::ports::repositories::BuildRepository;
+use crate::application::services::build_estimate::{estimate, HISTORY_LOOKBACK};
+use crate::db::models::BuildStatus;
+use crate::state::AppContext;
+
+/// 진행률 이벤트 발행 주기
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Build progress monitor
+///
+/// Responsibilities:
+/// - Periodically emit BuildProgress (elapsed, ETA, percent) for running builds,
+///   estimated from the project's recent successful build durations
+pub async fn run_build_progress_monitor(context: AppContext) -> Result<()> {
+    info!("Build progress monitor started");
+
+    let mut tick = interval(PROGRESS_INTERVAL);
+    tick.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        tick.tick().await;
+
+        let running = match context.build_repo.list_by_status(BuildStatus::Building).await {
+            Ok(builds) => builds,
+            Err(e) => {
+                warn!("Build progress monitor failed to list running builds: {}", e);
+                continue;
+            }
+        };
+
+        // 같은 프로젝트 빌드가 여러 개 실행 중이어도 이력은 한 번만 조회
+        let mut histories = HashMap::new();
+        let now = Utc::now();
+        for build in running {
+            if let Entry::Vacant(entry) = histories.entry(build.project_id) {
+                match context.build_repo.list_by_project(build.project_id, HISTORY_LOOKBACK).await {
+                    Ok(history) => {
+                        entry.insert(history);
+                    }
+                    Err(e) => {
+                        warn!("Build progress monitor failed to load history for project {}: {}", build.project_id, e);
+                        continue;
+                    }
+                }
+            }
+
+            if let Some(estimate) = estimate(&build, &histories[&build.project_id], now) {
+                context
+                    .event_bus
+                    .emit(Event::build_progress(build.id, build.project_id, &estimate))
+                    .await;
+            }
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tokio::time::{interval, Duration, MissedTickBehavior};
+use tracing::{debug, info, warn};
+
+use crate::application::ports::repositories::{BuildRepository, ProjectRepository};
+use crate::db::models::{Build, BuildStatus, Project, Slot};
+use crate::infrastructure::timezone;
+use crate::state::AppContext;
+
+/// 보존 정책 확인 주기
+const CHECK_INTERVAL: Duration = Duration::from_secs(3600);
+
+const OUTPUT_ROOT: &str = "/data/output";
+
+/// 프로젝트 빌드 보존 정책
+#[derive(Debug, Clone, Copy)]
+struct RetentionPolicy {
+    /// 보존할 최근 성공 빌드 수
+    keep_successful: Option<i64>,
+    /// 최대 보존 일수
+    max_age_days: Option<i64>,
+}
+
+impl RetentionPolicy {
+    fn of(project: &Project) -> Option<Self> {
+        let policy = Self { keep_successful: project.build_retention_count, max_age_days: project.build_retention_days };
+        (policy.keep_successful.is_some() || policy.max_age_days.is_some()).then_some(policy)
+    }
+}
+
+fn is_successful(build: &Build) -> bool {
+    matches!(build.status, BuildStatus::Success | BuildStatus::Verified)
+}
+
+/// 정리하면 안 되는 빌드: 진행 중/배포 대기, 슬롯별 최근 배포 빌드(서비스 중 + 롤백 대상),
+/// 마지막 성공 빌드(last-known-good), 카나리 빌드, `extra`(프리뷰 컨테이너로 실행 중인 빌드)
+fn protected_builds(builds: &[Build], canary_build_id: Option<i64>, extra: &HashSet<i64>) -> HashSet<i64> {
+    let mut protected = extra.clone();
+    protected.extend(canary_build_id);
+    protected.extend(
+        builds
+            .iter()
+            .filter(|b| matches!(b.status, BuildStatus::Queued | BuildStatus::Building | BuildStatus::Held))
+            .map(|b| b.id),
+    );
+    let newest = |filter: &dyn Fn(&Build) -> bool| builds.iter().filter(|b| filter(b)).max_by_key(|b| b.id).map(|b| b.id);
+    for slot in [Slot::Blue, Slot::Green] {
+        protected.extend(newest(&|b| b.status == BuildStatus::Success && b.get_deployed_slot() == Some(slot)));
+    }
+    protected.extend(newest(&|b| b.status == BuildStatus::Success));
+    protected
+}
+
+/// 보존 정책으로 정리할 빌드
+///
+/// - keep_successful N: N번째로 최근인 성공 빌드보다 오래된 빌드 (실패 빌드 포함)
+/// - max_age_days D: D일보다 오래된 빌드
+///
+/// 보호 대상(protected_builds)은 어느 규칙에도 정리하지 않는다.
+fn builds_to_prune<'a>(builds: &'a [Build], policy: RetentionPolicy, protected: &HashSet<i64>, now: DateTime<Utc>) -> Vec<&'a Build> {
+    let count_cutoff = policy.keep_successful.and_then(|n| {
+        let mut successful: Vec<i64> = builds.iter().filter(|b| is_successful(b)).map(|b| b.id).collect();
+        successful.sort_unstable_by(|a, b| b.cmp(a));
+        successful.get(usize::try_from(n).ok()?.checked_sub(1)?).copied()
+    });
+    let age_cutoff = policy.max_age_days.map(|days| now - ChronoDuration::days(days));
+
+    builds
+        .iter()
+        .filter(|b| !protected.contains(&b.id))
+        .filter(|b| {
+            let over_count = count_cutoff.is_some_and(|cutoff| b.id < cutoff);
+            let too_old = age_cutoff.is_some_and(|cutoff| {
+                timezone::parse_stored(&b.started_at).is_some_and(|started| started < cutoff)
+            });
+            over_count || too_old
+        })
+        .collect()
+}
+
+/// Build retention worker
+///
+/// Runs every hour for projects with a retention policy (build_retention_count /
+/// build_retention_days):
+/// - Removes build outputs (/data/output/build{id}), build/deploy logs, images built
+///   from the project Dockerfile and the build rows (test results/stages cascade)
+/// - Never touches running builds, the builds deployed to each slot, the last
+///   successful build, the canary build or builds backing a live PR preview
+pub async fn run_build_retention(context: AppContext) -> Result<()> {
+    info!("Build retention worker started (runs every hour)");
+
+    let mut check_interval = interval(CHECK_INTERVAL);
+    check_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        check_interval.tick().await;
+
+        let projects = match context.project_repo.list().await {
+            Ok(projects) => projects,
+            Err(e) => {
+                warn!("Build retention failed to list projects: {}", e);
+                continue;
+            }
+        };
+        for project in &projects {
+            let Some(policy) = RetentionPolicy::of(project) else { continue };
+            if let Err(e) = apply_retention(&context, project, policy).await {
+                warn!("Build retention failed for project '{}': {}", project.name, e);
+            }
+        }
+    }
+}
+
+async fn apply_retention(ctx: &AppContext, project: &Project, policy: RetentionPolicy) -> Result<()> {
+    let builds = ctx.build_repo.list_by_project(project.id, i64::MAX).await?;
+    let previews: HashSet<i64> = ctx
+        .preview_repo
+        .list_by_project(project.id)
+        .await?
+        .into_iter()
+        .filter_map(|p| p.build_id)
+        .collect();
+    let protected = protected_builds(&builds, project.canary_build_id, &previews);
+
+    let prune = builds_to_prune(&builds, policy, &protected, Utc::now());
+    if prune.is_empty() {
+        return Ok(());
+    }
+
+    let mut removed = 0;
+    let mut freed_bytes = 0;
+    for build in prune {
+        // 빌드가 끝난 직후 배포가 시작됐을 수 있으므로 배포 중인 프로젝트는 다음 주기에
+        if ctx.build_queue.is_processing(project.id).await {
+            debug!("Project '{}' has a running build, deferring retention", project.name);
+            break;
+        }
+        match prune_build(ctx, build).await {
+            Ok(bytes) => {
+                removed += 1;
+                freed_bytes += bytes;
+            }
+            Err(e) => warn!("Failed to prune build #{} of project '{}': {}", build.build_number, project.name, e),
+        }
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "build.retention_pruned",
+        project_id = project.id,
+        removed,
+        freed_bytes,
+    );
+    info!("Pruned {} build(s) of project '{}' ({} bytes freed)", removed, project.name, freed_bytes);
+    Ok(())
+}
+
+/// 빌드 산출물/로그/이미지와 기록 삭제. 해제한 바이트 수
+async fn prune_build(ctx: &AppContext, build: &Build) -> Result<u64> {
+    let mut paths = vec![
+        build
+            .output_path
+            .as_ref()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| Path::new(OUTPUT_ROOT).join(format!("build{}", build.id))),
+        PathBuf::from(&build.log_path),
+    ];
+    paths.extend(build.deploy_log_path.as_ref().map(PathBuf::from));
+
+    if let Some(image_tag) = &build.image_tag {
+        if let Err(e) = ctx.docker.remove_image(image_tag).await {
+            warn!("Failed to remove image of build #{}: {}", build.build_number, e);
+        }
+    }
+
+    let freed = tokio::task::spawn_blocking(move || paths.iter().map(|p| remove_path(p)).sum()).await?;
+    ctx.build_repo.delete(build.id).await?;
+    Ok(freed)
+}
+
+/// 파일/디렉토리 삭제. 삭제한 크기 (없으면 0)
+fn remove_path(path: &Path) -> u64 {
+    let size = path_size(path);
+    let removed = if path.is_dir() {
+        std::fs::remove_dir_all(path).is_ok()
+    } else {
+        std::fs::remove_file(path).is_ok()
+    };
+    if removed { size } else { 0 }
+}
+
+fn path_size(path: &Path) -> u64 {
+    let Ok(meta) = std::fs::symlink_metadata(path) else { return 0 };
+    if !meta.is_dir() {
+        return meta.len();
+    }
+    std::fs::read_dir(path)
+        .map(|entries| entries.flatten().map(|e| path_size(&e.path())).sum())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(id: i64, status: BuildStatus, slot: Option<&str>, started_at: &str) -> Build {
+        Build {
+            deployed_slot: slot.map(str::to_string),
+            started_at: started_at.to_string(),
+            ..Build::test(id, status)
+        }
+    }
+
+    #[test]
+    fn test_builds_to_prune() {
+        let builds = vec![
+            build(1, BuildStatus::Success, Some("Blue"), "2026-01-01 00:00:00"),
+            build(2, BuildStatus::Failed, None, "2026-01-02 00:00:00"),
+            build(3, BuildStatus::Success, Some("Green"), "2026-01-03 00:00:00"),
+            build(4, BuildStatus::Success, Some("Green"), "2026-01-04 00:00:00"),
+            build(5, BuildStatus::Failed, None, "2026-01-05 00:00:00"),
+            build(6, BuildStatus::Success, Some("Blue"), "2026-01-06 00:00:00"),
+            build(7, BuildStatus::Building, None, "2026-01-07 00:00:00"),
+        ];
+        let now = timezone::parse_stored("2026-01-10 00:00:00").unwrap();
+        let prune = |policy, protected: &HashSet<i64>| -> Vec<i64> {
+            builds_to_prune(&builds, policy, protected, now).iter().map(|b| b.id).collect()
+        };
+
+        // 최근 성공 2개(6, 4)보다 오래된 빌드. 1은 Blue 슬롯의 이전 배포지만 Blue에는 6이 더 최근
+        let keep_two = RetentionPolicy { keep_successful: Some(2), max_age_days: None };
+        let protected = protected_builds(&builds, None, &HashSet::new());
+        assert_eq!(prune(keep_two, &protected), vec![1, 2, 3]);
+
+        // 프리뷰로 실행 중인 빌드와 카나리 빌드는 보존
+        let protected = protected_builds(&builds, Some(1), &HashSet::from([2]));
+        assert_eq!(prune(keep_two, &protected), vec![3]);
+
+        // 최대 보존 일수: 5일보다 오래된 빌드 (서비스 중인 빌드 제외)
+        let five_days = RetentionPolicy { keep_successful: None, max_age_days: Some(5) };
+        let protected = protected_builds(&builds, None, &HashSet::new());
+        assert_eq!(prune(five_days, &protected), vec![1, 2, 3]);
+
+        // 성공 빌드가 N개보다 적으면 개수 규칙으로는 정리하지 않음
+        let keep_ten = RetentionPolicy { keep_successful: Some(10), max_age_days: None };
+        assert!(prune(keep_ten, &protected).is_empty());
+    }
+}
@@ -0,0 +1,266 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tokio::time::{interval, Duration};
+use tracing::{debug, info, warn};
+
+use crate::state::AppContext;
+use crate::application::ports::repositories::{ProjectRepository, SettingsRepository};
+
+/// 빌드 캐시 루트 (/data/cache/{cache_type})
+pub const CACHE_ROOT: &str = "/data/cache";
+
+/// 캐시 용량 제한 설정 키 (JSON, CacheLimits)
+pub const CACHE_LIMITS_SETTING: &str = "cache_limits";
+
+/// 제한 초과 시 이 비율까지 줄임 (매 주기마다 경계선에서 반복 삭제되는 것 방지)
+const LOW_WATER_RATIO: f64 = 0.9;
+
+/// 캐시 용량 제한 (MB)
+///
+/// ```json
+/// { "default_mb": 10240, "per_type": { "gradle": 20480, "npm": 5120 } }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CacheLimits {
+    /// per_type에 없는 캐시에 적용. None이면 제한 없음
+    #[serde(default)]
+    pub default_mb: Option<u64>,
+    #[serde(default)]
+    pub per_type: HashMap<String, u64>,
+}
+
+impl CacheLimits {
+    pub fn limit_for(&self, cache_type: &str) -> Option<u64> {
+        self.per_type.get(cache_type).copied().or(self.default_mb).filter(|mb| *mb > 0)
+    }
+
+    pub async fn load(context: &AppContext) -> CacheLimits {
+        match context.settings_repo.get(CACHE_LIMITS_SETTING).await {
+            Ok(Some(json)) => serde_json::from_str(&json).unwrap_or_else(|e| {
+                warn!("Invalid cache_limits setting, ignoring: {}", e);
+                CacheLimits::default()
+            }),
+            Ok(None) => CacheLimits::default(),
+            Err(e) => {
+                warn!("Failed to load cache_limits setting: {}", e);
+                CacheLimits::default()
+            }
+        }
+    }
+}
+
+/// 캐시 파일 (LRU 판단용)
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    path: PathBuf,
+    size: u64,
+    /// 마지막 사용 시각 (atime과 mtime 중 최신값)
+    last_used: SystemTime,
+}
+
+/// Cache eviction worker
+///
+/// Runs every 30 minutes:
+/// - Measures each cache directory under /data/cache
+/// - If over its limit, deletes least-recently-used files down to 90% of the limit
+/// - Skips caches used by a build that is currently running
+pub async fn run_cache_eviction(context: AppContext) -> Result<()> {
+    info!("Cache eviction worker started (runs every 30 minutes)");
+
+    let mut ticker = interval(Duration::from_secs(30 * 60));
+
+    loop {
+        ticker.tick().await;
+
+        let limits = CacheLimits::load(&context).await;
+        if limits.default_mb.is_none() && limits.per_type.is_empty() {
+            continue;
+        }
+
+        if let Err(e) = evict_caches(&context, &limits).await {
+            warn!("Cache eviction failed: {}", e);
+        }
+    }
+}
+
+async fn evict_caches(context: &AppContext, limits: &CacheLimits) -> Result<()> {
+    let mut dirs = match tokio::fs::read_dir(CACHE_ROOT).await {
+        Ok(d) => d,
+        Err(_) => return Ok(()),
+    };
+
+    let projects = context.project_repo.list().await?;
+
+    while let Some(entry) = dirs.next_entry().await? {
+        if !entry.file_type().await?.is_dir() {
+            continue;
+        }
+        let cache_type = entry.file_name().to_string_lossy().to_string();
+        let Some(limit_mb) = limits.limit_for(&cache_type) else { continue };
+
+        // 이 캐시를 쓰는 빌드가 실행 중이면 이번 주기는 건너뜀
+        let mut in_use = false;
+        for project in projects.iter().filter(|p| p.cache_type == cache_type) {
+            if context.build_queue.is_processing(project.id).await {
+                in_use = true;
+                break;
+            }
+        }
+        if in_use {
+            debug!("Cache {} is in use by a running build, skipping eviction", cache_type);
+            continue;
+        }
+
+        let dir = entry.path();
+        let limit = limit_mb * 1024 * 1024;
+        let (freed, removed) = tokio::task::spawn_blocking(move || evict_dir(&dir, limit)).await??;
+
+        if removed > 0 {
+            info!(
+                "Evicted {} files ({} MB) from {} cache (limit {} MB)",
+                removed,
+                freed / (1024 * 1024),
+                cache_type,
+                limit_mb
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// 디렉토리 크기가 limit을 넘으면 LRU 순으로 삭제. (해제된 bytes, 삭제 파일 수) 반환
+fn evict_dir(dir: &Path, limit: u64) -> Result<(u64, usize)> {
+    let entries = collect_entries(dir);
+    let total: u64 = entries.iter().map(|e| e.size).sum();
+    if total <= limit {
+        return Ok((0, 0));
+    }
+
+    let target = (limit as f64 * LOW_WATER_RATIO) as u64;
+    let mut freed = 0;
+    let mut removed = 0;
+    for entry in select_evictions(entries, total, target) {
+        match std::fs::remove_file(&entry.path) {
+            Ok(()) => {
+                freed += entry.size;
+                removed += 1;
+            }
+            Err(e) => debug!("Failed to evict {}: {}", entry.path.display(), e),
+        }
+    }
+
+    remove_empty_dirs(dir);
+    Ok((freed, removed))
+}
+
+fn collect_entries(dir: &Path) -> Vec<CacheEntry> {
+    let mut entries = Vec::new();
+    let mut stack = vec![dir.to_path_buf()];
+    while let Some(current) = stack.pop() {
+        let Ok(read_dir) = std::fs::read_dir(&current) else { continue };
+        for entry in read_dir.flatten() {
+            let Ok(meta) = entry.metadata() else { continue };
+            if meta.is_dir() {
+                stack.push(entry.path());
+            } else if meta.is_file() {
+                let modified = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                let accessed = meta.accessed().unwrap_or(modified);
+                entries.push(CacheEntry {
+                    path: entry.path(),
+                    size: meta.len(),
+                    last_used: accessed.max(modified),
+                });
+            }
+        }
+    }
+    entries
+}
+
+/// 오래 사용되지 않은 파일부터 total이 target 이하가 될 때까지 선택
+fn select_evictions(mut entries: Vec<CacheEntry>, total: u64, target: u64) -> Vec<CacheEntry> {
+    entries.sort_by_key(|e| e.last_used);
+
+    let mut remaining = total;
+    let mut selected = Vec::new();
+    for entry in entries {
+        if remaining <= target {
+            break;
+        }
+        remaining = remaining.saturating_sub(entry.size);
+        selected.push(entry);
+    }
+    selected
+}
+
+/// 하위 빈 디렉토리 정리 (루트는 유지)
+fn remove_empty_dirs(dir: &Path) {
+    let Ok(read_dir) = std::fs::read_dir(dir) else { return };
+    for entry in read_dir.flatten() {
+        let path = entry.path();
+        if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+            remove_empty_dirs(&path);
+            // 비어 있지 않으면 실패하므로 무시
+            let _ = std::fs::remove_dir(&path);
+        }
+    }
+}
+
+/// 캐시 디렉토리별 현재 사용량 (bytes)
+pub async fn cache_usage() -> HashMap<String, u64> {
+    tokio::task::spawn_blocking(|| {
+        let mut usage = HashMap::new();
+        let Ok(read_dir) = std::fs::read_dir(CACHE_ROOT) else { return usage };
+        for entry in read_dir.flatten() {
+            if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+                let size = collect_entries(&entry.path()).iter().map(|e| e.size).sum();
+                usage.insert(entry.file_name().to_string_lossy().to_string(), size);
+            }
+        }
+        usage
+    })
+    .await
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn entry(name: &str, size: u64, age_secs: u64) -> CacheEntry {
+        CacheEntry {
+            path: PathBuf::from(name),
+            size,
+            last_used: SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000 - age_secs),
+        }
+    }
+
+    #[test]
+    fn test_select_evictions_oldest_first() {
+        let entries = vec![entry("new", 40, 10), entry("old", 30, 1000), entry("mid", 30, 100)];
+        let selected: Vec<String> = select_evictions(entries, 100, 50)
+            .into_iter()
+            .map(|e| e.path.to_string_lossy().to_string())
+            .collect();
+        assert_eq!(selected, vec!["old", "mid"]);
+    }
+
+    #[test]
+    fn test_select_evictions_under_target() {
+        let entries = vec![entry("a", 10, 10)];
+        assert!(select_evictions(entries, 10, 50).is_empty());
+    }
+
+    #[test]
+    fn test_limit_for() {
+        let limits: CacheLimits =
+            serde_json::from_str(r#"{"default_mb": 100, "per_type": {"gradle": 500}}"#).unwrap();
+        assert_eq!(limits.limit_for("gradle"), Some(500));
+        assert_eq!(limits.limit_for("npm"), Some(100));
+        assert_eq!(CacheLimits::default().limit_for("npm"), None);
+    }
+}
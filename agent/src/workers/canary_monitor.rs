@@ -0,0 +1,88 @@
+use anyhow::Result;
+use tokio::time::{interval, Duration, MissedTickBehavior};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::application::ports::repositories::ProjectRepository;
+use crate::application::services::canary_traffic::{evaluate, CanaryVerdict};
+use crate::build::{abort_canary_build, promote_canary_build};
+use crate::db::models::Project;
+use crate::state::{AppContext, DeploymentOperation};
+
+/// 카나리 지표 확인 주기
+const CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Canary monitor worker
+///
+/// Responsibilities:
+/// - Evaluate the proxy's canary/stable error rates of every in-progress canary
+/// - Step the traffic weight up (10 → 25 → 50) while healthy, promote at 100%
+/// - Abort when the canary errors more than the stable slot or its container stops
+pub async fn run_canary_monitor(context: AppContext) -> Result<()> {
+    info!("Canary monitor started");
+
+    let mut check_interval = interval(CHECK_INTERVAL);
+    check_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        check_interval.tick().await;
+
+        let projects = match context.project_repo.list().await {
+            Ok(projects) => projects,
+            Err(e) => {
+                warn!("Canary monitor failed to list projects: {}", e);
+                continue;
+            }
+        };
+
+        for project in projects.into_iter().filter(|p| p.canary_build_id.is_some()) {
+            check_canary(&context, &project).await;
+        }
+    }
+}
+
+async fn check_canary(ctx: &AppContext, project: &Project) {
+    let Some(session) = ctx.deployment_service.canary_session(project) else {
+        return;
+    };
+
+    let verdict = if ctx.docker.is_container_running(&session.target).await {
+        evaluate(&session.metrics())
+    } else {
+        CanaryVerdict::Abort("Canary container is not running".to_string())
+    };
+    if verdict == CanaryVerdict::Continue {
+        return;
+    }
+
+    let trace_id = format!("canary-{}-{}", project.id, Uuid::new_v4());
+    // 다른 배포 작업 중이면 다음 주기에 다시 판단
+    let Ok(_guard) = ctx.deployment_locks.try_acquire(project.id, DeploymentOperation::Canary, &trace_id) else {
+        return;
+    };
+    // 잠금 전에 승격/중단/새 배포로 카나리가 바뀌었을 수 있음
+    let project = match ctx.project_repo.get(project.id).await {
+        Ok(Some(current)) if current.canary_build_id == Some(session.build_id) => current,
+        Ok(_) => return,
+        Err(e) => {
+            warn!("[{}] Canary monitor failed to reload project {}: {}", trace_id, project.id, e);
+            return;
+        }
+    };
+
+    let result = match verdict {
+        CanaryVerdict::Continue => Ok(()),
+        CanaryVerdict::Step(weight) => {
+            info!("[{}] Canary of project '{}' is healthy, raising traffic to {}%", trace_id, project.name, weight);
+            ctx.deployment_service.set_canary_weight(&trace_id, &project, weight).await
+        }
+        CanaryVerdict::Promote => {
+            info!("[{}] Canary of project '{}' is healthy at {}%, promoting", trace_id, project.name, session.weight());
+            promote_canary_build(ctx, &trace_id, &project).await.map(|_| ())
+        }
+        CanaryVerdict::Abort(reason) => abort_canary_build(ctx, &trace_id, &project, &reason).await.map(|_| ()),
+    };
+    if let Err(e) = result {
+        warn!("[{}] Canary monitor failed to update canary of project '{}': {}", trace_id, project.name, e);
+    }
+}
@@ -0,0 +1,79 @@
+use anyhow::Result;
+use chrono::Utc;
+use tokio::time::{interval, Duration, MissedTickBehavior};
+use tracing::{info, warn};
+
+use crate::application::ports::repositories::{ProjectRepository, SettingsRepository};
+use crate::infrastructure::acme::{directory_url, AcmeClient};
+use crate::proxy::tls::{load_or_create_account_key, tls_domains, ACME_EMAIL_SETTING};
+use crate::state::AppContext;
+
+/// 인증서 만료 확인 주기 (설정 변경 시에는 바로 실행)
+const CHECK_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Certificate renewal worker
+///
+/// Responsibilities:
+/// - Obtain certificates for base_domain and project subdomains that have none
+/// - Renew certificates expiring within RENEW_BEFORE_DAYS via ACME HTTP-01
+pub async fn run_cert_renewal(context: AppContext) -> Result<()> {
+    info!("Certificate renewal worker started");
+
+    let mut check_interval = interval(CHECK_INTERVAL);
+    check_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        tokio::select! {
+            _ = check_interval.tick() => {}
+            _ = context.tls.renewal_requested() => {}
+        }
+
+        if let Err(e) = renew_certificates(&context).await {
+            warn!("Certificate renewal failed: {}", e);
+        }
+    }
+}
+
+async fn renew_certificates(ctx: &AppContext) -> Result<()> {
+    if !ctx.tls.enabled() {
+        return Ok(());
+    }
+    let Some(base_domain) = ctx.base_domain() else {
+        return Ok(());
+    };
+    let Some(email) = ctx.settings_repo.get(ACME_EMAIL_SETTING).await? else {
+        warn!("TLS is enabled but no ACME email is configured");
+        return Ok(());
+    };
+
+    // 내부 전용 프로젝트는 외부에 노출하지 않으므로 제외
+    let projects = ctx.project_repo.list().await?;
+    let now = Utc::now();
+    let due: Vec<String> = tls_domains(&base_domain, projects.iter().filter(|p| !p.internal_only).map(|p| p.name.as_str()))
+        .into_iter()
+        .filter(|domain| ctx.tls.needs_renewal(domain, now))
+        .collect();
+    if due.is_empty() {
+        return Ok(());
+    }
+
+    let account_key = load_or_create_account_key().await?;
+    let client = AcmeClient::connect(&directory_url(), &account_key, &email).await?;
+
+    for domain in due {
+        let result = match client.issue(&domain, &ctx.tls.challenges).await {
+            Ok(certificate) => ctx.tls.install(&domain, &certificate.chain_pem, &certificate.key_pem).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(()) => {
+                tracing::info!(target: "audit", event = "tls.certificate_issued", domain = %domain);
+            }
+            Err(e) => {
+                warn!("Failed to obtain certificate for {}: {:#}", domain, e);
+                ctx.tls.set_error(&domain, format!("{:#}", e));
+            }
+        }
+    }
+    Ok(())
+}
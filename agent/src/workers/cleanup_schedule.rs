@@ -0,0 +1,319 @@
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::time::Duration;
+use tracing::{info, warn};
+
+use crate::state::AppContext;
+use crate::application::ports::repositories::SettingsRepository;
+
+/// 설정이 바뀌었는지 확인하는 주기 (대기 중에도 새 스케줄이 반영되도록)
+const RELOAD_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 최소 실행 간격 (너무 짧은 interval로 Docker/DB에 부하 주는 것 방지)
+pub const MIN_INTERVAL_SECS: u64 = 60;
+
+/// 스케줄을 설정할 수 있는 주기 작업
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CleanupWorker {
+    Containers,
+    Sessions,
+    /// 베이스 이미지 업데이트 확인 (see image_update_check)
+    ImageUpdates,
+    /// GitHub 팀 멤버십 동기화 (see github_team_sync)
+    GitHubTeams,
+}
+
+impl CleanupWorker {
+    pub const ALL: [CleanupWorker; 4] = [
+        CleanupWorker::Containers,
+        CleanupWorker::Sessions,
+        CleanupWorker::ImageUpdates,
+        CleanupWorker::GitHubTeams,
+    ];
+
+    pub fn setting_key(&self) -> &'static str {
+        match self {
+            CleanupWorker::Containers => "cleanup_schedule.containers",
+            CleanupWorker::Sessions => "cleanup_schedule.sessions",
+            CleanupWorker::ImageUpdates => "cleanup_schedule.image_updates",
+            CleanupWorker::GitHubTeams => "cleanup_schedule.github_teams",
+        }
+    }
+
+    /// 설정이 없을 때의 기본 주기
+    pub fn default_schedule(&self) -> CleanupSchedule {
+        match self {
+            CleanupWorker::Containers => CleanupSchedule::Interval { interval_secs: 30 * 60 },
+            CleanupWorker::Sessions => CleanupSchedule::Interval { interval_secs: 3600 },
+            // 매일 새벽 3시 (UTC)
+            CleanupWorker::ImageUpdates => CleanupSchedule::Cron { cron: "0 3 * * *".to_string() },
+            CleanupWorker::GitHubTeams => CleanupSchedule::Interval { interval_secs: 3600 },
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "containers" => Some(CleanupWorker::Containers),
+            "sessions" => Some(CleanupWorker::Sessions),
+            "image_updates" => Some(CleanupWorker::ImageUpdates),
+            "github_teams" => Some(CleanupWorker::GitHubTeams),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for CleanupWorker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CleanupWorker::Containers => write!(f, "containers"),
+            CleanupWorker::Sessions => write!(f, "sessions"),
+            CleanupWorker::ImageUpdates => write!(f, "image_updates"),
+            CleanupWorker::GitHubTeams => write!(f, "github_teams"),
+        }
+    }
+}
+
+/// 정리 작업 스케줄 (settings에 JSON으로 저장)
+///
+/// ```json
+/// { "interval_secs": 1800 }
+/// { "cron": "0 3 * * *" }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CleanupSchedule {
+    Interval { interval_secs: u64 },
+    /// 5필드 cron (분 시 일 월 요일, UTC)
+    Cron { cron: String },
+}
+
+impl CleanupSchedule {
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            CleanupSchedule::Interval { interval_secs } if *interval_secs < MIN_INTERVAL_SECS => {
+                Err(format!("interval_secs must be at least {}", MIN_INTERVAL_SECS))
+            }
+            CleanupSchedule::Interval { .. } => Ok(()),
+            CleanupSchedule::Cron { cron } => CronExpr::parse(cron).map(|_| ()),
+        }
+    }
+
+    /// `last_run` 이후 다음 실행 시각
+    pub fn next_after(&self, last_run: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            CleanupSchedule::Interval { interval_secs } => {
+                last_run + ChronoDuration::seconds((*interval_secs).max(MIN_INTERVAL_SECS) as i64)
+            }
+            CleanupSchedule::Cron { cron } => match CronExpr::parse(cron) {
+                Ok(expr) => expr.next_after(last_run),
+                // 잘못된 설정은 저장 시 걸러지지만 혹시 모를 경우 하루 뒤로
+                Err(_) => last_run + ChronoDuration::days(1),
+            },
+        }
+    }
+
+    pub async fn load(context: &AppContext, worker: CleanupWorker) -> CleanupSchedule {
+        match context.settings_repo.get(worker.setting_key()).await {
+            Ok(Some(json)) => match serde_json::from_str::<CleanupSchedule>(&json) {
+                Ok(schedule) if schedule.validate().is_ok() => schedule,
+                _ => {
+                    warn!("Invalid {} cleanup schedule '{}', using default", worker, json);
+                    worker.default_schedule()
+                }
+            },
+            Ok(None) => worker.default_schedule(),
+            Err(e) => {
+                warn!("Failed to load {} cleanup schedule: {}", worker, e);
+                worker.default_schedule()
+            }
+        }
+    }
+}
+
+/// 다음 실행 시각까지 대기. 대기 중 스케줄이 바뀌면 새 스케줄 기준으로 다시 계산한다.
+pub async fn wait_next_run(context: &AppContext, worker: CleanupWorker, last_run: DateTime<Utc>) {
+    let mut schedule = CleanupSchedule::load(context, worker).await;
+    let mut next = schedule.next_after(last_run);
+
+    loop {
+        let now = Utc::now();
+        if now >= next {
+            return;
+        }
+
+        let remaining = (next - now).to_std().unwrap_or_default();
+        tokio::time::sleep(remaining.min(RELOAD_INTERVAL)).await;
+
+        let reloaded = CleanupSchedule::load(context, worker).await;
+        if reloaded != schedule {
+            info!("{} cleanup schedule changed to {:?}", worker, reloaded);
+            schedule = reloaded;
+            next = schedule.next_after(last_run);
+        }
+    }
+}
+
+/// 5필드 cron 표현식 (`*`, 숫자, `a-b`, `a,b`, `*/n`, `a-b/n` 지원)
+#[derive(Debug, Clone)]
+pub struct CronExpr {
+    minutes: Vec<bool>,
+    hours: Vec<bool>,
+    days: Vec<bool>,
+    months: Vec<bool>,
+    weekdays: Vec<bool>,
+    /// 일/요일 중 하나라도 `*`이면 AND, 둘 다 지정되면 OR (표준 cron 동작)
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+impl CronExpr {
+    pub fn parse(expr: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err("cron must have 5 fields (minute hour day month weekday)".to_string());
+        }
+
+        let mut weekdays = parse_field(fields[4], 0, 7)?;
+        // 7 = 일요일
+        if weekdays[7] {
+            weekdays[0] = true;
+        }
+
+        Ok(Self {
+            minutes: parse_field(fields[0], 0, 59)?,
+            hours: parse_field(fields[1], 0, 23)?,
+            days: parse_field(fields[2], 1, 31)?,
+            months: parse_field(fields[3], 1, 12)?,
+            weekdays,
+            days_restricted: fields[2] != "*",
+            weekdays_restricted: fields[4] != "*",
+        })
+    }
+
+    fn matches(&self, t: &DateTime<Utc>) -> bool {
+        let day_ok = self.days[t.day() as usize];
+        let weekday_ok = self.weekdays[t.weekday().num_days_from_sunday() as usize];
+        let date_ok = if self.days_restricted && self.weekdays_restricted {
+            day_ok || weekday_ok
+        } else {
+            day_ok && weekday_ok
+        };
+
+        self.minutes[t.minute() as usize]
+            && self.hours[t.hour() as usize]
+            && self.months[t.month() as usize]
+            && date_ok
+    }
+
+    /// `after` 이후(초과) 처음 일치하는 분. 4년 안에 없으면 (예: 2월 31일) 하루 뒤
+    pub fn next_after(&self, after: DateTime<Utc>) -> DateTime<Utc> {
+        let start = after
+            .with_second(0)
+            .and_then(|t| t.with_nanosecond(0))
+            .unwrap_or(after)
+            + ChronoDuration::minutes(1);
+
+        let mut t = start;
+        let limit = start + ChronoDuration::days(366 * 4);
+        while t < limit {
+            if !self.months[t.month() as usize] {
+                // 다음 달 1일 0시로 건너뜀
+                let (year, month) = if t.month() == 12 { (t.year() + 1, 1) } else { (t.year(), t.month() + 1) };
+                t = t
+                    .with_day(1)
+                    .and_then(|d| d.with_month(month))
+                    .and_then(|d| d.with_year(year))
+                    .and_then(|d| d.with_hour(0))
+                    .and_then(|d| d.with_minute(0))
+                    .unwrap_or(t + ChronoDuration::days(1));
+                continue;
+            }
+            if self.matches(&t) {
+                return t;
+            }
+            t += ChronoDuration::minutes(1);
+        }
+        after + ChronoDuration::days(1)
+    }
+}
+
+fn parse_field(field: &str, min: u32, max: u32) -> Result<Vec<bool>, String> {
+    let mut allowed = vec![false; max as usize + 1];
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step.parse().map_err(|_| format!("Invalid step in '{}'", part))?;
+                if step == 0 {
+                    return Err(format!("Step must be positive in '{}'", part));
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((a, b)) = range.split_once('-') {
+            let a: u32 = a.parse().map_err(|_| format!("Invalid value in '{}'", part))?;
+            let b: u32 = b.parse().map_err(|_| format!("Invalid value in '{}'", part))?;
+            (a, b)
+        } else {
+            let v: u32 = range.parse().map_err(|_| format!("Invalid value in '{}'", part))?;
+            // "5/15" = 5부터 끝까지 15 간격
+            if step > 1 { (v, max) } else { (v, v) }
+        };
+
+        if start < min || end > max || start > end {
+            return Err(format!("Value out of range ({}-{}) in '{}'", min, max, part));
+        }
+
+        let mut v = start;
+        while v <= end {
+            allowed[v as usize] = true;
+            v += step;
+        }
+    }
+
+    Ok(allowed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_cron_next_daily() {
+        let expr = CronExpr::parse("30 3 * * *").unwrap();
+        let after = Utc.with_ymd_and_hms(2024, 5, 1, 10, 0, 0).unwrap();
+        assert_eq!(expr.next_after(after), Utc.with_ymd_and_hms(2024, 5, 2, 3, 30, 0).unwrap());
+    }
+
+    #[test]
+    fn test_cron_next_step_and_weekday() {
+        let every_15 = CronExpr::parse("*/15 * * * *").unwrap();
+        let after = Utc.with_ymd_and_hms(2024, 5, 1, 10, 7, 12).unwrap();
+        assert_eq!(every_15.next_after(after), Utc.with_ymd_and_hms(2024, 5, 1, 10, 15, 0).unwrap());
+
+        // 2024-05-01은 수요일 → 다음 일요일(7)은 05-05
+        let sunday = CronExpr::parse("0 0 * * 7").unwrap();
+        assert_eq!(sunday.next_after(after), Utc.with_ymd_and_hms(2024, 5, 5, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_cron_invalid() {
+        assert!(CronExpr::parse("* * * *").is_err());
+        assert!(CronExpr::parse("60 * * * *").is_err());
+        assert!(CronExpr::parse("*/0 * * * *").is_err());
+    }
+
+    #[test]
+    fn test_schedule_json() {
+        let interval: CleanupSchedule = serde_json::from_str(r#"{"interval_secs": 600}"#).unwrap();
+        assert_eq!(interval, CleanupSchedule::Interval { interval_secs: 600 });
+        let cron: CleanupSchedule = serde_json::from_str(r#"{"cron": "0 3 * * *"}"#).unwrap();
+        assert!(cron.validate().is_ok());
+        assert!(CleanupSchedule::Interval { interval_secs: 5 }.validate().is_err());
+    }
+}
@@ -1,28 +1,26 @@
 use anyhow::Result;
 use std::collections::HashSet;
-use tokio::time::{interval, Duration};
+use chrono::Utc;
 use tracing::{info, warn};
 
 use crate::state::AppContext;
 use crate::docker::DockerClient;
+use crate::workers::cleanup_schedule::{wait_next_run, CleanupSchedule, CleanupWorker};
 use crate::application::ports::repositories::{ProjectRepository, ContainerRepository};
 
 /// Container cleanup worker
 ///
-/// Runs periodically to clean up orphaned and stale containers:
+/// Runs on a settings-backed schedule (default: every 30 minutes, see cleanup_schedule)
+/// to clean up orphaned and stale containers:
 /// - Build containers (build-*) that have exited
 /// - Project containers (project-*-blue/green) without matching DB entries
 /// - Standalone containers (container-*) without matching DB entries
 /// - Stopped containers that are no longer needed
 pub async fn run_container_cleanup(context: AppContext) -> Result<()> {
-    info!("Starting container cleanup worker (runs every 30 minutes)");
-
-    // Run every 30 minutes
-    let mut ticker = interval(Duration::from_secs(30 * 60));
+    info!("Starting container cleanup worker (schedule: {:?})",
+        CleanupSchedule::load(&context, CleanupWorker::Containers).await);
 
     loop {
-        ticker.tick().await;
-
         info!("🧹 Running periodic container cleanup...");
 
         if let Err(e) = cleanup_containers(&context).await {
@@ -30,11 +28,13 @@ pub async fn run_container_cleanup(context: AppContext) -> Result<()> {
         } else {
             info!("✅ Container cleanup completed successfully");
         }
+
+        wait_next_run(&context, CleanupWorker::Containers, Utc::now()).await;
     }
 }
 
-/// Perform container cleanup
-async fn cleanup_containers(context: &AppContext) -> Result<()> {
+/// Perform container cleanup. Returns the number of removed containers
+pub async fn cleanup_containers(context: &AppContext) -> Result<usize> {
     let docker = &context.docker;
 
     // Get all containers (including stopped ones)
@@ -46,8 +46,8 @@ async fn cleanup_containers(context: &AppContext) -> Result<()> {
         }))
         .await?;
 
-    // Get valid project IDs from database
-    let projects = context.project_repo.list().await?;
+    // Get valid project IDs from database (유예 중인 삭제 프로젝트의 컨테이너도 복원용으로 유지)
+    let projects = context.project_repo.list_with_deleted().await?;
     let valid_project_ids: HashSet<i64> = projects.iter().map(|p| p.id).collect();
 
     // Get valid container names from database
@@ -126,5 +126,5 @@ async fn cleanup_containers(context: &AppContext) -> Result<()> {
         info!("✨ No orphaned containers found");
     }
 
-    Ok(())
+    Ok(cleaned_count)
 }
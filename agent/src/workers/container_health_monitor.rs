@@ -1,15 +1,17 @@
 use anyhow::Result;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::RwLock;
-use tokio::time::{sleep, Duration};
-use tracing::{info, error};
+use tokio::time::{sleep, timeout, Duration};
+use tracing::{info, error, warn};
 
 use crate::state::AppContext;
 use crate::events::Event;
-use crate::db::models::Slot;
+use crate::db::models::{Container, ContainerHealth, ContainerHealthCheck, ContainerStatus, HealthProbe, Slot};
 use crate::application::events::event_bus::EventBus;
-use crate::application::ports::repositories::ProjectRepository;
+use crate::application::ports::repositories::{ContainerRepository, ProjectRepository};
+use crate::proxy::routes::container_target;
 
 /// Container health monitoring worker
 ///
@@ -19,13 +21,18 @@ use crate::application::ports::repositories::ProjectRepository;
 /// - Emit ContainerStatus events when state changes
 /// - Track previous state to avoid duplicate events
 /// - Monitor both Blue and Green slots for all projects
+/// - Run configured health checks (tcp/http/command) for standalone containers
 pub async fn run_container_health_monitor(context: AppContext) -> Result<()> {
     info!("Container health monitor worker started");
 
     // Track previous state: (project_id, slot) -> is_running
     let previous_state: Arc<RwLock<HashMap<(i64, Slot), bool>>> = Arc::new(RwLock::new(HashMap::new()));
+    // Standalone container id -> health check state
+    let mut health_trackers: HashMap<i64, HealthTracker> = HashMap::new();
 
     loop {
+        check_standalone_containers(&context, &mut health_trackers).await;
+
         // Get all projects from database
         let projects = match context.project_repo.list().await {
             Ok(projects) => projects,
@@ -39,13 +46,20 @@ pub async fn run_container_health_monitor(context: AppContext) -> Result<()> {
         for project in projects {
             // Check both Blue and Green slots
             for slot in [Slot::Blue, Slot::Green] {
-                let container_id = match slot {
-                    Slot::Blue => &project.blue_container_id,
-                    Slot::Green => &project.green_container_id,
+                // 수동 docker 조작으로 저장된 ID가 stale이면 이름으로 다시 찾아 DB 갱신
+                let container_id = match context.docker.resolve_container(context.project_repo.as_ref(), project.id, slot).await {
+                    Ok(container_id) => container_id,
+                    Err(e) => {
+                        warn!("[Project:{}] Failed to resolve {} container: {}", project.name, slot, e);
+                        match slot {
+                            Slot::Blue => project.blue_container_id.clone(),
+                            Slot::Green => project.green_container_id.clone(),
+                        }
+                    }
                 };
 
                 // If no container ID, it means no container exists for this slot
-                let is_running = if let Some(cid) = container_id {
+                let is_running = if let Some(ref cid) = container_id {
                     // Check if container is running via DockerClient method
                     context.docker.is_container_running(cid).await
                 } else {
@@ -90,3 +104,192 @@ pub async fn run_container_health_monitor(context: AppContext) -> Result<()> {
         sleep(Duration::from_secs(10)).await;
     }
 }
+
+/// 독립 컨테이너 하나의 헬스체크 진행 상태 (Docker 컨테이너가 바뀌면 새로 시작)
+#[derive(Debug)]
+struct HealthTracker {
+    docker_id: String,
+    started_at: Instant,
+    last_check: Option<Instant>,
+    failures: u32,
+    health: ContainerHealth,
+}
+
+impl HealthTracker {
+    fn new(docker_id: String, now: Instant) -> Self {
+        Self {
+            docker_id,
+            started_at: now,
+            last_check: None,
+            failures: 0,
+            health: ContainerHealth::Starting,
+        }
+    }
+
+    fn is_due(&self, config: &ContainerHealthCheck, now: Instant) -> bool {
+        self.last_check
+            .is_none_or(|last| now.duration_since(last) >= Duration::from_secs(config.interval_secs))
+    }
+
+    /// 검사 결과 반영 (Docker HEALTHCHECK 규칙).
+    /// 성공하면 healthy, 연속 실패가 retries에 도달하면 unhealthy.
+    /// start period 안의 실패는 아직 healthy가 된 적이 없으면 세지 않는다.
+    fn record(&mut self, config: &ContainerHealthCheck, ok: bool, now: Instant) -> ContainerHealth {
+        self.last_check = Some(now);
+        if ok {
+            self.failures = 0;
+            self.health = ContainerHealth::Healthy;
+            return self.health;
+        }
+
+        let in_start_period = now.duration_since(self.started_at) < Duration::from_secs(config.start_period_secs);
+        if in_start_period && self.health == ContainerHealth::Starting {
+            return self.health;
+        }
+
+        self.failures += 1;
+        if self.failures >= config.retries {
+            self.health = ContainerHealth::Unhealthy;
+        }
+        self.health
+    }
+}
+
+/// 헬스체크가 설정된 실행 중 독립 컨테이너를 주기(interval_secs)에 맞춰 검사하고 상태 변화만 DB에 기록
+async fn check_standalone_containers(context: &AppContext, trackers: &mut HashMap<i64, HealthTracker>) {
+    let containers = match context.container_repo.list().await {
+        Ok(containers) => containers,
+        Err(e) => {
+            error!("Failed to list containers: {}", e);
+            return;
+        }
+    };
+
+    let now = Instant::now();
+    let mut seen = Vec::with_capacity(containers.len());
+
+    for container in &containers {
+        let config = container.health_check.as_deref()
+            .and_then(|json| serde_json::from_str::<ContainerHealthCheck>(json).ok());
+        let running_id = container.container_id.as_ref()
+            .filter(|_| container.status == ContainerStatus::Running);
+
+        let (Some(config), Some(docker_id)) = (config, running_id) else {
+            // 헬스체크 없음/중지: 남은 결과 정리
+            if container.health_status.is_some() && container.status != ContainerStatus::Running {
+                if let Err(e) = context.container_repo.update_health_status(container.id, None).await {
+                    warn!("Failed to clear health of container {}: {}", container.name, e);
+                }
+            }
+            continue;
+        };
+        seen.push(container.id);
+
+        let tracker = trackers.entry(container.id).or_insert_with(|| HealthTracker::new(docker_id.clone(), now));
+        if tracker.docker_id != *docker_id {
+            *tracker = HealthTracker::new(docker_id.clone(), now);
+        }
+        if !tracker.is_due(&config, now) {
+            continue;
+        }
+
+        let previous = tracker.health;
+        let ok = probe(context, container, docker_id, &config).await;
+        let health = tracker.record(&config, ok, now);
+
+        if container.health_status.as_deref() != Some(&health.to_string()) {
+            if previous != health {
+                info!("Container {} health changed: {} -> {}", container.name, previous, health);
+            }
+            if let Err(e) = context.container_repo.update_health_status(container.id, Some(health)).await {
+                warn!("Failed to record health of container {}: {}", container.name, e);
+            }
+        }
+    }
+
+    trackers.retain(|id, _| seen.contains(id));
+}
+
+/// 설정된 방식으로 한 번 검사 (timeout_secs 안에 성공해야 true)
+async fn probe(context: &AppContext, container: &Container, docker_id: &str, config: &ContainerHealthCheck) -> bool {
+    let (host, port) = container_target(container);
+    let limit = Duration::from_secs(config.timeout_secs);
+
+    match &config.probe {
+        HealthProbe::Tcp => timeout(limit, tokio::net::TcpStream::connect((host.as_str(), port as u16)))
+            .await
+            .is_ok_and(|r| r.is_ok()),
+        HealthProbe::Http { path, expected_status } => {
+            let url = format!("http://{}:{}{}", host, port, path);
+            let client = reqwest::Client::builder()
+                .timeout(limit)
+                .redirect(reqwest::redirect::Policy::none())
+                .build();
+            match client {
+                Ok(client) => match client.get(&url).send().await {
+                    Ok(res) => match expected_status {
+                        Some(code) => res.status().as_u16() == *code,
+                        None => res.status().is_success() || res.status().is_redirection(),
+                    },
+                    Err(_) => false,
+                },
+                Err(_) => false,
+            }
+        }
+        HealthProbe::Command { command } => {
+            timeout(limit, context.docker.exec_exit_code(docker_id, command))
+                .await
+                .is_ok_and(|r| matches!(r, Ok(0)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(retries: u32, start_period_secs: u64) -> ContainerHealthCheck {
+        ContainerHealthCheck {
+            probe: HealthProbe::Tcp,
+            interval_secs: 10,
+            timeout_secs: 5,
+            retries,
+            start_period_secs,
+        }
+    }
+
+    #[test]
+    fn test_failures_reach_unhealthy_after_retries() {
+        let cfg = config(3, 0);
+        let start = Instant::now();
+        let mut tracker = HealthTracker::new("c1".to_string(), start);
+
+        assert_eq!(tracker.record(&cfg, true, start), ContainerHealth::Healthy);
+        assert_eq!(tracker.record(&cfg, false, start), ContainerHealth::Healthy);
+        assert_eq!(tracker.record(&cfg, false, start), ContainerHealth::Healthy);
+        assert_eq!(tracker.record(&cfg, false, start), ContainerHealth::Unhealthy);
+        assert_eq!(tracker.record(&cfg, true, start), ContainerHealth::Healthy);
+    }
+
+    #[test]
+    fn test_start_period_failures_are_not_counted() {
+        let cfg = config(1, 60);
+        let start = Instant::now();
+        let mut tracker = HealthTracker::new("c1".to_string(), start);
+
+        assert_eq!(tracker.record(&cfg, false, start + Duration::from_secs(10)), ContainerHealth::Starting);
+        assert_eq!(tracker.record(&cfg, false, start + Duration::from_secs(70)), ContainerHealth::Unhealthy);
+    }
+
+    #[test]
+    fn test_is_due_respects_interval() {
+        let cfg = config(3, 0);
+        let start = Instant::now();
+        let mut tracker = HealthTracker::new("c1".to_string(), start);
+
+        assert!(tracker.is_due(&cfg, start));
+        tracker.record(&cfg, true, start);
+        assert!(!tracker.is_due(&cfg, start + Duration::from_secs(5)));
+        assert!(tracker.is_due(&cfg, start + Duration::from_secs(10)));
+    }
+}
@@ -11,6 +11,11 @@ use crate::events::Event;
 use crate::db::models::ContainerStatus;
 use crate::application::events::event_bus::EventBus;
 use crate::application::ports::repositories::ContainerRepository;
+use crate::infrastructure::logging::ContainerLogStore;
+use crate::infrastructure::logging::container_log_store::LogRetention;
+
+/// 삭제된 컨테이너 로그 정리 및 보관 기간 적용 주기 (루프 횟수, 5초 × 12 = 1분)
+const PRUNE_EVERY: u64 = 12;
 
 /// Container log streaming worker
 ///
@@ -18,6 +23,8 @@ use crate::application::ports::repositories::ContainerRepository;
 /// - Monitor all running standalone containers
 /// - Stream logs in real-time for each running container
 /// - Emit ContainerLog events to WebSocket clients
+/// - Append logs to per-container ring files (download/search, kept across agent restarts)
+/// - Enforce per-container log retention (size via ring segments, age via periodic sweep)
 /// - Auto-restart streaming when containers start/stop
 /// - Track active streams to prevent duplicates
 pub async fn run_container_log_streamer(context: AppContext) -> Result<()> {
@@ -25,6 +32,8 @@ pub async fn run_container_log_streamer(context: AppContext) -> Result<()> {
 
     // Track which containers are currently being streamed
     let active_streams: Arc<RwLock<HashSet<i64>>> = Arc::new(RwLock::new(HashSet::new()));
+    let log_store = ContainerLogStore::default();
+    let mut iteration: u64 = 0;
 
     loop {
         // Get all running containers from database
@@ -37,6 +46,26 @@ pub async fn run_container_log_streamer(context: AppContext) -> Result<()> {
             }
         };
 
+        // Remove ring files of deleted containers and expired segments (about once a minute)
+        if iteration.is_multiple_of(PRUNE_EVERY) {
+            let ids: Vec<i64> = containers.iter().map(|c| c.id).collect();
+            match log_store.prune(&ids).await {
+                Ok(0) => {}
+                Ok(removed) => info!("Removed logs of {} deleted container(s)", removed),
+                Err(e) => warn!("Failed to prune container logs: {}", e),
+            }
+
+            for container in &containers {
+                let retention = LogRetention::new(container.log_max_size_mb, container.log_retention_days);
+                match log_store.enforce_age(container.id, retention.max_age).await {
+                    Ok(0) => {}
+                    Ok(freed) => info!("[Container:{}] Removed {} bytes of expired logs", container.name, freed),
+                    Err(e) => warn!("[Container:{}] Failed to apply log retention: {}", container.name, e),
+                }
+            }
+        }
+        iteration += 1;
+
         // Spawn log streaming tasks for running containers
         for container in containers {
             if container.status != ContainerStatus::Running {
@@ -56,6 +85,8 @@ pub async fn run_container_log_streamer(context: AppContext) -> Result<()> {
                 let container_name = container.name.clone();
                 let docker_id = docker_id_ref.clone(); // Clone to owned String for async move
                 let streams = active_streams.clone();
+                let store = log_store.clone();
+                let retention = LogRetention::new(container.log_max_size_mb, container.log_retention_days);
 
                 // Mark as active
                 streams.write().await.insert(container_id);
@@ -63,7 +94,17 @@ pub async fn run_container_log_streamer(context: AppContext) -> Result<()> {
                 tokio::spawn(async move {
                     info!("[Container:{}] Starting log stream for docker:{}", container_name, docker_id);
 
-                    match ctx.docker.stream_container_logs(&docker_id, None).await {
+                    // 이미 파일에 남긴 로그는 건너뛰고 이어 받음 (에이전트 재시작/스트림 재연결)
+                    let since = store.last_written(container_id).await;
+                    let mut writer = match store.writer(container_id, &retention).await {
+                        Ok(writer) => Some(writer),
+                        Err(e) => {
+                            warn!("[Container:{}] Failed to open log file: {}", container_name, e);
+                            None
+                        }
+                    };
+
+                    match ctx.docker.stream_container_logs_since(&docker_id, since).await {
                         Ok(mut log_stream) => {
                             while let Some(log_result) = log_stream.next().await {
                                 match log_result {
@@ -71,6 +112,13 @@ pub async fn run_container_log_streamer(context: AppContext) -> Result<()> {
                                         // Convert bytes to string
                                         let line = String::from_utf8_lossy(&bytes).to_string();
                                         if !line.is_empty() {
+                                            if let Some(w) = writer.as_mut() {
+                                                if let Err(e) = w.write_line(&line).await {
+                                                    warn!("[Container:{}] Failed to write log file: {}", container_name, e);
+                                                    writer = None;
+                                                }
+                                            }
+
                                             // Emit log event to WebSocket
                                             let event = Event::container_log(
                                                 container_id,
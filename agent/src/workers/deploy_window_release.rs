@@ -0,0 +1,81 @@
+use anyhow::Result;
+use std::collections::HashMap;
+use tokio::time::{interval, Duration, MissedTickBehavior};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::application::ports::repositories::{BuildRepository, ProjectRepository};
+use crate::application::services::deploy_allowed_now;
+use crate::build::release_held_build;
+use crate::db::models::{Build, BuildStatus};
+use crate::state::{AppContext, DeploymentOperation};
+
+/// Held 빌드 확인 주기
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Deploy window release worker
+///
+/// Responsibilities:
+/// - Find builds held outside their project's deploy window (BuildStatus::Held)
+/// - When the window opens, deploy the newest held build of each project
+///   (older held builds are superseded and marked Verified)
+pub async fn run_deploy_window_release(context: AppContext) -> Result<()> {
+    info!("Deploy window release worker started");
+
+    let mut check_interval = interval(CHECK_INTERVAL);
+    check_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        check_interval.tick().await;
+
+        let held = match context.build_repo.list_by_status(BuildStatus::Held).await {
+            Ok(builds) => builds,
+            Err(e) => {
+                warn!("Deploy window release failed to list held builds: {}", e);
+                continue;
+            }
+        };
+
+        // 프로젝트별 가장 최근 Held 빌드만 배포 (오래된 순으로 정렬되어 있으므로 덮어쓰기)
+        let mut latest: HashMap<i64, Build> = HashMap::new();
+        for build in held {
+            latest.insert(build.project_id, build);
+        }
+
+        for (project_id, build) in latest {
+            let project = match context.project_repo.get(project_id).await {
+                Ok(Some(project)) => project,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!("Deploy window release failed to load project {}: {}", project_id, e);
+                    continue;
+                }
+            };
+            if project.deleted_at.is_some() || project.archived_at.is_some() || !deploy_allowed_now(&project) {
+                continue;
+            }
+
+            let trace_id = format!("deploy-window-{}-{}", project_id, Uuid::new_v4());
+            // 롤백/재배포 중이면 다음 주기에 다시 시도
+            let Ok(_guard) = context.deployment_locks.try_acquire(project_id, DeploymentOperation::Deploy, &trace_id) else {
+                continue;
+            };
+            // 잠금 전에 수동 release로 이미 배포되었을 수 있음
+            match context.build_repo.get(build.id).await {
+                Ok(Some(current)) if current.status == BuildStatus::Held => {}
+                _ => continue,
+            }
+
+            info!(
+                "[{}] Deploy window open, releasing held build #{} for project '{}'",
+                trace_id, build.build_number, project.name
+            );
+            if release_held_build(&context, &trace_id, &build).await.is_ok() {
+                info!(
+                    "[{}] Held build #{} for project '{}' deployed",
+                    trace_id, build.build_number, project.name
+                );
+            }
+        }
+    }
+}
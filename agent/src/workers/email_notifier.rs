@@ -0,0 +1,164 @@
+use anyhow::Result;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+use crate::application::events::Event;
+use crate::application::ports::repositories::{BuildRepository, ProjectRepository};
+use crate::db::models::{BuildStatus, Project};
+use crate::infrastructure::notifications::email_client::{
+    build_failure_email, deployment_email, send_email, EmailContent, SmtpSettings,
+};
+use crate::state::AppContext;
+
+/// 전송 한도 집계 구간
+const RATE_WINDOW: Duration = Duration::from_secs(3600);
+
+/// 프로젝트별 전송 한도 (RATE_WINDOW 동안 최대 N통, 슬라이딩 윈도우)
+#[derive(Debug, Default)]
+struct RateLimiter {
+    sent: HashMap<i64, VecDeque<Instant>>,
+}
+
+impl RateLimiter {
+    /// 한도 안이면 전송으로 기록하고 true
+    fn allow(&mut self, project_id: i64, max_per_window: u32, now: Instant) -> bool {
+        let sent = self.sent.entry(project_id).or_default();
+        while sent.front().is_some_and(|t| now.duration_since(*t) >= RATE_WINDOW) {
+            sent.pop_front();
+        }
+        if sent.len() >= max_per_window as usize {
+            return false;
+        }
+        sent.push_back(now);
+        true
+    }
+}
+
+/// Email notifier
+///
+/// Responsibilities:
+/// - Send build failure and deployment result emails (HTML) to each project's
+///   notification_emails through the SMTP server configured in settings (`smtp`)
+/// - Rate limit per project (max_emails_per_hour); emails over the limit are dropped
+pub async fn run_email_notifier(context: AppContext) -> Result<()> {
+    info!("Email notifier started");
+
+    let mut event_rx = context.subscribe_events();
+    let base_url = std::env::var("BASE_URL").unwrap_or_else(|_| "http://localhost:10000".to_string());
+    let mut limiter = RateLimiter::default();
+
+    loop {
+        let event = match event_rx.recv().await {
+            Ok(event) => event,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!("Email notifier lagged, skipped {} events", skipped);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => {
+                info!("Event bus closed, email notifier stopping");
+                break;
+            }
+        };
+
+        let prepared = match prepare_email(&context, &event, &base_url).await {
+            Ok(Some(prepared)) => prepared,
+            Ok(None) => continue,
+            Err(e) => {
+                warn!("Failed to prepare email notification: {}", e);
+                continue;
+            }
+        };
+        let (settings, project, content) = prepared;
+
+        if !limiter.allow(project.id, settings.max_emails_per_hour, Instant::now()) {
+            warn!(
+                "Email rate limit ({}/hour) reached for project '{}', dropping '{}'",
+                settings.max_emails_per_hour, project.name, content.subject
+            );
+            continue;
+        }
+
+        // SMTP 응답이 느려도 다른 이벤트를 놓치지 않도록 분리
+        tokio::spawn(async move {
+            let recipients = project.parsed_notification_emails();
+            match send_email(&settings, &recipients, content).await {
+                Ok(()) => debug!("Sent email notification for project '{}' to {} recipient(s)", project.name, recipients.len()),
+                Err(e) => warn!("Failed to send email notification for project '{}': {:#}", project.name, e),
+            }
+        });
+    }
+
+    Ok(())
+}
+
+/// 보낼 메일 (SMTP 설정, 프로젝트, 내용). 대상 이벤트가 아니거나 SMTP/수신자가 없으면 None
+async fn prepare_email(
+    ctx: &AppContext,
+    event: &Event,
+    base_url: &str,
+) -> Result<Option<(SmtpSettings, Project, EmailContent)>> {
+    let (project_id, build_id) = match event {
+        Event::BuildStatus { project_id, build_id, status: BuildStatus::Failed, .. } => (*project_id, *build_id),
+        Event::Deployment { project_id, build_id, status, .. }
+            if status == "Success" || status.contains("fail") || status.contains("Fail") =>
+        {
+            (*project_id, *build_id)
+        }
+        _ => return Ok(None),
+    };
+
+    let Some(settings) = SmtpSettings::load(ctx.settings_repo.as_ref()).await? else { return Ok(None) };
+    let Some(project) = ctx.project_repo.get(project_id).await? else { return Ok(None) };
+    if project.parsed_notification_emails().is_empty() {
+        return Ok(None);
+    }
+    let Some(build) = ctx.build_repo.get(build_id).await? else { return Ok(None) };
+
+    let build_url = format!("{}/builds/{}", base_url, build.id);
+    let notes_url = project.notes.as_ref().map(|_| format!("{}/projects/{}/notes", base_url, project.id));
+
+    let content = match event {
+        Event::Deployment { status, slot, url, .. } => deployment_email(
+            &project.name,
+            build.build_number,
+            &slot.to_string(),
+            status == "Success",
+            url,
+            &build_url,
+            notes_url.as_deref(),
+        ),
+        _ => build_failure_email(
+            &project.name,
+            build.build_number,
+            &project.branch,
+            &build.commit_hash,
+            build.failure_summary.as_deref(),
+            &build_url,
+            notes_url.as_deref(),
+        ),
+    };
+
+    Ok(Some((settings, project, content)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limiter() {
+        let mut limiter = RateLimiter::default();
+        let start = Instant::now();
+
+        assert!(limiter.allow(1, 2, start));
+        assert!(limiter.allow(1, 2, start + Duration::from_secs(10)));
+        assert!(!limiter.allow(1, 2, start + Duration::from_secs(20)));
+        // 다른 프로젝트는 별도 한도
+        assert!(limiter.allow(2, 2, start + Duration::from_secs(20)));
+        // 첫 전송이 구간을 벗어나면 다시 허용
+        assert!(limiter.allow(1, 2, start + RATE_WINDOW));
+        assert!(!limiter.allow(1, 2, start + RATE_WINDOW + Duration::from_secs(1)));
+    }
+}
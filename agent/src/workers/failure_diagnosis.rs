@@ -0,0 +1,70 @@
+use anyhow::Result;
+use tokio::sync::broadcast;
+use tracing::{info, warn};
+
+use crate::application::events::Event;
+use crate::application::ports::repositories::{BuildRepository, ProjectRepository};
+use crate::db::models::BuildStatus;
+use crate::infrastructure::failure_diagnosis::{diagnose, FailureDiagnosisConfig};
+use crate::state::AppContext;
+
+/// Failure diagnosis worker
+///
+/// Responsibilities:
+/// - Send the failure summary and log tail of failed builds to the configured
+///   OpenAI-compatible endpoint (FAILURE_DIAGNOSIS_URL)
+/// - Attach the suggested diagnosis to the build (builds.diagnosis)
+pub async fn run_failure_diagnosis(config: FailureDiagnosisConfig, context: AppContext) -> Result<()> {
+    info!("Failure diagnosis worker started (model {})", config.model);
+
+    let mut event_rx = context.subscribe_events();
+    let client = reqwest::Client::new();
+
+    loop {
+        match event_rx.recv().await {
+            Ok(Event::BuildStatus { build_id, status: BuildStatus::Failed, .. }) => {
+                // 응답이 느려도 다른 이벤트를 놓치지 않도록 빌드별로 분리
+                let (context, client, config) = (context.clone(), client.clone(), config.clone());
+                tokio::spawn(async move {
+                    if let Err(e) = diagnose_build(&context, &client, &config, build_id).await {
+                        warn!("Failed to diagnose build {}: {}", build_id, e);
+                    }
+                });
+            }
+            Ok(_) => {}
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!("Failure diagnosis worker lagged, skipped {} events", skipped);
+            }
+            Err(broadcast::error::RecvError::Closed) => {
+                info!("Event bus closed, failure diagnosis worker stopping");
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn diagnose_build(
+    ctx: &AppContext,
+    client: &reqwest::Client,
+    config: &FailureDiagnosisConfig,
+    build_id: i64,
+) -> Result<()> {
+    let Some(build) = ctx.build_repo.get(build_id).await? else { return Ok(()) };
+    let Some(project) = ctx.project_repo.get(build.project_id).await? else { return Ok(()) };
+
+    let log = tokio::fs::read_to_string(&build.log_path).await.unwrap_or_default();
+    let summary = build.failure_summary.as_deref().unwrap_or("Build failed");
+    let diagnosis = diagnose(client, config, &project.name, summary, &log).await?;
+
+    ctx.build_repo.update_diagnosis(build.id, &diagnosis).await?;
+    info!(
+        "Attached failure diagnosis to build #{} for project '{}' ({} chars)",
+        build.build_number,
+        project.name,
+        diagnosis.chars().count()
+    );
+
+    Ok(())
+}
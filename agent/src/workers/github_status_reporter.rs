@@ -0,0 +1,200 @@
+use anyhow::Result;
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+use crate::application::events::Event;
+use crate::application::ports::repositories::{BuildRepository, ProjectRepository};
+use crate::application::services::resolve_github_token;
+use crate::db::models::{BuildStatus, ProjectCommitStatus};
+use crate::github::{parse_repo_owner_name, CreateCommitStatusRequest, GitHubClient};
+use crate::state::AppContext;
+
+/// commit status를 보고하는 빌드/배포 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    /// 큐에 들어감 (PR에 바로 pending 표시)
+    BuildQueued,
+    BuildStarted,
+    BuildFailed,
+    /// dry-run 빌드 성공 (배포 없음)
+    BuildVerified,
+    /// 빌드 성공, 배포 창을 기다리는 중
+    BuildHeld,
+    /// 빌드 성공, 배포 정책(커밋 서명 등)으로 배포 차단
+    DeployBlocked,
+    Deploying,
+    Deployed,
+    DeployFailed,
+}
+
+impl Stage {
+    /// 이벤트 → (build_id, 단계). 롤백/재배포는 커밋과 무관하므로 보고하지 않음
+    fn from_event(event: &Event) -> Option<(i64, Stage)> {
+        match event {
+            Event::BuildStatus { build_id, status, .. } => {
+                let stage = match status {
+                    BuildStatus::Building => Stage::BuildStarted,
+                    BuildStatus::Failed => Stage::BuildFailed,
+                    BuildStatus::Verified => Stage::BuildVerified,
+                    BuildStatus::Held => Stage::BuildHeld,
+                    BuildStatus::Success => Stage::Deployed,
+                    BuildStatus::Queued => Stage::BuildQueued,
+                };
+                Some((*build_id, stage))
+            }
+            Event::Deployment { build_id, status, .. } => match status.as_str() {
+                "deploying" => Some((*build_id, Stage::Deploying)),
+                "Failed" => Some((*build_id, Stage::DeployFailed)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// 단계별로 보고할 (context, state, description)
+fn commit_statuses(config: &ProjectCommitStatus, stage: Stage) -> Vec<(String, &'static str, &'static str)> {
+    let build = config.build_context();
+    let deploy = config.deploy_context();
+    match (stage, config.separate_deploy) {
+        (Stage::BuildQueued, _) => vec![(build, "pending", "Build queued")],
+        (Stage::BuildStarted, _) => vec![(build, "pending", "Build started")],
+        (Stage::BuildFailed, _) => vec![(build, "failure", "Build failed")],
+        (Stage::BuildVerified, _) => vec![(build, "success", "Build verified (dry run, not deployed)")],
+        (Stage::BuildHeld, true) => vec![
+            (build, "success", "Build succeeded"),
+            (deploy, "pending", "Waiting for deploy window"),
+        ],
+        (Stage::BuildHeld, false) => vec![(build, "pending", "Build succeeded, waiting for deploy window")],
+        (Stage::DeployBlocked, true) => vec![
+            (build, "success", "Build succeeded"),
+            (deploy, "failure", "Deployment blocked: commit signature not verified"),
+        ],
+        (Stage::DeployBlocked, false) => vec![(build, "failure", "Deployment blocked: commit signature not verified")],
+        (Stage::Deploying, true) => vec![
+            (build, "success", "Build succeeded"),
+            (deploy, "pending", "Deploying"),
+        ],
+        (Stage::Deploying, false) => vec![(build, "pending", "Deploying")],
+        (Stage::Deployed, _) => vec![(deploy, "success", "Deployed")],
+        (Stage::DeployFailed, _) => vec![(deploy, "failure", "Deployment failed")],
+    }
+}
+
+/// GitHub commit status reporter
+///
+/// Responsibilities:
+/// - Report build/deploy progress of projects with `commit_status` configured
+///   to GitHub as commit statuses (context name per project, see ProjectCommitStatus)
+/// - Uses the project's GitHub PAT (or the legacy global PAT)
+pub async fn run_github_status_reporter(context: AppContext) -> Result<()> {
+    info!("GitHub commit status reporter started");
+
+    let mut event_rx = context.subscribe_events();
+    let base_url = std::env::var("BASE_URL").unwrap_or_else(|_| "http://localhost:10000".to_string());
+
+    loop {
+        match event_rx.recv().await {
+            Ok(event) => {
+                let Some((build_id, stage)) = Stage::from_event(&event) else { continue };
+                if let Err(e) = report(&context, build_id, stage, &base_url).await {
+                    warn!("Failed to report GitHub commit status for build {}: {}", build_id, e);
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!("GitHub commit status reporter lagged, skipped {} events", skipped);
+            }
+            Err(broadcast::error::RecvError::Closed) => {
+                info!("Event bus closed, GitHub commit status reporter stopping");
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn report(ctx: &AppContext, build_id: i64, stage: Stage, base_url: &str) -> Result<()> {
+    let Some(build) = ctx.build_repo.get(build_id).await? else { return Ok(()) };
+    let Some(project) = ctx.project_repo.get(build.project_id).await? else { return Ok(()) };
+    let Some(config) = project.parsed_commit_status() else { return Ok(()) };
+    // 배포가 막힌 빌드도 Verified로 끝나므로 dry-run과 구분
+    let stage = if stage == Stage::BuildVerified && build.deploy_blocked_reason.is_some() {
+        Stage::DeployBlocked
+    } else {
+        stage
+    };
+
+    // workspace가 없어 커밋을 알 수 없는 수동 빌드
+    if build.commit_hash.is_empty() || build.commit_hash == "HEAD" {
+        debug!("Skipping commit status for build {} (unknown commit)", build_id);
+        return Ok(());
+    }
+    let Some((owner, repo)) = parse_repo_owner_name(&project.repo) else {
+        debug!("Skipping commit status for project '{}' (not a GitHub repo)", project.name);
+        return Ok(());
+    };
+    let Some(token) = resolve_github_token(ctx.github_pat_repo.as_ref(), ctx.settings_repo.as_ref(), project.github_pat_id).await? else {
+        debug!("Skipping commit status for project '{}' (no GitHub token)", project.name);
+        return Ok(());
+    };
+
+    let client = GitHubClient::new(token);
+    let target_url = format!("{}/builds/{}", base_url, build.id);
+    for (context, state, description) in commit_statuses(&config, stage) {
+        debug!(
+            "Reporting commit status {} = {} for {}/{}@{}",
+            context, state, owner, repo, build.commit_hash
+        );
+        let status = CreateCommitStatusRequest {
+            state: state.to_string(),
+            target_url: Some(target_url.clone()),
+            description: description.to_string(),
+            context,
+        };
+        client.create_commit_status(&owner, &repo, &build.commit_hash, &status).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(separate_deploy: bool) -> ProjectCommitStatus {
+        ProjectCommitStatus {
+            context: "easyCICD".to_string(),
+            separate_deploy,
+        }
+    }
+
+    #[test]
+    fn test_single_context() {
+        let config = config(false);
+        assert_eq!(commit_statuses(&config, Stage::BuildStarted), vec![("easyCICD".to_string(), "pending", "Build started")]);
+        assert_eq!(commit_statuses(&config, Stage::Deploying)[0].1, "pending");
+        assert_eq!(commit_statuses(&config, Stage::Deployed), vec![("easyCICD".to_string(), "success", "Deployed")]);
+        assert_eq!(commit_statuses(&config, Stage::DeployFailed)[0].1, "failure");
+    }
+
+    #[test]
+    fn test_separate_contexts() {
+        let config = config(true);
+        assert_eq!(commit_statuses(&config, Stage::BuildFailed)[0].0, "easyCICD/build");
+
+        let held = commit_statuses(&config, Stage::BuildHeld);
+        assert_eq!(held[0], ("easyCICD/build".to_string(), "success", "Build succeeded"));
+        assert_eq!(held[1].0, "easyCICD/deploy");
+        assert_eq!(held[1].1, "pending");
+
+        assert_eq!(commit_statuses(&config, Stage::Deployed)[0].0, "easyCICD/deploy");
+    }
+
+    #[test]
+    fn test_stage_from_event() {
+        let event = Event::build_status(7, 1, BuildStatus::Held);
+        assert_eq!(Stage::from_event(&event), Some((7, Stage::BuildHeld)));
+        assert_eq!(Stage::from_event(&Event::build_status(7, 1, BuildStatus::Queued)), Some((7, Stage::BuildQueued)));
+    }
+}
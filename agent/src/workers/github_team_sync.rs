@@ -0,0 +1,301 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use tracing::{info, warn};
+
+use crate::application::ports::repositories::{ProjectRepository, SettingsRepository};
+use crate::application::services::resolve_github_token;
+use crate::github::GitHubClient;
+use crate::infrastructure::database::ProjectPermission;
+use crate::infrastructure::timezone;
+use crate::state::AppContext;
+use crate::workers::cleanup_schedule::{wait_next_run, CleanupSchedule, CleanupWorker};
+
+/// 팀 동기화 설정 키 (JSON, see TeamSyncConfig). 없으면 동기화하지 않음
+pub const GITHUB_TEAM_SYNC_SETTING: &str = "github_team_sync";
+
+/// 마지막 동기화 결과와 동기화가 관리하는 이메일 (see TeamSyncState)
+pub const GITHUB_TEAM_SYNC_STATE_SETTING: &str = "github_team_sync.state";
+
+/// 동기화가 부여한 프로젝트 권한의 created_by
+pub const TEAM_SYNC_GRANTOR: &str = "github-team-sync";
+
+/// 로그인 허용 목록 (api::settings의 allowed-emails와 같은 키)
+const ALLOWED_EMAILS_SETTING: &str = "allowed_emails";
+
+/// 팀 멤버에게 부여할 프로젝트 권한
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamProjectPermissions {
+    pub project_id: i64,
+    pub permissions: Vec<ProjectPermission>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamMapping {
+    /// 팀 slug (URL에 쓰이는 이름)
+    pub slug: String,
+    #[serde(default)]
+    pub project_permissions: Vec<TeamProjectPermissions>,
+}
+
+/// GitHub 조직/팀 → easyCICD 접근 매핑
+///
+/// ```json
+/// { "org": "acme", "github_pat_id": 1,
+///   "teams": [{ "slug": "platform", "project_permissions": [{ "project_id": 3, "permissions": ["deploy", "view_logs"] }] }],
+///   "email_overrides": { "octocat": "octocat@acme.com" } }
+/// ```
+///
+/// 팀 멤버는 로그인 허용 목록에 추가되고, 팀에서 빠지면 동기화가 추가한 항목만 제거된다.
+/// 이메일은 email_overrides, 없으면 GitHub 공개 프로필 이메일을 쓴다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamSyncConfig {
+    pub org: String,
+    pub teams: Vec<TeamMapping>,
+    /// 조직 read:org 권한이 있는 PAT. 없으면 레거시 전역 PAT
+    #[serde(default)]
+    pub github_pat_id: Option<i64>,
+    /// GitHub login → 이메일
+    #[serde(default)]
+    pub email_overrides: BTreeMap<String, String>,
+}
+
+impl TeamSyncConfig {
+    pub async fn load(settings_repo: &impl SettingsRepository) -> Result<Option<Self>> {
+        settings_repo
+            .get(GITHUB_TEAM_SYNC_SETTING)
+            .await?
+            .map(|json| serde_json::from_str(&json).context("Invalid github_team_sync setting"))
+            .transpose()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let valid_name = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.');
+        if !valid_name(&self.org) {
+            return Err(format!("Invalid org: {}", self.org));
+        }
+        if self.teams.is_empty() {
+            return Err("teams must not be empty".to_string());
+        }
+        if let Some(team) = self.teams.iter().find(|t| !valid_name(&t.slug)) {
+            return Err(format!("Invalid team slug: {}", team.slug));
+        }
+        if let Some((login, email)) = self.email_overrides.iter().find(|(_, e)| !e.contains('@')) {
+            return Err(format!("Invalid email for {}: {}", login, email));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncedMember {
+    pub login: String,
+    pub email: Option<String>,
+    pub teams: Vec<String>,
+}
+
+/// 마지막 동기화 결과
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TeamSyncState {
+    /// 동기화가 로그인 허용 목록에 추가한 이메일 (직접 추가한 이메일은 제거하지 않음)
+    #[serde(default)]
+    pub managed_emails: Vec<String>,
+    pub last_synced_at: Option<String>,
+    pub last_error: Option<String>,
+    #[serde(default)]
+    pub members: Vec<SyncedMember>,
+}
+
+impl TeamSyncState {
+    pub async fn load(settings_repo: &impl SettingsRepository) -> Self {
+        match settings_repo.get(GITHUB_TEAM_SYNC_STATE_SETTING).await {
+            Ok(Some(json)) => serde_json::from_str(&json).unwrap_or_default(),
+            _ => Self::default(),
+        }
+    }
+
+    async fn save(&self, settings_repo: &impl SettingsRepository) -> Result<()> {
+        settings_repo.set(GITHUB_TEAM_SYNC_STATE_SETTING, &serde_json::to_string(self)?).await
+    }
+}
+
+/// 새 로그인 허용 목록과 동기화가 관리하는 이메일 계산
+///
+/// - 팀 멤버 중 목록에 없는 이메일은 추가하고 관리 대상으로 기록
+/// - 관리 대상이었지만 더 이상 팀 멤버가 아닌 이메일은 제거
+/// - 직접 추가된 이메일은 건드리지 않음
+fn plan_allowed_emails(current: &[String], managed: &[String], members: &BTreeSet<String>) -> (Vec<String>, Vec<String>) {
+    let current: BTreeSet<String> = current.iter().map(|e| e.to_lowercase()).collect();
+    let managed: BTreeSet<String> = managed.iter().map(|e| e.to_lowercase()).collect();
+
+    let manual: BTreeSet<&String> = current.difference(&managed).collect();
+    let new_managed: Vec<String> = members.iter().filter(|e| !manual.contains(e)).cloned().collect();
+    let allowed: Vec<String> = manual.into_iter().cloned().chain(new_managed.iter().cloned()).collect::<BTreeSet<_>>().into_iter().collect();
+    (allowed, new_managed)
+}
+
+/// GitHub team sync worker
+///
+/// Runs on a settings-backed schedule (default: hourly, see cleanup_schedule) when
+/// `github_team_sync` is configured
+/// - Fetches the configured teams' members and keeps the login allow list in sync
+/// - Maintains the per-project permissions mapped to each team
+pub async fn run_github_team_sync(context: AppContext) -> Result<()> {
+    info!("GitHub team sync worker started (schedule: {:?})",
+        CleanupSchedule::load(&context, CleanupWorker::GitHubTeams).await);
+
+    loop {
+        wait_next_run(&context, CleanupWorker::GitHubTeams, Utc::now()).await;
+
+        match sync_github_teams(&context).await {
+            Ok(Some(state)) => info!("GitHub team sync completed, {} member(s)", state.members.len()),
+            Ok(None) => {}
+            Err(e) => warn!("GitHub team sync failed: {}", e),
+        }
+    }
+}
+
+/// 한 번 동기화. 설정이 없으면 None. 실패하면 state에 last_error를 남기고 허용 목록은 그대로 둠
+pub async fn sync_github_teams(context: &AppContext) -> Result<Option<TeamSyncState>> {
+    let Some(config) = TeamSyncConfig::load(context.settings_repo.as_ref()).await? else { return Ok(None) };
+    let mut state = TeamSyncState::load(context.settings_repo.as_ref()).await;
+    state.last_synced_at = Some(timezone::now_display());
+
+    match apply(context, &config, &mut state).await {
+        Ok(()) => {
+            state.last_error = None;
+            state.save(context.settings_repo.as_ref()).await?;
+            Ok(Some(state))
+        }
+        Err(e) => {
+            state.last_error = Some(format!("{:#}", e));
+            state.save(context.settings_repo.as_ref()).await?;
+            Err(e)
+        }
+    }
+}
+
+async fn apply(context: &AppContext, config: &TeamSyncConfig, state: &mut TeamSyncState) -> Result<()> {
+    let token = resolve_github_token(context.github_pat_repo.as_ref(), context.settings_repo.as_ref(), config.github_pat_id)
+        .await?
+        .context("No GitHub PAT configured for team sync")?;
+    let client = GitHubClient::new(token);
+
+    // login → 팀 목록 (팀 하나라도 가져오지 못하면 허용 목록을 바꾸지 않음)
+    let mut teams_by_login: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for team in &config.teams {
+        let members = client
+            .list_team_members(&config.org, &team.slug)
+            .await
+            .with_context(|| format!("Failed to list members of {}/{}", config.org, team.slug))?;
+        for member in members {
+            teams_by_login.entry(member.login).or_default().push(team.slug.clone());
+        }
+    }
+
+    let mut members = Vec::with_capacity(teams_by_login.len());
+    for (login, teams) in teams_by_login {
+        let email = match config.email_overrides.get(&login) {
+            Some(email) => Some(email.trim().to_lowercase()),
+            None => match client.get_user_profile(&login).await {
+                Ok(profile) => profile.email.map(|e| e.trim().to_lowercase()).filter(|e| e.contains('@')),
+                Err(e) => {
+                    warn!("Failed to fetch GitHub profile of {}: {}", login, e);
+                    None
+                }
+            },
+        };
+        members.push(SyncedMember { login, email, teams });
+    }
+
+    // 로그인 허용 목록
+    let member_emails: BTreeSet<String> = members.iter().filter_map(|m| m.email.clone()).collect();
+    // 빈 허용 목록은 "모든 계정 허용"이므로 이메일을 하나도 확인하지 못하면 중단
+    if member_emails.is_empty() {
+        anyhow::bail!("No team member emails could be resolved (set email_overrides or public profile emails)");
+    }
+    let current: Vec<String> = context
+        .settings_repo
+        .get(ALLOWED_EMAILS_SETTING)
+        .await?
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+    let (allowed, managed) = plan_allowed_emails(&current, &state.managed_emails, &member_emails);
+    let current_set: BTreeSet<String> = current.iter().map(|e| e.to_lowercase()).collect();
+    let allowed_set: BTreeSet<String> = allowed.iter().cloned().collect();
+    if current_set != allowed_set {
+        context.settings_repo.set(ALLOWED_EMAILS_SETTING, &serde_json::to_string(&allowed)?).await?;
+        tracing::info!(
+            target: "audit",
+            event = "whitelist.github_team_synced",
+            org = %config.org,
+            added = ?allowed_set.difference(&current_set).collect::<Vec<_>>(),
+            removed = ?current_set.difference(&allowed_set).collect::<Vec<_>>(),
+        );
+    }
+
+    sync_project_permissions(context, config, &members).await?;
+
+    state.managed_emails = managed;
+    state.members = members;
+    Ok(())
+}
+
+/// 팀 매핑의 프로젝트 권한 반영 (동기화가 부여한 권한만 회수)
+async fn sync_project_permissions(context: &AppContext, config: &TeamSyncConfig, members: &[SyncedMember]) -> Result<()> {
+    let mut desired: BTreeSet<(i64, String, String)> = BTreeSet::new();
+    for team in &config.teams {
+        for mapping in &team.project_permissions {
+            if context.project_repo.get(mapping.project_id).await?.is_none() {
+                warn!("Team {} maps to unknown project {}, skipping", team.slug, mapping.project_id);
+                continue;
+            }
+            for member in members.iter().filter(|m| m.teams.contains(&team.slug)) {
+                let Some(email) = &member.email else { continue };
+                for permission in &mapping.permissions {
+                    desired.insert((mapping.project_id, email.clone(), permission.to_string()));
+                }
+            }
+        }
+    }
+
+    let repo = &context.project_permission_repo;
+    let existing = repo.list_created_by(TEAM_SYNC_GRANTOR).await?;
+    for grant in &existing {
+        if !desired.contains(&(grant.project_id, grant.user_email.clone(), grant.permission.clone())) {
+            repo.revoke(grant.project_id, grant.id).await?;
+        }
+    }
+    for (project_id, email, permission) in &desired {
+        let exists = existing.iter().any(|g| g.project_id == *project_id && &g.user_email == email && &g.permission == permission);
+        if !exists {
+            if let Ok(permission) = permission.parse::<ProjectPermission>() {
+                repo.grant(*project_id, email, permission, Some(TEAM_SYNC_GRANTOR)).await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_allowed_emails() {
+        let current = vec!["admin@acme.com".to_string(), "old@acme.com".to_string(), "kept@acme.com".to_string()];
+        let managed = vec!["old@acme.com".to_string(), "kept@acme.com".to_string()];
+        let members: BTreeSet<String> = ["kept@acme.com", "new@acme.com", "admin@acme.com"].iter().map(|s| s.to_string()).collect();
+
+        let (allowed, managed) = plan_allowed_emails(&current, &managed, &members);
+        // old는 팀에서 빠져 제거, admin은 직접 추가한 항목이라 관리 대상이 아님
+        assert_eq!(allowed, vec!["admin@acme.com", "kept@acme.com", "new@acme.com"]);
+        assert_eq!(managed, vec!["kept@acme.com", "new@acme.com"]);
+
+        let config: TeamSyncConfig = serde_json::from_str(r#"{"org": "acme", "teams": [{"slug": "platform"}]}"#).unwrap();
+        assert!(config.validate().is_ok());
+        let invalid = TeamSyncConfig { org: "acme/x".to_string(), ..config };
+        assert!(invalid.validate().is_err());
+    }
+}
@@ -0,0 +1,231 @@
+use anyhow::Result;
+use chrono::Utc;
+use std::collections::HashMap;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::application::ports::repositories::{BuildRepository, ProjectRepository};
+use crate::db::models::{BuildStatus, BuildTrigger, ImageUpdate, ImageUpdateStatus, Project};
+use crate::docker::image_digest;
+use crate::state::AppContext;
+use crate::workers::cleanup_schedule::{wait_next_run, CleanupSchedule, CleanupWorker};
+
+/// 한 번의 확인 동안 이미지별 레지스트리 digest 캐시 (여러 프로젝트가 같은 베이스 이미지를 쓰는 경우가 많음)
+pub type RegistryDigests = HashMap<String, Option<String>>;
+
+/// Base image update check worker
+///
+/// Runs on a settings-backed schedule (default: daily at 03:00 UTC, see cleanup_schedule)
+/// - Compares each project's build/runtime image digest with the registry's latest digest
+///   and stores the result on the project (image_update_status)
+/// - Projects with auto_rebuild_on_image_update pull the new image and get a rebuild
+///   (once per newly published digest)
+pub async fn run_image_update_check(context: AppContext) -> Result<()> {
+    info!("Image update check worker started (schedule: {:?})",
+        CleanupSchedule::load(&context, CleanupWorker::ImageUpdates).await);
+
+    loop {
+        wait_next_run(&context, CleanupWorker::ImageUpdates, Utc::now()).await;
+
+        match check_all_projects(&context).await {
+            Ok(0) => info!("Image update check completed, all images up to date"),
+            Ok(count) => info!("Image update check completed, {} project(s) have image updates", count),
+            Err(e) => warn!("Image update check failed: {}", e),
+        }
+    }
+}
+
+/// 모든 활성 프로젝트 확인. 업데이트가 있는 프로젝트 수를 반환
+async fn check_all_projects(context: &AppContext) -> Result<usize> {
+    let projects = context.project_repo.list().await?;
+    let mut registry = RegistryDigests::new();
+    let mut updated = 0;
+
+    for project in projects.into_iter().filter(|p| p.archived_at.is_none()) {
+        let trace_id = format!("image-update-{}-{}", project.id, Uuid::new_v4());
+        let previous = project.parsed_image_update_status();
+
+        let status = match check_project_images(context, &trace_id, &project, &mut registry).await {
+            Ok(status) => status,
+            Err(e) => {
+                warn!("[{}] Failed to check image updates for project '{}': {}", trace_id, project.name, e);
+                continue;
+            }
+        };
+        if !status.update_available() {
+            continue;
+        }
+        updated += 1;
+
+        let new_updates = newly_available(previous.as_ref(), &status);
+        if project.auto_rebuild_on_image_update && !new_updates.is_empty() {
+            if let Err(e) = rebuild(context, &trace_id, &project, &new_updates).await {
+                warn!("[{}] Failed to rebuild project '{}' for image update: {}", trace_id, project.name, e);
+            }
+        }
+    }
+
+    Ok(updated)
+}
+
+/// 프로젝트의 빌드/런타임 이미지 업데이트 확인 후 결과 저장
+pub async fn check_project_images(
+    context: &AppContext,
+    trace_id: &str,
+    project: &Project,
+    registry: &mut RegistryDigests,
+) -> Result<ImageUpdateStatus> {
+    let build_current = context.docker.local_image_digest(&project.build_image).await?;
+    let build_latest = registry_digest(context, registry, &project.build_image).await?;
+
+    let mut images = vec![ImageUpdate::new("build", &project.build_image, build_current, build_latest)];
+
+    // Dockerfile 빌드는 런타임 이미지를 직접 만들므로 확인하지 않음
+    if project.dockerfile_path.is_none() {
+        images.push(check_runtime_image(context, project, registry).await?);
+    }
+
+    let status = ImageUpdateStatus {
+        checked_at: Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        images,
+    };
+
+    for image in status.images.iter().filter(|i| i.update_available) {
+        info!(
+            "[{}] Image update available for project '{}': {} image {} ({} → {})",
+            trace_id,
+            project.name,
+            image.kind,
+            image.image,
+            image.current_digest.as_deref().unwrap_or_default(),
+            image.latest_digest.as_deref().unwrap_or_default()
+        );
+    }
+
+    context.project_repo.update_image_update_status(project.id, &serde_json::to_string(&status)?).await?;
+    Ok(status)
+}
+
+/// 런타임 이미지 업데이트 확인: 서비스 중인 빌드에 고정된 digest 기준 (고정 전 빌드는 로컬 이미지)
+async fn check_runtime_image(
+    context: &AppContext,
+    project: &Project,
+    registry: &mut RegistryDigests,
+) -> Result<ImageUpdate> {
+    let builds = context.build_repo.list_by_project(project.id, 100).await?;
+    let pinned = builds
+        .iter()
+        .find(|b| b.status == BuildStatus::Success && b.get_deployed_slot() == Some(project.active_slot))
+        .and_then(|b| b.runtime_image_digest.as_deref())
+        .and_then(image_digest)
+        .map(str::to_string);
+    let runtime_current = match pinned {
+        Some(digest) => Some(digest),
+        None => context.docker.local_image_digest(&project.runtime_image).await?,
+    };
+    let runtime_latest = registry_digest(context, registry, &project.runtime_image).await?;
+
+    Ok(ImageUpdate::new("runtime", &project.runtime_image, runtime_current, runtime_latest))
+}
+
+async fn registry_digest(context: &AppContext, registry: &mut RegistryDigests, image: &str) -> Result<Option<String>> {
+    if let Some(digest) = registry.get(image) {
+        return Ok(digest.clone());
+    }
+    let digest = context.docker.registry_image_digest(image).await?;
+    registry.insert(image.to_string(), digest.clone());
+    Ok(digest)
+}
+
+/// 이전 확인 이후 새로 발견된 업데이트 (같은 최신 digest로 매일 재빌드하지 않도록)
+fn newly_available(previous: Option<&ImageUpdateStatus>, current: &ImageUpdateStatus) -> Vec<ImageUpdate> {
+    current
+        .images
+        .iter()
+        .filter(|image| image.update_available)
+        .filter(|image| {
+            !previous.is_some_and(|p| {
+                p.images.iter().any(|old| {
+                    old.update_available
+                        && old.kind == image.kind
+                        && old.image == image.image
+                        && old.latest_digest == image.latest_digest
+                })
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+/// 새 이미지를 pull한 뒤 재빌드 (빌드는 로컬에 있는 태그를 그대로 쓰므로 pull이 먼저)
+async fn rebuild(context: &AppContext, trace_id: &str, project: &Project, updates: &[ImageUpdate]) -> Result<()> {
+    for update in updates {
+        context.docker.pull_image(&update.image).await?;
+    }
+
+    let quota = context.disk_quota_service.check(trace_id, project).await?;
+    if quota.exceeded {
+        warn!("[{}] Skipping image update rebuild for project '{}': {}", trace_id, project.name, quota.error_message());
+        return Ok(());
+    }
+
+    let build = context.project_service.trigger_build(trace_id, project.id, false, BuildTrigger::ImageUpdate).await?;
+    context.build_queue.enqueue_new(&context.event_bus, build.project_id, build.id).await;
+
+    info!(
+        "[{}] Build #{} triggered for project '{}' by base image update",
+        trace_id, build.build_number, project.name
+    );
+    tracing::info!(
+        target: "audit",
+        event = "build.triggered",
+        trace_id = %trace_id,
+        project_id = build.project_id,
+        build_id = build.id,
+        dry_run = build.dry_run,
+        triggered_by = build.triggered_by.as_deref().unwrap_or_default(),
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(images: Vec<ImageUpdate>) -> ImageUpdateStatus {
+        ImageUpdateStatus {
+            checked_at: "2026-10-16 03:00:00".to_string(),
+            images,
+        }
+    }
+
+    fn digest(d: &str) -> Option<String> {
+        Some(d.to_string())
+    }
+
+    #[test]
+    fn test_update_available() {
+        assert!(ImageUpdate::new("build", "node:20", digest("sha256:a"), digest("sha256:b")).update_available);
+        assert!(!ImageUpdate::new("build", "node:20", digest("sha256:a"), digest("sha256:a")).update_available);
+        // 로컬에 없거나 레지스트리 조회 실패면 판단하지 않음
+        assert!(!ImageUpdate::new("build", "node:20", None, digest("sha256:b")).update_available);
+        assert!(!ImageUpdate::new("build", "node:20", digest("sha256:a"), None).update_available);
+    }
+
+    #[test]
+    fn test_newly_available() {
+        let current = status(vec![
+            ImageUpdate::new("build", "node:20", digest("sha256:a"), digest("sha256:b")),
+            ImageUpdate::new("runtime", "node:20-slim", digest("sha256:c"), digest("sha256:c")),
+        ]);
+        assert_eq!(newly_available(None, &current).len(), 1);
+
+        // 같은 digest로 이미 알려진 업데이트는 다시 재빌드하지 않음
+        assert!(newly_available(Some(&current), &current).is_empty());
+
+        // upstream이 또 바뀌면 새 업데이트
+        let newer = status(vec![ImageUpdate::new("build", "node:20", digest("sha256:a"), digest("sha256:d"))]);
+        assert_eq!(newly_available(Some(&current), &newer)[0].latest_digest, digest("sha256:d"));
+    }
+}
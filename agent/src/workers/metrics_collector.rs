@@ -0,0 +1,85 @@
+use anyhow::Result;
+use tokio::time::{interval, Duration, Instant, MissedTickBehavior};
+use tracing::{debug, info, warn};
+
+use crate::state::AppContext;
+use crate::db::models::Slot;
+use crate::application::ports::repositories::ProjectRepository;
+
+/// 샘플링 주기
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 보존 기간 정리 주기
+const RETENTION_SWEEP_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// 메트릭 보존 기간 (일). METRICS_RETENTION_DAYS로 변경 가능
+const DEFAULT_RETENTION_DAYS: i64 = 30;
+
+/// Container metrics collector worker
+///
+/// Responsibilities:
+/// - Sample CPU/memory of every project's Blue/Green containers once a minute
+/// - Roll samples up into 5-minute buckets (container_metrics table)
+/// - Drop buckets older than the retention period
+pub async fn run_metrics_collector(context: AppContext) -> Result<()> {
+    let retention_days = std::env::var("METRICS_RETENTION_DAYS")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .filter(|d| *d > 0)
+        .unwrap_or(DEFAULT_RETENTION_DAYS);
+
+    info!("Metrics collector worker started (retention: {} days)", retention_days);
+
+    let mut sample_interval = interval(SAMPLE_INTERVAL);
+    // docker stats는 컨테이너당 1~2초 걸리므로 밀린 tick은 몰아서 실행하지 않음
+    sample_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut last_sweep: Option<Instant> = None;
+
+    loop {
+        sample_interval.tick().await;
+
+        let projects = match context.project_repo.list().await {
+            Ok(projects) => projects,
+            Err(e) => {
+                warn!("Metrics collector failed to list projects: {}", e);
+                continue;
+            }
+        };
+
+        let now = chrono::Utc::now().timestamp();
+
+        for project in projects {
+            for slot in [Slot::Blue, Slot::Green] {
+                let container_id = match slot {
+                    Slot::Blue => &project.blue_container_id,
+                    Slot::Green => &project.green_container_id,
+                };
+                let Some(cid) = container_id else { continue };
+
+                if !context.docker.is_container_running(cid).await {
+                    continue;
+                }
+
+                match context.docker.container_stats(cid).await {
+                    Ok(Some(stats)) => {
+                        if let Err(e) = context.metrics_repo.record_sample(project.id, slot, now, &stats).await {
+                            warn!("[Project:{}] Failed to store {} metrics: {}", project.name, slot, e);
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(e) => debug!("[Project:{}] Failed to sample {} container: {}", project.name, slot, e),
+                }
+            }
+        }
+
+        if last_sweep.is_none_or(|t| t.elapsed() >= RETENTION_SWEEP_INTERVAL) {
+            let cutoff = now - retention_days * 86400;
+            match context.metrics_repo.delete_before(cutoff).await {
+                Ok(count) if count > 0 => info!("Removed {} expired metric buckets", count),
+                Ok(_) => {}
+                Err(e) => warn!("Failed to remove expired metrics: {}", e),
+            }
+            last_sweep = Some(Instant::now());
+        }
+    }
+}
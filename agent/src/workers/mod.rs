@@ -3,9 +3,45 @@ pub mod container_log_streamer;
 pub mod container_cleanup;
 pub mod session_cleanup;
 pub mod container_health_monitor;
+pub mod metrics_collector;
+pub mod cache_eviction;
+pub mod cleanup_schedule;
+pub mod system_cleanup;
+pub mod project_purge;
+pub mod deploy_window_release;
+pub mod github_status_reporter;
+pub mod image_update_check;
+pub mod build_log_shipper;
+pub mod failure_diagnosis;
+pub mod email_notifier;
+pub mod outbound_webhooks;
+pub mod github_team_sync;
+pub mod queue_wait_monitor;
+pub mod stale_build_watchdog;
+pub mod build_retention;
+pub mod build_progress_monitor;
+pub mod canary_monitor;
+pub mod cert_renewal;
 
 pub use port_scanner::run_port_scanner;
 pub use container_log_streamer::run_container_log_streamer;
 pub use container_cleanup::run_container_cleanup;
 pub use session_cleanup::run_session_cleanup;
 pub use container_health_monitor::run_container_health_monitor;
+pub use metrics_collector::run_metrics_collector;
+pub use cache_eviction::run_cache_eviction;
+pub use project_purge::run_project_purge;
+pub use deploy_window_release::run_deploy_window_release;
+pub use github_status_reporter::run_github_status_reporter;
+pub use image_update_check::run_image_update_check;
+pub use build_log_shipper::run_build_log_shipper;
+pub use failure_diagnosis::run_failure_diagnosis;
+pub use email_notifier::run_email_notifier;
+pub use outbound_webhooks::run_outbound_webhooks;
+pub use github_team_sync::run_github_team_sync;
+pub use queue_wait_monitor::run_queue_wait_monitor;
+pub use stale_build_watchdog::run_stale_build_watchdog;
+pub use build_retention::run_build_retention;
+pub use build_progress_monitor::run_build_progress_monitor;
+pub use canary_monitor::run_canary_monitor;
+pub use cert_renewal::run_cert_renewal;
@@ -0,0 +1,231 @@
+use anyhow::Result;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+use crate::application::events::Event;
+use crate::application::ports::repositories::{BuildRepository, ProjectRepository};
+use crate::infrastructure::database::{DeliveryStatus, OutboundWebhook};
+use crate::state::AppContext;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// 요청 본문의 HMAC-SHA256 서명 (`sha256=<hex>`)
+pub const SIGNATURE_HEADER: &str = "X-EasyCICD-Signature";
+
+/// 전송 대기 시간
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 실패 후 재시도 간격. 모두 실패하면 failed로 기록
+const RETRY_DELAYS: [Duration; 4] = [
+    Duration::from_secs(10),
+    Duration::from_secs(60),
+    Duration::from_secs(5 * 60),
+    Duration::from_secs(30 * 60),
+];
+
+/// GitHub webhook과 같은 형식의 서명
+pub fn sign_payload(secret: &str, body: &str) -> String {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body.as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// `attempt`번째 시도가 실패한 뒤 기다릴 시간. 더 재시도하지 않으면 None
+fn retry_delay(attempt: u32) -> Option<Duration> {
+    RETRY_DELAYS.get(attempt.checked_sub(1)? as usize).copied()
+}
+
+/// 수신 측 문제로 다시 보내도 같은 결과일 응답 (408, 429 제외한 4xx)
+fn is_permanent_failure(status: reqwest::StatusCode) -> bool {
+    status.is_client_error()
+        && status != reqwest::StatusCode::REQUEST_TIMEOUT
+        && status != reqwest::StatusCode::TOO_MANY_REQUESTS
+}
+
+/// Outbound webhook dispatcher
+///
+/// Responsibilities:
+/// - Forward BuildStatus, Deployment and Error events to each project's registered
+///   webhooks as signed JSON (`X-EasyCICD-Signature: sha256=...`)
+/// - Retry failed deliveries with backoff and record every attempt in webhook_deliveries
+/// - Resume deliveries left pending by a restart
+pub async fn run_outbound_webhooks(context: AppContext) -> Result<()> {
+    info!("Outbound webhook dispatcher started");
+
+    let client = reqwest::Client::builder().timeout(DELIVERY_TIMEOUT).build()?;
+    let mut event_rx = context.subscribe_events();
+
+    match context.outbound_webhook_repo.list_pending_deliveries().await {
+        Ok(pending) => {
+            for delivery in pending {
+                let Ok(Some(webhook)) = context.outbound_webhook_repo.get_by_id(delivery.webhook_id).await else { continue };
+                tokio::spawn(deliver(
+                    context.clone(),
+                    client.clone(),
+                    webhook,
+                    delivery.id,
+                    delivery.event_type,
+                    delivery.payload,
+                    delivery.attempts as u32,
+                ));
+            }
+        }
+        Err(e) => warn!("Failed to load pending webhook deliveries: {}", e),
+    }
+
+    loop {
+        match event_rx.recv().await {
+            Ok(event) => {
+                if let Err(e) = dispatch(&context, &client, &event).await {
+                    warn!("Failed to dispatch outbound webhooks for {} event: {}", event.event_type(), e);
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!("Outbound webhook dispatcher lagged, skipped {} events", skipped);
+            }
+            Err(broadcast::error::RecvError::Closed) => {
+                info!("Event bus closed, outbound webhook dispatcher stopping");
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn dispatch(ctx: &AppContext, client: &reqwest::Client, event: &Event) -> Result<()> {
+    let project_id = match event {
+        Event::BuildStatus { project_id, .. }
+        | Event::Deployment { project_id, .. }
+        | Event::Error { project_id: Some(project_id), .. } => *project_id,
+        Event::Error { project_id: None, build_id: Some(build_id), .. } => match ctx.build_repo.get(*build_id).await? {
+            Some(build) => build.project_id,
+            None => return Ok(()),
+        },
+        _ => return Ok(()),
+    };
+
+    let event_type = event.event_type();
+    let webhooks: Vec<OutboundWebhook> = ctx
+        .outbound_webhook_repo
+        .list_by_project(project_id)
+        .await?
+        .into_iter()
+        .filter(|w| w.wants(event_type))
+        .collect();
+    if webhooks.is_empty() {
+        return Ok(());
+    }
+
+    let project_name = ctx.project_repo.get(project_id).await?.map(|p| p.name);
+    let payload = serde_json::to_string(&serde_json::json!({
+        "event": event_type,
+        "project": {"id": project_id, "name": project_name},
+        "data": event,
+    }))?;
+
+    for webhook in webhooks {
+        let delivery_id = ctx.outbound_webhook_repo.create_delivery(webhook.id, event_type, &payload).await?;
+        // 재시도 대기 중에도 다른 이벤트를 놓치지 않도록 전송마다 분리
+        tokio::spawn(deliver(
+            ctx.clone(),
+            client.clone(),
+            webhook,
+            delivery_id,
+            event_type.to_string(),
+            payload.clone(),
+            0,
+        ));
+    }
+    Ok(())
+}
+
+/// 성공하거나 재시도가 끝날 때까지 전송 (previous_attempts: 재시작 전에 이미 시도한 횟수)
+async fn deliver(
+    ctx: AppContext,
+    client: reqwest::Client,
+    webhook: OutboundWebhook,
+    delivery_id: i64,
+    event_type: String,
+    payload: String,
+    previous_attempts: u32,
+) {
+    let signature = sign_payload(&webhook.secret, &payload);
+    let mut attempt = previous_attempts;
+
+    loop {
+        attempt += 1;
+        let result = client
+            .post(&webhook.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header("X-EasyCICD-Event", &event_type)
+            .header("X-EasyCICD-Delivery", delivery_id.to_string())
+            .header(SIGNATURE_HEADER, &signature)
+            .body(payload.clone())
+            .send()
+            .await;
+
+        let (response_status, error, retriable) = match result {
+            Ok(response) if response.status().is_success() => (Some(response.status().as_u16()), None, false),
+            Ok(response) => {
+                let status = response.status();
+                (Some(status.as_u16()), Some(format!("Endpoint returned {}", status)), !is_permanent_failure(status))
+            }
+            Err(e) => (None, Some(format!("Request failed: {}", e)), true),
+        };
+
+        let delay = if retriable { retry_delay(attempt) } else { None };
+        let status = match (&error, delay) {
+            (None, _) => DeliveryStatus::Success,
+            (Some(_), Some(_)) => DeliveryStatus::Pending,
+            (Some(_), None) => DeliveryStatus::Failed,
+        };
+        if let Err(e) = ctx
+            .outbound_webhook_repo
+            .record_attempt(delivery_id, status, attempt, response_status, error.as_deref())
+            .await
+        {
+            warn!("Failed to record webhook delivery {}: {}", delivery_id, e);
+        }
+
+        match (error, delay) {
+            (None, _) => {
+                debug!("Delivered {} event to webhook {} (delivery {})", event_type, webhook.id, delivery_id);
+                return;
+            }
+            (Some(error), Some(delay)) => {
+                debug!("Webhook delivery {} attempt {} failed: {} (retrying in {:?})", delivery_id, attempt, error, delay);
+                tokio::time::sleep(delay).await;
+            }
+            (Some(error), None) => {
+                warn!("Webhook delivery {} to {} failed after {} attempts: {}", delivery_id, webhook.url, attempt, error);
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_payload_and_retry() {
+        assert_eq!(
+            sign_payload("key", "The quick brown fox jumps over the lazy dog"),
+            "sha256=f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+
+        assert_eq!(retry_delay(1), Some(Duration::from_secs(10)));
+        assert_eq!(retry_delay(4), Some(Duration::from_secs(30 * 60)));
+        assert_eq!(retry_delay(5), None);
+        assert_eq!(retry_delay(0), None);
+
+        assert!(is_permanent_failure(reqwest::StatusCode::NOT_FOUND));
+        assert!(!is_permanent_failure(reqwest::StatusCode::TOO_MANY_REQUESTS));
+        assert!(!is_permanent_failure(reqwest::StatusCode::BAD_GATEWAY));
+    }
+}
@@ -1,11 +1,23 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::time::Duration;
 use anyhow::Result;
+use serde::Serialize;
 use tokio::net::TcpListener;
 use tokio::time::sleep;
 use tracing::{info, warn};
 use sqlx::SqlitePool;
 
+use crate::infrastructure::database::{PortAllocation, PortOwner, SqlitePortAllocationRepository};
+
+/// 호스트에서 스캔하는 포트 범위 (start, end, port_type)
+pub const SCAN_RANGES: [(u16, u16, &str); 2] = [
+    (10000, 10099, "application"),
+    (15000, 15099, "container"),
+];
+
+/// 주인 없는 할당을 자동 해제하기 전 최소 경과 시간 (생성 중인 컨테이너 보호)
+pub const STALE_ALLOCATION_GRACE_SECS: i64 = 600;
+
 /// Port Scanner Worker
 /// Scans port ranges every 5 minutes and updates port_allocations table
 /// Optimized to scan only necessary ports to reduce CPU usage
@@ -18,6 +30,10 @@ pub async fn run_port_scanner(pool: SqlitePool) -> Result<()> {
         if let Err(e) = scan_and_update_ports(&pool).await {
             warn!("Port scan failed: {}", e);
         }
+
+        if let Err(e) = collect_garbage(&SqlitePortAllocationRepository::new(pool.clone())).await {
+            warn!("Port allocation GC failed: {}", e);
+        }
     }
 }
 
@@ -28,11 +44,10 @@ async fn scan_and_update_ports(pool: &SqlitePool) -> Result<()> {
     // 기존: 10000개 포트 스캔 (CPU 50% 사용)
     // 최적화: 200개 포트만 스캔 (CPU < 5% 사용)
 
-    // 1. Application 포트 범위 스캔 (10000-10099: 100개만)
-    scan_port_range(pool, 10000, 10099, "application").await?;
-
-    // 2. Container 포트 범위 스캔 (15000-15099: 100개만)
-    scan_port_range(pool, 15000, 15099, "container").await?;
+    // Application 포트 범위 (10000-10099: 100개만), Container 포트 범위 (15000-15099: 100개만)
+    for (start, end, port_type) in SCAN_RANGES {
+        scan_port_range(pool, start, end, port_type).await?;
+    }
 
     info!("Port scan completed (200 ports scanned)");
     Ok(())
@@ -44,7 +59,7 @@ async fn scan_port_range(
     end: u16,
     port_type: &str,
 ) -> Result<()> {
-    let now = chrono::Local::now().to_rfc3339();
+    let now = chrono::Utc::now().to_rfc3339();
 
     // DB에서 현재 할당된 포트 조회
     let allocated_ports = get_allocated_ports(pool, port_type).await?;
@@ -89,9 +104,9 @@ async fn scan_port_range(
 
 async fn get_allocated_ports(pool: &SqlitePool, port_type: &str) -> Result<HashSet<i32>> {
     let ports: Vec<i32> = if port_type == "application" {
-        // projects 테이블에서 blue_port, green_port 조회
+        // projects 테이블에서 blue_port, green_port 조회 (보관된 프로젝트의 포트는 해제된 것으로 취급)
         sqlx::query_scalar(
-            "SELECT blue_port FROM projects UNION SELECT green_port FROM projects"
+            "SELECT blue_port FROM projects WHERE archived_at IS NULL UNION SELECT green_port FROM projects WHERE archived_at IS NULL"
         )
         .fetch_all(pool)
         .await?
@@ -107,9 +122,246 @@ async fn get_allocated_ports(pool: &SqlitePool, port_type: &str) -> Result<HashS
     Ok(ports.into_iter().collect())
 }
 
-async fn check_port_available(port: u16) -> bool {
+pub async fn check_port_available(port: u16) -> bool {
     match TcpListener::bind(format!("0.0.0.0:{}", port)).await {
         Ok(_) => true,   // 바인딩 성공 = 사용 가능
         Err(_) => false, // 바인딩 실패 = 사용 중
     }
 }
+
+/// port_allocations와 실제 사용 상태가 어긋난 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PortConflictKind {
+    /// 'allocated'로 기록됐지만 쓰는 프로젝트/컨테이너가 없음 → 해제
+    StaleAllocation,
+    /// 프로젝트/컨테이너가 쓰지만 port_allocations에 없음 → 등록
+    Unregistered,
+    /// 호스트에서 사용 중이지만 DB에 전혀 없음 → 외부 사용으로 기록
+    UnknownHostPort,
+    /// 여러 주인이 같은 포트를 쓰거나, 외부 프로그램이 쓰던 포트가 프로젝트/컨테이너에 배정됨 → 포트 재배정 필요
+    OwnerConflict,
+}
+
+impl PortConflictKind {
+    /// 자동으로 고칠 수 있는지 (OwnerConflict는 어느 쪽 포트를 바꿀지 사람이 정해야 함)
+    pub fn is_resolvable(self) -> bool {
+        self != PortConflictKind::OwnerConflict
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PortConflict {
+    pub port: i32,
+    pub port_type: String,
+    pub kind: PortConflictKind,
+    pub owners: Vec<PortOwner>,
+    pub allocation: Option<PortAllocation>,
+}
+
+/// DB 기록(allocations), 실제 주인(owners), 호스트에서 사용 중인 포트를 비교해 어긋난 포트를 찾는다.
+/// 포트마다 가장 심각한 문제 하나만 반환 (OwnerConflict 우선)
+pub fn find_port_conflicts(
+    allocations: &[PortAllocation],
+    owners: &[PortOwner],
+    host_in_use: &HashSet<i32>,
+) -> Vec<PortConflict> {
+    let mut owners_by_port: BTreeMap<i32, Vec<PortOwner>> = BTreeMap::new();
+    for owner in owners {
+        owners_by_port.entry(owner.port).or_default().push(owner.clone());
+    }
+    let allocation_by_port: BTreeMap<i32, &PortAllocation> =
+        allocations.iter().map(|a| (a.port, a)).collect();
+
+    let mut conflicts = Vec::new();
+
+    for (&port, port_owners) in &owners_by_port {
+        let allocation = allocation_by_port.get(&port).copied();
+        let kind = if port_owners.len() > 1
+            || allocation.is_some_and(|a| a.status == "used_by_system")
+        {
+            PortConflictKind::OwnerConflict
+        } else if allocation.is_none() {
+            PortConflictKind::Unregistered
+        } else {
+            continue;
+        };
+        conflicts.push(PortConflict {
+            port,
+            port_type: port_owners[0].port_type.clone(),
+            kind,
+            owners: port_owners.clone(),
+            allocation: allocation.cloned(),
+        });
+    }
+
+    for allocation in allocations {
+        if allocation.status == "allocated" && !owners_by_port.contains_key(&allocation.port) {
+            conflicts.push(PortConflict {
+                port: allocation.port,
+                port_type: allocation.port_type.clone(),
+                kind: PortConflictKind::StaleAllocation,
+                owners: Vec::new(),
+                allocation: Some(allocation.clone()),
+            });
+        }
+    }
+
+    for &port in host_in_use {
+        if !owners_by_port.contains_key(&port) && !allocation_by_port.contains_key(&port) {
+            conflicts.push(PortConflict {
+                port,
+                port_type: scan_range_type(port).unwrap_or("application").to_string(),
+                kind: PortConflictKind::UnknownHostPort,
+                owners: Vec::new(),
+                allocation: None,
+            });
+        }
+    }
+
+    conflicts.sort_by_key(|c| c.port);
+    conflicts
+}
+
+fn scan_range_type(port: i32) -> Option<&'static str> {
+    SCAN_RANGES
+        .iter()
+        .find(|(start, end, _)| (*start as i32..=*end as i32).contains(&port))
+        .map(|(_, _, port_type)| *port_type)
+}
+
+/// 호스트에서 현재 사용 중인 포트 (스캔 범위 + DB에 기록/배정된 포트)
+pub async fn host_ports_in_use(allocations: &[PortAllocation], owners: &[PortOwner]) -> HashSet<i32> {
+    let mut candidates: HashSet<i32> = SCAN_RANGES
+        .iter()
+        .flat_map(|(start, end, _)| *start as i32..=*end as i32)
+        .collect();
+    candidates.extend(allocations.iter().map(|a| a.port));
+    candidates.extend(owners.iter().map(|o| o.port));
+
+    let mut in_use = HashSet::new();
+    for port in candidates {
+        let Ok(port_u16) = u16::try_from(port) else { continue };
+        if !check_port_available(port_u16).await {
+            in_use.insert(port);
+        }
+    }
+    in_use
+}
+
+/// 현재 어긋난 포트 목록 (호스트 스캔 포함)
+pub async fn detect_port_conflicts(repo: &SqlitePortAllocationRepository) -> Result<Vec<PortConflict>> {
+    let allocations = repo.list().await?;
+    let owners = repo.owners().await?;
+    let in_use = host_ports_in_use(&allocations, &owners).await;
+    Ok(find_port_conflicts(&allocations, &owners, &in_use))
+}
+
+/// 충돌 하나를 해결. OwnerConflict는 자동 해결하지 않음 (Ok(false))
+pub async fn resolve_port_conflict(repo: &SqlitePortAllocationRepository, conflict: &PortConflict) -> Result<bool> {
+    match conflict.kind {
+        PortConflictKind::StaleAllocation => repo.release_if_older(conflict.port, 0).await,
+        PortConflictKind::Unregistered => {
+            repo.register(&conflict.owners[0]).await?;
+            Ok(true)
+        }
+        PortConflictKind::UnknownHostPort => {
+            repo.mark_external(conflict.port, &conflict.port_type).await?;
+            Ok(true)
+        }
+        PortConflictKind::OwnerConflict => Ok(false),
+    }
+}
+
+/// 주기적 GC: 주인 없는 할당 해제 + 주인 있는 포트 등록 (호스트 스캔 없이 DB만 비교)
+async fn collect_garbage(repo: &SqlitePortAllocationRepository) -> Result<()> {
+    let allocations = repo.list().await?;
+    let owners = repo.owners().await?;
+
+    let mut released = 0;
+    let mut registered = 0;
+    for conflict in find_port_conflicts(&allocations, &owners, &HashSet::new()) {
+        match conflict.kind {
+            PortConflictKind::StaleAllocation => {
+                if repo.release_if_older(conflict.port, STALE_ALLOCATION_GRACE_SECS).await? {
+                    released += 1;
+                }
+            }
+            PortConflictKind::Unregistered => {
+                repo.register(&conflict.owners[0]).await?;
+                registered += 1;
+            }
+            PortConflictKind::OwnerConflict => {
+                warn!("Port {} conflict: {} owner(s), allocation status {:?}",
+                    conflict.port, conflict.owners.len(), conflict.allocation.as_ref().map(|a| &a.status));
+            }
+            PortConflictKind::UnknownHostPort => {}
+        }
+    }
+
+    if released > 0 || registered > 0 {
+        info!("Port allocation GC: released {} stale, registered {} untracked", released, registered);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn allocation(port: i32, status: &str) -> PortAllocation {
+        PortAllocation {
+            port,
+            port_type: "application".to_string(),
+            status: status.to_string(),
+            owner_type: None,
+            owner_id: None,
+            last_checked_at: "2024-05-01 00:00:00".to_string(),
+            created_at: "2024-05-01 00:00:00".to_string(),
+        }
+    }
+
+    fn project(port: i32, id: i64) -> PortOwner {
+        PortOwner {
+            port,
+            port_type: "application".to_string(),
+            owner_type: "project".to_string(),
+            owner_id: id,
+            name: format!("p{}", id),
+        }
+    }
+
+    fn kinds(conflicts: &[PortConflict]) -> Vec<(i32, PortConflictKind)> {
+        conflicts.iter().map(|c| (c.port, c.kind)).collect()
+    }
+
+    #[test]
+    fn test_find_port_conflicts() {
+        let allocations = vec![
+            allocation(10002, "allocated"),
+            allocation(10004, "allocated"),
+            allocation(10005, "used_by_system"),
+        ];
+        let owners = vec![project(10002, 1), project(10003, 1), project(10005, 2), project(10006, 2), project(10006, 3)];
+        let in_use = HashSet::from([10002, 10050]);
+
+        assert_eq!(
+            kinds(&find_port_conflicts(&allocations, &owners, &in_use)),
+            vec![
+                (10003, PortConflictKind::Unregistered),
+                (10004, PortConflictKind::StaleAllocation),
+                (10005, PortConflictKind::OwnerConflict),
+                (10006, PortConflictKind::OwnerConflict),
+                (10050, PortConflictKind::UnknownHostPort),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_port_conflicts_clean() {
+        let allocations = vec![allocation(10002, "allocated"), allocation(10060, "used_by_system")];
+        let owners = vec![project(10002, 1)];
+        let in_use = HashSet::from([10002, 10060]);
+        assert!(find_port_conflicts(&allocations, &owners, &in_use).is_empty());
+    }
+}
@@ -0,0 +1,92 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use tokio::time::{interval, Duration};
+use tracing::{info, warn};
+
+use crate::state::AppContext;
+use crate::application::ports::repositories::ProjectRepository;
+use crate::infrastructure::logging::TraceContext;
+use crate::infrastructure::timezone;
+
+/// 삭제 유예 기간이 지난 프로젝트를 확인하는 주기
+const PURGE_INTERVAL: Duration = Duration::from_secs(3600);
+
+pub const DEFAULT_GRACE_DAYS: i64 = 7;
+
+/// 삭제 후 복원 가능한 기간 (PROJECT_DELETE_GRACE_DAYS, 기본 7일)
+pub fn grace_days() -> i64 {
+    std::env::var("PROJECT_DELETE_GRACE_DAYS")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .filter(|d| *d >= 0)
+        .unwrap_or(DEFAULT_GRACE_DAYS)
+}
+
+/// 실제 삭제 예정 시각
+pub fn purge_after(deleted_at: &str, grace_days: i64) -> Option<DateTime<Utc>> {
+    timezone::parse_stored(deleted_at).map(|t| t + ChronoDuration::days(grace_days))
+}
+
+/// Run project purge worker
+/// soft delete 후 유예 기간이 지난 프로젝트의 컨테이너/파일/DB 레코드를 정리
+pub async fn run_project_purge(context: AppContext) -> Result<()> {
+    info!("Project purge worker started (grace period: {} days)", grace_days());
+
+    let mut ticker = interval(PURGE_INTERVAL);
+    loop {
+        ticker.tick().await;
+
+        if let Err(e) = purge_expired(&context).await {
+            warn!("Project purge failed: {}", e);
+        }
+    }
+}
+
+async fn purge_expired(context: &AppContext) -> Result<()> {
+    let grace = grace_days();
+    let now = Utc::now();
+
+    for project in context.project_repo.list_deleted().await? {
+        let Some(deleted_at) = project.deleted_at.as_deref() else { continue };
+        if purge_after(deleted_at, grace).is_none_or(|t| t > now) {
+            continue;
+        }
+
+        // 삭제 직전에 큐에 있던 빌드가 끝날 때까지 대기
+        if context.build_queue.is_processing(project.id).await {
+            continue;
+        }
+
+        let trace_id = TraceContext::new_trace_id();
+        match context.project_service.delete_project(&trace_id, project.id).await {
+            Ok(()) => {
+                info!("[{}] Purged project {} (deleted at {})", trace_id, project.name, deleted_at);
+                tracing::info!(
+                    target: "audit",
+                    event = "project.purged",
+                    trace_id = %trace_id,
+                    project_id = project.id,
+                    project_name = %project.name,
+                );
+            }
+            Err(e) => warn!("[{}] Failed to purge project {}: {}", trace_id, project.name, e),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_purge_after() {
+        assert_eq!(
+            purge_after("2024-05-01 03:00:00", 7),
+            Some(Utc.with_ymd_and_hms(2024, 5, 8, 3, 0, 0).unwrap())
+        );
+        assert_eq!(purge_after("garbage", 7), None);
+    }
+}
@@ -0,0 +1,118 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use tokio::time::{interval, Duration, MissedTickBehavior};
+use tracing::{info, warn};
+
+use crate::application::events::{Event, EventBus};
+use crate::application::ports::repositories::{BuildRepository, SettingsRepository};
+use crate::db::models::{Build, BuildStatus};
+use crate::infrastructure::timezone;
+use crate::state::AppContext;
+
+/// 큐 대기 알림 기준 설정 키 (초). 없으면 알림 안 함
+pub const QUEUE_WAIT_ALERT_SETTING: &str = "build_queue_wait_alert_secs";
+
+/// Queued 빌드 확인 주기
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// 큐 대기 알림 기준 (초)
+pub async fn queue_wait_threshold_secs(settings_repo: &impl SettingsRepository) -> Result<Option<i64>> {
+    Ok(settings_repo
+        .get(QUEUE_WAIT_ALERT_SETTING)
+        .await?
+        .and_then(|v| v.parse::<i64>().ok())
+        .filter(|secs| *secs > 0))
+}
+
+/// Queued 빌드가 기다린 시간 (초). 생성 시각을 알 수 없으면 None
+pub fn queue_wait_secs(build: &Build, now: DateTime<Utc>) -> Option<i64> {
+    timezone::parse_stored(&build.started_at).map(|queued_at| (now - queued_at).num_seconds().max(0))
+}
+
+/// 기준을 넘긴 Queued 빌드 (build, 대기 초)
+fn overdue(queued: &[Build], now: DateTime<Utc>, threshold_secs: i64) -> Vec<(&Build, i64)> {
+    queued
+        .iter()
+        .filter_map(|b| queue_wait_secs(b, now).map(|wait| (b, wait)))
+        .filter(|(_, wait)| *wait >= threshold_secs)
+        .collect()
+}
+
+/// Queue wait monitor
+///
+/// Responsibilities:
+/// - Watch builds sitting in `Queued` and emit QueueWaitExceeded (once per build)
+///   when they wait longer than the configured threshold
+pub async fn run_queue_wait_monitor(context: AppContext) -> Result<()> {
+    info!("Queue wait monitor started");
+
+    let mut check_interval = interval(CHECK_INTERVAL);
+    check_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    // 이미 알린 빌드 (큐를 벗어나면 제거)
+    let mut alerted: HashSet<i64> = HashSet::new();
+
+    loop {
+        check_interval.tick().await;
+
+        let threshold_secs = match queue_wait_threshold_secs(context.settings_repo.as_ref()).await {
+            Ok(Some(secs)) => secs,
+            Ok(None) => continue,
+            Err(e) => {
+                warn!("Queue wait monitor failed to load threshold: {}", e);
+                continue;
+            }
+        };
+
+        let queued = match context.build_repo.list_by_status(BuildStatus::Queued).await {
+            Ok(builds) => builds,
+            Err(e) => {
+                warn!("Queue wait monitor failed to list queued builds: {}", e);
+                continue;
+            }
+        };
+        alerted.retain(|id| queued.iter().any(|b| b.id == *id));
+
+        for (build, wait_secs) in overdue(&queued, Utc::now(), threshold_secs) {
+            if !alerted.insert(build.id) {
+                continue;
+            }
+            warn!(
+                "Build #{} (project {}) has been queued for {}s (threshold {}s, {} builds queued)",
+                build.build_number,
+                build.project_id,
+                wait_secs,
+                threshold_secs,
+                queued.len()
+            );
+            context
+                .event_bus
+                .emit(Event::queue_wait_exceeded(build.id, build.project_id, wait_secs, threshold_secs, queued.len()))
+                .await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queued(id: i64, started_at: &str) -> Build {
+        Build { started_at: started_at.to_string(), ..Build::test(id, BuildStatus::Queued) }
+    }
+
+    #[test]
+    fn test_overdue() {
+        let now = timezone::parse_stored("2026-01-01 12:10:00").unwrap();
+        let builds = vec![
+            queued(1, "2026-01-01 12:00:00"),
+            queued(2, "2026-01-01 12:09:00"),
+            queued(3, "not a date"),
+        ];
+
+        let result = overdue(&builds, now, 300);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].0.id, 1);
+        assert_eq!(result[0].1, 600);
+    }
+}
@@ -1,22 +1,18 @@
 use anyhow::Result;
-use std::sync::Arc;
-use tokio::time::{interval, Duration};
+use chrono::Utc;
 use tracing::{info, warn};
 
-use crate::infrastructure::database::SqliteSessionRepository;
+use crate::state::AppContext;
 use crate::application::ports::repositories::SessionRepository;
+use crate::workers::cleanup_schedule::{wait_next_run, CleanupWorker};
 
 /// Run session cleanup worker
-/// Removes expired sessions every hour
-pub async fn run_session_cleanup(session_repo: Arc<SqliteSessionRepository>) -> Result<()> {
-    let mut cleanup_interval = interval(Duration::from_secs(3600)); // 1 hour
-
+/// Removes expired sessions on a settings-backed schedule (default: every hour)
+pub async fn run_session_cleanup(context: AppContext) -> Result<()> {
     info!("Session cleanup worker started");
 
     loop {
-        cleanup_interval.tick().await;
-
-        match session_repo.delete_expired().await {
+        match context.session_repo.delete_expired().await {
             Ok(count) => {
                 if count > 0 {
                     info!("Cleaned up {} expired sessions", count);
@@ -26,5 +22,7 @@ pub async fn run_session_cleanup(session_repo: Arc<SqliteSessionRepository>) ->
                 warn!("Failed to cleanup expired sessions: {}", e);
             }
         }
+
+        wait_next_run(&context, CleanupWorker::Sessions, Utc::now()).await;
     }
 }
@@ -0,0 +1,153 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tokio::time::{interval, Duration, MissedTickBehavior};
+use tracing::{info, warn};
+
+use crate::application::events::{Event, EventBus};
+use crate::application::ports::repositories::{BuildRepository, SettingsRepository};
+use crate::db::models::{Build, BuildStatus};
+use crate::infrastructure::timezone;
+use crate::state::AppContext;
+
+/// 빌드 최대 수명 설정 키 (초). 0이면 감시 안 함
+pub const STALE_BUILD_SETTING: &str = "stale_build_max_age_secs";
+
+/// 설정이 없을 때 최대 수명 (빌드 컨테이너 타임아웃 30분보다 충분히 길게)
+pub const DEFAULT_STALE_BUILD_SECS: i64 = 2 * 3600;
+
+/// Queued/Building 빌드 확인 주기
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 빌드 최대 수명 (초). None이면 감시 안 함
+pub async fn stale_build_max_age_secs(settings_repo: &impl SettingsRepository) -> Result<Option<i64>> {
+    let secs = settings_repo
+        .get(STALE_BUILD_SETTING)
+        .await?
+        .and_then(|v| v.parse::<i64>().ok())
+        .unwrap_or(DEFAULT_STALE_BUILD_SECS);
+    Ok(Some(secs).filter(|secs| *secs > 0))
+}
+
+/// 생성된 지 max_age_secs가 지난 빌드 (build, 경과 초). 생성 시각을 알 수 없으면 제외
+fn stale(builds: &[Build], now: DateTime<Utc>, max_age_secs: i64) -> Vec<(&Build, i64)> {
+    builds
+        .iter()
+        .filter_map(|b| timezone::parse_stored(&b.started_at).map(|created| (b, (now - created).num_seconds())))
+        .filter(|(_, age)| *age >= max_age_secs)
+        .collect()
+}
+
+/// Stale build watchdog
+///
+/// Responsibilities:
+/// - Find builds stuck in `Queued`/`Building` longer than the maximum age
+///   (e.g. the agent crashed mid-build)
+/// - Remove their build/test containers, mark them failed with an explanatory
+///   log line and emit BuildStatus/Error events (Discord failure notification)
+pub async fn run_stale_build_watchdog(context: AppContext) -> Result<()> {
+    info!("Stale build watchdog started");
+
+    let mut check_interval = interval(CHECK_INTERVAL);
+    check_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        check_interval.tick().await;
+
+        let max_age_secs = match stale_build_max_age_secs(context.settings_repo.as_ref()).await {
+            Ok(Some(secs)) => secs,
+            Ok(None) => continue,
+            Err(e) => {
+                warn!("Stale build watchdog failed to load max age: {}", e);
+                continue;
+            }
+        };
+
+        for status in [BuildStatus::Queued, BuildStatus::Building] {
+            let builds = match context.build_repo.list_by_status(status.clone()).await {
+                Ok(builds) => builds,
+                Err(e) => {
+                    warn!("Stale build watchdog failed to list {} builds: {}", status, e);
+                    continue;
+                }
+            };
+            for (build, age_secs) in stale(&builds, Utc::now(), max_age_secs) {
+                if let Err(e) = fail_stale_build(&context, build, age_secs, max_age_secs).await {
+                    warn!("Failed to clean up stale build #{}: {}", build.build_number, e);
+                }
+            }
+        }
+    }
+}
+
+async fn fail_stale_build(ctx: &AppContext, build: &Build, age_secs: i64, max_age_secs: i64) -> Result<()> {
+    let message = format!(
+        "Build was still {} after {}s (maximum {}s); the agent may have restarted mid-build. Marked as failed by the stale build watchdog",
+        build.status, age_secs, max_age_secs
+    );
+    warn!("Build #{} (project {}): {}", build.build_number, build.project_id, message);
+
+    ctx.build_queue.remove(build.project_id, build.id).await;
+    let removed = fail_abandoned_build(ctx, build, message).await?;
+
+    tracing::info!(
+        target: "audit",
+        event = "build.stale_failed",
+        build_id = build.id,
+        project_id = build.project_id,
+        age_secs,
+        removed_containers = removed,
+    );
+    Ok(())
+}
+
+/// 실행 주체가 없는 빌드를 Failed로 처리: 빌드/테스트 컨테이너 삭제, 원인을 빌드 로그에 기록, 이벤트 발행.
+/// 삭제한 컨테이너 수
+pub async fn fail_abandoned_build(ctx: &AppContext, build: &Build, message: String) -> Result<usize> {
+    let removed = ctx.docker.remove_build_containers(build.id).await.unwrap_or_else(|e| {
+        warn!("Failed to remove containers of build #{}: {}", build.build_number, e);
+        0
+    });
+
+    // 로그 화면에서도 원인이 보이도록 빌드 로그에 남김
+    if let Ok(mut log_file) = fs::OpenOptions::new().create(true).append(true).open(&build.log_path).await {
+        log_file.write_all(format!("[WATCHDOG] {}\n", message).as_bytes()).await.ok();
+    }
+
+    ctx.build_repo.finish(build.id, BuildStatus::Failed).await?;
+    ctx.event_bus.emit(Event::build_status(build.id, build.project_id, BuildStatus::Failed)).await;
+    ctx.event_bus
+        .emit(Event::Error {
+            project_id: Some(build.project_id),
+            build_id: Some(build.id),
+            message,
+            timestamp: Event::now(),
+        })
+        .await;
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(id: i64, status: BuildStatus, started_at: &str) -> Build {
+        Build { started_at: started_at.to_string(), ..Build::test(id, status) }
+    }
+
+    #[test]
+    fn test_stale() {
+        let now = timezone::parse_stored("2026-01-01 12:00:00").unwrap();
+        let builds = vec![
+            build(1, BuildStatus::Building, "2026-01-01 09:00:00"),
+            build(2, BuildStatus::Queued, "2026-01-01 11:30:00"),
+            build(3, BuildStatus::Building, "not a date"),
+        ];
+
+        let result = stale(&builds, now, DEFAULT_STALE_BUILD_SECS);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].0.id, 1);
+        assert_eq!(result[0].1, 3 * 3600);
+    }
+}
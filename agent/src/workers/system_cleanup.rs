@@ -0,0 +1,249 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use tracing::info;
+
+use crate::state::AppContext;
+use crate::db::models::BuildStatus;
+use crate::application::ports::repositories::{BuildRepository, ProjectRepository, SessionRepository};
+use crate::workers::container_cleanup::cleanup_containers;
+
+const OUTPUT_ROOT: &str = "/data/output";
+const LOGS_ROOT: &str = "/data/easycicd/logs";
+
+/// 테스트 shard / 캐시 예열용 임시 디렉토리는 이 시간 이상 지난 것만 정리 (실행 중일 수 있음)
+const SCRATCH_MIN_AGE: Duration = Duration::from_secs(3600);
+
+/// 수동 정리 범위
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CleanupScope {
+    /// 삭제된 프로젝트의 로그 (older_than_days 지정 시 오래된 로그 파일도)
+    Logs,
+    /// 삭제/실패한 빌드의 산출물과 남은 임시 디렉토리
+    Artifacts,
+    /// dangling 이미지
+    Images,
+    /// 만료된 세션
+    Sessions,
+    /// 고아 컨테이너 (container cleanup worker와 동일)
+    Containers,
+}
+
+impl std::fmt::Display for CleanupScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            CleanupScope::Logs => "logs",
+            CleanupScope::Artifacts => "artifacts",
+            CleanupScope::Images => "images",
+            CleanupScope::Sessions => "sessions",
+            CleanupScope::Containers => "containers",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CleanupScopeResult {
+    pub scope: CleanupScope,
+    pub removed: u64,
+    pub freed_bytes: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 선택한 범위를 순서대로 정리. 한 범위가 실패해도 나머지는 계속 진행
+pub async fn run_cleanup(
+    context: &AppContext,
+    scopes: &[CleanupScope],
+    older_than_days: Option<u64>,
+) -> Vec<CleanupScopeResult> {
+    let mut results = Vec::with_capacity(scopes.len());
+
+    for scope in scopes {
+        let outcome = match scope {
+            CleanupScope::Logs => cleanup_logs(context, older_than_days).await,
+            CleanupScope::Artifacts => cleanup_artifacts(context).await,
+            CleanupScope::Images => context
+                .docker
+                .prune_dangling_images()
+                .await
+                .map(|(count, bytes)| (count as u64, bytes.max(0) as u64)),
+            CleanupScope::Sessions => context.session_repo.delete_expired().await.map(|count| (count, 0)),
+            CleanupScope::Containers => cleanup_containers(context).await.map(|count| (count as u64, 0)),
+        };
+
+        let result = match outcome {
+            Ok((removed, freed_bytes)) => {
+                info!("Cleanup [{}]: removed {} item(s), freed {} bytes", scope, removed, freed_bytes);
+                CleanupScopeResult { scope: *scope, removed, freed_bytes, error: None }
+            }
+            Err(e) => CleanupScopeResult { scope: *scope, removed: 0, freed_bytes: 0, error: Some(e.to_string()) },
+        };
+        results.push(result);
+    }
+
+    results
+}
+
+/// 삭제된 프로젝트의 로그 디렉토리 제거, older_than_days가 있으면 그보다 오래된 로그 파일도 제거
+async fn cleanup_logs(context: &AppContext, older_than_days: Option<u64>) -> Result<(u64, u64)> {
+    let project_ids: HashSet<String> = context
+        .project_repo
+        .list_with_deleted()
+        .await?
+        .iter()
+        .map(|p| p.id.to_string())
+        .collect();
+    let max_age = older_than_days.map(|d| Duration::from_secs(d * 86400));
+
+    tokio::task::spawn_blocking(move || {
+        let mut removed = 0;
+        let mut freed = 0;
+        let Ok(entries) = std::fs::read_dir(LOGS_ROOT) else { return Ok((0, 0)) };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().to_string();
+
+            // 프로젝트별 로그 디렉토리는 숫자 id
+            if name.parse::<i64>().is_ok() && !project_ids.contains(&name) {
+                let (size, ok) = remove_path(&path);
+                if ok {
+                    removed += 1;
+                    freed += size;
+                }
+                continue;
+            }
+
+            if let Some(max_age) = max_age {
+                let Ok(files) = std::fs::read_dir(&path) else { continue };
+                for file in files.flatten() {
+                    let file_path = file.path();
+                    if file_path.is_file() && is_older_than(&file_path, max_age) {
+                        let (size, ok) = remove_path(&file_path);
+                        if ok {
+                            removed += 1;
+                            freed += size;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok((removed, freed))
+    })
+    .await?
+}
+
+/// DB에 없거나 실패한 빌드의 산출물, 오래된 임시 디렉토리 제거
+///
+/// 성공한 빌드의 산출물은 롤백에 쓰이므로 남겨둔다.
+async fn cleanup_artifacts(context: &AppContext) -> Result<(u64, u64)> {
+    let builds: HashMap<i64, BuildStatus> = context
+        .build_repo
+        .list(i64::MAX)
+        .await?
+        .into_iter()
+        .map(|b| (b.id, b.status))
+        .collect();
+
+    tokio::task::spawn_blocking(move || {
+        let mut removed = 0;
+        let mut freed = 0;
+        let Ok(entries) = std::fs::read_dir(OUTPUT_ROOT) else { return Ok((0, 0)) };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+
+            let remove = match artifact_kind(&name) {
+                ArtifactKind::Build(id) => match builds.get(&id) {
+                    None => true,
+                    Some(status) => *status == BuildStatus::Failed,
+                },
+                ArtifactKind::Scratch => is_older_than(&path, SCRATCH_MIN_AGE),
+                ArtifactKind::Other => false,
+            };
+
+            if remove {
+                let (size, ok) = remove_path(&path);
+                if ok {
+                    removed += 1;
+                    freed += size;
+                }
+            }
+        }
+
+        Ok((removed, freed))
+    })
+    .await?
+}
+
+#[derive(Debug, PartialEq)]
+enum ArtifactKind {
+    /// build{id}
+    Build(i64),
+    /// build{id}-shard{n}, warm-cache-{project_id}
+    Scratch,
+    Other,
+}
+
+fn artifact_kind(name: &str) -> ArtifactKind {
+    if name.starts_with("warm-cache-") {
+        return ArtifactKind::Scratch;
+    }
+    match name.strip_prefix("build") {
+        Some(rest) if rest.contains("-shard") => ArtifactKind::Scratch,
+        Some(rest) => rest.parse().map(ArtifactKind::Build).unwrap_or(ArtifactKind::Other),
+        None => ArtifactKind::Other,
+    }
+}
+
+fn is_older_than(path: &Path, age: Duration) -> bool {
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|elapsed| elapsed >= age)
+}
+
+/// 파일/디렉토리 삭제. (삭제 전 크기, 성공 여부) 반환
+fn remove_path(path: &Path) -> (u64, bool) {
+    let size = path_size(path);
+    let ok = if path.is_dir() {
+        std::fs::remove_dir_all(path).is_ok()
+    } else {
+        std::fs::remove_file(path).is_ok()
+    };
+    (size, ok)
+}
+
+fn path_size(path: &Path) -> u64 {
+    let Ok(meta) = std::fs::symlink_metadata(path) else { return 0 };
+    if !meta.is_dir() {
+        return meta.len();
+    }
+    std::fs::read_dir(path)
+        .map(|entries| entries.flatten().map(|e| path_size(&e.path())).sum())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_artifact_kind() {
+        assert_eq!(artifact_kind("build42"), ArtifactKind::Build(42));
+        assert_eq!(artifact_kind("build42-shard3"), ArtifactKind::Scratch);
+        assert_eq!(artifact_kind("warm-cache-7"), ArtifactKind::Scratch);
+        assert_eq!(artifact_kind("buildx"), ArtifactKind::Other);
+        assert_eq!(artifact_kind("something"), ArtifactKind::Other);
+    }
+}
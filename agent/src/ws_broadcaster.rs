@@ -1,14 +1,21 @@
 use anyhow::Result;
-use tokio::time::{sleep, Duration};
+use tokio::time::{interval, Duration, MissedTickBehavior};
 use tracing::{info, warn, error};
 
 use crate::state::AppContext;
 use crate::application::events::event_bus::EventBus;
 
+/// 서버 ping 주기
+const WS_PING_INTERVAL: Duration = Duration::from_secs(20);
+/// 이 시간 동안 클라이언트 응답(pong 포함)이 없으면 연결 제거
+const WS_LIVENESS_TIMEOUT: Duration = Duration::from_secs(60);
+
 pub async fn run_ws_broadcaster(context: AppContext) -> Result<()> {
     info!("WebSocket broadcaster started");
 
     let mut event_rx = context.event_bus.subscribe();
+    let mut heartbeat = interval(WS_PING_INTERVAL);
+    heartbeat.set_missed_tick_behavior(MissedTickBehavior::Delay);
 
     loop {
         tokio::select! {
@@ -25,8 +32,12 @@ pub async fn run_ws_broadcaster(context: AppContext) -> Result<()> {
                     }
                 }
             }
-            _ = sleep(Duration::from_secs(1)) => {
-                // Periodic cleanup or heartbeat if needed
+            _ = heartbeat.tick() => {
+                let reaped = context.ws_connections.reap_stale(WS_LIVENESS_TIMEOUT).await;
+                if reaped > 0 {
+                    info!("Removed {} stale WebSocket connection(s)", reaped);
+                }
+                context.ws_connections.ping_all().await;
             }
         }
     }
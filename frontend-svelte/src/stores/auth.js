@@ -4,6 +4,8 @@ import { writable, derived } from 'svelte/store';
 export const user = writable(null);
 export const authLoading = writable(true);
 export const authError = writable(null);
+// Configured login providers (from /auth/me)
+export const authProviders = writable({ google: true, github: false });
 
 // Derived store for easy auth check
 export const isAuthenticated = derived(user, ($user) => $user !== null);
@@ -26,6 +28,10 @@ export async function checkAuth() {
 
         const data = await response.json();
 
+        if (data.providers) {
+            authProviders.set(data.providers);
+        }
+
         if (data.authenticated && data.user) {
             user.set(data.user);
         } else {
@@ -47,6 +53,13 @@ export function loginWithGoogle() {
     window.location.href = '/auth/google';
 }
 
+/**
+ * Redirect to GitHub login (links the GitHub account to the current user if logged in)
+ */
+export function loginWithGitHub() {
+    window.location.href = '/auth/github';
+}
+
 /**
  * Logout
  */
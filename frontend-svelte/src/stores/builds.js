@@ -124,7 +124,7 @@ export function updateBuildFromWebSocket(data) {
 
         selectedBuild.update(build => {
             if (build && build.id === build_id) {
-                if (status === 'Success' || status === 'Failed') {
+                if (status === 'Success' || status === 'Failed' || status === 'Verified') {
                     isStreaming.set(false);
                 }
                 return { ...build, status, updated_at: data.timestamp };
@@ -151,6 +151,8 @@ export const buildProgress = derived(selectedBuild, $selectedBuild => {
             return { stage: '배포 중', progress: 80 };
         case 'Success':
             return { stage: '완료', progress: 100 };
+        case 'Verified':
+            return { stage: '검증 완료 (배포 생략)', progress: 100 };
         case 'Failed':
             return { stage: '실패', progress: 100 };
         default: